@@ -1,8 +1,106 @@
-extern type Array<T>;
-extern fn array_new<T>() -> Array::<T> nopanic;
-extern fn array_append<T>(ref arr: Array::<T>, value: T) nopanic;
-extern fn array_pop_front<T>(ref arr: Array::<T>) -> Option::<T> nopanic;
-extern fn array_at<T>(
+pub extern type Array<T>;
+pub extern fn array_new<T>() -> Array::<T> nopanic;
+pub extern fn array_append<T>(ref arr: Array::<T>, value: T) nopanic;
+pub extern fn array_pop_front<T>(ref arr: Array::<T>) -> Option::<T> nopanic;
+pub extern fn array_at<T>(
     ref arr: Array::<T>, index: u128
 ) -> Option::<T> implicits(RangeCheck) nopanic;
-extern fn array_len<T>(ref arr: Array::<T>) -> u128 nopanic;
+pub extern fn array_len<T>(ref arr: Array::<T>) -> u128 nopanic;
+pub extern fn array_slice<T>(
+    ref arr: Array::<T>, start: u128, length: u128
+) -> Option::<ArraySlice::<T>> implicits(RangeCheck) nopanic;
+
+// A read-only view of a contiguous part of an array. Taking a slice does not copy the elements, so
+// they must be copyable, as they are shared by all the copies of the slice.
+pub extern type ArraySlice<T>;
+pub impl ArraySliceCopy<T> of Copy::<ArraySlice::<T>>;
+pub impl ArraySliceDrop<T> of Drop::<ArraySlice::<T>>;
+pub extern fn array_slice_pop_front<T>(ref slice: ArraySlice::<T>) -> Option::<T> nopanic;
+pub extern fn array_slice_len<T>(ref slice: ArraySlice::<T>) -> u128 nopanic;
+
+pub trait ArrayTrait<T> {
+    fn new() -> Array::<T> nopanic;
+    fn append(ref self: Array::<T>, value: T) nopanic;
+    fn pop_front(ref self: Array::<T>) -> Option::<T> nopanic;
+    fn at(ref self: Array::<T>, index: u128) -> Option::<T> implicits(RangeCheck) nopanic;
+    fn len(ref self: Array::<T>) -> u128 nopanic;
+    fn slice(
+        ref self: Array::<T>, start: u128, length: u128
+    ) -> Option::<ArraySlice::<T>> implicits(RangeCheck) nopanic;
+}
+pub impl ArrayImpl<T> of ArrayTrait::<T> {
+    fn new() -> Array::<T> nopanic {
+        array_new()
+    }
+    fn append(ref self: Array::<T>, value: T) nopanic {
+        array_append(self, value)
+    }
+    fn pop_front(ref self: Array::<T>) -> Option::<T> nopanic {
+        array_pop_front(self)
+    }
+    fn at(ref self: Array::<T>, index: u128) -> Option::<T> implicits(RangeCheck) nopanic {
+        array_at(self, index)
+    }
+    fn len(ref self: Array::<T>) -> u128 nopanic {
+        array_len(self)
+    }
+    fn slice(
+        ref self: Array::<T>, start: u128, length: u128
+    ) -> Option::<ArraySlice::<T>> implicits(RangeCheck) nopanic {
+        array_slice(self, start, length)
+    }
+}
+
+pub impl ArrayIndex<T> of Index::<Array::<T>, T> {
+    fn index(ref self: Array::<T>, index: u128) -> T {
+        match array_at(self, index) {
+            Option::Some(x) => x,
+            Option::None(_) => {
+                let mut data = array_new();
+                array_append(data, 'Index out of bounds');
+                panic(data)
+            },
+        }
+    }
+}
+
+pub trait ArraySliceTrait<T> {
+    fn pop_front(ref self: ArraySlice::<T>) -> Option::<T> nopanic;
+    fn len(ref self: ArraySlice::<T>) -> u128 nopanic;
+}
+pub impl ArraySliceImpl<T> of ArraySliceTrait::<T> {
+    fn pop_front(ref self: ArraySlice::<T>) -> Option::<T> nopanic {
+        array_slice_pop_front(self)
+    }
+    fn len(ref self: ArraySlice::<T>) -> u128 nopanic {
+        array_slice_len(self)
+    }
+}
+
+// Returns an array of the results of calling `f` on the elements of `arr`, consuming them.
+pub fn array_map<T, U, F>(ref arr: Array::<T>, mut f: F) -> Array::<U> {
+    let mut result = array_new::<U>();
+    loop {
+        match array_pop_front(arr) {
+            Option::Some(value) => {
+                array_append(result, Fn::<F, (T, ), U>::call(f, (value, )));
+            },
+            Option::None(_) => {
+                break ();
+            },
+        }
+    };
+    result
+}
+
+// Folds the elements of `arr` into an accumulator, starting from `init`, by calling `f` on the
+// accumulator and each element in turn. Consumes the elements of `arr`.
+pub fn array_fold<T, A, F>(ref arr: Array::<T>, init: A, mut f: F) -> A {
+    match array_pop_front(arr) {
+        Option::Some(value) => {
+            let acc = Fn::<F, (A, T), A>::call(f, (init, value));
+            array_fold(arr, acc, f)
+        },
+        Option::None(_) => init,
+    }
+}
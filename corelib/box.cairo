@@ -1,6 +1,6 @@
-extern type Box<T>;
-impl BoxFeltCopy of Copy::<Box::<felt>>;
-impl BoxFeltDrop of Drop::<Box::<felt>>;
+pub extern type Box<T>;
+pub impl BoxFeltCopy of Copy::<Box::<felt>>;
+pub impl BoxFeltDrop of Drop::<Box::<felt>>;
 
-extern fn into_box<T>(value: T) -> Box::<T> nopanic;
-extern fn unbox<T>(box: Box::<T>) -> T nopanic;
+pub extern fn into_box<T>(value: T) -> Box::<T> nopanic;
+pub extern fn unbox<T>(box: Box::<T>) -> T nopanic;
@@ -0,0 +1,271 @@
+// The number of bytes in each of the full words of a byte array.
+const BYTES_IN_WORD: u128 = 31_u128;
+// 2^128, the weight of the high limb of a u256.
+const POW_2_128: felt = 0x100000000000000000000000000000000;
+// Marks the start of a serialized byte array in panic data, so that it can be told apart from
+// short strings when the panic is displayed.
+pub const BYTE_ARRAY_MAGIC: felt = 0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3;
+
+// A sequence of bytes of any length. String literals ("...") are of this type.
+pub struct ByteArray {
+    // Full words of `BYTES_IN_WORD` bytes each, most significant byte first.
+    pub data: Array::<felt>,
+    // The bytes following the full words, most significant byte first.
+    pub pending_word: felt,
+    // The number of bytes in `pending_word`. Always smaller than `BYTES_IN_WORD`.
+    pub pending_word_len: u128,
+}
+
+// Used by the compiler to build string literals: the full words of the literal are pushed to an
+// empty array, which is then combined with the remaining bytes.
+pub fn string_literal_push_word(mut data: Array::<felt>, word: felt) -> Array::<felt> nopanic {
+    array_append(data, word);
+    data
+}
+pub fn string_literal_build(
+    data: Array::<felt>, pending_word: felt, pending_word_len: u128
+) -> ByteArray nopanic {
+    ByteArray { data, pending_word, pending_word_len }
+}
+
+pub trait ByteArrayTrait {
+    fn new() -> ByteArray nopanic;
+    fn len(ref self: ByteArray) -> u128;
+    fn at(ref self: ByteArray, index: u128) -> Option::<u8>;
+    fn append_byte(ref self: ByteArray, byte: u8);
+    fn append_word(ref self: ByteArray, word: felt, len: u128);
+    fn append(ref self: ByteArray, other: ByteArray);
+}
+pub impl ByteArrayImpl of ByteArrayTrait {
+    fn new() -> ByteArray nopanic {
+        ByteArray { data: array_new(), pending_word: 0, pending_word_len: 0_u128 }
+    }
+    fn len(ref self: ByteArray) -> u128 {
+        let ByteArray{data, pending_word, pending_word_len } = self;
+        let mut data = data;
+        let n_words = array_len(data);
+        self = ByteArray { data, pending_word, pending_word_len };
+        n_words * BYTES_IN_WORD + pending_word_len
+    }
+    fn at(ref self: ByteArray, index: u128) -> Option::<u8> {
+        if index >= self.len() {
+            return Option::<u8>::None(());
+        }
+        let word_index = index / BYTES_IN_WORD;
+        let ByteArray{data, pending_word, pending_word_len } = self;
+        let mut data = data;
+        let mut word = pending_word;
+        let mut word_len = pending_word_len;
+        match array_at(data, word_index) {
+            Option::Some(full_word) => {
+                word = full_word;
+                word_len = BYTES_IN_WORD;
+            },
+            Option::None(_) => {
+            },
+        }
+        self = ByteArray { data, pending_word, pending_word_len };
+        let (high, _) = split_word(word, word_len - index % BYTES_IN_WORD - 1_u128);
+        let (_, byte) = split_word(high, 1_u128);
+        Option::<u8>::Some(u8_from_felt(byte))
+    }
+    fn append_byte(ref self: ByteArray, byte: u8) {
+        self.append_word(u8_to_felt(byte), 1_u128);
+    }
+    // Appends the `len` bytes of `word`, most significant byte first. `len` must be at most
+    // `BYTES_IN_WORD`.
+    fn append_word(ref self: ByteArray, word: felt, len: u128) {
+        let (pending_word, pending_word_len) = get_pending_word(self);
+        let total_len = pending_word_len + len;
+        if total_len < BYTES_IN_WORD {
+            set_pending_word(self, pending_word * pow_256(len) + word, total_len);
+        } else {
+            // The first bytes of `word` complete the pending word, and the rest start a new one.
+            let new_pending_word_len = total_len - BYTES_IN_WORD;
+            let (high, low) = split_word(word, new_pending_word_len);
+            push_full_word(self, pending_word * pow_256(len - new_pending_word_len) + high);
+            set_pending_word(self, low, new_pending_word_len);
+        }
+    }
+    fn append(ref self: ByteArray, other: ByteArray) {
+        let ByteArray{data, pending_word, pending_word_len } = other;
+        let mut data = data;
+        loop {
+            match get_gas() {
+                Option::Some(_) => {
+                },
+                Option::None(_) => {
+                    let mut data = array_new();
+                    array_append(data, 'Out of gas');
+                    panic(data);
+                },
+            }
+            match array_pop_front(data) {
+                Option::Some(word) => {
+                    self.append_word(word, BYTES_IN_WORD);
+                },
+                Option::None(_) => {
+                    break ();
+                },
+            }
+        };
+        self.append_word(pending_word, pending_word_len);
+    }
+}
+
+pub impl ByteArrayAdd of Add::<ByteArray> {
+    fn add(a: ByteArray, b: ByteArray) -> ByteArray {
+        let mut result = a;
+        result.append(b);
+        result
+    }
+}
+
+pub impl ByteArrayPartialEq of PartialEq::<ByteArray> {
+    fn eq(a: ByteArray, b: ByteArray) -> bool {
+        let ByteArray{data: a_data, pending_word: a_pending_word, pending_word_len: a_pending_word_len
+        } = a;
+        let ByteArray{data: b_data, pending_word: b_pending_word, pending_word_len: b_pending_word_len
+        } = b;
+        let mut a_data = a_data;
+        let mut b_data = b_data;
+        if a_pending_word_len != b_pending_word_len | a_pending_word != b_pending_word {
+            return false;
+        }
+        if array_len(a_data) != array_len(b_data) {
+            return false;
+        }
+        loop {
+            match get_gas() {
+                Option::Some(_) => {
+                },
+                Option::None(_) => {
+                    let mut data = array_new();
+                    array_append(data, 'Out of gas');
+                    panic(data);
+                },
+            }
+            match array_pop_front(a_data) {
+                Option::Some(a_word) => {
+                    match array_pop_front(b_data) {
+                        Option::Some(b_word) => {
+                            if a_word != b_word {
+                                break false;
+                            }
+                        },
+                        Option::None(_) => {
+                            break false;
+                        },
+                    }
+                },
+                Option::None(_) => {
+                    break true;
+                },
+            }
+        }
+    }
+    fn ne(a: ByteArray, b: ByteArray) -> bool {
+        !(a == b)
+    }
+}
+
+pub impl ByteArrayIndex of Index::<ByteArray, u8> {
+    fn index(ref self: ByteArray, index: u128) -> u8 {
+        match self.at(index) {
+            Option::Some(byte) => byte,
+            Option::None(_) => {
+                let mut data = array_new();
+                array_append(data, 'Index out of bounds');
+                panic(data)
+            },
+        }
+    }
+}
+
+// Panics with the given byte array. The panic data is `BYTE_ARRAY_MAGIC` followed by the
+// serialization of `err`.
+pub fn panic_with_byte_array(err: ByteArray) -> never {
+    let mut data = array_new();
+    array_append(data, BYTE_ARRAY_MAGIC);
+    serde::serialize_byte_array(data, err);
+    panic(data)
+}
+
+// The following helpers access the members of a byte array without consuming it.
+fn get_pending_word(ref byte_array: ByteArray) -> (felt, u128) nopanic {
+    let ByteArray{data, pending_word, pending_word_len } = byte_array;
+    byte_array = ByteArray { data, pending_word, pending_word_len };
+    (pending_word, pending_word_len)
+}
+fn set_pending_word(ref byte_array: ByteArray, pending_word: felt, pending_word_len: u128) nopanic {
+    let ByteArray{data, pending_word: _, pending_word_len: _ } = byte_array;
+    byte_array = ByteArray { data, pending_word, pending_word_len };
+}
+fn push_full_word(ref byte_array: ByteArray, word: felt) nopanic {
+    let ByteArray{data, pending_word, pending_word_len } = byte_array;
+    let mut data = data;
+    array_append(data, word);
+    byte_array = ByteArray { data, pending_word, pending_word_len };
+}
+
+// Returns 256^n, for `n` at most `BYTES_IN_WORD`.
+fn pow_256(n: u128) -> felt {
+    match n {
+        0_u128 => 0x1,
+        1_u128 => 0x100,
+        2_u128 => 0x10000,
+        3_u128 => 0x1000000,
+        4_u128 => 0x100000000,
+        5_u128 => 0x10000000000,
+        6_u128 => 0x1000000000000,
+        7_u128 => 0x100000000000000,
+        8_u128 => 0x10000000000000000,
+        9_u128 => 0x1000000000000000000,
+        10_u128 => 0x100000000000000000000,
+        11_u128 => 0x10000000000000000000000,
+        12_u128 => 0x1000000000000000000000000,
+        13_u128 => 0x100000000000000000000000000,
+        14_u128 => 0x10000000000000000000000000000,
+        15_u128 => 0x1000000000000000000000000000000,
+        16_u128 => 0x100000000000000000000000000000000,
+        17_u128 => 0x10000000000000000000000000000000000,
+        18_u128 => 0x1000000000000000000000000000000000000,
+        19_u128 => 0x100000000000000000000000000000000000000,
+        20_u128 => 0x10000000000000000000000000000000000000000,
+        21_u128 => 0x1000000000000000000000000000000000000000000,
+        22_u128 => 0x100000000000000000000000000000000000000000000,
+        23_u128 => 0x10000000000000000000000000000000000000000000000,
+        24_u128 => 0x1000000000000000000000000000000000000000000000000,
+        25_u128 => 0x100000000000000000000000000000000000000000000000000,
+        26_u128 => 0x10000000000000000000000000000000000000000000000000000,
+        27_u128 => 0x1000000000000000000000000000000000000000000000000000000,
+        28_u128 => 0x100000000000000000000000000000000000000000000000000000000,
+        29_u128 => 0x10000000000000000000000000000000000000000000000000000000000,
+        30_u128 => 0x1000000000000000000000000000000000000000000000000000000000000,
+        31_u128 => 0x100000000000000000000000000000000000000000000000000000000000000,
+        _ => {
+            let mut data = array_new();
+            array_append(data, 'pow_256 out of range');
+            panic(data)
+        },
+    }
+}
+
+// Splits a word into the value of its bytes above the `n` least significant bytes, and the value
+// of those `n` bytes. `n` must be at most `BYTES_IN_WORD`.
+fn split_word(word: felt, n: u128) -> (felt, felt) {
+    let value = u256_from_felt(word);
+    if n < 16_u128 {
+        let divisor = u128_from_felt(pow_256(n));
+        (
+            u128_to_felt(value.high) * pow_256(16_u128 - n) + u128_to_felt(value.low / divisor),
+            u128_to_felt(value.low % divisor)
+        )
+    } else {
+        let divisor = u128_from_felt(pow_256(n - 16_u128));
+        (
+            u128_to_felt(value.high / divisor),
+            u128_to_felt(value.high % divisor) * POW_2_128 + u128_to_felt(value.low)
+        )
+    }
+}
@@ -1,11 +1,32 @@
-extern type DictManager;
-extern type DictFeltTo<T>;
+pub extern type DictManager;
+pub extern type DictFeltTo<T>;
 #[derive(Drop)]
-extern type SquashedDictFeltTo<T>;
+pub extern type SquashedDictFeltTo<T>;
 
-extern fn dict_felt_to_new<T>() -> DictFeltTo::<T> implicits(DictManager) nopanic;
-extern fn dict_felt_to_write<T>(ref dict: DictFeltTo::<T>, key: felt, value: T) nopanic;
-extern fn dict_felt_to_read<T>(ref dict: DictFeltTo::<T>, key: felt) -> T nopanic;
-extern fn dict_felt_to_squash<T>(
+pub extern fn dict_felt_to_new<T>() -> DictFeltTo::<T> implicits(DictManager) nopanic;
+pub extern fn dict_felt_to_write<T>(ref dict: DictFeltTo::<T>, key: felt, value: T) nopanic;
+pub extern fn dict_felt_to_read<T>(ref dict: DictFeltTo::<T>, key: felt) -> T nopanic;
+pub extern fn dict_felt_to_squash<T>(
     dict: DictFeltTo::<T>
 ) -> SquashedDictFeltTo::<T> implicits(DictManager) nopanic;
+
+pub trait DictFeltToTrait<T> {
+    fn new() -> DictFeltTo::<T> implicits(DictManager) nopanic;
+    fn insert(ref self: DictFeltTo::<T>, key: felt, value: T) nopanic;
+    fn get(ref self: DictFeltTo::<T>, key: felt) -> T nopanic;
+    fn squash(self: DictFeltTo::<T>) -> SquashedDictFeltTo::<T> implicits(DictManager) nopanic;
+}
+pub impl DictFeltToImpl<T> of DictFeltToTrait::<T> {
+    fn new() -> DictFeltTo::<T> implicits(DictManager) nopanic {
+        dict_felt_to_new()
+    }
+    fn insert(ref self: DictFeltTo::<T>, key: felt, value: T) nopanic {
+        dict_felt_to_write(self, key, value)
+    }
+    fn get(ref self: DictFeltTo::<T>, key: felt) -> T nopanic {
+        dict_felt_to_read(self, key)
+    }
+    fn squash(self: DictFeltTo::<T>) -> SquashedDictFeltTo::<T> implicits(DictManager) nopanic {
+        dict_felt_to_squash(self)
+    }
+}
@@ -1,5 +1,5 @@
-extern type EcPoint;
+pub extern type EcPoint;
 
 #[panic_with('not on EC', ec_point_from_felts)]
-extern fn ec_point_try_create(x: felt, y: felt) -> Option::<EcPoint> nopanic;
-extern fn ec_point_unwrap(p: EcPoint) -> (felt, felt) nopanic;
+pub extern fn ec_point_try_create(x: felt, y: felt) -> Option::<EcPoint> nopanic;
+pub extern fn ec_point_unwrap(p: EcPoint) -> (felt, felt) nopanic;
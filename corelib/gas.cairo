@@ -1,10 +1,10 @@
-extern type BuiltinCosts;
-extern type GasBuiltin;
+pub extern type BuiltinCosts;
+pub extern type GasBuiltin;
 
-impl BuiltinCostsCopy of Copy::<BuiltinCosts>;
-impl BuiltinCostsDrop of Drop::<BuiltinCosts>;
+pub impl BuiltinCostsCopy of Copy::<BuiltinCosts>;
+pub impl BuiltinCostsDrop of Drop::<BuiltinCosts>;
 
-extern fn get_gas() -> Option::<()> implicits(RangeCheck, GasBuiltin) nopanic;
-extern fn get_gas_all(
+pub extern fn get_gas() -> Option::<()> implicits(RangeCheck, GasBuiltin) nopanic;
+pub extern fn get_gas_all(
     costs: BuiltinCosts
 ) -> Option::<()> implicits(RangeCheck, GasBuiltin) nopanic;
@@ -1,3 +1,59 @@
-extern type Pedersen;
+pub extern type Pedersen;
 
-extern fn pedersen(a: felt, b: felt) -> felt implicits(Pedersen) nopanic;
+pub extern fn pedersen(a: felt, b: felt) -> felt implicits(Pedersen) nopanic;
+
+// Hashes a value into a state, using Pedersen hash. Used for computing the addresses of the
+// entries of storage mappings.
+pub trait LegacyHash<T> {
+    fn hash(state: felt, value: T) -> felt;
+}
+
+impl LegacyHashFelt of LegacyHash::<felt> {
+    fn hash(state: felt, value: felt) -> felt {
+        pedersen(state, value)
+    }
+}
+
+impl LegacyHashBool of LegacyHash::<bool> {
+    fn hash(state: felt, value: bool) -> felt {
+        LegacyHash::<felt>::hash(state, value.into())
+    }
+}
+
+impl LegacyHashU8 of LegacyHash::<u8> {
+    fn hash(state: felt, value: u8) -> felt {
+        LegacyHash::<felt>::hash(state, value.into())
+    }
+}
+
+impl LegacyHashU16 of LegacyHash::<u16> {
+    fn hash(state: felt, value: u16) -> felt {
+        LegacyHash::<felt>::hash(state, value.into())
+    }
+}
+
+impl LegacyHashU32 of LegacyHash::<u32> {
+    fn hash(state: felt, value: u32) -> felt {
+        LegacyHash::<felt>::hash(state, value.into())
+    }
+}
+
+impl LegacyHashU64 of LegacyHash::<u64> {
+    fn hash(state: felt, value: u64) -> felt {
+        LegacyHash::<felt>::hash(state, value.into())
+    }
+}
+
+impl LegacyHashU128 of LegacyHash::<u128> {
+    fn hash(state: felt, value: u128) -> felt {
+        LegacyHash::<felt>::hash(state, value.into())
+    }
+}
+
+// A u256 value is hashed as its low word followed by its high word.
+impl LegacyHashU256 of LegacyHash::<u256> {
+    fn hash(state: felt, value: u256) -> felt {
+        let state = LegacyHash::<u128>::hash(state, value.low);
+        LegacyHash::<u128>::hash(state, value.high)
+    }
+}
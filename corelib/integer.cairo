@@ -1,38 +1,41 @@
-extern type u128;
-impl U128Copy of Copy::<u128>;
-impl U128Drop of Drop::<u128>;
-extern fn u128_const<value>() -> u128 nopanic;
+pub extern type u128;
+pub impl U128Copy of Copy::<u128>;
+pub impl U128Drop of Drop::<u128>;
+pub extern fn u128_const<value>() -> u128 nopanic;
 
-enum U128sFromFeltResult { Narrow: u128, Wide: (u128, u128), }
-extern fn u128s_from_felt(a: felt) -> U128sFromFeltResult implicits(RangeCheck) nopanic;
+pub enum U128sFromFeltResult { Narrow: u128, Wide: (u128, u128), }
+#[deprecated(into)]
+pub extern fn u128s_from_felt(a: felt) -> U128sFromFeltResult implicits(RangeCheck) nopanic;
 
+#[deprecated(try_into)]
 #[panic_with('u128_from OF', u128_from_felt)]
-fn u128_try_from_felt(a: felt) -> Option::<u128> implicits(RangeCheck) nopanic {
+pub fn u128_try_from_felt(a: felt) -> Option::<u128> implicits(RangeCheck) nopanic {
     match u128s_from_felt(a) {
         U128sFromFeltResult::Narrow(x) => Option::<u128>::Some(x),
         U128sFromFeltResult::Wide(x) => Option::<u128>::None(()),
     }
 }
 
-extern fn u128_to_felt(a: u128) -> felt nopanic;
+#[deprecated(into)]
+pub extern fn u128_to_felt(a: u128) -> felt nopanic;
 
-extern fn u128_overflow_add(
+pub extern fn u128_overflow_add(
     a: u128, b: u128
 ) -> Result::<u128, u128> implicits(RangeCheck) nopanic;
-extern fn u128_overflow_sub(
+pub extern fn u128_overflow_sub(
     a: u128, b: u128
 ) -> Result::<u128, u128> implicits(RangeCheck) nopanic;
 
-fn u128_wrapping_add(a: u128, b: u128) -> u128 implicits(RangeCheck) nopanic {
+pub fn u128_wrapping_add(a: u128, b: u128) -> u128 implicits(RangeCheck) nopanic {
     match u128_overflow_add(a, b) {
         Result::Ok(x) => x,
         Result::Err(x) => x,
     }
 }
 
-extern fn u128_wide_mul(a: u128, b: u128) -> (u128, u128) implicits(RangeCheck) nopanic;
+pub extern fn u128_wide_mul(a: u128, b: u128) -> (u128, u128) implicits(RangeCheck) nopanic;
 
-fn u128_overflow_mul(a: u128, b: u128) -> (u128, bool) implicits(RangeCheck) nopanic {
+pub fn u128_overflow_mul(a: u128, b: u128) -> (u128, bool) implicits(RangeCheck) nopanic {
     let (top_word, bottom_word) = u128_wide_mul(a, b);
     match u128_to_felt(top_word) {
         0 => (bottom_word, false),
@@ -41,7 +44,7 @@ fn u128_overflow_mul(a: u128, b: u128) -> (u128, bool) implicits(RangeCheck) nop
 }
 
 #[panic_with('u128_add OF', u128_add)]
-fn u128_checked_add(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
+pub fn u128_checked_add(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
     match u128_overflow_add(a, b) {
         Result::Ok(r) => Option::<u128>::Some(r),
         Result::Err(r) => Option::<u128>::None(()),
@@ -49,7 +52,7 @@ fn u128_checked_add(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) no
 }
 
 #[panic_with('u128_sub OF', u128_sub)]
-fn u128_checked_sub(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
+pub fn u128_checked_sub(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
     match u128_overflow_sub(a, b) {
         Result::Ok(r) => Option::<u128>::Some(r),
         Result::Err(r) => Option::<u128>::None(()),
@@ -57,7 +60,7 @@ fn u128_checked_sub(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) no
 }
 
 #[panic_with('u128_mul OF', u128_mul)]
-fn u128_checked_mul(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
+pub fn u128_checked_mul(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
     let (top_word, bottom_word) = u128_wide_mul(a, b);
     match u128_to_felt(top_word) {
         0 => Option::<u128>::Some(bottom_word),
@@ -65,200 +68,2874 @@ fn u128_checked_mul(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) no
     }
 }
 
-impl NonZeroU128Copy of Copy::<NonZero::<u128>>;
-impl NonZeroU128Drop of Drop::<NonZero::<u128>>;
+pub impl NonZeroU128Copy of Copy::<NonZero::<u128>>;
+pub impl NonZeroU128Drop of Drop::<NonZero::<u128>>;
 
 #[panic_with('u128 is 0', u128_as_non_zero)]
-fn u128_checked_as_non_zero(a: u128) -> Option::<NonZero::<u128>> implicits() nopanic {
+pub fn u128_checked_as_non_zero(a: u128) -> Option::<NonZero::<u128>> implicits() nopanic {
     match u128_jump_nz(a) {
         JumpNzResult::Zero(()) => Option::<NonZero::<u128>>::None(()),
         JumpNzResult::NonZero(x) => Option::<NonZero::<u128>>::Some(x),
     }
 }
 
-fn u128_safe_div(a: u128, b: NonZero::<u128>) -> u128 implicits(RangeCheck) nopanic {
+pub fn u128_safe_div(a: u128, b: NonZero::<u128>) -> u128 implicits(RangeCheck) nopanic {
     let (q, r) = u128_safe_divmod(a, b);
     q
 }
 
-fn u128_div(a: u128, b: u128) -> u128 implicits(RangeCheck) {
+pub fn u128_div(a: u128, b: u128) -> u128 implicits(RangeCheck) {
     u128_safe_div(a, u128_as_non_zero(b))
 }
 
-fn u128_safe_mod(a: u128, b: NonZero::<u128>) -> u128 implicits(RangeCheck) nopanic {
+pub fn u128_safe_mod(a: u128, b: NonZero::<u128>) -> u128 implicits(RangeCheck) nopanic {
     let (q, r) = u128_safe_divmod(a, b);
     r
 }
 
-fn u128_mod(a: u128, b: u128) -> u128 implicits(RangeCheck) {
+pub fn u128_mod(a: u128, b: u128) -> u128 implicits(RangeCheck) {
     u128_safe_mod(a, u128_as_non_zero(b))
 }
 
-extern fn u128_safe_divmod(
+pub extern fn u128_safe_divmod(
     a: u128, b: NonZero::<u128>
     ) -> (
     u128, u128
 ) implicits(RangeCheck) nopanic;
 
-extern fn u128_lt(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic;
-extern fn u128_eq(a: u128, b: u128) -> bool implicits() nopanic;
-extern fn u128_le(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic;
+pub extern fn u128_lt(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic;
+pub extern fn u128_eq(a: u128, b: u128) -> bool implicits() nopanic;
+pub extern fn u128_le(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic;
 
-fn u128_gt(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic {
+pub fn u128_gt(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic {
     u128_lt(b, a)
 }
 
-fn u128_ge(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic {
+pub fn u128_ge(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic {
     u128_le(b, a)
 }
 
-fn u128_ne(a: u128, b: u128) -> bool implicits() nopanic {
+pub fn u128_ne(a: u128, b: u128) -> bool implicits() nopanic {
     !(a == b)
 }
 
-extern type Bitwise;
-extern fn bitwise(a: u128, b: u128) -> (u128, u128, u128) implicits(Bitwise) nopanic;
-fn u128_and(a: u128, b: u128) -> u128 implicits(Bitwise) nopanic {
+pub extern type Bitwise;
+pub extern fn bitwise(a: u128, b: u128) -> (u128, u128, u128) implicits(Bitwise) nopanic;
+pub fn u128_and(a: u128, b: u128) -> u128 implicits(Bitwise) nopanic {
     let (v, _, _) = bitwise(a, b);
     v
 }
-fn u128_xor(a: u128, b: u128) -> u128 implicits(Bitwise) nopanic {
+pub fn u128_xor(a: u128, b: u128) -> u128 implicits(Bitwise) nopanic {
     let (_, v, _) = bitwise(a, b);
     v
 }
-fn u128_or(a: u128, b: u128) -> u128 implicits(Bitwise) nopanic {
+pub fn u128_or(a: u128, b: u128) -> u128 implicits(Bitwise) nopanic {
     let (_, _, v) = bitwise(a, b);
     v
 }
 
-extern fn u128_jump_nz(a: u128) -> JumpNzResult::<u128> implicits() nopanic;
+pub extern fn u128_jump_nz(a: u128) -> JumpNzResult::<u128> implicits() nopanic;
 
-#[derive(Copy, Drop)]
-struct u256 { low: u128, high: u128, }
+// Returns `None` if `b >= 128`.
+pub extern fn u128_shl(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic;
+pub extern fn u128_shr(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic;
 
-fn u256_overflow_add(a: u256, b: u256) -> (u256, bool) implicits(RangeCheck) nopanic {
-    let (high, overflow) = match u128_overflow_add(a.high, b.high) {
-        Result::Ok(high) => (high, false),
-        Result::Err(high) => (high, true),
-    };
-    match u128_overflow_add(a.low, b.low) {
-        Result::Ok(low) => (u256 { low, high }, overflow),
-        Result::Err(low) => {
-            match u128_overflow_add(high, 1_u128) {
-                Result::Ok(high) => (u256 { low, high }, overflow),
-                Result::Err(high) => (u256 { low, high }, true),
-            }
-        },
+pub impl U128Add of Add::<u128> {
+    fn add(a: u128, b: u128) -> u128 implicits(RangeCheck) {
+        u128_add(a, b)
+    }
+}
+pub impl U128Sub of Sub::<u128> {
+    fn sub(a: u128, b: u128) -> u128 implicits(RangeCheck) {
+        u128_sub(a, b)
+    }
+}
+pub impl U128Mul of Mul::<u128> {
+    fn mul(a: u128, b: u128) -> u128 implicits(RangeCheck) {
+        u128_mul(a, b)
+    }
+}
+pub impl U128Div of Div::<u128> {
+    fn div(a: u128, b: u128) -> u128 implicits(RangeCheck) {
+        u128_div(a, b)
+    }
+}
+pub impl U128Rem of Rem::<u128> {
+    fn rem(a: u128, b: u128) -> u128 implicits(RangeCheck) {
+        u128_mod(a, b)
+    }
+}
+pub impl U128PartialEq of PartialEq::<u128> {
+    fn eq(a: u128, b: u128) -> bool implicits() nopanic {
+        u128_eq(a, b)
+    }
+    fn ne(a: u128, b: u128) -> bool implicits() nopanic {
+        u128_ne(a, b)
+    }
+}
+pub impl U128PartialOrd of PartialOrd::<u128> {
+    fn le(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic {
+        u128_le(a, b)
+    }
+    fn ge(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic {
+        u128_ge(a, b)
+    }
+    fn lt(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic {
+        u128_lt(a, b)
+    }
+    fn gt(a: u128, b: u128) -> bool implicits(RangeCheck) nopanic {
+        u128_gt(a, b)
+    }
+}
+pub impl U128BitAnd of BitAnd::<u128> {
+    fn bitand(a: u128, b: u128) -> u128 implicits(Bitwise) nopanic {
+        u128_and(a, b)
+    }
+}
+pub impl U128BitOr of BitOr::<u128> {
+    fn bitor(a: u128, b: u128) -> u128 implicits(Bitwise) nopanic {
+        u128_or(a, b)
+    }
+}
+pub impl U128BitXor of BitXor::<u128> {
+    fn bitxor(a: u128, b: u128) -> u128 implicits(Bitwise) nopanic {
+        u128_xor(a, b)
+    }
+}
+pub impl U128Shl of Shl::<u128> {
+    fn shl(a: u128, b: u128) -> u128 implicits(RangeCheck) nopanic {
+        match u128_shl(a, b) {
+            Option::Some(x) => x,
+            // All the bits are shifted out.
+            Option::None(()) => 0_u128,
+        }
+    }
+}
+pub impl U128Shr of Shr::<u128> {
+    fn shr(a: u128, b: u128) -> u128 implicits(RangeCheck) nopanic {
+        match u128_shr(a, b) {
+            Option::Some(x) => x,
+            // All the bits are shifted out.
+            Option::None(()) => 0_u128,
+        }
     }
 }
 
-fn u256_overflow_sub(a: u256, b: u256) -> (u256, bool) implicits(RangeCheck) nopanic {
-    let (high, overflow) = match u128_overflow_sub(a.high, b.high) {
-        Result::Ok(high) => (high, false),
-        Result::Err(high) => (high, true),
-    };
-    match u128_overflow_sub(a.low, b.low) {
-        Result::Ok(low) => (u256 { low, high }, overflow),
-        Result::Err(low) => {
-            match u128_overflow_sub(high, 1_u128) {
-                Result::Ok(high) => (u256 { low, high }, overflow),
-                Result::Err(high) => (u256 { low, high }, true),
-            }
-        },
+pub trait U128Trait {
+    fn checked_add(self: u128, other: u128) -> Option::<u128> implicits(RangeCheck) nopanic;
+    fn checked_sub(self: u128, other: u128) -> Option::<u128> implicits(RangeCheck) nopanic;
+    fn checked_mul(self: u128, other: u128) -> Option::<u128> implicits(RangeCheck) nopanic;
+    fn wrapping_add(self: u128, other: u128) -> u128 implicits(RangeCheck) nopanic;
+    fn checked_as_non_zero(self: u128) -> Option::<NonZero::<u128>> implicits() nopanic;
+    fn to_felt(self: u128) -> felt nopanic;
+}
+pub impl U128Impl of U128Trait {
+    fn checked_add(self: u128, other: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
+        u128_checked_add(self, other)
+    }
+    fn checked_sub(self: u128, other: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
+        u128_checked_sub(self, other)
+    }
+    fn checked_mul(self: u128, other: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
+        u128_checked_mul(self, other)
+    }
+    fn wrapping_add(self: u128, other: u128) -> u128 implicits(RangeCheck) nopanic {
+        u128_wrapping_add(self, other)
+    }
+    fn checked_as_non_zero(self: u128) -> Option::<NonZero::<u128>> implicits() nopanic {
+        u128_checked_as_non_zero(self)
+    }
+    fn to_felt(self: u128) -> felt nopanic {
+        u128_to_felt(self)
     }
 }
 
-fn u256_overflow_mul(a: u256, b: u256) -> (u256, bool) nopanic {
-    let (high1, low) = u128_wide_mul(a.low, b.low);
-    let (overflow_value1, high2) = u128_wide_mul(a.low, b.high);
-    let (overflow_value2, high3) = u128_wide_mul(a.high, b.low);
-    let (high, overflow) = match u128_overflow_add(high1, high2) {
-        Result::Ok(high) => (
-            high,
-            overflow_value1 != 0_u128 | overflow_value2 != 0_u128 | (a.high > 0_u128 & b.high > 0_u128)
-        ),
-        Result::Err(high) => (high, true),
-    };
-    let (high, overflow) = match u128_overflow_add(high, high3) {
-        Result::Ok(high) => (high, overflow),
-        Result::Err(high) => (high, true),
-    };
-    (u256 { low, high }, overflow)
+pub extern type u8;
+pub impl U8Copy of Copy::<u8>;
+pub impl U8Drop of Drop::<u8>;
+pub extern fn u8_const<value>() -> u8 nopanic;
+#[deprecated(into)]
+pub extern fn u8_to_felt(a: u8) -> felt nopanic;
+
+#[deprecated(try_into)]
+#[panic_with('u8_from OF', u8_from_felt)]
+pub extern fn u8_try_from_felt(a: felt) -> Option::<u8> implicits(RangeCheck) nopanic;
+
+pub extern fn u8_lt(a: u8, b: u8) -> bool implicits(RangeCheck) nopanic;
+pub extern fn u8_eq(a: u8, b: u8) -> bool implicits() nopanic;
+pub extern fn u8_le(a: u8, b: u8) -> bool implicits(RangeCheck) nopanic;
+
+pub fn u8_gt(a: u8, b: u8) -> bool implicits(RangeCheck) nopanic {
+    u8_lt(b, a)
 }
 
-#[panic_with('u256_add OF', u256_add)]
-fn u256_checked_add(a: u256, b: u256) -> Option::<u256> implicits(RangeCheck) nopanic {
-    let (r, overflow) = u256_overflow_add(a, b);
-    if overflow {
-        Option::<u256>::None(())
-    } else {
-        Option::<u256>::Some(r)
+pub fn u8_ge(a: u8, b: u8) -> bool implicits(RangeCheck) nopanic {
+    u8_le(b, a)
+}
+
+pub fn u8_ne(a: u8, b: u8) -> bool implicits() nopanic {
+    !(a == b)
+}
+
+pub impl U8PartialEq of PartialEq::<u8> {
+    fn eq(a: u8, b: u8) -> bool implicits() nopanic {
+        u8_eq(a, b)
+    }
+    fn ne(a: u8, b: u8) -> bool implicits() nopanic {
+        u8_ne(a, b)
     }
 }
 
-#[panic_with('u256_sub OF', u256_sub)]
-fn u256_checked_sub(a: u256, b: u256) -> Option::<u256> implicits(RangeCheck) nopanic {
-    let (r, overflow) = u256_overflow_sub(a, b);
-    if overflow {
-        Option::<u256>::None(())
-    } else {
-        Option::<u256>::Some(r)
+pub impl U8PartialOrd of PartialOrd::<u8> {
+    fn le(a: u8, b: u8) -> bool implicits(RangeCheck) nopanic {
+        u8_le(a, b)
+    }
+    fn ge(a: u8, b: u8) -> bool implicits(RangeCheck) nopanic {
+        u8_ge(a, b)
+    }
+    fn lt(a: u8, b: u8) -> bool implicits(RangeCheck) nopanic {
+        u8_lt(a, b)
+    }
+    fn gt(a: u8, b: u8) -> bool implicits(RangeCheck) nopanic {
+        u8_gt(a, b)
     }
 }
 
-#[panic_with('u256_mul OF', u256_mul)]
-fn u256_checked_mul(a: u256, b: u256) -> Option::<u256> implicits(RangeCheck) nopanic {
-    let (r, overflow) = u256_overflow_mul(a, b);
-    if overflow {
-        Option::<u256>::None(())
-    } else {
-        Option::<u256>::Some(r)
+pub extern fn u8_overflow_add(a: u8, b: u8) -> Result::<u8, u8> implicits(RangeCheck) nopanic;
+pub extern fn u8_overflow_sub(a: u8, b: u8) -> Result::<u8, u8> implicits(RangeCheck) nopanic;
+
+pub fn u8_wrapping_add(a: u8, b: u8) -> u8 implicits(RangeCheck) nopanic {
+    match u8_overflow_add(a, b) {
+        Result::Ok(x) => x,
+        Result::Err(x) => x,
     }
 }
 
-fn u256_eq(a: u256, b: u256) -> bool implicits() {
-    a.low == b.low & a.high == b.high
+#[panic_with('u8_add OF', u8_add)]
+pub fn u8_checked_add(a: u8, b: u8) -> Option::<u8> implicits(RangeCheck) nopanic {
+    match u8_overflow_add(a, b) {
+        Result::Ok(r) => Option::<u8>::Some(r),
+        Result::Err(r) => Option::<u8>::None(()),
+    }
+}
+
+pub impl U8Add of Add::<u8> {
+    fn add(a: u8, b: u8) -> u8 implicits(RangeCheck) {
+        u8_add(a, b)
+    }
+}
+
+#[panic_with('u8_sub OF', u8_sub)]
+pub fn u8_checked_sub(a: u8, b: u8) -> Option::<u8> implicits(RangeCheck) nopanic {
+    match u8_overflow_sub(a, b) {
+        Result::Ok(r) => Option::<u8>::Some(r),
+        Result::Err(r) => Option::<u8>::None(()),
+    }
+}
+
+pub impl U8Sub of Sub::<u8> {
+    fn sub(a: u8, b: u8) -> u8 implicits(RangeCheck) {
+        u8_sub(a, b)
+    }
+}
+
+pub extern fn u8_wide_mul(a: u8, b: u8) -> u16 implicits() nopanic;
+
+#[panic_with('u8_mul OF', u8_mul)]
+pub fn u8_checked_mul(a: u8, b: u8) -> Option::<u8> implicits(RangeCheck) nopanic {
+    u8_try_from_felt(u16_to_felt(u8_wide_mul(a, b)))
+}
+
+pub impl U8Mul of Mul::<u8> {
+    fn mul(a: u8, b: u8) -> u8 implicits(RangeCheck) {
+        u8_mul(a, b)
+    }
+}
+
+pub impl NonZeroU8Copy of Copy::<NonZero::<u8>>;
+pub impl NonZeroU8Drop of Drop::<NonZero::<u8>>;
+
+pub extern fn u8_jump_nz(a: u8) -> JumpNzResult::<u8> implicits() nopanic;
+
+#[panic_with('u8 is 0', u8_as_non_zero)]
+pub fn u8_checked_as_non_zero(a: u8) -> Option::<NonZero::<u8>> implicits() nopanic {
+    match u8_jump_nz(a) {
+        JumpNzResult::Zero(()) => Option::<NonZero::<u8>>::None(()),
+        JumpNzResult::NonZero(x) => Option::<NonZero::<u8>>::Some(x),
+    }
+}
+
+pub extern fn u8_safe_divmod(
+    a: u8, b: NonZero::<u8>
+    ) -> (
+    u8, u8
+) implicits(RangeCheck) nopanic;
+
+pub fn u8_safe_div(a: u8, b: NonZero::<u8>) -> u8 implicits(RangeCheck) nopanic {
+    let (q, r) = u8_safe_divmod(a, b);
+    q
+}
+
+pub fn u8_div(a: u8, b: u8) -> u8 implicits(RangeCheck) {
+    u8_safe_div(a, u8_as_non_zero(b))
+}
+
+pub impl U8Div of Div::<u8> {
+    fn div(a: u8, b: u8) -> u8 implicits(RangeCheck) {
+        u8_div(a, b)
+    }
+}
+
+pub fn u8_safe_mod(a: u8, b: NonZero::<u8>) -> u8 implicits(RangeCheck) nopanic {
+    let (q, r) = u8_safe_divmod(a, b);
+    r
+}
+
+pub fn u8_mod(a: u8, b: u8) -> u8 implicits(RangeCheck) {
+    u8_safe_mod(a, u8_as_non_zero(b))
+}
+
+pub impl U8Rem of Rem::<u8> {
+    fn rem(a: u8, b: u8) -> u8 implicits(RangeCheck) {
+        u8_mod(a, b)
+    }
+}
+
+pub extern type u16;
+pub impl U16Copy of Copy::<u16>;
+pub impl U16Drop of Drop::<u16>;
+pub extern fn u16_const<value>() -> u16 nopanic;
+#[deprecated(into)]
+pub extern fn u16_to_felt(a: u16) -> felt nopanic;
+
+#[deprecated(try_into)]
+#[panic_with('u16_from OF', u16_from_felt)]
+pub extern fn u16_try_from_felt(a: felt) -> Option::<u16> implicits(RangeCheck) nopanic;
+
+pub extern fn u16_lt(a: u16, b: u16) -> bool implicits(RangeCheck) nopanic;
+pub extern fn u16_eq(a: u16, b: u16) -> bool implicits() nopanic;
+pub extern fn u16_le(a: u16, b: u16) -> bool implicits(RangeCheck) nopanic;
+
+pub fn u16_gt(a: u16, b: u16) -> bool implicits(RangeCheck) nopanic {
+    u16_lt(b, a)
+}
+
+pub fn u16_ge(a: u16, b: u16) -> bool implicits(RangeCheck) nopanic {
+    u16_le(b, a)
 }
 
-fn u256_ne(a: u256, b: u256) -> bool implicits() {
+pub fn u16_ne(a: u16, b: u16) -> bool implicits() nopanic {
     !(a == b)
 }
 
-fn u256_lt(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
-    if a.high < b.high {
-        true
-    } else if a.high == b.high {
-        a.low < b.low
-    } else {
-        false
+pub impl U16PartialEq of PartialEq::<u16> {
+    fn eq(a: u16, b: u16) -> bool implicits() nopanic {
+        u16_eq(a, b)
+    }
+    fn ne(a: u16, b: u16) -> bool implicits() nopanic {
+        u16_ne(a, b)
     }
 }
 
-fn u256_le(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
-    !u256_lt(b, a)
+pub impl U16PartialOrd of PartialOrd::<u16> {
+    fn le(a: u16, b: u16) -> bool implicits(RangeCheck) nopanic {
+        u16_le(a, b)
+    }
+    fn ge(a: u16, b: u16) -> bool implicits(RangeCheck) nopanic {
+        u16_ge(a, b)
+    }
+    fn lt(a: u16, b: u16) -> bool implicits(RangeCheck) nopanic {
+        u16_lt(a, b)
+    }
+    fn gt(a: u16, b: u16) -> bool implicits(RangeCheck) nopanic {
+        u16_gt(a, b)
+    }
 }
 
-fn u256_gt(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
-    u256_lt(b, a)
+pub extern fn u16_overflow_add(a: u16, b: u16) -> Result::<u16, u16> implicits(RangeCheck) nopanic;
+pub extern fn u16_overflow_sub(a: u16, b: u16) -> Result::<u16, u16> implicits(RangeCheck) nopanic;
+
+pub fn u16_wrapping_add(a: u16, b: u16) -> u16 implicits(RangeCheck) nopanic {
+    match u16_overflow_add(a, b) {
+        Result::Ok(x) => x,
+        Result::Err(x) => x,
+    }
 }
 
-fn u256_ge(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
-    !u256_lt(a, b)
+#[panic_with('u16_add OF', u16_add)]
+pub fn u16_checked_add(a: u16, b: u16) -> Option::<u16> implicits(RangeCheck) nopanic {
+    match u16_overflow_add(a, b) {
+        Result::Ok(r) => Option::<u16>::Some(r),
+        Result::Err(r) => Option::<u16>::None(()),
+    }
 }
 
-fn u256_and(a: u256, b: u256) -> u256 implicits(Bitwise) nopanic {
-    u256 { low: a.low & b.low, high: a.high & b.high }
+pub impl U16Add of Add::<u16> {
+    fn add(a: u16, b: u16) -> u16 implicits(RangeCheck) {
+        u16_add(a, b)
+    }
 }
-fn u256_or(a: u256, b: u256) -> u256 implicits(Bitwise) nopanic {
-    u256 { low: a.low | b.low, high: a.high | b.high }
+
+#[panic_with('u16_sub OF', u16_sub)]
+pub fn u16_checked_sub(a: u16, b: u16) -> Option::<u16> implicits(RangeCheck) nopanic {
+    match u16_overflow_sub(a, b) {
+        Result::Ok(r) => Option::<u16>::Some(r),
+        Result::Err(r) => Option::<u16>::None(()),
+    }
 }
-fn u256_xor(a: u256, b: u256) -> u256 implicits(Bitwise) nopanic {
-    u256 { low: a.low ^ b.low, high: a.high ^ b.high }
+
+pub impl U16Sub of Sub::<u16> {
+    fn sub(a: u16, b: u16) -> u16 implicits(RangeCheck) {
+        u16_sub(a, b)
+    }
 }
 
-fn u256_from_felt(a: felt) -> u256 implicits(RangeCheck) nopanic {
-    match u128s_from_felt(a) {
-        U128sFromFeltResult::Narrow(low) => u256 { low, high: 0_u128 },
-        U128sFromFeltResult::Wide((high, low)) => u256 { low, high },
+pub extern fn u16_wide_mul(a: u16, b: u16) -> u32 implicits() nopanic;
+
+#[panic_with('u16_mul OF', u16_mul)]
+pub fn u16_checked_mul(a: u16, b: u16) -> Option::<u16> implicits(RangeCheck) nopanic {
+    u16_try_from_felt(u32_to_felt(u16_wide_mul(a, b)))
+}
+
+pub impl U16Mul of Mul::<u16> {
+    fn mul(a: u16, b: u16) -> u16 implicits(RangeCheck) {
+        u16_mul(a, b)
+    }
+}
+
+pub impl NonZeroU16Copy of Copy::<NonZero::<u16>>;
+pub impl NonZeroU16Drop of Drop::<NonZero::<u16>>;
+
+pub extern fn u16_jump_nz(a: u16) -> JumpNzResult::<u16> implicits() nopanic;
+
+#[panic_with('u16 is 0', u16_as_non_zero)]
+pub fn u16_checked_as_non_zero(a: u16) -> Option::<NonZero::<u16>> implicits() nopanic {
+    match u16_jump_nz(a) {
+        JumpNzResult::Zero(()) => Option::<NonZero::<u16>>::None(()),
+        JumpNzResult::NonZero(x) => Option::<NonZero::<u16>>::Some(x),
+    }
+}
+
+pub extern fn u16_safe_divmod(
+    a: u16, b: NonZero::<u16>
+    ) -> (
+    u16, u16
+) implicits(RangeCheck) nopanic;
+
+pub fn u16_safe_div(a: u16, b: NonZero::<u16>) -> u16 implicits(RangeCheck) nopanic {
+    let (q, r) = u16_safe_divmod(a, b);
+    q
+}
+
+pub fn u16_div(a: u16, b: u16) -> u16 implicits(RangeCheck) {
+    u16_safe_div(a, u16_as_non_zero(b))
+}
+
+pub impl U16Div of Div::<u16> {
+    fn div(a: u16, b: u16) -> u16 implicits(RangeCheck) {
+        u16_div(a, b)
+    }
+}
+
+pub fn u16_safe_mod(a: u16, b: NonZero::<u16>) -> u16 implicits(RangeCheck) nopanic {
+    let (q, r) = u16_safe_divmod(a, b);
+    r
+}
+
+pub fn u16_mod(a: u16, b: u16) -> u16 implicits(RangeCheck) {
+    u16_safe_mod(a, u16_as_non_zero(b))
+}
+
+pub impl U16Rem of Rem::<u16> {
+    fn rem(a: u16, b: u16) -> u16 implicits(RangeCheck) {
+        u16_mod(a, b)
+    }
+}
+
+pub extern type u32;
+pub impl U32Copy of Copy::<u32>;
+pub impl U32Drop of Drop::<u32>;
+pub extern fn u32_const<value>() -> u32 nopanic;
+#[deprecated(into)]
+pub extern fn u32_to_felt(a: u32) -> felt nopanic;
+
+#[deprecated(try_into)]
+#[panic_with('u32_from OF', u32_from_felt)]
+pub extern fn u32_try_from_felt(a: felt) -> Option::<u32> implicits(RangeCheck) nopanic;
+
+pub extern fn u32_lt(a: u32, b: u32) -> bool implicits(RangeCheck) nopanic;
+pub extern fn u32_eq(a: u32, b: u32) -> bool implicits() nopanic;
+pub extern fn u32_le(a: u32, b: u32) -> bool implicits(RangeCheck) nopanic;
+
+pub fn u32_gt(a: u32, b: u32) -> bool implicits(RangeCheck) nopanic {
+    u32_lt(b, a)
+}
+
+pub fn u32_ge(a: u32, b: u32) -> bool implicits(RangeCheck) nopanic {
+    u32_le(b, a)
+}
+
+pub fn u32_ne(a: u32, b: u32) -> bool implicits() nopanic {
+    !(a == b)
+}
+
+pub impl U32PartialEq of PartialEq::<u32> {
+    fn eq(a: u32, b: u32) -> bool implicits() nopanic {
+        u32_eq(a, b)
+    }
+    fn ne(a: u32, b: u32) -> bool implicits() nopanic {
+        u32_ne(a, b)
+    }
+}
+
+pub impl U32PartialOrd of PartialOrd::<u32> {
+    fn le(a: u32, b: u32) -> bool implicits(RangeCheck) nopanic {
+        u32_le(a, b)
+    }
+    fn ge(a: u32, b: u32) -> bool implicits(RangeCheck) nopanic {
+        u32_ge(a, b)
+    }
+    fn lt(a: u32, b: u32) -> bool implicits(RangeCheck) nopanic {
+        u32_lt(a, b)
+    }
+    fn gt(a: u32, b: u32) -> bool implicits(RangeCheck) nopanic {
+        u32_gt(a, b)
+    }
+}
+
+pub extern fn u32_overflow_add(a: u32, b: u32) -> Result::<u32, u32> implicits(RangeCheck) nopanic;
+pub extern fn u32_overflow_sub(a: u32, b: u32) -> Result::<u32, u32> implicits(RangeCheck) nopanic;
+
+pub fn u32_wrapping_add(a: u32, b: u32) -> u32 implicits(RangeCheck) nopanic {
+    match u32_overflow_add(a, b) {
+        Result::Ok(x) => x,
+        Result::Err(x) => x,
+    }
+}
+
+#[panic_with('u32_add OF', u32_add)]
+pub fn u32_checked_add(a: u32, b: u32) -> Option::<u32> implicits(RangeCheck) nopanic {
+    match u32_overflow_add(a, b) {
+        Result::Ok(r) => Option::<u32>::Some(r),
+        Result::Err(r) => Option::<u32>::None(()),
+    }
+}
+
+pub impl U32Add of Add::<u32> {
+    fn add(a: u32, b: u32) -> u32 implicits(RangeCheck) {
+        u32_add(a, b)
+    }
+}
+
+#[panic_with('u32_sub OF', u32_sub)]
+pub fn u32_checked_sub(a: u32, b: u32) -> Option::<u32> implicits(RangeCheck) nopanic {
+    match u32_overflow_sub(a, b) {
+        Result::Ok(r) => Option::<u32>::Some(r),
+        Result::Err(r) => Option::<u32>::None(()),
+    }
+}
+
+pub impl U32Sub of Sub::<u32> {
+    fn sub(a: u32, b: u32) -> u32 implicits(RangeCheck) {
+        u32_sub(a, b)
+    }
+}
+
+pub extern fn u32_wide_mul(a: u32, b: u32) -> u64 implicits() nopanic;
+
+#[panic_with('u32_mul OF', u32_mul)]
+pub fn u32_checked_mul(a: u32, b: u32) -> Option::<u32> implicits(RangeCheck) nopanic {
+    u32_try_from_felt(u64_to_felt(u32_wide_mul(a, b)))
+}
+
+pub impl U32Mul of Mul::<u32> {
+    fn mul(a: u32, b: u32) -> u32 implicits(RangeCheck) {
+        u32_mul(a, b)
+    }
+}
+
+pub impl NonZeroU32Copy of Copy::<NonZero::<u32>>;
+pub impl NonZeroU32Drop of Drop::<NonZero::<u32>>;
+
+pub extern fn u32_jump_nz(a: u32) -> JumpNzResult::<u32> implicits() nopanic;
+
+#[panic_with('u32 is 0', u32_as_non_zero)]
+pub fn u32_checked_as_non_zero(a: u32) -> Option::<NonZero::<u32>> implicits() nopanic {
+    match u32_jump_nz(a) {
+        JumpNzResult::Zero(()) => Option::<NonZero::<u32>>::None(()),
+        JumpNzResult::NonZero(x) => Option::<NonZero::<u32>>::Some(x),
+    }
+}
+
+pub extern fn u32_safe_divmod(
+    a: u32, b: NonZero::<u32>
+    ) -> (
+    u32, u32
+) implicits(RangeCheck) nopanic;
+
+pub fn u32_safe_div(a: u32, b: NonZero::<u32>) -> u32 implicits(RangeCheck) nopanic {
+    let (q, r) = u32_safe_divmod(a, b);
+    q
+}
+
+pub fn u32_div(a: u32, b: u32) -> u32 implicits(RangeCheck) {
+    u32_safe_div(a, u32_as_non_zero(b))
+}
+
+pub impl U32Div of Div::<u32> {
+    fn div(a: u32, b: u32) -> u32 implicits(RangeCheck) {
+        u32_div(a, b)
+    }
+}
+
+pub fn u32_safe_mod(a: u32, b: NonZero::<u32>) -> u32 implicits(RangeCheck) nopanic {
+    let (q, r) = u32_safe_divmod(a, b);
+    r
+}
+
+pub fn u32_mod(a: u32, b: u32) -> u32 implicits(RangeCheck) {
+    u32_safe_mod(a, u32_as_non_zero(b))
+}
+
+pub impl U32Rem of Rem::<u32> {
+    fn rem(a: u32, b: u32) -> u32 implicits(RangeCheck) {
+        u32_mod(a, b)
+    }
+}
+
+pub extern type u64;
+pub impl U64Copy of Copy::<u64>;
+pub impl U64Drop of Drop::<u64>;
+pub extern fn u64_const<value>() -> u64 nopanic;
+#[deprecated(into)]
+pub extern fn u64_to_felt(a: u64) -> felt nopanic;
+
+#[deprecated(try_into)]
+#[panic_with('u64_from OF', u64_from_felt)]
+pub extern fn u64_try_from_felt(a: felt) -> Option::<u64> implicits(RangeCheck) nopanic;
+
+pub extern fn u64_lt(a: u64, b: u64) -> bool implicits(RangeCheck) nopanic;
+pub extern fn u64_eq(a: u64, b: u64) -> bool implicits() nopanic;
+pub extern fn u64_le(a: u64, b: u64) -> bool implicits(RangeCheck) nopanic;
+
+pub fn u64_gt(a: u64, b: u64) -> bool implicits(RangeCheck) nopanic {
+    u64_lt(b, a)
+}
+
+pub fn u64_ge(a: u64, b: u64) -> bool implicits(RangeCheck) nopanic {
+    u64_le(b, a)
+}
+
+pub fn u64_ne(a: u64, b: u64) -> bool implicits() nopanic {
+    !(a == b)
+}
+
+pub impl U64PartialEq of PartialEq::<u64> {
+    fn eq(a: u64, b: u64) -> bool implicits() nopanic {
+        u64_eq(a, b)
+    }
+    fn ne(a: u64, b: u64) -> bool implicits() nopanic {
+        u64_ne(a, b)
+    }
+}
+
+pub impl U64PartialOrd of PartialOrd::<u64> {
+    fn le(a: u64, b: u64) -> bool implicits(RangeCheck) nopanic {
+        u64_le(a, b)
+    }
+    fn ge(a: u64, b: u64) -> bool implicits(RangeCheck) nopanic {
+        u64_ge(a, b)
+    }
+    fn lt(a: u64, b: u64) -> bool implicits(RangeCheck) nopanic {
+        u64_lt(a, b)
+    }
+    fn gt(a: u64, b: u64) -> bool implicits(RangeCheck) nopanic {
+        u64_gt(a, b)
+    }
+}
+
+pub extern fn u64_overflow_add(a: u64, b: u64) -> Result::<u64, u64> implicits(RangeCheck) nopanic;
+pub extern fn u64_overflow_sub(a: u64, b: u64) -> Result::<u64, u64> implicits(RangeCheck) nopanic;
+
+pub fn u64_wrapping_add(a: u64, b: u64) -> u64 implicits(RangeCheck) nopanic {
+    match u64_overflow_add(a, b) {
+        Result::Ok(x) => x,
+        Result::Err(x) => x,
+    }
+}
+
+#[panic_with('u64_add OF', u64_add)]
+pub fn u64_checked_add(a: u64, b: u64) -> Option::<u64> implicits(RangeCheck) nopanic {
+    match u64_overflow_add(a, b) {
+        Result::Ok(r) => Option::<u64>::Some(r),
+        Result::Err(r) => Option::<u64>::None(()),
+    }
+}
+
+pub impl U64Add of Add::<u64> {
+    fn add(a: u64, b: u64) -> u64 implicits(RangeCheck) {
+        u64_add(a, b)
+    }
+}
+
+#[panic_with('u64_sub OF', u64_sub)]
+pub fn u64_checked_sub(a: u64, b: u64) -> Option::<u64> implicits(RangeCheck) nopanic {
+    match u64_overflow_sub(a, b) {
+        Result::Ok(r) => Option::<u64>::Some(r),
+        Result::Err(r) => Option::<u64>::None(()),
+    }
+}
+
+pub impl U64Sub of Sub::<u64> {
+    fn sub(a: u64, b: u64) -> u64 implicits(RangeCheck) {
+        u64_sub(a, b)
+    }
+}
+
+pub extern fn u64_wide_mul(a: u64, b: u64) -> u128 implicits() nopanic;
+
+#[panic_with('u64_mul OF', u64_mul)]
+pub fn u64_checked_mul(a: u64, b: u64) -> Option::<u64> implicits(RangeCheck) nopanic {
+    u64_try_from_felt(u128_to_felt(u64_wide_mul(a, b)))
+}
+
+pub impl U64Mul of Mul::<u64> {
+    fn mul(a: u64, b: u64) -> u64 implicits(RangeCheck) {
+        u64_mul(a, b)
+    }
+}
+
+pub impl NonZeroU64Copy of Copy::<NonZero::<u64>>;
+pub impl NonZeroU64Drop of Drop::<NonZero::<u64>>;
+
+pub extern fn u64_jump_nz(a: u64) -> JumpNzResult::<u64> implicits() nopanic;
+
+#[panic_with('u64 is 0', u64_as_non_zero)]
+pub fn u64_checked_as_non_zero(a: u64) -> Option::<NonZero::<u64>> implicits() nopanic {
+    match u64_jump_nz(a) {
+        JumpNzResult::Zero(()) => Option::<NonZero::<u64>>::None(()),
+        JumpNzResult::NonZero(x) => Option::<NonZero::<u64>>::Some(x),
+    }
+}
+
+pub extern fn u64_safe_divmod(
+    a: u64, b: NonZero::<u64>
+    ) -> (
+    u64, u64
+) implicits(RangeCheck) nopanic;
+
+pub fn u64_safe_div(a: u64, b: NonZero::<u64>) -> u64 implicits(RangeCheck) nopanic {
+    let (q, r) = u64_safe_divmod(a, b);
+    q
+}
+
+pub fn u64_div(a: u64, b: u64) -> u64 implicits(RangeCheck) {
+    u64_safe_div(a, u64_as_non_zero(b))
+}
+
+pub impl U64Div of Div::<u64> {
+    fn div(a: u64, b: u64) -> u64 implicits(RangeCheck) {
+        u64_div(a, b)
+    }
+}
+
+pub fn u64_safe_mod(a: u64, b: NonZero::<u64>) -> u64 implicits(RangeCheck) nopanic {
+    let (q, r) = u64_safe_divmod(a, b);
+    r
+}
+
+pub fn u64_mod(a: u64, b: u64) -> u64 implicits(RangeCheck) {
+    u64_safe_mod(a, u64_as_non_zero(b))
+}
+
+pub impl U64Rem of Rem::<u64> {
+    fn rem(a: u64, b: u64) -> u64 implicits(RangeCheck) {
+        u64_mod(a, b)
+    }
+}
+
+pub enum SignedIntegerResult<T> { InRange: T, Underflow: T, Overflow: T, }
+
+pub extern type i8;
+pub impl I8Copy of Copy::<i8>;
+pub impl I8Drop of Drop::<i8>;
+pub extern fn i8_const<value>() -> i8 nopanic;
+#[deprecated(into)]
+pub extern fn i8_to_felt(a: i8) -> felt nopanic;
+
+#[deprecated(try_into)]
+#[panic_with('i8_from OF', i8_from_felt)]
+pub extern fn i8_try_from_felt(a: felt) -> Option::<i8> implicits(RangeCheck) nopanic;
+
+pub extern fn i8_eq(a: i8, b: i8) -> bool implicits() nopanic;
+
+pub fn i8_ne(a: i8, b: i8) -> bool implicits() nopanic {
+    !(a == b)
+}
+
+// Returns `Ok(a - b)` if `a >= b`, and `Err(a - b + 2**n)` otherwise, where `n` is the bit size.
+pub extern fn i8_diff(a: i8, b: i8) -> Result::<u8, u8> implicits(RangeCheck) nopanic;
+
+pub fn i8_lt(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic {
+    match i8_diff(a, b) {
+        Result::Ok(d) => false,
+        Result::Err(d) => true,
+    }
+}
+
+pub fn i8_le(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic {
+    !i8_lt(b, a)
+}
+
+pub fn i8_gt(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic {
+    i8_lt(b, a)
+}
+
+pub fn i8_ge(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic {
+    i8_le(b, a)
+}
+
+pub impl I8PartialEq of PartialEq::<i8> {
+    fn eq(a: i8, b: i8) -> bool implicits() nopanic {
+        i8_eq(a, b)
+    }
+    fn ne(a: i8, b: i8) -> bool implicits() nopanic {
+        i8_ne(a, b)
+    }
+}
+
+pub impl I8PartialOrd of PartialOrd::<i8> {
+    fn le(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic {
+        i8_le(a, b)
+    }
+    fn ge(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic {
+        i8_ge(a, b)
+    }
+    fn lt(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic {
+        i8_lt(a, b)
+    }
+    fn gt(a: i8, b: i8) -> bool implicits(RangeCheck) nopanic {
+        i8_gt(a, b)
+    }
+}
+
+pub extern fn i8_overflow_add(
+    a: i8, b: i8
+) -> SignedIntegerResult::<i8> implicits(RangeCheck) nopanic;
+pub extern fn i8_overflow_sub(
+    a: i8, b: i8
+) -> SignedIntegerResult::<i8> implicits(RangeCheck) nopanic;
+
+pub fn i8_wrapping_add(a: i8, b: i8) -> i8 implicits(RangeCheck) nopanic {
+    match i8_overflow_add(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+pub fn i8_wrapping_sub(a: i8, b: i8) -> i8 implicits(RangeCheck) nopanic {
+    match i8_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+#[panic_with('i8_add OF', i8_add)]
+pub fn i8_checked_add(a: i8, b: i8) -> Option::<i8> implicits(RangeCheck) nopanic {
+    match i8_overflow_add(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i8>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i8>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i8>::None(()),
+    }
+}
+
+pub impl I8Add of Add::<i8> {
+    fn add(a: i8, b: i8) -> i8 implicits(RangeCheck) {
+        i8_add(a, b)
+    }
+}
+
+#[panic_with('i8_sub OF', i8_sub)]
+pub fn i8_checked_sub(a: i8, b: i8) -> Option::<i8> implicits(RangeCheck) nopanic {
+    match i8_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i8>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i8>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i8>::None(()),
+    }
+}
+
+pub impl I8Sub of Sub::<i8> {
+    fn sub(a: i8, b: i8) -> i8 implicits(RangeCheck) {
+        i8_sub(a, b)
+    }
+}
+
+// Returns whether `a` is negative, and its absolute value.
+fn i8_sign_and_magnitude(a: i8) -> (bool, u8) implicits(RangeCheck) nopanic {
+    match i8_diff(a, 0_i8) {
+        Result::Ok(magnitude) => (false, magnitude),
+        // `wrapped` is `a + 2**n`, so the magnitude is `2**n - wrapped`.
+        Result::Err(wrapped) => match u8_overflow_sub(0_u8, wrapped) {
+            Result::Ok(magnitude) => (true, magnitude),
+            Result::Err(magnitude) => (true, magnitude),
+        },
+    }
+}
+
+// Returns the value with the given sign and absolute value, if it is in range.
+fn i8_from_sign_and_magnitude(
+    is_negative: bool, magnitude: u8
+) -> Option::<i8> implicits(RangeCheck) nopanic {
+    if is_negative {
+        i8_try_from_felt(-u8_to_felt(magnitude))
+    } else {
+        i8_try_from_felt(u8_to_felt(magnitude))
+    }
+}
+
+pub extern fn i8_wide_mul(a: i8, b: i8) -> i16 implicits() nopanic;
+
+#[panic_with('i8_mul OF', i8_mul)]
+pub fn i8_checked_mul(a: i8, b: i8) -> Option::<i8> implicits(RangeCheck) nopanic {
+    i8_try_from_felt(i16_to_felt(i8_wide_mul(a, b)))
+}
+
+pub impl I8Mul of Mul::<i8> {
+    fn mul(a: i8, b: i8) -> i8 implicits(RangeCheck) {
+        i8_mul(a, b)
+    }
+}
+
+// Returns the quotient rounded towards zero and the remainder, which has the sign of `a`.
+// Returns `None` if `b` is zero or the quotient is out of range.
+pub fn i8_checked_divmod(a: i8, b: i8) -> Option::<(i8, i8)> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i8_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i8_sign_and_magnitude(b);
+    match u8_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u8_safe_divmod(a_magnitude, b_magnitude);
+            match i8_from_sign_and_magnitude(a_is_negative ^ b_is_negative, q_magnitude) {
+                Option::Some(q) => match i8_from_sign_and_magnitude(a_is_negative, r_magnitude) {
+                    Option::Some(r) => Option::<(i8, i8)>::Some((q, r)),
+                    Option::None(()) => Option::<(i8, i8)>::None(()),
+                },
+                Option::None(()) => Option::<(i8, i8)>::None(()),
+            }
+        },
+        Option::None(()) => Option::<(i8, i8)>::None(()),
+    }
+}
+
+#[panic_with('i8_div OF', i8_div)]
+pub fn i8_checked_div(a: i8, b: i8) -> Option::<i8> implicits(RangeCheck) nopanic {
+    match i8_checked_divmod(a, b) {
+        Option::Some(q_r) => {
+            let (q, r) = q_r;
+            Option::<i8>::Some(q)
+        },
+        Option::None(()) => Option::<i8>::None(()),
+    }
+}
+
+pub impl I8Div of Div::<i8> {
+    fn div(a: i8, b: i8) -> i8 implicits(RangeCheck) {
+        i8_div(a, b)
+    }
+}
+
+// The remainder always fits, even when the quotient overflows (e.g. `min % -1`).
+#[panic_with('i8_mod OF', i8_mod)]
+pub fn i8_checked_mod(a: i8, b: i8) -> Option::<i8> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i8_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i8_sign_and_magnitude(b);
+    match u8_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u8_safe_divmod(a_magnitude, b_magnitude);
+            i8_from_sign_and_magnitude(a_is_negative, r_magnitude)
+        },
+        Option::None(()) => Option::<i8>::None(()),
+    }
+}
+
+pub impl I8Rem of Rem::<i8> {
+    fn rem(a: i8, b: i8) -> i8 implicits(RangeCheck) {
+        i8_mod(a, b)
+    }
+}
+
+#[panic_with('i8_neg OF', i8_neg)]
+pub fn i8_checked_neg(a: i8) -> Option::<i8> implicits(RangeCheck) nopanic {
+    i8_try_from_felt(-i8_to_felt(a))
+}
+
+pub impl I8Neg of Neg::<i8> {
+    fn neg(a: i8) -> i8 implicits(RangeCheck) {
+        i8_neg(a)
+    }
+}
+
+pub extern type i16;
+pub impl I16Copy of Copy::<i16>;
+pub impl I16Drop of Drop::<i16>;
+pub extern fn i16_const<value>() -> i16 nopanic;
+#[deprecated(into)]
+pub extern fn i16_to_felt(a: i16) -> felt nopanic;
+
+#[deprecated(try_into)]
+#[panic_with('i16_from OF', i16_from_felt)]
+pub extern fn i16_try_from_felt(a: felt) -> Option::<i16> implicits(RangeCheck) nopanic;
+
+pub extern fn i16_eq(a: i16, b: i16) -> bool implicits() nopanic;
+
+pub fn i16_ne(a: i16, b: i16) -> bool implicits() nopanic {
+    !(a == b)
+}
+
+// Returns `Ok(a - b)` if `a >= b`, and `Err(a - b + 2**n)` otherwise, where `n` is the bit size.
+pub extern fn i16_diff(a: i16, b: i16) -> Result::<u16, u16> implicits(RangeCheck) nopanic;
+
+pub fn i16_lt(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic {
+    match i16_diff(a, b) {
+        Result::Ok(d) => false,
+        Result::Err(d) => true,
+    }
+}
+
+pub fn i16_le(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic {
+    !i16_lt(b, a)
+}
+
+pub fn i16_gt(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic {
+    i16_lt(b, a)
+}
+
+pub fn i16_ge(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic {
+    i16_le(b, a)
+}
+
+pub impl I16PartialEq of PartialEq::<i16> {
+    fn eq(a: i16, b: i16) -> bool implicits() nopanic {
+        i16_eq(a, b)
+    }
+    fn ne(a: i16, b: i16) -> bool implicits() nopanic {
+        i16_ne(a, b)
+    }
+}
+
+pub impl I16PartialOrd of PartialOrd::<i16> {
+    fn le(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic {
+        i16_le(a, b)
+    }
+    fn ge(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic {
+        i16_ge(a, b)
+    }
+    fn lt(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic {
+        i16_lt(a, b)
+    }
+    fn gt(a: i16, b: i16) -> bool implicits(RangeCheck) nopanic {
+        i16_gt(a, b)
+    }
+}
+
+pub extern fn i16_overflow_add(
+    a: i16, b: i16
+) -> SignedIntegerResult::<i16> implicits(RangeCheck) nopanic;
+pub extern fn i16_overflow_sub(
+    a: i16, b: i16
+) -> SignedIntegerResult::<i16> implicits(RangeCheck) nopanic;
+
+pub fn i16_wrapping_add(a: i16, b: i16) -> i16 implicits(RangeCheck) nopanic {
+    match i16_overflow_add(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+pub fn i16_wrapping_sub(a: i16, b: i16) -> i16 implicits(RangeCheck) nopanic {
+    match i16_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+#[panic_with('i16_add OF', i16_add)]
+pub fn i16_checked_add(a: i16, b: i16) -> Option::<i16> implicits(RangeCheck) nopanic {
+    match i16_overflow_add(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i16>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i16>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i16>::None(()),
+    }
+}
+
+pub impl I16Add of Add::<i16> {
+    fn add(a: i16, b: i16) -> i16 implicits(RangeCheck) {
+        i16_add(a, b)
+    }
+}
+
+#[panic_with('i16_sub OF', i16_sub)]
+pub fn i16_checked_sub(a: i16, b: i16) -> Option::<i16> implicits(RangeCheck) nopanic {
+    match i16_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i16>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i16>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i16>::None(()),
+    }
+}
+
+pub impl I16Sub of Sub::<i16> {
+    fn sub(a: i16, b: i16) -> i16 implicits(RangeCheck) {
+        i16_sub(a, b)
+    }
+}
+
+// Returns whether `a` is negative, and its absolute value.
+fn i16_sign_and_magnitude(a: i16) -> (bool, u16) implicits(RangeCheck) nopanic {
+    match i16_diff(a, 0_i16) {
+        Result::Ok(magnitude) => (false, magnitude),
+        // `wrapped` is `a + 2**n`, so the magnitude is `2**n - wrapped`.
+        Result::Err(wrapped) => match u16_overflow_sub(0_u16, wrapped) {
+            Result::Ok(magnitude) => (true, magnitude),
+            Result::Err(magnitude) => (true, magnitude),
+        },
+    }
+}
+
+// Returns the value with the given sign and absolute value, if it is in range.
+fn i16_from_sign_and_magnitude(
+    is_negative: bool, magnitude: u16
+) -> Option::<i16> implicits(RangeCheck) nopanic {
+    if is_negative {
+        i16_try_from_felt(-u16_to_felt(magnitude))
+    } else {
+        i16_try_from_felt(u16_to_felt(magnitude))
+    }
+}
+
+pub extern fn i16_wide_mul(a: i16, b: i16) -> i32 implicits() nopanic;
+
+#[panic_with('i16_mul OF', i16_mul)]
+pub fn i16_checked_mul(a: i16, b: i16) -> Option::<i16> implicits(RangeCheck) nopanic {
+    i16_try_from_felt(i32_to_felt(i16_wide_mul(a, b)))
+}
+
+pub impl I16Mul of Mul::<i16> {
+    fn mul(a: i16, b: i16) -> i16 implicits(RangeCheck) {
+        i16_mul(a, b)
+    }
+}
+
+// Returns the quotient rounded towards zero and the remainder, which has the sign of `a`.
+// Returns `None` if `b` is zero or the quotient is out of range.
+pub fn i16_checked_divmod(a: i16, b: i16) -> Option::<(i16, i16)> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i16_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i16_sign_and_magnitude(b);
+    match u16_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u16_safe_divmod(a_magnitude, b_magnitude);
+            match i16_from_sign_and_magnitude(a_is_negative ^ b_is_negative, q_magnitude) {
+                Option::Some(q) => match i16_from_sign_and_magnitude(a_is_negative, r_magnitude) {
+                    Option::Some(r) => Option::<(i16, i16)>::Some((q, r)),
+                    Option::None(()) => Option::<(i16, i16)>::None(()),
+                },
+                Option::None(()) => Option::<(i16, i16)>::None(()),
+            }
+        },
+        Option::None(()) => Option::<(i16, i16)>::None(()),
+    }
+}
+
+#[panic_with('i16_div OF', i16_div)]
+pub fn i16_checked_div(a: i16, b: i16) -> Option::<i16> implicits(RangeCheck) nopanic {
+    match i16_checked_divmod(a, b) {
+        Option::Some(q_r) => {
+            let (q, r) = q_r;
+            Option::<i16>::Some(q)
+        },
+        Option::None(()) => Option::<i16>::None(()),
+    }
+}
+
+pub impl I16Div of Div::<i16> {
+    fn div(a: i16, b: i16) -> i16 implicits(RangeCheck) {
+        i16_div(a, b)
+    }
+}
+
+// The remainder always fits, even when the quotient overflows (e.g. `min % -1`).
+#[panic_with('i16_mod OF', i16_mod)]
+pub fn i16_checked_mod(a: i16, b: i16) -> Option::<i16> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i16_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i16_sign_and_magnitude(b);
+    match u16_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u16_safe_divmod(a_magnitude, b_magnitude);
+            i16_from_sign_and_magnitude(a_is_negative, r_magnitude)
+        },
+        Option::None(()) => Option::<i16>::None(()),
+    }
+}
+
+pub impl I16Rem of Rem::<i16> {
+    fn rem(a: i16, b: i16) -> i16 implicits(RangeCheck) {
+        i16_mod(a, b)
+    }
+}
+
+#[panic_with('i16_neg OF', i16_neg)]
+pub fn i16_checked_neg(a: i16) -> Option::<i16> implicits(RangeCheck) nopanic {
+    i16_try_from_felt(-i16_to_felt(a))
+}
+
+pub impl I16Neg of Neg::<i16> {
+    fn neg(a: i16) -> i16 implicits(RangeCheck) {
+        i16_neg(a)
+    }
+}
+
+pub extern type i32;
+pub impl I32Copy of Copy::<i32>;
+pub impl I32Drop of Drop::<i32>;
+pub extern fn i32_const<value>() -> i32 nopanic;
+#[deprecated(into)]
+pub extern fn i32_to_felt(a: i32) -> felt nopanic;
+
+#[deprecated(try_into)]
+#[panic_with('i32_from OF', i32_from_felt)]
+pub extern fn i32_try_from_felt(a: felt) -> Option::<i32> implicits(RangeCheck) nopanic;
+
+pub extern fn i32_eq(a: i32, b: i32) -> bool implicits() nopanic;
+
+pub fn i32_ne(a: i32, b: i32) -> bool implicits() nopanic {
+    !(a == b)
+}
+
+// Returns `Ok(a - b)` if `a >= b`, and `Err(a - b + 2**n)` otherwise, where `n` is the bit size.
+pub extern fn i32_diff(a: i32, b: i32) -> Result::<u32, u32> implicits(RangeCheck) nopanic;
+
+pub fn i32_lt(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic {
+    match i32_diff(a, b) {
+        Result::Ok(d) => false,
+        Result::Err(d) => true,
+    }
+}
+
+pub fn i32_le(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic {
+    !i32_lt(b, a)
+}
+
+pub fn i32_gt(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic {
+    i32_lt(b, a)
+}
+
+pub fn i32_ge(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic {
+    i32_le(b, a)
+}
+
+pub impl I32PartialEq of PartialEq::<i32> {
+    fn eq(a: i32, b: i32) -> bool implicits() nopanic {
+        i32_eq(a, b)
+    }
+    fn ne(a: i32, b: i32) -> bool implicits() nopanic {
+        i32_ne(a, b)
+    }
+}
+
+pub impl I32PartialOrd of PartialOrd::<i32> {
+    fn le(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic {
+        i32_le(a, b)
+    }
+    fn ge(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic {
+        i32_ge(a, b)
+    }
+    fn lt(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic {
+        i32_lt(a, b)
+    }
+    fn gt(a: i32, b: i32) -> bool implicits(RangeCheck) nopanic {
+        i32_gt(a, b)
+    }
+}
+
+pub extern fn i32_overflow_add(
+    a: i32, b: i32
+) -> SignedIntegerResult::<i32> implicits(RangeCheck) nopanic;
+pub extern fn i32_overflow_sub(
+    a: i32, b: i32
+) -> SignedIntegerResult::<i32> implicits(RangeCheck) nopanic;
+
+pub fn i32_wrapping_add(a: i32, b: i32) -> i32 implicits(RangeCheck) nopanic {
+    match i32_overflow_add(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+pub fn i32_wrapping_sub(a: i32, b: i32) -> i32 implicits(RangeCheck) nopanic {
+    match i32_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+#[panic_with('i32_add OF', i32_add)]
+pub fn i32_checked_add(a: i32, b: i32) -> Option::<i32> implicits(RangeCheck) nopanic {
+    match i32_overflow_add(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i32>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i32>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i32>::None(()),
+    }
+}
+
+pub impl I32Add of Add::<i32> {
+    fn add(a: i32, b: i32) -> i32 implicits(RangeCheck) {
+        i32_add(a, b)
+    }
+}
+
+#[panic_with('i32_sub OF', i32_sub)]
+pub fn i32_checked_sub(a: i32, b: i32) -> Option::<i32> implicits(RangeCheck) nopanic {
+    match i32_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i32>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i32>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i32>::None(()),
+    }
+}
+
+pub impl I32Sub of Sub::<i32> {
+    fn sub(a: i32, b: i32) -> i32 implicits(RangeCheck) {
+        i32_sub(a, b)
+    }
+}
+
+// Returns whether `a` is negative, and its absolute value.
+fn i32_sign_and_magnitude(a: i32) -> (bool, u32) implicits(RangeCheck) nopanic {
+    match i32_diff(a, 0_i32) {
+        Result::Ok(magnitude) => (false, magnitude),
+        // `wrapped` is `a + 2**n`, so the magnitude is `2**n - wrapped`.
+        Result::Err(wrapped) => match u32_overflow_sub(0_u32, wrapped) {
+            Result::Ok(magnitude) => (true, magnitude),
+            Result::Err(magnitude) => (true, magnitude),
+        },
+    }
+}
+
+// Returns the value with the given sign and absolute value, if it is in range.
+fn i32_from_sign_and_magnitude(
+    is_negative: bool, magnitude: u32
+) -> Option::<i32> implicits(RangeCheck) nopanic {
+    if is_negative {
+        i32_try_from_felt(-u32_to_felt(magnitude))
+    } else {
+        i32_try_from_felt(u32_to_felt(magnitude))
+    }
+}
+
+pub extern fn i32_wide_mul(a: i32, b: i32) -> i64 implicits() nopanic;
+
+#[panic_with('i32_mul OF', i32_mul)]
+pub fn i32_checked_mul(a: i32, b: i32) -> Option::<i32> implicits(RangeCheck) nopanic {
+    i32_try_from_felt(i64_to_felt(i32_wide_mul(a, b)))
+}
+
+pub impl I32Mul of Mul::<i32> {
+    fn mul(a: i32, b: i32) -> i32 implicits(RangeCheck) {
+        i32_mul(a, b)
+    }
+}
+
+// Returns the quotient rounded towards zero and the remainder, which has the sign of `a`.
+// Returns `None` if `b` is zero or the quotient is out of range.
+pub fn i32_checked_divmod(a: i32, b: i32) -> Option::<(i32, i32)> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i32_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i32_sign_and_magnitude(b);
+    match u32_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u32_safe_divmod(a_magnitude, b_magnitude);
+            match i32_from_sign_and_magnitude(a_is_negative ^ b_is_negative, q_magnitude) {
+                Option::Some(q) => match i32_from_sign_and_magnitude(a_is_negative, r_magnitude) {
+                    Option::Some(r) => Option::<(i32, i32)>::Some((q, r)),
+                    Option::None(()) => Option::<(i32, i32)>::None(()),
+                },
+                Option::None(()) => Option::<(i32, i32)>::None(()),
+            }
+        },
+        Option::None(()) => Option::<(i32, i32)>::None(()),
+    }
+}
+
+#[panic_with('i32_div OF', i32_div)]
+pub fn i32_checked_div(a: i32, b: i32) -> Option::<i32> implicits(RangeCheck) nopanic {
+    match i32_checked_divmod(a, b) {
+        Option::Some(q_r) => {
+            let (q, r) = q_r;
+            Option::<i32>::Some(q)
+        },
+        Option::None(()) => Option::<i32>::None(()),
+    }
+}
+
+pub impl I32Div of Div::<i32> {
+    fn div(a: i32, b: i32) -> i32 implicits(RangeCheck) {
+        i32_div(a, b)
+    }
+}
+
+// The remainder always fits, even when the quotient overflows (e.g. `min % -1`).
+#[panic_with('i32_mod OF', i32_mod)]
+pub fn i32_checked_mod(a: i32, b: i32) -> Option::<i32> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i32_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i32_sign_and_magnitude(b);
+    match u32_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u32_safe_divmod(a_magnitude, b_magnitude);
+            i32_from_sign_and_magnitude(a_is_negative, r_magnitude)
+        },
+        Option::None(()) => Option::<i32>::None(()),
+    }
+}
+
+pub impl I32Rem of Rem::<i32> {
+    fn rem(a: i32, b: i32) -> i32 implicits(RangeCheck) {
+        i32_mod(a, b)
+    }
+}
+
+#[panic_with('i32_neg OF', i32_neg)]
+pub fn i32_checked_neg(a: i32) -> Option::<i32> implicits(RangeCheck) nopanic {
+    i32_try_from_felt(-i32_to_felt(a))
+}
+
+pub impl I32Neg of Neg::<i32> {
+    fn neg(a: i32) -> i32 implicits(RangeCheck) {
+        i32_neg(a)
+    }
+}
+
+pub extern type i64;
+pub impl I64Copy of Copy::<i64>;
+pub impl I64Drop of Drop::<i64>;
+pub extern fn i64_const<value>() -> i64 nopanic;
+#[deprecated(into)]
+pub extern fn i64_to_felt(a: i64) -> felt nopanic;
+
+#[deprecated(try_into)]
+#[panic_with('i64_from OF', i64_from_felt)]
+pub extern fn i64_try_from_felt(a: felt) -> Option::<i64> implicits(RangeCheck) nopanic;
+
+pub extern fn i64_eq(a: i64, b: i64) -> bool implicits() nopanic;
+
+pub fn i64_ne(a: i64, b: i64) -> bool implicits() nopanic {
+    !(a == b)
+}
+
+// Returns `Ok(a - b)` if `a >= b`, and `Err(a - b + 2**n)` otherwise, where `n` is the bit size.
+pub extern fn i64_diff(a: i64, b: i64) -> Result::<u64, u64> implicits(RangeCheck) nopanic;
+
+pub fn i64_lt(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic {
+    match i64_diff(a, b) {
+        Result::Ok(d) => false,
+        Result::Err(d) => true,
+    }
+}
+
+pub fn i64_le(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic {
+    !i64_lt(b, a)
+}
+
+pub fn i64_gt(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic {
+    i64_lt(b, a)
+}
+
+pub fn i64_ge(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic {
+    i64_le(b, a)
+}
+
+pub impl I64PartialEq of PartialEq::<i64> {
+    fn eq(a: i64, b: i64) -> bool implicits() nopanic {
+        i64_eq(a, b)
+    }
+    fn ne(a: i64, b: i64) -> bool implicits() nopanic {
+        i64_ne(a, b)
+    }
+}
+
+pub impl I64PartialOrd of PartialOrd::<i64> {
+    fn le(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic {
+        i64_le(a, b)
+    }
+    fn ge(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic {
+        i64_ge(a, b)
+    }
+    fn lt(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic {
+        i64_lt(a, b)
+    }
+    fn gt(a: i64, b: i64) -> bool implicits(RangeCheck) nopanic {
+        i64_gt(a, b)
+    }
+}
+
+pub extern fn i64_overflow_add(
+    a: i64, b: i64
+) -> SignedIntegerResult::<i64> implicits(RangeCheck) nopanic;
+pub extern fn i64_overflow_sub(
+    a: i64, b: i64
+) -> SignedIntegerResult::<i64> implicits(RangeCheck) nopanic;
+
+pub fn i64_wrapping_add(a: i64, b: i64) -> i64 implicits(RangeCheck) nopanic {
+    match i64_overflow_add(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+pub fn i64_wrapping_sub(a: i64, b: i64) -> i64 implicits(RangeCheck) nopanic {
+    match i64_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+#[panic_with('i64_add OF', i64_add)]
+pub fn i64_checked_add(a: i64, b: i64) -> Option::<i64> implicits(RangeCheck) nopanic {
+    match i64_overflow_add(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i64>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i64>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i64>::None(()),
+    }
+}
+
+pub impl I64Add of Add::<i64> {
+    fn add(a: i64, b: i64) -> i64 implicits(RangeCheck) {
+        i64_add(a, b)
+    }
+}
+
+#[panic_with('i64_sub OF', i64_sub)]
+pub fn i64_checked_sub(a: i64, b: i64) -> Option::<i64> implicits(RangeCheck) nopanic {
+    match i64_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i64>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i64>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i64>::None(()),
+    }
+}
+
+pub impl I64Sub of Sub::<i64> {
+    fn sub(a: i64, b: i64) -> i64 implicits(RangeCheck) {
+        i64_sub(a, b)
+    }
+}
+
+// Returns whether `a` is negative, and its absolute value.
+fn i64_sign_and_magnitude(a: i64) -> (bool, u64) implicits(RangeCheck) nopanic {
+    match i64_diff(a, 0_i64) {
+        Result::Ok(magnitude) => (false, magnitude),
+        // `wrapped` is `a + 2**n`, so the magnitude is `2**n - wrapped`.
+        Result::Err(wrapped) => match u64_overflow_sub(0_u64, wrapped) {
+            Result::Ok(magnitude) => (true, magnitude),
+            Result::Err(magnitude) => (true, magnitude),
+        },
+    }
+}
+
+// Returns the value with the given sign and absolute value, if it is in range.
+fn i64_from_sign_and_magnitude(
+    is_negative: bool, magnitude: u64
+) -> Option::<i64> implicits(RangeCheck) nopanic {
+    if is_negative {
+        i64_try_from_felt(-u64_to_felt(magnitude))
+    } else {
+        i64_try_from_felt(u64_to_felt(magnitude))
+    }
+}
+
+pub extern fn i64_wide_mul(a: i64, b: i64) -> i128 implicits() nopanic;
+
+#[panic_with('i64_mul OF', i64_mul)]
+pub fn i64_checked_mul(a: i64, b: i64) -> Option::<i64> implicits(RangeCheck) nopanic {
+    i64_try_from_felt(i128_to_felt(i64_wide_mul(a, b)))
+}
+
+pub impl I64Mul of Mul::<i64> {
+    fn mul(a: i64, b: i64) -> i64 implicits(RangeCheck) {
+        i64_mul(a, b)
+    }
+}
+
+// Returns the quotient rounded towards zero and the remainder, which has the sign of `a`.
+// Returns `None` if `b` is zero or the quotient is out of range.
+pub fn i64_checked_divmod(a: i64, b: i64) -> Option::<(i64, i64)> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i64_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i64_sign_and_magnitude(b);
+    match u64_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u64_safe_divmod(a_magnitude, b_magnitude);
+            match i64_from_sign_and_magnitude(a_is_negative ^ b_is_negative, q_magnitude) {
+                Option::Some(q) => match i64_from_sign_and_magnitude(a_is_negative, r_magnitude) {
+                    Option::Some(r) => Option::<(i64, i64)>::Some((q, r)),
+                    Option::None(()) => Option::<(i64, i64)>::None(()),
+                },
+                Option::None(()) => Option::<(i64, i64)>::None(()),
+            }
+        },
+        Option::None(()) => Option::<(i64, i64)>::None(()),
+    }
+}
+
+#[panic_with('i64_div OF', i64_div)]
+pub fn i64_checked_div(a: i64, b: i64) -> Option::<i64> implicits(RangeCheck) nopanic {
+    match i64_checked_divmod(a, b) {
+        Option::Some(q_r) => {
+            let (q, r) = q_r;
+            Option::<i64>::Some(q)
+        },
+        Option::None(()) => Option::<i64>::None(()),
+    }
+}
+
+pub impl I64Div of Div::<i64> {
+    fn div(a: i64, b: i64) -> i64 implicits(RangeCheck) {
+        i64_div(a, b)
+    }
+}
+
+// The remainder always fits, even when the quotient overflows (e.g. `min % -1`).
+#[panic_with('i64_mod OF', i64_mod)]
+pub fn i64_checked_mod(a: i64, b: i64) -> Option::<i64> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i64_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i64_sign_and_magnitude(b);
+    match u64_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u64_safe_divmod(a_magnitude, b_magnitude);
+            i64_from_sign_and_magnitude(a_is_negative, r_magnitude)
+        },
+        Option::None(()) => Option::<i64>::None(()),
+    }
+}
+
+pub impl I64Rem of Rem::<i64> {
+    fn rem(a: i64, b: i64) -> i64 implicits(RangeCheck) {
+        i64_mod(a, b)
+    }
+}
+
+#[panic_with('i64_neg OF', i64_neg)]
+pub fn i64_checked_neg(a: i64) -> Option::<i64> implicits(RangeCheck) nopanic {
+    i64_try_from_felt(-i64_to_felt(a))
+}
+
+pub impl I64Neg of Neg::<i64> {
+    fn neg(a: i64) -> i64 implicits(RangeCheck) {
+        i64_neg(a)
+    }
+}
+
+pub extern type i128;
+pub impl I128Copy of Copy::<i128>;
+pub impl I128Drop of Drop::<i128>;
+pub extern fn i128_const<value>() -> i128 nopanic;
+#[deprecated(into)]
+pub extern fn i128_to_felt(a: i128) -> felt nopanic;
+
+#[deprecated(try_into)]
+#[panic_with('i128_from OF', i128_from_felt)]
+pub extern fn i128_try_from_felt(a: felt) -> Option::<i128> implicits(RangeCheck) nopanic;
+
+pub extern fn i128_eq(a: i128, b: i128) -> bool implicits() nopanic;
+
+pub fn i128_ne(a: i128, b: i128) -> bool implicits() nopanic {
+    !(a == b)
+}
+
+// Returns `Ok(a - b)` if `a >= b`, and `Err(a - b + 2**n)` otherwise, where `n` is the bit size.
+pub extern fn i128_diff(a: i128, b: i128) -> Result::<u128, u128> implicits(RangeCheck) nopanic;
+
+pub fn i128_lt(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic {
+    match i128_diff(a, b) {
+        Result::Ok(d) => false,
+        Result::Err(d) => true,
+    }
+}
+
+pub fn i128_le(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic {
+    !i128_lt(b, a)
+}
+
+pub fn i128_gt(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic {
+    i128_lt(b, a)
+}
+
+pub fn i128_ge(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic {
+    i128_le(b, a)
+}
+
+pub impl I128PartialEq of PartialEq::<i128> {
+    fn eq(a: i128, b: i128) -> bool implicits() nopanic {
+        i128_eq(a, b)
+    }
+    fn ne(a: i128, b: i128) -> bool implicits() nopanic {
+        i128_ne(a, b)
+    }
+}
+
+pub impl I128PartialOrd of PartialOrd::<i128> {
+    fn le(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic {
+        i128_le(a, b)
+    }
+    fn ge(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic {
+        i128_ge(a, b)
+    }
+    fn lt(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic {
+        i128_lt(a, b)
+    }
+    fn gt(a: i128, b: i128) -> bool implicits(RangeCheck) nopanic {
+        i128_gt(a, b)
+    }
+}
+
+pub extern fn i128_overflow_add(
+    a: i128, b: i128
+) -> SignedIntegerResult::<i128> implicits(RangeCheck) nopanic;
+pub extern fn i128_overflow_sub(
+    a: i128, b: i128
+) -> SignedIntegerResult::<i128> implicits(RangeCheck) nopanic;
+
+pub fn i128_wrapping_add(a: i128, b: i128) -> i128 implicits(RangeCheck) nopanic {
+    match i128_overflow_add(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+pub fn i128_wrapping_sub(a: i128, b: i128) -> i128 implicits(RangeCheck) nopanic {
+    match i128_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(x) => x,
+        SignedIntegerResult::Underflow(x) => x,
+        SignedIntegerResult::Overflow(x) => x,
+    }
+}
+
+#[panic_with('i128_add OF', i128_add)]
+pub fn i128_checked_add(a: i128, b: i128) -> Option::<i128> implicits(RangeCheck) nopanic {
+    match i128_overflow_add(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i128>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i128>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i128>::None(()),
+    }
+}
+
+pub impl I128Add of Add::<i128> {
+    fn add(a: i128, b: i128) -> i128 implicits(RangeCheck) {
+        i128_add(a, b)
+    }
+}
+
+#[panic_with('i128_sub OF', i128_sub)]
+pub fn i128_checked_sub(a: i128, b: i128) -> Option::<i128> implicits(RangeCheck) nopanic {
+    match i128_overflow_sub(a, b) {
+        SignedIntegerResult::InRange(r) => Option::<i128>::Some(r),
+        SignedIntegerResult::Underflow(r) => Option::<i128>::None(()),
+        SignedIntegerResult::Overflow(r) => Option::<i128>::None(()),
+    }
+}
+
+pub impl I128Sub of Sub::<i128> {
+    fn sub(a: i128, b: i128) -> i128 implicits(RangeCheck) {
+        i128_sub(a, b)
+    }
+}
+
+// Returns whether `a` is negative, and its absolute value.
+fn i128_sign_and_magnitude(a: i128) -> (bool, u128) implicits(RangeCheck) nopanic {
+    match i128_diff(a, 0_i128) {
+        Result::Ok(magnitude) => (false, magnitude),
+        // `wrapped` is `a + 2**n`, so the magnitude is `2**n - wrapped`.
+        Result::Err(wrapped) => match u128_overflow_sub(0_u128, wrapped) {
+            Result::Ok(magnitude) => (true, magnitude),
+            Result::Err(magnitude) => (true, magnitude),
+        },
+    }
+}
+
+// Returns the value with the given sign and absolute value, if it is in range.
+fn i128_from_sign_and_magnitude(
+    is_negative: bool, magnitude: u128
+) -> Option::<i128> implicits(RangeCheck) nopanic {
+    if is_negative {
+        i128_try_from_felt(-u128_to_felt(magnitude))
+    } else {
+        i128_try_from_felt(u128_to_felt(magnitude))
+    }
+}
+
+#[panic_with('i128_mul OF', i128_mul)]
+pub fn i128_checked_mul(a: i128, b: i128) -> Option::<i128> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i128_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i128_sign_and_magnitude(b);
+    match u128_checked_mul(a_magnitude, b_magnitude) {
+        Option::Some(magnitude) => i128_from_sign_and_magnitude(
+            a_is_negative ^ b_is_negative, magnitude
+        ),
+        Option::None(()) => Option::<i128>::None(()),
+    }
+}
+
+pub impl I128Mul of Mul::<i128> {
+    fn mul(a: i128, b: i128) -> i128 implicits(RangeCheck) {
+        i128_mul(a, b)
+    }
+}
+
+// Returns the quotient rounded towards zero and the remainder, which has the sign of `a`.
+// Returns `None` if `b` is zero or the quotient is out of range.
+pub fn i128_checked_divmod(
+    a: i128, b: i128
+) -> Option::<(i128, i128)> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i128_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i128_sign_and_magnitude(b);
+    match u128_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u128_safe_divmod(a_magnitude, b_magnitude);
+            match i128_from_sign_and_magnitude(a_is_negative ^ b_is_negative, q_magnitude) {
+                Option::Some(q) => match i128_from_sign_and_magnitude(a_is_negative, r_magnitude) {
+                    Option::Some(r) => Option::<(i128, i128)>::Some((q, r)),
+                    Option::None(()) => Option::<(i128, i128)>::None(()),
+                },
+                Option::None(()) => Option::<(i128, i128)>::None(()),
+            }
+        },
+        Option::None(()) => Option::<(i128, i128)>::None(()),
+    }
+}
+
+#[panic_with('i128_div OF', i128_div)]
+pub fn i128_checked_div(a: i128, b: i128) -> Option::<i128> implicits(RangeCheck) nopanic {
+    match i128_checked_divmod(a, b) {
+        Option::Some(q_r) => {
+            let (q, r) = q_r;
+            Option::<i128>::Some(q)
+        },
+        Option::None(()) => Option::<i128>::None(()),
+    }
+}
+
+pub impl I128Div of Div::<i128> {
+    fn div(a: i128, b: i128) -> i128 implicits(RangeCheck) {
+        i128_div(a, b)
+    }
+}
+
+// The remainder always fits, even when the quotient overflows (e.g. `min % -1`).
+#[panic_with('i128_mod OF', i128_mod)]
+pub fn i128_checked_mod(a: i128, b: i128) -> Option::<i128> implicits(RangeCheck) nopanic {
+    let (a_is_negative, a_magnitude) = i128_sign_and_magnitude(a);
+    let (b_is_negative, b_magnitude) = i128_sign_and_magnitude(b);
+    match u128_checked_as_non_zero(b_magnitude) {
+        Option::Some(b_magnitude) => {
+            let (q_magnitude, r_magnitude) = u128_safe_divmod(a_magnitude, b_magnitude);
+            i128_from_sign_and_magnitude(a_is_negative, r_magnitude)
+        },
+        Option::None(()) => Option::<i128>::None(()),
+    }
+}
+
+pub impl I128Rem of Rem::<i128> {
+    fn rem(a: i128, b: i128) -> i128 implicits(RangeCheck) {
+        i128_mod(a, b)
+    }
+}
+
+#[panic_with('i128_neg OF', i128_neg)]
+pub fn i128_checked_neg(a: i128) -> Option::<i128> implicits(RangeCheck) nopanic {
+    i128_try_from_felt(-i128_to_felt(a))
+}
+
+pub impl I128Neg of Neg::<i128> {
+    fn neg(a: i128) -> i128 implicits(RangeCheck) {
+        i128_neg(a)
+    }
+}
+
+#[derive(Copy, Drop)]
+pub struct u256 { pub low: u128, pub high: u128, }
+
+pub fn u256_overflow_add(a: u256, b: u256) -> (u256, bool) implicits(RangeCheck) nopanic {
+    let (high, overflow) = match u128_overflow_add(a.high, b.high) {
+        Result::Ok(high) => (high, false),
+        Result::Err(high) => (high, true),
+    };
+    match u128_overflow_add(a.low, b.low) {
+        Result::Ok(low) => (u256 { low, high }, overflow),
+        Result::Err(low) => {
+            match u128_overflow_add(high, 1_u128) {
+                Result::Ok(high) => (u256 { low, high }, overflow),
+                Result::Err(high) => (u256 { low, high }, true),
+            }
+        },
+    }
+}
+
+pub fn u256_overflow_sub(a: u256, b: u256) -> (u256, bool) implicits(RangeCheck) nopanic {
+    let (high, overflow) = match u128_overflow_sub(a.high, b.high) {
+        Result::Ok(high) => (high, false),
+        Result::Err(high) => (high, true),
+    };
+    match u128_overflow_sub(a.low, b.low) {
+        Result::Ok(low) => (u256 { low, high }, overflow),
+        Result::Err(low) => {
+            match u128_overflow_sub(high, 1_u128) {
+                Result::Ok(high) => (u256 { low, high }, overflow),
+                Result::Err(high) => (u256 { low, high }, true),
+            }
+        },
+    }
+}
+
+pub fn u256_overflow_mul(a: u256, b: u256) -> (u256, bool) nopanic {
+    let (high1, low) = u128_wide_mul(a.low, b.low);
+    let (overflow_value1, high2) = u128_wide_mul(a.low, b.high);
+    let (overflow_value2, high3) = u128_wide_mul(a.high, b.low);
+    let (high, overflow) = match u128_overflow_add(high1, high2) {
+        Result::Ok(high) => (
+            high,
+            overflow_value1 != 0_u128 | overflow_value2 != 0_u128 | (a.high > 0_u128 & b.high > 0_u128)
+        ),
+        Result::Err(high) => (high, true),
+    };
+    let (high, overflow) = match u128_overflow_add(high, high3) {
+        Result::Ok(high) => (high, overflow),
+        Result::Err(high) => (high, true),
+    };
+    (u256 { low, high }, overflow)
+}
+
+#[panic_with('u256_add OF', u256_add)]
+pub fn u256_checked_add(a: u256, b: u256) -> Option::<u256> implicits(RangeCheck) nopanic {
+    let (r, overflow) = u256_overflow_add(a, b);
+    if overflow {
+        Option::<u256>::None(())
+    } else {
+        Option::<u256>::Some(r)
+    }
+}
+
+#[panic_with('u256_sub OF', u256_sub)]
+pub fn u256_checked_sub(a: u256, b: u256) -> Option::<u256> implicits(RangeCheck) nopanic {
+    let (r, overflow) = u256_overflow_sub(a, b);
+    if overflow {
+        Option::<u256>::None(())
+    } else {
+        Option::<u256>::Some(r)
+    }
+}
+
+#[panic_with('u256_mul OF', u256_mul)]
+pub fn u256_checked_mul(a: u256, b: u256) -> Option::<u256> implicits(RangeCheck) nopanic {
+    let (r, overflow) = u256_overflow_mul(a, b);
+    if overflow {
+        Option::<u256>::None(())
+    } else {
+        Option::<u256>::Some(r)
+    }
+}
+
+pub extern fn u256_jump_nz(a: u256) -> JumpNzResult::<u256> implicits() nopanic;
+
+pub impl NonZeroU256Copy of Copy::<NonZero::<u256>>;
+pub impl NonZeroU256Drop of Drop::<NonZero::<u256>>;
+
+#[panic_with('u256 is 0', u256_as_non_zero)]
+pub fn u256_checked_as_non_zero(a: u256) -> Option::<NonZero::<u256>> implicits() nopanic {
+    match u256_jump_nz(a) {
+        JumpNzResult::Zero(()) => Option::<NonZero::<u256>>::None(()),
+        JumpNzResult::NonZero(x) => Option::<NonZero::<u256>>::Some(x),
+    }
+}
+
+pub extern fn u256_safe_divmod(
+    a: u256, b: NonZero::<u256>
+    ) -> (
+    u256, u256
+) implicits(RangeCheck) nopanic;
+
+pub fn u256_div(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+    let (q, r) = u256_safe_divmod(a, u256_as_non_zero(b));
+    q
+}
+
+pub fn u256_mod(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+    let (q, r) = u256_safe_divmod(a, u256_as_non_zero(b));
+    r
+}
+
+pub fn u256_shl(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+    if b.high != 0_u128 | b.low >= 256_u128 {
+        return u256 { low: 0_u128, high: 0_u128 };
+    }
+    if b.low < 128_u128 {
+        // The bits of `a.low` shifted into `high` don't overlap with `a.high << b.low`.
+        u256 { low: a.low << b.low, high: (a.high << b.low) + (a.low >> (128_u128 - b.low)) }
+    } else {
+        u256 { low: 0_u128, high: a.low << (b.low - 128_u128) }
+    }
+}
+
+pub fn u256_shr(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+    if b.high != 0_u128 | b.low >= 256_u128 {
+        return u256 { low: 0_u128, high: 0_u128 };
+    }
+    if b.low < 128_u128 {
+        // The bits of `a.high` shifted into `low` don't overlap with `a.low >> b.low`.
+        u256 { low: (a.low >> b.low) + (a.high << (128_u128 - b.low)), high: a.high >> b.low }
+    } else {
+        u256 { low: a.high >> (b.low - 128_u128), high: 0_u128 }
+    }
+}
+
+#[derive(Copy, Drop)]
+pub struct u512 { pub limb0: u128, pub limb1: u128, pub limb2: u128, pub limb3: u128, }
+
+// Returns `a + b` as a `(sum, carry)` pair.
+fn u128_add_with_carry(a: u128, b: u128) -> (u128, u128) implicits(RangeCheck) nopanic {
+    match u128_overflow_add(a, b) {
+        Result::Ok(x) => (x, 0_u128),
+        Result::Err(x) => (x, 1_u128),
+    }
+}
+
+// Returns the full 512 bit product of `a` and `b`.
+pub fn u256_wide_mul(a: u256, b: u256) -> u512 implicits(RangeCheck) nopanic {
+    let (high00, low00) = u128_wide_mul(a.low, b.low);
+    let (high01, low01) = u128_wide_mul(a.low, b.high);
+    let (high10, low10) = u128_wide_mul(a.high, b.low);
+    let (high11, low11) = u128_wide_mul(a.high, b.high);
+
+    let (limb1, carry0) = u128_add_with_carry(high00, low01);
+    let (limb1, carry1) = u128_add_with_carry(limb1, low10);
+    let (limb2, carry2) = u128_add_with_carry(high01, high10);
+    let (limb2, carry3) = u128_add_with_carry(limb2, low11);
+    let (limb2, carry4) = u128_add_with_carry(limb2, carry0);
+    let (limb2, carry5) = u128_add_with_carry(limb2, carry1);
+    // The product is less than 2**512, so the top limb never overflows.
+    let limb3 = u128_wrapping_add(high11, carry2);
+    let limb3 = u128_wrapping_add(limb3, carry3);
+    let limb3 = u128_wrapping_add(limb3, carry4);
+    let limb3 = u128_wrapping_add(limb3, carry5);
+    u512 { limb0: low00, limb1, limb2, limb3 }
+}
+
+pub fn u256_eq(a: u256, b: u256) -> bool implicits() {
+    a.low == b.low & a.high == b.high
+}
+
+pub fn u256_ne(a: u256, b: u256) -> bool implicits() {
+    !(a == b)
+}
+
+pub fn u256_lt(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
+    if a.high < b.high {
+        true
+    } else if a.high == b.high {
+        a.low < b.low
+    } else {
+        false
+    }
+}
+
+pub fn u256_le(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
+    !u256_lt(b, a)
+}
+
+pub fn u256_gt(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
+    u256_lt(b, a)
+}
+
+pub fn u256_ge(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
+    !u256_lt(a, b)
+}
+
+pub fn u256_and(a: u256, b: u256) -> u256 implicits(Bitwise) nopanic {
+    u256 { low: a.low & b.low, high: a.high & b.high }
+}
+pub fn u256_or(a: u256, b: u256) -> u256 implicits(Bitwise) nopanic {
+    u256 { low: a.low | b.low, high: a.high | b.high }
+}
+pub fn u256_xor(a: u256, b: u256) -> u256 implicits(Bitwise) nopanic {
+    u256 { low: a.low ^ b.low, high: a.high ^ b.high }
+}
+
+pub impl U256Add of Add::<u256> {
+    fn add(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+        u256_add(a, b)
+    }
+}
+pub impl U256Sub of Sub::<u256> {
+    fn sub(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+        u256_sub(a, b)
+    }
+}
+pub impl U256Mul of Mul::<u256> {
+    fn mul(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+        u256_mul(a, b)
+    }
+}
+pub impl U256Div of Div::<u256> {
+    fn div(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+        u256_div(a, b)
+    }
+}
+pub impl U256Rem of Rem::<u256> {
+    fn rem(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+        u256_mod(a, b)
+    }
+}
+pub impl U256PartialEq of PartialEq::<u256> {
+    fn eq(a: u256, b: u256) -> bool implicits() {
+        u256_eq(a, b)
+    }
+    fn ne(a: u256, b: u256) -> bool implicits() {
+        u256_ne(a, b)
+    }
+}
+pub impl U256PartialOrd of PartialOrd::<u256> {
+    fn le(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
+        u256_le(a, b)
+    }
+    fn ge(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
+        u256_ge(a, b)
+    }
+    fn lt(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
+        u256_lt(a, b)
+    }
+    fn gt(a: u256, b: u256) -> bool implicits(RangeCheck) nopanic {
+        u256_gt(a, b)
+    }
+}
+pub impl U256BitAnd of BitAnd::<u256> {
+    fn bitand(a: u256, b: u256) -> u256 implicits(Bitwise) nopanic {
+        u256_and(a, b)
+    }
+}
+pub impl U256BitOr of BitOr::<u256> {
+    fn bitor(a: u256, b: u256) -> u256 implicits(Bitwise) nopanic {
+        u256_or(a, b)
+    }
+}
+pub impl U256BitXor of BitXor::<u256> {
+    fn bitxor(a: u256, b: u256) -> u256 implicits(Bitwise) nopanic {
+        u256_xor(a, b)
+    }
+}
+pub impl U256Shl of Shl::<u256> {
+    fn shl(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+        u256_shl(a, b)
+    }
+}
+pub impl U256Shr of Shr::<u256> {
+    fn shr(a: u256, b: u256) -> u256 implicits(RangeCheck) {
+        u256_shr(a, b)
+    }
+}
+
+#[deprecated(into)]
+pub fn u256_from_felt(a: felt) -> u256 implicits(RangeCheck) nopanic {
+    match u128s_from_felt(a) {
+        U128sFromFeltResult::Narrow(low) => u256 { low, high: 0_u128 },
+        U128sFromFeltResult::Wide((high, low)) => u256 { low, high },
+    }
+}
+
+pub trait U256Trait {
+    fn checked_add(self: u256, other: u256) -> Option::<u256> implicits(RangeCheck) nopanic;
+    fn checked_sub(self: u256, other: u256) -> Option::<u256> implicits(RangeCheck) nopanic;
+    fn checked_mul(self: u256, other: u256) -> Option::<u256> implicits(RangeCheck) nopanic;
+}
+pub impl U256Impl of U256Trait {
+    fn checked_add(self: u256, other: u256) -> Option::<u256> implicits(RangeCheck) nopanic {
+        u256_checked_add(self, other)
+    }
+    fn checked_sub(self: u256, other: u256) -> Option::<u256> implicits(RangeCheck) nopanic {
+        u256_checked_sub(self, other)
+    }
+    fn checked_mul(self: u256, other: u256) -> Option::<u256> implicits(RangeCheck) nopanic {
+        u256_checked_mul(self, other)
+    }
+}
+
+// Conversions.
+pub impl U8IntoFelt of Into::<u8, felt> {
+    fn into(self: u8) -> felt {
+        u8_to_felt(self)
+    }
+}
+pub impl U8IntoU16 of Into::<u8, u16> {
+    fn into(self: u8) -> u16 {
+        u16_from_felt(u8_to_felt(self))
+    }
+}
+pub impl U8IntoU32 of Into::<u8, u32> {
+    fn into(self: u8) -> u32 {
+        u32_from_felt(u8_to_felt(self))
+    }
+}
+pub impl U8IntoU64 of Into::<u8, u64> {
+    fn into(self: u8) -> u64 {
+        u64_from_felt(u8_to_felt(self))
+    }
+}
+pub impl U8IntoU128 of Into::<u8, u128> {
+    fn into(self: u8) -> u128 {
+        u128_from_felt(u8_to_felt(self))
+    }
+}
+pub impl U16IntoFelt of Into::<u16, felt> {
+    fn into(self: u16) -> felt {
+        u16_to_felt(self)
+    }
+}
+pub impl U16TryIntoU8 of TryInto::<u16, u8> {
+    fn try_into(self: u16) -> Option::<u8> {
+        u8_try_from_felt(u16_to_felt(self))
+    }
+}
+pub impl U16IntoU32 of Into::<u16, u32> {
+    fn into(self: u16) -> u32 {
+        u32_from_felt(u16_to_felt(self))
+    }
+}
+pub impl U16IntoU64 of Into::<u16, u64> {
+    fn into(self: u16) -> u64 {
+        u64_from_felt(u16_to_felt(self))
+    }
+}
+pub impl U16IntoU128 of Into::<u16, u128> {
+    fn into(self: u16) -> u128 {
+        u128_from_felt(u16_to_felt(self))
+    }
+}
+pub impl U32IntoFelt of Into::<u32, felt> {
+    fn into(self: u32) -> felt {
+        u32_to_felt(self)
+    }
+}
+pub impl U32TryIntoU8 of TryInto::<u32, u8> {
+    fn try_into(self: u32) -> Option::<u8> {
+        u8_try_from_felt(u32_to_felt(self))
+    }
+}
+pub impl U32TryIntoU16 of TryInto::<u32, u16> {
+    fn try_into(self: u32) -> Option::<u16> {
+        u16_try_from_felt(u32_to_felt(self))
+    }
+}
+pub impl U32IntoU64 of Into::<u32, u64> {
+    fn into(self: u32) -> u64 {
+        u64_from_felt(u32_to_felt(self))
+    }
+}
+pub impl U32IntoU128 of Into::<u32, u128> {
+    fn into(self: u32) -> u128 {
+        u128_from_felt(u32_to_felt(self))
+    }
+}
+pub impl U64IntoFelt of Into::<u64, felt> {
+    fn into(self: u64) -> felt {
+        u64_to_felt(self)
+    }
+}
+pub impl U64TryIntoU8 of TryInto::<u64, u8> {
+    fn try_into(self: u64) -> Option::<u8> {
+        u8_try_from_felt(u64_to_felt(self))
+    }
+}
+pub impl U64TryIntoU16 of TryInto::<u64, u16> {
+    fn try_into(self: u64) -> Option::<u16> {
+        u16_try_from_felt(u64_to_felt(self))
+    }
+}
+pub impl U64TryIntoU32 of TryInto::<u64, u32> {
+    fn try_into(self: u64) -> Option::<u32> {
+        u32_try_from_felt(u64_to_felt(self))
+    }
+}
+pub impl U64IntoU128 of Into::<u64, u128> {
+    fn into(self: u64) -> u128 {
+        u128_from_felt(u64_to_felt(self))
+    }
+}
+pub impl U128IntoFelt of Into::<u128, felt> {
+    fn into(self: u128) -> felt {
+        u128_to_felt(self)
+    }
+}
+pub impl U128TryIntoU8 of TryInto::<u128, u8> {
+    fn try_into(self: u128) -> Option::<u8> {
+        u8_try_from_felt(u128_to_felt(self))
+    }
+}
+pub impl U128TryIntoU16 of TryInto::<u128, u16> {
+    fn try_into(self: u128) -> Option::<u16> {
+        u16_try_from_felt(u128_to_felt(self))
+    }
+}
+pub impl U128TryIntoU32 of TryInto::<u128, u32> {
+    fn try_into(self: u128) -> Option::<u32> {
+        u32_try_from_felt(u128_to_felt(self))
+    }
+}
+pub impl U128TryIntoU64 of TryInto::<u128, u64> {
+    fn try_into(self: u128) -> Option::<u64> {
+        u64_try_from_felt(u128_to_felt(self))
+    }
+}
+pub impl I8IntoFelt of Into::<i8, felt> {
+    fn into(self: i8) -> felt {
+        i8_to_felt(self)
+    }
+}
+pub impl I8IntoI16 of Into::<i8, i16> {
+    fn into(self: i8) -> i16 {
+        i16_from_felt(i8_to_felt(self))
+    }
+}
+pub impl I8IntoI32 of Into::<i8, i32> {
+    fn into(self: i8) -> i32 {
+        i32_from_felt(i8_to_felt(self))
+    }
+}
+pub impl I8IntoI64 of Into::<i8, i64> {
+    fn into(self: i8) -> i64 {
+        i64_from_felt(i8_to_felt(self))
+    }
+}
+pub impl I8IntoI128 of Into::<i8, i128> {
+    fn into(self: i8) -> i128 {
+        i128_from_felt(i8_to_felt(self))
+    }
+}
+pub impl I16IntoFelt of Into::<i16, felt> {
+    fn into(self: i16) -> felt {
+        i16_to_felt(self)
+    }
+}
+pub impl I16TryIntoI8 of TryInto::<i16, i8> {
+    fn try_into(self: i16) -> Option::<i8> {
+        i8_try_from_felt(i16_to_felt(self))
+    }
+}
+pub impl I16IntoI32 of Into::<i16, i32> {
+    fn into(self: i16) -> i32 {
+        i32_from_felt(i16_to_felt(self))
+    }
+}
+pub impl I16IntoI64 of Into::<i16, i64> {
+    fn into(self: i16) -> i64 {
+        i64_from_felt(i16_to_felt(self))
+    }
+}
+pub impl I16IntoI128 of Into::<i16, i128> {
+    fn into(self: i16) -> i128 {
+        i128_from_felt(i16_to_felt(self))
+    }
+}
+pub impl I32IntoFelt of Into::<i32, felt> {
+    fn into(self: i32) -> felt {
+        i32_to_felt(self)
+    }
+}
+pub impl I32TryIntoI8 of TryInto::<i32, i8> {
+    fn try_into(self: i32) -> Option::<i8> {
+        i8_try_from_felt(i32_to_felt(self))
+    }
+}
+pub impl I32TryIntoI16 of TryInto::<i32, i16> {
+    fn try_into(self: i32) -> Option::<i16> {
+        i16_try_from_felt(i32_to_felt(self))
+    }
+}
+pub impl I32IntoI64 of Into::<i32, i64> {
+    fn into(self: i32) -> i64 {
+        i64_from_felt(i32_to_felt(self))
+    }
+}
+pub impl I32IntoI128 of Into::<i32, i128> {
+    fn into(self: i32) -> i128 {
+        i128_from_felt(i32_to_felt(self))
+    }
+}
+pub impl I64IntoFelt of Into::<i64, felt> {
+    fn into(self: i64) -> felt {
+        i64_to_felt(self)
+    }
+}
+pub impl I64TryIntoI8 of TryInto::<i64, i8> {
+    fn try_into(self: i64) -> Option::<i8> {
+        i8_try_from_felt(i64_to_felt(self))
+    }
+}
+pub impl I64TryIntoI16 of TryInto::<i64, i16> {
+    fn try_into(self: i64) -> Option::<i16> {
+        i16_try_from_felt(i64_to_felt(self))
+    }
+}
+pub impl I64TryIntoI32 of TryInto::<i64, i32> {
+    fn try_into(self: i64) -> Option::<i32> {
+        i32_try_from_felt(i64_to_felt(self))
+    }
+}
+pub impl I64IntoI128 of Into::<i64, i128> {
+    fn into(self: i64) -> i128 {
+        i128_from_felt(i64_to_felt(self))
+    }
+}
+pub impl I128IntoFelt of Into::<i128, felt> {
+    fn into(self: i128) -> felt {
+        i128_to_felt(self)
+    }
+}
+pub impl I128TryIntoI8 of TryInto::<i128, i8> {
+    fn try_into(self: i128) -> Option::<i8> {
+        i8_try_from_felt(i128_to_felt(self))
+    }
+}
+pub impl I128TryIntoI16 of TryInto::<i128, i16> {
+    fn try_into(self: i128) -> Option::<i16> {
+        i16_try_from_felt(i128_to_felt(self))
+    }
+}
+pub impl I128TryIntoI32 of TryInto::<i128, i32> {
+    fn try_into(self: i128) -> Option::<i32> {
+        i32_try_from_felt(i128_to_felt(self))
+    }
+}
+pub impl I128TryIntoI64 of TryInto::<i128, i64> {
+    fn try_into(self: i128) -> Option::<i64> {
+        i64_try_from_felt(i128_to_felt(self))
+    }
+}
+pub impl U8TryIntoI8 of TryInto::<u8, i8> {
+    fn try_into(self: u8) -> Option::<i8> {
+        i8_try_from_felt(u8_to_felt(self))
+    }
+}
+pub impl U8IntoI16 of Into::<u8, i16> {
+    fn into(self: u8) -> i16 {
+        i16_from_felt(u8_to_felt(self))
+    }
+}
+pub impl U8IntoI32 of Into::<u8, i32> {
+    fn into(self: u8) -> i32 {
+        i32_from_felt(u8_to_felt(self))
+    }
+}
+pub impl U8IntoI64 of Into::<u8, i64> {
+    fn into(self: u8) -> i64 {
+        i64_from_felt(u8_to_felt(self))
+    }
+}
+pub impl U8IntoI128 of Into::<u8, i128> {
+    fn into(self: u8) -> i128 {
+        i128_from_felt(u8_to_felt(self))
+    }
+}
+pub impl U16TryIntoI8 of TryInto::<u16, i8> {
+    fn try_into(self: u16) -> Option::<i8> {
+        i8_try_from_felt(u16_to_felt(self))
+    }
+}
+pub impl U16TryIntoI16 of TryInto::<u16, i16> {
+    fn try_into(self: u16) -> Option::<i16> {
+        i16_try_from_felt(u16_to_felt(self))
+    }
+}
+pub impl U16IntoI32 of Into::<u16, i32> {
+    fn into(self: u16) -> i32 {
+        i32_from_felt(u16_to_felt(self))
+    }
+}
+pub impl U16IntoI64 of Into::<u16, i64> {
+    fn into(self: u16) -> i64 {
+        i64_from_felt(u16_to_felt(self))
+    }
+}
+pub impl U16IntoI128 of Into::<u16, i128> {
+    fn into(self: u16) -> i128 {
+        i128_from_felt(u16_to_felt(self))
+    }
+}
+pub impl U32TryIntoI8 of TryInto::<u32, i8> {
+    fn try_into(self: u32) -> Option::<i8> {
+        i8_try_from_felt(u32_to_felt(self))
+    }
+}
+pub impl U32TryIntoI16 of TryInto::<u32, i16> {
+    fn try_into(self: u32) -> Option::<i16> {
+        i16_try_from_felt(u32_to_felt(self))
+    }
+}
+pub impl U32TryIntoI32 of TryInto::<u32, i32> {
+    fn try_into(self: u32) -> Option::<i32> {
+        i32_try_from_felt(u32_to_felt(self))
+    }
+}
+pub impl U32IntoI64 of Into::<u32, i64> {
+    fn into(self: u32) -> i64 {
+        i64_from_felt(u32_to_felt(self))
+    }
+}
+pub impl U32IntoI128 of Into::<u32, i128> {
+    fn into(self: u32) -> i128 {
+        i128_from_felt(u32_to_felt(self))
+    }
+}
+pub impl U64TryIntoI8 of TryInto::<u64, i8> {
+    fn try_into(self: u64) -> Option::<i8> {
+        i8_try_from_felt(u64_to_felt(self))
+    }
+}
+pub impl U64TryIntoI16 of TryInto::<u64, i16> {
+    fn try_into(self: u64) -> Option::<i16> {
+        i16_try_from_felt(u64_to_felt(self))
+    }
+}
+pub impl U64TryIntoI32 of TryInto::<u64, i32> {
+    fn try_into(self: u64) -> Option::<i32> {
+        i32_try_from_felt(u64_to_felt(self))
+    }
+}
+pub impl U64TryIntoI64 of TryInto::<u64, i64> {
+    fn try_into(self: u64) -> Option::<i64> {
+        i64_try_from_felt(u64_to_felt(self))
+    }
+}
+pub impl U64IntoI128 of Into::<u64, i128> {
+    fn into(self: u64) -> i128 {
+        i128_from_felt(u64_to_felt(self))
+    }
+}
+pub impl U128TryIntoI8 of TryInto::<u128, i8> {
+    fn try_into(self: u128) -> Option::<i8> {
+        i8_try_from_felt(u128_to_felt(self))
+    }
+}
+pub impl U128TryIntoI16 of TryInto::<u128, i16> {
+    fn try_into(self: u128) -> Option::<i16> {
+        i16_try_from_felt(u128_to_felt(self))
+    }
+}
+pub impl U128TryIntoI32 of TryInto::<u128, i32> {
+    fn try_into(self: u128) -> Option::<i32> {
+        i32_try_from_felt(u128_to_felt(self))
+    }
+}
+pub impl U128TryIntoI64 of TryInto::<u128, i64> {
+    fn try_into(self: u128) -> Option::<i64> {
+        i64_try_from_felt(u128_to_felt(self))
+    }
+}
+pub impl U128TryIntoI128 of TryInto::<u128, i128> {
+    fn try_into(self: u128) -> Option::<i128> {
+        i128_try_from_felt(u128_to_felt(self))
+    }
+}
+pub impl I8TryIntoU8 of TryInto::<i8, u8> {
+    fn try_into(self: i8) -> Option::<u8> {
+        u8_try_from_felt(i8_to_felt(self))
+    }
+}
+pub impl I8TryIntoU16 of TryInto::<i8, u16> {
+    fn try_into(self: i8) -> Option::<u16> {
+        u16_try_from_felt(i8_to_felt(self))
+    }
+}
+pub impl I8TryIntoU32 of TryInto::<i8, u32> {
+    fn try_into(self: i8) -> Option::<u32> {
+        u32_try_from_felt(i8_to_felt(self))
+    }
+}
+pub impl I8TryIntoU64 of TryInto::<i8, u64> {
+    fn try_into(self: i8) -> Option::<u64> {
+        u64_try_from_felt(i8_to_felt(self))
+    }
+}
+pub impl I8TryIntoU128 of TryInto::<i8, u128> {
+    fn try_into(self: i8) -> Option::<u128> {
+        u128_try_from_felt(i8_to_felt(self))
+    }
+}
+pub impl I16TryIntoU8 of TryInto::<i16, u8> {
+    fn try_into(self: i16) -> Option::<u8> {
+        u8_try_from_felt(i16_to_felt(self))
+    }
+}
+pub impl I16TryIntoU16 of TryInto::<i16, u16> {
+    fn try_into(self: i16) -> Option::<u16> {
+        u16_try_from_felt(i16_to_felt(self))
+    }
+}
+pub impl I16TryIntoU32 of TryInto::<i16, u32> {
+    fn try_into(self: i16) -> Option::<u32> {
+        u32_try_from_felt(i16_to_felt(self))
+    }
+}
+pub impl I16TryIntoU64 of TryInto::<i16, u64> {
+    fn try_into(self: i16) -> Option::<u64> {
+        u64_try_from_felt(i16_to_felt(self))
+    }
+}
+pub impl I16TryIntoU128 of TryInto::<i16, u128> {
+    fn try_into(self: i16) -> Option::<u128> {
+        u128_try_from_felt(i16_to_felt(self))
+    }
+}
+pub impl I32TryIntoU8 of TryInto::<i32, u8> {
+    fn try_into(self: i32) -> Option::<u8> {
+        u8_try_from_felt(i32_to_felt(self))
+    }
+}
+pub impl I32TryIntoU16 of TryInto::<i32, u16> {
+    fn try_into(self: i32) -> Option::<u16> {
+        u16_try_from_felt(i32_to_felt(self))
+    }
+}
+pub impl I32TryIntoU32 of TryInto::<i32, u32> {
+    fn try_into(self: i32) -> Option::<u32> {
+        u32_try_from_felt(i32_to_felt(self))
+    }
+}
+pub impl I32TryIntoU64 of TryInto::<i32, u64> {
+    fn try_into(self: i32) -> Option::<u64> {
+        u64_try_from_felt(i32_to_felt(self))
+    }
+}
+pub impl I32TryIntoU128 of TryInto::<i32, u128> {
+    fn try_into(self: i32) -> Option::<u128> {
+        u128_try_from_felt(i32_to_felt(self))
+    }
+}
+pub impl I64TryIntoU8 of TryInto::<i64, u8> {
+    fn try_into(self: i64) -> Option::<u8> {
+        u8_try_from_felt(i64_to_felt(self))
+    }
+}
+pub impl I64TryIntoU16 of TryInto::<i64, u16> {
+    fn try_into(self: i64) -> Option::<u16> {
+        u16_try_from_felt(i64_to_felt(self))
+    }
+}
+pub impl I64TryIntoU32 of TryInto::<i64, u32> {
+    fn try_into(self: i64) -> Option::<u32> {
+        u32_try_from_felt(i64_to_felt(self))
+    }
+}
+pub impl I64TryIntoU64 of TryInto::<i64, u64> {
+    fn try_into(self: i64) -> Option::<u64> {
+        u64_try_from_felt(i64_to_felt(self))
+    }
+}
+pub impl I64TryIntoU128 of TryInto::<i64, u128> {
+    fn try_into(self: i64) -> Option::<u128> {
+        u128_try_from_felt(i64_to_felt(self))
+    }
+}
+pub impl I128TryIntoU8 of TryInto::<i128, u8> {
+    fn try_into(self: i128) -> Option::<u8> {
+        u8_try_from_felt(i128_to_felt(self))
+    }
+}
+pub impl I128TryIntoU16 of TryInto::<i128, u16> {
+    fn try_into(self: i128) -> Option::<u16> {
+        u16_try_from_felt(i128_to_felt(self))
+    }
+}
+pub impl I128TryIntoU32 of TryInto::<i128, u32> {
+    fn try_into(self: i128) -> Option::<u32> {
+        u32_try_from_felt(i128_to_felt(self))
+    }
+}
+pub impl I128TryIntoU64 of TryInto::<i128, u64> {
+    fn try_into(self: i128) -> Option::<u64> {
+        u64_try_from_felt(i128_to_felt(self))
+    }
+}
+pub impl I128TryIntoU128 of TryInto::<i128, u128> {
+    fn try_into(self: i128) -> Option::<u128> {
+        u128_try_from_felt(i128_to_felt(self))
+    }
+}
+pub impl U8IntoU256 of Into::<u8, u256> {
+    fn into(self: u8) -> u256 {
+        u256 { low: u128_from_felt(u8_to_felt(self)), high: 0_u128 }
+    }
+}
+pub impl U16IntoU256 of Into::<u16, u256> {
+    fn into(self: u16) -> u256 {
+        u256 { low: u128_from_felt(u16_to_felt(self)), high: 0_u128 }
+    }
+}
+pub impl U32IntoU256 of Into::<u32, u256> {
+    fn into(self: u32) -> u256 {
+        u256 { low: u128_from_felt(u32_to_felt(self)), high: 0_u128 }
+    }
+}
+pub impl U64IntoU256 of Into::<u64, u256> {
+    fn into(self: u64) -> u256 {
+        u256 { low: u128_from_felt(u64_to_felt(self)), high: 0_u128 }
+    }
+}
+pub impl U128IntoU256 of Into::<u128, u256> {
+    fn into(self: u128) -> u256 {
+        u256 { low: self, high: 0_u128 }
+    }
+}
+pub impl U256TryIntoU8 of TryInto::<u256, u8> {
+    fn try_into(self: u256) -> Option::<u8> {
+        if self.high == 0_u128 {
+            u8_try_from_felt(u128_to_felt(self.low))
+        } else {
+            Option::<u8>::None(())
+        }
+    }
+}
+pub impl U256TryIntoU16 of TryInto::<u256, u16> {
+    fn try_into(self: u256) -> Option::<u16> {
+        if self.high == 0_u128 {
+            u16_try_from_felt(u128_to_felt(self.low))
+        } else {
+            Option::<u16>::None(())
+        }
+    }
+}
+pub impl U256TryIntoU32 of TryInto::<u256, u32> {
+    fn try_into(self: u256) -> Option::<u32> {
+        if self.high == 0_u128 {
+            u32_try_from_felt(u128_to_felt(self.low))
+        } else {
+            Option::<u32>::None(())
+        }
+    }
+}
+pub impl U256TryIntoU64 of TryInto::<u256, u64> {
+    fn try_into(self: u256) -> Option::<u64> {
+        if self.high == 0_u128 {
+            u64_try_from_felt(u128_to_felt(self.low))
+        } else {
+            Option::<u64>::None(())
+        }
+    }
+}
+pub impl U256TryIntoU128 of TryInto::<u256, u128> {
+    fn try_into(self: u256) -> Option::<u128> {
+        if self.high == 0_u128 {
+            Option::<u128>::Some(self.low)
+        } else {
+            Option::<u128>::None(())
+        }
+    }
+}
+// The high and low limbs of the field prime, 2^251 + 17 * 2^192 + 1.
+const FELT_PRIME_HIGH: u128 = 0x8000000000000110000000000000000_u128;
+const FELT_PRIME_LOW: u128 = 1_u128;
+pub impl U256TryIntoFelt of TryInto::<u256, felt> {
+    fn try_into(self: u256) -> Option::<felt> {
+        if self.high < FELT_PRIME_HIGH | self.high == FELT_PRIME_HIGH & self.low < FELT_PRIME_LOW {
+            Option::<felt>::Some(
+                u128_to_felt(self.high) * 0x100000000000000000000000000000000 + u128_to_felt(self.low)
+            )
+        } else {
+            Option::<felt>::None(())
+        }
+    }
+}
+pub impl I8TryIntoU256 of TryInto::<i8, u256> {
+    fn try_into(self: i8) -> Option::<u256> {
+        match u128_try_from_felt(i8_to_felt(self)) {
+            Option::Some(low) => Option::<u256>::Some(u256 { low, high: 0_u128 }),
+            Option::None(()) => Option::<u256>::None(()),
+        }
+    }
+}
+pub impl I16TryIntoU256 of TryInto::<i16, u256> {
+    fn try_into(self: i16) -> Option::<u256> {
+        match u128_try_from_felt(i16_to_felt(self)) {
+            Option::Some(low) => Option::<u256>::Some(u256 { low, high: 0_u128 }),
+            Option::None(()) => Option::<u256>::None(()),
+        }
+    }
+}
+pub impl I32TryIntoU256 of TryInto::<i32, u256> {
+    fn try_into(self: i32) -> Option::<u256> {
+        match u128_try_from_felt(i32_to_felt(self)) {
+            Option::Some(low) => Option::<u256>::Some(u256 { low, high: 0_u128 }),
+            Option::None(()) => Option::<u256>::None(()),
+        }
+    }
+}
+pub impl I64TryIntoU256 of TryInto::<i64, u256> {
+    fn try_into(self: i64) -> Option::<u256> {
+        match u128_try_from_felt(i64_to_felt(self)) {
+            Option::Some(low) => Option::<u256>::Some(u256 { low, high: 0_u128 }),
+            Option::None(()) => Option::<u256>::None(()),
+        }
+    }
+}
+pub impl I128TryIntoU256 of TryInto::<i128, u256> {
+    fn try_into(self: i128) -> Option::<u256> {
+        match u128_try_from_felt(i128_to_felt(self)) {
+            Option::Some(low) => Option::<u256>::Some(u256 { low, high: 0_u128 }),
+            Option::None(()) => Option::<u256>::None(()),
+        }
+    }
+}
+pub impl U256TryIntoI8 of TryInto::<u256, i8> {
+    fn try_into(self: u256) -> Option::<i8> {
+        if self.high == 0_u128 {
+            i8_try_from_felt(u128_to_felt(self.low))
+        } else {
+            Option::<i8>::None(())
+        }
+    }
+}
+pub impl U256TryIntoI16 of TryInto::<u256, i16> {
+    fn try_into(self: u256) -> Option::<i16> {
+        if self.high == 0_u128 {
+            i16_try_from_felt(u128_to_felt(self.low))
+        } else {
+            Option::<i16>::None(())
+        }
+    }
+}
+pub impl U256TryIntoI32 of TryInto::<u256, i32> {
+    fn try_into(self: u256) -> Option::<i32> {
+        if self.high == 0_u128 {
+            i32_try_from_felt(u128_to_felt(self.low))
+        } else {
+            Option::<i32>::None(())
+        }
+    }
+}
+pub impl U256TryIntoI64 of TryInto::<u256, i64> {
+    fn try_into(self: u256) -> Option::<i64> {
+        if self.high == 0_u128 {
+            i64_try_from_felt(u128_to_felt(self.low))
+        } else {
+            Option::<i64>::None(())
+        }
+    }
+}
+pub impl U256TryIntoI128 of TryInto::<u256, i128> {
+    fn try_into(self: u256) -> Option::<i128> {
+        if self.high == 0_u128 {
+            i128_try_from_felt(u128_to_felt(self.low))
+        } else {
+            Option::<i128>::None(())
+        }
     }
 }
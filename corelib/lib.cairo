@@ -1,221 +1,604 @@
-mod traits;
-use traits::Copy;
-use traits::Drop;
-
-enum bool { False: (), True: (), }
+pub mod traits;
+pub use traits::Copy;
+pub use traits::Drop;
+pub use traits::Add;
+pub use traits::Sub;
+pub use traits::Mul;
+pub use traits::Div;
+pub use traits::Rem;
+pub use traits::PartialEq;
+pub use traits::BitAnd;
+pub use traits::BitOr;
+pub use traits::BitXor;
+pub use traits::Shl;
+pub use traits::Shr;
+pub use traits::PartialOrd;
+pub use traits::Neg;
+pub use traits::Not;
+pub use traits::Fn;
+pub use traits::Index;
+pub use traits::Into;
+pub use traits::TryInto;
+
+pub enum bool { False: (), True: (), }
 // TODO(spapini): Make unnamed.
-impl BoolCopy of Copy::<bool>;
-impl BoolDrop of Drop::<bool>;
+pub impl BoolCopy of Copy::<bool>;
+pub impl BoolDrop of Drop::<bool>;
 
-extern fn bool_and_impl(a: bool, b: bool) -> (bool,) implicits() nopanic;
-fn bool_and(a: bool, b: bool) -> bool implicits() nopanic {
+pub extern fn bool_and_impl(a: bool, b: bool) -> (bool,) implicits() nopanic;
+pub fn bool_and(a: bool, b: bool) -> bool implicits() nopanic {
     let (r,) = bool_and_impl(a, b);
     r
 }
 
 // TODO(orizi): Change to extern when added.
-fn bool_or(a: bool, b: bool) -> bool implicits() nopanic {
+pub fn bool_or(a: bool, b: bool) -> bool implicits() nopanic {
     match a {
         bool::False(x) => b,
         bool::True(x) => bool::True(()),
     }
 }
 
-extern fn bool_not_impl(a: bool) -> (bool,) implicits() nopanic;
-fn bool_not(a: bool) -> bool implicits() nopanic {
+pub extern fn bool_not_impl(a: bool) -> (bool,) implicits() nopanic;
+pub fn bool_not(a: bool) -> bool implicits() nopanic {
     let (r,) = bool_not_impl(a);
     r
 }
 
-extern fn bool_xor_impl(a: bool, b: bool) -> (bool,) implicits() nopanic;
-fn bool_xor(a: bool, b: bool) -> bool implicits() nopanic {
+pub extern fn bool_xor_impl(a: bool, b: bool) -> (bool,) implicits() nopanic;
+pub fn bool_xor(a: bool, b: bool) -> bool implicits() nopanic {
     let (r,) = bool_xor_impl(a, b);
     r
 }
 
-extern fn bool_eq(a: bool, b: bool) -> bool implicits() nopanic;
+pub extern fn bool_eq(a: bool, b: bool) -> bool implicits() nopanic;
 
-fn bool_ne(a: bool, b: bool) -> bool implicits() nopanic {
+pub fn bool_ne(a: bool, b: bool) -> bool implicits() nopanic {
     !(a == b)
 }
 
+pub impl BoolPartialEq of PartialEq::<bool> {
+    fn eq(a: bool, b: bool) -> bool implicits() nopanic {
+        bool_eq(a, b)
+    }
+    fn ne(a: bool, b: bool) -> bool implicits() nopanic {
+        bool_ne(a, b)
+    }
+}
+pub impl BoolBitAnd of BitAnd::<bool> {
+    fn bitand(a: bool, b: bool) -> bool implicits() nopanic {
+        bool_and(a, b)
+    }
+}
+pub impl BoolBitOr of BitOr::<bool> {
+    fn bitor(a: bool, b: bool) -> bool implicits() nopanic {
+        bool_or(a, b)
+    }
+}
+pub impl BoolBitXor of BitXor::<bool> {
+    fn bitxor(a: bool, b: bool) -> bool implicits() nopanic {
+        bool_xor(a, b)
+    }
+}
+pub impl BoolNot of Not::<bool> {
+    fn not(a: bool) -> bool implicits() nopanic {
+        bool_not(a)
+    }
+}
+
 // Felt.
-extern type RangeCheck;
+pub extern type RangeCheck;
 
-extern type felt;
-extern fn felt_const<value>() -> felt nopanic;
+pub extern type felt;
+pub extern fn felt_const<value>() -> felt nopanic;
 
 // TODO(spapini): Make unnamed.
-impl FeltCopy of Copy::<felt>;
-impl FeltDrop of Drop::<felt>;
+pub impl FeltCopy of Copy::<felt>;
+pub impl FeltDrop of Drop::<felt>;
 
-extern fn felt_add(a: felt, b: felt) -> felt nopanic;
-extern fn felt_sub(a: felt, b: felt) -> felt nopanic;
-extern fn felt_mul(a: felt, b: felt) -> felt nopanic;
-fn felt_neg(a: felt) -> felt nopanic {
+pub extern fn felt_add(a: felt, b: felt) -> felt nopanic;
+pub extern fn felt_sub(a: felt, b: felt) -> felt nopanic;
+pub extern fn felt_mul(a: felt, b: felt) -> felt nopanic;
+pub fn felt_neg(a: felt) -> felt nopanic {
     a * felt_const::<-1>()
 }
 
-extern type NonZero<T>;
+pub extern type NonZero<T>;
 // TODO(spapini): Add generic impls for NonZero for Copy, Drop.
-enum JumpNzResult<T> { Zero: (), NonZero: NonZero::<T>, }
-extern fn unwrap_nz<T>(a: NonZero::<T>) -> T nopanic;
+pub enum JumpNzResult<T> { Zero: (), NonZero: NonZero::<T>, }
+pub extern fn unwrap_nz<T>(a: NonZero::<T>) -> T nopanic;
 
-impl NonZeroFeltCopy of Copy::<NonZero::<felt>>;
-impl NonZeroFeltDrop of Drop::<NonZero::<felt>>;
-extern fn felt_div(a: felt, b: NonZero::<felt>) -> felt nopanic;
+pub impl NonZeroFeltCopy of Copy::<NonZero::<felt>>;
+pub impl NonZeroFeltDrop of Drop::<NonZero::<felt>>;
+pub extern fn felt_div(a: felt, b: NonZero::<felt>) -> felt nopanic;
 
 // TODO(orizi): Change to extern when added.
-fn felt_eq(a: felt, b: felt) -> bool nopanic {
+pub fn felt_eq(a: felt, b: felt) -> bool nopanic {
     match a - b {
         0 => bool::True(()),
         _ => bool::False(()),
     }
 }
-fn felt_ne(a: felt, b: felt) -> bool nopanic {
+pub fn felt_ne(a: felt, b: felt) -> bool nopanic {
     !(a == b)
 }
 
-fn felt_lt(a: felt, b: felt) -> bool implicits(RangeCheck) {
+pub fn felt_lt(a: felt, b: felt) -> bool implicits(RangeCheck) {
     u256_from_felt(a) < u256_from_felt(b)
 }
 
-fn felt_gt(a: felt, b: felt) -> bool implicits(RangeCheck) {
+pub fn felt_gt(a: felt, b: felt) -> bool implicits(RangeCheck) {
     b < a
 }
 
-fn felt_le(a: felt, b: felt) -> bool implicits(RangeCheck) {
+pub fn felt_le(a: felt, b: felt) -> bool implicits(RangeCheck) {
     !(b < a)
 }
 
-fn felt_ge(a: felt, b: felt) -> bool implicits(RangeCheck) {
+pub fn felt_ge(a: felt, b: felt) -> bool implicits(RangeCheck) {
     !(a < b)
 }
 
-extern fn felt_jump_nz(a: felt) -> JumpNzResult::<felt> nopanic;
+pub impl FeltAdd of Add::<felt> {
+    fn add(a: felt, b: felt) -> felt nopanic {
+        felt_add(a, b)
+    }
+}
+pub impl FeltSub of Sub::<felt> {
+    fn sub(a: felt, b: felt) -> felt nopanic {
+        felt_sub(a, b)
+    }
+}
+pub impl FeltMul of Mul::<felt> {
+    fn mul(a: felt, b: felt) -> felt nopanic {
+        felt_mul(a, b)
+    }
+}
+pub impl FeltNeg of Neg::<felt> {
+    fn neg(a: felt) -> felt nopanic {
+        felt_neg(a)
+    }
+}
+pub impl FeltPartialEq of PartialEq::<felt> {
+    fn eq(a: felt, b: felt) -> bool nopanic {
+        felt_eq(a, b)
+    }
+    fn ne(a: felt, b: felt) -> bool nopanic {
+        felt_ne(a, b)
+    }
+}
+pub impl FeltPartialOrd of PartialOrd::<felt> {
+    fn le(a: felt, b: felt) -> bool implicits(RangeCheck) {
+        felt_le(a, b)
+    }
+    fn ge(a: felt, b: felt) -> bool implicits(RangeCheck) {
+        felt_ge(a, b)
+    }
+    fn lt(a: felt, b: felt) -> bool implicits(RangeCheck) {
+        felt_lt(a, b)
+    }
+    fn gt(a: felt, b: felt) -> bool implicits(RangeCheck) {
+        felt_gt(a, b)
+    }
+}
+
+// Conversions from felt.
+pub impl FeltTryIntoU8 of TryInto::<felt, u8> {
+    fn try_into(self: felt) -> Option::<u8> {
+        u8_try_from_felt(self)
+    }
+}
+pub impl FeltTryIntoU16 of TryInto::<felt, u16> {
+    fn try_into(self: felt) -> Option::<u16> {
+        u16_try_from_felt(self)
+    }
+}
+pub impl FeltTryIntoU32 of TryInto::<felt, u32> {
+    fn try_into(self: felt) -> Option::<u32> {
+        u32_try_from_felt(self)
+    }
+}
+pub impl FeltTryIntoU64 of TryInto::<felt, u64> {
+    fn try_into(self: felt) -> Option::<u64> {
+        u64_try_from_felt(self)
+    }
+}
+pub impl FeltTryIntoU128 of TryInto::<felt, u128> {
+    fn try_into(self: felt) -> Option::<u128> {
+        u128_try_from_felt(self)
+    }
+}
+pub impl FeltTryIntoI8 of TryInto::<felt, i8> {
+    fn try_into(self: felt) -> Option::<i8> {
+        i8_try_from_felt(self)
+    }
+}
+pub impl FeltTryIntoI16 of TryInto::<felt, i16> {
+    fn try_into(self: felt) -> Option::<i16> {
+        i16_try_from_felt(self)
+    }
+}
+pub impl FeltTryIntoI32 of TryInto::<felt, i32> {
+    fn try_into(self: felt) -> Option::<i32> {
+        i32_try_from_felt(self)
+    }
+}
+pub impl FeltTryIntoI64 of TryInto::<felt, i64> {
+    fn try_into(self: felt) -> Option::<i64> {
+        i64_try_from_felt(self)
+    }
+}
+pub impl FeltTryIntoI128 of TryInto::<felt, i128> {
+    fn try_into(self: felt) -> Option::<i128> {
+        i128_try_from_felt(self)
+    }
+}
+pub impl FeltIntoU256 of Into::<felt, u256> {
+    fn into(self: felt) -> u256 {
+        u256_from_felt(self)
+    }
+}
+pub impl FeltTryIntoBool of TryInto::<felt, bool> {
+    fn try_into(self: felt) -> Option::<bool> {
+        if self == 0 {
+            Option::<bool>::Some(false)
+        } else if self == 1 {
+            Option::<bool>::Some(true)
+        } else {
+            Option::<bool>::None(())
+        }
+    }
+}
+pub impl BoolIntoFelt of Into::<bool, felt> {
+    fn into(self: bool) -> felt {
+        if self {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+pub extern fn felt_jump_nz(a: felt) -> JumpNzResult::<felt> nopanic;
 
 // TODO(spapini): Constraint using Copy and Drop traits.
-extern fn dup<T>(obj: T) -> (T, T) nopanic;
-extern fn drop<T>(obj: T) nopanic;
+pub extern fn dup<T>(obj: T) -> (T, T) nopanic;
+pub extern fn drop<T>(obj: T) nopanic;
 
 // Boxes.
-mod box;
-use box::Box;
-use box::into_box;
-use box::unbox;
+pub mod box;
+pub use box::Box;
+pub use box::into_box;
+pub use box::unbox;
 
 // Nullable
-mod nullable;
-use nullable::FromNullableResult;
-use nullable::Nullable;
-use nullable::null;
-use nullable::into_nullable;
-use nullable::from_nullable;
+pub mod nullable;
+pub use nullable::FromNullableResult;
+pub use nullable::Nullable;
+pub use nullable::null;
+pub use nullable::into_nullable;
+pub use nullable::from_nullable;
 
 // Arrays.
-mod array;
-use array::Array;
-use array::array_new;
-use array::array_append;
-use array::array_pop_front;
-use array::array_at;
-use array::array_len;
+pub mod array;
+pub use array::Array;
+pub use array::ArrayTrait;
+pub use array::ArrayImpl;
+pub use array::array_new;
+pub use array::array_append;
+pub use array::array_pop_front;
+pub use array::array_at;
+pub use array::array_len;
+pub use array::array_slice;
+pub use array::ArraySlice;
+pub use array::ArraySliceTrait;
+pub use array::ArraySliceImpl;
+pub use array::array_slice_pop_front;
+pub use array::array_slice_len;
+pub use array::array_map;
+pub use array::array_fold;
+
+// Byte arrays.
+pub mod byte_array;
+pub use byte_array::ByteArray;
+pub use byte_array::ByteArrayTrait;
+pub use byte_array::ByteArrayImpl;
+pub use byte_array::panic_with_byte_array;
+pub use byte_array::string_literal_push_word;
+pub use byte_array::string_literal_build;
 
 // Dictionary.
-mod dict;
-use dict::DictFeltTo;
-use dict::SquashedDictFeltTo;
-use dict::dict_felt_to_new;
-use dict::dict_felt_to_write;
-use dict::dict_felt_to_read;
-use dict::dict_felt_to_squash;
+pub mod dict;
+pub use dict::DictFeltTo;
+pub use dict::DictFeltToTrait;
+pub use dict::DictFeltToImpl;
+pub use dict::SquashedDictFeltTo;
+pub use dict::dict_felt_to_new;
+pub use dict::dict_felt_to_write;
+pub use dict::dict_felt_to_read;
+pub use dict::dict_felt_to_squash;
 
 // Result.
-mod result;
-use result::Result;
+pub mod result;
+pub use result::Result;
 
 // Option.
-mod option;
-use option::Option;
+pub mod option;
+pub use option::Option;
 
 // EC.
-mod ec;
-use ec::EcPoint;
-use ec::ec_point_from_felts;
-use ec::ec_point_try_create;
-use ec::ec_point_unwrap;
+pub mod ec;
+pub use ec::EcPoint;
+pub use ec::ec_point_from_felts;
+pub use ec::ec_point_try_create;
+pub use ec::ec_point_unwrap;
 
 // Integer.
-mod integer;
-use integer::u128;
-use integer::u128_const;
-use integer::u128_from_felt;
-use integer::u128_try_from_felt;
-use integer::u128_to_felt;
-use integer::u128_add;
-use integer::u128_sub;
-use integer::u128_mul;
-use integer::u128_as_non_zero;
-use integer::u128_div;
-use integer::u128_mod;
-use integer::u128_lt;
-use integer::u128_le;
-use integer::u128_gt;
-use integer::u128_ge;
-use integer::u128_eq;
-use integer::u128_ne;
-use integer::u128_and;
-use integer::u128_or;
-use integer::u128_xor;
-use integer::u128_jump_nz;
-use integer::u256;
-use integer::u256_add;
-use integer::u256_sub;
-use integer::u256_mul;
-use integer::u256_eq;
-use integer::u256_ne;
-use integer::u256_lt;
-use integer::u256_le;
-use integer::u256_gt;
-use integer::u256_ge;
-use integer::u256_and;
-use integer::u256_or;
-use integer::u256_xor;
-use integer::u256_from_felt;
-use integer::Bitwise;
+pub mod integer;
+pub use integer::u8;
+pub use integer::u8_const;
+pub use integer::u8_from_felt;
+pub use integer::u8_try_from_felt;
+pub use integer::u8_to_felt;
+pub use integer::u8_add;
+pub use integer::u8_sub;
+pub use integer::u8_mul;
+pub use integer::u8_as_non_zero;
+pub use integer::u8_div;
+pub use integer::u8_mod;
+pub use integer::u8_lt;
+pub use integer::u8_le;
+pub use integer::u8_gt;
+pub use integer::u8_ge;
+pub use integer::u8_eq;
+pub use integer::u8_ne;
+pub use integer::u8_jump_nz;
+pub use integer::u16;
+pub use integer::u16_const;
+pub use integer::u16_from_felt;
+pub use integer::u16_try_from_felt;
+pub use integer::u16_to_felt;
+pub use integer::u16_add;
+pub use integer::u16_sub;
+pub use integer::u16_mul;
+pub use integer::u16_as_non_zero;
+pub use integer::u16_div;
+pub use integer::u16_mod;
+pub use integer::u16_lt;
+pub use integer::u16_le;
+pub use integer::u16_gt;
+pub use integer::u16_ge;
+pub use integer::u16_eq;
+pub use integer::u16_ne;
+pub use integer::u16_jump_nz;
+pub use integer::u32;
+pub use integer::u32_const;
+pub use integer::u32_from_felt;
+pub use integer::u32_try_from_felt;
+pub use integer::u32_to_felt;
+pub use integer::u32_add;
+pub use integer::u32_sub;
+pub use integer::u32_mul;
+pub use integer::u32_as_non_zero;
+pub use integer::u32_div;
+pub use integer::u32_mod;
+pub use integer::u32_lt;
+pub use integer::u32_le;
+pub use integer::u32_gt;
+pub use integer::u32_ge;
+pub use integer::u32_eq;
+pub use integer::u32_ne;
+pub use integer::u32_jump_nz;
+pub use integer::u64;
+pub use integer::u64_const;
+pub use integer::u64_from_felt;
+pub use integer::u64_try_from_felt;
+pub use integer::u64_to_felt;
+pub use integer::u64_add;
+pub use integer::u64_sub;
+pub use integer::u64_mul;
+pub use integer::u64_as_non_zero;
+pub use integer::u64_div;
+pub use integer::u64_mod;
+pub use integer::u64_lt;
+pub use integer::u64_le;
+pub use integer::u64_gt;
+pub use integer::u64_ge;
+pub use integer::u64_eq;
+pub use integer::u64_ne;
+pub use integer::u64_jump_nz;
+pub use integer::u128;
+pub use integer::U128Trait;
+pub use integer::U128Impl;
+pub use integer::u128_const;
+pub use integer::u128_from_felt;
+pub use integer::u128_try_from_felt;
+pub use integer::u128_to_felt;
+pub use integer::u128_add;
+pub use integer::u128_sub;
+pub use integer::u128_mul;
+pub use integer::u128_as_non_zero;
+pub use integer::u128_div;
+pub use integer::u128_mod;
+pub use integer::u128_lt;
+pub use integer::u128_le;
+pub use integer::u128_gt;
+pub use integer::u128_ge;
+pub use integer::u128_eq;
+pub use integer::u128_ne;
+pub use integer::u128_and;
+pub use integer::u128_or;
+pub use integer::u128_xor;
+pub use integer::u128_shl;
+pub use integer::u128_shr;
+pub use integer::u128_jump_nz;
+pub use integer::SignedIntegerResult;
+pub use integer::i8;
+pub use integer::i8_const;
+pub use integer::i8_from_felt;
+pub use integer::i8_try_from_felt;
+pub use integer::i8_to_felt;
+pub use integer::i8_add;
+pub use integer::i8_sub;
+pub use integer::i8_mul;
+pub use integer::i8_div;
+pub use integer::i8_mod;
+pub use integer::i8_neg;
+pub use integer::i8_lt;
+pub use integer::i8_le;
+pub use integer::i8_gt;
+pub use integer::i8_ge;
+pub use integer::i8_eq;
+pub use integer::i8_ne;
+pub use integer::i16;
+pub use integer::i16_const;
+pub use integer::i16_from_felt;
+pub use integer::i16_try_from_felt;
+pub use integer::i16_to_felt;
+pub use integer::i16_add;
+pub use integer::i16_sub;
+pub use integer::i16_mul;
+pub use integer::i16_div;
+pub use integer::i16_mod;
+pub use integer::i16_neg;
+pub use integer::i16_lt;
+pub use integer::i16_le;
+pub use integer::i16_gt;
+pub use integer::i16_ge;
+pub use integer::i16_eq;
+pub use integer::i16_ne;
+pub use integer::i32;
+pub use integer::i32_const;
+pub use integer::i32_from_felt;
+pub use integer::i32_try_from_felt;
+pub use integer::i32_to_felt;
+pub use integer::i32_add;
+pub use integer::i32_sub;
+pub use integer::i32_mul;
+pub use integer::i32_div;
+pub use integer::i32_mod;
+pub use integer::i32_neg;
+pub use integer::i32_lt;
+pub use integer::i32_le;
+pub use integer::i32_gt;
+pub use integer::i32_ge;
+pub use integer::i32_eq;
+pub use integer::i32_ne;
+pub use integer::i64;
+pub use integer::i64_const;
+pub use integer::i64_from_felt;
+pub use integer::i64_try_from_felt;
+pub use integer::i64_to_felt;
+pub use integer::i64_add;
+pub use integer::i64_sub;
+pub use integer::i64_mul;
+pub use integer::i64_div;
+pub use integer::i64_mod;
+pub use integer::i64_neg;
+pub use integer::i64_lt;
+pub use integer::i64_le;
+pub use integer::i64_gt;
+pub use integer::i64_ge;
+pub use integer::i64_eq;
+pub use integer::i64_ne;
+pub use integer::i128;
+pub use integer::i128_const;
+pub use integer::i128_from_felt;
+pub use integer::i128_try_from_felt;
+pub use integer::i128_to_felt;
+pub use integer::i128_add;
+pub use integer::i128_sub;
+pub use integer::i128_mul;
+pub use integer::i128_div;
+pub use integer::i128_mod;
+pub use integer::i128_neg;
+pub use integer::i128_lt;
+pub use integer::i128_le;
+pub use integer::i128_gt;
+pub use integer::i128_ge;
+pub use integer::i128_eq;
+pub use integer::i128_ne;
+pub use integer::u256;
+pub use integer::U256Trait;
+pub use integer::U256Impl;
+pub use integer::u256_add;
+pub use integer::u256_sub;
+pub use integer::u256_mul;
+pub use integer::u256_div;
+pub use integer::u256_mod;
+pub use integer::u256_as_non_zero;
+pub use integer::u256_safe_divmod;
+pub use integer::u256_eq;
+pub use integer::u256_ne;
+pub use integer::u256_lt;
+pub use integer::u256_le;
+pub use integer::u256_gt;
+pub use integer::u256_ge;
+pub use integer::u256_and;
+pub use integer::u256_or;
+pub use integer::u256_xor;
+pub use integer::u256_shl;
+pub use integer::u256_shr;
+pub use integer::u512;
+pub use integer::u256_wide_mul;
+pub use integer::u256_from_felt;
+pub use integer::Bitwise;
 
 // Gas.
-mod gas;
-use gas::BuiltinCosts;
-use gas::GasBuiltin;
-use gas::get_gas;
-use gas::get_gas_all;
+pub mod gas;
+pub use gas::BuiltinCosts;
+pub use gas::GasBuiltin;
+pub use gas::get_gas;
+pub use gas::get_gas_all;
 
 // Panics.
-enum PanicResult<T> { Ok: T, Err: Array::<felt>, }
-enum never { }
-extern fn panic(data: Array::<felt>) -> never;
-
-fn assert(cond: bool, err_code: felt) {
+pub enum PanicResult<T> { Ok: T, Err: Array::<felt>, }
+// The result of a loop that may return early from the function containing it: either the value of
+// the loop, or the value to return from the function.
+pub enum LoopResult<N, E> { Normal: N, EarlyReturn: E, }
+pub enum never { }
+pub extern fn panic(data: Array::<felt>) -> never;
+
+pub fn assert(cond: bool, err_code: felt) {
     if !cond {
-        let mut data = array_new::<felt>();
-        array_append::<felt>(data, err_code);
+        let mut data = array_new();
+        array_append(data, err_code);
+        panic(data);
+    }
+}
+
+// Marks the two compared values in the panic data of a failed `assert_eq`, so that they can be
+// told apart from the error code when the panic is displayed.
+pub const ASSERT_EQ_MAGIC: felt = 0x1a05699b6238917737df08e5945ed936dca2c938ca584c0f963c937327a68c2;
+
+// Panics with `err_code` followed by `ASSERT_EQ_MAGIC` and the serializations of `a` and `b` if `a`
+// and `b` differ. The values are serialized before they are compared, as comparing them consumes
+// them.
+pub fn assert_eq<T>(mut a: T, mut b: T, err_code: felt) {
+    let mut data = array_new();
+    array_append(data, err_code);
+    array_append(data, ASSERT_EQ_MAGIC);
+    serde::Serialize::<T>::serialize(data, a);
+    serde::Serialize::<T>::serialize(data, b);
+    if PartialEq::<T>::ne(a, b) {
         panic(data);
     }
 }
 
 // Serialization and Deserialization. DO NOT USE DIRECTLY - direct usage pending traits.
-mod serde;
+pub mod serde;
 
 // Hash functions.
-mod hash;
-use hash::pedersen;
-use hash::Pedersen;
+pub mod hash;
+pub use hash::pedersen;
+pub use hash::Pedersen;
+pub use hash::LegacyHash;
 
 // StarkNet
-mod starknet;
-use starknet::System;
-use starknet::ContractAddress;
+pub mod starknet;
+pub use starknet::System;
+pub use starknet::ContractAddress;
+pub use starknet::LegacyMap;
 
 #[cfg(test)]
 mod test;
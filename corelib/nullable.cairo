@@ -1,7 +1,7 @@
-extern type Nullable<T>;
+pub extern type Nullable<T>;
 
-enum FromNullableResult<T> { Null: (), NotNull: Box::<T>, }
+pub enum FromNullableResult<T> { Null: (), NotNull: Box::<T>, }
 
-extern fn null<T>() -> Nullable::<T> nopanic;
-extern fn into_nullable<T>(value: Box::<T>) -> Nullable::<T> nopanic;
-extern fn from_nullable<T>(value: Nullable::<T>) -> FromNullableResult::<T> nopanic;
+pub extern fn null<T>() -> Nullable::<T> nopanic;
+pub extern fn into_nullable<T>(value: Box::<T>) -> Nullable::<T> nopanic;
+pub extern fn from_nullable<T>(value: Nullable::<T>) -> FromNullableResult::<T> nopanic;
@@ -1 +1 @@
-enum Option<T> { Some: T, None: (), }
+pub enum Option<T> { Some: T, None: (), }
@@ -1 +1 @@
-enum Result<T, E> { Ok: T, Err: E, }
+pub enum Result<T, E> { Ok: T, Err: E, }
@@ -1,12 +1,12 @@
-fn serialize_felt(ref serialized: Array::<felt>, input: felt) {
-    array_append::<felt>(serialized, input);
+pub fn serialize_felt(ref serialized: Array::<felt>, input: felt) {
+    array_append(serialized, input);
 }
 
-fn deserialize_felt(ref serialized: Array::<felt>) -> Option::<felt> {
-    array_pop_front::<felt>(serialized)
+pub fn deserialize_felt(ref serialized: Array::<felt>) -> Option::<felt> {
+    array_pop_front(serialized)
 }
 
-fn serialize_bool(ref serialized: Array::<felt>, input: bool) {
+pub fn serialize_bool(ref serialized: Array::<felt>, input: bool) {
         serialize_felt(serialized, if input {
             1
         } else {
@@ -14,41 +14,49 @@ fn serialize_bool(ref serialized: Array::<felt>, input: bool) {
     });
 }
 
-fn deserialize_bool(ref serialized: Array::<felt>) -> Option::<bool> {
+pub fn deserialize_bool(ref serialized: Array::<felt>) -> Option::<bool> {
     Option::<bool>::Some(deserialize_felt(serialized)? != 0)
 }
 
-fn serialize_u128(ref serialized: Array::<felt>, input: u128) {
+pub fn serialize_u128(ref serialized: Array::<felt>, input: u128) {
     serialize_felt(serialized, u128_to_felt(input));
 }
 
-fn deserialize_u128(ref serialized: Array::<felt>) -> Option::<u128> {
+pub fn deserialize_u128(ref serialized: Array::<felt>) -> Option::<u128> {
     u128_try_from_felt(deserialize_felt(serialized)?)
 }
 
-fn serialize_u256(ref serialized: Array::<felt>, input: u256) {
+pub fn serialize_u256(ref serialized: Array::<felt>, input: u256) {
     serialize_u128(serialized, input.low);
     serialize_u128(serialized, input.high);
 }
 
-fn deserialize_u256(ref serialized: Array::<felt>) -> Option::<u256> {
+pub fn deserialize_u256(ref serialized: Array::<felt>) -> Option::<u256> {
     Option::<u256>::Some(
         u256 { low: deserialize_u128(serialized)?, high: deserialize_u128(serialized)?, }
     )
 }
 
-fn serialize_array_felt_helper(ref serialized: Array::<felt>, ref input: Array::<felt>) {
+pub fn serialize_contract_address(ref serialized: Array::<felt>, input: ContractAddress) {
+    serialize_felt(serialized, starknet::contract_address_to_felt(input));
+}
+
+pub fn deserialize_contract_address(ref serialized: Array::<felt>) -> Option::<ContractAddress> {
+    starknet::contract_address_try_from_felt(deserialize_felt(serialized)?)
+}
+
+pub fn serialize_array_felt_helper(ref serialized: Array::<felt>, ref input: Array::<felt>) {
     // TODO(orizi): Replace with simple call once inlining is supported.
     match get_gas() {
         Option::Some(_) => {
         },
         Option::None(_) => {
-            let mut data = array_new::<felt>();
-            array_append::<felt>(data, 'Out of gas');
+            let mut data = array_new();
+            array_append(data, 'Out of gas');
             panic(data);
         },
     }
-    match array_pop_front::<felt>(input) {
+    match array_pop_front(input) {
         Option::Some(value) => {
             serialize_felt(serialized, value);
             serialize_array_felt_helper(serialized, input);
@@ -58,12 +66,12 @@ fn serialize_array_felt_helper(ref serialized: Array::<felt>, ref input: Array::
     }
 }
 
-fn serialize_array_felt(ref serialized: Array::<felt>, mut input: Array::<felt>) {
-    serialize_u128(serialized, array_len::<felt>(input))
+pub fn serialize_array_felt(ref serialized: Array::<felt>, mut input: Array::<felt>) {
+    serialize_u128(serialized, array_len(input))
     serialize_array_felt_helper(serialized, input);
 }
 
-fn deserialize_array_felt_helper(
+pub fn deserialize_array_felt_helper(
     ref serialized: Array::<felt>, mut curr_output: Array::<felt>, remaining: felt
 ) -> Option::<Array::<felt>> {
     // TODO(orizi): Replace with simple call once inlining is supported.
@@ -71,8 +79,8 @@ fn deserialize_array_felt_helper(
         Option::Some(_) => {
         },
         Option::None(_) => {
-            let mut data = array_new::<felt>();
-            array_append::<felt>(data, 'Out of gas');
+            let mut data = array_new();
+            array_append(data, 'Out of gas');
             panic(data);
         },
     }
@@ -80,12 +88,116 @@ fn deserialize_array_felt_helper(
         return Option::<Array::<felt>>::Some(curr_output);
     }
     let value = deserialize_felt(serialized)?;
-    array_append::<felt>(curr_output, value);
+    array_append(curr_output, value);
     deserialize_array_felt_helper(serialized, curr_output, remaining - 1)
 }
 
-fn deserialize_array_felt(ref serialized: Array::<felt>) -> Option::<Array::<felt>> {
+pub fn deserialize_array_felt(ref serialized: Array::<felt>) -> Option::<Array::<felt>> {
     let length = deserialize_felt(serialized)?;
-    let mut arr = array_new::<felt>();
+    let mut arr = array_new();
     deserialize_array_felt_helper(serialized, arr, length)
 }
+
+pub fn serialize_byte_array(ref serialized: Array::<felt>, input: ByteArray) {
+    let ByteArray{data, pending_word, pending_word_len } = input;
+    serialize_array_felt(serialized, data);
+    serialize_felt(serialized, pending_word);
+    serialize_u128(serialized, pending_word_len);
+}
+
+pub fn deserialize_byte_array(ref serialized: Array::<felt>) -> Option::<ByteArray> {
+    let data = deserialize_array_felt(serialized)?;
+    let pending_word = deserialize_felt(serialized)?;
+    let pending_word_len = deserialize_u128(serialized)?;
+    Option::<ByteArray>::Some(ByteArray { data, pending_word, pending_word_len })
+}
+
+// Serializes values of type `T` to felts. Takes the value by reference, so that it can still be
+// used after it is serialized (e.g. compared by `assert_eq`).
+pub trait Serialize<T> {
+    fn serialize(ref serialized: Array::<felt>, ref input: T);
+}
+
+pub impl FeltSerialize of Serialize::<felt> {
+    fn serialize(ref serialized: Array::<felt>, ref input: felt) {
+        serialize_felt(serialized, input);
+    }
+}
+
+pub impl BoolSerialize of Serialize::<bool> {
+    fn serialize(ref serialized: Array::<felt>, ref input: bool) {
+        serialize_bool(serialized, input);
+    }
+}
+
+pub impl U8Serialize of Serialize::<u8> {
+    fn serialize(ref serialized: Array::<felt>, ref input: u8) {
+        serialize_felt(serialized, input.into());
+    }
+}
+
+pub impl U16Serialize of Serialize::<u16> {
+    fn serialize(ref serialized: Array::<felt>, ref input: u16) {
+        serialize_felt(serialized, input.into());
+    }
+}
+
+pub impl U32Serialize of Serialize::<u32> {
+    fn serialize(ref serialized: Array::<felt>, ref input: u32) {
+        serialize_felt(serialized, input.into());
+    }
+}
+
+pub impl U64Serialize of Serialize::<u64> {
+    fn serialize(ref serialized: Array::<felt>, ref input: u64) {
+        serialize_felt(serialized, input.into());
+    }
+}
+
+pub impl U128Serialize of Serialize::<u128> {
+    fn serialize(ref serialized: Array::<felt>, ref input: u128) {
+        serialize_u128(serialized, input);
+    }
+}
+
+pub impl U256Serialize of Serialize::<u256> {
+    fn serialize(ref serialized: Array::<felt>, ref input: u256) {
+        serialize_u256(serialized, input);
+    }
+}
+
+pub impl I8Serialize of Serialize::<i8> {
+    fn serialize(ref serialized: Array::<felt>, ref input: i8) {
+        serialize_felt(serialized, input.into());
+    }
+}
+
+pub impl I16Serialize of Serialize::<i16> {
+    fn serialize(ref serialized: Array::<felt>, ref input: i16) {
+        serialize_felt(serialized, input.into());
+    }
+}
+
+pub impl I32Serialize of Serialize::<i32> {
+    fn serialize(ref serialized: Array::<felt>, ref input: i32) {
+        serialize_felt(serialized, input.into());
+    }
+}
+
+pub impl I64Serialize of Serialize::<i64> {
+    fn serialize(ref serialized: Array::<felt>, ref input: i64) {
+        serialize_felt(serialized, input.into());
+    }
+}
+
+pub impl I128Serialize of Serialize::<i128> {
+    fn serialize(ref serialized: Array::<felt>, ref input: i128) {
+        serialize_felt(serialized, input.into());
+    }
+}
+
+pub impl ContractAddressSerialize of Serialize::<ContractAddress> {
+    fn serialize(ref serialized: Array::<felt>, ref input: ContractAddress) {
+        serialize_felt(serialized, starknet::contract_address_to_felt(input));
+    }
+}
@@ -1,28 +1,230 @@
-extern type System;
-extern type StorageAddress;
-extern type ContractAddress;
+pub extern type System;
+pub extern type StorageAddress;
+pub impl StorageAddressCopy of Copy::<StorageAddress>;
+pub impl StorageAddressDrop of Drop::<StorageAddress>;
+// Represents a base address of a storage variable; the variable's values are stored at this
+// address and at the consecutive addresses that follow it.
+pub extern type StorageBaseAddress;
+pub impl StorageBaseAddressCopy of Copy::<StorageBaseAddress>;
+pub impl StorageBaseAddressDrop of Drop::<StorageBaseAddress>;
+pub extern type ContractAddress;
+pub impl ContractAddressCopy of Copy::<ContractAddress>;
+pub impl ContractAddressDrop of Drop::<ContractAddress>;
+// A mapping from keys of type `K` to values of type `V`, usable only as the type of a member of the
+// `Storage` struct of a contract. The value of `key` is stored starting at the base address
+// `pedersen(sn_keccak(member_name), key)`, where keys that are tuples are hashed element by
+// element.
+pub extern type LegacyMap<K, V>;
 
 // An Helper function to force the inclusion of `System` in the list of implicits.
-fn use_system_implicit() implicits(System) {
+pub fn use_system_implicit() implicits(System) {
 }
 
 // Storage.
-extern fn storage_address_const<address>() -> StorageAddress nopanic;
+pub extern fn storage_address_const<address>() -> StorageAddress nopanic;
+pub extern fn storage_base_address_const<address>() -> StorageBaseAddress nopanic;
+pub extern fn storage_base_address_from_felt(
+    addr: felt
+) -> StorageBaseAddress implicits(RangeCheck) nopanic;
+pub extern fn storage_address_from_base(base: StorageBaseAddress) -> StorageAddress nopanic;
+pub extern fn storage_address_from_base_and_offset(
+    base: StorageBaseAddress, offset: u8
+) -> StorageAddress nopanic;
 
 // Only address_domain 0 is currently supported.
 // This parameter is going to be used to access address spaces with different
 // data availability guarantees.
-extern fn storage_read_syscall(
+pub extern fn storage_read_syscall(
     address_domain: felt, address: StorageAddress,
 ) -> Result::<felt, felt> implicits(GasBuiltin, System) nopanic;
-extern fn storage_write_syscall(
+pub extern fn storage_write_syscall(
     address_domain: felt, address: StorageAddress, value: felt
 ) -> Result::<(), felt> implicits(GasBuiltin, System) nopanic;
 
+// Events.
+// Emits an event with the given keys and data. The keys are used for filtering the events.
+pub extern fn emit_event_syscall(
+    keys: Array::<felt>, data: Array::<felt>
+) -> Result::<(), felt> implicits(GasBuiltin, System) nopanic;
+
+// Execution info.
+pub extern fn get_caller_address_syscall() -> Result::<ContractAddress,
+felt> implicits(GasBuiltin, System) nopanic;
+pub extern fn get_contract_address_syscall() -> Result::<ContractAddress,
+felt> implicits(GasBuiltin, System) nopanic;
+pub extern fn get_block_number_syscall() -> Result::<u64,
+felt> implicits(GasBuiltin, System) nopanic;
+pub extern fn get_block_timestamp_syscall() -> Result::<u64,
+felt> implicits(GasBuiltin, System) nopanic;
+// Returns the account contract address, max fee, signature, transaction hash, chain id and nonce of
+// the current transaction.
+pub extern fn get_tx_info_syscall() -> Result::<(
+    ContractAddress, u128, Array::<felt>, felt, felt, felt
+),
+felt> implicits(GasBuiltin, System) nopanic;
+
+// The info of the current transaction.
+pub struct TxInfo {
+    pub account_contract_address: ContractAddress,
+    pub max_fee: u128,
+    pub signature: Array::<felt>,
+    pub transaction_hash: felt,
+    pub chain_id: felt,
+    pub nonce: felt,
+}
+
+// Returns the address of the contract that called the current contract.
+pub fn get_caller_address() -> ContractAddress {
+    match get_caller_address_syscall() {
+        Result::Ok(address) => address,
+        Result::Err(revert_reason) => {
+            let mut err_data = array_new::<felt>();
+            array_append::<felt>(err_data, revert_reason);
+            panic(err_data)
+        },
+    }
+}
+
+// Returns the address of the current contract.
+pub fn get_contract_address() -> ContractAddress {
+    match get_contract_address_syscall() {
+        Result::Ok(address) => address,
+        Result::Err(revert_reason) => {
+            let mut err_data = array_new::<felt>();
+            array_append::<felt>(err_data, revert_reason);
+            panic(err_data)
+        },
+    }
+}
+
+// Returns the number of the current block.
+pub fn get_block_number() -> u64 {
+    match get_block_number_syscall() {
+        Result::Ok(block_number) => block_number,
+        Result::Err(revert_reason) => {
+            let mut err_data = array_new::<felt>();
+            array_append::<felt>(err_data, revert_reason);
+            panic(err_data)
+        },
+    }
+}
+
+// Returns the timestamp of the current block.
+pub fn get_block_timestamp() -> u64 {
+    match get_block_timestamp_syscall() {
+        Result::Ok(block_timestamp) => block_timestamp,
+        Result::Err(revert_reason) => {
+            let mut err_data = array_new::<felt>();
+            array_append::<felt>(err_data, revert_reason);
+            panic(err_data)
+        },
+    }
+}
+
+// Returns the info of the current transaction.
+pub fn get_tx_info() -> TxInfo {
+    match get_tx_info_syscall() {
+        Result::Ok((
+            account_contract_address, max_fee, signature, transaction_hash, chain_id, nonce
+        )) => {
+            TxInfo {
+                account_contract_address, max_fee, signature, transaction_hash, chain_id, nonce
+            }
+        },
+        Result::Err(revert_reason) => {
+            let mut err_data = array_new::<felt>();
+            array_append::<felt>(err_data, revert_reason);
+            panic(err_data)
+        },
+    }
+}
+
+// Reads and writes values of type `T` that are stored starting at a base address.
+// Values that do not fit in a single felt are spread over consecutive storage addresses.
+pub trait StorageAccess<T> {
+    fn read(address_domain: felt, base: StorageBaseAddress) -> Result::<T, felt>;
+    fn write(address_domain: felt, base: StorageBaseAddress, value: T) -> Result::<(), felt>;
+}
+
+impl StorageAccessFelt of StorageAccess::<felt> {
+    fn read(address_domain: felt, base: StorageBaseAddress) -> Result::<felt, felt> {
+        storage_read_syscall(address_domain, storage_address_from_base(base))
+    }
+    fn write(address_domain: felt, base: StorageBaseAddress, value: felt) -> Result::<(), felt> {
+        storage_write_syscall(address_domain, storage_address_from_base(base), value)
+    }
+}
+
+impl StorageAccessBool of StorageAccess::<bool> {
+    fn read(address_domain: felt, base: StorageBaseAddress) -> Result::<bool, felt> {
+        Result::Ok(StorageAccess::<felt>::read(address_domain, base)? != 0)
+    }
+    fn write(address_domain: felt, base: StorageBaseAddress, value: bool) -> Result::<(), felt> {
+        StorageAccess::<felt>::write(address_domain, base, value.into())
+    }
+}
+
+impl StorageAccessU128 of StorageAccess::<u128> {
+    fn read(address_domain: felt, base: StorageBaseAddress) -> Result::<u128, felt> {
+        let value: Option::<u128> = StorageAccess::<felt>::read(address_domain, base)?.try_into();
+        match value {
+            Option::Some(x) => Result::Ok(x),
+            Option::None(()) => Result::Err('StorageAccessU128 - non u128'),
+        }
+    }
+    fn write(address_domain: felt, base: StorageBaseAddress, value: u128) -> Result::<(), felt> {
+        StorageAccess::<felt>::write(address_domain, base, value.into())
+    }
+}
+
+impl StorageAccessU256 of StorageAccess::<u256> {
+    fn read(address_domain: felt, base: StorageBaseAddress) -> Result::<u256, felt> {
+        let low = StorageAccess::<u128>::read(address_domain, base)?;
+        let high_address = storage_address_from_base_and_offset(base, 1_u8);
+        let high: Option::<u128> = storage_read_syscall(address_domain, high_address)?.try_into();
+        let high = match high {
+            Option::Some(x) => x,
+            Option::None(()) => {
+                return Result::Err('StorageAccessU256 - non u256');
+            },
+        };
+        Result::Ok(u256 { low, high })
+    }
+    fn write(address_domain: felt, base: StorageBaseAddress, value: u256) -> Result::<(), felt> {
+        StorageAccess::<u128>::write(address_domain, base, value.low)?;
+        let high_address = storage_address_from_base_and_offset(base, 1_u8);
+        storage_write_syscall(address_domain, high_address, value.high.into())
+    }
+}
+
+impl StorageAccessContractAddress of StorageAccess::<ContractAddress> {
+    fn read(address_domain: felt, base: StorageBaseAddress) -> Result::<ContractAddress, felt> {
+        match contract_address_try_from_felt(StorageAccess::<felt>::read(address_domain, base)?) {
+            Option::Some(x) => Result::Ok(x),
+            Option::None(()) => Result::Err('StorageAccess - non address'),
+        }
+    }
+    fn write(
+        address_domain: felt, base: StorageBaseAddress, value: ContractAddress
+    ) -> Result::<(), felt> {
+        StorageAccess::<felt>::write(address_domain, base, contract_address_to_felt(value))
+    }
+}
+
+impl LegacyHashContractAddress of LegacyHash::<ContractAddress> {
+    fn hash(state: felt, value: ContractAddress) -> felt {
+        LegacyHash::<felt>::hash(state, contract_address_to_felt(value))
+    }
+}
+
 // Interoperability.
-type CallContractResult = Result::<Array::<felt>,
+pub extern fn contract_address_try_from_felt(
+    address: felt
+) -> Option::<ContractAddress> implicits(RangeCheck) nopanic;
+pub extern fn contract_address_to_felt(address: ContractAddress) -> felt nopanic;
+pub type CallContractResult = Result::<Array::<felt>,
 (
 felt, Array::<felt>
-)>; extern fn call_contract_syscall(
+)>; pub extern fn call_contract_syscall(
     address: ContractAddress, calldata: Array::<felt>
 ) -> CallContractResult implicits(GasBuiltin, System) nopanic;
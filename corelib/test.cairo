@@ -9,6 +9,47 @@ fn test_assert_true() {
     assert(true, 'assert(true)');
 }
 
+#[test]
+#[should_panic]
+fn test_assert_eq_different() {
+    assert_eq(1, 2, 'assert_eq(1, 2)');
+}
+
+#[test]
+fn test_assert_eq_equal() {
+    assert_eq(1 + 1, 2, 'assert_eq(1 + 1, 2)');
+}
+
+#[test]
+fn test_assert_eq_u256_equal() {
+    assert_eq(u256 { low: 1_u128, high: 2_u128 }, u256 { low: 1_u128, high: 2_u128 }, 'u256 eq');
+}
+
+#[test]
+#[should_panic]
+fn test_assert_eq_u256_different() {
+    assert_eq(u256 { low: 1_u128, high: 2_u128 }, u256 { low: 1_u128, high: 3_u128 }, 'u256 ne');
+}
+
+#[test]
+fn test_assert_eq_small_integers_equal() {
+    assert_eq(1_u8, 1_u8, 'u8 eq');
+    assert_eq(2_u16, 2_u16, 'u16 eq');
+    assert_eq(3_u32, 3_u32, 'u32 eq');
+    assert_eq(4_u64, 4_u64, 'u64 eq');
+    assert_eq(-1_i8, -1_i8, 'i8 eq');
+    assert_eq(-2_i16, -2_i16, 'i16 eq');
+    assert_eq(-3_i32, -3_i32, 'i32 eq');
+    assert_eq(-4_i64, -4_i64, 'i64 eq');
+    assert_eq(-5_i128, -5_i128, 'i128 eq');
+}
+
+#[test]
+#[should_panic]
+fn test_assert_eq_i8_different() {
+    assert_eq(-1_i8, 1_i8, 'i8 ne');
+}
+
 #[test]
 fn test_bool_operators() {
     assert(true == true, 't == t');
@@ -95,6 +136,46 @@ fn test_u128_operators() {
     assert((2_u128 & 2_u128) == 2_u128, '2 & 2 == 2');
     assert((2_u128 & 3_u128) == 2_u128, '2 & 3 == 2');
     assert((3_u128 ^ 6_u128) == 5_u128, '3 ^ 6 == 5');
+    assert(1_u128 << 0_u128 == 1_u128, '1 << 0 == 1');
+    assert(3_u128 << 2_u128 == 12_u128, '3 << 2 == 12');
+    assert(3_u128 << 127_u128 == pow_2_127(), '3 << 127 == 2**127');
+    assert(1_u128 << 128_u128 == 0_u128, '1 << 128 == 0');
+    assert(12_u128 >> 2_u128 == 3_u128, '12 >> 2 == 3');
+    assert(pow_2_127() >> 127_u128 == 1_u128, '2**127 >> 127 == 1');
+    assert(pow_2_127() >> 128_u128 == 0_u128, '2**127 >> 128 == 0');
+}
+
+#[test]
+fn test_compound_assignment() {
+    let mut a = 5;
+    a += 3;
+    a -= 1;
+    a *= 2;
+    assert(a == 14, 'a == 14');
+    let mut b = 0x1f_u128;
+    b /= 2_u128;
+    assert(b == 15_u128, 'b == 15');
+    b %= 4_u128;
+    assert(b == 3_u128, 'b == 3');
+    b &= 6_u128;
+    assert(b == 2_u128, 'b == 2');
+    b |= 5_u128;
+    assert(b == 7_u128, 'b == 7');
+    b ^= 12_u128;
+    assert(b == 11_u128, 'b == 11');
+}
+
+#[test]
+fn test_member_and_tuple_item_assignment() {
+    let mut t = (1, (2_u128, 3));
+    t.0 = 4;
+    t.1.0 += 5_u128;
+    assert(t.0 == 4, 't.0 == 4');
+    assert(t.1.0 == 7_u128, 't.1.0 == 7');
+    assert(t.1.1 == 3, 't.1.1 == 3');
+    let mut x = u256 { low: 1_u128, high: 2_u128 };
+    x.high = 3_u128;
+    assert(x == u256 { low: 1_u128, high: 3_u128 }, 'x.high == 3');
 }
 
 fn pow_2_127() -> u128 {
@@ -171,6 +252,222 @@ fn test_u128_mod_by_0() {
     2_u128 % 0_u128;
 }
 
+#[test]
+fn test_u16_operators() {
+    assert(65534_u16 + 1_u16 == 65535_u16, 'u16 max - 1 + 1 == max');
+    assert(65535_u16 - 65535_u16 == 0_u16, 'u16 max - max == 0');
+    let wrapped_add = match integer::u16_overflow_add(65535_u16, 1_u16) {
+        Result::Ok(_) => false,
+        Result::Err(x) => x == 0_u16,
+    };
+    assert(wrapped_add, 'u16 max + 1 wraps to 0');
+    let wrapped_sub = match integer::u16_overflow_sub(0_u16, 1_u16) {
+        Result::Ok(_) => false,
+        Result::Err(x) => x == 65535_u16,
+    };
+    assert(wrapped_sub, 'u16 0 - 1 wraps to max');
+    let max = 65535_u16;
+    assert(max / 256_u16 == 255_u16, 'u16 max / 256 == 255');
+    assert(max % 256_u16 == 255_u16, 'u16 max % 256 == 255');
+    let in_range: Option::<u16> = 65535.try_into();
+    let in_range_ok = match in_range {
+        Option::Some(x) => x == 65535_u16,
+        Option::None(()) => false,
+    };
+    assert(in_range_ok, 'felt try_into u16 max');
+    let out_of_range: Option::<u16> = 65536.try_into();
+    let out_of_range_ok = match out_of_range {
+        Option::Some(_) => false,
+        Option::None(()) => true,
+    };
+    assert(out_of_range_ok, 'felt try_into u16 2**16 OF');
+}
+
+#[test]
+#[should_panic]
+fn test_u16_add_overflow() {
+    65535_u16 + 1_u16;
+}
+
+#[test]
+#[should_panic]
+fn test_u16_sub_overflow() {
+    0_u16 - 1_u16;
+}
+
+#[test]
+fn test_u32_operators() {
+    assert(4294967294_u32 + 1_u32 == 4294967295_u32, 'u32 max - 1 + 1 == max');
+    assert(4294967295_u32 - 4294967295_u32 == 0_u32, 'u32 max - max == 0');
+    let wrapped_add = match integer::u32_overflow_add(4294967295_u32, 1_u32) {
+        Result::Ok(_) => false,
+        Result::Err(x) => x == 0_u32,
+    };
+    assert(wrapped_add, 'u32 max + 1 wraps to 0');
+    let wrapped_sub = match integer::u32_overflow_sub(0_u32, 1_u32) {
+        Result::Ok(_) => false,
+        Result::Err(x) => x == 4294967295_u32,
+    };
+    assert(wrapped_sub, 'u32 0 - 1 wraps to max');
+    let max = 4294967295_u32;
+    assert(max / 65536_u32 == 65535_u32, 'u32 max / 65536 == 65535');
+    assert(max % 65536_u32 == 65535_u32, 'u32 max % 65536 == 65535');
+    let in_range: Option::<u32> = 4294967295.try_into();
+    let in_range_ok = match in_range {
+        Option::Some(x) => x == 4294967295_u32,
+        Option::None(()) => false,
+    };
+    assert(in_range_ok, 'felt try_into u32 max');
+    let out_of_range: Option::<u32> = 4294967296.try_into();
+    let out_of_range_ok = match out_of_range {
+        Option::Some(_) => false,
+        Option::None(()) => true,
+    };
+    assert(out_of_range_ok, 'felt try_into u32 2**32 OF');
+}
+
+#[test]
+#[should_panic]
+fn test_u32_add_overflow() {
+    4294967295_u32 + 1_u32;
+}
+
+#[test]
+#[should_panic]
+fn test_u32_sub_overflow() {
+    0_u32 - 1_u32;
+}
+
+#[test]
+fn test_u64_operators() {
+    assert(18446744073709551614_u64 + 1_u64 == 18446744073709551615_u64, 'u64 max - 1 + 1 == max');
+    assert(18446744073709551615_u64 - 18446744073709551615_u64 == 0_u64, 'u64 max - max == 0');
+    let wrapped_add = match integer::u64_overflow_add(18446744073709551615_u64, 1_u64) {
+        Result::Ok(_) => false,
+        Result::Err(x) => x == 0_u64,
+    };
+    assert(wrapped_add, 'u64 max + 1 wraps to 0');
+    let wrapped_sub = match integer::u64_overflow_sub(0_u64, 1_u64) {
+        Result::Ok(_) => false,
+        Result::Err(x) => x == 18446744073709551615_u64,
+    };
+    assert(wrapped_sub, 'u64 0 - 1 wraps to max');
+    let max = 18446744073709551615_u64;
+    assert(max / 4294967296_u64 == 4294967295_u64, 'u64 max / 2**32 == 2**32 - 1');
+    assert(max % 4294967296_u64 == 4294967295_u64, 'u64 max % 2**32 == 2**32 - 1');
+    let in_range: Option::<u64> = 18446744073709551615.try_into();
+    let in_range_ok = match in_range {
+        Option::Some(x) => x == 18446744073709551615_u64,
+        Option::None(()) => false,
+    };
+    assert(in_range_ok, 'felt try_into u64 max');
+    let out_of_range: Option::<u64> = 18446744073709551616.try_into();
+    let out_of_range_ok = match out_of_range {
+        Option::Some(_) => false,
+        Option::None(()) => true,
+    };
+    assert(out_of_range_ok, 'felt try_into u64 2**64 OF');
+}
+
+#[test]
+#[should_panic]
+fn test_u64_add_overflow() {
+    18446744073709551615_u64 + 1_u64;
+}
+
+#[test]
+#[should_panic]
+fn test_u64_sub_overflow() {
+    0_u64 - 1_u64;
+}
+
+#[test]
+fn test_i8_operators() {
+    let i8_min = (-127_i8) - 1_i8;
+    assert((-7_i8) / 2_i8 == -3_i8, '-7 / 2 == -3');
+    assert((-7_i8) / (-2_i8) == 3_i8, '-7 / -2 == 3');
+    assert((-7_i8) % 2_i8 == -1_i8, '-7 % 2 == -1');
+    assert(7_i8 % (-2_i8) == 1_i8, '7 % -2 == 1');
+    assert(i8_min % (-1_i8) == 0_i8, 'i8 min % -1 == 0');
+    let min_div_minus_one = match integer::i8_checked_div(i8_min, -1_i8) {
+        Option::Some(_) => false,
+        Option::None(()) => true,
+    };
+    assert(min_div_minus_one, 'i8 min / -1 OF');
+    let neg_min = match integer::i8_checked_neg(i8_min) {
+        Option::Some(_) => false,
+        Option::None(()) => true,
+    };
+    assert(neg_min, '-(i8 min) OF');
+    let from_min: Option::<i8> = (-128).try_into();
+    let from_min_ok = match from_min {
+        Option::Some(x) => x == i8_min,
+        Option::None(()) => false,
+    };
+    assert(from_min_ok, 'felt try_into i8 min');
+    let below_min: Option::<i8> = (-129).try_into();
+    let below_min_ok = match below_min {
+        Option::Some(_) => false,
+        Option::None(()) => true,
+    };
+    assert(below_min_ok, 'felt try_into i8 min - 1 OF');
+    let above_max: Option::<i8> = 128.try_into();
+    let above_max_ok = match above_max {
+        Option::Some(_) => false,
+        Option::None(()) => true,
+    };
+    assert(above_max_ok, 'felt try_into i8 max + 1 OF');
+}
+
+#[test]
+#[should_panic]
+fn test_i8_div_overflow() {
+    ((-127_i8) - 1_i8) / (-1_i8);
+}
+
+#[test]
+#[should_panic]
+fn test_i8_add_overflow() {
+    127_i8 + 1_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_div_by_0() {
+    1_i8 / 0_i8;
+}
+
+#[test]
+fn test_i128_operators() {
+    let pow_2_126 = 0x40000000000000000000000000000000_i128;
+    let i128_min = (-pow_2_126) * 2_i128;
+    assert(i128_min + pow_2_126 == -pow_2_126, 'i128 min + 2**126 == -2**126');
+    assert((-pow_2_126) * (-1_i128) == pow_2_126, '-2**126 * -1 == 2**126');
+    let mul_overflow = match integer::i128_checked_mul(pow_2_126, 2_i128) {
+        Option::Some(_) => false,
+        Option::None(()) => true,
+    };
+    assert(mul_overflow, '2**126 * 2 OF');
+    let neg_mul_overflow = match integer::i128_checked_mul(i128_min, -1_i128) {
+        Option::Some(_) => false,
+        Option::None(()) => true,
+    };
+    assert(neg_mul_overflow, 'i128 min * -1 OF');
+    let from_negative: Option::<i128> = (-5).try_into();
+    let from_negative_ok = match from_negative {
+        Option::Some(x) => x == -5_i128,
+        Option::None(()) => false,
+    };
+    assert(from_negative_ok, 'felt try_into i128 -5');
+    assert((-7_i128) % 3_i128 == -1_i128, '-7 % 3 == -1');
+}
+
+#[test]
+#[should_panic]
+fn test_i128_mul_overflow() {
+    0x40000000000000000000000000000000_i128 * 2_i128;
+}
+
 // TODO(orizi): Remove when u256 literals are supported.
 fn as_u256(high: u128, low: u128) -> u256 {
     u256 { low, high }
@@ -185,6 +482,117 @@ fn test_u256_from_felt() {
     );
 }
 
+#[test]
+fn test_into() {
+    let felt_from_u8: felt = 3_u8.into();
+    assert(felt_from_u8 == 3, 'u8 into felt');
+    let felt_from_bool: felt = true.into();
+    assert(felt_from_bool == 1, 'bool into felt');
+    let u128_from_u8: u128 = 5_u8.into();
+    assert(u128_from_u8 == 5_u128, 'u8 into u128');
+    let u256_from_u128: u256 = 0xffffffffffffffffffffffffffffffff_u128.into();
+    assert(
+        u256_from_u128 == as_u256(0_u128, 0xffffffffffffffffffffffffffffffff_u128),
+        'u128 into u256'
+    );
+    let u256_from_felt: u256 = (170141183460469231731687303715884105728 * 2).into();
+    assert(u256_from_felt == as_u256(1_u128, 0_u128), 'felt into u256');
+    let i16_from_u8: i16 = 200_u8.into();
+    assert(i16_from_u8 == 200_i16, 'u8 into i16');
+}
+
+#[test]
+fn test_try_into() {
+    let u8_in_range: Option::<u8> = 255.try_into();
+    assert(
+        match u8_in_range {
+            Option::Some(x) => u8_to_felt(x) == 255,
+            Option::None(()) => false,
+        },
+        'felt try_into u8'
+    );
+    let u8_out_of_range: Option::<u8> = 256.try_into();
+    assert(
+        match u8_out_of_range {
+            Option::Some(_) => false,
+            Option::None(()) => true,
+        },
+        'felt try_into u8 OF'
+    );
+    let u16_from_u64: Option::<u16> = 1000_u64.try_into();
+    assert(
+        match u16_from_u64 {
+            Option::Some(x) => u16_to_felt(x) == 1000,
+            Option::None(()) => false,
+        },
+        'u64 try_into u16'
+    );
+    let u128_from_u256: Option::<u128> = as_u256(1_u128, 0_u128).try_into();
+    assert(
+        match u128_from_u256 {
+            Option::Some(_) => false,
+            Option::None(()) => true,
+        },
+        'u256 try_into u128 OF'
+    );
+    let felt_from_u256: Option::<felt> = as_u256(0_u128, 7_u128).try_into();
+    assert(
+        match felt_from_u256 {
+            Option::Some(x) => x == 7,
+            Option::None(()) => false,
+        },
+        'u256 try_into felt'
+    );
+    let i8_from_u8: Option::<i8> = 200_u8.try_into();
+    assert(
+        match i8_from_u8 {
+            Option::Some(_) => false,
+            Option::None(()) => true,
+        },
+        'u8 try_into i8 OF'
+    );
+    let u8_from_i8: Option::<u8> = 5_i8.try_into();
+    assert(
+        match u8_from_i8 {
+            Option::Some(x) => x == 5_u8,
+            Option::None(()) => false,
+        },
+        'i8 try_into u8'
+    );
+    let u64_from_negative: Option::<u64> = (-1_i32).try_into();
+    assert(
+        match u64_from_negative {
+            Option::Some(_) => false,
+            Option::None(()) => true,
+        },
+        'negative i32 try_into u64'
+    );
+    let u256_from_negative: Option::<u256> = (-1_i8).try_into();
+    assert(
+        match u256_from_negative {
+            Option::Some(_) => false,
+            Option::None(()) => true,
+        },
+        'negative i8 try_into u256'
+    );
+    let bool_from_one: Option::<bool> = 1.try_into();
+    assert(
+        match bool_from_one {
+            Option::Some(x) => x,
+            Option::None(()) => false,
+        },
+        'felt try_into bool'
+    );
+    let bool_from_two: Option::<bool> = 2.try_into();
+    assert(
+        match bool_from_two {
+            Option::Some(_) => false,
+            Option::None(()) => true,
+        },
+        'felt try_into bool OF'
+    );
+}
+
 // TODO(orizi): Use u256 literals when supported.
 #[test]
 fn test_u256_operators() {
@@ -318,20 +726,107 @@ fn test_u256_mul_overflow_2() {
     as_u256(0_u128, pow_2_127()) * as_u256(2_u128, 0_u128);
 }
 
-// TODO(orizi): Switch to operators and literals when added.
+#[test]
+fn test_u256_div_mod() {
+    let max_u128 = 0xffffffffffffffffffffffffffffffff_u128;
+    assert(as_u256(0_u128, 7_u128) / as_u256(0_u128, 3_u128) == as_u256(0_u128, 2_u128), '7 / 3');
+    assert(as_u256(0_u128, 7_u128) % as_u256(0_u128, 3_u128) == as_u256(0_u128, 1_u128), '7 % 3');
+    assert(
+        as_u256(5_u128, 3_u128) / as_u256(0_u128, 2_u128) == as_u256(2_u128, pow_2_127() + 1_u128),
+        '5.3 / 2'
+    );
+    assert(as_u256(5_u128, 3_u128) % as_u256(0_u128, 2_u128) == as_u256(0_u128, 1_u128), '5.3 % 2');
+    assert(
+        as_u256(5_u128, 3_u128) / as_u256(2_u128, 0_u128) == as_u256(0_u128, 2_u128),
+        '5.3 / 2.0'
+    );
+    assert(
+        as_u256(5_u128, 3_u128) % as_u256(2_u128, 0_u128) == as_u256(1_u128, 3_u128),
+        '5.3 % 2.0'
+    );
+    assert(
+        as_u256(max_u128, max_u128) / as_u256(max_u128, max_u128) == as_u256(0_u128, 1_u128),
+        'max / max'
+    );
+    assert(
+        as_u256(max_u128, max_u128) % as_u256(0_u128, max_u128) == as_u256(0_u128, 0_u128),
+        'max % max_u128'
+    );
+    assert(
+        as_u256(1_u128, 2_u128) / as_u256(1_u128, 3_u128) == as_u256(0_u128, 0_u128),
+        '1.2 / 1.3'
+    );
+    assert(
+        as_u256(1_u128, 2_u128) % as_u256(1_u128, 3_u128) == as_u256(1_u128, 2_u128),
+        '1.2 % 1.3'
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_u256_div_by_0() {
+    as_u256(1_u128, 2_u128) / as_u256(0_u128, 0_u128);
+}
+
+#[test]
+fn test_u256_shifts() {
+    assert(
+        as_u256(1_u128, 3_u128) << as_u256(0_u128, 2_u128) == as_u256(4_u128, 12_u128),
+        '1.3 << 2'
+    );
+    assert(
+        as_u256(0_u128, pow_2_127()) << as_u256(0_u128, 1_u128) == as_u256(1_u128, 0_u128),
+        '2**127 << 1'
+    );
+    assert(
+        as_u256(0_u128, 3_u128) << as_u256(0_u128, 129_u128) == as_u256(6_u128, 0_u128),
+        '3 << 129'
+    );
+    assert(
+        as_u256(0_u128, 1_u128) << as_u256(0_u128, 256_u128) == as_u256(0_u128, 0_u128),
+        '1 << 256'
+    );
+    assert(
+        as_u256(4_u128, 12_u128) >> as_u256(0_u128, 2_u128) == as_u256(1_u128, 3_u128),
+        '4.12 >> 2'
+    );
+    assert(
+        as_u256(1_u128, 0_u128) >> as_u256(0_u128, 1_u128) == as_u256(0_u128, pow_2_127()),
+        '2**128 >> 1'
+    );
+    assert(
+        as_u256(6_u128, 0_u128) >> as_u256(0_u128, 129_u128) == as_u256(0_u128, 3_u128),
+        '6.0 >> 129'
+    );
+    assert(
+        as_u256(1_u128, 0_u128) >> as_u256(1_u128, 0_u128) == as_u256(0_u128, 0_u128),
+        '2**128 >> 2**128'
+    );
+}
+
+#[test]
+fn test_u256_wide_mul() {
+    let max_u128 = 0xffffffffffffffffffffffffffffffff_u128;
+    let r = u256_wide_mul(as_u256(2_u128, 3_u128), as_u256(5_u128, 7_u128));
+    assert(r.limb0 == 21_u128, '2.3 * 5.7 limb0');
+    assert(r.limb1 == 29_u128, '2.3 * 5.7 limb1');
+    assert(r.limb2 == 10_u128, '2.3 * 5.7 limb2');
+    assert(r.limb3 == 0_u128, '2.3 * 5.7 limb3');
+    // (2**256 - 1)**2 = 2**512 - 2**257 + 1.
+    let r = u256_wide_mul(as_u256(max_u128, max_u128), as_u256(max_u128, max_u128));
+    assert(r.limb0 == 1_u128, 'max * max limb0');
+    assert(r.limb1 == 0_u128, 'max * max limb1');
+    assert(r.limb2 == max_u128 - 1_u128, 'max * max limb2');
+    assert(r.limb3 == max_u128, 'max * max limb3');
+}
+
+// TODO: Switch to literals when added.
 fn test_array_helper(idx: u128) -> felt {
-    let mut arr = array_new::<felt>();
-    array_append::<felt>(arr, 10);
-    array_append::<felt>(arr, 11);
-    array_append::<felt>(arr, 12);
-    match array_at::<felt>(arr, idx) {
-        Option::Some(x) => x,
-        Option::None(()) => {
-            let mut data = array_new::<felt>();
-            array_append::<felt>(data, 'array index OOB');
-            panic(data)
-        },
-    }
+    let mut arr = array_new();
+    array_append(arr, 10);
+    array_append(arr, 11);
+    array_append(arr, 12);
+    arr[idx]
 }
 
 #[test]
@@ -352,3 +847,142 @@ fn test_array_out_of_bound_1() {
 fn test_array_out_of_bound_2() {
     test_array_helper(11_u128);
 }
+
+fn test_array_slice_helper(start: u128, length: u128) -> Option::<ArraySlice::<felt>> {
+    let mut arr = array_new();
+    array_append(arr, 10);
+    array_append(arr, 11);
+    array_append(arr, 12);
+    arr.slice(start, length)
+}
+
+#[test]
+fn test_array_slice() {
+    let mut slice = match test_array_slice_helper(1_u128, 2_u128) {
+        Option::Some(slice) => slice,
+        Option::None(_) => {
+            let mut data = array_new();
+            array_append(data, 'slice in bounds');
+            panic(data)
+        },
+    };
+    assert(slice.len() == 2_u128, 'slice.len() == 2');
+    let mut copy = slice;
+    match slice.pop_front() {
+        Option::Some(x) => assert(x == 11, 'slice[0] == 11'),
+        Option::None(_) => assert(false, 'slice is not empty'),
+    }
+    match slice.pop_front() {
+        Option::Some(x) => assert(x == 12, 'slice[1] == 12'),
+        Option::None(_) => assert(false, 'slice is not empty'),
+    }
+    assert(slice.len() == 0_u128, 'slice is empty');
+    assert(copy.len() == 2_u128, 'copy.len() == 2');
+}
+
+#[test]
+fn test_array_slice_out_of_bound() {
+    let in_bounds = match test_array_slice_helper(3_u128, 0_u128) {
+        Option::Some(_) => true,
+        Option::None(_) => false,
+    };
+    assert(in_bounds, 'empty slice at end');
+    let in_bounds = match test_array_slice_helper(2_u128, 2_u128) {
+        Option::Some(_) => true,
+        Option::None(_) => false,
+    };
+    assert(!in_bounds, 'slice out of bounds');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_byte_array_literals() {
+    let mut empty = "";
+    assert(empty.len() == 0_u128, 'empty.len() == 0');
+    let mut short = "hello";
+    assert(short.len() == 5_u128, 'short.len() == 5');
+    assert(u8_to_felt(short[0_u128]) == 'h', 'short[0] == h');
+    assert(u8_to_felt(short[4_u128]) == 'o', 'short[4] == o');
+    let mut long = "This string is longer than the 31 bytes of a single word.";
+    assert(long.len() == 57_u128, 'long.len() == 57');
+    assert(u8_to_felt(long[0_u128]) == 'T', 'long[0] == T');
+    assert(u8_to_felt(long[31_u128]) == '3', 'long[31] == 3');
+    assert(u8_to_felt(long[32_u128]) == '1', 'long[32] == 1');
+    assert(u8_to_felt(long[56_u128]) == '.', 'long[56] == .');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_byte_array_append() {
+    let mut s = ByteArrayTrait::new();
+    s.append_byte(97_u8);
+    s.append_word('bcd', 3_u128);
+    s.append("efghijklmnopqrstuvwxyz0123456789");
+    assert(s == "abcdefghijklmnopqrstuvwxyz0123456789", 'append');
+    let mut concat = "The quick brown fox " + "jumps over the lazy dog";
+    assert(concat.len() == 43_u128, 'concat.len() == 43');
+    assert(u8_to_felt(concat[20_u128]) == 'j', 'concat[20] == j');
+    assert(concat == "The quick brown fox jumps over the lazy dog", 'concat');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_byte_array_eq() {
+    assert("abc" == "abc", 'abc == abc');
+    assert("abc" != "abd", 'abc != abd');
+    assert("abc" != "abcd", 'abc != abcd');
+    assert(
+        "This string is longer than the 31 bytes of a single word."
+            != "This string is longer than the 31 bytes of a single word!",
+        'long strings differ'
+    );
+    assert(
+        "This string is longer than the 31 bytes of a single word."
+            == "This string is longer than the 31 bytes of a single word.",
+        'long strings are equal'
+    );
+    assert(
+        "This string is longer than the 31 bytes of a single word."
+            != "That string is longer than the 31 bytes of a single word.",
+        'full words differ'
+    );
+}
+
+#[test]
+#[available_gas(10000000)]
+#[should_panic]
+fn test_byte_array_out_of_bound() {
+    let mut s = "This string is longer than the 31 bytes of a single word.";
+    s[57_u128];
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_byte_array_serde() {
+    let mut serialized = array_new();
+    serde::serialize_byte_array(
+        serialized, "This string is longer than the 31 bytes of a single word."
+    );
+    assert(array_len(serialized) == 4_u128, 'serialized.len() == 4');
+    match serde::deserialize_byte_array(serialized) {
+        Option::Some(s) => assert(
+            s == "This string is longer than the 31 bytes of a single word.", 'serde roundtrip'
+        ),
+        Option::None(_) => assert(false, 'deserialization failed'),
+    }
+}
+
+const CONST_SUM: felt = 1 + 2 * 3 - 10;
+const CONST_U8_MAX: u8 = 255;
+const CONST_QUOTIENT: i8 = (-7) / 2;
+const CONST_REMAINDER: i8 = (-7) % 2;
+const CONST_IN_RANGE: bool = (CONST_U8_MAX > 200_u8) & !(CONST_SUM == 0);
+
+#[test]
+fn test_constant_expressions() {
+    assert(CONST_SUM == -3, 'CONST_SUM == -3');
+    assert(CONST_U8_MAX == 255_u8, 'CONST_U8_MAX == 255');
+    assert(CONST_QUOTIENT == -3_i8, 'CONST_QUOTIENT == -3');
+    assert(CONST_REMAINDER == -1_i8, 'CONST_REMAINDER == -1');
+    assert(CONST_IN_RANGE, 'CONST_IN_RANGE');
+}
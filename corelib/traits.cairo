@@ -1,2 +1,68 @@
-trait Copy<T>;
-trait Drop<T>;
+pub trait Copy<T>;
+pub trait Drop<T>;
+
+pub trait Add<T> {
+    fn add(a: T, b: T) -> T;
+}
+pub trait Sub<T> {
+    fn sub(a: T, b: T) -> T;
+}
+pub trait Mul<T> {
+    fn mul(a: T, b: T) -> T;
+}
+pub trait Div<T> {
+    fn div(a: T, b: T) -> T;
+}
+pub trait Rem<T> {
+    fn rem(a: T, b: T) -> T;
+}
+pub trait PartialEq<T> {
+    fn eq(a: T, b: T) -> bool;
+    fn ne(a: T, b: T) -> bool;
+}
+pub trait BitAnd<T> {
+    fn bitand(a: T, b: T) -> T;
+}
+pub trait BitOr<T> {
+    fn bitor(a: T, b: T) -> T;
+}
+pub trait BitXor<T> {
+    fn bitxor(a: T, b: T) -> T;
+}
+pub trait Shl<T> {
+    fn shl(a: T, b: T) -> T;
+}
+pub trait Shr<T> {
+    fn shr(a: T, b: T) -> T;
+}
+pub trait PartialOrd<T> {
+    fn le(a: T, b: T) -> bool;
+    fn ge(a: T, b: T) -> bool;
+    fn lt(a: T, b: T) -> bool;
+    fn gt(a: T, b: T) -> bool;
+}
+pub trait Neg<T> {
+    fn neg(a: T) -> T;
+}
+pub trait Not<T> {
+    fn not(a: T) -> T;
+}
+// Implemented for the types that support the index operator. `a[i]` calls `Index::index(a, i)`.
+pub trait Index<C, V> {
+    fn index(ref self: C, index: u128) -> V;
+}
+// Implemented by the compiler for every closure type. `f(a, b)` calls the closure `f` through
+// `Fn::call(f, (a, b))`.
+pub trait Fn<F, Args, R> {
+    fn call(ref self: F, args: Args) -> R;
+}
+// Conversion of a value into a value of another type, which always succeeds. In `x.into()`, the
+// target type is inferred from the expected type of the result.
+pub trait Into<T, S> {
+    fn into(self: T) -> S;
+}
+// Conversion of a value into a value of another type, which returns `None` if the value does not
+// fit in the target type.
+pub trait TryInto<T, S> {
+    fn try_into(self: T) -> Option::<S>;
+}
@@ -46,6 +46,19 @@ pub enum Hint {
         quotient: CellRef,
         remainder: CellRef,
     },
+    /// Divides dividend (represented by 2 128bit limbs) by divisor (represented by 2 128bit
+    /// limbs). Returns the quotient (represented by 2 128bit limbs) and remainder (represented by
+    /// 2 128bit limbs).
+    Uint256DivMod {
+        dividend0: ResOperand,
+        dividend1: ResOperand,
+        divisor0: ResOperand,
+        divisor1: ResOperand,
+        quotient0: CellRef,
+        quotient1: CellRef,
+        remainder0: CellRef,
+        remainder1: CellRef,
+    },
     EnterScope,
     ExitScope,
     /// Represent a hint which is part of the dict_squash function. The hint_index is the position
@@ -144,6 +157,30 @@ impl Display for Hint {
                 fmt_res_operand(f, rhs)?;
                 write!(f, ")")?;
             }
+            Hint::Uint256DivMod {
+                dividend0,
+                dividend1,
+                divisor0,
+                divisor1,
+                quotient0,
+                quotient1,
+                remainder0,
+                remainder1,
+            } => {
+                write!(f, "dividend = ")?;
+                fmt_res_operand(f, dividend0)?;
+                write!(f, " + ")?;
+                fmt_res_operand(f, dividend1)?;
+                write!(f, " * 2**128; divisor = ")?;
+                fmt_res_operand(f, divisor0)?;
+                write!(f, " + ")?;
+                fmt_res_operand(f, divisor1)?;
+                write!(f, " * 2**128; quotient, remainder = divmod(dividend, divisor); ")?;
+                write!(f, "memory{quotient0} = quotient & 0xffffffffffffffffffffffffffffffff; ")?;
+                write!(f, "memory{quotient1} = quotient >> 128; ")?;
+                write!(f, "memory{remainder0} = remainder & 0xffffffffffffffffffffffffffffffff; ")?;
+                write!(f, "memory{remainder1} = remainder >> 128")?;
+            }
             Hint::EnterScope => write!(f, "vm_enter_scope()")?,
             Hint::ExitScope => write!(f, "vm_exit_scope()")?,
             Hint::DictSquashHints { hint_index } => dict_squash::fmt_hint_by_index(f, *hint_index)?,
@@ -95,3 +95,25 @@ fn test_syscall_hint_format() {
         "syscall_handler.syscall(syscall_ptr=memory[fp + -3] + 3)"
     );
 }
+
+#[test]
+fn test_uint256_div_mod_format() {
+    assert_eq!(
+        Hint::Uint256DivMod {
+            dividend0: res!([fp + -6]),
+            dividend1: res!([fp + -5]),
+            divisor0: res!([fp + -4]),
+            divisor1: res!([fp + -3]),
+            quotient0: CellRef { register: Register::AP, offset: 0 },
+            quotient1: CellRef { register: Register::AP, offset: 1 },
+            remainder0: CellRef { register: Register::AP, offset: 2 },
+            remainder1: CellRef { register: Register::AP, offset: 3 },
+        }
+        .to_string(),
+        "dividend = memory[fp + -6] + memory[fp + -5] * 2**128; divisor = memory[fp + -4] + \
+         memory[fp + -3] * 2**128; quotient, remainder = divmod(dividend, divisor); memory[ap + \
+         0] = quotient & 0xffffffffffffffffffffffffffffffff; memory[ap + 1] = quotient >> 128; \
+         memory[ap + 2] = remainder & 0xffffffffffffffffffffffffffffffff; memory[ap + 3] = \
+         remainder >> 128"
+    );
+}
@@ -46,11 +46,14 @@ struct CairoHintProcessor {
     pub hints_dict: HashMap<usize, Vec<HintParams>>,
     // A mapping from a string that represents a hint to the hint object.
     pub string_to_hint: HashMap<String, Hint>,
+    // The execution info returned by the simulated system calls.
+    pub starknet_state: StarknetState,
 }
 
 impl CairoHintProcessor {
     pub fn new<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
         instructions: Instructions,
+        starknet_state: StarknetState,
     ) -> Self {
         let mut hints_dict: HashMap<usize, Vec<HintParams>> = HashMap::new();
         let mut string_to_hint: HashMap<String, Hint> = HashMap::new();
@@ -71,7 +74,7 @@ impl CairoHintProcessor {
             }
             hint_offset += instruction.body.op_size();
         }
-        CairoHintProcessor { hints_dict, string_to_hint }
+        CairoHintProcessor { hints_dict, string_to_hint, starknet_state }
     }
 }
 
@@ -83,6 +86,66 @@ fn cell_ref_to_relocatable(cell_ref: &CellRef, vm: &VirtualMachine) -> Relocatab
     base + (cell_ref.offset as i32)
 }
 
+/// The execution info of a simulated StarkNet contract call, returned by the execution info system
+/// calls.
+#[derive(Clone, Debug, Default)]
+pub struct StarknetState {
+    pub caller_address: BigInt,
+    pub contract_address: BigInt,
+    pub block_number: BigInt,
+    pub block_timestamp: BigInt,
+    pub tx_info: TxInfo,
+}
+
+/// The info of the transaction of a simulated StarkNet contract call.
+#[derive(Clone, Debug, Default)]
+pub struct TxInfo {
+    pub account_contract_address: BigInt,
+    pub max_fee: BigInt,
+    pub signature: Vec<BigInt>,
+    pub transaction_hash: BigInt,
+    pub chain_id: BigInt,
+    pub nonce: BigInt,
+}
+
+/// The selectors of the system calls that return values from the execution info.
+const EXECUTION_INFO_SYSCALL_SELECTORS: [&str; 5] =
+    ["GetCallerAddress", "GetContractAddress", "GetBlockNumber", "GetBlockTimestamp", "GetTxInfo"];
+
+impl StarknetState {
+    /// Returns the results of the execution info system call with the given selector.
+    fn execution_info_syscall_results(
+        &self,
+        selector: &[u8],
+        vm: &mut VirtualMachine,
+    ) -> Result<Vec<MaybeRelocatable>, VirtualMachineError> {
+        Ok(match selector {
+            b"GetCallerAddress" => vec![self.caller_address.clone().into()],
+            b"GetContractAddress" => vec![self.contract_address.clone().into()],
+            b"GetBlockNumber" => vec![self.block_number.clone().into()],
+            b"GetBlockTimestamp" => vec![self.block_timestamp.clone().into()],
+            b"GetTxInfo" => {
+                let tx_info = &self.tx_info;
+                let signature_start = vm.add_memory_segment();
+                let signature_end = vm.load_data(
+                    &signature_start.into(),
+                    tx_info.signature.iter().cloned().map(MaybeRelocatable::from).collect(),
+                )?;
+                vec![
+                    tx_info.account_contract_address.clone().into(),
+                    tx_info.max_fee.clone().into(),
+                    signature_start.into(),
+                    signature_end,
+                    tx_info.transaction_hash.clone().into(),
+                    tx_info.chain_id.clone().into(),
+                    tx_info.nonce.clone().into(),
+                ]
+            }
+            _ => unreachable!("Not an execution info system call selector."),
+        })
+    }
+}
+
 /// Execution scope for starknet related data.
 struct StarknetExecScope {
     /// The values of addresses in the simulated storage.
@@ -161,6 +224,35 @@ impl HintProcessor for CairoHintProcessor {
                 )?;
                 vm.insert_value(&cell_ref_to_relocatable(remainder, vm), lhs_val % rhs_val)?;
             }
+            Hint::Uint256DivMod {
+                dividend0,
+                dividend1,
+                divisor0,
+                divisor1,
+                quotient0,
+                quotient1,
+                remainder0,
+                remainder1,
+            } => {
+                let pow_2_128 = BigInt::from(u128::MAX) + 1u32;
+                let dividend = get_val(dividend0)? + get_val(dividend1)? * pow_2_128.clone();
+                let divisor = get_val(divisor0)? + get_val(divisor1)? * pow_2_128.clone();
+                let quotient = dividend.clone() / divisor.clone();
+                let remainder = dividend % divisor;
+                vm.insert_value(
+                    &cell_ref_to_relocatable(quotient0, vm),
+                    quotient.clone() % pow_2_128.clone(),
+                )?;
+                vm.insert_value(
+                    &cell_ref_to_relocatable(quotient1, vm),
+                    quotient / pow_2_128.clone(),
+                )?;
+                vm.insert_value(
+                    &cell_ref_to_relocatable(remainder0, vm),
+                    remainder.clone() % pow_2_128.clone(),
+                )?;
+                vm.insert_value(&cell_ref_to_relocatable(remainder1, vm), remainder / pow_2_128)?;
+            }
             Hint::AllocDictFeltTo { .. } => todo!(),
             Hint::DictFeltToRead { .. } => todo!(),
             Hint::DictFeltToWrite { .. } => todo!(),
@@ -238,6 +330,44 @@ impl HintProcessor for CairoHintProcessor {
                         vm.insert_value(&gas_counter_updated_ptr, gas_counter)?;
                         vm.insert_value(&revert_reason_ptr, BigInt::from(1))?;
                     }
+                } else if selector == "EmitEvent".as_bytes() {
+                    let gas_counter = get_double_deref_val(cell, &(base_offset.clone() + 1))?;
+                    const EMIT_EVENT_GAS_SIM_COST: usize = 50;
+                    let gas_counter_updated_ptr = get_ptr(cell, &(base_offset.clone() + 6))?;
+                    let revert_reason_ptr = get_ptr(cell, &(base_offset + 7))?;
+
+                    // The emitted events are not recorded by the simulation.
+                    if gas_counter >= EMIT_EVENT_GAS_SIM_COST.into() {
+                        vm.insert_value(
+                            &gas_counter_updated_ptr,
+                            gas_counter - EMIT_EVENT_GAS_SIM_COST,
+                        )?;
+                        vm.insert_value(&revert_reason_ptr, BigInt::from(0))?;
+                    } else {
+                        vm.insert_value(&gas_counter_updated_ptr, gas_counter)?;
+                        vm.insert_value(&revert_reason_ptr, BigInt::from(1))?;
+                    }
+                } else if EXECUTION_INFO_SYSCALL_SELECTORS.iter().any(|s| selector == s.as_bytes())
+                {
+                    let gas_counter = get_double_deref_val(cell, &(base_offset.clone() + 1))?;
+                    const GET_INFO_GAS_SIM_COST: usize = 50;
+                    let gas_counter_updated_ptr = get_ptr(cell, &(base_offset.clone() + 2))?;
+                    let revert_reason_ptr = get_ptr(cell, &(base_offset.clone() + 3))?;
+                    let results_ptr = get_ptr(cell, &(base_offset + 4))?;
+
+                    if gas_counter >= GET_INFO_GAS_SIM_COST.into() {
+                        vm.insert_value(
+                            &gas_counter_updated_ptr,
+                            gas_counter - GET_INFO_GAS_SIM_COST,
+                        )?;
+                        vm.insert_value(&revert_reason_ptr, BigInt::from(0))?;
+                        let results =
+                            self.starknet_state.execution_info_syscall_results(&selector, vm)?;
+                        vm.load_data(&results_ptr.into(), results)?;
+                    } else {
+                        vm.insert_value(&gas_counter_updated_ptr, gas_counter)?;
+                        vm.insert_value(&revert_reason_ptr, BigInt::from(1))?;
+                    }
                 } else if selector == "call_contract".as_bytes() {
                     todo!()
                 } else {
@@ -264,6 +394,19 @@ impl HintProcessor for CairoHintProcessor {
 pub fn run_function<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
     instructions: Instructions,
     builtins: Vec<String>,
+) -> Result<(Vec<Option<BigInt>>, usize), Box<VirtualMachineError>> {
+    run_function_with_starknet_state(instructions, builtins, StarknetState::default())
+}
+
+/// Runs `program` on layout with prime, and returns the memory layout and ap value.
+/// The execution info system calls return the values in `starknet_state`.
+pub fn run_function_with_starknet_state<
+    'a,
+    Instructions: Iterator<Item = &'a Instruction> + Clone,
+>(
+    instructions: Instructions,
+    builtins: Vec<String>,
+    starknet_state: StarknetState,
 ) -> Result<(Vec<Option<BigInt>>, usize), Box<VirtualMachineError>> {
     let data: Vec<MaybeRelocatable> = instructions
         .clone()
@@ -271,7 +414,7 @@ pub fn run_function<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
         .map(MaybeRelocatable::from)
         .collect();
 
-    let mut hint_processor = CairoHintProcessor::new(instructions);
+    let mut hint_processor = CairoHintProcessor::new(instructions, starknet_state);
 
     let program = Program {
         builtins,
@@ -12,7 +12,7 @@ use crate::db::RootDatabase;
 ///
 /// # Returns
 ///
-/// Returns `true` if diagnostics were found.
+/// Returns `true` if errors were found. Warnings are reported, but do not count as errors.
 pub fn check_diagnostics(
     db: &mut RootDatabase,
     on_diagnostic: Option<Box<dyn FnMut(String)>>,
@@ -44,14 +44,14 @@ pub fn check_diagnostics(
 
             if let Ok(diag) = db.module_semantic_diagnostics(*module_id) {
                 if !diag.get_all().is_empty() {
-                    found_diagnostics = true;
+                    found_diagnostics |= diag.has_errors();
                     on_diagnostic(diag.format(db));
                 }
             }
 
             if let Ok(diag) = db.module_lowering_diagnostics(*module_id) {
                 if !diag.get_all().is_empty() {
-                    found_diagnostics = true;
+                    found_diagnostics |= diag.has_errors();
                     on_diagnostic(diag.format(db));
                 }
             }
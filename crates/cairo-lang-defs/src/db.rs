@@ -45,6 +45,8 @@ pub trait DefsGroup:
     #[salsa::interned]
     fn intern_type_alias(&self, id: TypeAliasLongId) -> TypeAliasId;
     #[salsa::interned]
+    fn intern_constant(&self, id: ConstantLongId) -> ConstantId;
+    #[salsa::interned]
     fn intern_member(&self, id: MemberLongId) -> MemberId;
     #[salsa::interned]
     fn intern_variant(&self, id: VariantLongId) -> VariantId;
@@ -108,6 +110,11 @@ pub trait DefsGroup:
         module_id: ModuleId,
     ) -> Maybe<OrderedHashMap<TypeAliasId, ast::ItemTypeAlias>>;
     fn module_type_aliases_ids(&self, module_id: ModuleId) -> Maybe<Vec<TypeAliasId>>;
+    fn module_constants(
+        &self,
+        module_id: ModuleId,
+    ) -> Maybe<OrderedHashMap<ConstantId, ast::ItemConstant>>;
+    fn module_constants_ids(&self, module_id: ModuleId) -> Maybe<Vec<ConstantId>>;
     fn module_traits(&self, module_id: ModuleId) -> Maybe<OrderedHashMap<TraitId, ast::ItemTrait>>;
     fn module_traits_ids(&self, module_id: ModuleId) -> Maybe<Vec<TraitId>>;
     fn module_impls(&self, module_id: ModuleId) -> Maybe<OrderedHashMap<ImplId, ast::ItemImpl>>;
@@ -246,6 +253,7 @@ pub struct ModuleData {
     structs: OrderedHashMap<StructId, ast::ItemStruct>,
     enums: OrderedHashMap<EnumId, ast::ItemEnum>,
     type_aliases: OrderedHashMap<TypeAliasId, ast::ItemTypeAlias>,
+    constants: OrderedHashMap<ConstantId, ast::ItemConstant>,
     traits: OrderedHashMap<TraitId, ast::ItemTrait>,
     impls: OrderedHashMap<ImplId, ast::ItemImpl>,
     extern_types: OrderedHashMap<ExternTypeId, ast::ItemExternType>,
@@ -395,6 +403,11 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
                     ));
                     res.type_aliases.insert(item_id, type_alias);
                 }
+                ast::Item::Constant(constant) => {
+                    let item_id =
+                        db.intern_constant(ConstantLongId(module_file_id, constant.stable_ptr()));
+                    res.constants.insert(item_id, constant);
+                }
             }
         }
     }
@@ -471,6 +484,17 @@ pub fn module_type_aliases_ids(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe
     Ok(db.module_type_aliases(module_id)?.keys().copied().collect())
 }
 
+/// Returns all the constants of the given module.
+pub fn module_constants(
+    db: &dyn DefsGroup,
+    module_id: ModuleId,
+) -> Maybe<OrderedHashMap<ConstantId, ast::ItemConstant>> {
+    Ok(db.priv_module_data(module_id)?.constants)
+}
+pub fn module_constants_ids(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<Vec<ConstantId>> {
+    Ok(db.module_constants(module_id)?.keys().copied().collect())
+}
+
 /// Returns all the traits of the given module.
 pub fn module_traits(
     db: &dyn DefsGroup,
@@ -576,6 +600,10 @@ fn module_items(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleItems> {
                 syntax.name(syntax_db).text(syntax_db),
                 ModuleItemId::TypeAlias(type_alias_id)
             )),
+            db.module_constants(module_id)?.into_iter().map(|(constant_id, syntax)| (
+                syntax.name(syntax_db).text(syntax_db),
+                ModuleItemId::Constant(constant_id)
+            )),
             db.module_traits(module_id)?.into_iter().map(|(trait_id, syntax)| (
                 syntax.name(syntax_db).text(syntax_db),
                 ModuleItemId::Trait(trait_id)
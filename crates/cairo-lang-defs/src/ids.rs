@@ -241,6 +241,16 @@ impl ModuleId {
             }
         }
     }
+    /// Returns the crate the module belongs to.
+    pub fn owning_crate(&self, db: &dyn DefsGroup) -> CrateId {
+        match self {
+            ModuleId::CrateRoot(crate_id) => *crate_id,
+            ModuleId::Submodule(id) => id.parent_module(db).owning_crate(db),
+            ModuleId::VirtualSubmodule(virtual_submodule_id) => {
+                db.lookup_intern_virtual_submodule(*virtual_submodule_id).parent.owning_crate(db)
+            }
+        }
+    }
 }
 impl DebugWithDb<dyn DefsGroup> for ModuleId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>, db: &dyn DefsGroup) -> std::fmt::Result {
@@ -272,6 +282,7 @@ define_language_element_id_as_enum! {
         Struct(StructId),
         Enum(EnumId),
         TypeAlias(TypeAliasId),
+        Constant(ConstantId),
         Trait(TraitId),
         Impl(ImplId),
         ExternType(ExternTypeId),
@@ -312,6 +323,16 @@ define_language_element_id!(
     lookup_intern_impl_function,
     name
 );
+impl PartialOrd for ImplFunctionId {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+impl Ord for ImplFunctionId {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
 impl ImplFunctionId {
     pub fn impl_id(&self, db: &dyn DefsGroup) -> ImplId {
         let ImplFunctionLongId(module_file_id, ptr) = db.lookup_intern_impl_function(*self);
@@ -346,6 +367,13 @@ define_language_element_id!(
     lookup_intern_type_alias,
     name
 );
+define_language_element_id!(
+    ConstantId,
+    ConstantLongId,
+    ast::ItemConstant,
+    lookup_intern_constant,
+    name
+);
 define_language_element_id!(
     ExternTypeId,
     ExternTypeLongId,
@@ -436,7 +464,54 @@ define_language_element_id_as_enum! {
 }
 impl GenericFunctionId {
     pub fn format(&self, db: &(dyn DefsGroup + 'static)) -> String {
-        format!("{}::{}", self.parent_module(db).full_path(db), self.name(db))
+        match self {
+            GenericFunctionId::TraitFunction(id) => {
+                format!("{}::{}", id.trait_id(db).full_path(db), self.name(db))
+            }
+            GenericFunctionId::ImplFunction(id) => {
+                format!("{}::{}", id.impl_id(db).full_path(db), self.name(db))
+            }
+            _ => format!("{}::{}", self.parent_module(db).full_path(db), self.name(db)),
+        }
+    }
+}
+
+define_language_element_id_as_enum! {
+    #[toplevel]
+    /// Id for a function that has a body - either a free function or an impl function.
+    pub enum FunctionWithBodyId {
+        Free(FreeFunctionId),
+        Impl(ImplFunctionId),
+    }
+}
+impl FunctionWithBodyId {
+    pub fn format(&self, db: &(dyn DefsGroup + 'static)) -> String {
+        GenericFunctionId::from(*self).format(db)
+    }
+}
+impl PartialOrd for FunctionWithBodyId {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for FunctionWithBodyId {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (FunctionWithBodyId::Free(a), FunctionWithBodyId::Free(b)) => a.cmp(b),
+            (FunctionWithBodyId::Impl(a), FunctionWithBodyId::Impl(b)) => a.cmp(b),
+            (FunctionWithBodyId::Free(_), FunctionWithBodyId::Impl(_)) => std::cmp::Ordering::Less,
+            (FunctionWithBodyId::Impl(_), FunctionWithBodyId::Free(_)) => {
+                std::cmp::Ordering::Greater
+            }
+        }
+    }
+}
+impl From<FunctionWithBodyId> for GenericFunctionId {
+    fn from(function: FunctionWithBodyId) -> Self {
+        match function {
+            FunctionWithBodyId::Free(id) => GenericFunctionId::Free(id),
+            FunctionWithBodyId::Impl(id) => GenericFunctionId::ImplFunction(id),
+        }
     }
 }
 
@@ -469,11 +544,23 @@ impl OptionFrom<ModuleItemId> for GenericFunctionId {
             | ModuleItemId::Struct(_)
             | ModuleItemId::Enum(_)
             | ModuleItemId::TypeAlias(_)
+            | ModuleItemId::Constant(_)
             | ModuleItemId::ExternType(_) => None,
         }
     }
 }
 
+/// Conversion from GenericFunctionId to FunctionWithBodyId.
+impl OptionFrom<GenericFunctionId> for FunctionWithBodyId {
+    fn option_from(item: GenericFunctionId) -> Option<Self> {
+        match item {
+            GenericFunctionId::Free(id) => Some(FunctionWithBodyId::Free(id)),
+            GenericFunctionId::ImplFunction(id) => Some(FunctionWithBodyId::Impl(id)),
+            GenericFunctionId::Extern(_) | GenericFunctionId::TraitFunction(_) => None,
+        }
+    }
+}
+
 /// Conversion from ModuleItemId to GenericTypeId.
 impl OptionFrom<ModuleItemId> for GenericTypeId {
     fn option_from(item: ModuleItemId) -> Option<Self> {
@@ -483,6 +570,7 @@ impl OptionFrom<ModuleItemId> for GenericTypeId {
             ModuleItemId::ExternType(id) => Some(GenericTypeId::Extern(id)),
             ModuleItemId::Submodule(_)
             | ModuleItemId::TypeAlias(_)
+            | ModuleItemId::Constant(_)
             | ModuleItemId::Use(_)
             | ModuleItemId::FreeFunction(_)
             | ModuleItemId::Trait(_)
@@ -18,8 +18,27 @@ pub trait DiagnosticEntry: Clone + std::fmt::Debug + Eq + std::hash::Hash {
     type DbType: Upcast<dyn FilesGroup> + ?Sized;
     fn format(&self, db: &Self::DbType) -> String;
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation;
+    /// Returns the severity of the diagnostic. Only errors fail the compilation.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
     // TODO(spapini): Add a way to inspect the diagnostic programmatically, e.g, downcast.
 }
+
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
 pub struct DiagnosticLocation {
     pub file_id: FileId,
     pub span: TextSpan,
@@ -103,6 +122,15 @@ pub fn format_diagnostics(
     db: &dyn FilesGroup,
     message: &str,
     location: DiagnosticLocation,
+) -> String {
+    format_diagnostic_with_severity(db, Severity::Error, message, location)
+}
+
+fn format_diagnostic_with_severity(
+    db: &dyn FilesGroup,
+    severity: Severity,
+    message: &str,
+    location: DiagnosticLocation,
 ) -> String {
     let file_name = location.file_id.file_name(db);
     let marks = get_location_marks(db, &location);
@@ -110,7 +138,7 @@ pub fn format_diagnostics(
         Some(pos) => format!("{}:{}", pos.line + 1, pos.col + 1),
         None => "?".into(),
     };
-    format!("error: {message}\n --> {file_name}:{pos}\n{marks}\n")
+    format!("{severity}: {message}\n --> {file_name}:{pos}\n{marks}\n")
 }
 
 /// A set of diagnostic entries that arose during a computation.
@@ -134,7 +162,12 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         // Format leaves.
         for entry in &self.0.leaves {
             let message = entry.format(db);
-            res += &format_diagnostics(db.upcast(), &message, entry.location(db));
+            res += &format_diagnostic_with_severity(
+                db.upcast(),
+                entry.severity(),
+                &message,
+                entry.location(db),
+            );
             res += "\n";
         }
         // Format subtrees.
@@ -142,20 +175,20 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         res
     }
 
-    /// Asserts that no diagnostic has occurred, panicking with an error message on failure.
+    /// Returns true if any of the diagnostics is an error, and not just a warning.
+    pub fn has_errors(&self) -> bool {
+        self.0.leaves.iter().any(|entry| entry.severity() == Severity::Error)
+            || self.0.subtrees.iter().any(|subtree| subtree.has_errors())
+    }
+
+    /// Asserts that no error has occurred, panicking with an error message on failure.
     pub fn expect(&self, error_message: &str) {
-        assert!(self.0.leaves.is_empty(), "{}\n{:?}", error_message, self);
-        for subtree in &self.0.subtrees {
-            subtree.expect(error_message);
-        }
+        assert!(!self.has_errors(), "{}\n{:?}", error_message, self);
     }
 
     /// Same as [Self::expect], except that the diagnostics are formatted.
     pub fn expect_with_db(&self, db: &TEntry::DbType, error_message: &str) {
-        assert!(self.0.leaves.is_empty(), "{}\n{}", error_message, self.format(db));
-        for subtree in &self.0.subtrees {
-            subtree.expect_with_db(db, error_message);
-        }
+        assert!(!self.has_errors(), "{}\n{}", error_message, self.format(db));
     }
 
     // TODO(spapini): This is temporary. Remove once the logic in language server doesn't use this.
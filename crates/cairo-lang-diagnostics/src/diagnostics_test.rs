@@ -7,12 +7,13 @@ use cairo_lang_filesystem::test_utils::FilesDatabaseForTesting;
 use indoc::indoc;
 use test_log::test;
 
-use super::{DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder};
+use super::{DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder, Severity};
 
 // Test diagnostic.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 struct SimpleDiag {
     file_id: FileId,
+    severity: Severity,
 }
 impl DiagnosticEntry for SimpleDiag {
     type DbType = dyn FilesGroup;
@@ -27,6 +28,10 @@ impl DiagnosticEntry for SimpleDiag {
             span: TextSpan { start: TextOffset(0), end: TextOffset(6) },
         }
     }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
 }
 
 fn setup() -> (FilesDatabaseForTesting, FileId) {
@@ -44,11 +49,13 @@ fn test_diagnostics() {
     let (db_val, file_id) = setup();
 
     let mut diagnostics: DiagnosticsBuilder<SimpleDiag> = DiagnosticsBuilder::default();
-    let diagnostic = SimpleDiag { file_id };
+    let diagnostic = SimpleDiag { file_id, severity: Severity::Error };
     diagnostics.add(diagnostic);
+    let diagnostics = diagnostics.build();
 
+    assert!(diagnostics.has_errors());
     assert_eq!(
-        diagnostics.build().format(&db_val),
+        diagnostics.format(&db_val),
         indoc! { "
             error: Simple diagnostic.
              --> dummy_file.sierra:1:1
@@ -58,3 +65,24 @@ fn test_diagnostics() {
         " }
     );
 }
+
+#[test]
+fn test_warnings() {
+    let (db_val, file_id) = setup();
+
+    let mut diagnostics: DiagnosticsBuilder<SimpleDiag> = DiagnosticsBuilder::default();
+    diagnostics.add(SimpleDiag { file_id, severity: Severity::Warning });
+    let diagnostics = diagnostics.build();
+
+    assert!(!diagnostics.has_errors());
+    assert_eq!(
+        diagnostics.format(&db_val),
+        indoc! { "
+            warning: Simple diagnostic.
+             --> dummy_file.sierra:1:1
+            abcd
+            ^**^
+
+        " }
+    );
+}
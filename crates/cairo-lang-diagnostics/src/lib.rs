@@ -6,5 +6,5 @@ mod location_marks;
 
 pub use self::diagnostics::{
     format_diagnostics, skip_diagnostic, DiagnosticAdded, DiagnosticEntry, DiagnosticLocation,
-    Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe, ToOption,
+    Diagnostics, DiagnosticsBuilder, Maybe, Severity, ToMaybe, ToOption,
 };
@@ -36,7 +36,15 @@ impl SyntaxNodeFormat for SyntaxNode {
                 true
             }
             SyntaxKind::TokenLBrack
-                if matches!(parent_parent_kind(db, self), Some(SyntaxKind::Attribute)) =>
+                if matches!(
+                    parent_parent_kind(db, self),
+                    Some(SyntaxKind::Attribute | SyntaxKind::ExprIndexed)
+                ) =>
+            {
+                true
+            }
+            SyntaxKind::ParamList
+                if matches!(parent_kind(db, self), Some(SyntaxKind::ClosureParamsWrapped)) =>
             {
                 true
             }
@@ -81,6 +89,9 @@ impl SyntaxNodeFormat for SyntaxNode {
             SyntaxKind::TokenMinus => {
                 matches!(parent_parent_kind(db, self), Some(SyntaxKind::ExprUnary))
             }
+            SyntaxKind::ParamList => {
+                matches!(parent_kind(db, self), Some(SyntaxKind::ClosureParamsWrapped))
+            }
             SyntaxKind::TokenLT
                 if matches!(
                     parent_parent_kind(db, self),
@@ -111,6 +122,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                 | SyntaxKind::GenericParamList
                 | SyntaxKind::GenericArgList
                 | SyntaxKind::ItemList
+                | SyntaxKind::TraitItemList
         )
     }
 
@@ -119,12 +131,16 @@ impl SyntaxNodeFormat for SyntaxNode {
             SyntaxKind::StatementLet
             | SyntaxKind::StatementExpr
             | SyntaxKind::StatementReturn
+            | SyntaxKind::StatementBreak
+            | SyntaxKind::StatementContinue
             | SyntaxKind::ItemFreeFunction
             | SyntaxKind::ItemExternFunction
             | SyntaxKind::ItemExternType
             | SyntaxKind::ItemTrait
+            | SyntaxKind::TraitItemFunction
             | SyntaxKind::ItemImpl
             | SyntaxKind::ItemStruct
+            | SyntaxKind::ItemConstant
             | SyntaxKind::Attribute
             | SyntaxKind::ItemEnum
             | SyntaxKind::ItemModule
@@ -142,6 +158,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                             | SyntaxKind::ExprMatch
                             | SyntaxKind::ModuleBody
                             | SyntaxKind::TraitBody
+                            | SyntaxKind::ImplBody
                     )
                 )
             }
@@ -156,7 +173,7 @@ impl SyntaxNodeFormat for SyntaxNode {
 
     fn allowed_empty_between(&self, db: &dyn SyntaxGroup) -> usize {
         match self.kind(db) {
-            SyntaxKind::ItemList => 2,
+            SyntaxKind::ItemList | SyntaxKind::TraitItemList => 2,
             SyntaxKind::StatementList => 1,
             _ => 0,
         }
@@ -48,3 +48,37 @@ fn fib(a: Box::<felt>, b: Box::<felt>, n: Box::<felt>) -> Box::<felt> {
         },
     }
 }
+
+fn loops(n: felt) -> felt {
+    let mut i = 0;
+    while i != n {
+        i = i + 1;
+        if i == 5 {
+            continue;
+        }
+    }
+    loop {
+        if i == 0 {
+            break i * 2;
+        }
+        i = i - 1;
+        break;
+    }
+}
+
+trait MyTrait<T> {
+    fn foo(ref self: Array::<T>, value: T) nopanic;
+    fn bar(self: T) -> T;
+}
+impl MyImpl<T> of MyTrait::<T> {
+    fn foo(ref self: Array::<T>, value: T) nopanic {
+        self.append(value)
+    }
+    fn bar(self: T) -> T {
+        self
+    }
+}
+
+fn index(ref arr: Array::<felt>, s: S) -> felt {
+    arr[0_u128] + s.arr[1_u128][2_u128]
+}
@@ -14,10 +14,10 @@ use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::{
     EnumLongId, ExternFunctionLongId, ExternTypeLongId, FileIndex, FreeFunctionId,
-    FreeFunctionLongId, ImplLongId, LanguageElementId, LookupItemId, ModuleFileId, ModuleId,
-    ModuleItemId, StructLongId, TraitLongId, UseLongId,
+    FreeFunctionLongId, FunctionWithBodyId, ImplLongId, LanguageElementId, LookupItemId,
+    ModuleFileId, ModuleId, ModuleItemId, StructLongId, TraitLongId, UseLongId,
 };
-use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, ToOption};
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, Severity, ToOption};
 use cairo_lang_filesystem::db::{
     AsFilesGroupMut, FilesGroup, FilesGroupEx, PrivRawFileContentQuery,
 };
@@ -30,7 +30,7 @@ use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_parser::ParserDiagnostic;
 use cairo_lang_project::ProjectConfig;
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::items::free_function::SemanticExprLookup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
 use cairo_lang_semantic::resolve_path::ResolvedGenericItem;
 use cairo_lang_semantic::SemanticDiagnostic;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -174,9 +174,14 @@ impl Backend {
             let end = from_pos(
                 location.span.start.position_in_file(db.upcast(), location.file_id).unwrap(),
             );
+            let severity = match diagnostic.severity() {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+            };
             diags.push(Diagnostic {
                 range: Range { start, end },
                 message,
+                severity: Some(severity),
                 ..Diagnostic::default()
             });
         }
@@ -451,6 +456,11 @@ impl LanguageServer for Backend {
                     imp.file_index(defs_db),
                     imp.stable_ptr(defs_db).untyped(),
                 ),
+                ResolvedGenericItem::Constant(constant) => (
+                    constant.parent_module(defs_db),
+                    constant.file_index(defs_db),
+                    constant.stable_ptr(defs_db).untyped(),
+                ),
             };
 
             let file = if let Ok(files) = db.module_files(module_id) {
@@ -658,12 +668,12 @@ fn get_expr_hint(
     let expr_node = ast::Expr::from_syntax_node(syntax_db, node);
     // Lookup semantic expression.
     let expr_id = db
-        .lookup_expr_by_ptr(free_function_id, expr_node.stable_ptr())
+        .lookup_expr_by_ptr(FunctionWithBodyId::Free(free_function_id), expr_node.stable_ptr())
         .to_option()
         .on_none(|| {
             eprintln!("Hover failed. Semantic model not found for expression.");
         })?;
-    let semantic_expr = db.expr_semantic(free_function_id, expr_id);
+    let semantic_expr = db.expr_semantic(FunctionWithBodyId::Free(free_function_id), expr_id);
     // Format the hover text.
     Some(format!("Type: `{}`", semantic_expr.ty().format(db)))
 }
@@ -5,18 +5,33 @@ use cairo_lang_defs::ids::{FreeFunctionId, ModuleId, ModuleItemId};
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::function_with_body_function_id;
 use cairo_lang_semantic::TypeId;
 use cairo_lang_utils::Upcast;
 
 use crate::diagnostic::LoweringDiagnostic;
-use crate::lower::{lower, Lowered};
+use crate::ids::{FunctionLongId, FunctionWithBodyId};
+use crate::lower::{lower, Lowered, MultiLowering};
 
 // Salsa database interface.
 #[salsa::query_group(LoweringDatabase)]
 pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
+    #[salsa::interned]
+    fn intern_lowering_function(&self, id: FunctionLongId) -> crate::ids::FunctionId;
+
+    /// Computes the lowered representation of a concrete function with a body, along with the
+    /// lowered representations of the functions generated from it (e.g. loops).
+    fn function_with_body_multi_lowering(
+        &self,
+        function: cairo_lang_semantic::FunctionId,
+    ) -> Maybe<Arc<MultiLowering>>;
+
     /// Computed the lowered representation of a free function.
     fn free_function_lowered(&self, free_function: FreeFunctionId) -> Maybe<Arc<Lowered>>;
 
+    /// Computes the lowered representation of a function with a body.
+    fn function_with_body_lowered(&self, function: FunctionWithBodyId) -> Maybe<Arc<Lowered>>;
+
     /// Aggregates module level semantic diagnostics.
     fn module_lowering_diagnostics(
         &self,
@@ -31,7 +46,10 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     /// Returns the representative of the function's strongly connected component. The
     /// representative is consistently chosen for all the functions in the same SCC.
     #[salsa::invoke(crate::lower::implicits::function_scc_representative)]
-    fn function_scc_representative(&self, function: FreeFunctionId) -> SCCRepresentative;
+    fn function_scc_representative(
+        &self,
+        function: cairo_lang_semantic::FunctionId,
+    ) -> SCCRepresentative;
 
     /// Returns the explicit implicits required by all the functions in the SCC of this function.
     /// These are all the implicit parameters that are explicitly declared in the functions of
@@ -54,29 +72,42 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
         function: cairo_lang_semantic::FunctionId,
     ) -> Maybe<Vec<TypeId>>;
 
-    /// Returns all the implicit parameters that the free function requires (according to both its
-    /// signature and the functions it calls).
-    #[salsa::invoke(crate::lower::implicits::free_function_all_implicits)]
-    fn free_function_all_implicits(&self, function: FreeFunctionId) -> Maybe<HashSet<TypeId>>;
+    /// Returns all the implicit parameters that the concrete function with body requires
+    /// (according to both its signature and the functions it calls).
+    #[salsa::invoke(crate::lower::implicits::function_with_body_all_implicits)]
+    fn function_with_body_all_implicits(
+        &self,
+        function: cairo_lang_semantic::FunctionId,
+    ) -> Maybe<HashSet<TypeId>>;
 
-    /// Returns all the implicit parameters that the free function requires (according to both its
-    /// signature and the functions it calls). The items in the returned vector are unique and the
-    /// order is consistent, but not necessarily related to the order of the explicit implicits in
-    /// the signature of the function.
-    #[salsa::invoke(crate::lower::implicits::free_function_all_implicits_vec)]
-    fn free_function_all_implicits_vec(&self, function: FreeFunctionId) -> Maybe<Vec<TypeId>>;
+    /// Returns all the implicit parameters that the concrete function with body requires
+    /// (according to both its signature and the functions it calls). The items in the returned
+    /// vector are unique and the order is consistent, but not necessarily related to the order of
+    /// the explicit implicits in the signature of the function.
+    #[salsa::invoke(crate::lower::implicits::function_with_body_all_implicits_vec)]
+    fn function_with_body_all_implicits_vec(
+        &self,
+        function: cairo_lang_semantic::FunctionId,
+    ) -> Maybe<Vec<TypeId>>;
 
     /// Returns whether the function may panic.
     #[salsa::invoke(crate::lower::implicits::function_may_panic)]
     fn function_may_panic(&self, function: cairo_lang_semantic::FunctionId) -> Maybe<bool>;
 
-    /// Returns whether the function may panic.
-    #[salsa::invoke(crate::lower::implicits::free_function_may_panic)]
-    fn free_function_may_panic(&self, free_function: FreeFunctionId) -> Maybe<bool>;
+    /// Returns whether the concrete function with body may panic.
+    #[salsa::invoke(crate::lower::implicits::function_with_body_may_panic)]
+    fn function_with_body_may_panic(
+        &self,
+        function: cairo_lang_semantic::FunctionId,
+    ) -> Maybe<bool>;
 
-    /// Returns all the functions in the same strongly connected component as the given function.
+    /// Returns all the concrete functions in the same strongly connected component as the given
+    /// concrete function.
     #[salsa::invoke(crate::lower::implicits::function_scc)]
-    fn function_scc(&self, function_id: FreeFunctionId) -> Vec<FreeFunctionId>;
+    fn function_scc(
+        &self,
+        function_id: cairo_lang_semantic::FunctionId,
+    ) -> Vec<cairo_lang_semantic::FunctionId>;
 
     /// An array that sets the precedence of implicit types.
     #[salsa::input]
@@ -89,13 +120,40 @@ pub fn init_lowering_group(db: &mut (dyn LoweringGroup + 'static)) {
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
-pub struct SCCRepresentative(pub FreeFunctionId);
+pub struct SCCRepresentative(pub cairo_lang_semantic::FunctionId);
+
+fn function_with_body_multi_lowering(
+    db: &dyn LoweringGroup,
+    function_id: cairo_lang_semantic::FunctionId,
+) -> Maybe<Arc<MultiLowering>> {
+    Ok(Arc::new(lower(db.upcast(), function_id)?))
+}
 
 fn free_function_lowered(
     db: &dyn LoweringGroup,
     free_function_id: FreeFunctionId,
 ) -> Maybe<Arc<Lowered>> {
-    Ok(Arc::new(lower(db.upcast(), free_function_id)?))
+    db.function_with_body_lowered(FunctionWithBodyId::Semantic(function_with_body_function_id(
+        db.upcast(),
+        cairo_lang_defs::ids::FunctionWithBodyId::Free(free_function_id),
+    )?))
+}
+
+fn function_with_body_lowered(
+    db: &dyn LoweringGroup,
+    function: FunctionWithBodyId,
+) -> Maybe<Arc<Lowered>> {
+    match function {
+        FunctionWithBodyId::Semantic(function_id) => {
+            Ok(Arc::new(db.function_with_body_multi_lowering(function_id)?.main_lowering.clone()))
+        }
+        FunctionWithBodyId::Generated(generated) => Ok(Arc::new(
+            db.function_with_body_multi_lowering(generated.parent)?.generated_lowerings
+                [generated.element]
+                .lowered
+                .clone(),
+        )),
+    }
 }
 
 fn module_lowering_diagnostics(
@@ -117,8 +175,23 @@ fn module_lowering_diagnostics(
             ModuleItemId::Struct(_) => {}
             ModuleItemId::Enum(_) => {}
             ModuleItemId::TypeAlias(_) => {}
+            ModuleItemId::Constant(_) => {}
             ModuleItemId::Trait(_) => {}
-            ModuleItemId::Impl(_) => {}
+            ModuleItemId::Impl(impl_id) => {
+                for impl_function in db.impl_functions(*impl_id)? {
+                    diagnostics.extend(
+                        function_with_body_function_id(
+                            db.upcast(),
+                            cairo_lang_defs::ids::FunctionWithBodyId::Impl(impl_function),
+                        )
+                        .and_then(|function_id| {
+                            db.function_with_body_lowered(FunctionWithBodyId::Semantic(function_id))
+                        })
+                        .map(|lowered| lowered.diagnostics.clone())
+                        .unwrap_or_default(),
+                    );
+                }
+            }
             ModuleItemId::ExternType(_) => {}
             ModuleItemId::ExternFunction(_) => {}
         }
@@ -4,6 +4,7 @@ use cairo_lang_diagnostics::{
     DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder,
 };
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::SemanticDiagnostic;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 
 pub struct LoweringDiagnostics {
@@ -37,18 +38,20 @@ pub struct LoweringDiagnostic {
 impl DiagnosticEntry for LoweringDiagnostic {
     type DbType = dyn SemanticGroup;
 
-    fn format(&self, _db: &Self::DbType) -> String {
+    fn format(&self, db: &Self::DbType) -> String {
         match &self.kind {
             LoweringDiagnosticKind::Unreachable { .. } => "Unreachable code".into(),
-            LoweringDiagnosticKind::NonZeroValueInMatch => {
-                "Match with a non-zero value is not supported.".into()
-            }
-            LoweringDiagnosticKind::OnlyMatchZeroIsSupported => {
-                "Only match zero (match ... { 0 => ..., _ => ... }) is currently supported.".into()
-            }
             LoweringDiagnosticKind::VariableMoved => "Variable was previously moved.".into(),
             LoweringDiagnosticKind::UnsupportedMatch => "Unsupported match.".into(),
-            LoweringDiagnosticKind::UnsupportedMatchArm => "Unsupported match arm.".into(),
+            LoweringDiagnosticKind::CallingNonClosure => {
+                "Only closures can be called through `Fn`.".into()
+            }
+            LoweringDiagnosticKind::SpecializationFailed { diagnostic } => {
+                format!(
+                    "Cannot call the function with these generic arguments. {}",
+                    diagnostic.format(db)
+                )
+            }
         }
     }
 
@@ -69,11 +72,8 @@ impl DiagnosticEntry for LoweringDiagnostic {
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum LoweringDiagnosticKind {
     Unreachable { last_statement_ptr: SyntaxStablePtrId },
-    // TODO(lior): Remove once supported.
-    NonZeroValueInMatch,
-    // TODO(lior): Remove once supported.
-    OnlyMatchZeroIsSupported,
     VariableMoved,
     UnsupportedMatch,
-    UnsupportedMatchArm,
+    CallingNonClosure,
+    SpecializationFailed { diagnostic: SemanticDiagnostic },
 }
@@ -0,0 +1,115 @@
+use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::ids::ExternFunctionId;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic::TypeId;
+use cairo_lang_utils::define_short_id;
+
+use crate::db::LoweringGroup;
+
+/// A function that was generated by the lowering phase from an element of a function with a body,
+/// such as a loop. It is identified by its parent function, specialized with concrete generic
+/// arguments, and the semantic expression it was generated from.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct GeneratedFunction {
+    pub parent: cairo_lang_semantic::FunctionId,
+    pub element: cairo_lang_semantic::ExprId,
+}
+impl GeneratedFunction {
+    /// Returns the full path of the generated function, for debug purposes.
+    pub fn full_path(&self, db: &dyn LoweringGroup) -> String {
+        let semantic_db: &dyn cairo_lang_semantic::db::SemanticGroup = db.upcast();
+        format!("{:?}[expr{}]", self.parent.debug(semantic_db), self.element.index())
+    }
+}
+
+/// A function that has a body that gets lowered - either a concrete function of the semantic model
+/// (a free function or an impl function, specialized with concrete generic arguments) or a
+/// function generated by the lowering phase.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FunctionWithBodyId {
+    Semantic(cairo_lang_semantic::FunctionId),
+    Generated(GeneratedFunction),
+}
+impl FunctionWithBodyId {
+    /// Returns the concrete semantic function this function was defined in.
+    pub fn parent(&self) -> cairo_lang_semantic::FunctionId {
+        match self {
+            FunctionWithBodyId::Semantic(function_id) => *function_id,
+            FunctionWithBodyId::Generated(generated) => generated.parent,
+        }
+    }
+}
+
+/// A function that may be called from lowered code - either a function of the semantic model, or
+/// a function generated by the lowering phase.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FunctionLongId {
+    Semantic(cairo_lang_semantic::FunctionId),
+    Generated(GeneratedFunction),
+}
+impl DebugWithDb<dyn LoweringGroup> for FunctionLongId {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        db: &(dyn LoweringGroup + 'static),
+    ) -> std::fmt::Result {
+        match self {
+            FunctionLongId::Semantic(semantic) => {
+                let semantic_db: &dyn cairo_lang_semantic::db::SemanticGroup = db.upcast();
+                write!(f, "{:?}", semantic.debug(semantic_db))
+            }
+            FunctionLongId::Generated(generated) => write!(f, "{}", generated.full_path(db)),
+        }
+    }
+}
+
+define_short_id!(FunctionId, FunctionLongId, LoweringGroup, lookup_intern_lowering_function);
+impl FunctionId {
+    /// Returns the lowering function id of a function of the semantic model.
+    pub fn from_semantic(
+        db: &dyn LoweringGroup,
+        function: cairo_lang_semantic::FunctionId,
+    ) -> Self {
+        db.intern_lowering_function(FunctionLongId::Semantic(function))
+    }
+
+    /// Returns the ExternFunctionId if this is an extern function. Otherwise returns none.
+    pub fn try_get_extern_function_id(&self, db: &dyn LoweringGroup) -> Option<ExternFunctionId> {
+        match db.lookup_intern_lowering_function(*self) {
+            FunctionLongId::Semantic(semantic) => semantic.try_get_extern_function_id(db.upcast()),
+            FunctionLongId::Generated(_) => None,
+        }
+    }
+
+    /// Returns the FunctionWithBodyId if this is a function with a body (a user defined function
+    /// or a generated function). Otherwise returns none.
+    pub fn try_get_function_with_body_id(
+        &self,
+        db: &dyn LoweringGroup,
+    ) -> Option<FunctionWithBodyId> {
+        match db.lookup_intern_lowering_function(*self) {
+            FunctionLongId::Semantic(semantic) => semantic
+                .try_get_function_with_body_id(db.upcast())
+                .map(|_| FunctionWithBodyId::Semantic(semantic)),
+            FunctionLongId::Generated(generated) => Some(FunctionWithBodyId::Generated(generated)),
+        }
+    }
+
+    /// Returns all the implicit parameters that the function requires.
+    /// A generated function requires all the implicits of its parent function.
+    pub fn all_implicits(&self, db: &dyn LoweringGroup) -> Maybe<Vec<TypeId>> {
+        match db.lookup_intern_lowering_function(*self) {
+            FunctionLongId::Semantic(semantic) => db.function_all_implicits(semantic),
+            FunctionLongId::Generated(generated) => db.function_all_implicits(generated.parent),
+        }
+    }
+
+    /// Returns whether the function may panic.
+    /// A generated function may panic if its parent function may panic.
+    pub fn may_panic(&self, db: &dyn LoweringGroup) -> Maybe<bool> {
+        match db.lookup_intern_lowering_function(*self) {
+            FunctionLongId::Semantic(semantic) => db.function_may_panic(semantic),
+            FunctionLongId::Generated(generated) => db.function_may_panic(generated.parent),
+        }
+    }
+}
@@ -4,6 +4,7 @@
 pub mod db;
 pub mod diagnostic;
 pub mod fmt;
+pub mod ids;
 pub mod lower;
 pub mod objects;
 
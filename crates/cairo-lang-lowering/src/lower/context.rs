@@ -1,19 +1,21 @@
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::{FreeFunctionId, LanguageElementId};
-use cairo_lang_diagnostics::{DiagnosticAdded, Maybe};
+use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId};
+use cairo_lang_diagnostics::{DiagnosticAdded, Maybe, ToMaybe};
 use cairo_lang_semantic::expr::fmt::ExprFormatter;
 use cairo_lang_semantic::items::enm::SemanticEnumEx;
 use cairo_lang_semantic::items::imp::ImplLookupContext;
 use cairo_lang_semantic::{Mutability, VarId};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use id_arena::Arena;
 use itertools::{chain, zip_eq};
 
-use super::lowered_expr_from_block_result;
+use super::lower_loop::LoopFunction;
 use super::scope::{generators, BlockScope, BlockScopeEnd};
 use super::variables::LivingVar;
+use super::{lowered_expr_from_block_result, GeneratedLowering};
 use crate::blocks::StructuredBlocks;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnostics;
@@ -25,8 +27,11 @@ use crate::VariableId;
 /// Builds a Lowering context.
 pub struct LoweringContextBuilder<'db> {
     pub db: &'db dyn LoweringGroup,
-    pub free_function_id: FreeFunctionId,
-    pub function_def: Arc<cairo_lang_semantic::FreeFunctionDefinition>,
+    /// The concrete function being lowered.
+    pub function_id: cairo_lang_semantic::FunctionId,
+    /// The semantic function with a body the concrete function is specialized from.
+    pub function_with_body_id: FunctionWithBodyId,
+    pub function_def: Arc<cairo_lang_semantic::FunctionDefinition>,
     /// Semantic signature for current function.
     pub signature: cairo_lang_semantic::Signature,
     // TODO(spapini): Document. (excluding implicits).
@@ -35,10 +40,15 @@ pub struct LoweringContextBuilder<'db> {
     pub implicits: Vec<cairo_lang_semantic::TypeId>,
 }
 impl<'db> LoweringContextBuilder<'db> {
-    pub fn new(db: &'db dyn LoweringGroup, free_function_id: FreeFunctionId) -> Maybe<Self> {
-        let function_def = db.free_function_definition(free_function_id)?;
-        let signature = db.free_function_declaration_signature(free_function_id)?;
-        let implicits = db.free_function_all_implicits_vec(free_function_id)?;
+    pub fn new(
+        db: &'db dyn LoweringGroup,
+        function_id: cairo_lang_semantic::FunctionId,
+    ) -> Maybe<Self> {
+        let function_with_body_id =
+            function_id.try_get_function_with_body_id(db.upcast()).to_maybe()?;
+        let function_def = db.concrete_function_with_body_definition(function_id)?;
+        let signature = db.concrete_function_signature(function_id)?;
+        let implicits = db.function_with_body_all_implicits_vec(function_id)?;
         let ref_params = signature
             .params
             .iter()
@@ -47,7 +57,8 @@ impl<'db> LoweringContextBuilder<'db> {
             .collect();
         Ok(LoweringContextBuilder {
             db,
-            free_function_id,
+            function_id,
+            function_with_body_id,
             function_def,
             signature,
             ref_params,
@@ -56,14 +67,15 @@ impl<'db> LoweringContextBuilder<'db> {
     }
     pub fn ctx<'a: 'db>(&'a self) -> Maybe<LoweringContext<'db>> {
         let generic_params =
-            self.db.free_function_declaration_generic_params(self.free_function_id)?;
+            self.db.function_with_body_generic_params(self.function_with_body_id)?;
         Ok(LoweringContext {
             db: self.db,
+            function_id: self.function_id,
             function_def: &self.function_def,
             signature: &self.signature,
-            may_panic: self.db.free_function_may_panic(self.free_function_id)?,
+            may_panic: self.db.function_with_body_may_panic(self.function_id)?,
             diagnostics: LoweringDiagnostics::new(
-                self.free_function_id.module_file(self.db.upcast()),
+                self.function_with_body_id.module_file(self.db.upcast()),
             ),
             variables: Arena::default(),
             blocks: StructuredBlocks::new(),
@@ -71,23 +83,28 @@ impl<'db> LoweringContextBuilder<'db> {
             ref_params: &self.ref_params,
             implicits: &self.implicits,
             lookup_context: ImplLookupContext {
-                module_id: self.free_function_id.parent_module(self.db.upcast()),
+                module_id: self.function_with_body_id.parent_module(self.db.upcast()),
                 extra_modules: vec![],
                 generic_params,
             },
             expr_formatter: ExprFormatter {
                 db: self.db.upcast(),
-                free_function_id: self.free_function_id,
+                function_id: self.function_with_body_id,
             },
+            current_loop: None,
+            generated_lowerings: OrderedHashMap::default(),
+            assigned_vars: vec![],
         })
     }
 }
 
-/// Context for the lowering phase of a free function.
+/// Context for the lowering phase of a function with a body.
 pub struct LoweringContext<'db> {
     pub db: &'db dyn LoweringGroup,
+    /// The concrete function being lowered, or the parent of the generated function being lowered.
+    pub function_id: cairo_lang_semantic::FunctionId,
     /// Semantic model for current function definition.
-    pub function_def: &'db cairo_lang_semantic::FreeFunctionDefinition,
+    pub function_def: &'db cairo_lang_semantic::FunctionDefinition,
     // Semantic signature for current function.
     pub signature: &'db cairo_lang_semantic::Signature,
     /// Whether the current function may panic.
@@ -107,8 +124,15 @@ pub struct LoweringContext<'db> {
     pub implicits: &'db [cairo_lang_semantic::TypeId],
     // Lookup context for impls.
     pub lookup_context: ImplLookupContext,
-    // Expression formatter of the free function.
+    // Expression formatter of the function.
     pub expr_formatter: ExprFormatter<'db>,
+    /// The loop whose generated function is currently being lowered, if any.
+    pub current_loop: Option<LoopFunction>,
+    /// The lowered functions generated so far from loops in the function.
+    pub generated_lowerings: OrderedHashMap<cairo_lang_semantic::ExprId, GeneratedLowering>,
+    /// The variables whose assignments are currently being lowered, from the outermost to the
+    /// innermost.
+    pub assigned_vars: Vec<cairo_lang_semantic::VarId>,
 }
 impl<'db> LoweringContext<'db> {
     pub fn new_var(&mut self, ty: cairo_lang_semantic::TypeId) -> VariableId {
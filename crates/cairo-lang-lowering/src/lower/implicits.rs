@@ -1,19 +1,25 @@
 use std::collections::HashSet;
 
-use cairo_lang_defs::ids::{FreeFunctionId, GenericFunctionId};
+use cairo_lang_defs::ids::GenericFunctionId;
 use cairo_lang_diagnostics::Maybe;
-use cairo_lang_semantic::TypeId;
+use cairo_lang_semantic::{FunctionId, TypeId, TypeLongId};
 use cairo_lang_utils::strongly_connected_components::{compute_scc, GraphNode};
 use itertools::Itertools;
+use salsa::InternKey;
 
 use crate::db::{LoweringGroup, SCCRepresentative};
 
 /// Query implementation of [crate::db::LoweringGroup::function_scc_representative].
 pub fn function_scc_representative(
     db: &dyn LoweringGroup,
-    function: FreeFunctionId,
+    function: FunctionId,
 ) -> SCCRepresentative {
-    SCCRepresentative(db.function_scc(function).into_iter().min().unwrap_or(function))
+    SCCRepresentative(
+        db.function_scc(function)
+            .into_iter()
+            .min_by_key(|function| function.as_intern_id())
+            .unwrap_or(function),
+    )
 }
 
 /// Query implementation of [crate::db::LoweringGroup::function_scc_explicit_implicits].
@@ -25,30 +31,84 @@ pub fn function_scc_explicit_implicits(
     let mut explicit_implicits = HashSet::new();
     for func in scc {
         let current_implicits: HashSet<TypeId> =
-            db.free_function_declaration_implicits(func)?.into_iter().collect();
+            db.concrete_function_signature(func)?.implicits.into_iter().collect();
         explicit_implicits.extend(current_implicits);
     }
     Ok(explicit_implicits)
 }
 
+/// Returns the function with a body whose lowering is called for the given callee, if any.
+/// A call to a closure through the `Fn` trait calls the generated function of the closure, which
+/// shares the implicits and panic behavior of the function defining the closure.
+fn callee_function_with_body(db: &dyn LoweringGroup, function: FunctionId) -> Option<FunctionId> {
+    if function.try_get_function_with_body_id(db.upcast()).is_some() {
+        return Some(function);
+    }
+    let callee_ty = function.try_get_fn_trait_callee_ty(db.upcast())?;
+    match db.lookup_intern_type(callee_ty) {
+        TypeLongId::Closure(closure) => Some(closure.parent),
+        _ => None,
+    }
+}
+
+/// Returns the implicits of a callee without a body, taken from its declaration.
+fn declared_implicits(db: &dyn LoweringGroup, function: FunctionId) -> Maybe<Vec<TypeId>> {
+    match db.lookup_intern_function(function).function.generic_function {
+        GenericFunctionId::Extern(extern_function) => {
+            db.extern_function_declaration_implicits(extern_function)
+        }
+        // A trait function not resolved to a closure, e.g. `Fn::call` on a generic parameter.
+        GenericFunctionId::TraitFunction(_) => {
+            Ok(db.concrete_function_signature(function)?.implicits)
+        }
+        GenericFunctionId::Free(_) | GenericFunctionId::ImplFunction(_) => {
+            unreachable!("Functions with a body are handled by the caller.")
+        }
+    }
+}
+
+/// Returns whether a callee without a body may panic, according to its declaration.
+fn declared_may_panic(db: &dyn LoweringGroup, function: FunctionId) -> Maybe<bool> {
+    match db.lookup_intern_function(function).function.generic_function {
+        GenericFunctionId::Extern(extern_function) => {
+            Ok(db.extern_function_declaration_signature(extern_function)?.panicable)
+        }
+        GenericFunctionId::TraitFunction(_) => {
+            Ok(db.concrete_function_signature(function)?.panicable)
+        }
+        GenericFunctionId::Free(_) | GenericFunctionId::ImplFunction(_) => {
+            unreachable!("Functions with a body are handled by the caller.")
+        }
+    }
+}
+
 /// Query implementation of [crate::db::LoweringGroup::function_all_implicits].
 pub fn function_all_implicits(
     db: &dyn LoweringGroup,
     function: cairo_lang_semantic::FunctionId,
 ) -> Maybe<Vec<TypeId>> {
-    match db.lookup_intern_function(function).function.generic_function {
-        GenericFunctionId::Free(free_function) => db.free_function_all_implicits_vec(free_function),
-        GenericFunctionId::Extern(extern_function) => {
-            db.extern_function_declaration_implicits(extern_function)
-        }
-        GenericFunctionId::TraitFunction(_) | GenericFunctionId::ImplFunction(_) => todo!(),
+    match callee_function_with_body(db, function) {
+        Some(function_with_body) => db.function_with_body_all_implicits_vec(function_with_body),
+        None => declared_implicits(db, function),
+    }
+}
+
+/// Returns the direct callees of all the functions in an SCC.
+fn scc_direct_callees(
+    db: &dyn LoweringGroup,
+    scc_representative: &SCCRepresentative,
+) -> Maybe<Vec<FunctionId>> {
+    let mut direct_callees = vec![];
+    for function in db.function_scc(scc_representative.0) {
+        direct_callees.extend(db.concrete_function_with_body_direct_callees(function)?);
     }
+    Ok(direct_callees)
 }
 
-/// Query implementation of [crate::db::LoweringGroup::free_function_all_implicits].
-pub fn free_function_all_implicits(
+/// Query implementation of [crate::db::LoweringGroup::function_with_body_all_implicits].
+pub fn function_with_body_all_implicits(
     db: &dyn LoweringGroup,
-    function: FreeFunctionId,
+    function: FunctionId,
 ) -> Maybe<HashSet<TypeId>> {
     // Find the SCC representative.
     let scc_representative = db.function_scc_representative(function);
@@ -57,37 +117,32 @@ pub fn free_function_all_implicits(
     let mut all_implicits = db.function_scc_explicit_implicits(scc_representative.clone())?;
 
     // For each direct callee, add its implicits.
-    for direct_callee in db.free_function_definition_direct_callees(function)? {
-        let current_implicits =
-            match db.lookup_intern_function(direct_callee).function.generic_function {
-                GenericFunctionId::Free(free_function) => {
-                    // For a free function, call this method recursively. To avoid cycles, first
-                    // check that the callee is not in this function's SCC.
-                    let direct_callee_representative =
-                        db.function_scc_representative(free_function);
-                    if direct_callee_representative == scc_representative {
-                        // We already have the implicits of this SCC - do nothing.
-                        continue;
-                    }
-                    db.free_function_all_implicits(direct_callee_representative.0)?
-                }
-                GenericFunctionId::Extern(extern_function) => {
-                    // All implicits of a libfunc are explicit implicits.
-                    db.extern_function_declaration_implicits(extern_function)?.into_iter().collect()
+    for direct_callee in scc_direct_callees(db, &scc_representative)? {
+        let current_implicits = match callee_function_with_body(db, direct_callee) {
+            Some(direct_callee) => {
+                // For a function with a body, call this method recursively. To avoid cycles, first
+                // check that the callee is not in this function's SCC.
+                let direct_callee_representative = db.function_scc_representative(direct_callee);
+                if direct_callee_representative == scc_representative {
+                    // We already have the implicits of this SCC - do nothing.
+                    continue;
                 }
-                GenericFunctionId::TraitFunction(_) | GenericFunctionId::ImplFunction(_) => todo!(),
-            };
+                db.function_with_body_all_implicits(direct_callee_representative.0)?
+            }
+            // All implicits of a libfunc are explicit implicits.
+            None => declared_implicits(db, direct_callee)?.into_iter().collect(),
+        };
         all_implicits.extend(&current_implicits);
     }
     Ok(all_implicits)
 }
 
-/// Query implementation of [crate::db::LoweringGroup::free_function_all_implicits_vec].
-pub fn free_function_all_implicits_vec(
+/// Query implementation of [crate::db::LoweringGroup::function_with_body_all_implicits_vec].
+pub fn function_with_body_all_implicits_vec(
     db: &dyn LoweringGroup,
-    function: FreeFunctionId,
+    function: FunctionId,
 ) -> Maybe<Vec<TypeId>> {
-    let implicits_set = db.free_function_all_implicits(function)?;
+    let implicits_set = db.function_with_body_all_implicits(function)?;
     let mut implicits_vec = implicits_set.into_iter().collect_vec();
 
     let semantic_db = db.upcast();
@@ -104,33 +159,31 @@ pub fn free_function_all_implicits_vec(
 }
 
 /// Query implementation of [crate::db::LoweringGroup::function_scc].
-pub fn function_scc(db: &dyn LoweringGroup, function_id: FreeFunctionId) -> Vec<FreeFunctionId> {
-    compute_scc::<FreeFunctionNode<'_>>(FreeFunctionNode {
-        free_function_id: function_id,
-        db: db.upcast(),
-    })
+pub fn function_scc(db: &dyn LoweringGroup, function_id: FunctionId) -> Vec<FunctionId> {
+    compute_scc::<FunctionWithBodyNode<'_>>(FunctionWithBodyNode { function_id, db: db.upcast() })
 }
 
 /// A node to use in the SCC computation.
 #[derive(Clone)]
-struct FreeFunctionNode<'a> {
-    free_function_id: FreeFunctionId,
+struct FunctionWithBodyNode<'a> {
+    function_id: FunctionId,
     db: &'a dyn LoweringGroup,
 }
-impl<'a> GraphNode for FreeFunctionNode<'a> {
-    type NodeId = FreeFunctionId;
+impl<'a> GraphNode for FunctionWithBodyNode<'a> {
+    type NodeId = FunctionId;
 
     fn get_neighbors(&self) -> Vec<Self> {
         self.db
-            .free_function_definition_direct_free_function_callees(self.free_function_id)
+            .concrete_function_with_body_direct_callees(self.function_id)
             .unwrap_or_default()
             .into_iter()
-            .map(|free_function_id| FreeFunctionNode { free_function_id, db: self.db })
+            .filter_map(|function_id| callee_function_with_body(self.db, function_id))
+            .map(|function_id| FunctionWithBodyNode { function_id, db: self.db })
             .collect()
     }
 
     fn get_id(&self) -> Self::NodeId {
-        self.free_function_id
+        self.function_id
     }
 }
 
@@ -139,45 +192,38 @@ pub fn function_may_panic(
     db: &dyn LoweringGroup,
     function: cairo_lang_semantic::FunctionId,
 ) -> Maybe<bool> {
-    match db.lookup_intern_function(function).function.generic_function {
-        GenericFunctionId::Free(free_function) => db.free_function_may_panic(free_function),
-        GenericFunctionId::Extern(extern_function) => {
-            Ok(db.extern_function_declaration_signature(extern_function)?.panicable)
-        }
-        GenericFunctionId::TraitFunction(_) | GenericFunctionId::ImplFunction(_) => todo!(),
+    match callee_function_with_body(db, function) {
+        Some(function_with_body) => db.function_with_body_may_panic(function_with_body),
+        None => declared_may_panic(db, function),
     }
 }
 
-/// Query implementation of [crate::db::LoweringGroup::free_function_may_panic].
-pub fn free_function_may_panic(
-    db: &dyn LoweringGroup,
-    free_function: FreeFunctionId,
-) -> Maybe<bool> {
+/// Query implementation of [crate::db::LoweringGroup::function_with_body_may_panic].
+pub fn function_with_body_may_panic(db: &dyn LoweringGroup, function: FunctionId) -> Maybe<bool> {
     // Find the SCC representative.
-    let scc_representative = db.function_scc_representative(free_function);
+    let scc_representative = db.function_scc_representative(function);
 
     // TODO(spapini): Add something that actually panics.
     // For each direct callee, find if it may panic.
-    for direct_callee in db.free_function_definition_direct_callees(free_function)? {
-        match db.lookup_intern_function(direct_callee).function.generic_function {
-            GenericFunctionId::Free(free_function) => {
-                // For a free function, call this method recursively. To avoid cycles, first
-                // check that the callee is not in this function's SCC.
-                let direct_callee_representative = function_scc_representative(db, free_function);
+    for direct_callee in scc_direct_callees(db, &scc_representative)? {
+        match callee_function_with_body(db, direct_callee) {
+            Some(direct_callee) => {
+                // For a function with a body, call this method recursively. To avoid cycles,
+                // first check that the callee is not in this function's SCC.
+                let direct_callee_representative = function_scc_representative(db, direct_callee);
                 if direct_callee_representative == scc_representative {
                     // We already have the implicits of this SCC - do nothing.
                     continue;
                 }
-                if db.free_function_may_panic(direct_callee_representative.0)? {
+                if db.function_with_body_may_panic(direct_callee_representative.0)? {
                     return Ok(true);
                 }
             }
-            GenericFunctionId::Extern(extern_function) => {
-                if db.extern_function_declaration_signature(extern_function)?.panicable {
+            None => {
+                if declared_may_panic(db, direct_callee)? {
                     return Ok(true);
                 }
             }
-            GenericFunctionId::TraitFunction(_) | GenericFunctionId::ImplFunction(_) => todo!(),
         };
     }
     Ok(false)
@@ -0,0 +1,206 @@
+//! Lowering of closures.
+//! Each closure is lowered into a generated function. The value of a closure expression is a
+//! struct of the variables it captures. Calling the closure destructs this struct and passes the
+//! captured variables to the generated function, followed by the arguments of the call. The
+//! captured variables are returned back similarly to `ref` parameters, so the closure may be
+//! called again.
+
+use cairo_lang_debug::DebugWithDb;
+use cairo_lang_diagnostics::{Maybe, ToMaybe};
+use cairo_lang_semantic::corelib::get_panic_ty;
+use cairo_lang_semantic::expr::fmt::ExprFormatter;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::ClosureTypeLongId;
+use id_arena::Arena;
+use itertools::{chain, zip_eq};
+
+use super::context::{LoweredExpr, LoweringContext, LoweringFlowError};
+use super::scope::{generators, BlockFlowMerger, BlockScope};
+use super::{
+    finalize_lowered, lower_expr, lower_panic_error_propagate, lower_tail_expr, take_semantic_var,
+    use_semantic_var, GeneratedLowering,
+};
+use crate::blocks::StructuredBlocks;
+use crate::diagnostic::LoweringDiagnostics;
+use crate::ids::{FunctionLongId, GeneratedFunction};
+
+/// Lowers an expression of type [cairo_lang_semantic::ExprClosure]. The closure is lowered into a
+/// generated function, and the expression itself is lowered into a struct of the captured
+/// variables.
+pub fn lower_expr_closure(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr: &cairo_lang_semantic::ExprClosure,
+    expr_id: cairo_lang_semantic::ExprId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    log::trace!("Lowering a closure expression: {:?}", expr.debug(&ctx.expr_formatter));
+
+    lower_closure_function(ctx, expr, expr_id).map_err(LoweringFlowError::Failed)?;
+
+    let inputs = expr
+        .captured_vars
+        .iter()
+        .map(|semantic_var_id| {
+            use_semantic_var(ctx, scope, *semantic_var_id, expr.stable_ptr.untyped())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(LoweredExpr::AtVariable(generators::StructConstruct { inputs, ty: expr.ty }.add(ctx, scope)))
+}
+
+/// Lowers the generated function of a closure, and adds it to the generated lowerings of the
+/// context.
+fn lower_closure_function(
+    ctx: &mut LoweringContext<'_>,
+    expr: &cairo_lang_semantic::ExprClosure,
+    expr_id: cairo_lang_semantic::ExprId,
+) -> Maybe<()> {
+    let return_type = ctx.function_def.exprs[expr.body].ty();
+    let signature = cairo_lang_semantic::Signature { return_type, ..ctx.signature.clone() };
+    let param_vars: Vec<_> =
+        expr.params.iter().cloned().map(cairo_lang_semantic::Variable::Param).collect();
+    let mut semantic_defs = ctx.semantic_defs.clone();
+    for semantic_var in &param_vars {
+        semantic_defs.insert(semantic_var.id(), semantic_var.clone());
+    }
+    let mut closure_ctx = LoweringContext {
+        db: ctx.db,
+        function_id: ctx.function_id,
+        function_def: ctx.function_def,
+        signature: &signature,
+        may_panic: ctx.may_panic,
+        diagnostics: LoweringDiagnostics::new(ctx.diagnostics.module_file_id),
+        variables: Arena::default(),
+        blocks: StructuredBlocks::new(),
+        semantic_defs,
+        ref_params: &expr.captured_vars,
+        implicits: ctx.implicits,
+        lookup_context: ctx.lookup_context.clone(),
+        expr_formatter: ExprFormatter {
+            db: ctx.db.upcast(),
+            function_id: ctx.expr_formatter.function_id,
+        },
+        current_loop: None,
+        generated_lowerings: std::mem::take(&mut ctx.generated_lowerings),
+        assigned_vars: vec![],
+    };
+
+    let ref_tys: Vec<_> =
+        expr.captured_vars.iter().map(|var_id| ctx.semantic_defs[*var_id].ty()).collect();
+    let param_tys: Vec<_> =
+        chain!(ref_tys.iter().copied(), param_vars.iter().map(|var| var.ty())).collect();
+    let param_var_ids =
+        chain!(expr.captured_vars.iter().copied(), param_vars.iter().map(|var| var.id()));
+    let input_var_tys = chain!(ctx.implicits.iter().copied(), param_tys.iter().copied()).collect();
+    let (block_sealed_opt, mut merger_finalized) =
+        BlockFlowMerger::with_root(&mut closure_ctx, &expr.captured_vars, |ctx, merger| {
+            merger.run_in_subscope(ctx, input_var_tys, |ctx, scope, variables| {
+                let mut variables_iter = variables.into_iter();
+                for ty in ctx.implicits {
+                    let var = variables_iter.next().to_maybe()?;
+                    scope.put_implicit(ctx, *ty, var);
+                }
+                for (semantic_var_id, var) in zip_eq(param_var_ids, variables_iter) {
+                    scope.put_semantic_variable(ctx, semantic_var_id, var);
+                }
+                lower_tail_expr(ctx, scope, Some(expr.body), true)
+            })
+        });
+    let root = block_sealed_opt
+        .map(|block_sealed| merger_finalized.finalize_block(&mut closure_ctx, block_sealed).block);
+
+    let (lowered, generated_lowerings) = finalize_lowered(closure_ctx, root);
+    ctx.generated_lowerings = generated_lowerings;
+    // Report the diagnostics of the closure as diagnostics of the parent function.
+    ctx.diagnostics.diagnostics.extend(lowered.diagnostics.clone());
+    ctx.generated_lowerings
+        .insert(expr_id, GeneratedLowering { lowered, param_tys, ref_tys, return_type });
+    Ok(())
+}
+
+/// Lowers a call to a closure through the `Fn` trait, as a call to the generated function of the
+/// closure. The closure is the only `ref` argument of the call, and its arguments are given as a
+/// single tuple.
+pub fn lower_closure_call(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr: &cairo_lang_semantic::ExprFunctionCall,
+    closure_ty: cairo_lang_semantic::TypeId,
+    closure: ClosureTypeLongId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    log::trace!("Lowering a closure call: {:?}", expr.debug(&ctx.expr_formatter));
+    let stable_ptr = expr.stable_ptr.untyped();
+    let (Ok([closure_var_id]), Ok([args_expr_id])) =
+        (<[_; 1]>::try_from(expr.ref_args.clone()), <[_; 1]>::try_from(expr.args.clone()))
+    else {
+        unreachable!("`Fn::call` has a single ref parameter and a single parameter.");
+    };
+
+    // The closure is defined in its parent function, so its generated function is keyed by the
+    // closure expression in the parent's definition.
+    let parent_with_body = closure
+        .parent
+        .try_get_function_with_body_id(ctx.db.upcast())
+        .to_maybe()
+        .map_err(LoweringFlowError::Failed)?;
+    let element = ctx
+        .db
+        .lookup_expr_by_ptr(parent_with_body, closure.stable_ptr)
+        .map_err(LoweringFlowError::Failed)?;
+    let function = ctx.db.intern_lowering_function(FunctionLongId::Generated(GeneratedFunction {
+        parent: closure.parent,
+        element,
+    }));
+
+    let args = match lower_expr(ctx, scope, args_expr_id)? {
+        LoweredExpr::Tuple(items) => {
+            items.into_iter().map(|item| item.var(ctx, scope)).collect::<Result<Vec<_>, _>>()?
+        }
+        lowered_expr => generators::StructDestructure {
+            input: lowered_expr.var(ctx, scope)?,
+            tys: closure.param_tys.clone(),
+        }
+        .add(ctx, scope),
+    };
+    let closure_var = take_semantic_var(ctx, scope, closure_var_id, stable_ptr)?;
+    let captured_inputs =
+        generators::StructDestructure { input: closure_var, tys: closure.captured_tys.clone() }
+            .add(ctx, scope);
+
+    let callee_implicit_types =
+        ctx.db.function_all_implicits(expr.function).map_err(LoweringFlowError::Failed)?;
+    let implicits = callee_implicit_types
+        .iter()
+        .map(|ty| scope.take_implicit(*ty))
+        .collect::<Option<Vec<_>>>()
+        .to_maybe()
+        .map_err(LoweringFlowError::Failed)?;
+    let may_panic = ctx.db.function_may_panic(expr.function).map_err(LoweringFlowError::Failed)?;
+    let ret_ty =
+        if may_panic { get_panic_ty(ctx.db.upcast(), closure.ret_ty) } else { closure.ret_ty };
+
+    let call_result = generators::Call {
+        function,
+        inputs: chain!(implicits, captured_inputs, args).collect(),
+        ref_tys: closure.captured_tys,
+        ret_tys: vec![ret_ty],
+    }
+    .add(ctx, scope);
+
+    // Rebind the implicits.
+    for (implicit_type, implicit_output) in
+        zip_eq(callee_implicit_types, call_result.implicit_outputs)
+    {
+        scope.put_implicit(ctx, implicit_type, implicit_output);
+    }
+    // Rebuild the closure from the returned captured variables.
+    let closure_var =
+        generators::StructConstruct { inputs: call_result.ref_outputs, ty: closure_ty }
+            .add(ctx, scope);
+    scope.put_semantic_variable(ctx, closure_var_id, closure_var);
+
+    let result = LoweredExpr::AtVariable(call_result.returns.into_iter().next().unwrap());
+    if may_panic {
+        return lower_panic_error_propagate(ctx, scope, result, expr.ty);
+    }
+    Ok(result)
+}
@@ -5,10 +5,12 @@ use cairo_lang_utils::extract_matches;
 use num_traits::Zero;
 
 use super::context::{LoweredExpr, LoweringContext, LoweringFlowError};
+use super::lower_match::lower_expr_if_let;
 use super::scope::{generators, BlockFlowMerger, BlockScope, BlockScopeEnd};
 use super::{
     lower_block, lower_expr, lowered_expr_from_block_result, lowered_expr_to_block_scope_end,
 };
+use crate::ids::FunctionId;
 
 #[allow(dead_code)]
 enum IfCondition {
@@ -126,7 +128,7 @@ pub fn lower_expr_if_eq(
         let lowered_b = lower_expr(ctx, scope, expr_b)?.var(ctx, scope)?;
         let ret_ty = corelib::core_felt_ty(ctx.db.upcast());
         let call_result = generators::Call {
-            function: corelib::felt_sub(ctx.db.upcast()),
+            function: FunctionId::from_semantic(ctx.db, corelib::felt_sub(ctx.db.upcast())),
             inputs: vec![lowered_a, lowered_b],
             ref_tys: vec![],
             ret_tys: vec![ret_ty],
@@ -194,6 +196,10 @@ fn lower_optional_else_block(
                 let lowered_if = lower_expr_if(ctx, scope, if_expr);
                 lowered_expr_to_block_scope_end(ctx, scope, lowered_if, false)
             }
+            cairo_lang_semantic::Expr::IfLet(if_let_expr) => {
+                let lowered_if_let = lower_expr_if_let(ctx, scope, if_let_expr);
+                lowered_expr_to_block_scope_end(ctx, scope, lowered_if_let, false)
+            }
             _ => unreachable!(),
         },
         None => lowered_expr_to_block_scope_end(ctx, scope, Ok(LoweredExpr::Tuple(vec![])), false),
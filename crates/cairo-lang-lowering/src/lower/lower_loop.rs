@@ -0,0 +1,564 @@
+//! Lowering of loops.
+//! Each loop is lowered into a generated function, that runs a single iteration of the loop and
+//! calls itself recursively to continue to the next one. The variables captured by the loop are
+//! passed as parameters to the generated function, and the ones changed by it are returned back to
+//! the caller, similarly to `ref` parameters.
+//! A loop that may return early from the function containing it (using `return` or `?`) returns a
+//! `LoopResult` from its generated function, which the caller matches on to either continue with
+//! the value of the loop or return the early returned value.
+
+use cairo_lang_debug::DebugWithDb;
+use cairo_lang_diagnostics::{Maybe, ToMaybe};
+use cairo_lang_semantic::corelib::{
+    self, get_enum_concrete_variant, get_loop_result_ty, get_panic_ty,
+};
+use cairo_lang_semantic::expr::fmt::ExprFormatter;
+use cairo_lang_semantic::GenericArgumentId;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::extract_matches;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use id_arena::Arena;
+use itertools::{chain, zip_eq};
+
+use super::context::{
+    lowering_flow_error_to_block_scope_end, LoweredExpr, LoweringContext, LoweringFlowError,
+};
+use super::scope::{generators, BlockFlowMerger, BlockScope, BlockScopeEnd};
+use super::variables::LivingVar;
+use super::{
+    finalize_lowered, get_full_return_vars, get_plain_full_return_vars, lower_block, lower_expr,
+    lower_panic_error_propagate, lowered_expr_from_block_result, maybe_wrap_with_panic,
+    take_semantic_var, use_semantic_var, GeneratedLowering,
+};
+use crate::blocks::StructuredBlocks;
+use crate::db::LoweringGroup;
+use crate::diagnostic::LoweringDiagnostics;
+use crate::ids::{FunctionId, FunctionLongId, GeneratedFunction};
+
+/// Information about a loop, required for calling its generated function.
+#[derive(Clone, Debug)]
+pub struct LoopFunction {
+    /// The function generated for the loop.
+    pub function: FunctionId,
+    /// The semantic variables captured by the loop. These are the parameters of the generated
+    /// function (excluding implicits).
+    pub captured: Vec<cairo_lang_semantic::VarId>,
+    /// The captured variables that may be changed by the loop. These are returned from the
+    /// generated function, and rebound at the caller.
+    pub changed: Vec<cairo_lang_semantic::VarId>,
+    /// The type of the value of the loop expression.
+    pub ty: cairo_lang_semantic::TypeId,
+    /// The return type of the function containing the loop, if the loop may return early from it.
+    pub early_return_ty: Option<cairo_lang_semantic::TypeId>,
+}
+impl LoopFunction {
+    /// Returns the type returned by the generated function of the loop, before wrapping it with
+    /// PanicResult.
+    pub fn return_ty(&self, db: &dyn LoweringGroup) -> cairo_lang_semantic::TypeId {
+        match self.early_return_ty {
+            Some(early_return_ty) => get_loop_result_ty(db.upcast(), self.ty, early_return_ty),
+            None => self.ty,
+        }
+    }
+
+    /// Returns the variant of the result of the generated function of the loop with the given name.
+    /// Only valid for a loop that may return early.
+    fn result_variant(
+        &self,
+        db: &dyn LoweringGroup,
+        name: &str,
+    ) -> Maybe<cairo_lang_semantic::ConcreteVariant> {
+        let early_return_ty = self.early_return_ty.to_maybe()?;
+        Ok(get_enum_concrete_variant(
+            db.upcast(),
+            "LoopResult",
+            vec![GenericArgumentId::Type(self.ty), GenericArgumentId::Type(early_return_ty)],
+            name,
+        ))
+    }
+}
+
+/// The semantic variables used and changed by an expression.
+#[derive(Default)]
+struct VariablesUsage {
+    /// Variables whose value is used.
+    usages: OrderedHashSet<cairo_lang_semantic::VarId>,
+    /// Variables that are assigned to or passed as `ref` arguments.
+    changes: OrderedHashSet<cairo_lang_semantic::VarId>,
+    /// Whether the expression may return from the function containing it, using `return` or `?`.
+    returns_early: bool,
+}
+impl VariablesUsage {
+    /// Collects the variables used and changed by the given expression.
+    fn from_expr(
+        function_def: &cairo_lang_semantic::FunctionDefinition,
+        expr_id: cairo_lang_semantic::ExprId,
+    ) -> Self {
+        let mut usage = Self::default();
+        usage.handle_expr(function_def, expr_id);
+        usage
+    }
+
+    fn handle_expr(
+        &mut self,
+        function_def: &cairo_lang_semantic::FunctionDefinition,
+        expr_id: cairo_lang_semantic::ExprId,
+    ) {
+        match &function_def.exprs[expr_id] {
+            cairo_lang_semantic::Expr::Tuple(expr) => {
+                for item in &expr.items {
+                    self.handle_expr(function_def, *item);
+                }
+            }
+            cairo_lang_semantic::Expr::Assignment(expr) => {
+                self.changes.insert(expr.var);
+                self.handle_expr(function_def, expr.rhs);
+            }
+            cairo_lang_semantic::Expr::Block(expr) => {
+                for stmt_id in &expr.statements {
+                    self.handle_statement(function_def, &function_def.statements[*stmt_id]);
+                }
+                if let Some(tail) = expr.tail {
+                    self.handle_expr(function_def, tail);
+                }
+            }
+            cairo_lang_semantic::Expr::FunctionCall(expr) => {
+                for ref_arg in &expr.ref_args {
+                    self.usages.insert(*ref_arg);
+                    self.changes.insert(*ref_arg);
+                }
+                for arg in &expr.args {
+                    self.handle_expr(function_def, *arg);
+                }
+            }
+            cairo_lang_semantic::Expr::Match(expr) => {
+                self.handle_expr(function_def, expr.matched_expr);
+                for arm in &expr.arms {
+                    self.handle_expr(function_def, arm.expression);
+                }
+            }
+            cairo_lang_semantic::Expr::If(expr) => {
+                self.handle_expr(function_def, expr.condition);
+                self.handle_expr(function_def, expr.if_block);
+                if let Some(else_block) = expr.else_block {
+                    self.handle_expr(function_def, else_block);
+                }
+            }
+            cairo_lang_semantic::Expr::IfLet(expr) => {
+                self.handle_expr(function_def, expr.matched_expr);
+                self.handle_expr(function_def, expr.if_block);
+                if let Some(else_block) = expr.else_block {
+                    self.handle_expr(function_def, else_block);
+                }
+            }
+            cairo_lang_semantic::Expr::Loop(expr) => self.handle_expr(function_def, expr.body),
+            cairo_lang_semantic::Expr::While(expr) => {
+                self.handle_expr(function_def, expr.condition);
+                self.handle_expr(function_def, expr.body);
+            }
+            cairo_lang_semantic::Expr::Closure(expr) => {
+                // The body of the closure only uses its captured variables, which are copied when
+                // the closure is created.
+                self.usages.extend(expr.captured_vars.iter().copied());
+            }
+            cairo_lang_semantic::Expr::Var(expr) => {
+                self.usages.insert(expr.var);
+            }
+            cairo_lang_semantic::Expr::MemberAccess(expr) => {
+                self.handle_expr(function_def, expr.expr)
+            }
+            cairo_lang_semantic::Expr::TupleItem(expr) => self.handle_expr(function_def, expr.expr),
+            cairo_lang_semantic::Expr::StructCtor(expr) => {
+                for (_, member_expr) in &expr.members {
+                    self.handle_expr(function_def, *member_expr);
+                }
+            }
+            cairo_lang_semantic::Expr::EnumVariantCtor(expr) => {
+                self.handle_expr(function_def, expr.value_expr)
+            }
+            cairo_lang_semantic::Expr::PropagateError(expr) => {
+                self.returns_early = true;
+                self.handle_expr(function_def, expr.inner)
+            }
+            cairo_lang_semantic::Expr::Literal(_) | cairo_lang_semantic::Expr::Missing(_) => {}
+        }
+    }
+
+    fn handle_statement(
+        &mut self,
+        function_def: &cairo_lang_semantic::FunctionDefinition,
+        stmt: &cairo_lang_semantic::Statement,
+    ) {
+        match stmt {
+            cairo_lang_semantic::Statement::Expr(stmt) => self.handle_expr(function_def, stmt.expr),
+            cairo_lang_semantic::Statement::Let(stmt) => {
+                self.handle_expr(function_def, stmt.expr);
+                if let Some(else_block) = stmt.else_block {
+                    self.handle_expr(function_def, else_block);
+                }
+            }
+            cairo_lang_semantic::Statement::Return(stmt) => {
+                self.returns_early = true;
+                self.handle_expr(function_def, stmt.expr)
+            }
+            cairo_lang_semantic::Statement::Break(stmt) => {
+                if let Some(expr) = stmt.expr_option {
+                    self.handle_expr(function_def, expr);
+                }
+            }
+            cairo_lang_semantic::Statement::Continue(_) => {}
+        }
+    }
+}
+
+/// Lowers an expression of type [cairo_lang_semantic::ExprLoop] or
+/// [cairo_lang_semantic::ExprWhile]. The loop is lowered into a generated function, and the
+/// expression itself is lowered into a call to it.
+pub fn lower_expr_loop(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr_id: cairo_lang_semantic::ExprId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    let expr = &ctx.function_def.exprs[expr_id];
+    log::trace!("Lowering a loop expression: {:?}", expr.debug(&ctx.expr_formatter));
+    let stable_ptr = expr.stable_ptr().untyped();
+
+    // Only variables defined before the loop are captured. Variables defined inside the loop are
+    // not known yet.
+    let usage = VariablesUsage::from_expr(ctx.function_def, expr_id);
+    let captured: Vec<_> = chain!(usage.usages.iter(), usage.changes.iter())
+        .copied()
+        .filter(|var_id| ctx.semantic_defs.contains_key(var_id))
+        .collect::<OrderedHashSet<_>>()
+        .into_iter()
+        .collect();
+    let changed =
+        captured.iter().copied().filter(|var_id| usage.changes.contains(var_id)).collect();
+    let loop_function = LoopFunction {
+        function: ctx.db.intern_lowering_function(FunctionLongId::Generated(GeneratedFunction {
+            parent: ctx.function_id,
+            element: expr_id,
+        })),
+        captured,
+        changed,
+        ty: expr.ty(),
+        early_return_ty: if usage.returns_early { Some(function_return_ty(ctx)) } else { None },
+    };
+
+    lower_loop_function(ctx, expr_id, &loop_function).map_err(LoweringFlowError::Failed)?;
+
+    let result = call_loop_function(ctx, scope, &loop_function, stable_ptr)?;
+    let result = if ctx.may_panic {
+        lower_panic_error_propagate(
+            ctx,
+            scope,
+            LoweredExpr::AtVariable(result),
+            loop_function.return_ty(ctx.db),
+        )?
+    } else {
+        LoweredExpr::AtVariable(result)
+    };
+    if loop_function.early_return_ty.is_none() {
+        return Ok(result);
+    }
+    lower_loop_result(ctx, scope, &loop_function, result)
+}
+
+/// Returns the return type of the function containing the currently lowered code. Inside a loop,
+/// this is the return type of the function containing the loop, rather than of its generated
+/// function.
+fn function_return_ty(ctx: &LoweringContext<'_>) -> cairo_lang_semantic::TypeId {
+    match &ctx.current_loop {
+        Some(loop_function) => loop_function
+            .early_return_ty
+            .expect("A loop containing a loop that returns early must return early itself."),
+        None => ctx.signature.return_type,
+    }
+}
+
+/// Lowers a match on the result of a loop that may return early. Continues with the value of the
+/// loop, or returns the early returned value.
+fn lower_loop_result(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    loop_function: &LoopFunction,
+    result: LoweredExpr,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    let normal_variant =
+        loop_function.result_variant(ctx.db, "Normal").map_err(LoweringFlowError::Failed)?;
+    let early_return_variant =
+        loop_function.result_variant(ctx.db, "EarlyReturn").map_err(LoweringFlowError::Failed)?;
+    let var = result.var(ctx, scope)?;
+    let (res, mut finalized_merger) =
+        BlockFlowMerger::with(ctx, scope, &[], |ctx, merger| -> Result<_, LoweringFlowError> {
+            Ok([
+                merger
+                    .run_in_subscope(ctx, vec![normal_variant.ty], |_ctx, _subscope, arm_inputs| {
+                        let [var] = <[_; 1]>::try_from(arm_inputs).ok().unwrap();
+                        Ok(BlockScopeEnd::Callsite(Some(var)))
+                    })
+                    .map_err(LoweringFlowError::Failed)?,
+                merger
+                    .run_in_subscope(
+                        ctx,
+                        vec![early_return_variant.ty],
+                        |ctx, subscope, arm_inputs| {
+                            let [var] = <[_; 1]>::try_from(arm_inputs).ok().unwrap();
+                            match lower_return(ctx, subscope, LoweredExpr::AtVariable(var)) {
+                                Ok((refs, returns)) => Ok(BlockScopeEnd::Return { refs, returns }),
+                                Err(err) => lowering_flow_error_to_block_scope_end(err),
+                            }
+                        },
+                    )
+                    .map_err(LoweringFlowError::Failed)?,
+            ])
+        });
+    let finalized_blocks = res?.map(|sealed| finalized_merger.finalize_block(ctx, sealed).block);
+    let arms = zip_eq([normal_variant.clone(), early_return_variant], finalized_blocks).collect();
+
+    let block_result = (generators::MatchEnum {
+        input: var,
+        concrete_enum_id: normal_variant.concrete_enum_id,
+        arms,
+        end_info: finalized_merger.end_info.clone(),
+    })
+    .add(ctx, scope);
+    lowered_expr_from_block_result(ctx, scope, block_result, finalized_merger)
+}
+
+/// Lowers the generated function of a loop, and adds it to the generated lowerings of the context.
+fn lower_loop_function(
+    ctx: &mut LoweringContext<'_>,
+    expr_id: cairo_lang_semantic::ExprId,
+    loop_function: &LoopFunction,
+) -> Maybe<()> {
+    let return_type = loop_function.return_ty(ctx.db);
+    let signature = cairo_lang_semantic::Signature { return_type, ..ctx.signature.clone() };
+    let mut loop_ctx = LoweringContext {
+        db: ctx.db,
+        function_id: ctx.function_id,
+        function_def: ctx.function_def,
+        signature: &signature,
+        may_panic: ctx.may_panic,
+        diagnostics: LoweringDiagnostics::new(ctx.diagnostics.module_file_id),
+        variables: Arena::default(),
+        blocks: StructuredBlocks::new(),
+        semantic_defs: ctx.semantic_defs.clone(),
+        ref_params: &loop_function.changed,
+        implicits: ctx.implicits,
+        lookup_context: ctx.lookup_context.clone(),
+        expr_formatter: ExprFormatter {
+            db: ctx.db.upcast(),
+            function_id: ctx.expr_formatter.function_id,
+        },
+        current_loop: Some(loop_function.clone()),
+        generated_lowerings: std::mem::take(&mut ctx.generated_lowerings),
+        assigned_vars: vec![],
+    };
+
+    let param_tys: Vec<_> =
+        loop_function.captured.iter().map(|var_id| ctx.semantic_defs[*var_id].ty()).collect();
+    let ref_tys =
+        loop_function.changed.iter().map(|var_id| ctx.semantic_defs[*var_id].ty()).collect();
+    let input_var_tys = chain!(ctx.implicits.iter().copied(), param_tys.iter().copied()).collect();
+    let (block_sealed_opt, mut merger_finalized) =
+        BlockFlowMerger::with_root(&mut loop_ctx, &loop_function.changed, |ctx, merger| {
+            merger.run_in_subscope(ctx, input_var_tys, |ctx, scope, variables| {
+                let mut variables_iter = variables.into_iter();
+                for ty in ctx.implicits {
+                    let var = variables_iter.next().to_maybe()?;
+                    scope.put_implicit(ctx, *ty, var);
+                }
+                for (semantic_var_id, var) in zip_eq(&loop_function.captured, variables_iter) {
+                    scope.put_semantic_variable(ctx, *semantic_var_id, var);
+                }
+                lower_loop_body(ctx, scope, expr_id)
+            })
+        });
+    let root = block_sealed_opt
+        .map(|block_sealed| merger_finalized.finalize_block(&mut loop_ctx, block_sealed).block);
+
+    let (lowered, generated_lowerings) = finalize_lowered(loop_ctx, root);
+    ctx.generated_lowerings = generated_lowerings;
+    // Report the diagnostics of the loop as diagnostics of the parent function.
+    ctx.diagnostics.diagnostics.extend(lowered.diagnostics.clone());
+    ctx.generated_lowerings
+        .insert(expr_id, GeneratedLowering { lowered, param_tys, ref_tys, return_type });
+    Ok(())
+}
+
+/// Lowers a single iteration of a loop, as the body of its generated function.
+fn lower_loop_body(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr_id: cairo_lang_semantic::ExprId,
+) -> Maybe<BlockScopeEnd> {
+    match &ctx.function_def.exprs[expr_id] {
+        cairo_lang_semantic::Expr::Loop(expr) => lower_loop_iteration(ctx, scope, expr.body),
+        cairo_lang_semantic::Expr::While(expr) => {
+            let condition_var =
+                match lower_expr(ctx, scope, expr.condition).and_then(|e| e.var(ctx, scope)) {
+                    Ok(var) => var,
+                    Err(err) => return lowering_flow_error_to_block_scope_end(err),
+                };
+
+            let semantic_db = ctx.db.upcast();
+            let unit_ty = corelib::unit_ty(semantic_db);
+            let (res, mut finalized_merger) =
+                BlockFlowMerger::with(ctx, scope, &[], |ctx, merger| {
+                    let main_block_scope =
+                        merger.run_in_subscope(ctx, vec![unit_ty], |ctx, subscope, _| {
+                            lower_loop_iteration(ctx, subscope, expr.body)
+                        });
+                    let exit_block_scope =
+                        merger.run_in_subscope(ctx, vec![unit_ty], |ctx, subscope, _| {
+                            match lower_loop_break(ctx, subscope, LoweredExpr::Tuple(vec![])) {
+                                Ok((refs, returns)) => Ok(BlockScopeEnd::Return { refs, returns }),
+                                Err(err) => lowering_flow_error_to_block_scope_end(err),
+                            }
+                        });
+                    (main_block_scope, exit_block_scope)
+                });
+            let (main_block_sealed, exit_block_sealed) = res;
+            let main_finalized = finalized_merger.finalize_block(ctx, main_block_sealed?);
+            let exit_finalized = finalized_merger.finalize_block(ctx, exit_block_sealed?);
+
+            let block_result = (generators::MatchEnum {
+                input: condition_var,
+                concrete_enum_id: corelib::core_bool_enum(semantic_db),
+                arms: vec![
+                    (corelib::false_variant(semantic_db), exit_finalized.block),
+                    (corelib::true_variant(semantic_db), main_finalized.block),
+                ],
+                end_info: finalized_merger.end_info.clone(),
+            })
+            .add(ctx, scope);
+            match lowered_expr_from_block_result(ctx, scope, block_result, finalized_merger) {
+                Ok(_) => unreachable!("A loop iteration can not return to its callsite."),
+                Err(err) => lowering_flow_error_to_block_scope_end(err),
+            }
+        }
+        _ => unreachable!("Expected a loop expression."),
+    }
+}
+
+/// Lowers the body block of a loop. If the end of the block is reached, continues to the next
+/// iteration.
+fn lower_loop_iteration(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    body: cairo_lang_semantic::ExprId,
+) -> Maybe<BlockScopeEnd> {
+    let block = extract_matches!(&ctx.function_def.exprs[body], cairo_lang_semantic::Expr::Block);
+    match lower_block(ctx, scope, block, false)? {
+        BlockScopeEnd::Callsite(_) => {
+            match lower_loop_continue(ctx, scope, block.stable_ptr.untyped()) {
+                Ok((refs, returns)) => Ok(BlockScopeEnd::Return { refs, returns }),
+                Err(err) => lowering_flow_error_to_block_scope_end(err),
+            }
+        }
+        end => Ok(end),
+    }
+}
+
+/// Lowers a `break` from the current loop with the given value. Returns the variables to return
+/// from the generated function of the loop.
+pub fn lower_loop_break(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    lowered_expr: LoweredExpr,
+) -> Result<(Vec<LivingVar>, Vec<LivingVar>), LoweringFlowError> {
+    let loop_function = ctx.current_loop.clone().to_maybe().map_err(LoweringFlowError::Failed)?;
+    let lowered_expr = if loop_function.early_return_ty.is_some() {
+        let variant =
+            loop_function.result_variant(ctx.db, "Normal").map_err(LoweringFlowError::Failed)?;
+        let input = lowered_expr.var(ctx, scope)?;
+        LoweredExpr::AtVariable(generators::EnumConstruct { input, variant }.add(ctx, scope))
+    } else {
+        lowered_expr
+    };
+    let value_var = maybe_wrap_with_panic(ctx, lowered_expr, scope)?.var(ctx, scope)?;
+    get_plain_full_return_vars(ctx, scope, vec![value_var])
+}
+
+/// Lowers a return of the given value from the function containing the currently lowered code.
+/// Inside a loop, the value is returned from the generated function of the loop as an early return,
+/// for its caller to return it. Returns the variables to return from the current function.
+pub fn lower_return(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    lowered_expr: LoweredExpr,
+) -> Result<(Vec<LivingVar>, Vec<LivingVar>), LoweringFlowError> {
+    let Some(loop_function) = ctx.current_loop.clone() else {
+        return get_full_return_vars(ctx, scope, lowered_expr);
+    };
+    let variant =
+        loop_function.result_variant(ctx.db, "EarlyReturn").map_err(LoweringFlowError::Failed)?;
+    let input = lowered_expr.var(ctx, scope)?;
+    let value_var = generators::EnumConstruct { input, variant }.add(ctx, scope);
+    get_full_return_vars(ctx, scope, LoweredExpr::AtVariable(value_var))
+}
+
+/// Lowers a `continue` of the current loop, as a recursive call to the generated function of the
+/// loop. Returns the variables to return from the generated function of the loop.
+pub fn lower_loop_continue(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<(Vec<LivingVar>, Vec<LivingVar>), LoweringFlowError> {
+    let loop_function = ctx.current_loop.clone().to_maybe().map_err(LoweringFlowError::Failed)?;
+    // The result of the recursive call is already wrapped with PanicResult if needed.
+    let result = call_loop_function(ctx, scope, &loop_function, stable_ptr)?;
+    get_plain_full_return_vars(ctx, scope, vec![result])
+}
+
+/// Adds a call to the generated function of a loop. Rebinds the implicits and the changed
+/// variables, and returns the result variable of the call (wrapped with PanicResult if the loop
+/// may panic).
+fn call_loop_function(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    loop_function: &LoopFunction,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<LivingVar, LoweringFlowError> {
+    let implicits = ctx
+        .implicits
+        .iter()
+        .map(|ty| scope.take_implicit(*ty))
+        .collect::<Option<Vec<_>>>()
+        .to_maybe()
+        .map_err(LoweringFlowError::Failed)?;
+    let captured_inputs = loop_function
+        .captured
+        .iter()
+        .map(|semantic_var_id| {
+            if loop_function.changed.contains(semantic_var_id) {
+                take_semantic_var(ctx, scope, *semantic_var_id, stable_ptr)
+            } else {
+                use_semantic_var(ctx, scope, *semantic_var_id, stable_ptr)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let ref_tys =
+        loop_function.changed.iter().map(|var_id| ctx.semantic_defs[*var_id].ty()).collect();
+    let ret_ty = loop_function.return_ty(ctx.db);
+    let ret_ty = if ctx.may_panic { get_panic_ty(ctx.db.upcast(), ret_ty) } else { ret_ty };
+
+    let call_result = generators::Call {
+        function: loop_function.function,
+        inputs: chain!(implicits, captured_inputs).collect(),
+        ref_tys,
+        ret_tys: vec![ret_ty],
+    }
+    .add(ctx, scope);
+
+    // Rebind the implicits.
+    for (ty, output_var) in zip_eq(ctx.implicits, call_result.implicit_outputs) {
+        scope.put_implicit(ctx, *ty, output_var);
+    }
+    // Rebind the changed variables.
+    for (semantic_var_id, output_var) in zip_eq(&loop_function.changed, call_result.ref_outputs) {
+        scope.put_semantic_variable(ctx, *semantic_var_id, output_var);
+    }
+    Ok(call_result.returns.into_iter().next().unwrap())
+}
@@ -0,0 +1,686 @@
+//! Lowering of match expressions, `if let` expressions and `let` statements with refutable
+//! patterns.
+//!
+//! The arms of a match are compiled into a decision tree of [generators::MatchEnum] and
+//! [generators::MatchExtern] statements. The state of the compilation is a pattern matrix: the
+//! columns are parts of the matched value, each held by an anonymous variable of the scope, and
+//! each row holds the remaining patterns of an arm that may still match. At every step, the
+//! decision tree inspects a column that the first row needs to inspect, until the first row
+//! matches any value.
+
+use cairo_lang_diagnostics::{Maybe, ToMaybe};
+use cairo_lang_semantic::corelib::{
+    core_bool_enum, core_felt_ty, core_jump_nz_func, get_core_function_id, jump_nz_zero_variant,
+    true_variant, try_get_core_integer_type_name,
+};
+use cairo_lang_semantic::items::enm::SemanticEnumEx;
+use cairo_lang_semantic::items::strct::SemanticStructEx;
+use cairo_lang_semantic::{ConcreteTypeId, ConcreteVariant, Pattern, TypeLongId};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::try_extract_matches;
+use itertools::{zip_eq, Itertools};
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+use super::context::{LoweredExpr, LoweredExprExternEnum, LoweringContext, LoweringFlowError};
+use super::external::extern_facade_expr;
+use super::scope::{generators, BlockFlowMerger, BlockScope};
+use super::variables::LivingVar;
+use super::{
+    lower_expr, lower_single_pattern, lowered_expr_from_block_result,
+    lowered_expr_to_block_scope_end, match_extern_arm_ref_args_bind,
+    match_extern_variant_arm_input_types, perform_function_call, take_semantic_var,
+};
+use crate::diagnostic::LoweringDiagnosticKind::*;
+use crate::VariableId;
+
+/// The value a pattern variable is bound to, in terms of the columns of the pattern matrix.
+#[derive(Clone)]
+enum BoundValue {
+    /// The value of a column.
+    Column(VariableId),
+    /// An enum value of the given variant, with the given inner value.
+    Variant(ConcreteVariant, Box<BoundValue>),
+    /// A struct or a tuple of the given type, with the given member values.
+    Members(cairo_lang_semantic::TypeId, Vec<BoundValue>),
+}
+impl BoundValue {
+    /// Replaces the value of a column that was consumed with the value that represents it.
+    fn substitute(&mut self, column: VariableId, value: &BoundValue) {
+        match self {
+            BoundValue::Column(var_id) => {
+                if *var_id == column {
+                    *self = value.clone();
+                }
+            }
+            BoundValue::Variant(_, inner) => inner.substitute(column, value),
+            BoundValue::Members(_, members) => {
+                for member in members {
+                    member.substitute(column, value);
+                }
+            }
+        }
+    }
+
+    /// Builds the value in the scope.
+    fn lower(
+        self,
+        ctx: &mut LoweringContext<'_>,
+        scope: &mut BlockScope,
+    ) -> Result<LivingVar, LoweringFlowError> {
+        Ok(match self {
+            BoundValue::Column(column) => use_column(ctx, scope, column)?,
+            BoundValue::Variant(variant, inner) => {
+                let input = inner.lower(ctx, scope)?;
+                generators::EnumConstruct { input, variant }.add(ctx, scope)
+            }
+            BoundValue::Members(ty, members) => {
+                let inputs = members
+                    .into_iter()
+                    .map(|member| member.lower(ctx, scope))
+                    .collect::<Result<Vec<_>, _>>()?;
+                generators::StructConstruct { inputs, ty }.add(ctx, scope)
+            }
+        })
+    }
+}
+
+/// What a row evaluates to, once it matched.
+#[derive(Clone, Copy)]
+enum RowBody<'a> {
+    /// The expression of a match arm, or a block of an if expression.
+    Expr(cairo_lang_semantic::ExprId),
+    /// The unit value, for an if expression without an else block.
+    Unit,
+    /// The values of the variables of the pattern of a let statement, as a tuple.
+    LetVariables(&'a Pattern),
+}
+
+/// A row of the pattern matrix, representing an arm that may still match.
+#[derive(Clone)]
+struct Row<'a> {
+    /// The patterns that remain to be matched, one for each column. `None` matches any value.
+    patterns: Vec<Option<&'a Pattern>>,
+    /// The variable patterns of the columns that were already consumed, with their values.
+    bindings: Vec<(&'a Pattern, BoundValue)>,
+    /// What the row evaluates to.
+    body: RowBody<'a>,
+}
+impl<'a> Row<'a> {
+    /// Creates a row for a value matched against the given pattern.
+    fn new(pattern: Option<&'a Pattern>, body: RowBody<'a>) -> Self {
+        Self { patterns: vec![pattern], bindings: vec![], body }
+    }
+
+    /// Replaces the pattern at `idx` with the patterns for the inner values of its column, after
+    /// the column was consumed. `value` is the value of the consumed column `column`, if there was
+    /// such a column.
+    fn consume(
+        &mut self,
+        idx: usize,
+        inner_patterns: Vec<Option<&'a Pattern>>,
+        column: Option<VariableId>,
+        value: &BoundValue,
+    ) {
+        let pattern = self.patterns.splice(idx..idx + 1, inner_patterns).next().flatten();
+        if let Some(column) = column {
+            for (_, bound_value) in self.bindings.iter_mut() {
+                bound_value.substitute(column, value);
+            }
+        }
+        if let Some(pattern @ Pattern::Variable(_)) = pattern {
+            self.bindings.push((pattern, value.clone()));
+        }
+    }
+}
+
+/// Returns true if the pattern matches any value.
+fn is_wildcard(pattern: Option<&Pattern>) -> bool {
+    matches!(pattern, None | Some(Pattern::Variable(_) | Pattern::Otherwise(_)))
+}
+
+/// Lowers an expression of type [cairo_lang_semantic::ExprMatch].
+pub fn lower_expr_match(
+    ctx: &mut LoweringContext<'_>,
+    expr: &cairo_lang_semantic::ExprMatch,
+    scope: &mut BlockScope,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    log::trace!("Lowering a match expression.");
+    let lowered_expr = lower_expr(ctx, scope, expr.matched_expr)?;
+    let rows = expr
+        .arms
+        .iter()
+        .map(|arm| Row::new(Some(&arm.pattern), RowBody::Expr(arm.expression)))
+        .collect_vec();
+    lower_rows(ctx, scope, lowered_expr, rows, expr.stable_ptr.untyped())
+}
+
+/// Lowers an expression of type [cairo_lang_semantic::ExprIfLet].
+pub fn lower_expr_if_let(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr: &cairo_lang_semantic::ExprIfLet,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    log::trace!("Lowering an if let expression.");
+    let lowered_expr = lower_expr(ctx, scope, expr.matched_expr)?;
+    let rows = vec![
+        Row::new(Some(&expr.pattern), RowBody::Expr(expr.if_block)),
+        Row::new(None, expr.else_block.map_or(RowBody::Unit, RowBody::Expr)),
+    ];
+    lower_rows(ctx, scope, lowered_expr, rows, expr.stable_ptr.untyped())
+}
+
+/// Lowers a let statement whose pattern requires a match, and binds the variables of the pattern
+/// in the scope. If the pattern does not match, `else_block` is evaluated.
+pub fn lower_let_with_match(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    pattern: &Pattern,
+    lowered_expr: LoweredExpr,
+    else_block: Option<cairo_lang_semantic::ExprId>,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<(), LoweringFlowError> {
+    log::trace!("Lowering a let statement with a match.");
+    let mut rows = vec![Row::new(Some(pattern), RowBody::LetVariables(pattern))];
+    if let Some(else_block) = else_block {
+        rows.push(Row::new(None, RowBody::Expr(else_block)));
+    }
+    let variables = pattern.variables();
+    let vars = match lower_rows(ctx, scope, lowered_expr, rows, stable_ptr)? {
+        LoweredExpr::Tuple(items) => {
+            items.into_iter().map(|item| item.var(ctx, scope)).collect::<Result<Vec<_>, _>>()?
+        }
+        lowered_expr => {
+            let input = lowered_expr.var(ctx, scope)?;
+            let tys = variables.iter().map(|variable| variable.var.ty).collect();
+            generators::StructDestructure { input, tys }.add(ctx, scope)
+        }
+    };
+    for (variable, var) in zip_eq(variables, vars) {
+        let sem_var = cairo_lang_semantic::Variable::Local(variable.var.clone());
+        scope.put_semantic_variable(ctx, sem_var.id(), var);
+        ctx.semantic_defs.insert(sem_var.id(), sem_var);
+    }
+    Ok(())
+}
+
+/// Returns true if the pattern has an enum variant pattern, so binding its variables requires a
+/// match.
+pub fn has_enum_variant_pattern(pattern: &Pattern) -> bool {
+    match pattern {
+        Pattern::EnumVariant(_) => true,
+        Pattern::Struct(pattern_struct) => pattern_struct
+            .field_patterns
+            .iter()
+            .any(|(_, field_pattern)| has_enum_variant_pattern(field_pattern)),
+        Pattern::Tuple(pattern_tuple) => pattern_tuple
+            .field_patterns
+            .iter()
+            .any(|field_pattern| has_enum_variant_pattern(field_pattern)),
+        Pattern::Literal(_) | Pattern::Variable(_) | Pattern::Otherwise(_) => false,
+    }
+}
+
+/// Lowers the decision tree of the rows, for the given matched value.
+fn lower_rows(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    lowered_expr: LoweredExpr,
+    rows: Vec<Row<'_>>,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    if let LoweredExpr::ExternEnum(extern_enum) = lowered_expr {
+        // Match directly on the result of the extern function call.
+        return lower_extern_switch(
+            ctx,
+            scope,
+            extern_enum,
+            |ctx, subscope, variant, variant_expr| {
+                let (columns, value) = put_lowered_expr(ctx, subscope, variant_expr)?;
+                let rows = specialize_by_variant(&rows, 0, None, variant, &value);
+                let rows = split_rows(ctx, rows, &columns, &value)?;
+                Ok((columns, rows))
+            },
+            stable_ptr,
+        );
+    }
+    let (columns, value) = put_lowered_expr(ctx, scope, lowered_expr)?;
+    let rows = split_rows(ctx, rows, &columns, &value)?;
+    lower_decision_tree(ctx, scope, columns, rows, stable_ptr)
+}
+
+/// Puts the value of a lowered expression in new columns. Tuples are put in a column for each of
+/// their items, without constructing them. Returns the new columns and the value they represent.
+fn put_lowered_expr(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    lowered_expr: LoweredExpr,
+) -> Result<(Vec<VariableId>, BoundValue), LoweringFlowError> {
+    let ty = lowered_expr.ty(ctx);
+    Ok(match lowered_expr {
+        LoweredExpr::Tuple(items) => {
+            let mut columns = vec![];
+            for item in items {
+                let var = item.var(ctx, scope)?;
+                columns.push(scope.put_anonymous_variable(var));
+            }
+            let members = columns.iter().map(|column| BoundValue::Column(*column)).collect();
+            (columns, BoundValue::Members(ty, members))
+        }
+        lowered_expr => {
+            let var = lowered_expr.var(ctx, scope)?;
+            let column = scope.put_anonymous_variable(var);
+            (vec![column], BoundValue::Column(column))
+        }
+    })
+}
+
+/// Replaces the first column of the rows, holding the patterns of the value put by
+/// [put_lowered_expr], with the patterns of its new columns.
+fn split_rows<'a>(
+    ctx: &LoweringContext<'_>,
+    rows: Vec<Row<'a>>,
+    columns: &[VariableId],
+    value: &BoundValue,
+) -> Result<Vec<Row<'a>>, LoweringFlowError> {
+    match value {
+        BoundValue::Members(ty, _) => specialize_by_members(ctx, &rows, 0, None, *ty, columns)
+            .map_err(LoweringFlowError::Failed),
+        _ => Ok(rows),
+    }
+}
+
+/// Lowers the decision tree of a pattern matrix.
+fn lower_decision_tree(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    columns: Vec<VariableId>,
+    rows: Vec<Row<'_>>,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    let Some(first_row) = rows.first() else {
+        // No arm matches. This is only possible if there is a column of an enum with no variants.
+        let idx = columns
+            .iter()
+            .position(|column| {
+                let ty = ctx.variables[*column].ty;
+                let variants =
+                    try_extract_matches!(ctx.db.lookup_intern_type(ty), TypeLongId::Concrete)
+                        .and_then(|concrete_ty| {
+                            try_extract_matches!(concrete_ty, ConcreteTypeId::Enum)
+                        })
+                        .and_then(|concrete_enum_id| {
+                            ctx.db.concrete_enum_variants(concrete_enum_id).ok()
+                        });
+                matches!(variants, Some(variants) if variants.is_empty())
+            })
+            .ok_or_else(|| {
+                LoweringFlowError::Failed(ctx.diagnostics.report(stable_ptr, UnsupportedMatch))
+            })?;
+        return lower_enum_switch(ctx, scope, columns, rows, idx, stable_ptr);
+    };
+    let Some(idx) = first_row.patterns.iter().position(|pattern| !is_wildcard(*pattern)) else {
+        // The first row matches any value.
+        return lower_arm(ctx, scope, columns, rows.into_iter().next().unwrap(), stable_ptr);
+    };
+    match first_row.patterns[idx].unwrap() {
+        Pattern::EnumVariant(_) => lower_enum_switch(ctx, scope, columns, rows, idx, stable_ptr),
+        Pattern::Struct(_) | Pattern::Tuple(_) => {
+            let column = columns[idx];
+            let ty = ctx.variables[column].ty;
+            let tys = match ctx.db.lookup_intern_type(ty) {
+                TypeLongId::Tuple(tys) => tys,
+                TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => ctx
+                    .db
+                    .concrete_struct_members(concrete_struct_id)
+                    .map_err(LoweringFlowError::Failed)?
+                    .values()
+                    .map(|member| member.ty)
+                    .collect(),
+                _ => {
+                    return Err(LoweringFlowError::Failed(
+                        ctx.diagnostics.report(stable_ptr, UnsupportedMatch),
+                    ));
+                }
+            };
+            let input = use_column(ctx, scope, column)?;
+            let inner_columns = generators::StructDestructure { input, tys }
+                .add(ctx, scope)
+                .into_iter()
+                .map(|var| scope.put_anonymous_variable(var))
+                .collect_vec();
+            let rows = specialize_by_members(ctx, &rows, idx, Some(column), ty, &inner_columns)
+                .map_err(LoweringFlowError::Failed)?;
+            let columns = chain_columns(&columns[..idx], inner_columns, &columns[idx + 1..]);
+            lower_decision_tree(ctx, scope, columns, rows, stable_ptr)
+        }
+        Pattern::Literal(pattern_literal) => {
+            let value = pattern_literal.literal.value.clone();
+            lower_literal_switch(ctx, scope, columns, rows, idx, value, stable_ptr)
+        }
+        Pattern::Variable(_) | Pattern::Otherwise(_) => unreachable!(),
+    }
+}
+
+/// Lowers the arm of the given row, after the row matched.
+fn lower_arm(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    columns: Vec<VariableId>,
+    row: Row<'_>,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    let mut bindings = row.bindings;
+    for (pattern, column) in zip_eq(row.patterns, columns) {
+        if let Some(pattern @ Pattern::Variable(_)) = pattern {
+            bindings.push((pattern, BoundValue::Column(column)));
+        }
+    }
+    for (pattern, value) in bindings {
+        let var = value.lower(ctx, scope)?;
+        lower_single_pattern(ctx, scope, pattern, LoweredExpr::AtVariable(var))?;
+    }
+    match row.body {
+        RowBody::Expr(expr_id) => lower_expr(ctx, scope, expr_id),
+        RowBody::Unit => Ok(LoweredExpr::Tuple(vec![])),
+        RowBody::LetVariables(pattern) => {
+            let mut items = vec![];
+            for variable in pattern.variables() {
+                let sem_var = cairo_lang_semantic::VarId::Local(variable.var.id);
+                items.push(LoweredExpr::AtVariable(take_semantic_var(
+                    ctx, scope, sem_var, stable_ptr,
+                )?));
+            }
+            Ok(LoweredExpr::Tuple(items))
+        }
+    }
+}
+
+/// Lowers a switch on the variant of the enum in column `idx`.
+fn lower_enum_switch(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    columns: Vec<VariableId>,
+    rows: Vec<Row<'_>>,
+    idx: usize,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    let column = columns[idx];
+    let concrete_enum_id = try_extract_matches!(
+        ctx.db.lookup_intern_type(ctx.variables[column].ty),
+        TypeLongId::Concrete
+    )
+    .and_then(|concrete_ty| try_extract_matches!(concrete_ty, ConcreteTypeId::Enum))
+    .to_maybe()
+    .map_err(LoweringFlowError::Failed)?;
+    let concrete_variants =
+        ctx.db.concrete_enum_variants(concrete_enum_id).map_err(LoweringFlowError::Failed)?;
+    let input = use_column(ctx, scope, column)?;
+
+    // Merge arm blocks.
+    let (res, mut finalized_merger) = BlockFlowMerger::with(ctx, scope, &[], |ctx, merger| {
+        // Create a sealed block for each variant.
+        let block_opts = concrete_variants.iter().map(|variant| {
+            merger.run_in_subscope(ctx, vec![variant.ty], |ctx, subscope, arm_inputs| {
+                // This assert is ok.
+                assert_eq!(arm_inputs.len(), 1);
+                let inner_column =
+                    subscope.put_anonymous_variable(arm_inputs.into_iter().next().unwrap());
+                let rows = specialize_by_variant(
+                    &rows,
+                    idx,
+                    Some(column),
+                    variant,
+                    &BoundValue::Column(inner_column),
+                );
+                let mut columns = columns.clone();
+                columns[idx] = inner_column;
+                let lowered_expr = lower_decision_tree(ctx, subscope, columns, rows, stable_ptr);
+                lowered_expr_to_block_scope_end(ctx, subscope, lowered_expr, false)
+            })
+        });
+        block_opts.collect::<Maybe<Vec<_>>>().map_err(LoweringFlowError::Failed)
+    });
+    let finalized_blocks =
+        res?.into_iter().map(|sealed| finalized_merger.finalize_block(ctx, sealed).block);
+    let arms = zip_eq(concrete_variants, finalized_blocks).collect();
+
+    // Emit the statement.
+    let block_result = generators::MatchEnum {
+        input,
+        concrete_enum_id,
+        arms,
+        end_info: finalized_merger.end_info.clone(),
+    }
+    .add(ctx, scope);
+    lowered_expr_from_block_result(ctx, scope, block_result, finalized_merger)
+}
+
+/// Lowers a switch on whether the value in column `idx` is equal to the literal `value`.
+fn lower_literal_switch(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    columns: Vec<VariableId>,
+    rows: Vec<Row<'_>>,
+    idx: usize,
+    value: BigInt,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    let semantic_db = ctx.db.upcast();
+    let ty = ctx.variables[columns[idx]].ty;
+    // Numeric values are duplicatable, so the column remains available for the inner decisions.
+    let input = use_column(ctx, scope, columns[idx])?;
+    let (extern_enum, equal_variant) = if ty == core_felt_ty(semantic_db) {
+        // Felts are compared using `felt_jump_nz` on the difference from the literal.
+        let diff = if value.is_zero() {
+            input
+        } else {
+            let literal = generators::Literal { value: value.clone(), ty }.add(ctx, scope);
+            let felt_sub = get_core_function_id(semantic_db, "felt_sub".into(), vec![]);
+            let (_, _, diff) =
+                perform_function_call(ctx, scope, felt_sub, vec![input, literal], vec![], ty)?;
+            diff.var(ctx, scope)?
+        };
+        let zero_variant = jump_nz_zero_variant(semantic_db);
+        let extern_enum = LoweredExprExternEnum {
+            function: core_jump_nz_func(semantic_db),
+            concrete_enum_id: zero_variant.concrete_enum_id,
+            inputs: vec![diff],
+            ref_args: vec![],
+            implicits: vec![],
+            stable_ptr,
+        };
+        (extern_enum, zero_variant)
+    } else {
+        // Integers are compared using their `eq` extern function.
+        let type_name = try_get_core_integer_type_name(semantic_db, ty).ok_or_else(|| {
+            LoweringFlowError::Failed(ctx.diagnostics.report(stable_ptr, UnsupportedMatch))
+        })?;
+        let literal = generators::Literal { value: value.clone(), ty }.add(ctx, scope);
+        let extern_enum = LoweredExprExternEnum {
+            function: get_core_function_id(semantic_db, format!("{type_name}_eq").into(), vec![]),
+            concrete_enum_id: core_bool_enum(semantic_db),
+            inputs: vec![input, literal],
+            ref_args: vec![],
+            implicits: vec![],
+            stable_ptr,
+        };
+        (extern_enum, true_variant(semantic_db))
+    };
+    lower_extern_switch(
+        ctx,
+        scope,
+        extern_enum,
+        |_ctx, _subscope, variant, _variant_expr| {
+            let is_equal = *variant == equal_variant;
+            Ok((columns.clone(), specialize_by_literal(&rows, idx, &value, is_equal)))
+        },
+        stable_ptr,
+    )
+}
+
+/// Lowers a switch on the variant of the result of an extern function call. `arm_matrix` returns
+/// the pattern matrix of each variant, given the value of the variant.
+fn lower_extern_switch<'a>(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    extern_enum: LoweredExprExternEnum,
+    arm_matrix: impl Fn(
+        &mut LoweringContext<'_>,
+        &mut BlockScope,
+        &ConcreteVariant,
+        LoweredExpr,
+    ) -> Result<(Vec<VariableId>, Vec<Row<'a>>), LoweringFlowError>,
+    stable_ptr: SyntaxStablePtrId,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    let concrete_variants = ctx
+        .db
+        .concrete_enum_variants(extern_enum.concrete_enum_id)
+        .map_err(LoweringFlowError::Failed)?;
+
+    // Merge arm blocks.
+    let (res, mut finalized_merger) =
+        BlockFlowMerger::with(ctx, scope, &extern_enum.ref_args, |ctx, merger| {
+            // Create a sealed block for each variant.
+            let block_opts = concrete_variants.iter().map(|variant| {
+                let input_tys = match_extern_variant_arm_input_types(ctx, variant.ty, &extern_enum);
+                merger.run_in_subscope(ctx, input_tys, |ctx, subscope, mut arm_inputs| {
+                    // Bind the arm inputs to implicits and semantic variables.
+                    match_extern_arm_ref_args_bind(ctx, &mut arm_inputs, &extern_enum, subscope);
+
+                    let variant_expr = extern_facade_expr(ctx, variant.ty, arm_inputs);
+                    let lowered_expr = arm_matrix(ctx, subscope, variant, variant_expr).and_then(
+                        |(columns, rows)| {
+                            lower_decision_tree(ctx, subscope, columns, rows, stable_ptr)
+                        },
+                    );
+                    lowered_expr_to_block_scope_end(ctx, subscope, lowered_expr, false)
+                })
+            });
+            block_opts.collect::<Maybe<Vec<_>>>().map_err(LoweringFlowError::Failed)
+        });
+    let finalized_blocks = res?
+        .into_iter()
+        .map(|sealed| finalized_merger.finalize_block(ctx, sealed).block)
+        .collect_vec();
+    let arms = zip_eq(concrete_variants, finalized_blocks).collect();
+
+    // Emit the statement.
+    let block_result = generators::MatchExtern {
+        function: extern_enum.function,
+        inputs: extern_enum.inputs,
+        arms,
+        end_info: finalized_merger.end_info.clone(),
+    }
+    .add(ctx, scope);
+    lowered_expr_from_block_result(ctx, scope, block_result, finalized_merger)
+}
+
+/// Returns the rows that may match when the enum in column `idx` is of the given variant, with the
+/// given inner value. `column` is the consumed column of the enum, if any.
+fn specialize_by_variant<'a>(
+    rows: &[Row<'a>],
+    idx: usize,
+    column: Option<VariableId>,
+    variant: &ConcreteVariant,
+    inner_value: &BoundValue,
+) -> Vec<Row<'a>> {
+    let value = BoundValue::Variant(variant.clone(), Box::new(inner_value.clone()));
+    rows.iter()
+        .filter_map(|row| {
+            let inner_pattern = match row.patterns[idx] {
+                Some(Pattern::EnumVariant(pattern_enum_variant)) => {
+                    if pattern_enum_variant.variant != *variant {
+                        return None;
+                    }
+                    Some(&*pattern_enum_variant.inner_pattern)
+                }
+                _ => None,
+            };
+            let mut row = row.clone();
+            row.consume(idx, vec![inner_pattern], column, &value);
+            Some(row)
+        })
+        .collect()
+}
+
+/// Returns the rows, after the struct or tuple of type `ty` in column `idx` was destructured into
+/// `inner_columns`. `column` is the consumed column of the struct or tuple, if any.
+fn specialize_by_members<'a>(
+    ctx: &LoweringContext<'_>,
+    rows: &[Row<'a>],
+    idx: usize,
+    column: Option<VariableId>,
+    ty: cairo_lang_semantic::TypeId,
+    inner_columns: &[VariableId],
+) -> Maybe<Vec<Row<'a>>> {
+    let value = BoundValue::Members(
+        ty,
+        inner_columns.iter().map(|inner_column| BoundValue::Column(*inner_column)).collect(),
+    );
+    rows.iter()
+        .map(|row| {
+            let inner_patterns = match row.patterns[idx] {
+                Some(Pattern::Tuple(pattern_tuple)) => {
+                    pattern_tuple.field_patterns.iter().map(|pattern| Some(&**pattern)).collect()
+                }
+                Some(Pattern::Struct(pattern_struct)) => ctx
+                    .db
+                    .concrete_struct_members(pattern_struct.concrete_struct_id)?
+                    .values()
+                    .map(|member| {
+                        pattern_struct.field_patterns.iter().find_map(|(field, pattern)| {
+                            (field.id == member.id).then_some(&**pattern)
+                        })
+                    })
+                    .collect(),
+                _ => vec![None; inner_columns.len()],
+            };
+            let mut row = row.clone();
+            row.consume(idx, inner_patterns, column, &value);
+            Ok(row)
+        })
+        .collect()
+}
+
+/// Returns the rows that may match when the value in column `idx` is equal to `value` if
+/// `is_equal`, or not equal to it otherwise.
+fn specialize_by_literal<'a>(
+    rows: &[Row<'a>],
+    idx: usize,
+    value: &BigInt,
+    is_equal: bool,
+) -> Vec<Row<'a>> {
+    rows.iter()
+        .filter_map(|row| {
+            let mut row = row.clone();
+            if let Some(Pattern::Literal(pattern_literal)) = row.patterns[idx] {
+                if (pattern_literal.literal.value == *value) != is_equal {
+                    return None;
+                }
+                if is_equal {
+                    row.patterns[idx] = None;
+                }
+            }
+            Some(row)
+        })
+        .collect()
+}
+
+/// Returns the columns with the given inner columns in the middle.
+fn chain_columns(
+    before: &[VariableId],
+    inner_columns: Vec<VariableId>,
+    after: &[VariableId],
+) -> Vec<VariableId> {
+    before.iter().copied().chain(inner_columns).chain(after.iter().copied()).collect()
+}
+
+/// Uses the value of a column, moving it if it is not duplicatable.
+fn use_column(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    column: VariableId,
+) -> Result<LivingVar, LoweringFlowError> {
+    scope.use_anonymous_variable(ctx, column).to_maybe().map_err(LoweringFlowError::Failed)
+}
@@ -1,18 +1,18 @@
+use std::collections::HashSet;
+
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_defs::ids::{FreeFunctionId, LanguageElementId};
-use cairo_lang_diagnostics::{skip_diagnostic, DiagnosticAdded, Diagnostics, Maybe, ToMaybe};
-use cairo_lang_semantic::corelib::{
-    core_felt_ty, core_jump_nz_func, core_nonzero_ty, get_core_function_id,
-    get_enum_concrete_variant, get_panic_ty, jump_nz_nonzero_variant, jump_nz_zero_variant,
-};
-use cairo_lang_semantic::items::enm::SemanticEnumEx;
-use cairo_lang_semantic::{ConcreteTypeId, GenericArgumentId, TypeLongId};
+use cairo_lang_defs::ids::{GenericFunctionId, LanguageElementId, VarId};
+use cairo_lang_diagnostics::{DiagnosticAdded, Diagnostics, Maybe, ToMaybe};
+use cairo_lang_semantic::corelib::{get_core_function_id, get_enum_concrete_variant, get_panic_ty};
+use cairo_lang_semantic::items::function_with_body::concrete_function_specialization_diagnostic;
+use cairo_lang_semantic::items::strct::SemanticStructEx;
+use cairo_lang_semantic::{GenericArgumentId, TypeLongId};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::{extract_matches, try_extract_matches};
 use id_arena::Arena;
-use itertools::{chain, zip_eq, Itertools};
-use num_traits::Zero;
+use itertools::{chain, zip_eq};
 use scope::{BlockScope, BlockScopeEnd};
 
 use self::context::{
@@ -20,26 +20,35 @@ use self::context::{
     LoweringFlowError, StatementLoweringFlowError,
 };
 use self::external::{extern_facade_expr, extern_facade_return_tys};
+use self::lower_closure::{lower_closure_call, lower_expr_closure};
 use self::lower_if::lower_expr_if;
+use self::lower_loop::{lower_expr_loop, lower_loop_break, lower_loop_continue, lower_return};
+use self::lower_match::{
+    has_enum_variant_pattern, lower_expr_if_let, lower_expr_match, lower_let_with_match,
+};
 use self::scope::{generators, BlockFlowMerger, BlockMergerFinalized};
 use self::variables::LivingVar;
 use crate::blocks::FlatBlocks;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnostic;
 use crate::diagnostic::LoweringDiagnosticKind::*;
+use crate::ids::FunctionId;
 use crate::lower::context::LoweringContextBuilder;
 use crate::objects::{BlockId, Variable};
 
 pub mod context;
 mod external;
 pub mod implicits;
+mod lower_closure;
 mod lower_if;
+mod lower_loop;
+mod lower_match;
 mod scope;
 mod semantic_map;
 mod variables;
 
 /// A lowered function code.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Lowered {
     /// Diagnostics produced while lowering.
     pub diagnostics: Diagnostics<LoweringDiagnostic>,
@@ -51,15 +60,43 @@ pub struct Lowered {
     pub blocks: FlatBlocks,
 }
 
-/// Lowers a semantic free function.
-pub fn lower(db: &dyn LoweringGroup, free_function_id: FreeFunctionId) -> Maybe<Lowered> {
-    log::trace!("Lowering a free function.");
-    let is_empty_semantic_diagnostics =
-        db.free_function_declaration_diagnostics(free_function_id).is_empty()
-            && db.free_function_definition_diagnostics(free_function_id).is_empty();
+/// The lowered code of a function with a body, along with the lowered code of the functions
+/// generated from it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MultiLowering {
+    /// The lowered code of the function itself.
+    pub main_lowering: Lowered,
+    /// The lowered code of the functions generated from loops in the function, by the loop
+    /// expression they were generated from.
+    pub generated_lowerings: OrderedHashMap<cairo_lang_semantic::ExprId, GeneratedLowering>,
+}
+
+/// A lowered function generated from an element of a function with a body (e.g. a loop).
+#[derive(Debug, PartialEq, Eq)]
+pub struct GeneratedLowering {
+    /// The lowered code of the generated function.
+    pub lowered: Lowered,
+    /// The types of the parameters of the generated function (excluding implicits).
+    pub param_tys: Vec<cairo_lang_semantic::TypeId>,
+    /// The types of the parameters that are returned back to the caller (excluding implicits).
+    pub ref_tys: Vec<cairo_lang_semantic::TypeId>,
+    /// The type of the value returned by the generated function, before wrapping it with
+    /// PanicResult.
+    pub return_type: cairo_lang_semantic::TypeId,
+}
+
+/// Lowers a concrete semantic function with a body.
+pub fn lower(
+    db: &dyn LoweringGroup,
+    function_id: cairo_lang_semantic::FunctionId,
+) -> Maybe<MultiLowering> {
+    log::trace!("Lowering a function with a body.");
+    let function_with_body_id =
+        function_id.try_get_function_with_body_id(db.upcast()).to_maybe()?;
+    let no_semantic_errors = !db.function_with_body_diagnostics(function_with_body_id).has_errors();
     // Params.
 
-    let lowering_builder = LoweringContextBuilder::new(db, free_function_id)?;
+    let lowering_builder = LoweringContextBuilder::new(db, function_id)?;
     let mut ctx = lowering_builder.ctx()?;
 
     let input_semantic_vars: Vec<cairo_lang_semantic::Variable> =
@@ -75,7 +112,7 @@ pub fn lower(db: &dyn LoweringGroup, free_function_id: FreeFunctionId) -> Maybe<
     let input_var_tys = chain!(ctx.implicits.iter().copied(), input_var_tys).collect();
     let ref_params = ctx.ref_params;
 
-    let root = if is_empty_semantic_diagnostics {
+    let root = if no_semantic_errors {
         // Fetch body block expr.
         let semantic_block = extract_matches!(
             &ctx.function_def.exprs[ctx.function_def.body],
@@ -104,6 +141,16 @@ pub fn lower(db: &dyn LoweringGroup, free_function_id: FreeFunctionId) -> Maybe<
         Err(DiagnosticAdded)
     };
 
+    let (main_lowering, generated_lowerings) = finalize_lowered(ctx, root);
+    Ok(MultiLowering { main_lowering, generated_lowerings })
+}
+
+/// Finalizes the lowering of a function, given its root block. Returns the [Lowered] function and
+/// the functions generated while lowering it.
+fn finalize_lowered(
+    ctx: LoweringContext<'_>,
+    root: Maybe<BlockId>,
+) -> (Lowered, OrderedHashMap<cairo_lang_semantic::ExprId, GeneratedLowering>) {
     // Convert block to flat block.
     // TODO(spapini): Do this in another phase.
     let mut flat_blocks = FlatBlocks::new();
@@ -111,12 +158,13 @@ pub fn lower(db: &dyn LoweringGroup, free_function_id: FreeFunctionId) -> Maybe<
         flat_blocks.alloc(block.try_into().expect("Panic block ends are not supported yet."));
     }
 
-    Ok(Lowered {
+    let lowered = Lowered {
         diagnostics: ctx.diagnostics.build(),
         root,
         variables: ctx.variables,
         blocks: flat_blocks,
-    })
+    };
+    (lowered, ctx.generated_lowerings)
 }
 
 /// Lowers a semantic block.
@@ -222,11 +270,23 @@ pub fn lower_statement(
         cairo_lang_semantic::Statement::Let(cairo_lang_semantic::StatementLet {
             pattern,
             expr,
-            stable_ptr: _,
+            else_block,
+            stable_ptr,
         }) => {
             log::trace!("Lowering a let statement.");
             let lowered_expr = lower_expr(ctx, scope, *expr)?;
-            lower_single_pattern(ctx, scope, pattern, lowered_expr)?
+            if else_block.is_some() || has_enum_variant_pattern(pattern) {
+                lower_let_with_match(
+                    ctx,
+                    scope,
+                    pattern,
+                    lowered_expr,
+                    *else_block,
+                    stable_ptr.untyped(),
+                )?
+            } else {
+                lower_single_pattern(ctx, scope, pattern, lowered_expr)?
+            }
         }
         cairo_lang_semantic::Statement::Return(cairo_lang_semantic::StatementReturn {
             expr,
@@ -234,7 +294,26 @@ pub fn lower_statement(
         }) => {
             log::trace!("Lowering a return statement.");
             let lowered_expr = lower_expr(ctx, scope, *expr)?;
-            let (refs, returns) = get_full_return_vars(ctx, scope, lowered_expr)?;
+            let (refs, returns) = lower_return(ctx, scope, lowered_expr)?;
+            return Err(StatementLoweringFlowError::End(BlockScopeEnd::Return { refs, returns }));
+        }
+        cairo_lang_semantic::Statement::Break(cairo_lang_semantic::StatementBreak {
+            expr_option,
+            stable_ptr: _,
+        }) => {
+            log::trace!("Lowering a break statement.");
+            let lowered_expr = match expr_option {
+                Some(expr) => lower_expr(ctx, scope, *expr)?,
+                None => LoweredExpr::Tuple(vec![]),
+            };
+            let (refs, returns) = lower_loop_break(ctx, scope, lowered_expr)?;
+            return Err(StatementLoweringFlowError::End(BlockScopeEnd::Return { refs, returns }));
+        }
+        cairo_lang_semantic::Statement::Continue(cairo_lang_semantic::StatementContinue {
+            stable_ptr,
+        }) => {
+            log::trace!("Lowering a continue statement.");
+            let (refs, returns) = lower_loop_continue(ctx, scope, stable_ptr.untyped())?;
             return Err(StatementLoweringFlowError::End(BlockScopeEnd::Return { refs, returns }));
         }
     }
@@ -247,13 +326,13 @@ fn get_full_return_vars(
     ctx: &mut LoweringContext<'_>,
     scope: &mut BlockScope,
     value_expr: LoweredExpr,
-) -> Result<(Vec<LivingVar>, Vec<LivingVar>), StatementLoweringFlowError> {
+) -> Result<(Vec<LivingVar>, Vec<LivingVar>), LoweringFlowError> {
     let lowered_expr = maybe_wrap_with_panic(ctx, value_expr, scope)?;
     let value_vars = match lowered_expr {
         LoweredExpr::Tuple(tys) if tys.is_empty() => vec![],
         _ => vec![lowered_expr.var(ctx, scope)?],
     };
-    Ok(get_plain_full_return_vars(ctx, scope, value_vars)?)
+    get_plain_full_return_vars(ctx, scope, value_vars)
 }
 
 /// Wraps a LoweredExpr with PanicResult::Ok id the current function panics.
@@ -337,7 +416,10 @@ fn lower_single_pattern(
             ctx.semantic_defs.insert(sem_var.id(), sem_var);
         }
         cairo_lang_semantic::Pattern::Struct(strct) => {
-            let members = ctx.db.struct_members(strct.id).map_err(LoweringFlowError::Failed)?;
+            let members = ctx
+                .db
+                .concrete_struct_members(strct.concrete_struct_id)
+                .map_err(LoweringFlowError::Failed)?;
             let mut required_members = UnorderedHashMap::from_iter(
                 strct.field_patterns.iter().map(|(member, pattern)| (member.id, pattern)),
             );
@@ -390,6 +472,11 @@ fn lower_expr(
         cairo_lang_semantic::Expr::FunctionCall(expr) => lower_expr_function_call(ctx, expr, scope),
         cairo_lang_semantic::Expr::Match(expr) => lower_expr_match(ctx, expr, scope),
         cairo_lang_semantic::Expr::If(expr) => lower_expr_if(ctx, scope, expr),
+        cairo_lang_semantic::Expr::IfLet(expr) => lower_expr_if_let(ctx, scope, expr),
+        cairo_lang_semantic::Expr::Loop(_) | cairo_lang_semantic::Expr::While(_) => {
+            lower_expr_loop(ctx, scope, expr_id)
+        }
+        cairo_lang_semantic::Expr::Closure(expr) => lower_expr_closure(ctx, scope, expr, expr_id),
         cairo_lang_semantic::Expr::Var(expr) => {
             log::trace!("Lowering a variable: {:?}", expr.debug(&ctx.expr_formatter));
             Ok(LoweredExpr::AtVariable(use_semantic_var(
@@ -405,7 +492,12 @@ fn lower_expr(
                 generators::Literal { value: expr.value.clone(), ty: expr.ty }.add(ctx, scope),
             ))
         }
-        cairo_lang_semantic::Expr::MemberAccess(expr) => lower_expr_member_access(ctx, expr, scope),
+        cairo_lang_semantic::Expr::MemberAccess(expr) => {
+            lower_expr_member_access(ctx, expr, expr_id, scope)
+        }
+        cairo_lang_semantic::Expr::TupleItem(expr) => {
+            lower_expr_tuple_item(ctx, expr, expr_id, scope)
+        }
         cairo_lang_semantic::Expr::StructCtor(expr) => lower_expr_struct_ctor(ctx, expr, scope),
         cairo_lang_semantic::Expr::EnumVariantCtor(expr) => lower_expr_enum_ctor(ctx, expr, scope),
         cairo_lang_semantic::Expr::PropagateError(expr) => {
@@ -465,6 +557,34 @@ fn lower_expr_function_call(
 ) -> Result<LoweredExpr, LoweringFlowError> {
     log::trace!("Lowering a function call expression: {:?}", expr.debug(&ctx.expr_formatter));
 
+    // If the function is a call to a closure, call its generated function.
+    if let Some(callee_ty) = expr.function.try_get_fn_trait_callee_ty(ctx.db.upcast()) {
+        match ctx.db.lookup_intern_type(callee_ty) {
+            TypeLongId::Closure(closure) => {
+                return lower_closure_call(ctx, scope, expr, callee_ty, closure);
+            }
+            TypeLongId::Concrete(_) | TypeLongId::Tuple(_) => {
+                return Err(LoweringFlowError::Failed(
+                    ctx.diagnostics.report(expr.stable_ptr.untyped(), CallingNonClosure),
+                ));
+            }
+            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {}
+        }
+    }
+
+    // The missing impls of a specialized generic function are reported at its call.
+    if let Some(diagnostic) =
+        concrete_function_specialization_diagnostic(ctx.db.upcast(), expr.function)
+            .map_err(LoweringFlowError::Failed)?
+    {
+        return Err(LoweringFlowError::Failed(
+            ctx.diagnostics.report(expr.stable_ptr.untyped(), SpecializationFailed { diagnostic }),
+        ));
+    }
+
+    let function =
+        if expr.ref_args.is_empty() { forwarded_callee(ctx, expr.function) } else { expr.function };
+
     // TODO(spapini): Use the correct stable pointer.
     let arg_inputs = lower_exprs_as_vars(ctx, &expr.args, scope)?;
     let (ref_tys, ref_inputs): (_, Vec<LivingVar>) = expr
@@ -480,7 +600,7 @@ fn lower_expr_function_call(
         .into_iter()
         .unzip();
     let callee_implicit_types =
-        ctx.db.function_all_implicits(expr.function).map_err(LoweringFlowError::Failed)?;
+        ctx.db.function_all_implicits(function).map_err(LoweringFlowError::Failed)?;
     let implicits = callee_implicit_types
         .iter()
         .map(|ty| scope.take_implicit(*ty))
@@ -491,19 +611,19 @@ fn lower_expr_function_call(
     let inputs = chain!(implicits, ref_inputs, arg_inputs.into_iter()).collect();
 
     // If the function is panic(), do something special.
-    if expr.function == get_core_function_id(ctx.db.upcast(), "panic".into(), vec![]) {
+    if function == get_core_function_id(ctx.db.upcast(), "panic".into(), vec![]) {
         let [input] = <[_; 1]>::try_from(inputs).ok().unwrap();
         return lower_panic(ctx, scope, input);
     }
 
     // The following is relevant only to extern functions.
-    if let Some(extern_function_id) = expr.function.try_get_extern_function_id(ctx.db.upcast()) {
+    if let Some(extern_function_id) = function.try_get_extern_function_id(ctx.db.upcast()) {
         if let cairo_lang_semantic::TypeLongId::Concrete(
             cairo_lang_semantic::ConcreteTypeId::Enum(concrete_enum_id),
         ) = ctx.db.lookup_intern_type(expr.ty)
         {
             let lowered_expr = LoweredExprExternEnum {
-                function: expr.function,
+                function,
                 concrete_enum_id,
                 inputs,
                 ref_args: expr.ref_args.clone(),
@@ -529,17 +649,17 @@ fn lower_expr_function_call(
             // It is still unknown whether we directly match on this enum result, or store it to a
             // variable. Thus we can't perform the call. Performing it and pushing/bringing-back
             // variables are done on the 2 places where this result is used:
-            // 1. [lower_optimized_extern_match]
+            // 1. [lower_match::lower_expr_match]
             // 2. [context::LoweredExprExternEnum::var]
             return Ok(LoweredExpr::ExternEnum(lowered_expr));
         }
     }
 
-    let may_panic = ctx.db.function_may_panic(expr.function).map_err(LoweringFlowError::Failed)?;
+    let may_panic = ctx.db.function_may_panic(function).map_err(LoweringFlowError::Failed)?;
     let expr_ty = if may_panic { get_panic_ty(ctx.db.upcast(), expr.ty) } else { expr.ty };
 
     let (implicit_outputs, ref_outputs, res) =
-        perform_function_call(ctx, scope, expr.function, inputs, ref_tys, expr_ty)?;
+        perform_function_call(ctx, scope, function, inputs, ref_tys, expr_ty)?;
 
     // Rebind the implicits.
     for (implicit_type, implicit_output) in zip_eq(callee_implicit_types, implicit_outputs) {
@@ -556,6 +676,64 @@ fn lower_expr_function_call(
     Ok(res)
 }
 
+/// Returns the function to call for a call to the given function without ref arguments. A call to
+/// an impl function whose body only forwards its parameters to another function (e.g. the operator
+/// impls of the core library) is inlined as a call to that function, to avoid the overhead of
+/// calling a wrapper.
+fn forwarded_callee(
+    ctx: &LoweringContext<'_>,
+    function: cairo_lang_semantic::FunctionId,
+) -> cairo_lang_semantic::FunctionId {
+    let mut visited = HashSet::new();
+    let mut function = function;
+    while visited.insert(function) {
+        match forwarded_function(ctx, function) {
+            Some(forwarded) => function = forwarded,
+            None => break,
+        }
+    }
+    function
+}
+
+/// Returns the function called by the body of the given impl function, if the body is a single
+/// call of it with the parameters of the impl function, in order.
+fn forwarded_function(
+    ctx: &LoweringContext<'_>,
+    function: cairo_lang_semantic::FunctionId,
+) -> Option<cairo_lang_semantic::FunctionId> {
+    let generic_function = ctx.db.lookup_intern_function(function).function.generic_function;
+    if !matches!(generic_function, GenericFunctionId::ImplFunction(_)) {
+        return None;
+    }
+    let params = ctx.db.concrete_function_signature(function).ok()?.params;
+    let definition = ctx.db.concrete_function_with_body_definition(function).ok()?;
+    let block =
+        try_extract_matches!(&definition.exprs[definition.body], cairo_lang_semantic::Expr::Block)?;
+    if !block.statements.is_empty() {
+        return None;
+    }
+    let call = try_extract_matches!(
+        &definition.exprs[block.tail?],
+        cairo_lang_semantic::Expr::FunctionCall
+    )?;
+    // Calls to trait functions are only kept in functions with missing impls, and calls to
+    // closures are lowered separately.
+    let callee = ctx.db.lookup_intern_function(call.function).function.generic_function;
+    if matches!(callee, GenericFunctionId::TraitFunction(_))
+        || !call.ref_args.is_empty()
+        || call.args.len() != params.len()
+    {
+        return None;
+    }
+    let forwards_params = zip_eq(&call.args, params).all(|(arg, param)| {
+        matches!(
+            &definition.exprs[*arg],
+            cairo_lang_semantic::Expr::Var(expr) if expr.var == VarId::Param(param.id)
+        )
+    });
+    forwards_params.then_some(call.function)
+}
+
 /// Creates a LoweredExpr for a function call, taking into consideration external function facades:
 /// For external functions, sometimes the high level signature doesn't exactly correspond to the
 /// external function returned variables / branches.
@@ -567,8 +745,10 @@ fn perform_function_call(
     ref_tys: Vec<cairo_lang_semantic::TypeId>,
     ret_ty: cairo_lang_semantic::TypeId,
 ) -> Result<(Vec<LivingVar>, Vec<LivingVar>, LoweredExpr), LoweringFlowError> {
+    let is_extern = function.try_get_extern_function_id(ctx.db.upcast()).is_some();
+    let function = FunctionId::from_semantic(ctx.db, function);
     // If the function is not extern, simply call it.
-    if function.try_get_extern_function_id(ctx.db.upcast()).is_none() {
+    if !is_extern {
         let call_result =
             generators::Call { function, inputs, ref_tys, ret_tys: vec![ret_ty] }.add(ctx, scope);
         let res = LoweredExpr::AtVariable(call_result.returns.into_iter().next().unwrap());
@@ -603,296 +783,6 @@ fn lower_panic(
     Err(LoweringFlowError::Return { refs, returns })
 }
 
-/// Lowers an expression of type [cairo_lang_semantic::ExprMatch].
-fn lower_expr_match(
-    ctx: &mut LoweringContext<'_>,
-    expr: &cairo_lang_semantic::ExprMatch,
-    scope: &mut BlockScope,
-) -> Result<LoweredExpr, LoweringFlowError> {
-    log::trace!("Lowering a match expression: {:?}", expr.debug(&ctx.expr_formatter));
-    let lowered_expr = lower_expr(ctx, scope, expr.matched_expr)?;
-
-    if ctx.function_def.exprs[expr.matched_expr].ty() == ctx.db.core_felt_ty() {
-        let var = lowered_expr.var(ctx, scope)?;
-        return lower_expr_match_felt(ctx, expr, var, scope);
-    }
-
-    // TODO(spapini): Use diagnostics.
-    // TODO(spapini): Handle more than just enums.
-    if let LoweredExpr::ExternEnum(extern_enum) = lowered_expr {
-        return lower_optimized_extern_match(ctx, scope, extern_enum, &expr.arms);
-    }
-
-    let (concrete_enum_id, concrete_variants) = extract_concrete_enum(ctx, expr)?;
-    let expr_var = lowered_expr.var(ctx, scope)?;
-
-    // Merge arm blocks.
-    let (res, mut finalized_merger) =
-        BlockFlowMerger::with(ctx, scope, &[], |ctx, merger| -> Result<_, LoweringFlowError> {
-            // Create a sealed block for each arm.
-            let block_opts =
-                zip_eq(&concrete_variants, &expr.arms).map(|(concrete_variant, arm)| {
-                    let input_tys = vec![concrete_variant.ty];
-
-                    // Create a scope for the arm block.
-                    merger.run_in_subscope(ctx, input_tys, |ctx, subscope, arm_inputs| {
-                        // TODO(spapini): Make a better diagnostic.
-                        let enum_pattern = try_extract_matches!(
-                            &arm.pattern,
-                            cairo_lang_semantic::Pattern::EnumVariant
-                        )
-                        .ok_or_else(|| {
-                            ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedMatchArm)
-                        })?;
-                        // TODO(spapini): Make a better diagnostic.
-                        if &enum_pattern.variant != concrete_variant {
-                            return Err(ctx
-                                .diagnostics
-                                .report(expr.stable_ptr.untyped(), UnsupportedMatchArm));
-                        }
-                        // This assert is ok.
-                        assert_eq!(arm_inputs.len(), 1);
-
-                        let variant_expr =
-                            LoweredExpr::AtVariable(arm_inputs.into_iter().next().unwrap());
-                        match lower_single_pattern(
-                            ctx,
-                            subscope,
-                            &enum_pattern.inner_pattern,
-                            variant_expr,
-                        ) {
-                            Ok(_) => {
-                                // Lower the arm expression.
-                                lower_tail_expr(ctx, subscope, Some(arm.expression), false)
-                            }
-                            Err(err) => lowering_flow_error_to_block_scope_end(err),
-                        }
-                    })
-                });
-            block_opts.collect::<Maybe<Vec<_>>>().map_err(LoweringFlowError::Failed)
-        });
-    let finalized_blocks =
-        res?.into_iter().map(|sealed| finalized_merger.finalize_block(ctx, sealed).block);
-
-    let arms = zip_eq(concrete_variants, finalized_blocks).collect();
-
-    // Emit the statement.
-    let block_result = (generators::MatchEnum {
-        input: expr_var,
-        concrete_enum_id,
-        arms,
-        end_info: finalized_merger.end_info.clone(),
-    })
-    .add(ctx, scope);
-    lowered_expr_from_block_result(ctx, scope, block_result, finalized_merger)
-}
-
-/// Lowers a match expression on a LoweredExpr::ExternEnum lowered expression.
-fn lower_optimized_extern_match(
-    ctx: &mut LoweringContext<'_>,
-    scope: &mut BlockScope,
-    extern_enum: LoweredExprExternEnum,
-    match_arms: &[cairo_lang_semantic::MatchArm],
-) -> Result<LoweredExpr, LoweringFlowError> {
-    log::trace!("Started lowering of an optimized extern match.");
-    let concrete_variants = ctx
-        .db
-        .concrete_enum_variants(extern_enum.concrete_enum_id)
-        .map_err(LoweringFlowError::Failed)?;
-    if match_arms.len() != concrete_variants.len() {
-        return Err(LoweringFlowError::Failed(skip_diagnostic()));
-    }
-    // Merge arm blocks.
-    let (blocks, mut finalized_merger) = BlockFlowMerger::with(
-        ctx,
-        scope,
-        &extern_enum.ref_args,
-        |ctx, merger| -> Result<_, LoweringFlowError> {
-            // Create a sealed block for each arm.
-            let block_opts =
-                zip_eq(&concrete_variants, match_arms).map(|(concrete_variant, arm)| {
-                    let input_tys = match_extern_variant_arm_input_types(
-                        ctx,
-                        concrete_variant.ty,
-                        &extern_enum,
-                    );
-
-                    // Create a scope for the arm block.
-                    merger.run_in_subscope(ctx, input_tys, |ctx, subscope, mut arm_inputs| {
-                        // TODO(spapini): Make a better diagnostic.
-                        let enum_pattern = try_extract_matches!(
-                            &arm.pattern,
-                            cairo_lang_semantic::Pattern::EnumVariant
-                        )
-                        .ok_or_else(|| {
-                            ctx.diagnostics.report(extern_enum.stable_ptr, UnsupportedMatchArm)
-                        })?;
-                        // TODO(spapini): Make a better diagnostic.
-                        if &enum_pattern.variant != concrete_variant {
-                            return Err(ctx
-                                .diagnostics
-                                .report(extern_enum.stable_ptr, UnsupportedMatchArm));
-                        }
-
-                        // Bind the arm inputs to implicits and semantic variables.
-                        match_extern_arm_ref_args_bind(
-                            ctx,
-                            &mut arm_inputs,
-                            &extern_enum,
-                            subscope,
-                        );
-
-                        let variant_expr = extern_facade_expr(ctx, concrete_variant.ty, arm_inputs);
-                        match lower_single_pattern(
-                            ctx,
-                            subscope,
-                            &enum_pattern.inner_pattern,
-                            variant_expr,
-                        ) {
-                            Ok(_) => {
-                                // Lower the arm expression.
-                                lower_tail_expr(ctx, subscope, Some(arm.expression), false)
-                            }
-                            Err(err) => lowering_flow_error_to_block_scope_end(err),
-                        }
-                    })
-                });
-            block_opts.collect::<Maybe<Vec<_>>>().map_err(LoweringFlowError::Failed)
-        },
-    );
-
-    let finalized_blocks = blocks?
-        .into_iter()
-        .map(|sealed| finalized_merger.finalize_block(ctx, sealed).block)
-        .collect_vec();
-    let arms = zip_eq(concrete_variants, finalized_blocks).collect();
-
-    // Emit the statement.
-    let block_result = generators::MatchExtern {
-        function: extern_enum.function,
-        inputs: extern_enum.inputs,
-        arms,
-        end_info: finalized_merger.end_info.clone(),
-    }
-    .add(ctx, scope);
-    lowered_expr_from_block_result(ctx, scope, block_result, finalized_merger)
-}
-
-/// Lowers an expression of type [cairo_lang_semantic::ExprMatch] where the matched expression is a
-/// felt. Currently only a simple match-zero is supported.
-fn lower_expr_match_felt(
-    ctx: &mut LoweringContext<'_>,
-    expr: &cairo_lang_semantic::ExprMatch,
-    expr_var: LivingVar,
-    scope: &mut BlockScope,
-) -> Result<LoweredExpr, LoweringFlowError> {
-    log::trace!("Lowering a match-felt expression.");
-    // Check that the match has the expected form.
-    let (literal, block0, block_otherwise) = if let [
-        cairo_lang_semantic::MatchArm {
-            pattern:
-                cairo_lang_semantic::Pattern::Literal(cairo_lang_semantic::PatternLiteral {
-                    literal,
-                    ..
-                }),
-            expression: block0,
-        },
-        cairo_lang_semantic::MatchArm {
-            pattern: cairo_lang_semantic::Pattern::Otherwise(_),
-            expression: block_otherwise,
-        },
-    ] = &expr.arms[..]
-    {
-        (literal, block0, block_otherwise)
-    } else {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(expr.stable_ptr.untyped(), OnlyMatchZeroIsSupported),
-        ));
-    };
-
-    // Make sure literal is 0.
-    if !literal.value.is_zero() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(literal.stable_ptr.untyped(), NonZeroValueInMatch),
-        ));
-    }
-
-    let semantic_db = ctx.db.upcast();
-
-    // Lower both blocks.
-    let (res, mut finalized_merger) = BlockFlowMerger::with(ctx, scope, &[], |ctx, merger| {
-        let block0_end = merger.run_in_subscope(ctx, vec![], |ctx, subscope, _| {
-            lower_tail_expr(ctx, subscope, Some(*block0), false)
-        });
-        let non_zero_type = core_nonzero_ty(semantic_db, core_felt_ty(semantic_db));
-        let block_otherwise_end =
-            merger.run_in_subscope(ctx, vec![non_zero_type], |ctx, subscope, _| {
-                lower_tail_expr(ctx, subscope, Some(*block_otherwise), false)
-            });
-        Ok((block0_end, block_otherwise_end))
-    });
-    let (block0_sealed, block_otherwise_sealed) = res.map_err(LoweringFlowError::Failed)?;
-    let block0_finalized =
-        finalized_merger.finalize_block(ctx, block0_sealed.map_err(LoweringFlowError::Failed)?);
-    let block_otherwise_finalized = finalized_merger
-        .finalize_block(ctx, block_otherwise_sealed.map_err(LoweringFlowError::Failed)?);
-
-    let concrete_variants =
-        vec![jump_nz_zero_variant(ctx.db.upcast()), jump_nz_nonzero_variant(ctx.db.upcast())];
-    let arms = zip_eq(concrete_variants, [block0_finalized.block, block_otherwise_finalized.block])
-        .collect();
-
-    // Emit the statement.
-    let block_result = (generators::MatchExtern {
-        function: core_jump_nz_func(semantic_db),
-        inputs: vec![expr_var],
-        arms,
-        end_info: finalized_merger.end_info.clone(),
-    })
-    .add(ctx, scope);
-    lowered_expr_from_block_result(ctx, scope, block_result, finalized_merger)
-}
-
-/// Extracts concrete enum and variants from a match expression. Assumes it is indeed a concrete
-/// enum.
-fn extract_concrete_enum(
-    ctx: &mut LoweringContext<'_>,
-    expr: &cairo_lang_semantic::ExprMatch,
-) -> Result<
-    (cairo_lang_semantic::ConcreteEnumId, Vec<cairo_lang_semantic::ConcreteVariant>),
-    LoweringFlowError,
-> {
-    let concrete_ty = try_extract_matches!(
-        ctx.db.lookup_intern_type(ctx.function_def.exprs[expr.matched_expr].ty()),
-        TypeLongId::Concrete
-    )
-    .to_maybe()
-    .map_err(LoweringFlowError::Failed)?;
-    let concrete_enum_id = try_extract_matches!(concrete_ty, ConcreteTypeId::Enum)
-        .to_maybe()
-        .map_err(LoweringFlowError::Failed)?;
-    let enum_id = concrete_enum_id.enum_id(ctx.db.upcast());
-    let variants = ctx.db.enum_variants(enum_id).map_err(LoweringFlowError::Failed)?;
-    let concrete_variants = variants
-        .values()
-        .map(|variant_id| {
-            let variant =
-                ctx.db.variant_semantic(enum_id, *variant_id).map_err(LoweringFlowError::Failed)?;
-
-            ctx.db
-                .concrete_enum_variant(concrete_enum_id, &variant)
-                .map_err(LoweringFlowError::Failed)
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    if expr.arms.len() != concrete_variants.len() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedMatch),
-        ));
-    }
-    Ok((concrete_enum_id, concrete_variants))
-}
-
 /// Lowers a sequence of expressions and return them all. If the flow ended in the middle,
 /// propagates that flow error without returning any variable.
 fn lower_exprs_as_vars(
@@ -929,10 +819,17 @@ fn lower_expr_enum_ctor(
 fn lower_expr_member_access(
     ctx: &mut LoweringContext<'_>,
     expr: &cairo_lang_semantic::ExprMemberAccess,
+    expr_id: cairo_lang_semantic::ExprId,
     scope: &mut BlockScope,
 ) -> Result<LoweredExpr, LoweringFlowError> {
     log::trace!("Lowering a member-access expression: {:?}", expr.debug(&ctx.expr_formatter));
-    let members = ctx.db.struct_members(expr.struct_id).map_err(LoweringFlowError::Failed)?;
+    if let Some(var) = copy_assigned_member(ctx, scope, expr_id)? {
+        return Ok(LoweredExpr::AtVariable(var));
+    }
+    let members = ctx
+        .db
+        .concrete_struct_members(expr.concrete_struct_id)
+        .map_err(LoweringFlowError::Failed)?;
     let member_idx = members
         .iter()
         .position(|(_, member)| member.id == expr.member)
@@ -948,6 +845,28 @@ fn lower_expr_member_access(
     ))
 }
 
+/// Lowers an expression of type [cairo_lang_semantic::ExprTupleItem].
+fn lower_expr_tuple_item(
+    ctx: &mut LoweringContext<'_>,
+    expr: &cairo_lang_semantic::ExprTupleItem,
+    expr_id: cairo_lang_semantic::ExprId,
+    scope: &mut BlockScope,
+) -> Result<LoweredExpr, LoweringFlowError> {
+    log::trace!("Lowering a tuple item expression: {:?}", expr.debug(&ctx.expr_formatter));
+    if let Some(var) = copy_assigned_member(ctx, scope, expr_id)? {
+        return Ok(LoweredExpr::AtVariable(var));
+    }
+    let input = lower_expr(ctx, scope, expr.expr)?.var(ctx, scope)?;
+    let member_tys = extract_matches!(
+        ctx.db.lookup_intern_type(ctx.variables[input.var_id()].ty),
+        TypeLongId::Tuple
+    );
+    Ok(LoweredExpr::AtVariable(
+        generators::StructMemberAccess { input, member_tys, member_idx: expr.index }
+            .add(ctx, scope),
+    ))
+}
+
 /// Lowers an expression of type [cairo_lang_semantic::ExprStructCtor].
 fn lower_expr_struct_ctor(
     ctx: &mut LoweringContext<'_>,
@@ -955,7 +874,10 @@ fn lower_expr_struct_ctor(
     scope: &mut BlockScope,
 ) -> Result<LoweredExpr, LoweringFlowError> {
     log::trace!("Lowering a struct c'tor expression: {:?}", expr.debug(&ctx.expr_formatter));
-    let members = ctx.db.struct_members(expr.struct_id).map_err(LoweringFlowError::Failed)?;
+    let members = ctx
+        .db
+        .concrete_struct_members(expr.concrete_struct_id)
+        .map_err(LoweringFlowError::Failed)?;
     let member_expr = UnorderedHashMap::from_iter(expr.members.iter().cloned());
     Ok(LoweredExpr::AtVariable(
         generators::StructConstruct {
@@ -1073,7 +995,7 @@ fn lower_error_propagate(
                                 .ok()
                                 .to_maybe()?
                         } else {
-                            get_full_return_vars(ctx, subscope, LoweredExpr::AtVariable(value_var))
+                            lower_return(ctx, subscope, LoweredExpr::AtVariable(value_var))
                                 .ok()
                                 .to_maybe()?
                         };
@@ -1156,13 +1078,9 @@ fn lower_optimized_extern_error_propagate(
                                     .ok()
                                     .to_maybe()?
                             } else {
-                                get_full_return_vars(
-                                    ctx,
-                                    subscope,
-                                    LoweredExpr::AtVariable(value_var),
-                                )
-                                .ok()
-                                .to_maybe()?
+                                lower_return(ctx, subscope, LoweredExpr::AtVariable(value_var))
+                                    .ok()
+                                    .to_maybe()?
                             };
                             Ok(BlockScopeEnd::Return { refs, returns })
                         })
@@ -1227,11 +1145,110 @@ fn lower_expr_assignment(
         expr.debug(&ctx.expr_formatter)
     );
     scope.try_ensure_semantic_variable(ctx, expr.var);
-    let var = lower_expr(ctx, scope, expr.rhs)?.var(ctx, scope)?;
+    ctx.assigned_vars.push(expr.var);
+    let rhs = lower_expr(ctx, scope, expr.rhs);
+    ctx.assigned_vars.pop();
+    let mut var = rhs?.var(ctx, scope)?;
+    if !expr.members.is_empty() {
+        let semantic_var = take_semantic_var(ctx, scope, expr.var, expr.stable_ptr.untyped())?;
+        var = lower_member_update(ctx, scope, semantic_var, &expr.members, |_ctx, _old_value| {
+            (var, ())
+        })?
+        .0;
+    }
     scope.put_semantic_variable(ctx, expr.var, var);
     Ok(LoweredExpr::Tuple(vec![]))
 }
 
+/// Lowers a read of a (possibly nested) struct member or tuple item of a variable that is being
+/// assigned to, e.g. `s.a` in `s.a = s.a + 1`. If the member is duplicatable but the variable is
+/// not, reading the member as usual would move the variable, and the assignment would fail. In
+/// this case, the member is copied out of the variable, which is reconstructed in place. Returns
+/// None if the expression is not such a read.
+fn copy_assigned_member(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    expr_id: cairo_lang_semantic::ExprId,
+) -> Result<Option<LivingVar>, LoweringFlowError> {
+    let exprs = &ctx.function_def.exprs;
+    let mut members = vec![];
+    let mut current = &exprs[expr_id];
+    let var_expr = loop {
+        match current {
+            cairo_lang_semantic::Expr::Var(var_expr) => break var_expr,
+            cairo_lang_semantic::Expr::MemberAccess(expr) => {
+                members.push(cairo_lang_semantic::AssignedMember::Struct {
+                    concrete_struct_id: expr.concrete_struct_id,
+                    member: expr.member,
+                });
+                current = &exprs[expr.expr];
+            }
+            cairo_lang_semantic::Expr::TupleItem(expr) => {
+                members.push(cairo_lang_semantic::AssignedMember::Tuple { index: expr.index });
+                current = &exprs[expr.expr];
+            }
+            _ => return Ok(None),
+        }
+    };
+    if !ctx.assigned_vars.contains(&var_expr.var) {
+        return Ok(None);
+    }
+    let is_duplicatable =
+        |ty| ctx.db.type_info(ctx.lookup_context.clone(), ty).unwrap_or_default().duplicatable;
+    if is_duplicatable(var_expr.ty) || !is_duplicatable(exprs[expr_id].ty()) {
+        return Ok(None);
+    }
+    members.reverse();
+    let input = take_semantic_var(ctx, scope, var_expr.var, var_expr.stable_ptr.untyped())?;
+    let (input, value) = lower_member_update(ctx, scope, input, &members, |ctx, old_value| {
+        let value = old_value.try_duplicate(ctx).unwrap();
+        (old_value, value)
+    })?;
+    scope.put_semantic_variable(ctx, var_expr.var, input);
+    Ok(Some(value))
+}
+
+/// Replaces the (possibly nested) struct member or tuple item `members` of `input` with the value
+/// returned by `update` for its current value, by destructuring and reconstructing the structs and
+/// tuples along the way. Returns the new value of `input`, along with the result of `update`.
+fn lower_member_update<T>(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockScope,
+    input: LivingVar,
+    members: &[cairo_lang_semantic::AssignedMember],
+    update: impl FnOnce(&LoweringContext<'_>, LivingVar) -> (LivingVar, T),
+) -> Result<(LivingVar, T), LoweringFlowError> {
+    let Some((member, inner_members)) = members.split_first() else {
+        return Ok(update(ctx, input));
+    };
+    let ty = ctx.variables[input.var_id()].ty;
+    let (tys, member_idx) = match member {
+        cairo_lang_semantic::AssignedMember::Struct { concrete_struct_id, member } => {
+            let struct_members = ctx
+                .db
+                .concrete_struct_members(*concrete_struct_id)
+                .map_err(LoweringFlowError::Failed)?;
+            let member_idx = struct_members
+                .iter()
+                .position(|(_, struct_member)| struct_member.id == *member)
+                .to_maybe()
+                .map_err(LoweringFlowError::Failed)?;
+            (
+                struct_members.into_iter().map(|(_, struct_member)| struct_member.ty).collect(),
+                member_idx,
+            )
+        }
+        cairo_lang_semantic::AssignedMember::Tuple { index } => {
+            (extract_matches!(ctx.db.lookup_intern_type(ty), TypeLongId::Tuple), *index)
+        }
+    };
+    let mut member_vars = generators::StructDestructure { input, tys }.add(ctx, scope);
+    let member_var = member_vars.remove(member_idx);
+    let (member_var, result) = lower_member_update(ctx, scope, member_var, inner_members, update)?;
+    member_vars.insert(member_idx, member_var);
+    Ok((generators::StructConstruct { inputs: member_vars, ty }.add(ctx, scope), result))
+}
+
 /// Retrieves a LivingVar that corresponds to a semantic var in the current scope.
 /// Moves it if necessary. If it is already moved, fails and emits a diagnostic.
 fn use_semantic_var(
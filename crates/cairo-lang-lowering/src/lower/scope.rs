@@ -33,6 +33,10 @@ pub struct BlockScope {
     implicits: HashMap<cairo_lang_semantic::TypeId, LivingVar>,
     // The implicits that are used/changed in this block.
     changed_implicits: HashSet<cairo_lang_semantic::TypeId>,
+    /// A store for lowered variables that are not bound to a semantic variable (e.g. intermediate
+    /// values of a match), owning their OwnedVariable instances. A `None` value means the variable
+    /// was moved. See [`BlockScope::use_anonymous_variable()`].
+    anonymous_variables: OrderedHashMap<VariableId, Option<LivingVar>>,
     /// Current sequence of lowered statements emitted.
     statements: Vec<Statement>,
 }
@@ -107,11 +111,40 @@ impl BlockScope {
             return Some(());
         }
         let var = self.merger.take_from_higher_scope(ctx, semantic_var_id)?;
-        let var = self.living_variables.introduce_var(var);
+        let var = self.living_variables.introduce_var_allow_living(var);
         self.semantic_variables.put(semantic_var_id, var);
         Some(())
     }
 
+    /// Puts a lowered variable that is not bound to any semantic variable into the current scope.
+    /// Returns the key for using it later in this scope or in any of its subscopes. See
+    /// [`BlockScope::use_anonymous_variable()`].
+    pub fn put_anonymous_variable(&mut self, var: LivingVar) -> VariableId {
+        let var_id = var.var_id();
+        self.anonymous_variables.insert(var_id, Some(var));
+        var_id
+    }
+
+    /// Returns an anonymous variable that was put in this scope or in a higher scope, pulling it
+    /// from the higher scope if necessary. Moves the variable if it is not duplicatable.
+    /// Returns None if the variable was already moved.
+    pub fn use_anonymous_variable(
+        &mut self,
+        ctx: &mut LoweringContext<'_>,
+        var_id: VariableId,
+    ) -> Option<LivingVar> {
+        if !self.anonymous_variables.contains_key(&var_id) {
+            let var = self.merger.take_anonymous_from_higher_scope(ctx, var_id)?;
+            let var = self.living_variables.introduce_var_allow_living(var);
+            self.anonymous_variables.insert(var_id, Some(var));
+        }
+        let entry = self.anonymous_variables.get_mut(&var_id)?;
+        match entry.as_ref()?.try_duplicate(ctx) {
+            Some(var) => Some(var),
+            None => entry.take(),
+        }
+    }
+
     /// Puts an implicit variable and its owned lowered variable into the current scope.
     pub fn put_implicit(
         &mut self,
@@ -149,10 +182,15 @@ impl BlockScope {
             ),
             BlockScopeEnd::Return { refs, returns } => {
                 let mut drops = Vec::new();
-                let refs =
-                    refs.into_iter().map(|var| self.living_variables.take_var(var)).collect();
-                let returns =
-                    returns.into_iter().map(|var| self.living_variables.take_var(var)).collect();
+                // The same (duplicatable) variable may be returned more than once.
+                let refs = refs
+                    .into_iter()
+                    .map(|var| self.living_variables.take_var_allow_taken(var))
+                    .collect();
+                let returns = returns
+                    .into_iter()
+                    .map(|var| self.living_variables.take_var_allow_taken(var))
+                    .collect();
                 self.append_all_living_stack(&mut drops);
                 BlockSealedEnd::Return { refs, returns, drops }
             }
@@ -164,6 +202,7 @@ impl BlockScope {
             semantic_variables: self.semantic_variables,
             implicits: self.implicits,
             changed_implicits: self.changed_implicits,
+            anonymous_variables: self.anonymous_variables,
             statements: self.statements,
             end,
         };
@@ -199,6 +238,8 @@ pub struct BlockSealed {
     implicits: HashMap<cairo_lang_semantic::TypeId, LivingVar>,
     /// The implicits that were used/changed by this block.
     changed_implicits: HashSet<cairo_lang_semantic::TypeId>,
+    /// The anonymous variables of this block, including the ones pulled from outer scopes.
+    anonymous_variables: OrderedHashMap<VariableId, Option<LivingVar>>,
     /// The lowered statements of this block.
     statements: Vec<Statement>,
     /// The end type of this block.
@@ -226,6 +267,8 @@ pub struct BlockFinalizeParams<'a> {
     bring_back: &'a [cairo_lang_semantic::VarId],
     // Implicits that are returned from current scope to the calling scope via block outputs.
     implicit_pushes: &'a [cairo_lang_semantic::TypeId],
+    // Anonymous variables that are pulled from the calling scope to current scope.
+    anonymous_pulls: OrderedHashMap<VariableId, UsableVariable>,
 }
 
 impl BlockSealed {
@@ -247,6 +290,7 @@ impl BlockSealed {
             mut living_variables,
             mut implicits,
             mut semantic_variables,
+            anonymous_variables,
             statements,
             end,
             ..
@@ -254,7 +298,15 @@ impl BlockSealed {
         // Pull extra semantic variables if necessary.
         for (semantic_var_id, var) in params.pulls.into_iter() {
             if !semantic_variables.contains(semantic_var_id) {
-                semantic_variables.put(semantic_var_id, living_variables.introduce_var(var));
+                semantic_variables
+                    .put(semantic_var_id, living_variables.introduce_var_allow_living(var));
+            }
+        }
+        // Pulled anonymous variables are never given back to the calling scope, so they are
+        // dropped if they were not used by this block.
+        for (var_id, var) in params.anonymous_pulls.into_iter() {
+            if !anonymous_variables.contains_key(&var_id) {
+                living_variables.introduce_var_allow_living(var);
             }
         }
         // Compute drops.
@@ -266,7 +318,7 @@ impl BlockSealed {
                     semantic_variables
                         .take(*semantic_var_id)
                         .and_then(|entry| entry.take_var())
-                        .map(|var| living_variables.take_var(var));
+                        .map(|var| living_variables.take_var_allow_taken(var));
                 }
                 let implicit_pushes: Vec<VariableId> = params
                     .implicit_pushes
@@ -294,7 +346,8 @@ impl BlockSealed {
                             .expect("Pushed variable was never pulled.")
                             .take_var()
                             .expect("Pushed variable is dead.");
-                        living_variables.take_var(var).var_id()
+                        // The variable may also be the output of the block.
+                        living_variables.take_var_allow_taken(var).var_id()
                     })
                     .collect();
                 let maybe_output = maybe_output.as_ref().map(UsableVariable::var_id);
@@ -378,6 +431,9 @@ pub struct BlockFlowMerger {
     /// All variables that were pulled and consumed (moved) in at least one branch, and thus cannot
     /// be available in the parent scope anymore (i.e. cannot be pushed).
     moved_semantic_vars: HashSet<cairo_lang_semantic::VarId>,
+    /// Anonymous variables that were pulled from a higher scope. These are never given back to the
+    /// higher scope.
+    anonymous_pulls: OrderedHashMap<VariableId, LivingVar>,
     /// All implicits that were changed in the block. Note, in the case of an optimized match, this
     /// doesn't include the implicits that were consumed by the extern function.
     changed_implicits: HashSet<cairo_lang_semantic::TypeId>,
@@ -493,6 +549,24 @@ impl BlockFlowMerger {
         Some(self.splitter.split(self.pulls.get(&semantic_var_id)?))
     }
 
+    /// Pulls an anonymous variable from an outer scope.
+    fn take_anonymous_from_higher_scope(
+        &mut self,
+        ctx: &mut LoweringContext<'_>,
+        var_id: VariableId,
+    ) -> Option<UsableVariable> {
+        if !self.anonymous_pulls.contains_key(&var_id) {
+            self.parent_scope.as_mut().and_then(|scope| {
+                let var = scope.use_anonymous_variable(ctx, var_id)?;
+                let var = self.splitter.add(scope.living_variables.use_var(ctx, var));
+                self.anonymous_pulls.insert(var_id, var);
+                Some(())
+            });
+        }
+
+        Some(self.splitter.split(self.anonymous_pulls.get(&var_id)?))
+    }
+
     /// Appends all the living variable in the call stack, from this scope to the root.
     fn append_all_living_stack(&self, all_living: &mut Vec<VariableId>) {
         if let Some(parent_scope) = &self.parent_scope {
@@ -612,6 +686,7 @@ impl BlockFlowMerger {
             BlockMergerFinalized {
                 end_info,
                 pulls,
+                anonymous_pulls: self.anonymous_pulls,
                 splitter: self.splitter,
                 outer_var_info: OuterVarInfo { pushes, bring_back },
                 outer_implicit_info: OuterImplicitInfo {
@@ -649,6 +724,8 @@ pub struct BlockMergerFinalized {
     splitter: Splitter,
     /// Variables that are pulled from the calling scope to current scope.
     pulls: OrderedHashMap<cairo_lang_semantic::VarId, LivingVar>,
+    /// Anonymous variables that are pulled from the calling scope to current scope.
+    anonymous_pulls: OrderedHashMap<VariableId, LivingVar>,
     /// Information about the effect on the variables of the outer scope.
     pub outer_var_info: OuterVarInfo,
     /// Information about the effect on the implicits of the outer scope.
@@ -668,6 +745,11 @@ impl BlockMergerFinalized {
             pushes: &self.outer_var_info.pushes,
             bring_back: &self.outer_var_info.bring_back.keys().copied().collect::<Vec<_>>(),
             implicit_pushes: &self.outer_implicit_info.pushes,
+            anonymous_pulls: self
+                .anonymous_pulls
+                .iter()
+                .map(|(key, var)| (*key, self.splitter.split(var)))
+                .collect(),
         };
         block_sealed.finalize(ctx, params)
     }
@@ -37,7 +37,7 @@ impl Literal {
 /// Generator for [StatementCall].
 pub struct Call {
     /// Called function.
-    pub function: cairo_lang_semantic::FunctionId,
+    pub function: crate::ids::FunctionId,
     /// Inputs to function.
     pub inputs: Vec<LivingVar>,
     /// Types for `ref` parameters of the function. An output variable will be introduced for each.
@@ -58,9 +58,9 @@ impl Call {
             .into_iter()
             .map(|ty| scope.living_variables.introduce_new_var(ctx, ty))
             .collect();
-        let implicit_outputs = ctx
-            .db
-            .function_all_implicits(self.function)
+        let implicit_outputs = self
+            .function
+            .all_implicits(ctx.db)
             .unwrap_or_default()
             .into_iter()
             .map(|ty| scope.living_variables.introduce_new_var(ctx, ty))
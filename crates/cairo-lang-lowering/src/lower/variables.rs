@@ -51,6 +51,15 @@ impl LivingVariables {
         UsableVariable(var_id)
     }
 
+    /// Takes a variable, allowing it to have already been taken by an earlier use in the same block
+    /// end (e.g. a variable that is returned both as a reference and as a value). Only a
+    /// duplicatable variable may appear more than once, as [LivingVar] is otherwise unique.
+    pub fn take_var_allow_taken(&mut self, var: LivingVar) -> UsableVariable {
+        let var_id = var.0;
+        self.living_variables.swap_remove(&var_id);
+        UsableVariable(var_id)
+    }
+
     /// Introduces a new variable into `living_variables`.
     pub fn introduce_new_var(
         &mut self,
@@ -67,6 +76,14 @@ impl LivingVariables {
         LivingVar(var.0)
     }
 
+    /// Introduces a variable into `living_variables`, allowing it to already be alive (e.g. a
+    /// variable that is bound to more than one semantic variable, and is pulled once for each).
+    /// Only a duplicatable variable may already be alive, as [LivingVar] is otherwise unique.
+    pub fn introduce_var_allow_living(&mut self, var: UsableVariable) -> LivingVar {
+        self.living_variables.insert(var.0);
+        LivingVar(var.0)
+    }
+
     /// Retrieves the set of living variables as an ordered vector in order to drop / destruct.
     pub fn get_all(&self) -> Vec<VariableId> {
         self.living_variables.iter().copied().collect()
@@ -89,7 +106,7 @@ pub struct Splitter {
 impl Splitter {
     /// Adds a variable to the living variables.
     pub fn add(&mut self, var: UsableVariable) -> LivingVar {
-        self.living_variables.introduce_var(var)
+        self.living_variables.introduce_var_allow_living(var)
     }
     /// Splits the variable, and get a [UsableVariable] from it. This can be repeated indefinitely.
     /// It does not represent any ownership taking.
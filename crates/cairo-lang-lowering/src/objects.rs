@@ -185,7 +185,7 @@ pub struct StatementLiteral {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StatementCall {
     /// A function to "call".
-    pub function: cairo_lang_semantic::FunctionId,
+    pub function: crate::ids::FunctionId,
     /// Living variables in current scope to move to the function, as arguments.
     pub inputs: Vec<VariableId>,
     /// New variables to be introduced into the current scope from the function outputs.
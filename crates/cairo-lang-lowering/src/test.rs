@@ -1,10 +1,13 @@
 use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::ids::FunctionWithBodyId;
 use cairo_lang_plugins::get_default_plugins;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::function_with_body_function_id;
 use cairo_lang_semantic::test_utils::setup_test_function;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::fmt::LoweredFormatter;
+use crate::ids::GeneratedFunction;
 use crate::lower::lower;
 use crate::test_utils::LoweringDatabaseForTesting;
 
@@ -14,11 +17,14 @@ cairo_lang_test_utils::test_file_test!(
     {
         assignment :"assignment",
         call :"call",
+        closure :"closure",
         enums :"enums",
         error_propagate :"error_propagate",
         extern_ :"extern",
         arm_pattern_destructure :"arm_pattern_destructure",
         if_ :"if",
+        impl_ :"impl",
+        loop_ :"loop",
         match_ :"match",
         panic :"panic",
         struct_ :"struct",
@@ -40,12 +46,26 @@ fn test_function_lowering(
         inputs["module_code"].as_str(),
     )
     .split();
-    let lowered = lower(db, test_function.function_id).unwrap();
+    let function_id =
+        function_with_body_function_id(db, FunctionWithBodyId::Free(test_function.function_id))
+            .unwrap();
+    let multi_lowering = lower(db, function_id).unwrap();
+    let lowered = &multi_lowering.main_lowering;
 
-    let lowered_formatter = LoweredFormatter { db, lowered: &lowered };
+    let lowered_formatter = LoweredFormatter { db, lowered };
+    let mut lowering_format = format!("{:?}", lowered.debug(&lowered_formatter));
+    for (expr_id, generated) in multi_lowering.generated_lowerings.iter() {
+        let generated_function = GeneratedFunction { parent: function_id, element: *expr_id };
+        let lowered_formatter = LoweredFormatter { db, lowered: &generated.lowered };
+        lowering_format += &format!(
+            "\nGenerated {}:\n{:?}",
+            generated_function.full_path(db),
+            generated.lowered.debug(&lowered_formatter)
+        );
+    }
     OrderedHashMap::from([
         ("semantic_diagnostics".into(), semantic_diagnostics),
         ("lowering_diagnostics".into(), lowered.diagnostics.format(db)),
-        ("lowering_format".into(), format!("{:?}", lowered.debug(&lowered_formatter))),
+        ("lowering_format".into(), lowering_format),
     ])
 }
@@ -67,9 +67,11 @@ fn set_color(text: SmolStr, kind: SyntaxKind) -> ColoredString {
         SyntaxKind::TokenLiteralNumber
         | SyntaxKind::TokenFalse
         | SyntaxKind::TokenTrue
-        | SyntaxKind::TokenShortString => text.bright_cyan(),
+        | SyntaxKind::TokenShortString
+        | SyntaxKind::TokenString => text.bright_cyan(),
         SyntaxKind::TokenExtern
         | SyntaxKind::TokenType
+        | SyntaxKind::TokenConst
         | SyntaxKind::TokenFunction
         | SyntaxKind::TokenModule
         | SyntaxKind::TokenEnum
@@ -82,7 +84,12 @@ fn set_color(text: SmolStr, kind: SyntaxKind) -> ColoredString {
         | SyntaxKind::TokenMatch
         | SyntaxKind::TokenIf
         | SyntaxKind::TokenElse
+        | SyntaxKind::TokenLoop
+        | SyntaxKind::TokenWhile
+        | SyntaxKind::TokenBreak
+        | SyntaxKind::TokenContinue
         | SyntaxKind::TokenUse
+        | SyntaxKind::TokenPub
         | SyntaxKind::TokenImplicits
         | SyntaxKind::TokenRef
         | SyntaxKind::TokenMut
@@ -98,11 +105,21 @@ fn set_color(text: SmolStr, kind: SyntaxKind) -> ColoredString {
         | SyntaxKind::TokenOr
         | SyntaxKind::TokenOrOr
         | SyntaxKind::TokenXor
+        | SyntaxKind::TokenShl
+        | SyntaxKind::TokenShr
         | SyntaxKind::TokenNot
         | SyntaxKind::TokenQuestionMark
         | SyntaxKind::TokenUnderscore
         | SyntaxKind::TokenHash => text.truecolor(255, 180, 255), // Pink
         SyntaxKind::TokenEq
+        | SyntaxKind::TokenPlusEq
+        | SyntaxKind::TokenMinusEq
+        | SyntaxKind::TokenMulEq
+        | SyntaxKind::TokenDivEq
+        | SyntaxKind::TokenModEq
+        | SyntaxKind::TokenAndEq
+        | SyntaxKind::TokenOrEq
+        | SyntaxKind::TokenXorEq
         | SyntaxKind::TokenEqEq
         | SyntaxKind::TokenGE
         | SyntaxKind::TokenGT
@@ -157,6 +157,22 @@ impl<'a> Lexer<'a> {
         TokenKind::ShortString
     }
 
+    /// Takes a string.
+    fn take_token_string(&mut self) -> TokenKind {
+        self.take();
+        let mut escaped = false;
+        while let Some(c) = self.take() {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                break;
+            }
+        }
+        TokenKind::String
+    }
+
     /// Assumes the next character is [a-zA-Z_].
     fn take_token_identifier(&mut self) -> TokenKind {
         // TODO(spapini): Support or explicitly report general unicode characters.
@@ -167,6 +183,7 @@ impl<'a> Lexer<'a> {
             "true" => TokenKind::True,
             "extern" => TokenKind::Extern,
             "type" => TokenKind::Type,
+            "const" => TokenKind::Const,
             "fn" => TokenKind::Function,
             "trait" => TokenKind::Trait,
             "impl" => TokenKind::Impl,
@@ -179,7 +196,12 @@ impl<'a> Lexer<'a> {
             "match" => TokenKind::Match,
             "if" => TokenKind::If,
             "else" => TokenKind::Else,
+            "loop" => TokenKind::Loop,
+            "while" => TokenKind::While,
+            "break" => TokenKind::Break,
+            "continue" => TokenKind::Continue,
             "use" => TokenKind::Use,
+            "pub" => TokenKind::Pub,
             "implicits" => TokenKind::Implicits,
             "ref" => TokenKind::Ref,
             "mut" => TokenKind::Mut,
@@ -218,6 +240,7 @@ impl<'a> Lexer<'a> {
             match current {
                 '0'..='9' => self.take_token_literal_number(),
                 '\'' => self.take_token_short_string(),
+                '"' => self.take_token_string(),
                 ',' => self.take_token_of_kind(TokenKind::Comma),
                 ';' => self.take_token_of_kind(TokenKind::Semicolon),
                 '?' => self.take_token_of_kind(TokenKind::QuestionMark),
@@ -228,14 +251,35 @@ impl<'a> Lexer<'a> {
                 '(' => self.take_token_of_kind(TokenKind::LParen),
                 ')' => self.take_token_of_kind(TokenKind::RParen),
                 '.' => self.pick_kind('.', TokenKind::DotDot, TokenKind::Dot),
-                '*' => self.take_token_of_kind(TokenKind::Mul),
-                '/' => self.take_token_of_kind(TokenKind::Div),
-                '%' => self.take_token_of_kind(TokenKind::Mod),
-                '+' => self.take_token_of_kind(TokenKind::Plus),
+                '*' => self.pick_kind('=', TokenKind::MulEq, TokenKind::Mul),
+                '/' => self.pick_kind('=', TokenKind::DivEq, TokenKind::Div),
+                '%' => self.pick_kind('=', TokenKind::ModEq, TokenKind::Mod),
+                '+' => self.pick_kind('=', TokenKind::PlusEq, TokenKind::Plus),
                 '#' => self.take_token_of_kind(TokenKind::Hash),
-                '-' => self.pick_kind('>', TokenKind::Arrow, TokenKind::Minus),
-                '<' => self.pick_kind('=', TokenKind::LE, TokenKind::LT),
-                '>' => self.pick_kind('=', TokenKind::GE, TokenKind::GT),
+                '-' => {
+                    self.take();
+                    match self.peek() {
+                        Some('>') => self.take_token_of_kind(TokenKind::Arrow),
+                        Some('=') => self.take_token_of_kind(TokenKind::MinusEq),
+                        _ => TokenKind::Minus,
+                    }
+                }
+                '<' => {
+                    self.take();
+                    match self.peek() {
+                        Some('=') => self.take_token_of_kind(TokenKind::LE),
+                        Some('<') => self.take_token_of_kind(TokenKind::Shl),
+                        _ => TokenKind::LT,
+                    }
+                }
+                '>' => {
+                    self.take();
+                    match self.peek() {
+                        Some('=') => self.take_token_of_kind(TokenKind::GE),
+                        Some('>') => self.take_token_of_kind(TokenKind::Shr),
+                        _ => TokenKind::GT,
+                    }
+                }
                 'a'..='z' | 'A'..='Z' | '_' => self.take_token_identifier(),
                 ':' => self.pick_kind(':', TokenKind::ColonColon, TokenKind::Colon),
                 '!' => self.pick_kind('=', TokenKind::Neq, TokenKind::Not),
@@ -247,9 +291,23 @@ impl<'a> Lexer<'a> {
                         _ => TokenKind::Eq,
                     }
                 }
-                '&' => self.pick_kind('&', TokenKind::AndAnd, TokenKind::And),
-                '|' => self.pick_kind('|', TokenKind::OrOr, TokenKind::Or),
-                '^' => self.take_token_of_kind(TokenKind::Xor),
+                '&' => {
+                    self.take();
+                    match self.peek() {
+                        Some('&') => self.take_token_of_kind(TokenKind::AndAnd),
+                        Some('=') => self.take_token_of_kind(TokenKind::AndEq),
+                        _ => TokenKind::And,
+                    }
+                }
+                '|' => {
+                    self.take();
+                    match self.peek() {
+                        Some('|') => self.take_token_of_kind(TokenKind::OrOr),
+                        Some('=') => self.take_token_of_kind(TokenKind::OrEq),
+                        _ => TokenKind::Or,
+                    }
+                }
+                '^' => self.pick_kind('=', TokenKind::XorEq, TokenKind::Xor),
                 _ => self.take_token_of_kind(TokenKind::BadCharacters),
             }
         } else {
@@ -306,12 +364,14 @@ enum TokenKind {
     // Literals.
     LiteralNumber,
     ShortString,
+    String,
 
     // Keywords.
     False,
     True,
     Extern,
     Type,
+    Const,
     Function,
     Trait,
     Impl,
@@ -324,7 +384,12 @@ enum TokenKind {
     Match,
     If,
     Else,
+    Loop,
+    While,
+    Break,
+    Continue,
     Use,
+    Pub,
     Implicits,
     NoPanic,
 
@@ -338,6 +403,8 @@ enum TokenKind {
     Or,
     OrOr,
     Xor,
+    Shl,
+    Shr,
     EqEq,
     Neq,
     GE,
@@ -357,6 +424,14 @@ enum TokenKind {
     Dot,
     DotDot,
     Eq,
+    PlusEq,
+    MinusEq,
+    MulEq,
+    DivEq,
+    ModEq,
+    AndEq,
+    OrEq,
+    XorEq,
     Hash,
     Semicolon,
     QuestionMark,
@@ -380,10 +455,12 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Identifier => SyntaxKind::TerminalIdentifier,
         TokenKind::LiteralNumber => SyntaxKind::TerminalLiteralNumber,
         TokenKind::ShortString => SyntaxKind::TerminalShortString,
+        TokenKind::String => SyntaxKind::TerminalString,
         TokenKind::False => SyntaxKind::TerminalFalse,
         TokenKind::True => SyntaxKind::TerminalTrue,
         TokenKind::Extern => SyntaxKind::TerminalExtern,
         TokenKind::Type => SyntaxKind::TerminalType,
+        TokenKind::Const => SyntaxKind::TerminalConst,
         TokenKind::Function => SyntaxKind::TerminalFunction,
         TokenKind::Trait => SyntaxKind::TerminalTrait,
         TokenKind::Impl => SyntaxKind::TerminalImpl,
@@ -396,7 +473,12 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Match => SyntaxKind::TerminalMatch,
         TokenKind::If => SyntaxKind::TerminalIf,
         TokenKind::Else => SyntaxKind::TerminalElse,
+        TokenKind::Loop => SyntaxKind::TerminalLoop,
+        TokenKind::While => SyntaxKind::TerminalWhile,
+        TokenKind::Break => SyntaxKind::TerminalBreak,
+        TokenKind::Continue => SyntaxKind::TerminalContinue,
         TokenKind::Use => SyntaxKind::TerminalUse,
+        TokenKind::Pub => SyntaxKind::TerminalPub,
         TokenKind::Implicits => SyntaxKind::TerminalImplicits,
         TokenKind::NoPanic => SyntaxKind::TerminalNoPanic,
         TokenKind::And => SyntaxKind::TerminalAnd,
@@ -404,6 +486,8 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Or => SyntaxKind::TerminalOr,
         TokenKind::OrOr => SyntaxKind::TerminalOrOr,
         TokenKind::Xor => SyntaxKind::TerminalXor,
+        TokenKind::Shl => SyntaxKind::TerminalShl,
+        TokenKind::Shr => SyntaxKind::TerminalShr,
         TokenKind::EqEq => SyntaxKind::TerminalEqEq,
         TokenKind::Neq => SyntaxKind::TerminalNeq,
         TokenKind::GE => SyntaxKind::TerminalGE,
@@ -422,6 +506,14 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Dot => SyntaxKind::TerminalDot,
         TokenKind::DotDot => SyntaxKind::TerminalDotDot,
         TokenKind::Eq => SyntaxKind::TerminalEq,
+        TokenKind::PlusEq => SyntaxKind::TerminalPlusEq,
+        TokenKind::MinusEq => SyntaxKind::TerminalMinusEq,
+        TokenKind::MulEq => SyntaxKind::TerminalMulEq,
+        TokenKind::DivEq => SyntaxKind::TerminalDivEq,
+        TokenKind::ModEq => SyntaxKind::TerminalModEq,
+        TokenKind::AndEq => SyntaxKind::TerminalAndEq,
+        TokenKind::OrEq => SyntaxKind::TerminalOrEq,
+        TokenKind::XorEq => SyntaxKind::TerminalXorEq,
         TokenKind::Hash => SyntaxKind::TerminalHash,
         TokenKind::Semicolon => SyntaxKind::TerminalSemicolon,
         TokenKind::QuestionMark => SyntaxKind::TerminalQuestionMark,
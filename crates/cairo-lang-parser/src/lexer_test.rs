@@ -25,9 +25,11 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
                 "0xA2_u128",
             ]
         }
+        SyntaxKind::TerminalString => vec![r#""""#, r#""abc""#, r#""a'b\"c""#],
         SyntaxKind::TerminalFalse => vec!["false"],
         SyntaxKind::TerminalExtern => vec!["extern"],
         SyntaxKind::TerminalType => vec!["type"],
+        SyntaxKind::TerminalConst => vec!["const"],
         SyntaxKind::TerminalFunction => vec!["fn"],
         SyntaxKind::TerminalTrait => vec!["trait"],
         SyntaxKind::TerminalImpl => vec!["impl"],
@@ -44,7 +46,12 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalMatch => vec!["match"],
         SyntaxKind::TerminalIf => vec!["if"],
         SyntaxKind::TerminalElse => vec!["else"],
+        SyntaxKind::TerminalLoop => vec!["loop"],
+        SyntaxKind::TerminalWhile => vec!["while"],
+        SyntaxKind::TerminalBreak => vec!["break"],
+        SyntaxKind::TerminalContinue => vec!["continue"],
         SyntaxKind::TerminalUse => vec!["use"],
+        SyntaxKind::TerminalPub => vec!["pub"],
         SyntaxKind::TerminalAnd => vec!["&"],
         SyntaxKind::TerminalAndAnd => vec!["&&"],
         SyntaxKind::TerminalColon => vec![":"],
@@ -56,6 +63,14 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalDotDot => vec![".."],
         SyntaxKind::TerminalEq => vec!["="],
         SyntaxKind::TerminalEqEq => vec!["=="],
+        SyntaxKind::TerminalPlusEq => vec!["+="],
+        SyntaxKind::TerminalMinusEq => vec!["-="],
+        SyntaxKind::TerminalMulEq => vec!["*="],
+        SyntaxKind::TerminalDivEq => vec!["/="],
+        SyntaxKind::TerminalModEq => vec!["%="],
+        SyntaxKind::TerminalAndEq => vec!["&="],
+        SyntaxKind::TerminalOrEq => vec!["|="],
+        SyntaxKind::TerminalXorEq => vec!["^="],
         SyntaxKind::TerminalGE => vec![">="],
         SyntaxKind::TerminalGT => vec![">"],
         SyntaxKind::TerminalLE => vec!["<="],
@@ -67,6 +82,8 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalOr => vec!["|"],
         SyntaxKind::TerminalOrOr => vec!["||"],
         SyntaxKind::TerminalXor => vec!["^"],
+        SyntaxKind::TerminalShl => vec!["<<"],
+        SyntaxKind::TerminalShr => vec![">>"],
         SyntaxKind::TerminalPlus => vec!["+"],
         SyntaxKind::TerminalSemicolon => vec![";"],
         SyntaxKind::TerminalQuestionMark => vec!["?"],
@@ -91,10 +108,12 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
     vec![
         SyntaxKind::TerminalIdentifier,
         SyntaxKind::TerminalLiteralNumber,
+        SyntaxKind::TerminalString,
         SyntaxKind::TerminalFalse,
         SyntaxKind::TerminalTrue,
         SyntaxKind::TerminalExtern,
         SyntaxKind::TerminalType,
+        SyntaxKind::TerminalConst,
         SyntaxKind::TerminalFunction,
         SyntaxKind::TerminalTrait,
         SyntaxKind::TerminalImpl,
@@ -110,12 +129,19 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalMatch,
         SyntaxKind::TerminalIf,
         SyntaxKind::TerminalElse,
+        SyntaxKind::TerminalLoop,
+        SyntaxKind::TerminalWhile,
+        SyntaxKind::TerminalBreak,
+        SyntaxKind::TerminalContinue,
         SyntaxKind::TerminalUse,
+        SyntaxKind::TerminalPub,
         SyntaxKind::TerminalAnd,
         SyntaxKind::TerminalAndAnd,
         SyntaxKind::TerminalOr,
         SyntaxKind::TerminalOrOr,
         SyntaxKind::TerminalXor,
+        SyntaxKind::TerminalShl,
+        SyntaxKind::TerminalShr,
         SyntaxKind::TerminalEqEq,
         SyntaxKind::TerminalNeq,
         SyntaxKind::TerminalGE,
@@ -134,6 +160,14 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalDot,
         SyntaxKind::TerminalDotDot,
         SyntaxKind::TerminalEq,
+        SyntaxKind::TerminalPlusEq,
+        SyntaxKind::TerminalMinusEq,
+        SyntaxKind::TerminalMulEq,
+        SyntaxKind::TerminalDivEq,
+        SyntaxKind::TerminalModEq,
+        SyntaxKind::TerminalAndEq,
+        SyntaxKind::TerminalOrEq,
+        SyntaxKind::TerminalXorEq,
         SyntaxKind::TerminalSemicolon,
         SyntaxKind::TerminalQuestionMark,
         SyntaxKind::TerminalUnderscore,
@@ -169,10 +203,13 @@ fn need_separator(
     }
     if (text0 == "&" && text1.starts_with('&'))
         || (text0 == "|" && text1.starts_with('|'))
-        || (text0 == "/" && text1 == "/")
+        || (text0 == "/" && text1.starts_with('/'))
+        || (["+", "-", "*", "/", "%", "&", "|", "^"].contains(&text0) && text1.starts_with('='))
         || ((text0 == "=" || text0 == "!") && text1.starts_with('='))
         || ((text0 == "=") && text1.starts_with('>'))
         || ((text0 == "<" || text0 == ">") && text1.starts_with('='))
+        || (text0 == "<" && text1.starts_with('<'))
+        || (text0 == ">" && text1.starts_with('>'))
         || (text0 == ":" && text1.starts_with(':'))
         || (text0 == "." && text1.starts_with('.'))
         || (text0 == "-" && text1.starts_with('>'))
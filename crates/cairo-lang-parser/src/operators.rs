@@ -16,18 +16,37 @@ pub fn get_binary_operator_precedence(kind: SyntaxKind) -> Option<usize> {
         SyntaxKind::TerminalNot => Some(1),
         SyntaxKind::TerminalMul | SyntaxKind::TerminalDiv | SyntaxKind::TerminalMod => Some(2),
         SyntaxKind::TerminalPlus | SyntaxKind::TerminalMinus => Some(3),
+        SyntaxKind::TerminalShl | SyntaxKind::TerminalShr => Some(4),
         SyntaxKind::TerminalEqEq
         | SyntaxKind::TerminalNeq
         | SyntaxKind::TerminalLT
         | SyntaxKind::TerminalGT
         | SyntaxKind::TerminalLE
-        | SyntaxKind::TerminalGE => Some(4),
-        SyntaxKind::TerminalAnd => Some(5),
-        SyntaxKind::TerminalOr => Some(6),
-        SyntaxKind::TerminalXor => Some(7),
-        SyntaxKind::TerminalEq => Some(8),
+        | SyntaxKind::TerminalGE => Some(5),
+        SyntaxKind::TerminalAnd => Some(6),
+        SyntaxKind::TerminalOr => Some(7),
+        SyntaxKind::TerminalXor => Some(8),
+        SyntaxKind::TerminalEq
+        | SyntaxKind::TerminalPlusEq
+        | SyntaxKind::TerminalMinusEq
+        | SyntaxKind::TerminalMulEq
+        | SyntaxKind::TerminalDivEq
+        | SyntaxKind::TerminalModEq
+        | SyntaxKind::TerminalAndEq
+        | SyntaxKind::TerminalOrEq
+        | SyntaxKind::TerminalXorEq => Some(9),
 
         // TODO(yuval): add more operators.
         _ => None,
     }
 }
+
+/// Returns the precedence of an operator that follows an expression - either a binary operator or
+/// the indexing operator.
+pub fn get_post_operator_precedence(kind: SyntaxKind) -> Option<usize> {
+    match kind {
+        // Indexing binds as tightly as member access, e.g. `a.b[i]` is parsed as `(a.b)[i]`.
+        SyntaxKind::TerminalLBrack => Some(0),
+        _ => get_binary_operator_precedence(kind),
+    }
+}
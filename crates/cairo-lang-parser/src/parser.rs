@@ -14,7 +14,7 @@ use cairo_lang_syntax::node::{SyntaxNode, Token, TypedSyntaxNode};
 
 use crate::diagnostic::ParserDiagnosticKind;
 use crate::lexer::{Lexer, LexerTerminal};
-use crate::operators::{get_binary_operator_precedence, get_unary_operator_precedence};
+use crate::operators::{get_post_operator_precedence, get_unary_operator_precedence};
 use crate::recovery::is_of_kind;
 use crate::ParserDiagnostic;
 
@@ -129,24 +129,45 @@ impl<'a> Parser<'a> {
         let attributes = self.parse_attribute_list(
             "Module/Use/FreeFunction/ExternFunction/ExternType/Trait/Impl/Struct/Enum",
         );
+        let visibility = self.parse_visibility();
 
         match self.peek().kind {
-            SyntaxKind::TerminalModule => Some(self.expect_module(attributes).into()),
-            SyntaxKind::TerminalStruct => Some(self.expect_struct(attributes).into()),
-            SyntaxKind::TerminalEnum => Some(self.expect_enum(attributes).into()),
-            SyntaxKind::TerminalType => Some(self.expect_type_alias(attributes).into()),
-            SyntaxKind::TerminalExtern => Some(self.expect_extern_item(attributes)),
-            SyntaxKind::TerminalFunction => Some(self.expect_free_function(attributes).into()),
-            SyntaxKind::TerminalUse => Some(self.expect_use(attributes).into()),
-            SyntaxKind::TerminalTrait => Some(self.expect_trait(attributes).into()),
-            SyntaxKind::TerminalImpl => Some(self.expect_impl(attributes).into()),
+            SyntaxKind::TerminalModule => Some(self.expect_module(attributes, visibility).into()),
+            SyntaxKind::TerminalStruct => Some(self.expect_struct(attributes, visibility).into()),
+            SyntaxKind::TerminalEnum => Some(self.expect_enum(attributes, visibility).into()),
+            SyntaxKind::TerminalType => Some(self.expect_type_alias(attributes, visibility).into()),
+            SyntaxKind::TerminalConst => Some(self.expect_const(attributes, visibility).into()),
+            SyntaxKind::TerminalExtern => Some(self.expect_extern_item(attributes, visibility)),
+            SyntaxKind::TerminalFunction => {
+                Some(self.expect_free_function(attributes, visibility).into())
+            }
+            SyntaxKind::TerminalUse => Some(self.expect_use(attributes, visibility).into()),
+            SyntaxKind::TerminalTrait => Some(self.expect_trait(attributes, visibility).into()),
+            SyntaxKind::TerminalImpl => Some(self.expect_impl(attributes, visibility).into()),
             _ => None,
         }
     }
 
+    /// Returns a GreenId of a node with a Visibility.* kind (see
+    /// [cairo_lang_syntax::node::ast::Visibility]).
+    /// Expected pattern: `pub` or nothing.
+    fn parse_visibility(&mut self) -> VisibilityGreen {
+        match self.peek().kind {
+            SyntaxKind::TerminalPub => {
+                let pub_kw = self.take::<TerminalPub>();
+                VisibilityPub::new_green(self.db, pub_kw).into()
+            }
+            _ => VisibilityDefault::new_green(self.db).into(),
+        }
+    }
+
     /// Assumes the current token is Module.
     /// Expected pattern: `mod <Identifier> \{<ItemList>\}` or `mod <Identifier>;`.
-    fn expect_module(&mut self, attributes: AttributeListGreen) -> ItemModuleGreen {
+    fn expect_module(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemModuleGreen {
         let module_kw = self.take::<TerminalModule>();
         let name = self.parse_identifier();
 
@@ -168,12 +189,16 @@ impl<'a> Parser<'a> {
             _ => self.parse_token::<TerminalSemicolon>().into(),
         };
 
-        ItemModule::new_green(self.db, attributes, module_kw, name, body)
+        ItemModule::new_green(self.db, attributes, visibility, module_kw, name, body)
     }
 
     /// Assumes the current token is Struct.
     /// Expected pattern: `struct<Identifier>{<ParamList>}`
-    fn expect_struct(&mut self, attributes: AttributeListGreen) -> ItemStructGreen {
+    fn expect_struct(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemStructGreen {
         let struct_kw = self.take::<TerminalStruct>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -183,6 +208,7 @@ impl<'a> Parser<'a> {
         ItemStruct::new_green(
             self.db,
             attributes,
+            visibility,
             struct_kw,
             name,
             generic_params,
@@ -194,7 +220,11 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Enum.
     /// Expected pattern: `enum<Identifier>{<ParamList>}`
-    fn expect_enum(&mut self, attributes: AttributeListGreen) -> ItemEnumGreen {
+    fn expect_enum(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemEnumGreen {
         let enum_kw = self.take::<TerminalEnum>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -204,6 +234,7 @@ impl<'a> Parser<'a> {
         ItemEnum::new_green(
             self.db,
             attributes,
+            visibility,
             enum_kw,
             name,
             generic_params,
@@ -215,7 +246,11 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is type.
     /// Expected pattern: `type <Identifier>{<ParamList>} = <TypeExpression>`
-    fn expect_type_alias(&mut self, attributes: AttributeListGreen) -> ItemTypeAliasGreen {
+    fn expect_type_alias(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemTypeAliasGreen {
         let type_kw = self.take::<TerminalType>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -227,6 +262,7 @@ impl<'a> Parser<'a> {
         ItemTypeAlias::new_green(
             self.db,
             attributes,
+            visibility,
             type_kw,
             name,
             generic_params,
@@ -236,6 +272,32 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Assumes the current token is Const.
+    /// Expected pattern: `const <Identifier><TypeClause> = <Expression>;`
+    fn expect_const(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemConstantGreen {
+        let const_kw = self.take::<TerminalConst>();
+        let name = self.parse_identifier();
+        let type_clause = self.parse_type_clause();
+        let eq = self.parse_token::<TerminalEq>();
+        let value = self.parse_expr();
+        let semicolon = self.parse_token::<TerminalSemicolon>();
+        ItemConstant::new_green(
+            self.db,
+            attributes,
+            visibility,
+            const_kw,
+            name,
+            type_clause,
+            eq,
+            value,
+            semicolon,
+        )
+    }
+
     /// Expected pattern: `<ParenthesizedParamList><ReturnTypeClause>`
     fn expect_function_signature(&mut self) -> FunctionSignatureGreen {
         let lparen = self.parse_token::<TerminalLParen>();
@@ -262,7 +324,11 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Extern.
     /// Expected pattern: `extern(<FunctionDeclaration>|type<Identifier>);`
-    fn expect_extern_item(&mut self, attributes: AttributeListGreen) -> ItemGreen {
+    fn expect_extern_item(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemGreen {
         let extern_kw = self.take::<TerminalExtern>();
         match self.peek().kind {
             SyntaxKind::TerminalFunction => {
@@ -271,6 +337,7 @@ impl<'a> Parser<'a> {
                 ItemExternFunction::new_green(
                     self.db,
                     attributes,
+                    visibility,
                     extern_kw,
                     declaration,
                     semicolon,
@@ -288,6 +355,7 @@ impl<'a> Parser<'a> {
                 ItemExternType::new_green(
                     self.db,
                     attributes,
+                    visibility,
                     extern_kw,
                     type_kw,
                     name,
@@ -301,11 +369,15 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Use.
     /// Expected pattern: `use<Path>;`
-    fn expect_use(&mut self, attributes: AttributeListGreen) -> ItemUseGreen {
+    fn expect_use(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemUseGreen {
         let use_kw = self.take::<TerminalUse>();
         let path = self.parse_path();
         let semicolon = self.parse_token::<TerminalSemicolon>();
-        ItemUse::new_green(self.db, attributes, use_kw, path, semicolon)
+        ItemUse::new_green(self.db, attributes, visibility, use_kw, path, semicolon)
     }
 
     /// Returns a GreenId of a node with an identifier kind or None if an identifier can't be
@@ -406,14 +478,22 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Function.
     /// Expected pattern: `<FunctionDeclaration><Block>`
-    fn expect_free_function(&mut self, attributes: AttributeListGreen) -> ItemFreeFunctionGreen {
+    fn expect_free_function(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemFreeFunctionGreen {
         let declaration = self.expect_function_declaration();
         let function_body = self.parse_block();
-        ItemFreeFunction::new_green(self.db, attributes, declaration, function_body)
+        ItemFreeFunction::new_green(self.db, attributes, visibility, declaration, function_body)
     }
 
     /// Assumes the current token is Trait.
-    fn expect_trait(&mut self, attributes: AttributeListGreen) -> ItemTraitGreen {
+    fn expect_trait(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemTraitGreen {
         let trait_kw = self.take::<TerminalTrait>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -433,7 +513,7 @@ impl<'a> Parser<'a> {
             self.parse_token::<TerminalSemicolon>().into()
         };
 
-        ItemTrait::new_green(self.db, attributes, trait_kw, name, generic_params, body)
+        ItemTrait::new_green(self.db, attributes, visibility, trait_kw, name, generic_params, body)
     }
 
     /// Returns a GreenId of a node with a TraitItem.* kind (see
@@ -460,7 +540,11 @@ impl<'a> Parser<'a> {
     }
 
     /// Assumes the current token is Impl.
-    fn expect_impl(&mut self, attributes: AttributeListGreen) -> ItemImplGreen {
+    fn expect_impl(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemImplGreen {
         let impl_kw = self.take::<TerminalImpl>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -485,6 +569,7 @@ impl<'a> Parser<'a> {
         ItemImpl::new_green(
             self.db,
             attributes,
+            visibility,
             impl_kw,
             name,
             generic_params,
@@ -524,6 +609,14 @@ impl<'a> Parser<'a> {
                 SyntaxKind::TerminalPlus => self.take::<TerminalPlus>().into(),
                 SyntaxKind::TerminalMinus => self.take::<TerminalMinus>().into(),
                 SyntaxKind::TerminalEq => self.take::<TerminalEq>().into(),
+                SyntaxKind::TerminalPlusEq => self.take::<TerminalPlusEq>().into(),
+                SyntaxKind::TerminalMinusEq => self.take::<TerminalMinusEq>().into(),
+                SyntaxKind::TerminalMulEq => self.take::<TerminalMulEq>().into(),
+                SyntaxKind::TerminalDivEq => self.take::<TerminalDivEq>().into(),
+                SyntaxKind::TerminalModEq => self.take::<TerminalModEq>().into(),
+                SyntaxKind::TerminalAndEq => self.take::<TerminalAndEq>().into(),
+                SyntaxKind::TerminalOrEq => self.take::<TerminalOrEq>().into(),
+                SyntaxKind::TerminalXorEq => self.take::<TerminalXorEq>().into(),
                 SyntaxKind::TerminalEqEq => self.take::<TerminalEqEq>().into(),
                 SyntaxKind::TerminalNeq => self.take::<TerminalNeq>().into(),
                 SyntaxKind::TerminalLT => self.take::<TerminalLT>().into(),
@@ -533,6 +626,8 @@ impl<'a> Parser<'a> {
                 SyntaxKind::TerminalAnd => self.take::<TerminalAnd>().into(),
                 SyntaxKind::TerminalOr => self.take::<TerminalOr>().into(),
                 SyntaxKind::TerminalXor => self.take::<TerminalXor>().into(),
+                SyntaxKind::TerminalShl => self.take::<TerminalShl>().into(),
+                SyntaxKind::TerminalShr => self.take::<TerminalShr>().into(),
                 _ => unreachable!(),
             })
         }
@@ -574,11 +669,13 @@ impl<'a> Parser<'a> {
             )
             .into();
         }
-        while let Some(precedence) = get_binary_operator_precedence(self.peek().kind) {
+        while let Some(precedence) = get_post_operator_precedence(self.peek().kind) {
             if precedence >= parent_precedence {
                 return Some(expr);
             }
-            if let Some(op) = self.try_parse_binary_operator() {
+            if self.peek().kind == SyntaxKind::TerminalLBrack {
+                expr = self.expect_indexed_expr(expr).into();
+            } else if let Some(op) = self.try_parse_binary_operator() {
                 let rhs = self.parse_expr_limited(precedence, lbrace_allowed);
                 expr = ExprBinary::new_green(self.db, expr, op, rhs).into();
             } else {
@@ -603,8 +700,8 @@ impl<'a> Parser<'a> {
     }
 
     /// Returns a GreenId of a node with an
-    /// ExprPath|ExprFunctionCall|ExprStructCtorCall|ExprParenthesized|ExprTuple kind, or None if
-    /// such an expression can't be parsed.
+    /// ExprPath|ExprFunctionCall|ExprStructCtorCall|ExprParenthesized|ExprTuple|ExprClosure kind,
+    /// or None if such an expression can't be parsed.
     ///
     /// `lbrace_allowed` - See [LbraceAllowed].
     fn try_parse_atom(&mut self, lbrace_allowed: LbraceAllowed) -> Option<ExprGreen> {
@@ -625,6 +722,7 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalTrue => Some(self.take::<TerminalTrue>().into()),
             SyntaxKind::TerminalLiteralNumber => Some(self.take::<TerminalLiteralNumber>().into()),
             SyntaxKind::TerminalShortString => Some(self.take::<TerminalShortString>().into()),
+            SyntaxKind::TerminalString => Some(self.take::<TerminalString>().into()),
             SyntaxKind::TerminalLParen => {
                 // Note that LBrace is allowed inside parenthesis, even if `lbrace_allowed` is
                 // [LbraceAllowed::Forbid].
@@ -637,7 +735,21 @@ impl<'a> Parser<'a> {
                 Some(self.expect_match_expr().into())
             }
             SyntaxKind::TerminalIf if lbrace_allowed == LbraceAllowed::Allow => {
-                Some(self.expect_if_expr().into())
+                let if_kw = self.take::<TerminalIf>();
+                Some(if self.peek().kind == SyntaxKind::TerminalLet {
+                    self.expect_if_let_expr(if_kw).into()
+                } else {
+                    self.expect_if_expr(if_kw).into()
+                })
+            }
+            SyntaxKind::TerminalLoop if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_loop_expr().into())
+            }
+            SyntaxKind::TerminalWhile if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_while_expr().into())
+            }
+            SyntaxKind::TerminalOr | SyntaxKind::TerminalOrOr => {
+                Some(self.expect_closure_expr().into())
             }
             _ => {
                 // TODO(yuval): report to diagnostics.
@@ -731,6 +843,39 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Assumes the current token is LBrack.
+    /// Expected pattern: `\[<expr>\]`
+    /// Returns a GreenId of a node with kind ExprIndexed, indexing the given expression.
+    fn expect_indexed_expr(&mut self, expr: ExprGreen) -> ExprIndexedGreen {
+        let lbrack = self.take::<TerminalLBrack>();
+        let index_expr = self.parse_expr();
+        let rbrack = self.parse_token::<TerminalRBrack>();
+        ExprIndexed::new_green(self.db, expr, lbrack, index_expr, rbrack)
+    }
+
+    /// Assumes the current token is Or or OrOr.
+    /// Expected pattern: `\|<ParamList>\|<Expr>` or `\|\|<Expr>`
+    fn expect_closure_expr(&mut self) -> ExprClosureGreen {
+        let params: ClosureParamsGreen = match self.peek().kind {
+            SyntaxKind::TerminalOrOr => self.take::<TerminalOrOr>().into(),
+            _ => {
+                let lor = self.take::<TerminalOr>();
+                let params = ParamList::new_green(
+                    self.db,
+                    self.parse_separated_list::<Param, TerminalComma, ParamListElementOrSeparatorGreen>(
+                        Self::try_parse_param,
+                        is_of_kind!(or, rparen, block, lbrace, rbrace, top_level),
+                        "parameter",
+                    ),
+                );
+                let ror = self.parse_token::<TerminalOr>();
+                ClosureParamsWrapped::new_green(self.db, lor, params, ror).into()
+            }
+        };
+        let body = self.parse_expr();
+        ExprClosure::new_green(self.db, params, body)
+    }
+
     /// Assumes the current token is DotDot.
     /// Expected pattern: `\.\.<Expr>`
     fn expect_struct_argument_tail(&mut self) -> StructArgTailGreen {
@@ -810,26 +955,65 @@ impl<'a> Parser<'a> {
         ExprMatch::new_green(self.db, match_kw, expr, lbrace, arms, rbrace)
     }
 
-    /// Assumes the current token is `If`.
+    /// Assumes the `if` keyword was already taken.
     /// Expected pattern: `if <expr> <block> [else <block>]`.
-    fn expect_if_expr(&mut self) -> ExprIfGreen {
-        let if_kw = self.take::<TerminalIf>();
+    fn expect_if_expr(&mut self, if_kw: TerminalIfGreen) -> ExprIfGreen {
         let condition = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
         let if_block = self.parse_block();
+        let else_clause = self.parse_option_else_clause();
+
+        ExprIf::new_green(self.db, if_kw, condition, if_block, else_clause)
+    }
+
+    /// Assumes the `if` keyword was already taken, and the current token is `Let`.
+    /// Expected pattern: `if let <pattern> = <expr> <block> [else <block>]`.
+    fn expect_if_let_expr(&mut self, if_kw: TerminalIfGreen) -> ExprIfLetGreen {
+        let let_kw = self.take::<TerminalLet>();
+        let pattern = self.parse_pattern();
+        let eq = self.parse_token::<TerminalEq>();
+        let expr = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
+        let if_block = self.parse_block();
+        let else_clause = self.parse_option_else_clause();
+
+        ExprIfLet::new_green(self.db, if_kw, let_kw, pattern, eq, expr, if_block, else_clause)
+    }
 
-        let else_clause: OptionElseClauseGreen = if self.peek().kind == SyntaxKind::TerminalElse {
-            let else_kw = self.take::<TerminalElse>();
-            let else_block_or_if = if self.peek().kind == SyntaxKind::TerminalIf {
-                BlockOrIfGreen::from(self.expect_if_expr())
+    /// Expected pattern: `[else <block>]`, where the block may be replaced by an if expression.
+    fn parse_option_else_clause(&mut self) -> OptionElseClauseGreen {
+        if self.peek().kind != SyntaxKind::TerminalElse {
+            return OptionElseClauseEmpty::new_green(self.db).into();
+        }
+        let else_kw = self.take::<TerminalElse>();
+        let else_block_or_if = if self.peek().kind == SyntaxKind::TerminalIf {
+            let if_kw = self.take::<TerminalIf>();
+            if self.peek().kind == SyntaxKind::TerminalLet {
+                BlockOrIfGreen::from(self.expect_if_let_expr(if_kw))
             } else {
-                BlockOrIfGreen::from(self.parse_block())
-            };
-            ElseClause::new_green(self.db, else_kw, else_block_or_if).into()
+                BlockOrIfGreen::from(self.expect_if_expr(if_kw))
+            }
         } else {
-            OptionElseClauseEmpty::new_green(self.db).into()
+            BlockOrIfGreen::from(self.parse_block())
         };
+        ElseClause::new_green(self.db, else_kw, else_block_or_if).into()
+    }
 
-        ExprIf::new_green(self.db, if_kw, condition, if_block, else_clause)
+    /// Assumes the current token is `Loop`.
+    /// Expected pattern: `loop <block>`.
+    fn expect_loop_expr(&mut self) -> ExprLoopGreen {
+        let loop_kw = self.take::<TerminalLoop>();
+        let body = self.parse_block();
+
+        ExprLoop::new_green(self.db, loop_kw, body)
+    }
+
+    /// Assumes the current token is `While`.
+    /// Expected pattern: `while <expr> <block>`.
+    fn expect_while_expr(&mut self) -> ExprWhileGreen {
+        let while_kw = self.take::<TerminalWhile>();
+        let condition = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
+        let body = self.parse_block();
+
+        ExprWhile::new_green(self.db, while_kw, condition, body)
     }
 
     /// Returns a GreenId of a node with a MatchArm kind or None if a match arm can't be parsed.
@@ -854,6 +1038,8 @@ impl<'a> Parser<'a> {
         Some(match self.peek().kind {
             SyntaxKind::TerminalLiteralNumber => self.take::<TerminalLiteralNumber>().into(),
             SyntaxKind::TerminalShortString => self.take::<TerminalShortString>().into(),
+            SyntaxKind::TerminalTrue => self.take::<TerminalTrue>().into(),
+            SyntaxKind::TerminalFalse => self.take::<TerminalFalse>().into(),
             SyntaxKind::TerminalUnderscore => self.take::<TerminalUnderscore>().into(),
             SyntaxKind::TerminalIdentifier => {
                 // TODO(ilya): Consider parsing a single identifier as PatternIdentifier rather
@@ -943,6 +1129,14 @@ impl<'a> Parser<'a> {
                 let type_clause = self.parse_option_type_clause();
                 let eq = self.parse_token::<TerminalEq>();
                 let rhs = self.parse_expr();
+                let let_else_clause: OptionLetElseClauseGreen =
+                    if self.peek().kind == SyntaxKind::TerminalElse {
+                        let else_kw = self.take::<TerminalElse>();
+                        let else_block = self.parse_block();
+                        LetElseClause::new_green(self.db, else_kw, else_block).into()
+                    } else {
+                        OptionLetElseClauseEmpty::new_green(self.db).into()
+                    };
                 let semicolon = self.parse_token::<TerminalSemicolon>();
                 Some(
                     StatementLet::new_green(
@@ -952,6 +1146,7 @@ impl<'a> Parser<'a> {
                         type_clause,
                         eq,
                         rhs,
+                        let_else_clause,
                         semicolon,
                     )
                     .into(),
@@ -963,6 +1158,22 @@ impl<'a> Parser<'a> {
                 let semicolon = self.parse_token::<TerminalSemicolon>();
                 Some(StatementReturn::new_green(self.db, return_kw, expr, semicolon).into())
             }
+            SyntaxKind::TerminalBreak => {
+                let break_kw = self.take::<TerminalBreak>();
+                let expr_clause: OptionExprClauseGreen =
+                    if self.peek().kind == SyntaxKind::TerminalSemicolon {
+                        OptionExprClauseEmpty::new_green(self.db).into()
+                    } else {
+                        ExprClause::new_green(self.db, self.parse_expr()).into()
+                    };
+                let semicolon = self.parse_token::<TerminalSemicolon>();
+                Some(StatementBreak::new_green(self.db, break_kw, expr_clause, semicolon).into())
+            }
+            SyntaxKind::TerminalContinue => {
+                let continue_kw = self.take::<TerminalContinue>();
+                let semicolon = self.parse_token::<TerminalSemicolon>();
+                Some(StatementContinue::new_green(self.db, continue_kw, semicolon).into())
+            }
             _ => match self.try_parse_expr() {
                 None => None,
                 Some(expr) => {
@@ -1115,9 +1326,13 @@ impl<'a> Parser<'a> {
     /// Returns a GreenId of a node with kind Member or None if a struct member/enum variant can't
     /// be parsed.
     fn try_parse_member(&mut self) -> Option<MemberGreen> {
-        let name = self.try_parse_identifier()?;
+        let has_visibility = self.peek().kind == SyntaxKind::TerminalPub;
+        let visibility = self.parse_visibility();
+        // A member is expected after a visibility modifier.
+        let name =
+            if has_visibility { self.parse_identifier() } else { self.try_parse_identifier()? };
         let type_clause = self.parse_type_clause();
-        Some(Member::new_green(self.db, name, type_clause))
+        Some(Member::new_green(self.db, visibility, name, type_clause))
     }
 
     /// Expected pattern: `<PathSegment>(::<PathSegment>)*`
@@ -1202,7 +1417,7 @@ impl<'a> Parser<'a> {
                 "generic arg",
             ),
         );
-        let rangle = self.parse_token::<TerminalGT>();
+        let rangle = self.parse_rangle();
         GenericArgs::new_green(self.db, langle, generic_args, rangle)
     }
 
@@ -1218,10 +1433,36 @@ impl<'a> Parser<'a> {
                 "generic param",
             ),
         );
-        let rangle = self.parse_token::<TerminalGT>();
+        let rangle = self.parse_rangle();
         WrappedGenericParamList::new_green(self.db, langle, generic_params, rangle)
     }
 
+    /// Parses the closing `>` of generic args or generic params.
+    /// Nested generics may end with a `>>` token (e.g. `Array::<Array::<u8>>`). In this case, the
+    /// token is split, and only its first `>` is taken.
+    fn parse_rangle(&mut self) -> TerminalGTGreen {
+        if self.peek().kind != SyntaxKind::TerminalShr {
+            return self.parse_token::<TerminalGT>();
+        }
+        let LexerTerminal { leading_trivia, trailing_trivia, .. } = self.next_terminal.clone();
+        let first = LexerTerminal {
+            text: ">".into(),
+            kind: SyntaxKind::TerminalGT,
+            leading_trivia,
+            trailing_trivia: vec![],
+        };
+        self.next_terminal = LexerTerminal {
+            text: ">".into(),
+            kind: SyntaxKind::TerminalGT,
+            leading_trivia: vec![],
+            trailing_trivia,
+        };
+        self.offset += self.current_width;
+        self.current_width = first.width(self.db);
+        self.last_trivia_length = 0;
+        self.add_trivia_to_terminal::<TerminalGT>(first)
+    }
+
     fn parse_optional_generic_params(&mut self) -> OptionWrappedGenericParamListGreen {
         if self.peek().kind != SyntaxKind::TerminalLT {
             return OptionWrappedGenericParamListEmpty::new_green(self.db).into();
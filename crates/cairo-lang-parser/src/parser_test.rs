@@ -276,6 +276,7 @@ cairo_lang_test_utils::test_file_test!(
         exprs: "exprs",
         fn_: "fn",
         if_: "if",
+        while_: "while",
         match_: "match",
         pattern: "pattern",
         question_mark: "question_mark",
@@ -295,8 +296,16 @@ cairo_lang_test_utils::test_file_test!(
         function_call: "function_call",
         not_isnt_a_binary_operator: "not_isnt_a_binary_operator",
         item_trait: "item_trait",
+        item_constant: "item_constant",
+        visibility: "visibility",
         let_statement: "let_statement",
         if_else: "if_else",
+        loop_: "loop",
+        closure: "closure",
+        index: "index",
+        shift: "shift",
+        compound_assignment: "compound_assignment",
+        tuple_item: "tuple_item",
         literal: "literal",
         module: "module",
     },
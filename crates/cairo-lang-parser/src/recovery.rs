@@ -34,9 +34,16 @@ macro_rules! rparen {
 }
 pub(crate) use rparen;
 
+macro_rules! or {
+    () => {
+        SyntaxKind::TerminalOr
+    };
+}
+pub(crate) use or;
+
 macro_rules! rangle {
     () => {
-        SyntaxKind::TerminalGT
+        SyntaxKind::TerminalGT | SyntaxKind::TerminalShr
     };
 }
 pub(crate) use rangle;
@@ -45,6 +52,7 @@ macro_rules! top_level {
     () => {
         SyntaxKind::TerminalExtern
             | SyntaxKind::TerminalType
+            | SyntaxKind::TerminalConst
             | SyntaxKind::TerminalEnum
             | SyntaxKind::TerminalTrait
             | SyntaxKind::TerminalImpl
@@ -52,13 +60,18 @@ macro_rules! top_level {
             | SyntaxKind::TerminalModule
             | SyntaxKind::TerminalStruct
             | SyntaxKind::TerminalUse
+            | SyntaxKind::TerminalPub
     };
 }
 pub(crate) use top_level;
 
 macro_rules! block {
     () => {
-        SyntaxKind::TerminalLet | SyntaxKind::TerminalMatch | SyntaxKind::TerminalReturn
+        SyntaxKind::TerminalLet
+            | SyntaxKind::TerminalMatch
+            | SyntaxKind::TerminalReturn
+            | SyntaxKind::TerminalBreak
+            | SyntaxKind::TerminalContinue
     };
 }
 pub(crate) use block;
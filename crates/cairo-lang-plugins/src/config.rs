@@ -27,6 +27,7 @@ impl MacroPlugin for ConfigPlugin {
             ast::Item::Struct(ast_node) => ast_node.attributes(db),
             ast::Item::Enum(ast_node) => ast_node.attributes(db),
             ast::Item::TypeAlias(ast_node) => ast_node.attributes(db),
+            ast::Item::Constant(ast_node) => ast_node.attributes(db),
         };
         for attr in item_attributes.elements(db) {
             if attr.attr(db).text(db) == "cfg" {
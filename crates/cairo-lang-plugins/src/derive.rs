@@ -7,6 +7,7 @@ use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialMappe
 use cairo_lang_syntax::node::ast::AttributeList;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use itertools::Itertools;
 
 #[derive(Debug)]
 pub struct DerivePlugin {}
@@ -14,12 +15,18 @@ pub struct DerivePlugin {}
 impl MacroPlugin for DerivePlugin {
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
         match item_ast {
-            ast::Item::Struct(struct_ast) => {
-                generate_derive_code_for_type(db, struct_ast.name(db), struct_ast.attributes(db))
-            }
-            ast::Item::Enum(enum_ast) => {
-                generate_derive_code_for_type(db, enum_ast.name(db), enum_ast.attributes(db))
-            }
+            ast::Item::Struct(struct_ast) => generate_derive_code_for_type(
+                db,
+                struct_ast.name(db),
+                struct_ast.generic_params(db),
+                struct_ast.attributes(db),
+            ),
+            ast::Item::Enum(enum_ast) => generate_derive_code_for_type(
+                db,
+                enum_ast.name(db),
+                enum_ast.generic_params(db),
+                enum_ast.attributes(db),
+            ),
             _ => PluginResult::default(),
         }
     }
@@ -35,13 +42,27 @@ impl AsDynMacroPlugin for DerivePlugin {
 impl SemanticPlugin for DerivePlugin {}
 
 /// Adds an implementation for all requested derives for the type.
+/// For a generic type, the implementations are generic over the same parameters.
 fn generate_derive_code_for_type(
     db: &dyn SyntaxGroup,
     ident: ast::TerminalIdentifier,
+    generic_params: ast::OptionWrappedGenericParamList,
     attributes: AttributeList,
 ) -> PluginResult {
     let mut diagnostics = vec![];
     let mut impls = vec![];
+    let (impl_generic_params, ty) = match generic_params {
+        ast::OptionWrappedGenericParamList::Empty(_) => ("".to_string(), ident.text(db).into()),
+        ast::OptionWrappedGenericParamList::WrappedGenericParamList(params) => {
+            let params = params
+                .generic_params(db)
+                .elements(db)
+                .iter()
+                .map(|param| param.name(db).text(db))
+                .join(", ");
+            (format!("<{params}>"), format!("{}::<{params}>", ident.text(db)))
+        }
+    };
     for attr in attributes.elements(db) {
         if attr.attr(db).text(db) == "derive" {
             if let ast::OptionAttributeArgs::AttributeArgs(args) = attr.args(db) {
@@ -50,7 +71,9 @@ fn generate_derive_code_for_type(
                         if let [ast::PathSegment::Simple(segment)] = &expr.elements(db)[..] {
                             let name = ident.text(db);
                             let derived = segment.ident(db).text(db);
-                            impls.push(format!("impl {name}{derived} of {derived}::<{name}>;\n"));
+                            impls.push(format!(
+                                "impl {name}{derived}{impl_generic_params} of {derived}::<{ty}>;\n"
+                            ));
                         } else {
                             diagnostics.push(PluginDiagnostic {
                                 stable_ptr: expr.stable_ptr().untyped(),
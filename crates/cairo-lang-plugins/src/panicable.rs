@@ -15,17 +15,21 @@ pub struct PanicablePlugin {}
 
 impl MacroPlugin for PanicablePlugin {
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
-        let (declaration, attributes) = match item_ast {
-            ast::Item::ExternFunction(extern_func_ast) => {
-                (extern_func_ast.declaration(db), extern_func_ast.attributes(db))
-            }
-            ast::Item::FreeFunction(free_func_ast) => {
-                (free_func_ast.declaration(db), free_func_ast.attributes(db))
-            }
+        let (declaration, attributes, visibility) = match item_ast {
+            ast::Item::ExternFunction(extern_func_ast) => (
+                extern_func_ast.declaration(db),
+                extern_func_ast.attributes(db),
+                extern_func_ast.visibility(db),
+            ),
+            ast::Item::FreeFunction(free_func_ast) => (
+                free_func_ast.declaration(db),
+                free_func_ast.attributes(db),
+                free_func_ast.visibility(db),
+            ),
             _ => return PluginResult::default(),
         };
 
-        generate_panicable_code(db, declaration, attributes)
+        generate_panicable_code(db, declaration, attributes, visibility)
     }
 }
 impl AsDynMacroPlugin for PanicablePlugin {
@@ -43,6 +47,7 @@ fn generate_panicable_code(
     db: &dyn SyntaxGroup,
     declaration: ast::FunctionDeclaration,
     attributes: AttributeList,
+    visibility: ast::Visibility,
 ) -> PluginResult {
     let remove_original_item = false;
     for attr in attributes.elements(db) {
@@ -102,6 +107,8 @@ fn generate_panicable_code(
             };
         };
 
+        // The wrapper is as visible as the wrapped function.
+        let visibility = visibility.as_syntax_node().get_text(db);
         let function_name = declaration.name(db).text(db);
         let params = signature.parameters(db).as_syntax_node().get_text(db);
         let args = signature
@@ -115,7 +122,7 @@ fn generate_panicable_code(
                 name: "panicable".into(),
                 content: indoc::formatdoc!(
                     r#"
-                    fn {panicable_name}({params}) -> {inner_ty_text} {{
+                    {visibility}fn {panicable_name}({params}) -> {inner_ty_text} {{
                         match {function_name}({args}) {{
                             {success_variant} (v) => {{
                                 v
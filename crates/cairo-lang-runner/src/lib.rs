@@ -2,6 +2,7 @@
 use std::collections::HashMap;
 
 use cairo_lang_casm::instructions::Instruction;
+use cairo_lang_casm::run::StarknetState;
 use cairo_lang_casm::{casm, casm_extend};
 use cairo_lang_sierra::extensions::builtin_cost::CostTokenType;
 use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
@@ -88,13 +89,31 @@ impl SierraCasmRunner {
         name_suffix: &str,
         args: &[BigInt],
         available_gas: Option<usize>,
+    ) -> Result<RunResult, RunnerError> {
+        self.run_function_with_starknet_state(
+            name_suffix,
+            args,
+            available_gas,
+            StarknetState::default(),
+        )
+    }
+
+    /// Runs the vm starting from a function, as `run_function` does, where the execution info
+    /// system calls return the values in `starknet_state`.
+    pub fn run_function_with_starknet_state(
+        &self,
+        name_suffix: &str,
+        args: &[BigInt],
+        available_gas: Option<usize>,
+        starknet_state: StarknetState,
     ) -> Result<RunResult, RunnerError> {
         let func = self.find_function(name_suffix)?;
         let initial_gas = self.get_initial_gas(func, available_gas)?;
         let (entry_code, builtins) = self.create_entry_code(func, args, initial_gas)?;
-        let (cells, ap) = cairo_lang_casm::run::run_function(
+        let (cells, ap) = cairo_lang_casm::run::run_function_with_starknet_state(
             chain!(entry_code.iter(), self.casm_program.instructions.iter()),
             builtins,
+            starknet_state,
         )?;
         let mut results_data = self.get_results_data(func, &cells, ap)?;
         // Handling implicits.
@@ -1,7 +1,7 @@
 use cairo_lang_defs::ids::{
     EnumId, GenericFunctionId, GenericTypeId, ModuleId, ModuleItemId, TraitId,
 };
-use cairo_lang_diagnostics::{Maybe, ToOption};
+use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToOption};
 use cairo_lang_filesystem::ids::CrateLongId;
 use cairo_lang_syntax::node::ast::{self, BinaryOperator, UnaryOperator};
 use cairo_lang_utils::{extract_matches, try_extract_matches, OptionFrom};
@@ -11,12 +11,13 @@ use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind;
 use crate::expr::compute::ComputationContext;
 use crate::items::enm::SemanticEnumEx;
+use crate::items::imp::{find_unique_impl, impl_function_by_name, ConcreteImplLongId};
 use crate::items::trt::ConcreteTraitId;
 use crate::resolve_path::ResolvedGenericItem;
 use crate::types::ConcreteEnumLongId;
 use crate::{
-    semantic, ConcreteEnumId, ConcreteFunction, ConcreteVariant, Expr, ExprId, ExprTuple,
-    FunctionId, FunctionLongId, GenericArgumentId, TypeId, TypeLongId,
+    semantic, ClosureTypeLongId, ConcreteEnumId, ConcreteFunction, ConcreteVariant, Expr, ExprId,
+    ExprTuple, FunctionId, FunctionLongId, GenericArgumentId, TypeId, TypeLongId,
 };
 
 pub fn core_module(db: &dyn SemanticGroup) -> ModuleId {
@@ -229,6 +230,8 @@ pub fn unwrap_error_propagation_type(
             semantic::ConcreteTypeId::Struct(_) | semantic::ConcreteTypeId::Extern(_),
         )
         | TypeLongId::Tuple(_)
+        | TypeLongId::Closure(_)
+        | TypeLongId::Var(_)
         | TypeLongId::Missing(_) => None,
     }
 }
@@ -243,107 +246,111 @@ pub fn unit_expr(ctx: &mut ComputationContext<'_>, stable_ptr: ast::ExprPtr) ->
     }))
 }
 
+/// Returns the function implementing the given unary operator for the given type, through the
+/// impl of the matching core trait (`Neg` or `Not`).
 pub fn core_unary_operator(
     db: &dyn SemanticGroup,
+    module_id: ModuleId,
     unary_op: &UnaryOperator,
     ty: TypeId,
-) -> Result<FunctionId, SemanticDiagnosticKind> {
-    let felt_ty = core_felt_ty(db);
-    let bool_ty = core_bool_ty(db);
-    let unsupported_operator =
-        |op: &str| Err(SemanticDiagnosticKind::UnsupportedUnaryOperator { op: op.into(), ty });
-
-    let function_name = match unary_op {
-        UnaryOperator::Minus(_) if ty == felt_ty => "felt_neg",
-        UnaryOperator::Minus(_) => return unsupported_operator("-"),
+) -> Maybe<Result<FunctionId, SemanticDiagnosticKind>> {
+    ty.check_not_missing(db)?;
 
-        UnaryOperator::Not(_) if ty == bool_ty => "bool_not",
-        UnaryOperator::Not(_) => return unsupported_operator("!"),
+    let (op, trait_name, function_name) = match unary_op {
+        UnaryOperator::Minus(_) => ("-", "Neg", "neg"),
+        UnaryOperator::Not(_) => ("!", "Not", "not"),
     };
-    Ok(get_core_function_id(db, function_name.into(), vec![]))
+    let concrete_trait_id =
+        get_core_concrete_trait(db, trait_name.into(), vec![GenericArgumentId::Type(ty)]);
+    Ok(match get_impl_function(db, module_id, concrete_trait_id, function_name.into())? {
+        Err(SemanticDiagnosticKind::NoImplementationOfTrait { .. }) => {
+            Err(SemanticDiagnosticKind::UnsupportedUnaryOperator { op: op.into(), ty })
+        }
+        result => result,
+    })
 }
 
+/// Returns the function implementing the given binary operator for the given types, through the
+/// impl of the matching core trait (e.g. `Add`, `PartialEq`).
 pub fn core_binary_operator(
     db: &dyn SemanticGroup,
+    module_id: ModuleId,
     binary_op: &BinaryOperator,
     type1: TypeId,
     type2: TypeId,
 ) -> Maybe<Result<FunctionId, SemanticDiagnosticKind>> {
-    // TODO(lior): Replace current hard-coded implementation with an implementation that is based on
-    //   traits.
     type1.check_not_missing(db)?;
     type2.check_not_missing(db)?;
 
-    let felt_ty = core_felt_ty(db);
-    let u128_ty = get_core_ty_by_name(db, "u128".into(), vec![]);
-    let u256_ty = get_core_ty_by_name(db, "u256".into(), vec![]);
-    let bool_ty = core_bool_ty(db);
-    let unsupported_operator = |op: &str| {
-        Ok(Err(SemanticDiagnosticKind::UnsupportedBinaryOperator { op: op.into(), type1, type2 }))
-    };
-    let function_name = match binary_op {
-        BinaryOperator::Plus(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_add",
-        BinaryOperator::Plus(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_add",
-        BinaryOperator::Plus(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_add",
-        BinaryOperator::Plus(_) => return unsupported_operator("+"),
-        BinaryOperator::Minus(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_sub",
-        BinaryOperator::Minus(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_sub",
-        BinaryOperator::Minus(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_sub",
-        BinaryOperator::Minus(_) => return unsupported_operator("-"),
-        BinaryOperator::Mul(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_mul",
-        BinaryOperator::Mul(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_mul",
-        BinaryOperator::Mul(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_mul",
-        BinaryOperator::Mul(_) => return unsupported_operator("*"),
-        BinaryOperator::Div(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_div",
-        BinaryOperator::Div(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_div",
-        BinaryOperator::Div(_) => return unsupported_operator("/"),
-        BinaryOperator::Mod(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_mod",
-        BinaryOperator::Mod(_) => return unsupported_operator("%"),
-        BinaryOperator::EqEq(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_eq",
-        BinaryOperator::EqEq(_) if [type1, type2] == [bool_ty, bool_ty] => "bool_eq",
-        BinaryOperator::EqEq(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_eq",
-        BinaryOperator::EqEq(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_eq",
-        BinaryOperator::EqEq(_) => return unsupported_operator("=="),
-        BinaryOperator::Neq(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_ne",
-        BinaryOperator::Neq(_) if [type1, type2] == [bool_ty, bool_ty] => "bool_ne",
-        BinaryOperator::Neq(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_ne",
-        BinaryOperator::Neq(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_ne",
-        BinaryOperator::Neq(_) => return unsupported_operator("!="),
-        BinaryOperator::And(_) if [type1, type2] == [bool_ty, bool_ty] => "bool_and",
-        BinaryOperator::And(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_and",
-        BinaryOperator::And(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_and",
-        BinaryOperator::And(_) => return unsupported_operator("&"),
-        BinaryOperator::Or(_) if [type1, type2] == [bool_ty, bool_ty] => "bool_or",
-        BinaryOperator::Or(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_or",
-        BinaryOperator::Or(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_or",
-        BinaryOperator::Or(_) => return unsupported_operator("|"),
-        BinaryOperator::Xor(_) if [type1, type2] == [bool_ty, bool_ty] => "bool_xor",
-        BinaryOperator::Xor(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_xor",
-        BinaryOperator::Xor(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_xor",
-        BinaryOperator::Xor(_) => return unsupported_operator("^"),
-        BinaryOperator::LE(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_le",
-        BinaryOperator::LE(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_le",
-        BinaryOperator::LE(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_le",
-        BinaryOperator::LE(_) => return unsupported_operator("<="),
-        BinaryOperator::GE(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_ge",
-        BinaryOperator::GE(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_ge",
-        BinaryOperator::GE(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_ge",
-        BinaryOperator::GE(_) => return unsupported_operator(">="),
-        BinaryOperator::LT(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_lt",
-        BinaryOperator::LT(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_lt",
-        BinaryOperator::LT(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_lt",
-        BinaryOperator::LT(_) => return unsupported_operator("<"),
-        BinaryOperator::GT(_) if [type1, type2] == [felt_ty, felt_ty] => "felt_gt",
-        BinaryOperator::GT(_) if [type1, type2] == [u128_ty, u128_ty] => "u128_gt",
-        BinaryOperator::GT(_) if [type1, type2] == [u256_ty, u256_ty] => "u256_gt",
-        BinaryOperator::GT(_) => return unsupported_operator(">"),
+    let (op, trait_name, function_name) = match binary_op {
+        BinaryOperator::Plus(_) => ("+", "Add", "add"),
+        BinaryOperator::Minus(_) => ("-", "Sub", "sub"),
+        BinaryOperator::Mul(_) => ("*", "Mul", "mul"),
+        BinaryOperator::Div(_) => ("/", "Div", "div"),
+        BinaryOperator::Mod(_) => ("%", "Rem", "rem"),
+        BinaryOperator::EqEq(_) => ("==", "PartialEq", "eq"),
+        BinaryOperator::Neq(_) => ("!=", "PartialEq", "ne"),
+        BinaryOperator::And(_) => ("&", "BitAnd", "bitand"),
+        BinaryOperator::Or(_) => ("|", "BitOr", "bitor"),
+        BinaryOperator::Xor(_) => ("^", "BitXor", "bitxor"),
+        BinaryOperator::PlusEq(_) => ("+=", "Add", "add"),
+        BinaryOperator::MinusEq(_) => ("-=", "Sub", "sub"),
+        BinaryOperator::MulEq(_) => ("*=", "Mul", "mul"),
+        BinaryOperator::DivEq(_) => ("/=", "Div", "div"),
+        BinaryOperator::ModEq(_) => ("%=", "Rem", "rem"),
+        BinaryOperator::AndEq(_) => ("&=", "BitAnd", "bitand"),
+        BinaryOperator::OrEq(_) => ("|=", "BitOr", "bitor"),
+        BinaryOperator::XorEq(_) => ("^=", "BitXor", "bitxor"),
+        BinaryOperator::Shl(_) => ("<<", "Shl", "shl"),
+        BinaryOperator::Shr(_) => (">>", "Shr", "shr"),
+        BinaryOperator::LE(_) => ("<=", "PartialOrd", "le"),
+        BinaryOperator::GE(_) => (">=", "PartialOrd", "ge"),
+        BinaryOperator::LT(_) => ("<", "PartialOrd", "lt"),
+        BinaryOperator::GT(_) => (">", "PartialOrd", "gt"),
         _ => return Ok(Err(SemanticDiagnosticKind::UnknownBinaryOperator)),
     };
-    Ok(Ok(get_core_function_id(db, function_name.into(), vec![])))
+    let unsupported_operator =
+        SemanticDiagnosticKind::UnsupportedBinaryOperator { op: op.into(), type1, type2 };
+    // The operator traits are only implemented for operands of the same type.
+    if type1 != type2 {
+        return Ok(Err(unsupported_operator));
+    }
+    let concrete_trait_id =
+        get_core_concrete_trait(db, trait_name.into(), vec![GenericArgumentId::Type(type1)]);
+    Ok(match get_impl_function(db, module_id, concrete_trait_id, function_name.into())? {
+        Err(SemanticDiagnosticKind::NoImplementationOfTrait { .. }) => Err(unsupported_operator),
+        result => result,
+    })
 }
 
+/// Returns the function named `function_name` of the impl of the given concrete trait, found in
+/// the lookup context of the given module.
+fn get_impl_function(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    concrete_trait_id: ConcreteTraitId,
+    function_name: SmolStr,
+) -> Maybe<Result<FunctionId, SemanticDiagnosticKind>> {
+    let concrete_impl_id = match find_unique_impl(db, module_id, concrete_trait_id)? {
+        Ok(concrete_impl_id) => concrete_impl_id,
+        Err(kind) => return Ok(Err(kind)),
+    };
+    let ConcreteImplLongId { impl_id, generic_args } =
+        db.lookup_intern_concrete_impl(concrete_impl_id);
+    // A missing function is reported on the impl itself.
+    let impl_function_id =
+        impl_function_by_name(db, impl_id, &function_name)?.ok_or_else(skip_diagnostic)?;
+    Ok(Ok(db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::ImplFunction(impl_function_id),
+            generic_args,
+        },
+    })))
+}
+
+/// Returns the function implementing `==` for felts.
 pub fn felt_eq(db: &dyn SemanticGroup) -> FunctionId {
-    get_core_function_id(db, "felt_eq".into(), vec![])
+    get_core_impl_function_id(db, "FeltPartialEq".into(), "eq".into())
 }
 
 pub fn felt_sub(db: &dyn SemanticGroup) -> FunctionId {
@@ -367,6 +374,31 @@ pub fn get_core_function_id(
     })
 }
 
+/// Given the name of a non-generic impl in the core library and the name of one of its functions,
+/// returns [FunctionId].
+fn get_core_impl_function_id(
+    db: &dyn SemanticGroup,
+    impl_name: SmolStr,
+    function_name: SmolStr,
+) -> FunctionId {
+    let core_module = db.core_module();
+    let impl_id = extract_matches!(
+        db.module_item_by_name(core_module, impl_name.clone())
+            .expect("Failed to load core lib.")
+            .unwrap_or_else(|| panic!("Impl '{impl_name}' was not found in core lib.")),
+        ModuleItemId::Impl
+    );
+    let impl_function_id = impl_function_by_name(db, impl_id, &function_name)
+        .expect("Failed to load core lib.")
+        .unwrap_or_else(|| panic!("Function '{function_name}' was not found in '{impl_name}'."));
+    db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::ImplFunction(impl_function_id),
+            generic_args: vec![],
+        },
+    })
+}
+
 /// Given a core library function name, returns [GenericFunctionId].
 pub fn get_core_generic_function_id(db: &dyn SemanticGroup, name: SmolStr) -> GenericFunctionId {
     let core_module = db.core_module();
@@ -401,6 +433,50 @@ pub fn drop_trait(db: &dyn SemanticGroup) -> TraitId {
     get_core_trait(db, "Drop".into())
 }
 
+pub fn fn_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "Fn".into())
+}
+
+pub fn index_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "Index".into())
+}
+
+/// Returns the `call` function of the `Fn` trait, with the given generic arguments of the trait.
+pub fn fn_trait_call_function(
+    db: &dyn SemanticGroup,
+    trait_generic_args: Vec<GenericArgumentId>,
+) -> FunctionId {
+    let trait_function_id = db
+        .trait_functions(fn_trait(db))
+        .expect("Failed to load core lib.")
+        .get("call")
+        .copied()
+        .expect("Function 'call' was not found in 'Fn'.");
+    db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::TraitFunction(trait_function_id),
+            generic_args: trait_generic_args,
+        },
+    })
+}
+
+/// Returns the function calling a closure of the given type, through the `Fn` trait.
+pub fn closure_call_function(
+    db: &dyn SemanticGroup,
+    closure_ty: TypeId,
+    closure: &ClosureTypeLongId,
+) -> FunctionId {
+    let args_ty = db.intern_type(TypeLongId::Tuple(closure.param_tys.clone()));
+    fn_trait_call_function(
+        db,
+        vec![
+            GenericArgumentId::Type(closure_ty),
+            GenericArgumentId::Type(args_ty),
+            GenericArgumentId::Type(closure.ret_ty),
+        ],
+    )
+}
+
 /// Given a core library trait name and its generic arguments, returns [ConcreteTraitId].
 fn get_core_concrete_trait(
     db: &dyn SemanticGroup,
@@ -428,20 +504,39 @@ pub fn get_panic_ty(db: &dyn SemanticGroup, inner_ty: TypeId) -> TypeId {
     get_core_ty_by_name(db.upcast(), "PanicResult".into(), vec![GenericArgumentId::Type(inner_ty)])
 }
 
+/// Returns the type of the result of a loop that may return early from the function containing it.
+pub fn get_loop_result_ty(
+    db: &dyn SemanticGroup,
+    normal_ty: TypeId,
+    early_return_ty: TypeId,
+) -> TypeId {
+    get_core_ty_by_name(
+        db.upcast(),
+        "LoopResult".into(),
+        vec![GenericArgumentId::Type(normal_ty), GenericArgumentId::Type(early_return_ty)],
+    )
+}
+
 /// Returns the name of the libfunc that creates a constant of type `ty`.
 pub fn try_get_const_libfunc_name_by_type(
     db: &dyn SemanticGroup,
     ty: TypeId,
 ) -> Result<String, SemanticDiagnosticKind> {
     let felt_ty = core_felt_ty(db);
-    let u128_ty = get_core_ty_by_name(db, "u128".into(), vec![]);
     if ty == felt_ty {
-        Ok("felt_const".into())
-    } else if ty == u128_ty {
-        Ok("u128_const".into())
-    } else {
-        Err(SemanticDiagnosticKind::NoLiteralFunctionFound)
+        return Ok("felt_const".into());
     }
+    let type_name = try_get_core_integer_type_name(db, ty)
+        .ok_or(SemanticDiagnosticKind::NoLiteralFunctionFound)?;
+    Ok(format!("{type_name}_const"))
+}
+
+/// Returns the name of the given type if it is one of the integer types of the core library
+/// (e.g. `u128`), which have literals.
+pub fn try_get_core_integer_type_name(db: &dyn SemanticGroup, ty: TypeId) -> Option<&'static str> {
+    ["u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128"]
+        .into_iter()
+        .find(|type_name| ty == get_core_ty_by_name(db, (*type_name).into(), vec![]))
 }
 
 pub fn get_const_libfunc_name_by_type(db: &dyn SemanticGroup, ty: TypeId) -> String {
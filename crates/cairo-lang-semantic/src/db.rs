@@ -3,9 +3,10 @@ use std::sync::Arc;
 use cairo_lang_defs::db::{DefsGroup, GeneratedFileInfo};
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
-    EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, GenericFunctionId, GenericParamId,
-    GenericTypeId, ImplFunctionId, ImplId, LanguageElementId, LookupItemId, ModuleId, ModuleItemId,
-    StructId, TraitFunctionId, TraitId, TypeAliasId, UseId, VariantId,
+    ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, FunctionWithBodyId,
+    GenericFunctionId, GenericParamId, GenericTypeId, ImplFunctionId, ImplId, LanguageElementId,
+    LookupItemId, ModuleId, ModuleItemId, StructId, TraitFunctionId, TraitId, TypeAliasId, UseId,
+    VariantId,
 };
 use cairo_lang_defs::plugin::MacroPlugin;
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
@@ -25,7 +26,7 @@ use crate::items::trt::ConcreteTraitId;
 use crate::plugin::{DynDiagnosticMapper, SemanticPlugin};
 use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, ResolvedLookback};
 use crate::{
-    corelib, items, literals, semantic, types, FreeFunctionDefinition, FunctionId, Parameter,
+    corelib, items, literals, semantic, types, FunctionDefinition, FunctionId, Parameter,
     SemanticDiagnostic, TypeId,
 };
 
@@ -94,6 +95,10 @@ pub trait SemanticGroup:
     // Returns the attributes of a module
     #[salsa::invoke(items::attribute::module_attributes)]
     fn module_attributes(&self, module_id: ModuleId) -> Maybe<Vec<Attribute>>;
+    /// Returns the visibility of a module item.
+    #[salsa::invoke(items::visibility::module_item_visibility)]
+    fn module_item_visibility(&self, item_id: ModuleItemId)
+    -> Maybe<items::visibility::Visibility>;
 
     // Struct.
     // =======
@@ -168,6 +173,28 @@ pub trait SemanticGroup:
         type_alias_id: TypeAliasId,
     ) -> Maybe<Arc<ResolvedLookback>>;
 
+    // Constant.
+    // ====
+    /// Private query to compute data about a constant.
+    #[salsa::invoke(items::constant::priv_constant_semantic_data)]
+    #[salsa::cycle(items::constant::priv_constant_semantic_data_cycle)]
+    fn priv_constant_semantic_data(
+        &self,
+        constant_id: ConstantId,
+    ) -> Maybe<items::constant::ConstantData>;
+    /// Returns the semantic diagnostics of a constant.
+    #[salsa::invoke(items::constant::constant_semantic_diagnostics)]
+    fn constant_semantic_diagnostics(
+        &self,
+        constant_id: ConstantId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the compile time value of a constant.
+    #[salsa::invoke(items::constant::constant_value)]
+    fn constant_value(&self, constant_id: ConstantId) -> Maybe<items::constant::ConstantValue>;
+    /// Returns the resolution lookback of a constant.
+    #[salsa::invoke(items::constant::constant_resolved_lookback)]
+    fn constant_resolved_lookback(&self, constant_id: ConstantId) -> Maybe<Arc<ResolvedLookback>>;
+
     // Trait.
     // =======
     /// Private query to compute data about a trait.
@@ -207,7 +234,14 @@ pub trait SemanticGroup:
         &self,
         trait_function_id: TraitFunctionId,
     ) -> Maybe<semantic::Signature>;
-    /// Returns the generic params of a trait function.
+    /// Returns the attributes of a trait function.
+    #[salsa::invoke(items::trt::trait_function_attributes)]
+    fn trait_function_attributes(
+        &self,
+        trait_function_id: TraitFunctionId,
+    ) -> Maybe<Vec<Attribute>>;
+    /// Returns the generic params of a trait function, starting with the generic params of the
+    /// trait.
     #[salsa::invoke(items::trt::trait_function_generic_params)]
     fn trait_function_generic_params(
         &self,
@@ -269,7 +303,8 @@ pub trait SemanticGroup:
         &self,
         impl_function_id: ImplFunctionId,
     ) -> Maybe<semantic::Signature>;
-    /// Returns the generic params of a impl function.
+    /// Returns the generic params of a impl function, starting with the generic params of the
+    /// impl.
     #[salsa::invoke(items::imp::impl_function_generic_params)]
     fn impl_function_generic_params(
         &self,
@@ -294,6 +329,24 @@ pub trait SemanticGroup:
         &self,
         impl_function_id: ImplFunctionId,
     ) -> Maybe<items::imp::ImplFunctionDeclarationData>;
+    /// Returns the semantic diagnostics of a impl function definition - its body.
+    #[salsa::invoke(items::imp::impl_function_definition_diagnostics)]
+    fn impl_function_definition_diagnostics(
+        &self,
+        impl_function_id: ImplFunctionId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the resolution lookback of the body of an impl function.
+    #[salsa::invoke(items::imp::impl_function_definition_resolved_lookback)]
+    fn impl_function_definition_resolved_lookback(
+        &self,
+        impl_function_id: ImplFunctionId,
+    ) -> Maybe<Arc<ResolvedLookback>>;
+    /// Private query to compute data about a impl function definition - its body.
+    #[salsa::invoke(items::imp::priv_impl_function_definition_data)]
+    fn priv_impl_function_definition_data(
+        &self,
+        impl_function_id: ImplFunctionId,
+    ) -> Maybe<items::function_with_body::FunctionDefinitionData>;
 
     // Free function.
     // ==============
@@ -347,7 +400,7 @@ pub trait SemanticGroup:
     fn priv_free_function_definition_data(
         &self,
         free_function_id: FreeFunctionId,
-    ) -> Maybe<items::free_function::FreeFunctionDefinitionData>;
+    ) -> Maybe<items::function_with_body::FunctionDefinitionData>;
     /// Returns the semantic diagnostics of a function definition - its body.
     #[salsa::invoke(items::free_function::free_function_definition_diagnostics)]
     fn free_function_definition_diagnostics(
@@ -367,19 +420,6 @@ pub trait SemanticGroup:
         &self,
         free_function_id: FreeFunctionId,
     ) -> Maybe<Vec<FunctionId>>;
-    /// Returns the free function direct callees of a free function definition (i.e. excluding
-    /// libfunc callees). The items in the vector are unique.
-    #[salsa::invoke(items::free_function::free_function_definition_direct_free_function_callees)]
-    fn free_function_definition_direct_free_function_callees(
-        &self,
-        free_function_id: FreeFunctionId,
-    ) -> Maybe<Vec<FreeFunctionId>>;
-    /// Returns the definition of a free function.
-    #[salsa::invoke(items::free_function::free_function_definition)]
-    fn free_function_definition(
-        &self,
-        free_function_id: FreeFunctionId,
-    ) -> Maybe<Arc<FreeFunctionDefinition>>;
     /// Returns the resolution lookback of a free function.
     #[salsa::invoke(items::free_function::free_function_definition_resolved_lookback)]
     fn free_function_definition_resolved_lookback(
@@ -387,6 +427,70 @@ pub trait SemanticGroup:
         free_function_id: FreeFunctionId,
     ) -> Maybe<Arc<ResolvedLookback>>;
 
+    // Function with body.
+    // ===================
+    /// Returns the signature of a function with a body.
+    #[salsa::invoke(items::function_with_body::function_with_body_signature)]
+    fn function_with_body_signature(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Maybe<semantic::Signature>;
+    /// Returns all the available generic params inside a function body.
+    #[salsa::invoke(items::function_with_body::function_with_body_generic_params)]
+    fn function_with_body_generic_params(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Maybe<Vec<GenericParamId>>;
+    /// Returns the semantic diagnostics of a function with a body - both its declaration and its
+    /// definition.
+    #[salsa::invoke(items::function_with_body::function_with_body_diagnostics)]
+    fn function_with_body_diagnostics(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Diagnostics<SemanticDiagnostic>;
+    /// Returns the definition of a function with a body.
+    #[salsa::invoke(items::function_with_body::function_with_body_definition)]
+    fn function_with_body_definition(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Maybe<Arc<FunctionDefinition>>;
+    /// Returns the direct callees of a function with a body. The items in the vector are unique.
+    #[salsa::invoke(items::function_with_body::function_with_body_direct_callees)]
+    fn function_with_body_direct_callees(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Maybe<Vec<FunctionId>>;
+    /// Returns the direct callees of a function with a body that have a body themselves (i.e.
+    /// excluding libfunc callees). The items in the vector are unique.
+    #[salsa::invoke(
+        items::function_with_body::function_with_body_direct_function_with_body_callees
+    )]
+    fn function_with_body_direct_function_with_body_callees(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Maybe<Vec<FunctionWithBodyId>>;
+    /// Private query to compute the definition of a function with a body, specialized with the
+    /// generic arguments of the given concrete function.
+    #[salsa::invoke(items::function_with_body::priv_concrete_function_with_body_definition_data)]
+    fn priv_concrete_function_with_body_definition_data(
+        &self,
+        function_id: FunctionId,
+    ) -> Maybe<items::function_with_body::ConcreteFunctionDefinitionData>;
+    /// Returns the definition of a function with a body, specialized with the generic arguments of
+    /// the given concrete function.
+    #[salsa::invoke(items::function_with_body::concrete_function_with_body_definition)]
+    fn concrete_function_with_body_definition(
+        &self,
+        function_id: FunctionId,
+    ) -> Maybe<Arc<FunctionDefinition>>;
+    /// Returns the direct callees of a concrete function with a body, specialized with its generic
+    /// arguments. The items in the vector are unique.
+    #[salsa::invoke(items::function_with_body::concrete_function_with_body_direct_callees)]
+    fn concrete_function_with_body_direct_callees(
+        &self,
+        function_id: FunctionId,
+    ) -> Maybe<Vec<FunctionId>>;
+
     // Extern function.
     // ================
     /// Private query to compute data about an extern function declaration. An extern function has
@@ -415,6 +519,12 @@ pub trait SemanticGroup:
         &self,
         extern_function_id: ExternFunctionId,
     ) -> Maybe<Vec<GenericParamId>>;
+    /// Returns the attributes of an extern function declaration.
+    #[salsa::invoke(items::extern_function::extern_function_declaration_attributes)]
+    fn extern_function_declaration_attributes(
+        &self,
+        extern_function_id: ExternFunctionId,
+    ) -> Maybe<Vec<Attribute>>;
     /// Returns the explicit implicits of an extern function declaration.
     #[salsa::invoke(items::extern_function::extern_function_declaration_implicits)]
     fn extern_function_declaration_implicits(
@@ -505,16 +615,16 @@ pub trait SemanticGroup:
 
     // Expression.
     // ===========
-    #[salsa::invoke(items::free_function::expr_semantic)]
+    #[salsa::invoke(items::function_with_body::expr_semantic)]
     fn expr_semantic(
         &self,
-        free_function_id: FreeFunctionId,
+        function_id: FunctionWithBodyId,
         id: semantic::ExprId,
     ) -> semantic::Expr;
-    #[salsa::invoke(items::free_function::statement_semantic)]
+    #[salsa::invoke(items::function_with_body::statement_semantic)]
     fn statement_semantic(
         &self,
-        free_function_id: FreeFunctionId,
+        function_id: FunctionWithBodyId,
         id: semantic::StatementId,
     ) -> semantic::Statement;
 
@@ -643,6 +753,9 @@ fn module_semantic_diagnostics(
             ModuleItemId::TypeAlias(type_alias) => {
                 diagnostics.extend(db.type_alias_semantic_diagnostics(*type_alias));
             }
+            ModuleItemId::Constant(constant) => {
+                diagnostics.extend(db.constant_semantic_diagnostics(*constant));
+            }
         }
     }
 
@@ -756,6 +869,7 @@ fn get_resolver_lookbacks(id: LookupItemId, db: &dyn SemanticGroup) -> Vec<Arc<R
             ModuleItemId::Struct(id) => vec![db.struct_resolved_lookback(id)],
             ModuleItemId::Enum(id) => vec![db.enum_resolved_lookback(id)],
             ModuleItemId::TypeAlias(id) => vec![db.type_alias_resolved_lookback(id)],
+            ModuleItemId::Constant(id) => vec![db.constant_resolved_lookback(id)],
             ModuleItemId::Trait(_) => vec![],
             ModuleItemId::Impl(id) => vec![db.impl_resolved_lookback(id)],
             ModuleItemId::ExternType(_) => vec![],
@@ -763,7 +877,10 @@ fn get_resolver_lookbacks(id: LookupItemId, db: &dyn SemanticGroup) -> Vec<Arc<R
                 vec![db.extern_function_declaration_resolved_lookback(id)]
             }
         },
-        LookupItemId::ImplFunction(_) => vec![],
+        LookupItemId::ImplFunction(id) => vec![
+            db.impl_function_resolved_lookback(id),
+            db.impl_function_definition_resolved_lookback(id),
+        ],
     }
     .into_iter()
     .flatten()
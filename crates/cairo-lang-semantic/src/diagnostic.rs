@@ -4,15 +4,16 @@ mod test;
 
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
-    EnumId, GenericFunctionId, ImplFunctionId, ImplId, ModuleFileId, StructId,
+    EnumId, GenericFunctionId, ImplFunctionId, ImplId, ModuleFileId, ModuleId, StructId,
     TopLevelLanguageElementId, TraitFunctionId, TraitId,
 };
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder,
+    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder, Severity,
 };
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::TypedSyntaxNode;
+use itertools::Itertools;
 use smol_str::SmolStr;
 
 use crate::db::SemanticGroup;
@@ -107,6 +108,15 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     trait_id.name(defs_db)
                 )
             }
+            SemanticDiagnosticKind::MissingImplFunctions { impl_id, trait_id, function_names } => {
+                let defs_db = db.upcast();
+                format!(
+                    "Impl `{}` is missing functions of trait `{}`: {}.",
+                    impl_id.name(defs_db),
+                    trait_id.name(defs_db),
+                    function_names.iter().map(|name| format!("`{name}`")).join(", ")
+                )
+            }
             SemanticDiagnosticKind::UnexpectedGenericArgs => "Unexpected generic arguments".into(),
             SemanticDiagnosticKind::UnknownMember => "Unknown member.".into(),
             SemanticDiagnosticKind::MemberSpecifiedMoreThanOnce => {
@@ -118,6 +128,21 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::TypeAliasCycle => {
                 "Cycle detected while resolving 'type alias' items.".into()
             }
+            SemanticDiagnosticKind::ConstantCycle => {
+                "Cycle detected while resolving 'const' items.".into()
+            }
+            SemanticDiagnosticKind::UnsupportedConstant => {
+                "This expression is not supported in constants.".into()
+            }
+            SemanticDiagnosticKind::ConstantOutOfRange { ty } => {
+                format!(
+                    r#"The value of the constant is out of range for type "{}"."#,
+                    ty.format(db)
+                )
+            }
+            SemanticDiagnosticKind::ConstantDivisionByZero => {
+                "Division by zero in a constant.".into()
+            }
             SemanticDiagnosticKind::ExpectedConcreteVariant => {
                 "Expected a concrete variant. Use `::<>` syntax.".to_string()
             }
@@ -150,6 +175,9 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::WrongNumberOfGenericArguments { expected, actual } => {
                 format!("Wrong number of generic arguments. Expected {expected}, found: {actual}")
             }
+            SemanticDiagnosticKind::TypeAnnotationsNeeded => {
+                "Type annotations needed. Failed to infer the generic arguments.".into()
+            }
             SemanticDiagnosticKind::WrongParameterType {
                 impl_id,
                 impl_function_id,
@@ -226,9 +254,6 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     actual_ty.format(db)
                 )
             }
-            SemanticDiagnosticKind::ImplBodyIsNotSupported => {
-                "impl body is not supported yet.".into()
-            }
             SemanticDiagnosticKind::WrongReturnType { expected_ty, actual_ty } => {
                 format!(
                     r#"Unexpected return type. Expected: "{}", found: "{}"."#,
@@ -289,15 +314,89 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     block_else_ty.format(db),
                 )
             }
+            SemanticDiagnosticKind::IncompatibleLoopBreakTypes { current_ty, break_ty } => {
+                format!(
+                    r#"Loop has incompatible break types: "{}" and "{}""#,
+                    current_ty.format(db),
+                    break_ty.format(db),
+                )
+            }
+            SemanticDiagnosticKind::BreakOnlyAllowedInsideALoop => {
+                "`break` only allowed inside a `loop` or `while`.".into()
+            }
+            SemanticDiagnosticKind::BreakWithValueOnlyAllowedInsideALoop => {
+                "Can only break with a value inside a `loop`.".into()
+            }
+            SemanticDiagnosticKind::ContinueOnlyAllowedInsideALoop => {
+                "`continue` only allowed inside a `loop` or `while`.".into()
+            }
+            SemanticDiagnosticKind::ReturnNotAllowedInsideAClosure => {
+                "`return` is not allowed inside a closure.".into()
+            }
+            SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideAClosure => {
+                "`?` is not allowed inside a closure.".into()
+            }
+            SemanticDiagnosticKind::RefClosureParam => "Closure parameters cannot be `ref`.".into(),
+            SemanticDiagnosticKind::CapturedVarModifiedInsideAClosure => {
+                "Variables captured by a closure cannot be modified inside it.".into()
+            }
             SemanticDiagnosticKind::TypeHasNoMembers { ty, member_name: _ } => {
                 format!(r#"Type "{}" has no members."#, ty.format(db))
             }
+            SemanticDiagnosticKind::NoSuchTupleItem { ty, index } => {
+                format!(r#"Tuple "{}" has no item {index}."#, ty.format(db))
+            }
             SemanticDiagnosticKind::NoSuchMember { struct_id, member_name } => {
                 format!(
                     r#"Struct "{}" has no member "{member_name}""#,
                     struct_id.full_path(db.upcast())
                 )
             }
+            SemanticDiagnosticKind::UnsupportedIndexOperator { ty } => {
+                format!(r#"Type "{}" does not support the index operator."#, ty.format(db))
+            }
+            SemanticDiagnosticKind::AmbiguousIndexOperator { ty, impl_ids } => {
+                format!(
+                    r#"Ambiguous index operator on type "{}". Candidates are: {}."#,
+                    ty.format(db),
+                    impl_ids
+                        .iter()
+                        .map(|impl_id| format!(r#""{}""#, impl_id.full_path(db.upcast())))
+                        .join(", ")
+                )
+            }
+            SemanticDiagnosticKind::NoSuchMethod { ty, method_name } => {
+                format!(r#"Method "{method_name}" not found on type "{}"."#, ty.format(db))
+            }
+            SemanticDiagnosticKind::AmbiguousMethod { ty, method_name, impl_ids } => {
+                format!(
+                    r#"Ambiguous method "{method_name}" on type "{}". Candidates are: {}."#,
+                    ty.format(db),
+                    impl_ids
+                        .iter()
+                        .map(|impl_id| format!(r#""{}""#, impl_id.full_path(db.upcast())))
+                        .join(", ")
+                )
+            }
+            SemanticDiagnosticKind::NoImplementationOfTrait { concrete_trait_id } => {
+                format!(
+                    r#"No implementation of trait "{}" was found."#,
+                    concrete_trait_id.format(db)
+                )
+            }
+            SemanticDiagnosticKind::MultipleImplementationOfTrait {
+                concrete_trait_id,
+                impl_ids,
+            } => {
+                format!(
+                    r#"Multiple implementations of trait "{}" were found: {}."#,
+                    concrete_trait_id.format(db),
+                    impl_ids
+                        .iter()
+                        .map(|impl_id| format!(r#""{}""#, impl_id.full_path(db.upcast())))
+                        .join(", ")
+                )
+            }
             SemanticDiagnosticKind::NoSuchVariant { enum_id, variant_name } => {
                 format!(
                     r#"Enum "{}" has no variant "{variant_name}""#,
@@ -336,10 +435,29 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::SuperUsedInRootModule => {
                 "'super' cannot be used for the crate's root module.".into()
             }
+            SemanticDiagnosticKind::ItemNotVisible { module_id, item_name } => {
+                format!(
+                    r#"Item "{}::{item_name}" is not visible in this context."#,
+                    module_id.full_path(db.upcast())
+                )
+            }
+            SemanticDiagnosticKind::MemberNotVisible { struct_id, member_name } => {
+                format!(
+                    r#"Member "{member_name}" of struct "{}" is not visible in this context."#,
+                    struct_id.full_path(db.upcast())
+                )
+            }
             SemanticDiagnosticKind::UnexpectedLiteralPattern { ty } => format!(
-                r#"Unexpected type for literal pattern. Expected: felt. Got: "{}""#,
+                r#"Unexpected type for literal pattern. "{}" is not felt or an integer type."#,
                 ty.format(db),
             ),
+            SemanticDiagnosticKind::WrongLiteralPatternType { expected_ty, actual_ty } => {
+                format!(
+                    r#"Unexpected literal type in pattern. Expected: "{}", found: "{}"."#,
+                    expected_ty.format(db),
+                    actual_ty.format(db)
+                )
+            }
             SemanticDiagnosticKind::UnexpectedEnumPattern { ty } => {
                 format!(r#"Unexpected type for enum pattern. "{}" is not an enum."#, ty.format(db),)
             }
@@ -352,6 +470,23 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::UnexpectedTuplePattern { ty } => {
                 format!(r#"Unexpected type for tuple pattern. "{}" is not a tuple."#, ty.format(db),)
             }
+            SemanticDiagnosticKind::MissingMatchArms { missing } => {
+                format!("Non-exhaustive match. Patterns not covered: {}.", missing.join(", "))
+            }
+            SemanticDiagnosticKind::UnreachableMatchArm => "Unreachable match arm.".into(),
+            SemanticDiagnosticKind::RefutableLetPattern { missing } => {
+                format!(
+                    "Refutable pattern in a let statement. Patterns not covered: {}. Consider \
+                     adding an else block.",
+                    missing.join(", ")
+                )
+            }
+            SemanticDiagnosticKind::NonDivergingLetElse { ty } => {
+                format!(
+                    r#"The else block of a let statement must diverge. Found type: "{}"."#,
+                    ty.format(db)
+                )
+            }
             SemanticDiagnosticKind::WrongEnum { expected_enum, actual_enum } => {
                 format!(
                     r#"Wrong enum in pattern. Expected: "{}". Got: "{}"."#,
@@ -372,6 +507,10 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::ShortStringMustBeAscii => {
                 "Short strings can only include ASCII characters.".into()
             }
+            SemanticDiagnosticKind::StringMustBeAscii => {
+                "Strings can only include ASCII characters.".into()
+            }
+            SemanticDiagnosticKind::UnterminatedString => "Unterminated string literal.".into(),
             SemanticDiagnosticKind::IllegalStringEscaping(err) => {
                 format!("Invalid string escaping:\n{err}")
             }
@@ -398,6 +537,15 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::PanicableExternFunction => {
                 "An extern function must be marked as nopanic.".into()
             }
+            SemanticDiagnosticKind::NonCopyableArraySliceElement { element_ty } => {
+                format!(
+                    r#"Array slices are only supported for copyable element types. Found: "{}"."#,
+                    element_ty.format(db)
+                )
+            }
+            SemanticDiagnosticKind::LegacyConversionFunction { function_name, replacement } => {
+                format!("`{function_name}` is deprecated. Use `{replacement}()` instead.")
+            }
             SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => {
                 format!("Plugin diagnostic: {}", diagnostic.message)
             }
@@ -417,6 +565,13 @@ impl DiagnosticEntry for SemanticDiagnostic {
             _ => location,
         }
     }
+
+    fn severity(&self) -> Severity {
+        match &self.kind {
+            SemanticDiagnosticKind::LegacyConversionFunction { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -453,11 +608,22 @@ pub enum SemanticDiagnosticKind {
         impl_function_id: ImplFunctionId,
         trait_id: TraitId,
     },
+    MissingImplFunctions {
+        impl_id: ImplId,
+        trait_id: TraitId,
+        function_names: Vec<SmolStr>,
+    },
     UnexpectedGenericArgs,
     UnknownMember,
     MemberSpecifiedMoreThanOnce,
     UseCycle,
     TypeAliasCycle,
+    ConstantCycle,
+    UnsupportedConstant,
+    ConstantOutOfRange {
+        ty: semantic::TypeId,
+    },
+    ConstantDivisionByZero,
     ExpectedConcreteVariant,
     MissingMember {
         member_name: SmolStr,
@@ -477,6 +643,7 @@ pub enum SemanticDiagnosticKind {
         expected: usize,
         actual: usize,
     },
+    TypeAnnotationsNeeded,
     WrongParameterType {
         impl_id: ImplId,
         impl_function_id: ImplFunctionId,
@@ -510,7 +677,6 @@ pub enum SemanticDiagnosticKind {
         expected_ty: semantic::TypeId,
         actual_ty: semantic::TypeId,
     },
-    ImplBodyIsNotSupported,
     WrongReturnTypeForImpl {
         impl_id: ImplId,
         impl_function_id: ImplFunctionId,
@@ -541,14 +707,52 @@ pub enum SemanticDiagnosticKind {
         block_if_ty: semantic::TypeId,
         block_else_ty: semantic::TypeId,
     },
+    IncompatibleLoopBreakTypes {
+        current_ty: semantic::TypeId,
+        break_ty: semantic::TypeId,
+    },
+    BreakOnlyAllowedInsideALoop,
+    BreakWithValueOnlyAllowedInsideALoop,
+    ContinueOnlyAllowedInsideALoop,
+    ReturnNotAllowedInsideAClosure,
+    ErrorPropagateNotAllowedInsideAClosure,
+    RefClosureParam,
+    CapturedVarModifiedInsideAClosure,
     TypeHasNoMembers {
         ty: semantic::TypeId,
         member_name: SmolStr,
     },
+    NoSuchTupleItem {
+        ty: semantic::TypeId,
+        index: usize,
+    },
     NoSuchMember {
         struct_id: StructId,
         member_name: SmolStr,
     },
+    UnsupportedIndexOperator {
+        ty: semantic::TypeId,
+    },
+    AmbiguousIndexOperator {
+        ty: semantic::TypeId,
+        impl_ids: Vec<ImplId>,
+    },
+    NoSuchMethod {
+        ty: semantic::TypeId,
+        method_name: SmolStr,
+    },
+    AmbiguousMethod {
+        ty: semantic::TypeId,
+        method_name: SmolStr,
+        impl_ids: Vec<ImplId>,
+    },
+    NoImplementationOfTrait {
+        concrete_trait_id: semantic::ConcreteTraitId,
+    },
+    MultipleImplementationOfTrait {
+        concrete_trait_id: semantic::ConcreteTraitId,
+        impl_ids: Vec<ImplId>,
+    },
     NoSuchVariant {
         enum_id: EnumId,
         variant_name: SmolStr,
@@ -568,6 +772,14 @@ pub enum SemanticDiagnosticKind {
     InvalidPath,
     PathNotFound(NotFoundItemType),
     SuperUsedInRootModule,
+    ItemNotVisible {
+        module_id: ModuleId,
+        item_name: SmolStr,
+    },
+    MemberNotVisible {
+        struct_id: StructId,
+        member_name: SmolStr,
+    },
     RedundantModifier {
         current_modifier: SmolStr,
         previous_modifier: SmolStr,
@@ -576,6 +788,20 @@ pub enum SemanticDiagnosticKind {
     UnexpectedLiteralPattern {
         ty: semantic::TypeId,
     },
+    WrongLiteralPatternType {
+        expected_ty: semantic::TypeId,
+        actual_ty: semantic::TypeId,
+    },
+    MissingMatchArms {
+        missing: Vec<String>,
+    },
+    UnreachableMatchArm,
+    RefutableLetPattern {
+        missing: Vec<String>,
+    },
+    NonDivergingLetElse {
+        ty: semantic::TypeId,
+    },
     UnexpectedEnumPattern {
         ty: semantic::TypeId,
     },
@@ -590,6 +816,8 @@ pub enum SemanticDiagnosticKind {
         actual_enum: EnumId,
     },
     ShortStringMustBeAscii,
+    StringMustBeAscii,
+    UnterminatedString,
     IllegalStringEscaping(String),
     InvalidCopyTraitImpl,
     InvalidDropTraitImpl,
@@ -602,6 +830,13 @@ pub enum SemanticDiagnosticKind {
     },
     PanicableFromNonPanicable,
     PanicableExternFunction,
+    NonCopyableArraySliceElement {
+        element_ty: semantic::TypeId,
+    },
+    LegacyConversionFunction {
+        function_name: SmolStr,
+        replacement: SmolStr,
+    },
     PluginDiagnostic(PluginDiagnostic),
     WrappedPluginDiagnostic {
         diagnostic: PluginMappedDiagnostic,
@@ -164,7 +164,9 @@ impl DiagnosticMapper for PatchMapper {
         db: &(dyn SemanticGroup + 'static),
         diag: &dyn std::any::Any,
     ) -> Option<PluginMappedDiagnostic> {
-        let Some(diag) = diag.downcast_ref::<SemanticDiagnostic>() else {return None;};
+        let Some(diag) = diag.downcast_ref::<SemanticDiagnostic>() else {
+            return None;
+        };
         let span = self
             .patches
             .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
@@ -220,8 +222,8 @@ fn test_inline_inline_module_diagnostics() {
                 }
             }
             mod b {
-                mod c {
-                    fn bad_c() -> u128 {
+                pub mod c {
+                    pub fn bad_c() -> u128 {
                         return 2;
                     }
                 }
@@ -5,42 +5,63 @@
 use std::collections::HashMap;
 
 use ast::{BinaryOperator, PathSegment};
-use cairo_lang_defs::ids::{GenericFunctionId, LocalVarLongId, MemberId};
+use cairo_lang_defs::ids::{
+    GenericFunctionId, ImplFunctionId, ImplId, LanguageElementId, LocalVarLongId, MemberId,
+    ModuleId, ModuleItemId, StructId, TopLevelLanguageElementId, TraitFunctionId, TraitId,
+};
 use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToMaybe, ToOption};
 use cairo_lang_syntax::node::ast::{BlockOrIf, PatternStructParam};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{GetIdentifier, PathSegmentEx};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
-use cairo_lang_utils::{try_extract_matches, OptionHelper};
+use cairo_lang_utils::{extract_matches, try_extract_matches, OptionHelper};
 use id_arena::Arena;
-use itertools::zip_eq;
+use itertools::{chain, zip_eq, Itertools};
 use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
 use smol_str::SmolStr;
 use unescaper::unescape;
 
+use super::inference::Inference;
+use super::match_check::{check_let_pattern, check_match_arms};
 use super::objects::*;
 use super::pattern::{
     Pattern, PatternEnumVariant, PatternLiteral, PatternOtherwise, PatternTuple, PatternVariable,
 };
 use crate::corelib::{
-    core_binary_operator, core_felt_ty, core_unary_operator, false_literal_expr, never_ty,
-    true_literal_expr, try_get_const_libfunc_name_by_type, try_get_core_ty_by_name, unit_ty,
+    closure_call_function, core_binary_operator, core_bool_ty, core_felt_ty, core_module,
+    core_unary_operator, false_literal_expr, false_variant, fn_trait, get_core_function_id,
+    get_core_generic_function_id, get_core_ty_by_name, index_trait, never_ty, true_literal_expr,
+    true_variant, try_get_const_libfunc_name_by_type, try_get_core_ty_by_name, unit_ty,
     unwrap_error_propagation_type,
 };
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
 use crate::items::enm::SemanticEnumEx;
+use crate::items::functions::update_env_with_ast_params;
+use crate::items::imp::{
+    concrete_trait_lookup_context, find_impls_at_context, find_trait_impls_at_context,
+    find_unique_impl, impl_function_by_name, ConcreteImplLongId, ImplLookupContext,
+};
 use crate::items::modifiers::compute_mutability;
 use crate::items::strct::SemanticStructEx;
 use crate::literals::LiteralLongId;
 use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, Resolver};
 use crate::semantic::{self, FunctionId, LocalVariable, TypeId, TypeLongId, Variable};
-use crate::types::{resolve_type, ConcreteTypeId};
-use crate::{Mutability, Parameter, PatternStruct, Signature};
+use crate::types::{generic_args_fully_concrete, infer_generic_args, resolve_type, ConcreteTypeId};
+use crate::{
+    ClosureTypeLongId, ConcreteFunction, ConcreteTraitLongId, FunctionLongId, GenericArgumentId,
+    Mutability, Parameter, PatternStruct, Signature,
+};
+
+/// The number of bytes in each of the full words of a `ByteArray`.
+const BYTES_IN_WORD: usize = 31;
 
 /// Context for computing the semantic model of expression trees.
 pub struct ComputationContext<'ctx> {
@@ -53,6 +74,12 @@ pub struct ComputationContext<'ctx> {
     pub statements: Arena<semantic::Statement>,
     /// Definitions of semantic variables.
     pub semantic_defs: UnorderedHashMap<semantic::VarId, semantic::Variable>,
+    /// The context of the innermost loop the computed code is in, if any.
+    loop_ctx: Option<LoopContext>,
+    /// The function whose body is computed, with its own generic parameters as generic arguments.
+    function_id: FunctionId,
+    /// The contexts of the closures the computed code is in, from the outermost to the innermost.
+    closure_ctxs: Vec<ClosureContext>,
 }
 impl<'ctx> ComputationContext<'ctx> {
     pub fn new(
@@ -61,6 +88,7 @@ impl<'ctx> ComputationContext<'ctx> {
         resolver: Resolver<'ctx>,
         signature: &'ctx Signature,
         environment: Environment,
+        function_id: FunctionId,
     ) -> Self {
         let semantic_defs = environment
             .variables
@@ -78,6 +106,9 @@ impl<'ctx> ComputationContext<'ctx> {
             exprs: Arena::default(),
             statements: Arena::default(),
             semantic_defs,
+            loop_ctx: None,
+            function_id,
+            closure_ctxs: vec![],
         }
     }
 
@@ -101,6 +132,66 @@ impl<'ctx> ComputationContext<'ctx> {
         self.environment = parent.unwrap();
         res
     }
+
+    /// Runs a function with a modified context, inside the given loop context.
+    /// Returns the result of the function and the loop context after the run.
+    fn run_in_loop_ctx<T, F>(&mut self, loop_ctx: LoopContext, f: F) -> (T, LoopContext)
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let old_loop_ctx = self.loop_ctx.replace(loop_ctx);
+        let res = f(self);
+        let loop_ctx = std::mem::replace(&mut self.loop_ctx, old_loop_ctx).unwrap();
+        (res, loop_ctx)
+    }
+
+    /// Runs a function with a modified context, inside the given closure context, and outside of
+    /// any loop.
+    /// Returns the result of the function and the closure context after the run.
+    fn run_in_closure_ctx<T, F>(&mut self, closure_ctx: ClosureContext, f: F) -> (T, ClosureContext)
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let old_loop_ctx = self.loop_ctx.take();
+        self.closure_ctxs.push(closure_ctx);
+        let res = f(self);
+        let closure_ctx = self.closure_ctxs.pop().unwrap();
+        self.loop_ctx = old_loop_ctx;
+        (res, closure_ctx)
+    }
+
+    /// Marks a used variable as captured by all the closures it is defined outside of.
+    fn mark_used_var(&mut self, var: semantic::VarId) {
+        for closure_ctx in self.closure_ctxs.iter_mut() {
+            if closure_ctx.outer_vars.contains_key(&var) {
+                closure_ctx.captured.insert(var);
+            }
+        }
+    }
+
+    /// Returns true if the variable is captured by the innermost closure the computed code is in.
+    fn is_captured_var(&self, var: semantic::VarId) -> bool {
+        self.closure_ctxs
+            .last()
+            .map_or(false, |closure_ctx| closure_ctx.outer_vars.contains_key(&var))
+    }
+}
+
+/// Information about a closure that is currently being computed.
+struct ClosureContext {
+    /// The definitions of the variables defined before the closure expression.
+    outer_vars: UnorderedHashMap<semantic::VarId, semantic::Variable>,
+    /// The variables defined before the closure expression that are used in its body, in the order
+    /// of their first use.
+    captured: OrderedHashSet<semantic::VarId>,
+}
+
+/// Information about the loop that is currently being computed.
+enum LoopContext {
+    /// A `loop` expression. Holds the helper merging the types of its `break` statements.
+    Loop(FlowMergeTypeHelper),
+    /// A `while` expression, which may only `break` without a value.
+    While,
 }
 
 // TODO(ilya): Change value to VarId.
@@ -116,6 +207,19 @@ pub struct Environment {
     unnamed_variables: Vec<Variable>,
 }
 impl Environment {
+    /// Returns the variable with the given name, defined in this environment or in one of its
+    /// ancestors.
+    fn get_variable(&self, name: &str) -> Option<&Variable> {
+        let mut maybe_env = Some(self);
+        while let Some(env) = maybe_env {
+            if let Some(var) = env.variables.get(name) {
+                return Some(var);
+            }
+            maybe_env = env.parent.as_deref();
+        }
+        None
+    }
+
     /// Adds a parameter to the environment.
     pub fn add_param(
         &mut self,
@@ -168,11 +272,12 @@ pub fn maybe_compute_expr_semantic(
     match syntax {
         ast::Expr::Path(path) => resolve_variable(ctx, path),
         ast::Expr::Literal(literal_syntax) => {
-            Ok(Expr::Literal(literal_to_semantic(ctx, literal_syntax)?))
+            Ok(Expr::Literal(literal_to_semantic(ctx.db, ctx.diagnostics, literal_syntax)?))
         }
         ast::Expr::ShortString(literal_syntax) => {
-            Ok(Expr::Literal(short_string_to_semantic(ctx, literal_syntax)?))
+            Ok(Expr::Literal(short_string_to_semantic(ctx.db, ctx.diagnostics, literal_syntax)?))
         }
+        ast::Expr::String(string_syntax) => compute_expr_string_semantic(ctx, string_syntax),
         ast::Expr::False(syntax) => Ok(false_literal_expr(ctx, syntax.stable_ptr().into())),
         ast::Expr::True(syntax) => Ok(true_literal_expr(ctx, syntax.stable_ptr().into())),
         ast::Expr::Parenthesized(paren_syntax) => {
@@ -188,7 +293,12 @@ pub fn maybe_compute_expr_semantic(
         ast::Expr::Block(block_syntax) => compute_expr_block_semantic(ctx, block_syntax),
         ast::Expr::Match(expr_match) => compute_expr_match_semantic(ctx, expr_match),
         ast::Expr::If(expr_if) => compute_expr_if_semantic(ctx, expr_if),
+        ast::Expr::IfLet(expr_if_let) => compute_expr_if_let_semantic(ctx, expr_if_let),
+        ast::Expr::Loop(expr_loop) => compute_expr_loop_semantic(ctx, expr_loop),
+        ast::Expr::While(expr_while) => compute_expr_while_semantic(ctx, expr_while),
+        ast::Expr::Closure(expr_closure) => compute_expr_closure_semantic(ctx, expr_closure),
         ast::Expr::ErrorPropagate(expr) => compute_expr_error_propagate_semantic(ctx, expr),
+        ast::Expr::Indexed(expr) => compute_expr_indexed_semantic(ctx, expr),
         ast::Expr::Missing(_) => Err(ctx.diagnostics.report(syntax, Unsupported)),
     }
 }
@@ -200,13 +310,15 @@ fn compute_expr_unary_semantic(
 
     let unary_op = syntax.op(syntax_db);
     let expr = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
+    let expr_ty = ctx.resolver.inference.reduce_ty(expr.ty());
 
-    let function = match core_unary_operator(ctx.db, &unary_op, expr.ty()) {
-        Err(err_kind) => {
-            return Err(ctx.diagnostics.report(&unary_op, err_kind));
-        }
-        Ok(function) => function,
-    };
+    let function =
+        match core_unary_operator(ctx.db, ctx.resolver.module_file_id.0, &unary_op, expr_ty)? {
+            Err(err_kind) => {
+                return Err(ctx.diagnostics.report(&unary_op, err_kind));
+            }
+            Ok(function) => function,
+        };
     expr_function_call(ctx, function, vec![expr], syntax.stable_ptr().into())
 }
 
@@ -223,37 +335,91 @@ fn compute_expr_binary_semantic(
     let lexpr = compute_expr_semantic(ctx, lhs_syntax);
     let rhs_syntax = syntax.rhs(syntax_db);
     if matches!(binary_op, BinaryOperator::Dot(_)) {
+        if let ast::Expr::FunctionCall(call_syntax) = rhs_syntax {
+            return method_call_expr(ctx, lexpr, call_syntax, stable_ptr);
+        }
         return member_access_expr(ctx, lexpr, rhs_syntax, stable_ptr);
     }
     let rexpr = compute_expr_semantic(ctx, &rhs_syntax);
     if matches!(binary_op, BinaryOperator::Eq(_)) {
-        return match lexpr {
-            Expr::Var(ExprVar { var, .. }) => {
-                // The semantic_var must be valid as 'lexpr' is a result of compute_expr_semantic.
-                let semantic_var = ctx.semantic_defs.get(&var).unwrap();
-
-                if !semantic_var.is_mut() {
-                    ctx.diagnostics.report(syntax, AssignmentToImmutableVar);
-                }
-                Ok(Expr::Assignment(ExprAssignment {
-                    var,
-                    rhs: ctx.exprs.alloc(rexpr),
-                    ty: unit_ty(db),
-                    stable_ptr,
-                }))
-            }
-            _ => Err(ctx.diagnostics.report(lhs_syntax, InvalidLhsForAssignment)),
-        };
+        return compute_assignment_semantic(ctx, syntax, lhs_syntax, &lexpr, rexpr);
     }
-    let function = match core_binary_operator(db, &binary_op, lexpr.ty(), rexpr.ty())? {
+    let module_id = ctx.resolver.module_file_id.0;
+    let lexpr_ty = ctx.resolver.inference.reduce_ty(lexpr.ty());
+    let rexpr_ty = ctx.resolver.inference.reduce_ty(rexpr.ty());
+    let function = match core_binary_operator(db, module_id, &binary_op, lexpr_ty, rexpr_ty)? {
         Err(err_kind) => {
             return Err(ctx.diagnostics.report(&binary_op, err_kind));
         }
         Ok(function) => function,
     };
+    if matches!(
+        binary_op,
+        BinaryOperator::PlusEq(_)
+            | BinaryOperator::MinusEq(_)
+            | BinaryOperator::MulEq(_)
+            | BinaryOperator::DivEq(_)
+            | BinaryOperator::ModEq(_)
+            | BinaryOperator::AndEq(_)
+            | BinaryOperator::OrEq(_)
+            | BinaryOperator::XorEq(_)
+    ) {
+        // `a op= b` is computed as `a = a op b`.
+        let rhs = expr_function_call(ctx, function, vec![lexpr.clone(), rexpr], stable_ptr)?;
+        return compute_assignment_semantic(ctx, syntax, lhs_syntax, &lexpr, rhs);
+    }
     expr_function_call(ctx, function, vec![lexpr, rexpr], stable_ptr)
 }
 
+/// Computes the semantic model of an assignment of `rhs` to `lexpr`, which must be a mutable
+/// variable or a (possibly nested) struct member or tuple item of one.
+fn compute_assignment_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprBinary,
+    lhs_syntax: &ast::Expr,
+    lexpr: &Expr,
+    rhs: Expr,
+) -> Maybe<Expr> {
+    let mut members = vec![];
+    let mut current = lexpr;
+    let var = loop {
+        match current {
+            Expr::Var(ExprVar { var, .. }) => break *var,
+            Expr::MemberAccess(member_access) => {
+                members.push(AssignedMember::Struct {
+                    concrete_struct_id: member_access.concrete_struct_id,
+                    member: member_access.member,
+                });
+                current = &ctx.exprs[member_access.expr];
+            }
+            Expr::TupleItem(tuple_item) => {
+                members.push(AssignedMember::Tuple { index: tuple_item.index });
+                current = &ctx.exprs[tuple_item.expr];
+            }
+            // A diagnostic was already reported for the invalid expression.
+            Expr::Missing(ExprMissing { diag_added, .. }) => return Err(*diag_added),
+            _ => return Err(ctx.diagnostics.report(lhs_syntax, InvalidLhsForAssignment)),
+        }
+    };
+    members.reverse();
+
+    // The semantic_var must be valid as 'lexpr' is a result of compute_expr_semantic.
+    let semantic_var = ctx.semantic_defs.get(&var).unwrap();
+    if !semantic_var.is_mut() {
+        ctx.diagnostics.report(syntax, AssignmentToImmutableVar);
+    }
+    if ctx.is_captured_var(var) {
+        ctx.diagnostics.report(syntax, CapturedVarModifiedInsideAClosure);
+    }
+    Ok(Expr::Assignment(ExprAssignment {
+        var,
+        members,
+        rhs: ctx.exprs.alloc(rhs),
+        ty: unit_ty(ctx.db),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
 fn compute_expr_tuple_semantic(
     ctx: &mut ComputationContext<'_>,
     syntax: &ast::ExprTuple,
@@ -283,17 +449,37 @@ fn compute_expr_function_call_semantic(
     let syntax_db = db.upcast();
 
     let path = syntax.path(syntax_db);
+    let args_syntax = syntax.arguments(syntax_db);
+    let compute_arg_exprs = |ctx: &mut ComputationContext<'_>| -> Vec<Expr> {
+        args_syntax
+            .expressions(syntax_db)
+            .elements(syntax_db)
+            .into_iter()
+            .map(|arg_syntax| compute_expr_semantic(ctx, &arg_syntax))
+            .collect()
+    };
+    // A call of a variable calls the closure it holds.
+    if let [PathSegment::Simple(segment)] = &path.elements(syntax_db)[..] {
+        let identifier = segment.ident(syntax_db);
+        if ctx.environment.get_variable(&identifier.text(syntax_db)).is_some() {
+            let callee = resolve_variable_by_name(ctx, &identifier, &path)?;
+            let arg_exprs = compute_arg_exprs(ctx);
+            return expr_closure_call(
+                ctx,
+                callee,
+                arg_exprs,
+                &path,
+                &args_syntax,
+                syntax.stable_ptr().into(),
+            );
+        }
+    }
     let item =
         ctx.resolver.resolve_concrete_path(ctx.diagnostics, &path, NotFoundItemType::Function)?;
-    let args_syntax = syntax.arguments(syntax_db);
-    let arg_exprs: Vec<_> = args_syntax
-        .expressions(syntax_db)
-        .elements(syntax_db)
-        .into_iter()
-        .map(|arg_syntax| compute_expr_semantic(ctx, &arg_syntax))
-        .collect();
+    let arg_exprs = compute_arg_exprs(ctx);
     match item {
         ResolvedConcreteItem::Function(function) => {
+            check_legacy_conversion_function(ctx, function, &path);
             expr_function_call(ctx, function, arg_exprs, syntax.stable_ptr().into())
         }
         ResolvedConcreteItem::Variant(concrete_variant) => {
@@ -304,10 +490,14 @@ fn compute_expr_function_call_semantic(
                 ));
             }
             let arg = arg_exprs[0].clone();
-            if concrete_variant.ty != arg.ty() {
+            if ctx.resolver.inference.conform_ty(arg.ty(), concrete_variant.ty).is_err() {
+                let inference = &ctx.resolver.inference;
                 return Err(ctx.diagnostics.report(
                     &args_syntax,
-                    WrongArgumentType { expected_ty: concrete_variant.ty, actual_ty: arg.ty() },
+                    WrongArgumentType {
+                        expected_ty: inference.reduce_ty(concrete_variant.ty),
+                        actual_ty: inference.reduce_ty(arg.ty()),
+                    },
                 ));
             }
             let concrete_enum_id = concrete_variant.concrete_enum_id;
@@ -352,10 +542,11 @@ pub fn compute_expr_block_semantic(
         let ty = if let Some(t) = &tail_semantic_expr {
             t.ty()
         } else if let Some(statement) = statements_semantic.last() {
-            if let Statement::Return(_) = &new_ctx.statements[*statement] {
-                never_ty(new_ctx.db)
-            } else {
-                unit_ty(db)
+            match &new_ctx.statements[*statement] {
+                Statement::Return(_) | Statement::Break(_) | Statement::Continue(_) => {
+                    never_ty(new_ctx.db)
+                }
+                _ => unit_ty(db),
             }
         } else {
             unit_ty(db)
@@ -385,12 +576,17 @@ impl FlowMergeTypeHelper {
     }
 
     /// Attempt merge a branch into the helper, on error will return the conflicting types.
-    fn try_merge_types(&mut self, ty: TypeId) -> Result<(), (TypeId, TypeId)> {
+    fn try_merge_types(
+        &mut self,
+        inference: &mut Inference<'_>,
+        ty: TypeId,
+    ) -> Result<(), (TypeId, TypeId)> {
         if ty != self.never_type && ty != self.missing_type {
-            if let Some(existing) = &self.final_type {
-                if ty != *existing {
-                    return Err((*existing, ty));
-                }
+            if let Some(existing) = self.final_type {
+                let merged_ty = inference
+                    .conform_ty(existing, ty)
+                    .map_err(|_| (inference.reduce_ty(existing), inference.reduce_ty(ty)))?;
+                self.final_type = Some(merged_ty);
             } else {
                 self.final_type = Some(ty);
             }
@@ -408,7 +604,6 @@ fn compute_expr_match_semantic(
     ctx: &mut ComputationContext<'_>,
     syntax: &ast::ExprMatch,
 ) -> Maybe<Expr> {
-    // TODO(yuval): verify exhaustiveness.
     let db = ctx.db;
     let syntax_db = db.upcast();
 
@@ -443,7 +638,9 @@ fn compute_expr_match_semantic(
     // Unify arm types.
     let mut helper = FlowMergeTypeHelper::new(ctx.db);
     for (_, expr) in pattern_and_expr_options.iter().flatten() {
-        if let Err((match_ty, arm_ty)) = helper.try_merge_types(expr.ty()) {
+        if let Err((match_ty, arm_ty)) =
+            helper.try_merge_types(&mut ctx.resolver.inference, expr.ty())
+        {
             ctx.diagnostics.report_by_ptr(
                 expr.stable_ptr().untyped(),
                 IncompatibleMatchArms { match_ty, arm_ty },
@@ -452,6 +649,14 @@ fn compute_expr_match_semantic(
     }
     // Compute semantic representation of the match arms.
     let pattern_and_exprs: Vec<_> = pattern_and_expr_options.into_iter().collect::<Maybe<_>>()?;
+    let patterns = pattern_and_exprs.iter().map(|(pattern, _)| pattern).collect_vec();
+    check_match_arms(
+        ctx.db,
+        ctx.diagnostics,
+        syntax,
+        ctx.resolver.inference.reduce_ty(expr.ty()),
+        &patterns,
+    );
     let semantic_arms = pattern_and_exprs
         .into_iter()
         .map(|(pattern, arm_expr)| MatchArm { pattern, expression: ctx.exprs.alloc(arm_expr) })
@@ -470,35 +675,193 @@ fn compute_expr_if_semantic(ctx: &mut ComputationContext<'_>, syntax: &ast::Expr
 
     let expr = compute_expr_semantic(ctx, &syntax.condition(syntax_db));
     let if_block = compute_expr_block_semantic(ctx, &syntax.if_block(syntax_db))?;
+    let (else_block_opt, else_block_ty) =
+        compute_else_clause_semantic(ctx, syntax.else_clause(syntax_db))?;
 
-    let (else_block_opt, else_block_ty) = match syntax.else_clause(syntax_db) {
-        ast::OptionElseClause::Empty(_) => (None, unit_ty(ctx.db)),
+    let ty = merge_if_block_types(ctx, syntax, if_block.ty(), else_block_ty);
+    Ok(Expr::If(ExprIf {
+        condition: ctx.exprs.alloc(expr),
+        if_block: ctx.exprs.alloc(if_block),
+        else_block: else_block_opt.map(|else_block| ctx.exprs.alloc(else_block)),
+        ty,
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprIfLet].
+fn compute_expr_if_let_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprIfLet,
+) -> Maybe<Expr> {
+    let syntax_db = ctx.db.upcast();
+
+    let expr = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
+    let (pattern, if_block) = ctx.run_in_subscope(|new_ctx| {
+        // Typecheck pattern, and introduce the new variables to the subscope of the if block.
+        let pattern = compute_pattern_semantic(new_ctx, syntax.pattern(syntax_db), expr.ty())?;
+        for v in pattern.variables() {
+            new_ctx.environment.variables.insert(v.name.clone(), Variable::Local(v.var.clone()));
+        }
+        let if_block = compute_expr_block_semantic(new_ctx, &syntax.if_block(syntax_db))?;
+        Ok((pattern, if_block))
+    })?;
+    let (else_block_opt, else_block_ty) =
+        compute_else_clause_semantic(ctx, syntax.else_clause(syntax_db))?;
+
+    let ty = merge_if_block_types(ctx, syntax, if_block.ty(), else_block_ty);
+    Ok(Expr::IfLet(ExprIfLet {
+        pattern,
+        matched_expr: ctx.exprs.alloc(expr),
+        if_block: ctx.exprs.alloc(if_block),
+        else_block: else_block_opt.map(|else_block| ctx.exprs.alloc(else_block)),
+        ty,
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of the optional else clause of an if expression. Returns the else
+/// expression, if any, and the type of the else branch.
+fn compute_else_clause_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: ast::OptionElseClause,
+) -> Maybe<(Option<Expr>, TypeId)> {
+    let syntax_db = ctx.db.upcast();
+    let else_expr = match syntax {
+        ast::OptionElseClause::Empty(_) => return Ok((None, unit_ty(ctx.db))),
         ast::OptionElseClause::ElseClause(else_clause) => {
             match else_clause.else_block_or_if(syntax_db) {
-                BlockOrIf::Block(block) => {
-                    let else_block = compute_expr_block_semantic(ctx, &block)?;
-                    (Some(else_block.clone()), else_block.ty())
-                }
-                BlockOrIf::If(expr_if) => {
-                    let else_if = compute_expr_if_semantic(ctx, &expr_if)?;
-                    (Some(else_if.clone()), else_if.ty())
-                }
+                BlockOrIf::Block(block) => compute_expr_block_semantic(ctx, &block)?,
+                BlockOrIf::If(expr_if) => compute_expr_if_semantic(ctx, &expr_if)?,
+                BlockOrIf::IfLet(expr_if_let) => compute_expr_if_let_semantic(ctx, &expr_if_let)?,
             }
         }
     };
+    let ty = else_expr.ty();
+    Ok((Some(else_expr), ty))
+}
 
+/// Merges the types of the blocks of an if expression, and returns the type of the expression.
+fn merge_if_block_types(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &impl TypedSyntaxNode,
+    if_block_ty: TypeId,
+    else_block_ty: TypeId,
+) -> TypeId {
     let mut helper = FlowMergeTypeHelper::new(ctx.db);
+    let inference = &mut ctx.resolver.inference;
     helper
-        .try_merge_types(if_block.ty())
-        .and(helper.try_merge_types(else_block_ty))
+        .try_merge_types(inference, if_block_ty)
+        .and(helper.try_merge_types(inference, else_block_ty))
         .unwrap_or_else(|(block_if_ty, block_else_ty)| {
             ctx.diagnostics.report(syntax, IncompatibleIfBlockTypes { block_if_ty, block_else_ty });
         });
-    Ok(Expr::If(ExprIf {
-        condition: ctx.exprs.alloc(expr),
-        if_block: ctx.exprs.alloc(if_block),
-        else_block: else_block_opt.map(|else_block| ctx.exprs.alloc(else_block)),
-        ty: helper.get_final_type(),
+    helper.get_final_type()
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprLoop].
+fn compute_expr_loop_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprLoop,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+
+    let (body, loop_ctx) = ctx
+        .run_in_loop_ctx(LoopContext::Loop(FlowMergeTypeHelper::new(db)), |new_ctx| {
+            compute_expr_block_semantic(new_ctx, &syntax.body(syntax_db))
+        });
+    let ty = match loop_ctx {
+        LoopContext::Loop(helper) => helper.get_final_type(),
+        LoopContext::While => unreachable!("Loop context was replaced."),
+    };
+    Ok(Expr::Loop(ExprLoop {
+        body: ctx.exprs.alloc(body?),
+        ty,
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprWhile].
+fn compute_expr_while_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprWhile,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+
+    let ((condition, body), _) = ctx.run_in_loop_ctx(LoopContext::While, |new_ctx| {
+        let condition = compute_expr_semantic(new_ctx, &syntax.condition(syntax_db));
+        let body = compute_expr_block_semantic(new_ctx, &syntax.body(syntax_db));
+        (condition, body)
+    });
+    Ok(Expr::While(ExprWhile {
+        condition: ctx.exprs.alloc(condition),
+        body: ctx.exprs.alloc(body?),
+        ty: unit_ty(db),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprClosure].
+fn compute_expr_closure_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprClosure,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+
+    let closure_ctx = ClosureContext {
+        outer_vars: ctx.semantic_defs.clone(),
+        captured: OrderedHashSet::default(),
+    };
+    let ((params, body), closure_ctx) = ctx.run_in_closure_ctx(closure_ctx, |new_ctx| {
+        new_ctx.run_in_subscope(|new_ctx| {
+            let params = match syntax.params(syntax_db) {
+                ast::ClosureParams::Empty(_) => vec![],
+                ast::ClosureParams::Wrapped(params_syntax) => {
+                    let generic_function =
+                        db.lookup_intern_function(new_ctx.function_id).function.generic_function;
+                    let params_syntax = params_syntax.params(syntax_db).elements(syntax_db);
+                    let params = update_env_with_ast_params(
+                        new_ctx.diagnostics,
+                        db,
+                        &mut new_ctx.resolver,
+                        &params_syntax,
+                        generic_function,
+                        &mut new_ctx.environment,
+                    );
+                    for param in &params {
+                        if param.mutability == Mutability::Reference {
+                            new_ctx.diagnostics.report_by_ptr(
+                                param.id.untyped_stable_ptr(db.upcast()),
+                                RefClosureParam,
+                            );
+                        }
+                    }
+                    params
+                }
+            };
+            for param in &params {
+                let var = Variable::Param(param.clone());
+                new_ctx.semantic_defs.insert(var.id(), var);
+            }
+            let body = compute_expr_semantic(new_ctx, &syntax.body(syntax_db));
+            (params, body)
+        })
+    });
+    let captured_vars: Vec<_> = closure_ctx.captured.into_iter().collect();
+    let ty = db.intern_type(TypeLongId::Closure(ClosureTypeLongId {
+        parent: ctx.function_id,
+        param_tys: params.iter().map(|param| param.ty).collect(),
+        ret_ty: body.ty(),
+        captured_tys: captured_vars.iter().map(|var| ctx.semantic_defs[*var].ty()).collect(),
+        stable_ptr: syntax.stable_ptr().into(),
+    }));
+    Ok(Expr::Closure(ExprClosure {
+        params,
+        body: ctx.exprs.alloc(body),
+        captured_vars,
+        ty,
         stable_ptr: syntax.stable_ptr().into(),
     }))
 }
@@ -510,9 +873,13 @@ fn compute_expr_error_propagate_semantic(
 ) -> Maybe<Expr> {
     let syntax_db = ctx.db.upcast();
     let inner = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
+    let inner_ty = ctx.resolver.inference.reduce_ty(inner.ty());
+    if !ctx.closure_ctxs.is_empty() {
+        ctx.diagnostics.report(syntax, ErrorPropagateNotAllowedInsideAClosure);
+    }
     let (ok_variant, err_variant) =
-        unwrap_error_propagation_type(ctx.db, inner.ty()).ok_or_else(|| {
-            ctx.diagnostics.report(syntax, ErrorPropagateOnNonErrorType { ty: inner.ty() })
+        unwrap_error_propagation_type(ctx.db, inner_ty).ok_or_else(|| {
+            ctx.diagnostics.report(syntax, ErrorPropagateOnNonErrorType { ty: inner_ty })
         })?;
     let (_, func_err_variant) = unwrap_error_propagation_type(ctx.db, ctx.signature.return_type)
         .ok_or_else(|| {
@@ -546,6 +913,46 @@ fn compute_expr_error_propagate_semantic(
     }))
 }
 
+/// Computes the semantic model of an expression of the form `expr[index_expr]`, which calls the
+/// `index` function of the impl of the core `Index` trait for the type of `expr`.
+fn compute_expr_indexed_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprIndexed,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let expr = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
+    let index_expr = compute_expr_semantic(ctx, &syntax.index_expr(syntax_db));
+    let ty = ctx.resolver.inference.reduce_ty(expr.ty());
+    ty.check_not_missing(db)?;
+
+    let candidates = find_method_impl_functions(
+        db,
+        ctx.resolver.module_file_id.0,
+        index_trait(db),
+        &"index".into(),
+        ty,
+    )?;
+    let (impl_function_id, impl_generic_args) = match &candidates[..] {
+        [] => return Err(ctx.diagnostics.report(syntax, UnsupportedIndexOperator { ty })),
+        [candidate] => candidate.clone(),
+        _ => {
+            let impl_ids = candidates
+                .into_iter()
+                .map(|(impl_function_id, _)| impl_function_id.impl_id(db.upcast()))
+                .collect();
+            return Err(ctx.diagnostics.report(syntax, AmbiguousIndexOperator { ty, impl_ids }));
+        }
+    };
+    let function = db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::ImplFunction(impl_function_id),
+            generic_args: impl_generic_args,
+        },
+    });
+    expr_function_call(ctx, function, vec![expr, index_expr], syntax.stable_ptr().into())
+}
+
 /// Computes the semantic model of a pattern, or None if invalid.
 fn compute_pattern_semantic(
     ctx: &mut ComputationContext<'_>,
@@ -554,25 +961,22 @@ fn compute_pattern_semantic(
 ) -> Maybe<Pattern> {
     // TODO(spapini): Check for missing type, and don't reemit an error.
     let syntax_db = ctx.db.upcast();
+    let ty = ctx.resolver.inference.reduce_ty(ty);
     Ok(match pattern_syntax {
         ast::Pattern::Underscore(_) => Pattern::Otherwise(PatternOtherwise { ty }),
         ast::Pattern::Literal(literal_pattern) => {
-            let literal = literal_to_semantic(ctx, &literal_pattern)?;
-            if ty != core_felt_ty(ctx.db) {
-                return Err(ctx
-                    .diagnostics
-                    .report(&literal_pattern, UnexpectedLiteralPattern { ty }));
-            }
-            Pattern::Literal(PatternLiteral { literal, ty })
+            let literal = literal_to_semantic(ctx.db, ctx.diagnostics, &literal_pattern)?;
+            compute_literal_pattern_semantic(ctx, literal, ty)?
         }
         ast::Pattern::ShortString(short_string_pattern) => {
-            let literal = short_string_to_semantic(ctx, &short_string_pattern)?;
-            if ty != core_felt_ty(ctx.db) {
-                return Err(ctx
-                    .diagnostics
-                    .report(&short_string_pattern, UnexpectedLiteralPattern { ty }));
-            }
-            Pattern::Literal(PatternLiteral { literal, ty })
+            let literal = short_string_to_semantic(ctx.db, ctx.diagnostics, &short_string_pattern)?;
+            compute_literal_pattern_semantic(ctx, literal, ty)?
+        }
+        ast::Pattern::True(true_pattern) => {
+            compute_bool_pattern_semantic(ctx, true_variant(ctx.db), &true_pattern, ty)?
+        }
+        ast::Pattern::False(false_pattern) => {
+            compute_bool_pattern_semantic(ctx, false_variant(ctx.db), &false_pattern, ty)?
         }
         ast::Pattern::Enum(enum_pattern) => {
             // Check that type is an enum, and get the concrete enum from it.
@@ -659,7 +1063,7 @@ fn compute_pattern_semantic(
                     })?;
             let pattern_param_asts = pattern_struct.params(syntax_db).elements(syntax_db);
             let struct_id = concrete_struct.struct_id(ctx.db);
-            let mut members = ctx.db.struct_members(struct_id).expect("This is a valid struct.");
+            let mut members = ctx.db.concrete_struct_members(concrete_struct)?;
             let mut used_members = UnorderedHashSet::default();
             let mut get_member = |ctx: &mut ComputationContext<'_>, member_name: SmolStr| {
                 let member = members.swap_remove(&member_name).on_none(|| {
@@ -680,13 +1084,28 @@ fn compute_pattern_semantic(
             for pattern_param_ast in pattern_param_asts {
                 match pattern_param_ast {
                     PatternStructParam::Single(single) => {
-                        let member = get_member(ctx, single.text(syntax_db)).to_maybe()?;
+                        let member_name = single.text(syntax_db);
+                        let member = get_member(ctx, member_name.clone()).to_maybe()?;
+                        validate_member_visibility(
+                            ctx,
+                            struct_id,
+                            member_name,
+                            &member,
+                            single.stable_ptr().untyped(),
+                        );
                         let pattern = create_variable_pattern(ctx, single, &[], member.ty);
                         field_patterns.push((member, Box::new(pattern)));
                     }
                     PatternStructParam::WithExpr(with_expr) => {
-                        let member = get_member(ctx, with_expr.name(syntax_db).text(syntax_db))
-                            .to_maybe()?;
+                        let member_name = with_expr.name(syntax_db).text(syntax_db);
+                        let member = get_member(ctx, member_name.clone()).to_maybe()?;
+                        validate_member_visibility(
+                            ctx,
+                            struct_id,
+                            member_name,
+                            &member,
+                            with_expr.stable_ptr().untyped(),
+                        );
                         let pattern =
                             compute_pattern_semantic(ctx, with_expr.pattern(syntax_db), member.ty)?;
                         field_patterns.push((member, Box::new(pattern)));
@@ -701,7 +1120,11 @@ fn compute_pattern_semantic(
                     ctx.diagnostics.report(&pattern_struct, MissingMember { member_name });
                 }
             }
-            Pattern::Struct(PatternStruct { id: struct_id, field_patterns, ty })
+            Pattern::Struct(PatternStruct {
+                concrete_struct_id: concrete_struct,
+                field_patterns,
+                ty,
+            })
         }
         ast::Pattern::Tuple(pattern_tuple) => {
             let tys = try_extract_matches!(ctx.db.lookup_intern_type(ty), TypeLongId::Tuple)
@@ -731,6 +1154,48 @@ fn compute_pattern_semantic(
     })
 }
 
+/// Computes the semantic model of a literal pattern, matching a value of type `ty`.
+/// A literal without an explicit type suffix takes the type of the matched value.
+fn compute_literal_pattern_semantic(
+    ctx: &mut ComputationContext<'_>,
+    mut literal: ExprLiteral,
+    ty: TypeId,
+) -> Maybe<Pattern> {
+    if try_get_const_libfunc_name_by_type(ctx.db, ty).is_err() {
+        return Err(ctx
+            .diagnostics
+            .report_by_ptr(literal.stable_ptr.untyped(), UnexpectedLiteralPattern { ty }));
+    }
+    if literal.ty == core_felt_ty(ctx.db) {
+        literal.ty = ty;
+    } else if literal.ty != ty {
+        return Err(ctx.diagnostics.report_by_ptr(
+            literal.stable_ptr.untyped(),
+            WrongLiteralPatternType { expected_ty: ty, actual_ty: literal.ty },
+        ));
+    }
+    Ok(Pattern::Literal(PatternLiteral { literal, ty }))
+}
+
+/// Computes the semantic model of a `true` or `false` pattern, which is a pattern of the
+/// corresponding `bool` variant.
+fn compute_bool_pattern_semantic<T: TypedSyntaxNode>(
+    ctx: &mut ComputationContext<'_>,
+    variant: semantic::ConcreteVariant,
+    syntax: &T,
+    ty: TypeId,
+) -> Maybe<Pattern> {
+    if ty != core_bool_ty(ctx.db) {
+        return Err(ctx.diagnostics.report(syntax, UnexpectedEnumPattern { ty }));
+    }
+    let inner_ty = variant.ty;
+    Ok(Pattern::EnumVariant(PatternEnumVariant {
+        variant,
+        inner_pattern: Box::new(Pattern::Otherwise(PatternOtherwise { ty: inner_ty })),
+        ty: inner_ty,
+    }))
+}
+
 /// Creates a local variable pattern.
 fn create_variable_pattern(
     ctx: &mut ComputationContext<'_>,
@@ -797,6 +1262,13 @@ fn struct_ctor_expr(
             ctx.diagnostics.report(&arg_identifier, UnknownMember);
             continue;
         };
+        validate_member_visibility(
+            ctx,
+            concrete_struct.struct_id(db),
+            arg_name,
+            member,
+            arg_identifier.stable_ptr().untyped(),
+        );
 
         // Extract expression.
         let arg_expr = match arg.arg_expr(syntax_db) {
@@ -809,11 +1281,15 @@ fn struct_ctor_expr(
         };
 
         // Check types.
-        if arg_expr.ty() != member.ty {
+        if ctx.resolver.inference.conform_ty(arg_expr.ty(), member.ty).is_err() {
             if !member.ty.is_missing(db) {
+                let inference = &ctx.resolver.inference;
                 ctx.diagnostics.report(
                     &arg_identifier,
-                    WrongArgumentType { expected_ty: member.ty, actual_ty: arg_expr.ty() },
+                    WrongArgumentType {
+                        expected_ty: inference.reduce_ty(member.ty),
+                        actual_ty: inference.reduce_ty(arg_expr.ty()),
+                    },
                 );
             }
             skipped_members.insert(member.id);
@@ -833,13 +1309,31 @@ fn struct_ctor_expr(
     }
 
     Ok(Expr::StructCtor(ExprStructCtor {
-        struct_id: concrete_struct.struct_id(db),
+        concrete_struct_id: concrete_struct,
         members: member_exprs.into_iter().collect(),
         ty: db.intern_type(TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct))),
         stable_ptr: ctor_syntax.stable_ptr().into(),
     }))
 }
 
+/// Reports a diagnostic if the given member of a struct is not visible from the current module.
+fn validate_member_visibility(
+    ctx: &mut ComputationContext<'_>,
+    struct_id: StructId,
+    member_name: SmolStr,
+    member: &semantic::Member,
+    stable_ptr: SyntaxStablePtrId,
+) {
+    let containing_module_id = struct_id.parent_module(ctx.db.upcast());
+    if !member.visibility.is_visible_from(
+        ctx.db.upcast(),
+        containing_module_id,
+        ctx.resolver.module_file_id.0,
+    ) {
+        ctx.diagnostics.report_by_ptr(stable_ptr, MemberNotVisible { struct_id, member_name });
+    }
+}
+
 /// Returns the tail expression of the given list of statements, if exists.
 /// A tail expression is the last statement in the list, if it is an expression and
 /// it does not end with a semicolon.
@@ -854,11 +1348,11 @@ fn get_tail_expression(
 }
 
 /// Creates the semantic model of a literal expression from its AST.
-fn literal_to_semantic(
-    ctx: &mut ComputationContext<'_>,
+pub fn literal_to_semantic(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
     literal_syntax: &ast::TerminalLiteralNumber,
 ) -> Maybe<ExprLiteral> {
-    let db = ctx.db;
     let syntax_db = db.upcast();
     let text = literal_syntax.text(syntax_db);
 
@@ -868,38 +1362,38 @@ fn literal_to_semantic(
         (text.clone(), None)
     };
     let value = LiteralLongId::try_from(literal_text)
-        .map_err(|_| ctx.diagnostics.report(literal_syntax, UnknownLiteral))?
+        .map_err(|_| diagnostics.report(literal_syntax, UnknownLiteral))?
         .value;
 
     let ty = if let Some(ty_str) = ty {
         try_get_core_ty_by_name(db, ty_str.into(), vec![])
-            .map_err(|err| ctx.diagnostics.report(literal_syntax, err))?
+            .map_err(|err| diagnostics.report(literal_syntax, err))?
     } else {
         db.core_felt_ty()
     };
     try_get_const_libfunc_name_by_type(db, ty)
-        .map_err(|err| ctx.diagnostics.report(literal_syntax, err))?;
+        .map_err(|err| diagnostics.report(literal_syntax, err))?;
     Ok(ExprLiteral { value, ty, stable_ptr: literal_syntax.stable_ptr().into() })
 }
 
 /// Creates the semantic model of a short string from its AST.
-fn short_string_to_semantic(
-    ctx: &mut ComputationContext<'_>,
+pub fn short_string_to_semantic(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
     short_string_syntax: &ast::TerminalShortString,
 ) -> Maybe<ExprLiteral> {
-    let db = ctx.db;
     let syntax_db = db.upcast();
     let text = short_string_syntax.text(syntax_db);
 
     if let Some((literal, suffix)) = text[1..].rsplit_once('\'') {
         let ty = if !suffix.is_empty() {
             try_get_core_ty_by_name(db, suffix[1..].into(), vec![])
-                .map_err(|err| ctx.diagnostics.report(short_string_syntax, err))?
+                .map_err(|err| diagnostics.report(short_string_syntax, err))?
         } else {
             db.core_felt_ty()
         };
         let unescaped_literal = unescape(literal).map_err(|err| {
-            ctx.diagnostics.report(short_string_syntax, IllegalStringEscaping(format!("{}", err)))
+            diagnostics.report(short_string_syntax, IllegalStringEscaping(format!("{}", err)))
         })?;
         if unescaped_literal.is_ascii() {
             Ok(ExprLiteral {
@@ -908,13 +1402,69 @@ fn short_string_to_semantic(
                 stable_ptr: short_string_syntax.stable_ptr().into(),
             })
         } else {
-            Err(ctx.diagnostics.report(short_string_syntax, ShortStringMustBeAscii))
+            Err(diagnostics.report(short_string_syntax, ShortStringMustBeAscii))
         }
     } else {
         unreachable!();
     }
 }
 
+/// Computes the semantic model of a string literal. The literal is a `ByteArray`, built by calls
+/// to the core library from its full words of [BYTES_IN_WORD] bytes and its remaining bytes.
+fn compute_expr_string_semantic(
+    ctx: &mut ComputationContext<'_>,
+    string_syntax: &ast::TerminalString,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let text = string_syntax.text(db.upcast());
+    let literal = text[1..]
+        .strip_suffix('"')
+        .ok_or_else(|| ctx.diagnostics.report(string_syntax, UnterminatedString))?;
+    let unescaped_literal = unescape(literal).map_err(|err| {
+        ctx.diagnostics.report(string_syntax, IllegalStringEscaping(format!("{}", err)))
+    })?;
+    if !unescaped_literal.is_ascii() {
+        return Err(ctx.diagnostics.report(string_syntax, StringMustBeAscii));
+    }
+
+    let stable_ptr: ast::ExprPtr = string_syntax.stable_ptr().into();
+    let felt_ty = db.core_felt_ty();
+    let word_literal = |bytes: &[u8]| {
+        Expr::Literal(ExprLiteral {
+            value: BigInt::from_bytes_be(Sign::Plus, bytes),
+            ty: felt_ty,
+            stable_ptr,
+        })
+    };
+    let array_new_function =
+        get_core_function_id(db, "array_new".into(), vec![GenericArgumentId::Type(felt_ty)]);
+    let mut data = expr_function_call(ctx, array_new_function, vec![], stable_ptr)?;
+    let words = unescaped_literal.as_bytes().chunks_exact(BYTES_IN_WORD);
+    let pending_word = words.remainder();
+    for word in words {
+        let push_word_function =
+            get_core_function_id(db, "string_literal_push_word".into(), vec![]);
+        data = expr_function_call(
+            ctx,
+            push_word_function,
+            vec![data, word_literal(word)],
+            stable_ptr,
+        )?;
+    }
+    let pending_word_len = Expr::Literal(ExprLiteral {
+        value: pending_word.len().into(),
+        ty: get_core_ty_by_name(db, "u128".into(), vec![]),
+        stable_ptr,
+    });
+    let build_function = get_core_function_id(db, "string_literal_build".into(), vec![]);
+    expr_function_call(
+        ctx,
+        build_function,
+        vec![data, word_literal(pending_word), pending_word_len],
+        stable_ptr,
+    )
+}
+
 /// Given an expression syntax, if it's an identifier, returns it. Otherwise, returns the proper
 /// error.
 fn expr_as_identifier(
@@ -941,26 +1491,36 @@ fn member_access_expr(
 ) -> Maybe<Expr> {
     let syntax_db = ctx.db.upcast();
 
+    let lexpr_ty = ctx.resolver.inference.reduce_ty(lexpr.ty());
+    if let TypeLongId::Tuple(item_tys) = ctx.db.lookup_intern_type(lexpr_ty) {
+        return tuple_item_expr(ctx, lexpr, lexpr_ty, item_tys, rhs_syntax, stable_ptr);
+    }
+
     // Find MemberId.
     let member_name = expr_as_identifier(ctx, &rhs_syntax, syntax_db)?;
-    match ctx.db.lookup_intern_type(lexpr.ty()) {
+    match ctx.db.lookup_intern_type(lexpr_ty) {
         TypeLongId::Concrete(concrete) => match concrete {
             ConcreteTypeId::Struct(concrete_struct_id) => {
                 // TODO(lior): Add a diagnostic test when accessing a member of a missing type.
+                let struct_id = concrete_struct_id.struct_id(ctx.db);
                 let members = ctx.db.concrete_struct_members(concrete_struct_id)?;
                 let member = members.get(&member_name).ok_or_else(|| {
                     ctx.diagnostics.report(
                         &rhs_syntax,
-                        NoSuchMember {
-                            struct_id: concrete_struct_id.struct_id(ctx.db),
-                            member_name,
-                        },
+                        NoSuchMember { struct_id, member_name: member_name.clone() },
                     )
                 })?;
+                validate_member_visibility(
+                    ctx,
+                    struct_id,
+                    member_name,
+                    member,
+                    rhs_syntax.stable_ptr().untyped(),
+                );
                 let lexpr_id = ctx.exprs.alloc(lexpr);
                 Ok(Expr::MemberAccess(ExprMemberAccess {
                     expr: lexpr_id,
-                    struct_id: concrete_struct_id.struct_id(ctx.db),
+                    concrete_struct_id,
                     member: member.id,
                     ty: member.ty,
                     stable_ptr,
@@ -968,26 +1528,484 @@ fn member_access_expr(
             }
             _ => Err(ctx
                 .diagnostics
-                .report(&rhs_syntax, TypeHasNoMembers { ty: lexpr.ty(), member_name })),
+                .report(&rhs_syntax, TypeHasNoMembers { ty: lexpr_ty, member_name })),
         },
-        TypeLongId::Tuple(_) => {
-            // TODO(spapini): Handle .0, .1, ...;
-            Err(ctx.diagnostics.report(&rhs_syntax, Unsupported))
+        TypeLongId::GenericParameter(_)
+        | TypeLongId::Tuple(_)
+        | TypeLongId::Var(_)
+        | TypeLongId::Closure(_) => {
+            Err(ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty: lexpr_ty, member_name }))
         }
-        TypeLongId::GenericParameter(_) => Err(ctx
-            .diagnostics
-            .report(&rhs_syntax, TypeHasNoMembers { ty: lexpr.ty(), member_name })),
         TypeLongId::Missing(diag_added) => Err(diag_added),
     }
 }
 
+/// Computes the semantic model of a tuple item access expression (e.g. "expr.1").
+fn tuple_item_expr(
+    ctx: &mut ComputationContext<'_>,
+    lexpr: Expr,
+    lexpr_ty: TypeId,
+    item_tys: Vec<TypeId>,
+    rhs_syntax: ast::Expr,
+    stable_ptr: ast::ExprPtr,
+) -> Maybe<Expr> {
+    let syntax_db = ctx.db.upcast();
+
+    let index = match &rhs_syntax {
+        ast::Expr::Literal(literal_syntax) => literal_syntax.text(syntax_db).parse::<usize>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| ctx.diagnostics.report(&rhs_syntax, InvalidMemberExpression))?;
+    let ty = *item_tys.get(index).ok_or_else(|| {
+        ctx.diagnostics.report(&rhs_syntax, NoSuchTupleItem { ty: lexpr_ty, index })
+    })?;
+    let lexpr_id = ctx.exprs.alloc(lexpr);
+    Ok(Expr::TupleItem(ExprTupleItem { expr: lexpr_id, index, ty, stable_ptr }))
+}
+
+/// Computes the semantic model of a method call expression (e.g. "expr.method(args)").
+/// The method is a function of a trait, whose first parameter is of the type of `expr`. The call is
+/// resolved to the matching function of the single applicable impl visible from the current module.
+fn method_call_expr(
+    ctx: &mut ComputationContext<'_>,
+    self_expr: Expr,
+    syntax: ast::ExprFunctionCall,
+    stable_ptr: ast::ExprPtr,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+
+    let path = syntax.path(syntax_db);
+    let segments = path.elements(syntax_db);
+    if segments.len() != 1 {
+        return Err(ctx.diagnostics.report(&path, InvalidMemberExpression));
+    }
+    let segment = &segments[0];
+    let method_name = segment.identifier(syntax_db);
+    let generic_args = match segment {
+        PathSegment::Simple(_) => vec![],
+        PathSegment::WithGenericArgs(generic_args_segment) => ctx.resolver.resolve_generic_args(
+            ctx.diagnostics,
+            &generic_args_segment
+                .generic_args(syntax_db)
+                .generic_args(syntax_db)
+                .elements(syntax_db),
+        )?,
+    };
+    let self_ty = ctx.resolver.inference.reduce_ty(self_expr.ty());
+    self_ty.check_not_missing(db)?;
+
+    let mut candidates = OrderedHashSet::default();
+    for trait_id in visible_traits(db, ctx.resolver.module_file_id.0)? {
+        candidates.extend(find_method_impl_functions(
+            db,
+            ctx.resolver.module_file_id.0,
+            trait_id,
+            &method_name,
+            self_ty,
+        )?);
+    }
+    let (impl_function_id, impl_generic_args) = match candidates.len() {
+        0 => {
+            return Err(ctx.diagnostics.report(segment, NoSuchMethod { ty: self_ty, method_name }));
+        }
+        1 => candidates.into_iter().next().unwrap(),
+        _ => {
+            let impl_ids: Vec<_> = candidates
+                .into_iter()
+                .map(|(impl_function_id, _)| impl_function_id.impl_id(db.upcast()))
+                .collect();
+            // Impls of the same trait may differ only in generic args that do not appear in the
+            // type of `self`, e.g. the target type of `Into`. These are inferred from the context
+            // of the call instead.
+            if let Some(trait_id) = common_trait(db, &impl_ids)? {
+                if let Some(trait_function_id) =
+                    db.trait_functions(trait_id)?.get(&method_name).copied()
+                {
+                    return trait_method_call_expr(
+                        ctx,
+                        trait_function_id,
+                        self_expr,
+                        syntax,
+                        generic_args,
+                        stable_ptr,
+                    );
+                }
+            }
+            return Err(ctx
+                .diagnostics
+                .report(segment, AmbiguousMethod { ty: self_ty, method_name, impl_ids }));
+        }
+    };
+
+    // The generic args of the impl are inferred. The ones of the function itself may be given
+    // explicitly.
+    let function_generic_params_count = db
+        .impl_function_generic_params(impl_function_id)?
+        .len()
+        .saturating_sub(impl_generic_args.len());
+    if generic_args.len() != function_generic_params_count {
+        return Err(ctx.diagnostics.report(
+            segment,
+            WrongNumberOfGenericArguments {
+                expected: function_generic_params_count,
+                actual: generic_args.len(),
+            },
+        ));
+    }
+    let function = db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::ImplFunction(impl_function_id),
+            generic_args: chain!(impl_generic_args, generic_args).collect(),
+        },
+    });
+    ctx.resolver.lookback.mark_concrete(db, segment, ResolvedConcreteItem::Function(function));
+
+    let mut arg_exprs = vec![self_expr];
+    for arg_syntax in syntax.arguments(syntax_db).expressions(syntax_db).elements(syntax_db) {
+        arg_exprs.push(compute_expr_semantic(ctx, &arg_syntax));
+    }
+    expr_function_call(ctx, function, arg_exprs, stable_ptr)
+}
+
+/// Warns about calls to conversion functions marked `#[deprecated(<replacement>)]`, which were
+/// replaced by the `Into` and `TryInto` traits. The core library itself still uses them to
+/// implement the traits.
+fn check_legacy_conversion_function(
+    ctx: &mut ComputationContext<'_>,
+    function: FunctionId,
+    path: &ast::ExprPath,
+) {
+    let db = ctx.db;
+    if ModuleId::CrateRoot(ctx.resolver.module_file_id.0.owning_crate(db.upcast()))
+        == core_module(db)
+    {
+        return;
+    }
+    let generic_function = db.lookup_intern_function(function).function.generic_function;
+    let attributes = match generic_function {
+        GenericFunctionId::Free(free_function) => {
+            db.free_function_declaration_attributes(free_function)
+        }
+        GenericFunctionId::Extern(extern_function) => {
+            db.extern_function_declaration_attributes(extern_function)
+        }
+        GenericFunctionId::TraitFunction(_) | GenericFunctionId::ImplFunction(_) => return,
+    }
+    .unwrap_or_default();
+    let Some(attribute) = attributes.iter().find(|attr| attr.id == "deprecated") else {
+        return;
+    };
+    let [replacement] = &attribute.args[..] else {
+        return;
+    };
+    ctx.diagnostics.report(
+        path,
+        LegacyConversionFunction {
+            function_name: generic_function.name(db.upcast()),
+            replacement: replacement.as_syntax_node().get_text(db.upcast()).trim().into(),
+        },
+    );
+}
+
+/// Returns the trait implemented by all the given impls, if they implement the same one.
+fn common_trait(db: &dyn SemanticGroup, impl_ids: &[ImplId]) -> Maybe<Option<TraitId>> {
+    let mut trait_ids = OrderedHashSet::default();
+    for impl_id in impl_ids {
+        trait_ids.insert(db.lookup_intern_concrete_trait(db.impl_trait(*impl_id)?).trait_id);
+    }
+    Ok(if trait_ids.len() == 1 { trait_ids.into_iter().next() } else { None })
+}
+
+/// Computes the semantic model of a method call to a trait function, whose impl is not determined
+/// by the type of `self`. The generic args of the trait that do not appear in the type of `self`
+/// are inferred like the omitted generic args of any other call, and the impl is selected once
+/// they are known, by [resolve_trait_function_calls].
+fn trait_method_call_expr(
+    ctx: &mut ComputationContext<'_>,
+    trait_function_id: TraitFunctionId,
+    self_expr: Expr,
+    syntax: ast::ExprFunctionCall,
+    generic_args: Vec<GenericArgumentId>,
+    stable_ptr: ast::ExprPtr,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let trait_id = trait_function_id.trait_id(db.upcast());
+    let signature = db.trait_function_signature(trait_function_id)?;
+    let self_param = signature.params.first().to_maybe()?;
+    let self_ty = ctx.resolver.inference.reduce_ty(self_expr.ty());
+
+    let trait_generic_params = db.trait_generic_params(trait_id)?;
+    let mut substitution = HashMap::new();
+    infer_generic_args(db, &trait_generic_params, self_param.ty, self_ty, &mut substitution);
+    let trait_generic_args = trait_generic_params
+        .iter()
+        .map(|param| match substitution.get(param) {
+            Some(generic_arg) => *generic_arg,
+            None => GenericArgumentId::Type(ctx.resolver.inference.new_var(stable_ptr.untyped())),
+        })
+        .collect_vec();
+
+    let function_generic_params_count = db
+        .trait_function_generic_params(trait_function_id)?
+        .len()
+        .saturating_sub(trait_generic_args.len());
+    if generic_args.len() != function_generic_params_count {
+        return Err(ctx.diagnostics.report(
+            &syntax.path(syntax_db),
+            WrongNumberOfGenericArguments {
+                expected: function_generic_params_count,
+                actual: generic_args.len(),
+            },
+        ));
+    }
+    let function = db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::TraitFunction(trait_function_id),
+            generic_args: chain!(trait_generic_args, generic_args).collect(),
+        },
+    });
+
+    let mut arg_exprs = vec![self_expr];
+    for arg_syntax in syntax.arguments(syntax_db).expressions(syntax_db).elements(syntax_db) {
+        arg_exprs.push(compute_expr_semantic(ctx, &arg_syntax));
+    }
+    expr_function_call(ctx, function, arg_exprs, stable_ptr)
+}
+
+/// Replaces the calls to trait functions made by [trait_method_call_expr] with calls to the
+/// functions of the impls of their concrete traits. Should be called once the inferred types are
+/// substituted in `exprs`.
+pub fn resolve_trait_function_calls(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    module_id: ModuleId,
+    exprs: &mut Arena<Expr>,
+) -> Maybe<()> {
+    for (_id, expr) in exprs.iter_mut() {
+        let Expr::FunctionCall(call) = expr else {
+            continue;
+        };
+        let ConcreteFunction { generic_function, generic_args } =
+            db.lookup_intern_function(call.function).function;
+        let GenericFunctionId::TraitFunction(trait_function_id) = generic_function else {
+            continue;
+        };
+        let trait_id = trait_function_id.trait_id(db.upcast());
+        // Calls to closures are resolved when lowering.
+        if trait_id == fn_trait(db) {
+            continue;
+        }
+        let trait_generic_args_count = db.trait_generic_params(trait_id)?.len();
+        let (trait_generic_args, function_generic_args) =
+            generic_args.split_at(trait_generic_args_count);
+        // A diagnostic was already reported for the generic args that could not be inferred.
+        if trait_generic_args.iter().any(
+            |generic_arg| matches!(generic_arg, GenericArgumentId::Type(ty) if ty.is_missing(db)),
+        ) {
+            continue;
+        }
+        // Calls on the generic params of the function are resolved when it is specialized.
+        if !generic_args_fully_concrete(db, trait_generic_args) {
+            continue;
+        }
+        let concrete_trait_id = db.intern_concrete_trait(ConcreteTraitLongId {
+            trait_id,
+            generic_args: trait_generic_args.to_vec(),
+        });
+        let concrete_impl_id = match find_unique_impl(db, module_id, concrete_trait_id)? {
+            Ok(concrete_impl_id) => concrete_impl_id,
+            Err(diagnostic_kind) => {
+                diagnostics.report_by_ptr(call.stable_ptr.untyped(), diagnostic_kind);
+                continue;
+            }
+        };
+        let ConcreteImplLongId { impl_id, generic_args: impl_generic_args } =
+            db.lookup_intern_concrete_impl(concrete_impl_id);
+        let impl_function_id =
+            impl_function_by_name(db, impl_id, &trait_function_id.name(db.upcast()))?.to_maybe()?;
+        call.function = db.intern_function(FunctionLongId {
+            function: ConcreteFunction {
+                generic_function: GenericFunctionId::ImplFunction(impl_function_id),
+                generic_args: chain!(impl_generic_args, function_generic_args.iter().copied())
+                    .collect(),
+            },
+        });
+    }
+    Ok(())
+}
+
+/// Reports the calls to `array_slice` with an element type that is not duplicatable, as the
+/// elements are shared by all the copies of a slice. Calls with the generic params of the function
+/// are checked once it is specialized.
+pub fn check_array_slice_calls(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    module_id: ModuleId,
+    exprs: &Arena<Expr>,
+) -> Maybe<()> {
+    let array_slice = get_core_generic_function_id(db, "array_slice".into());
+    for (_id, expr) in exprs.iter() {
+        let Expr::FunctionCall(call) = expr else {
+            continue;
+        };
+        let ConcreteFunction { generic_function, generic_args } =
+            db.lookup_intern_function(call.function).function;
+        if generic_function != array_slice || !generic_args_fully_concrete(db, &generic_args) {
+            continue;
+        }
+        let [GenericArgumentId::Type(element_ty)] = generic_args[..] else {
+            continue;
+        };
+        if element_ty.is_missing(db) {
+            continue;
+        }
+        let lookup_context =
+            ImplLookupContext { module_id, extra_modules: vec![], generic_params: vec![] };
+        if !db.type_info(lookup_context, element_ty)?.duplicatable {
+            diagnostics.report_by_ptr(
+                call.stable_ptr.untyped(),
+                NonCopyableArraySliceElement { element_ty },
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Returns the traits whose functions may be called as methods in the given module: the traits
+/// defined or used in it, and the ones defined or used in the core crate root.
+fn visible_traits(db: &dyn SemanticGroup, module_id: ModuleId) -> Maybe<OrderedHashSet<TraitId>> {
+    let mut traits = OrderedHashSet::default();
+    for module_id in [module_id, core_module(db)] {
+        traits.extend(db.module_traits_ids(module_id)?);
+        for use_id in db.module_uses_ids(module_id)? {
+            if let Ok(ResolvedGenericItem::Trait(trait_id)) = db.use_resolved_item(use_id) {
+                traits.insert(trait_id);
+            }
+        }
+    }
+    Ok(traits)
+}
+
+/// Finds the impl functions that a method call named `method_name` on a receiver of type `self_ty`
+/// may refer to, through the given trait. Returns them along with the inferred generic args of
+/// their impls.
+fn find_method_impl_functions(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    trait_id: TraitId,
+    method_name: &SmolStr,
+    self_ty: TypeId,
+) -> Maybe<Vec<(ImplFunctionId, Vec<GenericArgumentId>)>> {
+    let Some(trait_function_id) = db.trait_functions(trait_id)?.get(method_name).copied() else {
+        return Ok(vec![]);
+    };
+    let Ok(signature) = db.trait_function_signature(trait_function_id) else {
+        return Ok(vec![]);
+    };
+    let Some(self_param) = signature.params.first() else {
+        return Ok(vec![]);
+    };
+
+    // Infer the generic args of the trait from the type of the receiver.
+    let trait_generic_params = db.trait_generic_params(trait_id)?;
+    let mut substitution = HashMap::new();
+    if !infer_generic_args(db, &trait_generic_params, self_param.ty, self_ty, &mut substitution) {
+        return Ok(vec![]);
+    }
+    let Some(trait_generic_args) = trait_generic_params
+        .iter()
+        .map(|param| substitution.get(param).copied())
+        .collect::<Option<Vec<_>>>()
+    else {
+        // Some generic args of the trait do not appear in the type of `self`, so the concrete
+        // trait is unknown. Infer the generic args of each impl of the trait instead.
+        return find_method_impl_functions_by_self_param(
+            db,
+            module_id,
+            trait_id,
+            method_name,
+            self_ty,
+        );
+    };
+    let concrete_trait_id = db
+        .intern_concrete_trait(ConcreteTraitLongId { trait_id, generic_args: trait_generic_args });
+
+    // Look for impls also in the module defining the type of the receiver.
+    let mut lookup_context = concrete_trait_lookup_context(db, module_id, concrete_trait_id);
+    if let TypeLongId::Concrete(concrete) = db.lookup_intern_type(self_ty) {
+        let module_id = concrete.generic_type(db).parent_module(db.upcast());
+        if !lookup_context.extra_modules.contains(&module_id) {
+            lookup_context.extra_modules.push(module_id);
+        }
+    }
+    let mut res = vec![];
+    for concrete_impl_id in find_impls_at_context(db, &lookup_context, concrete_trait_id)? {
+        let ConcreteImplLongId { impl_id, generic_args } =
+            db.lookup_intern_concrete_impl(concrete_impl_id);
+        if let Some(impl_function_id) = impl_function_by_name(db, impl_id, method_name)? {
+            res.push((impl_function_id, generic_args));
+        }
+    }
+    Ok(res)
+}
+
+/// Finds the impl functions that a method call named `method_name` on a receiver of type `self_ty`
+/// may refer to, through any impl of the given trait. The generic args of each impl are inferred
+/// from the type of the first parameter of its function.
+fn find_method_impl_functions_by_self_param(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    trait_id: TraitId,
+    method_name: &SmolStr,
+    self_ty: TypeId,
+) -> Maybe<Vec<(ImplFunctionId, Vec<GenericArgumentId>)>> {
+    // Look for impls in the module of the trait and in the module defining the type of the
+    // receiver as well.
+    let mut extra_modules = vec![trait_id.parent_module(db.upcast())];
+    if let TypeLongId::Concrete(concrete) = db.lookup_intern_type(self_ty) {
+        let module_id = concrete.generic_type(db).parent_module(db.upcast());
+        if !extra_modules.contains(&module_id) {
+            extra_modules.push(module_id);
+        }
+    }
+    let lookup_context = ImplLookupContext { module_id, extra_modules, generic_params: vec![] };
+    let mut res = vec![];
+    for impl_id in find_trait_impls_at_context(db, &lookup_context, trait_id)? {
+        let Some(impl_function_id) = impl_function_by_name(db, impl_id, method_name)? else {
+            continue;
+        };
+        let Ok(signature) = db.impl_function_signature(impl_function_id) else {
+            continue;
+        };
+        let Some(self_param) = signature.params.first() else {
+            continue;
+        };
+        let impl_generic_params = db.impl_generic_params(impl_id)?;
+        let mut substitution = HashMap::new();
+        if !infer_generic_args(db, &impl_generic_params, self_param.ty, self_ty, &mut substitution)
+        {
+            continue;
+        }
+        if let Some(impl_generic_args) = impl_generic_params
+            .iter()
+            .map(|param| substitution.get(param).copied())
+            .collect::<Option<Vec<_>>>()
+        {
+            res.push((impl_function_id, impl_generic_args));
+        }
+    }
+    Ok(res)
+}
+
 /// Resolves a variable given a context and a path expression.
 fn resolve_variable(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) -> Maybe<Expr> {
     let db = ctx.db;
     let syntax_db = db.upcast();
     let segments = path.elements(syntax_db);
     if segments.len() != 1 {
-        return Err(ctx.diagnostics.report(path, Unsupported));
+        return resolve_constant_path(ctx, path);
     }
 
     match &segments[0] {
@@ -1001,6 +2019,29 @@ fn resolve_variable(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) -> M
     }
 }
 
+/// Resolves a path that is expected to refer to a constant item, and returns its value as an
+/// expression.
+fn resolve_constant_path(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) -> Maybe<Expr> {
+    let constant_id = match ctx.resolver.resolve_concrete_path(
+        ctx.diagnostics,
+        path,
+        NotFoundItemType::Identifier,
+    )? {
+        ResolvedConcreteItem::Constant(constant_id) => constant_id,
+        _ => return Err(ctx.diagnostics.report(path, Unsupported)),
+    };
+    let constant = ctx.db.constant_value(constant_id)?;
+    let stable_ptr = path.stable_ptr().into();
+    if constant.ty == core_bool_ty(ctx.db) {
+        return Ok(if constant.value.is_zero() {
+            false_literal_expr(ctx, stable_ptr)
+        } else {
+            true_literal_expr(ctx, stable_ptr)
+        });
+    }
+    Ok(Expr::Literal(ExprLiteral { value: constant.value, ty: constant.ty, stable_ptr }))
+}
+
 /// Resolves a variable given a context and a simple name.
 pub fn resolve_variable_by_name(
     ctx: &mut ComputationContext<'_>,
@@ -1008,16 +2049,16 @@ pub fn resolve_variable_by_name(
     path: &ast::ExprPath,
 ) -> Maybe<Expr> {
     let variable_name = identifier.text(ctx.db.upcast());
-    let mut maybe_env = Some(&*ctx.environment);
-    while let Some(env) = maybe_env {
-        if let Some(var) = env.variables.get(&variable_name) {
-            return Ok(Expr::Var(ExprVar {
-                var: var.id(),
-                ty: var.ty(),
-                stable_ptr: path.stable_ptr().into(),
-            }));
-        }
-        maybe_env = env.parent.as_deref();
+    if let Some(var) = ctx.environment.get_variable(&variable_name) {
+        let (var, ty) = (var.id(), var.ty());
+        ctx.mark_used_var(var);
+        return Ok(Expr::Var(ExprVar { var, ty, stable_ptr: path.stable_ptr().into() }));
+    }
+    // Fall back to a constant item defined in the current module.
+    if let Ok(Some(ModuleItemId::Constant(_))) =
+        ctx.db.module_item_by_name(ctx.resolver.module_file_id.0, variable_name.clone())
+    {
+        return resolve_constant_path(ctx, path);
     }
     Err(ctx.diagnostics.report(identifier, VariableNotFound { name: variable_name }))
 }
@@ -1057,10 +2098,16 @@ fn expr_function_call(
         // Don't add diagnostic if the type is missing (a diagnostic should have already been
         // added).
         // TODO(lior): Add a test to missing type once possible.
-        if arg_typ != param_typ && !arg_typ.is_missing(ctx.db) {
+        if !arg_typ.is_missing(ctx.db)
+            && ctx.resolver.inference.conform_ty(arg_typ, param_typ).is_err()
+        {
+            let inference = &ctx.resolver.inference;
             ctx.diagnostics.report_by_ptr(
                 arg.stable_ptr().untyped(),
-                WrongArgumentType { expected_ty: param_typ, actual_ty: arg_typ },
+                WrongArgumentType {
+                    expected_ty: inference.reduce_ty(param_typ),
+                    actual_ty: inference.reduce_ty(arg_typ),
+                },
             );
         }
 
@@ -1073,6 +2120,10 @@ fn expr_function_call(
             if !ctx.semantic_defs[expr_var.var].is_mut() {
                 ctx.diagnostics.report_by_ptr(arg.stable_ptr().untyped(), RefArgNotMutable);
             }
+            if ctx.is_captured_var(expr_var.var) {
+                ctx.diagnostics
+                    .report_by_ptr(arg.stable_ptr().untyped(), CapturedVarModifiedInsideAClosure);
+            }
             ref_args.push(expr_var.var);
         } else {
             args.push(ctx.exprs.alloc(arg));
@@ -1082,7 +2133,64 @@ fn expr_function_call(
         function: function_id,
         ref_args,
         args,
-        ty: signature.return_type,
+        ty: ctx.resolver.inference.reduce_ty(signature.return_type),
+        stable_ptr,
+    }))
+}
+
+/// Typechecks a call of the closure held by a variable. The closure is called through the `Fn`
+/// trait, with its arguments wrapped in a tuple.
+fn expr_closure_call(
+    ctx: &mut ComputationContext<'_>,
+    callee: Expr,
+    arg_exprs: Vec<Expr>,
+    path: &ast::ExprPath,
+    args_syntax: &ast::ExprListParenthesized,
+    stable_ptr: ast::ExprPtr,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let callee_ty = ctx.resolver.inference.reduce_ty(callee.ty());
+    let closure = match db.lookup_intern_type(callee_ty) {
+        TypeLongId::Closure(closure) => closure,
+        TypeLongId::Missing(diag_added) => return Err(diag_added),
+        _ => return Err(ctx.diagnostics.report(path, NotAFunction)),
+    };
+    if arg_exprs.len() != closure.param_tys.len() {
+        return Err(ctx.diagnostics.report(
+            args_syntax,
+            WrongNumberOfArguments { expected: closure.param_tys.len(), actual: arg_exprs.len() },
+        ));
+    }
+    let function = closure_call_function(db, callee_ty, &closure);
+    if db.concrete_function_signature(function)?.panicable && !ctx.signature.panicable {
+        return Err(ctx.diagnostics.report_by_ptr(stable_ptr.untyped(), PanicableFromNonPanicable));
+    }
+    let mut items = vec![];
+    for (arg, param_ty) in zip_eq(arg_exprs, &closure.param_tys) {
+        let arg_ty = arg.ty();
+        if !arg_ty.is_missing(db) && ctx.resolver.inference.conform_ty(arg_ty, *param_ty).is_err() {
+            let inference = &ctx.resolver.inference;
+            ctx.diagnostics.report_by_ptr(
+                arg.stable_ptr().untyped(),
+                WrongArgumentType {
+                    expected_ty: inference.reduce_ty(*param_ty),
+                    actual_ty: inference.reduce_ty(arg_ty),
+                },
+            );
+        }
+        items.push(ctx.exprs.alloc(arg));
+    }
+    let args = ctx.exprs.alloc(Expr::Tuple(ExprTuple {
+        items,
+        ty: db.intern_type(TypeLongId::Tuple(closure.param_tys)),
+        stable_ptr,
+    }));
+    Ok(Expr::FunctionCall(ExprFunctionCall {
+        function,
+        // The closure is passed by reference, so that it may be called again.
+        ref_args: vec![extract_matches!(callee, Expr::Var).var],
+        args: vec![args],
+        ty: closure.ret_ty,
         stable_ptr,
     }))
 }
@@ -1106,12 +2214,15 @@ pub fn compute_statement_semantic(
                     let var_type_path = type_clause.ty(syntax_db);
                     let explicit_type =
                         resolve_type(db, ctx.diagnostics, &mut ctx.resolver, &var_type_path);
-                    if !inferred_type.is_missing(db) && explicit_type != inferred_type {
+                    if !inferred_type.is_missing(db)
+                        && ctx.resolver.inference.conform_ty(inferred_type, explicit_type).is_err()
+                    {
+                        let inference = &ctx.resolver.inference;
                         ctx.diagnostics.report(
                             &let_syntax.rhs(syntax_db),
                             WrongArgumentType {
-                                expected_ty: explicit_type,
-                                actual_ty: inferred_type,
+                                expected_ty: inference.reduce_ty(explicit_type),
+                                actual_ty: inference.reduce_ty(inferred_type),
                             },
                         );
                     }
@@ -1119,7 +2230,28 @@ pub fn compute_statement_semantic(
                 }
             };
 
-            let pattern = compute_pattern_semantic(ctx, let_syntax.pattern(syntax_db), ty)?;
+            // The else block is computed before the variables of the pattern are introduced, as
+            // they are not available in it.
+            let else_block = match let_syntax.let_else_clause(syntax_db) {
+                ast::OptionLetElseClause::Empty(_) => None,
+                ast::OptionLetElseClause::LetElseClause(let_else_clause) => {
+                    let else_block_syntax = let_else_clause.else_block(syntax_db);
+                    let else_block = compute_expr_block_semantic(ctx, &else_block_syntax)?;
+                    let else_ty = ctx.resolver.inference.reduce_ty(else_block.ty());
+                    if else_ty != never_ty(db) && !else_ty.is_missing(db) {
+                        ctx.diagnostics
+                            .report(&else_block_syntax, NonDivergingLetElse { ty: else_ty });
+                    }
+                    Some(ctx.exprs.alloc(else_block))
+                }
+            };
+
+            let pattern_syntax = let_syntax.pattern(syntax_db);
+            let pattern = compute_pattern_semantic(ctx, pattern_syntax.clone(), ty)?;
+            if else_block.is_none() {
+                let ty = ctx.resolver.inference.reduce_ty(ty);
+                check_let_pattern(db, ctx.diagnostics, &pattern_syntax, ty, &pattern);
+            }
             let variables = pattern.variables();
             // TODO(yuval): allow unnamed variables. Add them here to
             // ctx.environment.unnamed_variables
@@ -1131,6 +2263,7 @@ pub fn compute_statement_semantic(
             semantic::Statement::Let(semantic::StatementLet {
                 pattern,
                 expr: rhs_expr_id,
+                else_block,
                 stable_ptr: syntax.stable_ptr(),
             })
         }
@@ -1146,15 +2279,62 @@ pub fn compute_statement_semantic(
             let expr = compute_expr_semantic(ctx, &expr_syntax);
             let expr_ty = expr.ty();
             let expected_ty = ctx.signature.return_type;
-            if expr_ty != expected_ty && !expected_ty.is_missing(db) && !expr_ty.is_missing(db) {
-                ctx.diagnostics
-                    .report(&expr_syntax, WrongReturnType { expected_ty, actual_ty: expr_ty });
+            if !expected_ty.is_missing(db)
+                && !expr_ty.is_missing(db)
+                && ctx.resolver.inference.conform_ty(expr_ty, expected_ty).is_err()
+            {
+                let actual_ty = ctx.resolver.inference.reduce_ty(expr_ty);
+                ctx.diagnostics.report(&expr_syntax, WrongReturnType { expected_ty, actual_ty });
+            }
+            if !ctx.closure_ctxs.is_empty() {
+                ctx.diagnostics.report(return_syntax, ReturnNotAllowedInsideAClosure);
             }
             semantic::Statement::Return(semantic::StatementReturn {
                 expr: ctx.exprs.alloc(expr),
                 stable_ptr: syntax.stable_ptr(),
             })
         }
+        ast::Statement::Break(break_syntax) => {
+            let expr_option = match break_syntax.expr_clause(syntax_db) {
+                ast::OptionExprClause::Empty(_) => None,
+                ast::OptionExprClause::ExprClause(expr_clause) => {
+                    Some(compute_expr_semantic(ctx, &expr_clause.expr(syntax_db)))
+                }
+            };
+            let ty = expr_option.as_ref().map(|expr| expr.ty()).unwrap_or_else(|| unit_ty(db));
+            match &mut ctx.loop_ctx {
+                None => {
+                    ctx.diagnostics.report(break_syntax, BreakOnlyAllowedInsideALoop);
+                }
+                Some(LoopContext::Loop(helper)) => {
+                    if let Err((current_ty, break_ty)) =
+                        helper.try_merge_types(&mut ctx.resolver.inference, ty)
+                    {
+                        ctx.diagnostics.report(
+                            break_syntax,
+                            IncompatibleLoopBreakTypes { current_ty, break_ty },
+                        );
+                    }
+                }
+                Some(LoopContext::While) => {
+                    if expr_option.is_some() {
+                        ctx.diagnostics.report(break_syntax, BreakWithValueOnlyAllowedInsideALoop);
+                    }
+                }
+            }
+            semantic::Statement::Break(semantic::StatementBreak {
+                expr_option: expr_option.map(|expr| ctx.exprs.alloc(expr)),
+                stable_ptr: syntax.stable_ptr(),
+            })
+        }
+        ast::Statement::Continue(continue_syntax) => {
+            if ctx.loop_ctx.is_none() {
+                ctx.diagnostics.report(continue_syntax, ContinueOnlyAllowedInsideALoop);
+            }
+            semantic::Statement::Continue(semantic::StatementContinue {
+                stable_ptr: syntax.stable_ptr(),
+            })
+        }
         ast::Statement::Missing(_) => todo!(),
     };
     Ok(ctx.statements.alloc(statement))
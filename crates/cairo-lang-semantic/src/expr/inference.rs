@@ -0,0 +1,174 @@
+//! Type inference for the generic arguments omitted in function bodies.
+//! Paths that omit the generic arguments of a generic item get fresh type variables instead. The
+//! variables are then unified with the other types they should be equal to, e.g. the types of the
+//! arguments of a call, the expected type of a `let` statement or the return type of the function.
+
+use std::collections::HashMap;
+use std::iter::zip;
+
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+use itertools::chain;
+
+use crate::db::SemanticGroup;
+use crate::diagnostic::SemanticDiagnosticKind::*;
+use crate::diagnostic::SemanticDiagnostics;
+use crate::substitution::SemanticRewriter;
+use crate::{ConcreteTypeId, GenericArgumentId, TypeId, TypeLongId};
+
+/// A type variable, standing for a type that is not known yet.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct TypeVar {
+    /// The index of the variable in the [Inference] that created it.
+    pub id: usize,
+    /// The location that caused the creation of the variable, e.g. the path of a generic function
+    /// whose generic arguments were omitted.
+    pub stable_ptr: SyntaxStablePtrId,
+}
+
+/// An error found while unifying two types.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InferenceError {
+    /// The types have different shapes, e.g. a tuple and a struct, or two different structs.
+    KindMismatch { ty0: TypeId, ty1: TypeId },
+    /// Assigning the type to the variable would create an infinite type.
+    Cycle { type_var: TypeVar },
+}
+
+/// The state of the type inference of a single function body.
+pub struct Inference<'db> {
+    db: &'db dyn SemanticGroup,
+    /// All the type variables created so far, by their ids.
+    type_vars: Vec<TypeVar>,
+    /// The types assigned to the variables. These may contain other variables.
+    type_assignment: HashMap<usize, TypeId>,
+}
+impl<'db> Inference<'db> {
+    pub fn new(db: &'db dyn SemanticGroup) -> Self {
+        Self { db, type_vars: vec![], type_assignment: HashMap::new() }
+    }
+
+    /// Creates a new type variable, and returns the type standing for it.
+    pub fn new_var(&mut self, stable_ptr: SyntaxStablePtrId) -> TypeId {
+        let type_var = TypeVar { id: self.type_vars.len(), stable_ptr };
+        self.type_vars.push(type_var);
+        self.db.intern_type(TypeLongId::Var(type_var))
+    }
+
+    /// Unifies two types, assigning the type variables in them as necessary.
+    /// Returns the unified type.
+    pub fn conform_ty(&mut self, ty0: TypeId, ty1: TypeId) -> Result<TypeId, InferenceError> {
+        let ty0 = self.reduce_ty(ty0);
+        let ty1 = self.reduce_ty(ty1);
+        if ty0 == ty1 {
+            return Ok(ty0);
+        }
+        let long_ty0 = self.db.lookup_intern_type(ty0);
+        let long_ty1 = self.db.lookup_intern_type(ty1);
+        match (long_ty0, long_ty1) {
+            (TypeLongId::Var(type_var), _) => self.assign(type_var, ty1),
+            (_, TypeLongId::Var(type_var)) => self.assign(type_var, ty0),
+            (TypeLongId::Concrete(concrete0), TypeLongId::Concrete(concrete1)) => {
+                let generic_type = concrete0.generic_type(self.db);
+                let generic_args0 = concrete0.generic_args(self.db);
+                let generic_args1 = concrete1.generic_args(self.db);
+                if generic_type != concrete1.generic_type(self.db)
+                    || generic_args0.len() != generic_args1.len()
+                {
+                    return Err(InferenceError::KindMismatch { ty0, ty1 });
+                }
+                let generic_args = zip(generic_args0, generic_args1)
+                    .map(|(generic_arg0, generic_arg1)| match (generic_arg0, generic_arg1) {
+                        (GenericArgumentId::Type(arg_ty0), GenericArgumentId::Type(arg_ty1)) => {
+                            Ok(GenericArgumentId::Type(self.conform_ty(arg_ty0, arg_ty1)?))
+                        }
+                        _ if generic_arg0 == generic_arg1 => Ok(generic_arg0),
+                        _ => Err(InferenceError::KindMismatch { ty0, ty1 }),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(self.db.intern_type(TypeLongId::Concrete(ConcreteTypeId::new(
+                    self.db,
+                    generic_type,
+                    generic_args,
+                ))))
+            }
+            (TypeLongId::Tuple(tys0), TypeLongId::Tuple(tys1)) => {
+                if tys0.len() != tys1.len() {
+                    return Err(InferenceError::KindMismatch { ty0, ty1 });
+                }
+                let tys = zip(tys0, tys1)
+                    .map(|(subty0, subty1)| self.conform_ty(subty0, subty1))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(self.db.intern_type(TypeLongId::Tuple(tys)))
+            }
+            _ => Err(InferenceError::KindMismatch { ty0, ty1 }),
+        }
+    }
+
+    /// Assigns a type to an unassigned type variable.
+    fn assign(&mut self, type_var: TypeVar, ty: TypeId) -> Result<TypeId, InferenceError> {
+        if self.contains_var(ty, type_var) {
+            return Err(InferenceError::Cycle { type_var });
+        }
+        self.type_assignment.insert(type_var.id, ty);
+        Ok(ty)
+    }
+
+    /// Returns true if the given type (assumed to be reduced) contains the given variable.
+    fn contains_var(&self, ty: TypeId, type_var: TypeVar) -> bool {
+        match self.db.lookup_intern_type(ty) {
+            TypeLongId::Concrete(concrete) => {
+                concrete.generic_args(self.db).into_iter().any(|generic_arg| match generic_arg {
+                    GenericArgumentId::Type(ty) => self.contains_var(ty, type_var),
+                    GenericArgumentId::Literal(_) => false,
+                })
+            }
+            TypeLongId::Tuple(tys) => tys.into_iter().any(|ty| self.contains_var(ty, type_var)),
+            TypeLongId::Closure(closure) => {
+                chain!(closure.param_tys, [closure.ret_ty], closure.captured_tys)
+                    .any(|ty| self.contains_var(ty, type_var))
+            }
+            TypeLongId::Var(other) => other == type_var,
+            TypeLongId::GenericParameter(_) | TypeLongId::Missing(_) => false,
+        }
+    }
+
+    /// Reports a diagnostic for each location with type variables that could not be inferred,
+    /// and assigns a missing type to these variables.
+    pub fn finalize(&mut self, diagnostics: &mut SemanticDiagnostics) {
+        let mut reported_ptrs = UnorderedHashSet::default();
+        for type_var in self.type_vars.clone() {
+            if self.type_assignment.contains_key(&type_var.id) {
+                continue;
+            }
+            if !reported_ptrs.insert(type_var.stable_ptr) {
+                continue;
+            }
+            let diag_added = diagnostics.report_by_ptr(type_var.stable_ptr, TypeAnnotationsNeeded);
+            for other_var in &self.type_vars {
+                if other_var.stable_ptr == type_var.stable_ptr {
+                    self.type_assignment
+                        .entry(other_var.id)
+                        .or_insert_with(|| TypeId::missing(self.db, diag_added));
+                }
+            }
+        }
+    }
+
+    /// Substitutes the assigned type variables in a type.
+    pub fn reduce_ty(&self, ty: TypeId) -> TypeId {
+        self.rewrite_ty(ty)
+    }
+}
+impl<'db> SemanticRewriter for Inference<'db> {
+    fn db(&self) -> &dyn SemanticGroup {
+        self.db
+    }
+
+    fn rewrite_type_var(&self, ty: TypeId, type_var: TypeVar) -> TypeId {
+        match self.type_assignment.get(&type_var.id) {
+            Some(assigned_ty) => self.rewrite_ty(*assigned_ty),
+            None => ty,
+        }
+    }
+}
@@ -0,0 +1,338 @@
+//! Exhaustiveness and reachability checks of match arms and let patterns.
+//!
+//! Based on the "usefulness" algorithm described in "Warnings for pattern matching" by Luc
+//! Maranget. A pattern vector is useful with respect to a matrix of pattern vectors if there is a
+//! value that it matches and no row of the matrix matches. An arm is unreachable if its pattern is
+//! not useful with respect to the patterns of the arms before it, and a match is exhaustive if a
+//! wildcard is not useful with respect to the patterns of all its arms.
+
+use cairo_lang_syntax::node::ast;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use smol_str::SmolStr;
+
+use super::pattern::Pattern;
+use crate::corelib::core_bool_enum;
+use crate::db::SemanticGroup;
+use crate::diagnostic::SemanticDiagnosticKind::*;
+use crate::diagnostic::SemanticDiagnostics;
+use crate::items::enm::SemanticEnumEx;
+use crate::items::strct::SemanticStructEx;
+use crate::{ConcreteStructId, ConcreteTypeId, ConcreteVariant, TypeId, TypeLongId};
+
+/// The maximal number of missing patterns to show in a diagnostic.
+const MAX_REPORTED_MISSING_PATTERNS: usize = 3;
+
+/// Checks that the arms of a match cover all the possible values of the matched type, and that
+/// every arm is reachable. Reports diagnostics on failure.
+pub fn check_match_arms(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    syntax: &ast::ExprMatch,
+    ty: TypeId,
+    patterns: &[&Pattern],
+) {
+    if ty.is_missing(db) {
+        return;
+    }
+    let syntax_db = db.upcast();
+    let mut matrix: Vec<Vec<Option<&Pattern>>> = vec![];
+    for (pattern, syntax_arm) in patterns.iter().zip(syntax.arms(syntax_db).elements(syntax_db)) {
+        let row = vec![Some(*pattern)];
+        if compute_witnesses(db, &matrix, &row, &[ty], 1).is_empty() {
+            diagnostics.report(&syntax_arm.pattern(syntax_db), UnreachableMatchArm);
+        }
+        matrix.push(row);
+    }
+    let missing = missing_patterns(db, &matrix, ty);
+    if !missing.is_empty() {
+        diagnostics.report(&syntax.expr(syntax_db), MissingMatchArms { missing });
+    }
+}
+
+/// Checks that the pattern of a let statement without an else block matches all the possible
+/// values of the type. Reports a diagnostic on failure.
+pub fn check_let_pattern(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    syntax: &ast::Pattern,
+    ty: TypeId,
+    pattern: &Pattern,
+) {
+    if ty.is_missing(db) {
+        return;
+    }
+    let missing = missing_patterns(db, &[vec![Some(pattern)]], ty);
+    if !missing.is_empty() {
+        diagnostics.report(syntax, RefutableLetPattern { missing });
+    }
+}
+
+/// Returns the formatted patterns of some of the values of the type that no row of the matrix
+/// matches.
+fn missing_patterns(
+    db: &dyn SemanticGroup,
+    matrix: &[Vec<Option<&Pattern>>],
+    ty: TypeId,
+) -> Vec<String> {
+    let witnesses =
+        compute_witnesses(db, matrix, &[None], &[ty], MAX_REPORTED_MISSING_PATTERNS + 1);
+    let has_more = witnesses.len() > MAX_REPORTED_MISSING_PATTERNS;
+    let mut missing = witnesses
+        .into_iter()
+        .take(MAX_REPORTED_MISSING_PATTERNS)
+        .map(|mut witness| format!("`{}`", witness.remove(0).format(db)))
+        .collect_vec();
+    if has_more {
+        missing.push("...".into());
+    }
+    missing
+}
+
+/// A constructor of values, that a pattern may match on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Constructor {
+    /// A variant of an enum.
+    Variant(ConcreteVariant),
+    /// The only constructor of a struct or a tuple.
+    Single,
+    /// A literal of a numeric type.
+    Literal(BigInt),
+}
+
+/// An example of a value that is not matched by a set of patterns.
+#[derive(Clone)]
+enum Witness {
+    /// Any value.
+    Wildcard,
+    Variant(ConcreteVariant, Box<Witness>),
+    Struct(ConcreteStructId, Vec<(SmolStr, Witness)>),
+    Tuple(Vec<Witness>),
+}
+impl Witness {
+    /// Formats the witness as a pattern.
+    fn format(&self, db: &dyn SemanticGroup) -> String {
+        match self {
+            Witness::Wildcard => "_".into(),
+            Witness::Variant(variant, inner) => {
+                let defs_db = db.upcast();
+                if variant.concrete_enum_id == core_bool_enum(db) {
+                    return variant.id.name(defs_db).to_lowercase();
+                }
+                format!(
+                    "{}::{}({})",
+                    variant.concrete_enum_id.enum_id(db).name(defs_db),
+                    variant.id.name(defs_db),
+                    inner.format(db)
+                )
+            }
+            Witness::Struct(concrete_struct_id, members) => format!(
+                "{} {{ {} }}",
+                concrete_struct_id.struct_id(db).name(db.upcast()),
+                members
+                    .iter()
+                    .map(|(name, member)| format!("{name}: {}", member.format(db)))
+                    .join(", ")
+            ),
+            Witness::Tuple(items) => {
+                format!("({})", items.iter().map(|item| item.format(db)).join(", "))
+            }
+        }
+    }
+}
+
+/// Returns the constructor the pattern matches on, or None if the pattern matches any value.
+fn pattern_constructor(pattern: &Pattern) -> Option<Constructor> {
+    match pattern {
+        Pattern::Literal(pattern_literal) => {
+            Some(Constructor::Literal(pattern_literal.literal.value.clone()))
+        }
+        Pattern::Struct(_) | Pattern::Tuple(_) => Some(Constructor::Single),
+        Pattern::EnumVariant(pattern_enum_variant) => {
+            Some(Constructor::Variant(pattern_enum_variant.variant.clone()))
+        }
+        Pattern::Variable(_) | Pattern::Otherwise(_) => None,
+    }
+}
+
+/// Returns all the constructors of the type, or None if there are infinitely many (or too many to
+/// be practically enumerated, as in the case of numeric types).
+fn type_constructors(db: &dyn SemanticGroup, ty: TypeId) -> Option<Vec<Constructor>> {
+    match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => Some(
+            db.concrete_enum_variants(concrete_enum_id)
+                .ok()?
+                .into_iter()
+                .map(Constructor::Variant)
+                .collect(),
+        ),
+        TypeLongId::Concrete(ConcreteTypeId::Struct(_)) | TypeLongId::Tuple(_) => {
+            Some(vec![Constructor::Single])
+        }
+        _ => None,
+    }
+}
+
+/// Returns the types of the inner values of a value of type `ty` built with the constructor.
+fn constructor_inner_tys(db: &dyn SemanticGroup, ctor: &Constructor, ty: TypeId) -> Vec<TypeId> {
+    match ctor {
+        Constructor::Variant(variant) => vec![variant.ty],
+        Constructor::Literal(_) => vec![],
+        Constructor::Single => match db.lookup_intern_type(ty) {
+            TypeLongId::Tuple(tys) => tys,
+            TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => db
+                .concrete_struct_members(concrete_struct_id)
+                .map(|members| members.values().map(|member| member.ty).collect())
+                .unwrap_or_default(),
+            _ => vec![],
+        },
+    }
+}
+
+/// Specializes a pattern vector by a constructor, with the given inner types: if the first
+/// pattern matches values of the constructor, replaces it with its inner patterns. Otherwise,
+/// returns None.
+fn specialize<'a>(
+    db: &dyn SemanticGroup,
+    row: &[Option<&'a Pattern>],
+    ctor: &Constructor,
+    inner_tys: &[TypeId],
+) -> Option<Vec<Option<&'a Pattern>>> {
+    let (first, rest) = row.split_first()?;
+    let inner_patterns: Vec<Option<&Pattern>> = match first {
+        Some(pattern) if pattern_constructor(pattern).is_some() => {
+            if pattern_constructor(pattern).as_ref() != Some(ctor) {
+                return None;
+            }
+            match pattern {
+                Pattern::EnumVariant(pattern_enum_variant) => {
+                    vec![Some(&*pattern_enum_variant.inner_pattern)]
+                }
+                Pattern::Tuple(pattern_tuple) => {
+                    pattern_tuple.field_patterns.iter().map(|pattern| Some(&**pattern)).collect()
+                }
+                Pattern::Struct(pattern_struct) => {
+                    let members =
+                        db.concrete_struct_members(pattern_struct.concrete_struct_id).ok()?;
+                    members
+                        .values()
+                        .map(|member| {
+                            pattern_struct.field_patterns.iter().find_map(|(field, pattern)| {
+                                (field.id == member.id).then_some(&**pattern)
+                            })
+                        })
+                        .collect()
+                }
+                _ => vec![],
+            }
+        }
+        _ => vec![None; inner_tys.len()],
+    };
+    Some(inner_patterns.into_iter().chain(rest.iter().copied()).collect())
+}
+
+/// Computes up to `limit` witnesses of values matched by `row` and not matched by any of the rows
+/// of `matrix`. Each witness has an entry for every column. `tys` are the types of the columns.
+fn compute_witnesses(
+    db: &dyn SemanticGroup,
+    matrix: &[Vec<Option<&Pattern>>],
+    row: &[Option<&Pattern>],
+    tys: &[TypeId],
+    limit: usize,
+) -> Vec<Vec<Witness>> {
+    let Some((ty, rest_tys)) = tys.split_first() else {
+        return if matrix.is_empty() { vec![vec![]] } else { vec![] };
+    };
+    let first_ctor = |row: &Vec<Option<&Pattern>>| row[0].and_then(pattern_constructor);
+
+    // Computes the witnesses of a single constructor, by specializing the matrix and the row.
+    let specialized_witnesses = |ctor: &Constructor, limit: usize| -> Vec<Vec<Witness>> {
+        let inner_tys = constructor_inner_tys(db, ctor, *ty);
+        let Some(specialized_row) = specialize(db, row, ctor, &inner_tys) else {
+            return vec![];
+        };
+        let specialized_matrix =
+            matrix.iter().filter_map(|row| specialize(db, row, ctor, &inner_tys)).collect_vec();
+        let tys = inner_tys.iter().chain(rest_tys).copied().collect_vec();
+        compute_witnesses(db, &specialized_matrix, &specialized_row, &tys, limit)
+            .into_iter()
+            .map(|mut witness| {
+                let rest = witness.split_off(inner_tys.len());
+                let first = build_witness(db, ctor, *ty, witness);
+                [first].into_iter().chain(rest).collect()
+            })
+            .collect()
+    };
+
+    if let Some(ctor) = row[0].and_then(pattern_constructor) {
+        return specialized_witnesses(&ctor, limit);
+    }
+
+    let used_ctors = matrix.iter().filter_map(first_ctor).collect_vec();
+    // A value of a constructor that is not used is only matched by the rows with a wildcard in the
+    // first column.
+    let default_witnesses = |limit: usize| {
+        let default_matrix = matrix
+            .iter()
+            .filter(|row| first_ctor(row).is_none())
+            .map(|row| row[1..].to_vec())
+            .collect_vec();
+        compute_witnesses(db, &default_matrix, &row[1..], rest_tys, limit)
+    };
+    match type_constructors(db, *ty) {
+        Some(all_ctors) if !used_ctors.is_empty() || all_ctors.is_empty() => {
+            // Check each of the constructors of the type.
+            let mut witnesses = vec![];
+            let mut rest_witnesses = None;
+            for ctor in &all_ctors {
+                let remaining = limit - witnesses.len();
+                if used_ctors.contains(ctor) {
+                    witnesses.extend(specialized_witnesses(ctor, remaining));
+                } else {
+                    let inner_count = constructor_inner_tys(db, ctor, *ty).len();
+                    let inner = (0..inner_count).map(|_| Witness::Wildcard).collect_vec();
+                    let first = build_witness(db, ctor, *ty, inner);
+                    let rest_witnesses =
+                        rest_witnesses.get_or_insert_with(|| default_witnesses(limit));
+                    witnesses.extend(rest_witnesses.iter().take(remaining).map(|rest| {
+                        [first.clone()].into_iter().chain(rest.iter().cloned()).collect()
+                    }));
+                }
+                if witnesses.len() >= limit {
+                    break;
+                }
+            }
+            witnesses
+        }
+        _ => default_witnesses(limit)
+            .into_iter()
+            .map(|rest| [Witness::Wildcard].into_iter().chain(rest).collect())
+            .collect(),
+    }
+}
+
+/// Builds a witness of a value built with the constructor from the witnesses of its inner values.
+fn build_witness(
+    db: &dyn SemanticGroup,
+    ctor: &Constructor,
+    ty: TypeId,
+    inner: Vec<Witness>,
+) -> Witness {
+    match ctor {
+        Constructor::Variant(variant) => Witness::Variant(
+            variant.clone(),
+            Box::new(inner.into_iter().next().unwrap_or(Witness::Wildcard)),
+        ),
+        Constructor::Literal(_) => Witness::Wildcard,
+        Constructor::Single => match db.lookup_intern_type(ty) {
+            TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+                let names = db
+                    .concrete_struct_members(concrete_struct_id)
+                    .map(|members| members.keys().cloned().collect_vec())
+                    .unwrap_or_default();
+                Witness::Struct(concrete_struct_id, names.into_iter().zip(inner).collect())
+            }
+            _ => Witness::Tuple(inner),
+        },
+    }
+}
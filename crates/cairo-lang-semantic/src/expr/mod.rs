@@ -4,5 +4,7 @@ mod test;
 
 pub mod compute;
 pub mod fmt;
+pub mod inference;
+pub mod match_check;
 pub mod objects;
 pub mod pattern;
@@ -1,5 +1,5 @@
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_defs::ids::{MemberId, StructId, VarId};
+use cairo_lang_defs::ids::{MemberId, VarId};
 use cairo_lang_diagnostics::DiagnosticAdded;
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax::node::ast::{self};
@@ -8,7 +8,7 @@ use num_bigint::BigInt;
 
 use super::fmt::ExprFormatter;
 use super::pattern::Pattern;
-use crate::{semantic, FunctionId};
+use crate::{semantic, ConcreteStructId, FunctionId};
 
 pub type ExprId = Id<Expr>;
 pub type StatementId = Id<Statement>;
@@ -19,10 +19,7 @@ impl DebugWithDb<ExprFormatter<'_>> for ExprId {
         f: &mut std::fmt::Formatter<'_>,
         expr_formatter: &ExprFormatter<'_>,
     ) -> std::fmt::Result {
-        expr_formatter
-            .db
-            .expr_semantic(expr_formatter.free_function_id, *self)
-            .fmt(f, expr_formatter)
+        expr_formatter.db.expr_semantic(expr_formatter.function_id, *self).fmt(f, expr_formatter)
     }
 }
 impl DebugWithDb<ExprFormatter<'_>> for StatementId {
@@ -33,7 +30,7 @@ impl DebugWithDb<ExprFormatter<'_>> for StatementId {
     ) -> std::fmt::Result {
         expr_formatter
             .db
-            .statement_semantic(expr_formatter.free_function_id, *self)
+            .statement_semantic(expr_formatter.function_id, *self)
             .fmt(f, expr_formatter)
     }
 }
@@ -44,6 +41,8 @@ pub enum Statement {
     Expr(StatementExpr),
     Let(StatementLet),
     Return(StatementReturn),
+    Break(StatementBreak),
+    Continue(StatementContinue),
 }
 impl Statement {
     pub fn stable_ptr(&self) -> ast::StatementPtr {
@@ -51,6 +50,8 @@ impl Statement {
             Statement::Expr(stmt) => stmt.stable_ptr,
             Statement::Let(stmt) => stmt.stable_ptr,
             Statement::Return(stmt) => stmt.stable_ptr,
+            Statement::Break(stmt) => stmt.stable_ptr,
+            Statement::Continue(stmt) => stmt.stable_ptr,
         }
     }
 }
@@ -68,6 +69,9 @@ pub struct StatementExpr {
 pub struct StatementLet {
     pub pattern: Pattern,
     pub expr: ExprId,
+    /// The block of a `let ... else` statement, evaluated if the pattern does not match. It never
+    /// completes normally.
+    pub else_block: Option<ExprId>,
     #[hide_field_debug_with_db]
     pub stable_ptr: ast::StatementPtr,
 }
@@ -80,6 +84,22 @@ pub struct StatementReturn {
     pub stable_ptr: ast::StatementPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct StatementBreak {
+    /// The value the loop evaluates to, if given (`break expr;`).
+    pub expr_option: Option<ExprId>,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::StatementPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct StatementContinue {
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::StatementPtr,
+}
+
 // Expressions.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
@@ -90,9 +110,14 @@ pub enum Expr {
     FunctionCall(ExprFunctionCall),
     Match(ExprMatch),
     If(ExprIf),
+    IfLet(ExprIfLet),
+    Loop(ExprLoop),
+    While(ExprWhile),
+    Closure(ExprClosure),
     Var(ExprVar),
     Literal(ExprLiteral),
     MemberAccess(ExprMemberAccess),
+    TupleItem(ExprTupleItem),
     StructCtor(ExprStructCtor),
     EnumVariantCtor(ExprEnumVariantCtor),
     PropagateError(ExprPropagateError),
@@ -107,9 +132,14 @@ impl Expr {
             Expr::FunctionCall(expr) => expr.ty,
             Expr::Match(expr) => expr.ty,
             Expr::If(expr) => expr.ty,
+            Expr::IfLet(expr) => expr.ty,
+            Expr::Loop(expr) => expr.ty,
+            Expr::While(expr) => expr.ty,
+            Expr::Closure(expr) => expr.ty,
             Expr::Var(expr) => expr.ty,
             Expr::Literal(expr) => expr.ty,
             Expr::MemberAccess(expr) => expr.ty,
+            Expr::TupleItem(expr) => expr.ty,
             Expr::StructCtor(expr) => expr.ty,
             Expr::EnumVariantCtor(expr) => expr.ty,
             Expr::PropagateError(expr) => expr.ok_variant.ty,
@@ -124,9 +154,14 @@ impl Expr {
             Expr::FunctionCall(expr) => expr.stable_ptr,
             Expr::Match(expr) => expr.stable_ptr,
             Expr::If(expr) => expr.stable_ptr,
+            Expr::IfLet(expr) => expr.stable_ptr,
+            Expr::Loop(expr) => expr.stable_ptr,
+            Expr::While(expr) => expr.stable_ptr,
+            Expr::Closure(expr) => expr.stable_ptr,
             Expr::Var(expr) => expr.stable_ptr,
             Expr::Literal(expr) => expr.stable_ptr,
             Expr::MemberAccess(expr) => expr.stable_ptr,
+            Expr::TupleItem(expr) => expr.stable_ptr,
             Expr::StructCtor(expr) => expr.stable_ptr,
             Expr::EnumVariantCtor(expr) => expr.stable_ptr,
             Expr::PropagateError(expr) => expr.stable_ptr,
@@ -190,6 +225,53 @@ pub struct ExprIf {
     pub stable_ptr: ast::ExprPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprIfLet {
+    pub pattern: Pattern,
+    pub matched_expr: ExprId,
+    pub if_block: ExprId,
+    pub else_block: Option<ExprId>,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprLoop {
+    pub body: ExprId,
+    /// The type of the loop is the type of the values it breaks with, or never if it has no
+    /// `break`.
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprWhile {
+    pub condition: ExprId,
+    pub body: ExprId,
+    // ExprWhile is always of unit type.
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprClosure {
+    pub params: Vec<semantic::Parameter>,
+    pub body: ExprId,
+    /// The variables of the enclosing scopes used in the body, captured by value when the closure
+    /// is created.
+    pub captured_vars: Vec<VarId>,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct MatchArm {
@@ -201,6 +283,9 @@ pub struct MatchArm {
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprAssignment {
     pub var: VarId,
+    /// The (possibly nested) struct members and tuple items of `var` that are assigned to, from
+    /// the outermost to the innermost. Empty if `var` itself is assigned to.
+    pub members: Vec<AssignedMember>,
     pub rhs: semantic::ExprId,
     // ExprAssignment is always of unit type.
     pub ty: semantic::TypeId,
@@ -208,6 +293,14 @@ pub struct ExprAssignment {
     pub stable_ptr: ast::ExprPtr,
 }
 
+/// A struct member or a tuple item in the left hand side of an assignment.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub enum AssignedMember {
+    Struct { concrete_struct_id: ConcreteStructId, member: MemberId },
+    Tuple { index: usize },
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprVar {
@@ -230,17 +323,28 @@ pub struct ExprLiteral {
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprMemberAccess {
     pub expr: semantic::ExprId,
-    pub struct_id: StructId,
+    pub concrete_struct_id: ConcreteStructId,
     pub member: MemberId,
     pub ty: semantic::TypeId,
     #[hide_field_debug_with_db]
     pub stable_ptr: ast::ExprPtr,
 }
 
+/// An access to an item of a tuple (e.g. "expr.1").
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprTupleItem {
+    pub expr: semantic::ExprId,
+    pub index: usize,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprStructCtor {
-    pub struct_id: StructId,
+    pub concrete_struct_id: ConcreteStructId,
     pub members: Vec<(MemberId, ExprId)>,
     pub ty: semantic::TypeId,
     #[hide_field_debug_with_db]
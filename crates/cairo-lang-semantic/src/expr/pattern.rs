@@ -1,12 +1,9 @@
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_defs::ids::StructId;
 use cairo_lang_proc_macros::DebugWithDb;
 use smol_str::SmolStr;
 
 use super::fmt::ExprFormatter;
-use crate::corelib::core_felt_ty;
-use crate::db::SemanticGroup;
-use crate::{semantic, ExprLiteral, LocalVariable};
+use crate::{semantic, ConcreteStructId, ExprLiteral, LocalVariable};
 
 /// Semantic representation of a Pattern.
 /// A pattern is a way to "destructure" values. A pattern may introduce new variables that are bound
@@ -26,9 +23,9 @@ pub enum Pattern {
     Otherwise(PatternOtherwise),
 }
 impl Pattern {
-    pub fn ty(&self, db: &dyn SemanticGroup) -> semantic::TypeId {
+    pub fn ty(&self) -> semantic::TypeId {
         match self {
-            Pattern::Literal(_) => core_felt_ty(db),
+            Pattern::Literal(pattern_literal) => pattern_literal.ty,
             Pattern::Variable(variable) => variable.var.ty,
             Pattern::Struct(pattern_struct) => pattern_struct.ty,
             Pattern::Tuple(pattern_tuple) => pattern_tuple.ty,
@@ -81,7 +78,7 @@ impl DebugWithDb<ExprFormatter<'_>> for PatternVariable {
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct PatternStruct {
-    pub id: StructId,
+    pub concrete_struct_id: ConcreteStructId,
     // TODO(spapini): This should be ConcreteMember, when available.
     pub field_patterns: Vec<(semantic::Member, Box<Pattern>)>,
     pub ty: semantic::TypeId,
@@ -1,7 +1,7 @@
 use assert_matches::assert_matches;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::db::DefsGroup;
-use cairo_lang_defs::ids::{ModuleItemId, VarId};
+use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleItemId, VarId};
 use cairo_lang_utils::extract_matches;
 use indoc::indoc;
 use num_bigint::ToBigInt;
@@ -22,16 +22,21 @@ cairo_lang_test_utils::test_file_test!(
     "src/expr/test_data",
     {
         assignment: "assignment",
+        closure: "closure",
         enum_: "enum",
         error_propagate: "error_propagate",
         generics: "generics",
         if_: "if",
+        inference: "inference",
         let_statement: "let_statement",
         literal: "literal",
+        loop_: "loop",
         match_: "match",
+        method: "method",
         operators: "operators",
         pattern: "pattern",
         return_: "return",
+        visibility: "visibility",
     },
     test_function_diagnostics
 );
@@ -39,7 +44,11 @@ cairo_lang_test_utils::test_file_test!(
 #[test_case("7", 7, "felt")]
 #[test_case("0x123", 0x123, "felt")]
 #[test_case("12_felt", 12, "felt")]
+#[test_case("16_u8", 16, "u8")]
+#[test_case("16_u64", 16, "u64")]
 #[test_case("16_u128", 16, "u128")]
+#[test_case("16_i8", 16, "i8")]
+#[test_case("16_i128", 16, "i128")]
 #[test_case("0x16_u128", 0x16, "u128")]
 #[test_case("'a'", 0x61, "felt")]
 #[test_case("'B'_u128", 0x42, "u128")]
@@ -50,8 +59,9 @@ fn test_expr_literal(expr: &str, value: i128, ty_name: &str) {
     let mut db_val = SemanticDatabaseForTesting::default();
     let test_expr = setup_test_expr(&mut db_val, expr, "", "").unwrap();
     let db = &db_val;
-    let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr = db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), test_expr.expr_id);
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(test_expr.function_id) };
     // TODO(spapini): Currently, DebugWithDb can't "switch" dbs, and thus ExternTypeId is not
     // followed (it uses SyntaxGroup, and not SemanticGroup).
     // Fix this.
@@ -62,7 +72,11 @@ fn test_expr_literal(expr: &str, value: i128, ty_name: &str) {
             value,
             match ty_name {
                 "felt" => "felt",
+                "u8" => "integer::u8",
+                "u64" => "integer::u64",
                 "u128" => "integer::u128",
+                "i8" => "integer::i8",
+                "i128" => "integer::i128",
                 _ => unreachable!(),
             }
         )
@@ -81,15 +95,16 @@ fn test_expr_assignment() {
     let mut db_val = SemanticDatabaseForTesting::default();
     let test_expr = setup_test_expr(&mut db_val, "a = a * 3", "", "let mut a = 5;").unwrap();
     let db = &db_val;
-    let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr = db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), test_expr.expr_id);
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(test_expr.function_id) };
 
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
-        "Assignment(ExprAssignment { var: LocalVarId(test::a), rhs: FunctionCall(ExprFunctionCall \
-         { function: core::felt_mul, ref_args: [], args: [Var(ExprVar { var: LocalVarId(test::a), \
-         ty: core::felt }), Literal(ExprLiteral { value: 3, ty: core::felt })], ty: core::felt \
-         }), ty: () })"
+        "Assignment(ExprAssignment { var: LocalVarId(test::a), members: [], rhs: \
+         FunctionCall(ExprFunctionCall { function: core::FeltMul::mul, ref_args: [], args: \
+         [Var(ExprVar { var: LocalVarId(test::a), ty: core::felt }), Literal(ExprLiteral { value: \
+         3, ty: core::felt })], ty: core::felt }), ty: () })"
     );
 }
 
@@ -98,19 +113,20 @@ fn test_expr_operator() {
     let mut db_val = SemanticDatabaseForTesting::default();
     let test_expr = setup_test_expr(&mut db_val, "!(-5 + 9 * 3 == 0)", "", "").unwrap();
     let db = &db_val;
-    let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr = db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), test_expr.expr_id);
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(test_expr.function_id) };
 
     // TODO(spapini): Make transparent DebugWithDb attribute, to have better outputs.
     // TODO(spapini): Have better whitespaces here somehow.
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
-        "FunctionCall(ExprFunctionCall { function: core::bool_not, ref_args: [], args: \
-         [FunctionCall(ExprFunctionCall { function: core::felt_eq, ref_args: [], args: \
-         [FunctionCall(ExprFunctionCall { function: core::felt_add, ref_args: [], args: \
-         [FunctionCall(ExprFunctionCall { function: core::felt_neg, ref_args: [], args: \
+        "FunctionCall(ExprFunctionCall { function: core::BoolNot::not, ref_args: [], args: \
+         [FunctionCall(ExprFunctionCall { function: core::FeltPartialEq::eq, ref_args: [], args: \
+         [FunctionCall(ExprFunctionCall { function: core::FeltAdd::add, ref_args: [], args: \
+         [FunctionCall(ExprFunctionCall { function: core::FeltNeg::neg, ref_args: [], args: \
          [Literal(ExprLiteral { value: 5, ty: core::felt })], ty: core::felt }), \
-         FunctionCall(ExprFunctionCall { function: core::felt_mul, ref_args: [], args: \
+         FunctionCall(ExprFunctionCall { function: core::FeltMul::mul, ref_args: [], args: \
          [Literal(ExprLiteral { value: 9, ty: core::felt }), Literal(ExprLiteral { value: 3, ty: \
          core::felt })], ty: core::felt })], ty: core::felt }), Literal(ExprLiteral { value: 0, \
          ty: core::felt })], ty: core::bool })], ty: core::bool })"
@@ -145,9 +161,12 @@ fn test_member_access() {
         db.module_item_by_name(module_id, "foo".into()).unwrap().unwrap(),
         ModuleItemId::FreeFunction
     );
-    let expr_formatter = ExprFormatter { db, free_function_id: foo_id };
+    let expr_formatter = ExprFormatter { db, function_id: FunctionWithBodyId::Free(foo_id) };
     let block = extract_matches!(
-        db.expr_semantic(foo_id, db.free_function_definition_body(foo_id).unwrap()),
+        db.expr_semantic(
+            FunctionWithBodyId::Free(foo_id),
+            db.free_function_definition_body(foo_id).unwrap()
+        ),
         semantic::Expr::Block
     );
     let exprs: Vec<_> = block
@@ -157,9 +176,9 @@ fn test_member_access() {
             format!(
                 "{:?}",
                 db.expr_semantic(
-                    foo_id,
+                    FunctionWithBodyId::Free(foo_id),
                     extract_matches!(
-                        db.statement_semantic(foo_id, *stmt_id),
+                        db.statement_semantic(FunctionWithBodyId::Free(foo_id), *stmt_id),
                         semantic::Statement::Expr
                     )
                     .expr
@@ -172,17 +191,20 @@ fn test_member_access() {
         exprs,
         vec![
             "MemberAccess(ExprMemberAccess { expr: Var(ExprVar { var: ParamId(test::a), ty: \
-             test::A }), struct_id: StructId(test::A), member: MemberId(test::a), ty: \
-             (core::felt,) })",
+             test::A }), concrete_struct_id: ConcreteStructLongId { struct_id: StructId(test::A), \
+             generic_args: [] }, member: MemberId(test::a), ty: (core::felt,) })",
             "MemberAccess(ExprMemberAccess { expr: Var(ExprVar { var: ParamId(test::a), ty: \
-             test::A }), struct_id: StructId(test::A), member: MemberId(test::b), ty: core::felt \
-             })",
+             test::A }), concrete_struct_id: ConcreteStructLongId { struct_id: StructId(test::A), \
+             generic_args: [] }, member: MemberId(test::b), ty: core::felt })",
             "MemberAccess(ExprMemberAccess { expr: Var(ExprVar { var: ParamId(test::a), ty: \
-             test::A }), struct_id: StructId(test::A), member: MemberId(test::c), ty: test::B })",
+             test::A }), concrete_struct_id: ConcreteStructLongId { struct_id: StructId(test::A), \
+             generic_args: [] }, member: MemberId(test::c), ty: test::B })",
             "MemberAccess(ExprMemberAccess { expr: MemberAccess(ExprMemberAccess { expr: \
-             Var(ExprVar { var: ParamId(test::a), ty: test::A }), struct_id: StructId(test::A), \
-             member: MemberId(test::c), ty: test::B }), struct_id: StructId(test::B), member: \
-             MemberId(test::a), ty: core::felt })",
+             Var(ExprVar { var: ParamId(test::a), ty: test::A }), concrete_struct_id: \
+             ConcreteStructLongId { struct_id: StructId(test::A), generic_args: [] }, member: \
+             MemberId(test::c), ty: test::B }), concrete_struct_id: ConcreteStructLongId { \
+             struct_id: StructId(test::B), generic_args: [] }, member: MemberId(test::a), ty: \
+             core::felt })",
         ]
     );
 }
@@ -314,15 +336,17 @@ fn test_let_statement() {
     )
     .unwrap();
     let db = &db_val;
-    let expr = db.expr_semantic(test_function.function_id, test_function.body);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_function.function_id };
+    let expr =
+        db.expr_semantic(FunctionWithBodyId::Free(test_function.function_id), test_function.body);
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(test_function.function_id) };
 
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
         "Block(ExprBlock { statements: [Let(StatementLet { pattern: Variable(a), expr: \
-         Literal(ExprLiteral { value: 3, ty: core::felt }) }), Let(StatementLet { pattern: \
-         Variable(b), expr: Var(ExprVar { var: LocalVarId(test::a), ty: core::felt }) })], tail: \
-         None, ty: () })"
+         Literal(ExprLiteral { value: 3, ty: core::felt }), else_block: None }), Let(StatementLet \
+         { pattern: Variable(b), expr: Var(ExprVar { var: LocalVarId(test::a), ty: core::felt }), \
+         else_block: None })], tail: None, ty: () })"
     );
 }
 
@@ -369,13 +393,13 @@ fn test_expr_var() {
     let db = &db_val;
 
     let semantic::ExprBlock { statements: _, tail, ty: _, stable_ptr: _ } = extract_matches!(
-        db.expr_semantic(test_function.function_id, test_function.body),
+        db.expr_semantic(FunctionWithBodyId::Free(test_function.function_id), test_function.body),
         crate::Expr::Block
     );
 
     // Check expr.
     let semantic::ExprVar { var: _, ty: _, stable_ptr: _ } = extract_matches!(
-        db.expr_semantic(test_function.function_id, tail.unwrap()),
+        db.expr_semantic(FunctionWithBodyId::Free(test_function.function_id), tail.unwrap()),
         crate::Expr::Var,
         "Expected a variable."
     );
@@ -399,10 +423,10 @@ fn test_expr_var_failures() {
     assert_eq!(
         diagnostics,
         indoc! {"
-            error: Unsupported feature.
+            error: Identifier not found.
              --> lib.cairo:2:5
                 a::b;
-                ^**^
+                ^
 
         "}
     )
@@ -427,11 +451,12 @@ fn test_expr_match() {
     .unwrap();
     let db = &db_val;
     let semantic::ExprBlock { statements: _, tail, ty: _, stable_ptr: _ } = extract_matches!(
-        db.expr_semantic(test_function.function_id, test_function.body),
+        db.expr_semantic(FunctionWithBodyId::Free(test_function.function_id), test_function.body),
         crate::Expr::Block
     );
-    let expr = db.expr_semantic(test_function.function_id, tail.unwrap());
-    let expr_formatter = ExprFormatter { db, free_function_id: test_function.function_id };
+    let expr = db.expr_semantic(FunctionWithBodyId::Free(test_function.function_id), tail.unwrap());
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(test_function.function_id) };
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
         "Match(ExprMatch { matched_expr: Var(ExprVar { var: ParamId(test::a), ty: core::felt }), \
@@ -476,7 +501,7 @@ fn test_expr_block() {
     let mut db_val = SemanticDatabaseForTesting::default();
     let test_expr = setup_test_expr(&mut db_val, "{6;8;}", "", "").unwrap();
     let db = &db_val;
-    let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
+    let expr = db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), test_expr.expr_id);
 
     // Check expr.
     let semantic::ExprBlock { statements, tail, ty, stable_ptr: _ } =
@@ -486,8 +511,10 @@ fn test_expr_block() {
 
     match statements[..] {
         [stmt_id0, stmt_id1] => {
-            let stmt0 = db.statement_semantic(test_expr.function_id, stmt_id0);
-            let stmt1 = db.statement_semantic(test_expr.function_id, stmt_id1);
+            let stmt0 =
+                db.statement_semantic(FunctionWithBodyId::Free(test_expr.function_id), stmt_id0);
+            let stmt1 =
+                db.statement_semantic(FunctionWithBodyId::Free(test_expr.function_id), stmt_id1);
             assert_matches!(stmt0, semantic::Statement::Expr(_));
             assert_matches!(stmt1, semantic::Statement::Expr(_));
         }
@@ -500,7 +527,7 @@ fn test_expr_block_with_tail_expression() {
     let mut db_val = SemanticDatabaseForTesting::default();
     let test_expr = setup_test_expr(&mut db_val, "{6;8;9}", "", "").unwrap();
     let db = &db_val;
-    let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
+    let expr = db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), test_expr.expr_id);
 
     // Check expr.
     let semantic::ExprBlock { statements, tail, ty, stable_ptr: _ } =
@@ -509,7 +536,7 @@ fn test_expr_block_with_tail_expression() {
 
     // Check tail expression.
     let semantic::ExprLiteral { value, ty: _, stable_ptr: _ } = extract_matches!(
-        db.expr_semantic(test_expr.function_id, tail.unwrap()),
+        db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), tail.unwrap()),
         crate::Expr::Literal,
         "Expected a literal expression."
     );
@@ -518,8 +545,10 @@ fn test_expr_block_with_tail_expression() {
     // Check statements.
     match statements[..] {
         [stmt_id0, stmt_id1] => {
-            let stmt0 = db.statement_semantic(test_expr.function_id, stmt_id0);
-            let stmt1 = db.statement_semantic(test_expr.function_id, stmt_id1);
+            let stmt0 =
+                db.statement_semantic(FunctionWithBodyId::Free(test_expr.function_id), stmt_id0);
+            let stmt1 =
+                db.statement_semantic(FunctionWithBodyId::Free(test_expr.function_id), stmt_id1);
             assert_matches!(stmt0, semantic::Statement::Expr(_));
             assert_matches!(stmt1, semantic::Statement::Expr(_));
         }
@@ -533,7 +562,7 @@ fn test_expr_call() {
     // TODO(spapini): Add types.
     let test_expr = setup_test_expr(&mut db_val, "foo()", "fn foo() {6;}", "").unwrap();
     let db = &db_val;
-    let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
+    let expr = db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), test_expr.expr_id);
 
     // Check expr.
     let semantic::ExprFunctionCall { ref_args, args, ty, .. } =
@@ -549,7 +578,8 @@ fn test_expr_call_failures() {
     // TODO(spapini): Add types.
     let (test_expr, diagnostics) = setup_test_expr(&mut db_val, "foo()", "", "").split();
     let db = &db_val;
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(test_expr.function_id) };
 
     // Check expr.
     assert_eq!(
@@ -566,7 +596,8 @@ fn test_expr_call_failures() {
     assert_eq!(
         format!(
             "{:?}",
-            db.expr_semantic(test_expr.function_id, test_expr.expr_id).debug(&expr_formatter)
+            db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), test_expr.expr_id)
+                .debug(&expr_formatter)
         ),
         "Missing(ExprMissing { ty: <missing> })"
     );
@@ -594,15 +625,18 @@ fn test_function_body() {
 
     // Test the resulting semantic function body.
     let semantic::ExprBlock { statements, .. } = extract_matches!(
-        db.expr_semantic(test_function.function_id, body),
+        db.expr_semantic(FunctionWithBodyId::Free(test_function.function_id), body),
         crate::Expr::Block,
         "Expected a block."
     );
     assert_eq!(statements.len(), 1);
     let expr = db.expr_semantic(
-        test_function.function_id,
+        FunctionWithBodyId::Free(test_function.function_id),
         extract_matches!(
-            db.statement_semantic(test_function.function_id, statements[0]),
+            db.statement_semantic(
+                FunctionWithBodyId::Free(test_function.function_id),
+                statements[0]
+            ),
             crate::Statement::Expr
         )
         .expr,
@@ -630,13 +664,15 @@ fn test_expr_struct_ctor() {
     )
     .unwrap();
     let db = &db_val;
-    let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr = db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), test_expr.expr_id);
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(test_expr.function_id) };
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
-        "StructCtor(ExprStructCtor { struct_id: StructId(test::A), members: [(MemberId(test::a), \
-         Literal(ExprLiteral { value: 1, ty: core::felt })), (MemberId(test::b), Var(ExprVar { \
-         var: LocalVarId(test::b), ty: core::felt }))], ty: test::A })"
+        "StructCtor(ExprStructCtor { concrete_struct_id: ConcreteStructLongId { struct_id: \
+         StructId(test::A), generic_args: [] }, members: [(MemberId(test::a), Literal(ExprLiteral \
+         { value: 1, ty: core::felt })), (MemberId(test::b), Var(ExprVar { var: \
+         LocalVarId(test::b), ty: core::felt }))], ty: test::A })"
     );
 }
 
@@ -645,11 +681,12 @@ fn test_expr_tuple() {
     let mut db_val = SemanticDatabaseForTesting::default();
     let test_expr = setup_test_expr(&mut db_val, "(1 + 2, (2, 3))", "", "").unwrap();
     let db = &db_val;
-    let expr = db.expr_semantic(test_expr.function_id, test_expr.expr_id);
-    let expr_formatter = ExprFormatter { db, free_function_id: test_expr.function_id };
+    let expr = db.expr_semantic(FunctionWithBodyId::Free(test_expr.function_id), test_expr.expr_id);
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(test_expr.function_id) };
     assert_eq!(
         format!("{:?}", expr.debug(&expr_formatter)),
-        "Tuple(ExprTuple { items: [FunctionCall(ExprFunctionCall { function: core::felt_add, \
+        "Tuple(ExprTuple { items: [FunctionCall(ExprFunctionCall { function: core::FeltAdd::add, \
          ref_args: [], args: [Literal(ExprLiteral { value: 1, ty: core::felt }), \
          Literal(ExprLiteral { value: 2, ty: core::felt })], ty: core::felt }), Tuple(ExprTuple { \
          items: [Literal(ExprLiteral { value: 2, ty: core::felt }), Literal(ExprLiteral { value: \
@@ -0,0 +1,284 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::ids::{ConstantId, LanguageElementId};
+use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
+use cairo_lang_proc_macros::DebugWithDb;
+use cairo_lang_syntax::node::{ast, Terminal};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+
+use crate::corelib::{
+    core_bool_ty, core_felt_ty, try_get_const_libfunc_name_by_type, try_get_core_integer_type_name,
+};
+use crate::db::SemanticGroup;
+use crate::diagnostic::SemanticDiagnosticKind::*;
+use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
+use crate::expr::compute::{literal_to_semantic, short_string_to_semantic};
+use crate::resolve_path::{ResolvedConcreteItem, ResolvedLookback, Resolver};
+use crate::types::resolve_type;
+use crate::{SemanticDiagnostic, TypeId};
+
+/// The compile time value of a constant item.
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct ConstantValue {
+    /// The numeric value of the constant. For `bool` constants, this is 0 or 1.
+    pub value: BigInt,
+    pub ty: TypeId,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct ConstantData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    value: Maybe<ConstantValue>,
+    resolved_lookback: Arc<ResolvedLookback>,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_constant_semantic_data].
+pub fn priv_constant_semantic_data(
+    db: &dyn SemanticGroup,
+    constant_id: ConstantId,
+) -> Maybe<ConstantData> {
+    let module_file_id = constant_id.module_file(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let module_constants = db.module_constants(module_file_id.0)?;
+    let constant_ast = module_constants.get(&constant_id).to_maybe()?;
+    let syntax_db = db.upcast();
+    let mut resolver = Resolver::new(db, module_file_id, &[]);
+    let ty = resolve_type(
+        db,
+        &mut diagnostics,
+        &mut resolver,
+        &constant_ast.type_clause(syntax_db).ty(syntax_db),
+    );
+    let value_syntax = constant_ast.value(syntax_db);
+    let value = evaluate_constant_expr(db, &mut diagnostics, &mut resolver, &value_syntax, ty)
+        .and_then(|value| {
+            if ty.is_missing(db) || value.ty == ty {
+                Ok(value)
+            } else {
+                Err(diagnostics.report(
+                    &value_syntax,
+                    WrongArgumentType { expected_ty: ty, actual_ty: value.ty },
+                ))
+            }
+        });
+    let resolved_lookback = Arc::new(resolver.lookback);
+    Ok(ConstantData { diagnostics: diagnostics.build(), value, resolved_lookback })
+}
+
+/// Cycle handling for [crate::db::SemanticGroup::priv_constant_semantic_data].
+pub fn priv_constant_semantic_data_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    constant_id: &ConstantId,
+) -> Maybe<ConstantData> {
+    let module_file_id = constant_id.module_file(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let module_constants = db.module_constants(module_file_id.0)?;
+    let constant_ast = module_constants.get(constant_id).to_maybe()?;
+    let syntax_db = db.upcast();
+    let value = Err(diagnostics.report(&constant_ast.name(syntax_db), ConstantCycle));
+    Ok(ConstantData {
+        diagnostics: diagnostics.build(),
+        value,
+        resolved_lookback: Arc::new(ResolvedLookback::default()),
+    })
+}
+
+/// Evaluates the value expression of a constant at compile time.
+/// Only literals, `true`/`false`, unary and binary operators and references to other constants
+/// are supported. Literals without a suffix take the type `expected_ty` if it has literals, e.g.
+/// the declared type of the constant.
+fn evaluate_constant_expr(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    resolver: &mut Resolver<'_>,
+    syntax: &ast::Expr,
+    expected_ty: TypeId,
+) -> Maybe<ConstantValue> {
+    let syntax_db = db.upcast();
+    let value = match syntax {
+        ast::Expr::Literal(literal_syntax) => {
+            evaluate_constant_literal(db, diagnostics, literal_syntax, expected_ty)?
+        }
+        ast::Expr::ShortString(short_string_syntax) => {
+            let literal = short_string_to_semantic(db, diagnostics, short_string_syntax)?;
+            ConstantValue { value: literal.value, ty: literal.ty }
+        }
+        ast::Expr::False(_) => ConstantValue { value: BigInt::zero(), ty: core_bool_ty(db) },
+        ast::Expr::True(_) => ConstantValue { value: BigInt::from(1), ty: core_bool_ty(db) },
+        ast::Expr::Parenthesized(paren_syntax) => {
+            let inner_syntax = paren_syntax.expr(syntax_db);
+            return evaluate_constant_expr(db, diagnostics, resolver, &inner_syntax, expected_ty);
+        }
+        ast::Expr::Unary(unary_syntax) => {
+            let inner = match unary_syntax.expr(syntax_db) {
+                // The bounds are checked after the negation, e.g. for `-128` of type `i8`.
+                ast::Expr::Literal(literal_syntax) => {
+                    evaluate_constant_literal(db, diagnostics, &literal_syntax, expected_ty)?
+                }
+                inner_syntax => {
+                    evaluate_constant_expr(db, diagnostics, resolver, &inner_syntax, expected_ty)?
+                }
+            };
+            let is_bool = inner.ty == core_bool_ty(db);
+            match unary_syntax.op(syntax_db) {
+                ast::UnaryOperator::Minus(_) if !is_bool => {
+                    ConstantValue { value: -inner.value, ty: inner.ty }
+                }
+                ast::UnaryOperator::Not(_) if is_bool => {
+                    ConstantValue { value: BigInt::from(inner.value.is_zero() as u8), ty: inner.ty }
+                }
+                _ => return Err(diagnostics.report(syntax, UnsupportedConstant)),
+            }
+        }
+        ast::Expr::Binary(binary_syntax) => {
+            let op = binary_syntax.op(syntax_db);
+            let is_comparison = matches!(
+                op,
+                ast::BinaryOperator::EqEq(_)
+                    | ast::BinaryOperator::Neq(_)
+                    | ast::BinaryOperator::LT(_)
+                    | ast::BinaryOperator::GT(_)
+                    | ast::BinaryOperator::LE(_)
+                    | ast::BinaryOperator::GE(_)
+            );
+            // The operands of a comparison do not take the type of its result.
+            let lhs_expected_ty = if is_comparison { core_felt_ty(db) } else { expected_ty };
+            let lhs_syntax = binary_syntax.lhs(syntax_db);
+            let lhs =
+                evaluate_constant_expr(db, diagnostics, resolver, &lhs_syntax, lhs_expected_ty)?;
+            let rhs_syntax = binary_syntax.rhs(syntax_db);
+            let rhs = evaluate_constant_expr(db, diagnostics, resolver, &rhs_syntax, lhs.ty)?;
+            if lhs.ty != rhs.ty {
+                return Err(diagnostics.report(
+                    &rhs_syntax,
+                    WrongArgumentType { expected_ty: lhs.ty, actual_ty: rhs.ty },
+                ));
+            }
+            evaluate_constant_binary_op(db, diagnostics, syntax, op, lhs, rhs)?
+        }
+        ast::Expr::Path(path) => {
+            match resolver.resolve_concrete_path(diagnostics, path, NotFoundItemType::Identifier)? {
+                // Use the private query directly, for cycle detection.
+                ResolvedConcreteItem::Constant(constant_id) => {
+                    db.priv_constant_semantic_data(constant_id)?.value?
+                }
+                _ => return Err(diagnostics.report(path, UnsupportedConstant)),
+            }
+        }
+        _ => return Err(diagnostics.report(syntax, UnsupportedConstant)),
+    };
+    if let Some((min, max)) = integer_type_bounds(db, value.ty) {
+        if value.value < min || value.value > max {
+            return Err(diagnostics.report(syntax, ConstantOutOfRange { ty: value.ty }));
+        }
+    }
+    Ok(value)
+}
+
+/// Evaluates a literal in a constant, without checking the bounds of its type. A literal without a
+/// suffix takes the type `expected_ty` if it has literals.
+fn evaluate_constant_literal(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    literal_syntax: &ast::TerminalLiteralNumber,
+    expected_ty: TypeId,
+) -> Maybe<ConstantValue> {
+    let literal = literal_to_semantic(db, diagnostics, literal_syntax)?;
+    let has_suffix = literal_syntax.text(db.upcast()).contains('_');
+    let ty = if !has_suffix && try_get_const_libfunc_name_by_type(db, expected_ty).is_ok() {
+        expected_ty
+    } else {
+        literal.ty
+    };
+    Ok(ConstantValue { value: literal.value, ty })
+}
+
+/// Evaluates a binary operator on the values of two constants of the same type.
+fn evaluate_constant_binary_op(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    syntax: &ast::Expr,
+    op: ast::BinaryOperator,
+    lhs: ConstantValue,
+    rhs: ConstantValue,
+) -> Maybe<ConstantValue> {
+    let ty = lhs.ty;
+    let bool_ty = core_bool_ty(db);
+    let is_integer = try_get_core_integer_type_name(db, ty).is_some();
+    let is_numeric = is_integer || ty == core_felt_ty(db);
+    let bool_value = |value: bool| ConstantValue { value: BigInt::from(value as u8), ty: bool_ty };
+    Ok(match op {
+        ast::BinaryOperator::Plus(_) if is_numeric => {
+            ConstantValue { value: lhs.value + rhs.value, ty }
+        }
+        ast::BinaryOperator::Minus(_) if is_numeric => {
+            ConstantValue { value: lhs.value - rhs.value, ty }
+        }
+        ast::BinaryOperator::Mul(_) if is_numeric => {
+            ConstantValue { value: lhs.value * rhs.value, ty }
+        }
+        ast::BinaryOperator::Div(_) | ast::BinaryOperator::Mod(_) if is_integer => {
+            if rhs.value.is_zero() {
+                return Err(diagnostics.report(syntax, ConstantDivisionByZero));
+            }
+            // Rounds towards zero, as the integer division of the core library.
+            let value = if matches!(op, ast::BinaryOperator::Div(_)) {
+                lhs.value / rhs.value
+            } else {
+                lhs.value % rhs.value
+            };
+            ConstantValue { value, ty }
+        }
+        ast::BinaryOperator::And(_) if ty == bool_ty => {
+            bool_value(!lhs.value.is_zero() && !rhs.value.is_zero())
+        }
+        ast::BinaryOperator::Or(_) if ty == bool_ty => {
+            bool_value(!lhs.value.is_zero() || !rhs.value.is_zero())
+        }
+        ast::BinaryOperator::Xor(_) if ty == bool_ty => bool_value(lhs.value != rhs.value),
+        ast::BinaryOperator::EqEq(_) => bool_value(lhs.value == rhs.value),
+        ast::BinaryOperator::Neq(_) => bool_value(lhs.value != rhs.value),
+        ast::BinaryOperator::LT(_) if is_integer => bool_value(lhs.value < rhs.value),
+        ast::BinaryOperator::GT(_) if is_integer => bool_value(lhs.value > rhs.value),
+        ast::BinaryOperator::LE(_) if is_integer => bool_value(lhs.value <= rhs.value),
+        ast::BinaryOperator::GE(_) if is_integer => bool_value(lhs.value >= rhs.value),
+        _ => return Err(diagnostics.report(syntax, UnsupportedConstant)),
+    })
+}
+
+/// Returns the minimal and maximal values of the given type, if it is one of the integer types of
+/// the core library.
+fn integer_type_bounds(db: &dyn SemanticGroup, ty: TypeId) -> Option<(BigInt, BigInt)> {
+    let type_name = try_get_core_integer_type_name(db, ty)?;
+    let bits: usize = type_name[1..].parse().ok()?;
+    Some(if type_name.starts_with('i') {
+        (-(BigInt::one() << (bits - 1)), (BigInt::one() << (bits - 1)) - 1)
+    } else {
+        (BigInt::zero(), (BigInt::one() << bits) - 1)
+    })
+}
+
+/// Query implementation of [crate::db::SemanticGroup::constant_semantic_diagnostics].
+pub fn constant_semantic_diagnostics(
+    db: &dyn SemanticGroup,
+    constant_id: ConstantId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_constant_semantic_data(constant_id).map(|data| data.diagnostics).unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::constant_value].
+pub fn constant_value(db: &dyn SemanticGroup, constant_id: ConstantId) -> Maybe<ConstantValue> {
+    db.priv_constant_semantic_data(constant_id)?.value
+}
+
+/// Query implementation of [crate::db::SemanticGroup::constant_resolved_lookback].
+pub fn constant_resolved_lookback(
+    db: &dyn SemanticGroup,
+    constant_id: ConstantId,
+) -> Maybe<Arc<ResolvedLookback>> {
+    Ok(db.priv_constant_semantic_data(constant_id)?.resolved_lookback)
+}
@@ -7,6 +7,7 @@ use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_utils::extract_matches;
 
+use super::attribute::{ast_attributes_to_semantic, Attribute};
 use super::generics::semantic_generic_params;
 use crate::corelib::get_core_generic_function_id;
 use crate::db::SemanticGroup;
@@ -27,6 +28,7 @@ pub struct ExternFunctionDeclarationData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
     signature: semantic::Signature,
     generic_params: Vec<GenericParamId>,
+    attributes: Vec<Attribute>,
     resolved_lookback: Arc<ResolvedLookback>,
 }
 
@@ -55,6 +57,13 @@ pub fn extern_function_declaration_generic_params(
 ) -> Maybe<Vec<GenericParamId>> {
     Ok(db.priv_extern_function_declaration_data(extern_function_id)?.generic_params)
 }
+/// Query implementation of [crate::db::SemanticGroup::extern_function_declaration_attributes].
+pub fn extern_function_declaration_attributes(
+    db: &dyn SemanticGroup,
+    extern_function_id: ExternFunctionId,
+) -> Maybe<Vec<Attribute>> {
+    Ok(db.priv_extern_function_declaration_data(extern_function_id)?.attributes)
+}
 /// Query implementation of [crate::db::SemanticGroup::extern_function_declaration_implicits].
 pub fn extern_function_declaration_implicits(
     db: &dyn SemanticGroup,
@@ -126,11 +135,13 @@ pub fn priv_extern_function_declaration_data(
         }
     }
 
+    let attributes = ast_attributes_to_semantic(syntax_db, function_syntax.attributes(syntax_db));
     let resolved_lookback = Arc::new(resolver.lookback);
     Ok(ExternFunctionDeclarationData {
         diagnostics: diagnostics.build(),
         signature,
         generic_params,
+        attributes,
         resolved_lookback,
     })
 }
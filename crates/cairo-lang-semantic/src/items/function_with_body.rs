@@ -0,0 +1,382 @@
+use std::collections::{HashMap, HashSet};
+use std::iter::zip;
+use std::sync::Arc;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, GenericParamId, LanguageElementId, ModuleFileId};
+use cairo_lang_diagnostics::{skip_diagnostic, Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe};
+use cairo_lang_proc_macros::DebugWithDb;
+use cairo_lang_syntax::node::ast;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use cairo_lang_utils::{try_extract_matches, OptionFrom, Upcast};
+use id_arena::Arena;
+
+use crate::corelib::never_ty;
+use crate::db::SemanticGroup;
+use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
+use crate::expr::compute::{
+    check_array_slice_calls, compute_expr_block_semantic, resolve_trait_function_calls,
+    ComputationContext, Environment,
+};
+use crate::resolve_path::{ResolvedLookback, Resolver};
+use crate::substitution::{GenericSubstitution, SemanticRewriter};
+use crate::types::generic_args_fully_concrete;
+use crate::{
+    semantic, ConcreteFunction, Expr, ExprId, FunctionId, FunctionLongId, GenericArgumentId,
+    SemanticDiagnostic, TypeLongId,
+};
+
+// === Definition ===
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct FunctionDefinitionData {
+    pub diagnostics: Diagnostics<SemanticDiagnostic>,
+    pub expr_lookup: UnorderedHashMap<ast::ExprPtr, ExprId>,
+    pub resolved_lookback: Arc<ResolvedLookback>,
+    pub definition: Arc<FunctionDefinition>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct FunctionDefinition {
+    pub exprs: Arena<semantic::Expr>,
+    pub statements: Arena<semantic::Statement>,
+    pub body: semantic::ExprId,
+    /// The set of direct callees of the function (user functions and libfuncs that are called
+    /// from this function). The items in the vector are unique.
+    pub direct_callees: Vec<FunctionId>,
+}
+
+// --- Selectors ---
+
+/// Query implementation of [crate::db::SemanticGroup::function_with_body_signature].
+pub fn function_with_body_signature(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<semantic::Signature> {
+    match function_id {
+        FunctionWithBodyId::Free(free_function_id) => {
+            db.free_function_declaration_signature(free_function_id)
+        }
+        FunctionWithBodyId::Impl(impl_function_id) => db.impl_function_signature(impl_function_id),
+    }
+}
+
+/// Query implementation of [crate::db::SemanticGroup::function_with_body_generic_params].
+pub fn function_with_body_generic_params(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<Vec<GenericParamId>> {
+    match function_id {
+        FunctionWithBodyId::Free(free_function_id) => {
+            db.free_function_declaration_generic_params(free_function_id)
+        }
+        FunctionWithBodyId::Impl(impl_function_id) => {
+            db.impl_function_generic_params(impl_function_id)
+        }
+    }
+}
+
+/// Query implementation of [crate::db::SemanticGroup::function_with_body_diagnostics].
+pub fn function_with_body_diagnostics(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Diagnostics<SemanticDiagnostic> {
+    let mut diagnostics = DiagnosticsBuilder::default();
+    match function_id {
+        FunctionWithBodyId::Free(free_function_id) => {
+            diagnostics.extend(db.free_function_declaration_diagnostics(free_function_id));
+            diagnostics.extend(db.free_function_definition_diagnostics(free_function_id));
+        }
+        FunctionWithBodyId::Impl(impl_function_id) => {
+            diagnostics.extend(db.impl_function_declaration_diagnostics(impl_function_id));
+            diagnostics.extend(db.impl_function_definition_diagnostics(impl_function_id));
+        }
+    }
+    diagnostics.build()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::function_with_body_definition].
+pub fn function_with_body_definition(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<Arc<FunctionDefinition>> {
+    Ok(function_definition_data(db, function_id)?.definition)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::function_with_body_direct_callees].
+pub fn function_with_body_direct_callees(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<Vec<FunctionId>> {
+    Ok(db.function_with_body_definition(function_id)?.direct_callees.clone())
+}
+
+/// Query implementation of
+/// [crate::db::SemanticGroup::function_with_body_direct_function_with_body_callees].
+pub fn function_with_body_direct_function_with_body_callees(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<Vec<FunctionWithBodyId>> {
+    Ok(db
+        .function_with_body_direct_callees(function_id)?
+        .into_iter()
+        .filter_map(|function_id| {
+            FunctionWithBodyId::option_from(
+                db.lookup_intern_function(function_id).function.generic_function,
+            )
+        })
+        .collect())
+}
+
+/// The definition of a function with a body, specialized with concrete generic arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConcreteFunctionDefinitionData {
+    /// The diagnostics of resolving the calls to trait functions in the specialized function.
+    pub diagnostics: Diagnostics<SemanticDiagnostic>,
+    pub definition: Arc<FunctionDefinition>,
+}
+
+/// Query implementation of
+/// [crate::db::SemanticGroup::priv_concrete_function_with_body_definition_data].
+pub fn priv_concrete_function_with_body_definition_data(
+    db: &dyn SemanticGroup,
+    function_id: FunctionId,
+) -> Maybe<ConcreteFunctionDefinitionData> {
+    let ConcreteFunction { generic_function, generic_args } =
+        db.lookup_intern_function(function_id).function;
+    let function_with_body_id = FunctionWithBodyId::option_from(generic_function).to_maybe()?;
+    let definition = db.function_with_body_definition(function_with_body_id)?;
+    let generic_params = db.function_with_body_generic_params(function_with_body_id)?;
+    if generic_params.len() != generic_args.len() {
+        return Err(skip_diagnostic());
+    }
+    let substitution: HashMap<_, _> = zip(generic_params, generic_args).collect();
+    if substitution.iter().all(|(param, arg)| {
+        *arg == GenericArgumentId::Type(db.intern_type(TypeLongId::GenericParameter(*param)))
+    }) {
+        return Ok(ConcreteFunctionDefinitionData { diagnostics: Diagnostics::new(), definition });
+    }
+
+    let rewriter = GenericSubstitution::new(db, &substitution);
+    let mut definition = (*definition).clone();
+    for (_id, expr) in definition.exprs.iter_mut() {
+        rewriter.rewrite_expr(expr);
+    }
+    for (_id, statement) in definition.statements.iter_mut() {
+        rewriter.rewrite_statement(statement);
+    }
+    // Resolve the calls to the trait functions of the generic params, now that they are
+    // substituted.
+    let module_file_id = function_with_body_id.module_file(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    resolve_trait_function_calls(db, &mut diagnostics, module_file_id.0, &mut definition.exprs)?;
+    check_array_slice_calls(db, &mut diagnostics, module_file_id.0, &definition.exprs)?;
+    let direct_callees: HashSet<FunctionId> = definition
+        .exprs
+        .iter()
+        .filter_map(|(_id, expr)| try_extract_matches!(expr, Expr::FunctionCall))
+        .map(|f| f.function)
+        .collect();
+    definition.direct_callees = direct_callees.into_iter().collect();
+    Ok(ConcreteFunctionDefinitionData {
+        diagnostics: diagnostics.build(),
+        definition: Arc::new(definition),
+    })
+}
+
+/// Query implementation of [crate::db::SemanticGroup::concrete_function_with_body_definition].
+pub fn concrete_function_with_body_definition(
+    db: &dyn SemanticGroup,
+    function_id: FunctionId,
+) -> Maybe<Arc<FunctionDefinition>> {
+    // The trait function calls that could not be resolved are kept, and their diagnostics are
+    // reported at the calls to the function, see [concrete_function_specialization_diagnostic].
+    Ok(db.priv_concrete_function_with_body_definition_data(function_id)?.definition)
+}
+
+/// Returns the first diagnostic of specializing the given concrete function, or the generic
+/// functions it transitively calls, with their generic arguments, if any.
+pub fn concrete_function_specialization_diagnostic(
+    db: &dyn SemanticGroup,
+    function_id: FunctionId,
+) -> Maybe<Option<SemanticDiagnostic>> {
+    let mut visited = HashSet::new();
+    let mut stack = vec![function_id];
+    while let Some(function_id) = stack.pop() {
+        if !visited.insert(function_id) {
+            continue;
+        }
+        let ConcreteFunction { generic_function, generic_args } =
+            db.lookup_intern_function(function_id).function;
+        // Non-generic functions are checked on their own, and calls that depend on generic params
+        // are checked once these are specialized.
+        if FunctionWithBodyId::option_from(generic_function).is_none()
+            || generic_args.is_empty()
+            || !generic_args_fully_concrete(db, &generic_args)
+        {
+            continue;
+        }
+        let data = db.priv_concrete_function_with_body_definition_data(function_id)?;
+        if let Some(diagnostic) = data.diagnostics.get_all().into_iter().next() {
+            return Ok(Some(diagnostic));
+        }
+        stack.extend(data.definition.direct_callees.iter().copied());
+    }
+    Ok(None)
+}
+
+/// Query implementation of
+/// [crate::db::SemanticGroup::concrete_function_with_body_direct_callees].
+pub fn concrete_function_with_body_direct_callees(
+    db: &dyn SemanticGroup,
+    function_id: FunctionId,
+) -> Maybe<Vec<FunctionId>> {
+    Ok(db.concrete_function_with_body_definition(function_id)?.direct_callees.clone())
+}
+
+/// Returns the concrete function of a function with a body, with its own generic parameters as
+/// generic arguments. For a non-generic function, this is its only concrete function.
+pub fn function_with_body_function_id(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<FunctionId> {
+    let generic_args = db
+        .function_with_body_generic_params(function_id)?
+        .into_iter()
+        .map(|param| GenericArgumentId::Type(db.intern_type(TypeLongId::GenericParameter(param))))
+        .collect();
+    Ok(db.intern_function(FunctionLongId {
+        function: ConcreteFunction { generic_function: function_id.into(), generic_args },
+    }))
+}
+
+/// Returns the definition data of a function with a body.
+fn function_definition_data(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<FunctionDefinitionData> {
+    match function_id {
+        FunctionWithBodyId::Free(free_function_id) => {
+            db.priv_free_function_definition_data(free_function_id)
+        }
+        FunctionWithBodyId::Impl(impl_function_id) => {
+            db.priv_impl_function_definition_data(impl_function_id)
+        }
+    }
+}
+
+// --- Computation ---
+
+/// Computes the semantic model of the body of a function, given the semantic model of its
+/// declaration.
+pub fn compute_function_definition_data(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+    module_file_id: ModuleFileId,
+    signature: &semantic::Signature,
+    generic_params: &[GenericParamId],
+    environment: Environment,
+    body_syntax: &ast::ExprBlock,
+) -> Maybe<FunctionDefinitionData> {
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id);
+    let resolver = Resolver::new_with_inference(db, module_file_id, generic_params);
+    // Compute body semantic expr.
+    let mut ctx = ComputationContext::new(
+        db,
+        &mut diagnostics,
+        resolver,
+        signature,
+        environment,
+        function_with_body_function_id(db, function_id)?,
+    );
+    let expr = compute_expr_block_semantic(&mut ctx, body_syntax)?;
+    let expr_ty = expr.ty();
+    let signature_return_ty = signature.return_type;
+    if !expr_ty.is_missing(db)
+        && !signature_return_ty.is_missing(db)
+        && expr_ty != never_ty(db)
+        && ctx.resolver.inference.conform_ty(expr_ty, signature_return_ty).is_err()
+    {
+        ctx.diagnostics.report(
+            body_syntax,
+            SemanticDiagnosticKind::WrongReturnType {
+                expected_ty: signature_return_ty,
+                actual_ty: ctx.resolver.inference.reduce_ty(expr_ty),
+            },
+        );
+    }
+    let body = ctx.exprs.alloc(expr);
+    ctx.resolver.inference.finalize(ctx.diagnostics);
+    let ComputationContext {
+        diagnostics: ctx_diagnostics,
+        mut exprs,
+        mut statements,
+        resolver,
+        ..
+    } = ctx;
+
+    // Substitute the inferred types in the semantic model.
+    for (_id, expr) in exprs.iter_mut() {
+        resolver.inference.rewrite_expr(expr);
+    }
+    for (_id, statement) in statements.iter_mut() {
+        resolver.inference.rewrite_statement(statement);
+    }
+    resolve_trait_function_calls(db, ctx_diagnostics, module_file_id.0, &mut exprs)?;
+    check_array_slice_calls(db, ctx_diagnostics, module_file_id.0, &exprs)?;
+
+    let direct_callees: HashSet<FunctionId> = exprs
+        .iter()
+        .filter_map(|(_id, expr)| try_extract_matches!(expr, Expr::FunctionCall))
+        .map(|f| f.function)
+        .collect();
+
+    let expr_lookup: UnorderedHashMap<_, _> =
+        exprs.iter().map(|(expr_id, expr)| (expr.stable_ptr(), expr_id)).collect();
+    let resolved_lookback = Arc::new(resolver.lookback);
+    Ok(FunctionDefinitionData {
+        diagnostics: diagnostics.build(),
+        expr_lookup,
+        resolved_lookback,
+        definition: Arc::new(FunctionDefinition {
+            exprs,
+            statements,
+            body,
+            direct_callees: direct_callees.into_iter().collect(),
+        }),
+    })
+}
+
+/// Query implementation of [crate::db::SemanticGroup::expr_semantic].
+/// Assumes function and expression are present.
+pub fn expr_semantic(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+    id: semantic::ExprId,
+) -> semantic::Expr {
+    db.function_with_body_definition(function_id).unwrap().exprs.get(id).unwrap().clone()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::statement_semantic].
+/// Assumes function and statement are valid.
+pub fn statement_semantic(
+    db: &dyn SemanticGroup,
+    function_id: FunctionWithBodyId,
+    id: semantic::StatementId,
+) -> semantic::Statement {
+    db.function_with_body_definition(function_id).unwrap().statements.get(id).unwrap().clone()
+}
+
+pub trait SemanticExprLookup<'a>: Upcast<dyn SemanticGroup + 'a> {
+    fn lookup_expr_by_ptr(
+        &self,
+        function_id: FunctionWithBodyId,
+        ptr: ast::ExprPtr,
+    ) -> Maybe<ExprId> {
+        let definition_data = function_definition_data(self.upcast(), function_id)?;
+        definition_data.expr_lookup.get(&ptr).copied().to_maybe()
+    }
+}
+
+impl<'a, T: Upcast<dyn SemanticGroup + 'a> + ?Sized> SemanticExprLookup<'a> for T {}
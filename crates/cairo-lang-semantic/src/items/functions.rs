@@ -1,19 +1,22 @@
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_defs::ids::{ExternFunctionId, GenericFunctionId, GenericParamId, ParamLongId};
+use cairo_lang_defs::ids::{
+    ExternFunctionId, FunctionWithBodyId, GenericFunctionId, GenericParamId, ParamLongId,
+};
 use cairo_lang_diagnostics::{skip_diagnostic, Maybe};
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
-use cairo_lang_utils::{define_short_id, try_extract_matches};
+use cairo_lang_utils::{define_short_id, try_extract_matches, OptionFrom};
+use itertools::Itertools;
 use smol_str::SmolStr;
 
 use super::modifiers;
-use crate::corelib::unit_ty;
+use crate::corelib::{fn_trait, unit_ty};
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnostics;
 use crate::expr::compute::Environment;
 use crate::resolve_path::Resolver;
 use crate::types::{resolve_type, substitute_generics};
-use crate::{semantic, Parameter};
+use crate::{semantic, GenericArgumentId, Parameter, TypeId};
 
 /// Function instance.
 /// For example: `ImplA::foo<A, B>`, or `bar<A>`.
@@ -44,6 +47,27 @@ impl FunctionId {
             GenericFunctionId::Extern
         )
     }
+
+    /// Returns the FunctionWithBodyId if this is a function with a body. Otherwise returns none.
+    pub fn try_get_function_with_body_id(
+        &self,
+        db: &(dyn SemanticGroup + 'static),
+    ) -> Option<FunctionWithBodyId> {
+        FunctionWithBodyId::option_from(db.lookup_intern_function(*self).function.generic_function)
+    }
+
+    /// Returns the type of the called value if this is the `call` function of the `Fn` trait.
+    /// Otherwise returns none.
+    pub fn try_get_fn_trait_callee_ty(&self, db: &(dyn SemanticGroup + 'static)) -> Option<TypeId> {
+        let ConcreteFunction { generic_function, generic_args } =
+            db.lookup_intern_function(*self).function;
+        let trait_function_id =
+            try_extract_matches!(generic_function, GenericFunctionId::TraitFunction)?;
+        if trait_function_id.trait_id(db.upcast()) != fn_trait(db) {
+            return None;
+        }
+        try_extract_matches!(generic_args.first()?, GenericArgumentId::Type).copied()
+    }
 }
 
 // TODO(spapini): Refactor to an enum.
@@ -60,11 +84,7 @@ impl DebugWithDb<dyn SemanticGroup> for ConcreteFunction {
     ) -> std::fmt::Result {
         write!(f, "{}", self.generic_function.format(db.upcast()))?;
         if !self.generic_args.is_empty() {
-            write!(f, "<")?;
-            for arg in self.generic_args.iter() {
-                write!(f, "{:?},", arg.debug(db))?;
-            }
-            write!(f, ">")?;
+            write!(f, "::<{}>", self.generic_args.iter().map(|arg| arg.format(db)).join(", "))?;
         }
         Ok(())
     }
@@ -226,8 +246,6 @@ pub fn concrete_function_signature(
         //   always have the correct number of generic arguemnts.
         return Err(skip_diagnostic());
     }
-    // TODO(spapini): When trait generics are supported, they need to be substituted
-    //   one by one, not together.
     let substitution_map = generic_params.into_iter().zip(generic_args.into_iter()).collect();
     let generic_signature = db.generic_function_signature(generic_function)?;
     let concretize_param = |param: semantic::Parameter| Parameter {
@@ -245,7 +263,7 @@ pub fn concrete_function_signature(
 
 /// For a given list of AST parameters, returns the list of semantic parameters along with the
 /// corresponding environment.
-fn update_env_with_ast_params(
+pub fn update_env_with_ast_params(
     diagnostics: &mut SemanticDiagnostics,
     db: &dyn SemanticGroup,
     resolver: &mut Resolver<'_>,
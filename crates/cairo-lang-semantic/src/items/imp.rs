@@ -1,9 +1,11 @@
+use std::collections::{HashMap, HashSet};
+use std::iter::zip;
 use std::sync::Arc;
 use std::vec;
 
 use cairo_lang_defs::ids::{
-    GenericFunctionId, GenericParamId, ImplFunctionId, ImplFunctionLongId, ImplId,
-    LanguageElementId, ModuleId,
+    FunctionWithBodyId, GenericFunctionId, GenericParamId, ImplFunctionId, ImplFunctionLongId,
+    ImplId, LanguageElementId, ModuleId, TraitId,
 };
 use cairo_lang_diagnostics::{
     skip_diagnostic, Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe, ToOption,
@@ -15,18 +17,21 @@ use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::TypedSyntaxNode;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::{define_short_id, extract_matches, try_extract_matches, OptionHelper};
-use itertools::izip;
+use itertools::{izip, Itertools};
+use smol_str::SmolStr;
 
 use super::attribute::{ast_attributes_to_semantic, Attribute};
 use super::enm::SemanticEnumEx;
+use super::function_with_body::{compute_function_definition_data, FunctionDefinitionData};
 use super::generics::semantic_generic_params;
 use super::strct::SemanticStructEx;
 use crate::corelib::{copy_trait, drop_trait};
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
-use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
+use crate::diagnostic::{NotFoundItemType, SemanticDiagnosticKind, SemanticDiagnostics};
 use crate::expr::compute::Environment;
 use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, ResolvedLookback, Resolver};
+use crate::types::{infer_generic_args_from_args, substitute_generics};
 use crate::{
     semantic, ConcreteTraitId, ConcreteTraitLongId, GenericArgumentId, Mutability,
     SemanticDiagnostic, TypeId, TypeLongId,
@@ -81,8 +86,6 @@ pub fn impl_trait(db: &dyn SemanticGroup, impl_id: ImplId) -> Maybe<ConcreteTrai
     db.priv_impl_declaration_data(impl_id)?.concrete_trait
 }
 
-// TODO(ilya): Remove `allow` once when enable impl.
-#[allow(unreachable_code)]
 /// Query implementation of [crate::db::SemanticGroup::priv_impl_declaration_data].
 pub fn priv_impl_declaration_data(
     db: &dyn SemanticGroup,
@@ -98,21 +101,6 @@ pub fn priv_impl_declaration_data(
     let syntax_db = db.upcast();
     let impl_ast = module_impls.get(&impl_id).to_maybe()?;
 
-    // TODO(ilya): Enable impl logic.
-    if let MaybeImplBody::Some(body) = impl_ast.body(syntax_db) {
-        let resolved_lookback = Arc::new(ResolvedLookback::default());
-        let diag_added = diagnostics
-            .report_by_ptr(body.lbrace(syntax_db).stable_ptr().untyped(), ImplBodyIsNotSupported);
-
-        return Ok(ImplDeclarationData {
-            diagnostics: diagnostics.build(),
-            generic_params: vec![],
-            concrete_trait: Err(diag_added),
-            attributes: vec![],
-            resolved_lookback,
-        });
-    }
-
     // Generic params.
     let generic_params = semantic_generic_params(
         db,
@@ -163,6 +151,7 @@ pub fn impl_semantic_definition_diagnostics(
     diagnostics.extend(data.diagnostics);
     for impl_function_id in data.function_asts.keys() {
         diagnostics.extend(db.impl_function_declaration_diagnostics(*impl_function_id));
+        diagnostics.extend(db.impl_function_definition_diagnostics(*impl_function_id));
     }
 
     diagnostics.build()
@@ -247,6 +236,11 @@ pub fn priv_impl_definition_data(
                 Item::TypeAlias(ty) => {
                     report_invalid_in_impl(syntax_db, &mut diagnostics, ty.type_kw(syntax_db))
                 }
+                Item::Constant(constant) => report_invalid_in_impl(
+                    syntax_db,
+                    &mut diagnostics,
+                    constant.const_kw(syntax_db),
+                ),
                 Item::FreeFunction(func) => {
                     let impl_function_id = db.intern_impl_function(ImplFunctionLongId(
                         module_file_id,
@@ -258,6 +252,23 @@ pub fn priv_impl_definition_data(
         }
     }
 
+    // Check that all the functions of the trait are implemented.
+    let trait_id = db.lookup_intern_concrete_trait(concrete_trait).trait_id;
+    let implemented_names: HashSet<_> =
+        function_asts.keys().map(|impl_function_id| impl_function_id.name(db.upcast())).collect();
+    let missing_names: Vec<_> = db
+        .trait_functions(trait_id)?
+        .keys()
+        .filter(|name| !implemented_names.contains(*name))
+        .cloned()
+        .collect();
+    if !missing_names.is_empty() {
+        diagnostics.report(
+            &impl_ast.name(syntax_db),
+            MissingImplFunctions { impl_id, trait_id, function_names: missing_names },
+        );
+    }
+
     Ok(ImplDefinitionData { diagnostics: diagnostics.build(), function_asts })
 }
 
@@ -267,6 +278,8 @@ pub fn impl_functions(db: &dyn SemanticGroup, impl_id: ImplId) -> Maybe<Vec<Impl
 }
 
 /// Handle special cases such as Copy and Drop checking.
+/// Inner types that depend on generic params are only checked once the type is concretized, by
+/// [crate::db::SemanticGroup::type_info].
 fn check_special_impls(
     db: &dyn SemanticGroup,
     diagnostics: &mut SemanticDiagnostics,
@@ -283,6 +296,7 @@ fn check_special_impls(
         let tys = get_inner_types(db, extract_matches!(generic_args[0], GenericArgumentId::Type))?;
         if !tys
             .into_iter()
+            .filter(|ty| ty.is_fully_concrete(db))
             .filter_map(|ty| db.type_info(lookup_context.clone(), ty).to_option())
             .all(|info| info.duplicatable)
         {
@@ -293,6 +307,7 @@ fn check_special_impls(
         let tys = get_inner_types(db, extract_matches!(generic_args[0], GenericArgumentId::Type))?;
         if !tys
             .into_iter()
+            .filter(|ty| ty.is_fully_concrete(db))
             .filter_map(|ty| db.type_info(lookup_context.clone(), ty).to_option())
             .all(|info| info.droppable)
         {
@@ -325,7 +340,8 @@ fn get_inner_types(db: &dyn SemanticGroup, ty: TypeId) -> Maybe<Vec<TypeId>> {
             }
         }
         TypeLongId::Tuple(tys) => tys,
-        TypeLongId::GenericParameter(_) => {
+        TypeLongId::Closure(closure) => closure.captured_tys,
+        TypeLongId::GenericParameter(_) | TypeLongId::Var(_) => {
             return Err(skip_diagnostic());
         }
         TypeLongId::Missing(diag_added) => {
@@ -344,21 +360,53 @@ pub fn find_impls_at_module(
     let impls = db.module_impls(module_id)?;
     // TODO(spapini): Index better.
     for impl_id in impls.keys().copied() {
-        let Ok(imp_data)= db.priv_impl_declaration_data(impl_id) else {continue};
-        if !imp_data.generic_params.is_empty() {
-            // TODO(spapini): Infer generics and substitute.
-            continue;
-        }
-
-        if imp_data.concrete_trait == Ok(concrete_trait_id) {
-            let concrete_impl_id =
-                db.intern_concrete_impl(ConcreteImplLongId { impl_id, generic_args: vec![] });
+        if let Some(concrete_impl_id) = try_concretize_impl(db, impl_id, concrete_trait_id) {
             res.push(concrete_impl_id);
         }
     }
     Ok(res)
 }
 
+/// Returns the concrete impl of `impl_id` that implements `concrete_trait_id`, if exists.
+/// The generic args of the impl are inferred from the generic args of the concrete trait.
+fn try_concretize_impl(
+    db: &dyn SemanticGroup,
+    impl_id: ImplId,
+    concrete_trait_id: ConcreteTraitId,
+) -> Option<ConcreteImplId> {
+    let imp_data = db.priv_impl_declaration_data(impl_id).ok()?;
+    let impl_concrete_trait = imp_data.concrete_trait.ok()?;
+    if impl_concrete_trait == concrete_trait_id {
+        // Fast path, for non generic impls.
+        return imp_data.generic_params.is_empty().then(|| {
+            db.intern_concrete_impl(ConcreteImplLongId { impl_id, generic_args: vec![] })
+        });
+    }
+
+    let impl_concrete_trait = db.lookup_intern_concrete_trait(impl_concrete_trait);
+    let concrete_trait = db.lookup_intern_concrete_trait(concrete_trait_id);
+    if impl_concrete_trait.trait_id != concrete_trait.trait_id {
+        return None;
+    }
+    let mut substitution = HashMap::new();
+    if !infer_generic_args_from_args(
+        db,
+        &imp_data.generic_params,
+        &impl_concrete_trait.generic_args,
+        &concrete_trait.generic_args,
+        &mut substitution,
+    ) {
+        return None;
+    }
+    // All the generic params of the impl must be determined by the trait.
+    let generic_args = imp_data
+        .generic_params
+        .iter()
+        .map(|param| substitution.get(param).copied())
+        .collect::<Option<Vec<_>>>()?;
+    Some(db.intern_concrete_impl(ConcreteImplLongId { impl_id, generic_args }))
+}
+
 #[allow(dead_code)]
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ImplLookupContext {
@@ -367,6 +415,63 @@ pub struct ImplLookupContext {
     pub generic_params: Vec<GenericParamId>,
 }
 
+/// Returns the context in which the impls of a concrete trait are looked up, from a given module:
+/// the module itself, the module of the trait and the modules of the types in its generic args.
+pub fn concrete_trait_lookup_context(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    concrete_trait_id: ConcreteTraitId,
+) -> ImplLookupContext {
+    let ConcreteTraitLongId { trait_id, generic_args } =
+        db.lookup_intern_concrete_trait(concrete_trait_id);
+    let mut extra_modules = vec![trait_id.parent_module(db.upcast())];
+    for generic_arg in generic_args {
+        if let GenericArgumentId::Type(ty) = generic_arg {
+            if let TypeLongId::Concrete(concrete) = db.lookup_intern_type(ty) {
+                let module_id = concrete.generic_type(db).parent_module(db.upcast());
+                if !extra_modules.contains(&module_id) {
+                    extra_modules.push(module_id);
+                }
+            }
+        }
+    }
+    ImplLookupContext { module_id, extra_modules, generic_params: vec![] }
+}
+
+/// Finds the unique implementation of a concrete trait, in the lookup context of the given module.
+/// Returns the diagnostic to report if there is no such implementation, or more than one.
+pub fn find_unique_impl(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    concrete_trait_id: ConcreteTraitId,
+) -> Maybe<Result<ConcreteImplId, SemanticDiagnosticKind>> {
+    let lookup_context = concrete_trait_lookup_context(db, module_id, concrete_trait_id);
+    let concrete_impls = find_impls_at_context(db, &lookup_context, concrete_trait_id)?;
+    Ok(match concrete_impls[..] {
+        [concrete_impl_id] => Ok(concrete_impl_id),
+        [] => Err(NoImplementationOfTrait { concrete_trait_id }),
+        _ => Err(MultipleImplementationOfTrait {
+            concrete_trait_id,
+            impl_ids: concrete_impls
+                .into_iter()
+                .map(|concrete_impl_id| db.lookup_intern_concrete_impl(concrete_impl_id).impl_id)
+                .collect(),
+        }),
+    })
+}
+
+/// Returns the function named `name` in the given impl, if exists.
+pub fn impl_function_by_name(
+    db: &dyn SemanticGroup,
+    impl_id: ImplId,
+    name: &SmolStr,
+) -> Maybe<Option<ImplFunctionId>> {
+    Ok(db
+        .impl_functions(impl_id)?
+        .into_iter()
+        .find(|impl_function_id| impl_function_id.name(db.upcast()) == *name))
+}
+
 /// Finds all the implementations for a concrete trait, in a specific lookup context.
 pub fn find_impls_at_context(
     db: &dyn SemanticGroup,
@@ -385,11 +490,55 @@ pub fn find_impls_at_context(
         res.extend(find_impls_at_module(db, ModuleId::Submodule(submodule), concrete_trait_id)?);
     }
     for use_id in db.module_uses_ids(lookup_context.module_id)? {
-        if let Ok(ResolvedGenericItem::Module(submodule)) = db.use_resolved_item(use_id) {
-            res.extend(find_impls_at_module(db, submodule, concrete_trait_id)?);
+        match db.use_resolved_item(use_id) {
+            Ok(ResolvedGenericItem::Module(submodule)) => {
+                res.extend(find_impls_at_module(db, submodule, concrete_trait_id)?);
+            }
+            Ok(ResolvedGenericItem::Impl(impl_id)) => {
+                res.extend(try_concretize_impl(db, impl_id, concrete_trait_id));
+            }
+            _ => {}
         }
     }
-    Ok(res)
+    // The same impl may be found through more than one module.
+    Ok(res.into_iter().unique().collect())
+}
+
+/// Finds all the implementations of a trait, for any generic args, in a specific lookup context.
+pub fn find_trait_impls_at_context(
+    db: &dyn SemanticGroup,
+    lookup_context: &ImplLookupContext,
+    trait_id: TraitId,
+) -> Maybe<Vec<ImplId>> {
+    let mut module_ids = vec![lookup_context.module_id];
+    module_ids.extend(lookup_context.extra_modules.iter().copied());
+    module_ids.extend(
+        db.module_submodules_ids(lookup_context.module_id)?.into_iter().map(ModuleId::Submodule),
+    );
+    let mut impl_ids = Vec::new();
+    for use_id in db.module_uses_ids(lookup_context.module_id)? {
+        match db.use_resolved_item(use_id) {
+            Ok(ResolvedGenericItem::Module(submodule)) => module_ids.push(submodule),
+            Ok(ResolvedGenericItem::Impl(impl_id)) => impl_ids.push(impl_id),
+            _ => {}
+        }
+    }
+    for module_id in module_ids {
+        if let Ok(impls) = db.module_impls(module_id) {
+            impl_ids.extend(impls.keys().copied());
+        }
+    }
+    Ok(impl_ids
+        .into_iter()
+        .unique()
+        .filter(|impl_id| {
+            matches!(
+                db.impl_trait(*impl_id),
+                Ok(concrete_trait_id)
+                    if db.lookup_intern_concrete_trait(concrete_trait_id).trait_id == trait_id
+            )
+        })
+        .collect())
 }
 
 // Declaration.
@@ -399,7 +548,7 @@ pub struct ImplFunctionDeclarationData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
     signature: semantic::Signature,
     generic_params: Vec<GenericParamId>,
-    // TODO(ilya): Do we need Environment like in a free function?
+    environment: Environment,
     attributes: Vec<Attribute>,
     resolved_lookback: Arc<ResolvedLookback>,
 }
@@ -450,12 +599,14 @@ pub fn priv_impl_function_declaration_data(
     let function_syntax = &data.function_asts[impl_function_id];
     let syntax_db = db.upcast();
     let declaration = function_syntax.declaration(syntax_db);
-    let generic_params = semantic_generic_params(
+    // The generic params of the impl are accessible in the function, and precede its own.
+    let mut generic_params = db.impl_generic_params(impl_id)?;
+    generic_params.extend(semantic_generic_params(
         db,
         &mut diagnostics,
         module_file_id,
         &declaration.generic_params(syntax_db),
-    );
+    ));
     let mut resolver = Resolver::new(db, module_file_id, &generic_params);
 
     let signature_syntax = declaration.signature(syntax_db);
@@ -475,8 +626,8 @@ pub fn priv_impl_function_declaration_data(
         &mut diagnostics,
         impl_id,
         impl_function_id,
-        &signature_syntax,
         &signature,
+        &generic_params,
         function_syntax,
     );
 
@@ -487,21 +638,65 @@ pub fn priv_impl_function_declaration_data(
         diagnostics: diagnostics.build(),
         signature,
         generic_params,
+        environment,
         attributes,
         resolved_lookback,
     })
 }
 
+// Definition.
+
+/// Query implementation of [crate::db::SemanticGroup::impl_function_definition_diagnostics].
+pub fn impl_function_definition_diagnostics(
+    db: &dyn SemanticGroup,
+    impl_function_id: ImplFunctionId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_impl_function_definition_data(impl_function_id)
+        .map(|data| data.diagnostics)
+        .unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_function_definition_resolved_lookback].
+pub fn impl_function_definition_resolved_lookback(
+    db: &dyn SemanticGroup,
+    impl_function_id: ImplFunctionId,
+) -> Maybe<Arc<ResolvedLookback>> {
+    Ok(db.priv_impl_function_definition_data(impl_function_id)?.resolved_lookback)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_impl_function_definition_data].
+pub fn priv_impl_function_definition_data(
+    db: &dyn SemanticGroup,
+    impl_function_id: ImplFunctionId,
+) -> Maybe<FunctionDefinitionData> {
+    let module_file_id = impl_function_id.module_file(db.upcast());
+    let impl_id = impl_function_id.impl_id(db.upcast());
+    let data = db.priv_impl_definition_data(impl_id)?;
+    let function_syntax = &data.function_asts[impl_function_id];
+    // Compute signature semantic.
+    let declaration = db.priv_impl_function_declaration_data(impl_function_id)?;
+    compute_function_definition_data(
+        db,
+        FunctionWithBodyId::Impl(impl_function_id),
+        module_file_id,
+        &declaration.signature,
+        &declaration.generic_params,
+        declaration.environment,
+        &function_syntax.body(db.upcast()),
+    )
+}
+
 fn validate_impl_function_signature(
     db: &dyn SemanticGroup,
     diagnostics: &mut SemanticDiagnostics,
     impl_id: ImplId,
     impl_function_id: ImplFunctionId,
-    signature_syntax: &ast::FunctionSignature,
     signature: &semantic::Signature,
+    generic_params: &[GenericParamId],
     function_syntax: &ast::ItemFreeFunction,
 ) {
     let syntax_db = db.upcast();
+    let signature_syntax = function_syntax.declaration(syntax_db).signature(syntax_db);
     let Ok(declaraton_data) = db.priv_impl_declaration_data(impl_id) else {
         return;
     };
@@ -515,13 +710,33 @@ fn validate_impl_function_signature(
     };
     let function_name = db.lookup_intern_impl_function(impl_function_id).name(db.upcast());
     let Some(trait_function_id) = trait_functions.get(&function_name).on_none(|| {
-        diagnostics.report(function_syntax, FunctionNotMemberOfTrait { impl_id, impl_function_id, trait_id });
+        diagnostics.report(
+            function_syntax,
+            FunctionNotMemberOfTrait { impl_id, impl_function_id, trait_id },
+        );
     }) else {
         return;
     };
     let Ok(trait_signature) = db.trait_function_signature(*trait_function_id) else {
         return;
     };
+    // Express the trait signature in terms of the impl: the generic params of the trait are
+    // replaced by the generic args of the implemented concrete trait, and the generic params of
+    // the trait function by those of the impl function.
+    let Ok(trait_function_generic_params) = db.trait_function_generic_params(*trait_function_id)
+    else {
+        return;
+    };
+    let trait_generic_args = concrete_trait_long_id.generic_args;
+    let impl_function_generic_args = generic_params
+        .iter()
+        .skip(declaraton_data.generic_params.len())
+        .map(|param| GenericArgumentId::Type(db.intern_type(TypeLongId::GenericParameter(*param))));
+    let substitution: HashMap<_, _> = zip(
+        trait_function_generic_params,
+        trait_generic_args.into_iter().chain(impl_function_generic_args),
+    )
+    .collect();
     if signature.params.len() != trait_signature.params.len() {
         diagnostics.report(
             &signature_syntax.parameters(syntax_db),
@@ -537,10 +752,10 @@ fn validate_impl_function_signature(
     for (idx, (param, trait_param)) in
         izip!(signature.params.iter(), trait_signature.params.iter()).enumerate()
     {
-        let expected_ty = trait_param.ty;
+        let expected_ty = substitute_generics(db, &substitution, trait_param.ty);
         let actual_ty = param.ty;
 
-        if expected_ty != actual_ty {
+        if expected_ty != actual_ty && !expected_ty.is_missing(db) {
             diagnostics.report(
                 &signature_syntax.parameters(syntax_db).elements(syntax_db)[idx]
                     .type_clause(syntax_db)
@@ -569,12 +784,12 @@ fn validate_impl_function_signature(
     }
 
     if !trait_signature.panicable && signature.panicable {
-        diagnostics.report(signature_syntax, PassPanicAsNopanic { impl_function_id, trait_id });
+        diagnostics.report(&signature_syntax, PassPanicAsNopanic { impl_function_id, trait_id });
     }
 
-    let expected_ty = trait_signature.return_type;
+    let expected_ty = substitute_generics(db, &substitution, trait_signature.return_type);
     let actual_ty = signature.return_type;
-    if expected_ty != actual_ty {
+    if expected_ty != actual_ty && !expected_ty.is_missing(db) {
         let location_ptr = match signature_syntax.ret_ty(syntax_db) {
             OptionReturnTypeClause::ReturnTypeClause(ret_ty) => {
                 ret_ty.ty(syntax_db).as_syntax_node()
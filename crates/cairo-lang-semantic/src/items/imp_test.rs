@@ -7,8 +7,6 @@ use test_log::test;
 use crate::db::SemanticGroup;
 use crate::test_utils::{setup_test_module, SemanticDatabaseForTesting};
 
-// TODO(ilya): enable test once impls are enabled.
-#[ignore]
 #[test]
 fn test_impl() {
     let mut db_val = SemanticDatabaseForTesting::default();
@@ -25,7 +23,7 @@ fn test_impl() {
             #[Contract]
             impl Contract of IContract {
                 fn foo(a: felt) {
-                    return 1;
+                    return ();
                 }
             }
         "},
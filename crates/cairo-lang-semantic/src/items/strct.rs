@@ -10,6 +10,7 @@ use smol_str::SmolStr;
 
 use super::attribute::{ast_attributes_to_semantic, Attribute};
 use super::generics::semantic_generic_params;
+use super::visibility::Visibility;
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::SemanticDiagnostics;
@@ -35,6 +36,7 @@ pub struct StructData {
 pub struct Member {
     pub id: MemberId,
     pub ty: semantic::TypeId,
+    pub visibility: Visibility,
 }
 
 /// Query implementation of [crate::db::SemanticGroup::struct_semantic_diagnostics].
@@ -81,7 +83,6 @@ pub fn priv_struct_semantic_data(db: &dyn SemanticGroup, struct_id: StructId) ->
     // TODO(spapini): when code changes in a file, all the AST items change (as they contain a path
     // to the green root that changes. Once ASTs are rooted on items, use a selector that picks only
     // the item instead of all the module data.
-    // TODO(spapini): Add generic args when they are supported on structs.
     let module_structs = db.module_structs(module_file_id.0)?;
     let struct_ast = module_structs.get(&struct_id).to_maybe()?;
     let syntax_db = db.upcast();
@@ -105,8 +106,11 @@ pub fn priv_struct_semantic_data(db: &dyn SemanticGroup, struct_id: StructId) ->
             &mut resolver,
             &member.type_clause(syntax_db).ty(syntax_db),
         );
+        let visibility = Visibility::from_ast(&member.visibility(syntax_db));
         let member_name = member.name(syntax_db).text(syntax_db);
-        if let Some(_other_member) = members.insert(member_name.clone(), Member { id, ty }) {
+        if let Some(_other_member) =
+            members.insert(member_name.clone(), Member { id, ty, visibility })
+        {
             diagnostics.report(&member, StructMemberRedefinition { struct_id, member_name });
         }
     }
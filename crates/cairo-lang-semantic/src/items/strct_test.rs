@@ -19,7 +19,7 @@ fn test_struct() {
             #[contract(MyImpl1, MyImpl2)]
             struct A {
                 a: felt,
-                b: (felt, felt),
+                pub b: (felt, felt),
                 c: (),
                 a: (),
                 a: ()
@@ -62,9 +62,9 @@ fn test_struct() {
     assert_eq!(
         actual,
         indoc! {"
-            a: Member { id: MemberId(test::a), ty: () },
-            b: Member { id: MemberId(test::b), ty: (core::felt, core::felt) },
-            c: Member { id: MemberId(test::c), ty: () }"}
+            a: Member { id: MemberId(test::a), ty: (), visibility: Private },
+            b: Member { id: MemberId(test::b), ty: (core::felt, core::felt), visibility: Public },
+            c: Member { id: MemberId(test::c), ty: (), visibility: Private }"}
     );
 
     assert_eq!(
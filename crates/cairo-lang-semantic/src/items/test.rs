@@ -4,9 +4,11 @@ cairo_lang_test_utils::test_file_test!(
     diagnostics,
     "src/items/tests",
     {
+        constant: "constant",
         enum_: "enum",
         extern_func: "extern_func",
         free_function: "free_function",
+        impl_: "impl",
         panicable: "panicable",
         struct_: "struct",
         trait_: "trait",
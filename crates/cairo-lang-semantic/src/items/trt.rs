@@ -1,14 +1,15 @@
 use std::sync::Arc;
 
 use cairo_lang_defs::ids::{
-    GenericFunctionId, GenericParamId, LanguageElementId, TraitFunctionId, TraitFunctionLongId,
-    TraitId,
+    GenericFunctionId, GenericParamId, LanguageElementId, TopLevelLanguageElementId,
+    TraitFunctionId, TraitFunctionLongId, TraitId,
 };
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe};
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 use cairo_lang_utils::define_short_id;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::Itertools;
 use smol_str::SmolStr;
 
 use super::attribute::{ast_attributes_to_semantic, Attribute};
@@ -30,6 +31,21 @@ pub struct ConcreteTraitLongId {
     pub generic_args: Vec<GenericArgumentId>,
 }
 define_short_id!(ConcreteTraitId, ConcreteTraitLongId, SemanticGroup, lookup_intern_concrete_trait);
+impl ConcreteTraitId {
+    pub fn format(&self, db: &dyn SemanticGroup) -> String {
+        let ConcreteTraitLongId { trait_id, generic_args } = db.lookup_intern_concrete_trait(*self);
+        let trait_format = trait_id.full_path(db.upcast());
+        if generic_args.is_empty() {
+            trait_format
+        } else {
+            format!(
+                "{}::<{}>",
+                trait_format,
+                generic_args.iter().map(|arg| arg.format(db)).join(", ")
+            )
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
@@ -154,6 +170,13 @@ pub fn trait_function_signature(
 ) -> Maybe<semantic::Signature> {
     Ok(db.priv_trait_function_data(trait_function_id)?.signature)
 }
+/// Query implementation of [crate::db::SemanticGroup::trait_function_attributes].
+pub fn trait_function_attributes(
+    db: &dyn SemanticGroup,
+    trait_function_id: TraitFunctionId,
+) -> Maybe<Vec<Attribute>> {
+    Ok(db.priv_trait_function_data(trait_function_id)?.attributes)
+}
 /// Query implementation of [crate::db::SemanticGroup::trait_function_generic_params].
 pub fn trait_function_generic_params(
     db: &dyn SemanticGroup,
@@ -182,12 +205,14 @@ pub fn priv_trait_function_data(
     let data = db.priv_trait_semantic_data(trait_id)?;
     let function_syntax = &data.function_asts[trait_function_id];
     let declaration = function_syntax.declaration(syntax_db);
-    let generic_params = semantic_generic_params(
+    // The generic params of the trait are accessible in the function, and precede its own.
+    let mut generic_params = data.generic_params;
+    generic_params.extend(semantic_generic_params(
         db,
         &mut diagnostics,
         module_file_id,
         &declaration.generic_params(syntax_db),
-    );
+    ));
     let mut resolver = Resolver::new(db, module_file_id, &generic_params);
 
     let signature_syntax = declaration.signature(syntax_db);
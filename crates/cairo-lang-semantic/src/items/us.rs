@@ -21,7 +21,6 @@ pub struct UseData {
 pub fn priv_use_semantic_data(db: &(dyn SemanticGroup), use_id: UseId) -> Maybe<UseData> {
     let module_file_id = use_id.module_file(db.upcast());
     let mut diagnostics = SemanticDiagnostics::new(module_file_id);
-    // TODO(spapini): Add generic args when they are supported on structs.
     let mut resolver = Resolver::new(db, module_file_id, &[]);
     // TODO(spapini): when code changes in a file, all the AST items change (as they contain a path
     // to the green root that changes. Once ASTs are rooted on items, use a selector that picks only
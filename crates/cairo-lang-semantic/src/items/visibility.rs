@@ -0,0 +1,99 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId};
+use cairo_lang_diagnostics::{Maybe, ToMaybe};
+use cairo_lang_syntax::node::ast;
+
+use crate::db::SemanticGroup;
+
+/// The visibility of a module item or of a struct member.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Visibility {
+    /// Declared with `pub`. Visible from any module, including modules of other crates.
+    Public,
+    /// Visible only from the module it is defined in, and from the descendants of that module.
+    Private,
+}
+impl Visibility {
+    pub fn from_ast(visibility: &ast::Visibility) -> Self {
+        match visibility {
+            ast::Visibility::Pub(_) => Visibility::Public,
+            ast::Visibility::Default(_) => Visibility::Private,
+        }
+    }
+
+    /// Returns whether an element with this visibility, defined in `containing_module_id`, may be
+    /// accessed from `user_module_id`.
+    pub fn is_visible_from(
+        &self,
+        db: &dyn DefsGroup,
+        containing_module_id: ModuleId,
+        user_module_id: ModuleId,
+    ) -> bool {
+        match self {
+            Visibility::Public => true,
+            Visibility::Private => is_submodule_of(db, user_module_id, containing_module_id),
+        }
+    }
+}
+
+/// Returns whether `module_id` is `ancestor_module_id` or one of its descendants.
+fn is_submodule_of(
+    db: &dyn DefsGroup,
+    mut module_id: ModuleId,
+    ancestor_module_id: ModuleId,
+) -> bool {
+    loop {
+        if module_id == ancestor_module_id {
+            return true;
+        }
+        module_id = match module_id {
+            ModuleId::CrateRoot(_) => return false,
+            ModuleId::Submodule(submodule_id) => submodule_id.parent_module(db),
+            ModuleId::VirtualSubmodule(submodule_id) => {
+                db.lookup_intern_virtual_submodule(submodule_id).parent
+            }
+        };
+    }
+}
+
+/// Query implementation of [crate::db::SemanticGroup::module_item_visibility].
+pub fn module_item_visibility(db: &dyn SemanticGroup, item_id: ModuleItemId) -> Maybe<Visibility> {
+    let syntax_db = db.upcast();
+    let module_id = item_id.parent_module(db.upcast());
+    let visibility = match item_id {
+        ModuleItemId::Submodule(id) => {
+            db.module_submodules(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::Use(id) => {
+            db.module_uses(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::FreeFunction(id) => {
+            db.module_free_functions(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::Struct(id) => {
+            db.module_structs(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::Enum(id) => {
+            db.module_enums(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::TypeAlias(id) => {
+            db.module_type_aliases(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::Constant(id) => {
+            db.module_constants(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::Trait(id) => {
+            db.module_traits(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::Impl(id) => {
+            db.module_impls(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::ExternType(id) => {
+            db.module_extern_types(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+        ModuleItemId::ExternFunction(id) => {
+            db.module_extern_functions(module_id)?.get(&id).to_maybe()?.visibility(syntax_db)
+        }
+    };
+    Ok(Visibility::from_ast(&visibility))
+}
@@ -11,6 +11,7 @@ pub mod literals;
 pub mod patcher;
 pub mod plugin;
 pub mod resolve_path;
+pub mod substitution;
 pub mod types;
 
 mod semantic;
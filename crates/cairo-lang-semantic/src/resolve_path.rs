@@ -5,10 +5,10 @@ mod test;
 use std::iter::Peekable;
 
 use cairo_lang_defs::ids::{
-    GenericFunctionId, GenericParamId, GenericTypeId, ImplId, LanguageElementId, ModuleFileId,
-    ModuleId, ModuleItemId, TraitId, TypeAliasId,
+    ConstantId, GenericFunctionId, GenericParamId, GenericTypeId, ImplFunctionId, ImplId,
+    LanguageElementId, ModuleFileId, ModuleId, ModuleItemId, TraitId, TypeAliasId,
 };
-use cairo_lang_diagnostics::Maybe;
+use cairo_lang_diagnostics::{skip_diagnostic, Maybe};
 use cairo_lang_filesystem::ids::CrateLongId;
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax::node::helpers::PathSegmentEx;
@@ -16,18 +16,21 @@ use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::extract_matches;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
-use itertools::Itertools;
+use itertools::{chain, Itertools};
 use smol_str::SmolStr;
 
-use crate::corelib::core_module;
+use crate::corelib::{core_bool_ty, core_module, fn_trait, fn_trait_call_function};
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
+use crate::expr::inference::Inference;
 use crate::items::enm::{ConcreteVariant, SemanticEnumEx};
-use crate::items::imp::{ConcreteImplId, ConcreteImplLongId};
+use crate::items::imp::{
+    find_unique_impl, impl_function_by_name, ConcreteImplId, ConcreteImplLongId,
+};
 use crate::items::trt::{ConcreteTraitId, ConcreteTraitLongId};
 use crate::literals::LiteralLongId;
-use crate::types::{resolve_type, substitute_generics};
+use crate::types::{generic_args_fully_concrete, resolve_type, substitute_generics};
 use crate::{
     ConcreteFunction, ConcreteTypeId, FunctionId, FunctionLongId, GenericArgumentId, TypeId,
     TypeLongId, Variant,
@@ -45,6 +48,7 @@ pub enum ResolvedConcreteItem {
     Variant(ConcreteVariant),
     Trait(ConcreteTraitId),
     Impl(ConcreteImplId),
+    Constant(ConstantId),
 }
 #[derive(Clone, PartialEq, Eq, Debug, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
@@ -56,6 +60,7 @@ pub enum ResolvedGenericItem {
     Variant(Variant),
     Trait(TraitId),
     Impl(ImplId),
+    Constant(ConstantId),
 }
 impl ResolvedConcreteItem {
     pub fn generic(&self, db: &dyn SemanticGroup) -> Option<ResolvedGenericItem> {
@@ -85,6 +90,9 @@ impl ResolvedConcreteItem {
             ResolvedConcreteItem::Impl(concrete_impl) => {
                 ResolvedGenericItem::Impl(db.lookup_intern_concrete_impl(*concrete_impl).impl_id)
             }
+            ResolvedConcreteItem::Constant(constant_id) => {
+                ResolvedGenericItem::Constant(*constant_id)
+            }
         })
     }
 }
@@ -137,6 +145,10 @@ pub struct Resolver<'db> {
     generic_params: UnorderedHashMap<SmolStr, GenericParamId>,
     // Lookback map for resolved identifiers in path. Used in "Go to definition".
     pub lookback: ResolvedLookback,
+    // Inference of the types standing for omitted generic arguments.
+    pub inference: Inference<'db>,
+    // Whether generic arguments may be omitted, to be inferred. Only allowed in function bodies.
+    allow_inference: bool,
 }
 impl<'db> Resolver<'db> {
     pub fn new(
@@ -152,9 +164,27 @@ impl<'db> Resolver<'db> {
                 .map(|generic_param| (generic_param.name(db.upcast()), *generic_param))
                 .collect(),
             lookback: ResolvedLookback::default(),
+            inference: Inference::new(db),
+            allow_inference: false,
         }
     }
 
+    /// Creates a resolver for paths in a function body, where the generic arguments of generic
+    /// functions and types may be omitted. Each omitted generic argument is replaced by a new type
+    /// variable of [Self::inference].
+    pub fn new_with_inference(
+        db: &'db dyn SemanticGroup,
+        module_file_id: ModuleFileId,
+        generic_params: &[GenericParamId],
+    ) -> Self {
+        Self { allow_inference: true, ..Self::new(db, module_file_id, generic_params) }
+    }
+
+    /// Returns the inference to use for omitted generic arguments, if they may be omitted.
+    fn inference_if_allowed(&mut self) -> Option<&mut Inference<'db>> {
+        if self.allow_inference { Some(&mut self.inference) } else { None }
+    }
+
     /// Resolves a concrete item, given a path.
     /// Guaranteed to result in at most one diagnostic.
     pub fn resolve_concrete_path(
@@ -175,45 +205,13 @@ impl<'db> Resolver<'db> {
             let segment = segments.next().unwrap();
             let (identifier, generic_args) = match segment {
                 cairo_lang_syntax::node::ast::PathSegment::WithGenericArgs(segment) => {
-                    let mut generic_args = vec![];
-                    for generic_arg_syntax in
-                        segment.generic_args(syntax_db).generic_args(syntax_db).elements(syntax_db)
-                    {
-                        match generic_arg_syntax {
-                            ast::Expr::Literal(literal_syntax) => {
-                                let literal =
-                                    LiteralLongId::try_from(literal_syntax.text(syntax_db))
-                                        .map_err(|_| {
-                                            diagnostics.report(&literal_syntax, UnknownLiteral)
-                                        })?;
-                                generic_args.push(GenericArgumentId::Literal(
-                                    self.db.intern_literal(literal),
-                                ));
-                            }
-                            ast::Expr::Unary(unary)
-                                if matches!(unary.expr(syntax_db), ast::Expr::Literal(_))
-                                    && matches!(
-                                        unary.op(syntax_db),
-                                        ast::UnaryOperator::Minus(_)
-                                    ) =>
-                            {
-                                let mut literal = LiteralLongId::try_from(
-                                    extract_matches!(unary.expr(syntax_db), ast::Expr::Literal)
-                                        .text(syntax_db),
-                                )
-                                .map_err(|_| diagnostics.report(&unary, UnknownLiteral))?;
-                                literal.value *= -1;
-                                generic_args.push(GenericArgumentId::Literal(
-                                    self.db.intern_literal(literal),
-                                ));
-                            }
-                            _ => {
-                                let ty =
-                                    resolve_type(self.db, diagnostics, self, &generic_arg_syntax);
-                                generic_args.push(GenericArgumentId::Type(ty));
-                            }
-                        }
-                    }
+                    let generic_args = self.resolve_generic_args(
+                        diagnostics,
+                        &segment
+                            .generic_args(syntax_db)
+                            .generic_args(syntax_db)
+                            .elements(syntax_db),
+                    )?;
                     (segment.ident(syntax_db), Some(generic_args))
                 }
                 cairo_lang_syntax::node::ast::PathSegment::Simple(segment) => {
@@ -237,6 +235,70 @@ impl<'db> Resolver<'db> {
         Ok(item)
     }
 
+    /// Resolves the generic arguments given in a path segment (e.g. `::<felt, 1>`).
+    pub fn resolve_generic_args(
+        &mut self,
+        diagnostics: &mut SemanticDiagnostics,
+        generic_args_syntax: &[ast::Expr],
+    ) -> Maybe<Vec<GenericArgumentId>> {
+        let syntax_db = self.db.upcast();
+        let mut generic_args = vec![];
+        for generic_arg_syntax in generic_args_syntax.iter() {
+            match generic_arg_syntax {
+                ast::Expr::Literal(literal_syntax) => {
+                    let literal = LiteralLongId::try_from(literal_syntax.text(syntax_db))
+                        .map_err(|_| diagnostics.report(literal_syntax, UnknownLiteral))?;
+                    generic_args.push(GenericArgumentId::Literal(self.db.intern_literal(literal)));
+                }
+                ast::Expr::Unary(unary)
+                    if matches!(unary.expr(syntax_db), ast::Expr::Literal(_))
+                        && matches!(unary.op(syntax_db), ast::UnaryOperator::Minus(_)) =>
+                {
+                    let mut literal = LiteralLongId::try_from(
+                        extract_matches!(unary.expr(syntax_db), ast::Expr::Literal).text(syntax_db),
+                    )
+                    .map_err(|_| diagnostics.report(unary, UnknownLiteral))?;
+                    literal.value *= -1;
+                    generic_args.push(GenericArgumentId::Literal(self.db.intern_literal(literal)));
+                }
+                ast::Expr::Path(path) => {
+                    generic_args.push(self.resolve_generic_arg_path(diagnostics, path));
+                }
+                _ => {
+                    let ty = resolve_type(self.db, diagnostics, self, generic_arg_syntax);
+                    generic_args.push(GenericArgumentId::Type(ty));
+                }
+            }
+        }
+        Ok(generic_args)
+    }
+
+    /// Resolves a generic argument given as a path. The path may refer either to a type or to a
+    /// constant.
+    fn resolve_generic_arg_path(
+        &mut self,
+        diagnostics: &mut SemanticDiagnostics,
+        path: &ast::ExprPath,
+    ) -> GenericArgumentId {
+        let db = self.db;
+        let generic_arg = self
+            .resolve_concrete_path(diagnostics, path, NotFoundItemType::Type)
+            .and_then(|item| match item {
+                ResolvedConcreteItem::Type(ty) => Ok(GenericArgumentId::Type(ty)),
+                ResolvedConcreteItem::Constant(constant_id) => {
+                    let constant = db.constant_value(constant_id)?;
+                    if constant.ty == core_bool_ty(db) {
+                        return Err(diagnostics.report(path, UnsupportedConstant));
+                    }
+                    let literal = LiteralLongId { value: constant.value };
+                    Ok(GenericArgumentId::Literal(db.intern_literal(literal)))
+                }
+                _ => Err(diagnostics.report(path, NotAType)),
+            });
+        generic_arg
+            .unwrap_or_else(|diag_added| GenericArgumentId::Type(TypeId::missing(db, diag_added)))
+    }
+
     /// Resolves the first segment of a concrete path.
     fn resolve_concrete_path_first_segment(
         &mut self,
@@ -400,6 +462,7 @@ impl<'db> Resolver<'db> {
                     .db
                     .module_item_by_name(*module_id, ident)?
                     .ok_or_else(|| diagnostics.report(identifier, PathNotFound(item_type)))?;
+                self.validate_item_visibility(diagnostics, *module_id, identifier, module_item)?;
                 let generic_item = self.module_item_to_generic_item(diagnostics, module_item)?;
                 Ok(self.specialize_generic_module_item(
                     diagnostics,
@@ -429,6 +492,84 @@ impl<'db> Resolver<'db> {
                     Err(diagnostics.report(identifier, InvalidPath))
                 }
             }
+            ResolvedConcreteItem::Trait(concrete_trait_id) => {
+                // Resolve the function through the impl of the trait found in the current
+                // context.
+                let ConcreteTraitLongId { trait_id, generic_args: trait_generic_args } =
+                    self.db.lookup_intern_concrete_trait(*concrete_trait_id);
+                let trait_functions = self.db.trait_functions(trait_id)?;
+                let Some(trait_function_id) = trait_functions.get(&ident).copied() else {
+                    return Err(diagnostics.report(identifier, PathNotFound(item_type)));
+                };
+                if trait_id == fn_trait(self.db) {
+                    // `Fn` has no impls. Its function is kept as is, and the call is dispatched to
+                    // the called closure when lowered.
+                    if let Some(GenericArgumentId::Type(callee_ty)) = trait_generic_args.first() {
+                        if matches!(
+                            self.db.lookup_intern_type(*callee_ty),
+                            TypeLongId::Concrete(_) | TypeLongId::Tuple(_)
+                        ) {
+                            return Err(diagnostics.report(
+                                identifier,
+                                NoImplementationOfTrait { concrete_trait_id: *concrete_trait_id },
+                            ));
+                        }
+                    }
+                    return Ok(ResolvedConcreteItem::Function(fn_trait_call_function(
+                        self.db,
+                        trait_generic_args,
+                    )));
+                }
+                let concrete_impl_id =
+                    match find_unique_impl(self.db, self.module_file_id.0, *concrete_trait_id)? {
+                        Ok(concrete_impl_id) => concrete_impl_id,
+                        // A trait of the generic params of the function (e.g. `PartialEq::<T>`)
+                        // is implemented only once they are substituted. Its function is kept as
+                        // is, and resolved when the function is specialized.
+                        Err(NoImplementationOfTrait { .. })
+                            if !generic_args_fully_concrete(self.db, &trait_generic_args) =>
+                        {
+                            return Ok(ResolvedConcreteItem::Function(self.db.intern_function(
+                                FunctionLongId {
+                                    function: ConcreteFunction {
+                                        generic_function: GenericFunctionId::TraitFunction(
+                                            trait_function_id,
+                                        ),
+                                        generic_args: trait_generic_args,
+                                    },
+                                },
+                            )));
+                        }
+                        Err(kind) => return Err(diagnostics.report(identifier, kind)),
+                    };
+                let impl_id = self.db.lookup_intern_concrete_impl(concrete_impl_id).impl_id;
+                // A missing function is reported on the impl itself.
+                let impl_function_id =
+                    impl_function_by_name(self.db, impl_id, &ident)?.ok_or_else(skip_diagnostic)?;
+                Ok(ResolvedConcreteItem::Function(specialize_impl_function(
+                    self.db,
+                    diagnostics,
+                    self.inference_if_allowed(),
+                    identifier.stable_ptr().untyped(),
+                    concrete_impl_id,
+                    impl_function_id,
+                    generic_args.unwrap_or_default(),
+                )?))
+            }
+            ResolvedConcreteItem::Impl(concrete_impl_id) => {
+                let impl_id = self.db.lookup_intern_concrete_impl(*concrete_impl_id).impl_id;
+                let impl_function_id = impl_function_by_name(self.db, impl_id, &ident)?
+                    .ok_or_else(|| diagnostics.report(identifier, PathNotFound(item_type)))?;
+                Ok(ResolvedConcreteItem::Function(specialize_impl_function(
+                    self.db,
+                    diagnostics,
+                    self.inference_if_allowed(),
+                    identifier.stable_ptr().untyped(),
+                    *concrete_impl_id,
+                    impl_function_id,
+                    generic_args.unwrap_or_default(),
+                )?))
+            }
             _ => Err(diagnostics.report(identifier, InvalidPath)),
         }
     }
@@ -452,6 +593,7 @@ impl<'db> Resolver<'db> {
                 ResolvedConcreteItem::Function(specialize_function(
                     self.db,
                     diagnostics,
+                    self.inference_if_allowed(),
                     identifier.stable_ptr().untyped(),
                     generic_function,
                     generic_args.unwrap_or_default(),
@@ -461,6 +603,7 @@ impl<'db> Resolver<'db> {
                 ResolvedConcreteItem::Type(specialize_type(
                     self.db,
                     diagnostics,
+                    self.inference_if_allowed(),
                     identifier.stable_ptr().untyped(),
                     generic_type,
                     generic_args.unwrap_or_default(),
@@ -477,6 +620,7 @@ impl<'db> Resolver<'db> {
                 conform_generic_args(
                     self.db,
                     diagnostics,
+                    self.inference_if_allowed(),
                     &generic_params,
                     &mut generic_args,
                     identifier.stable_ptr().untyped(),
@@ -499,10 +643,17 @@ impl<'db> Resolver<'db> {
             ResolvedGenericItem::Impl(impl_id) => ResolvedConcreteItem::Impl(specialize_impl(
                 self.db,
                 diagnostics,
+                self.inference_if_allowed(),
                 identifier.stable_ptr().untyped(),
                 impl_id,
                 generic_args.unwrap_or_default(),
             )?),
+            ResolvedGenericItem::Constant(constant_id) => {
+                if generic_args.is_some() {
+                    return Err(diagnostics.report(identifier, UnexpectedGenericArgs));
+                }
+                ResolvedConcreteItem::Constant(constant_id)
+            }
         })
     }
 
@@ -522,6 +673,7 @@ impl<'db> Resolver<'db> {
                     .db
                     .module_item_by_name(*module_id, ident)?
                     .ok_or_else(|| diagnostics.report(identifier, PathNotFound(item_type)))?;
+                self.validate_item_visibility(diagnostics, *module_id, identifier, module_item)?;
                 self.module_item_to_generic_item(diagnostics, module_item)
             }
             ResolvedGenericItem::GenericType(GenericTypeId::Enum(enum_id)) => {
@@ -539,6 +691,29 @@ impl<'db> Resolver<'db> {
         }
     }
 
+    /// Reports a diagnostic if the given item of `containing_module_id` is not visible from the
+    /// current module.
+    fn validate_item_visibility(
+        &self,
+        diagnostics: &mut SemanticDiagnostics,
+        containing_module_id: ModuleId,
+        identifier: &ast::TerminalIdentifier,
+        module_item: ModuleItemId,
+    ) -> Maybe<()> {
+        let visibility = self.db.module_item_visibility(module_item)?;
+        if visibility.is_visible_from(self.db.upcast(), containing_module_id, self.module_file_id.0)
+        {
+            return Ok(());
+        }
+        Err(diagnostics.report(
+            identifier,
+            ItemNotVisible {
+                module_id: containing_module_id,
+                item_name: identifier.text(self.db.upcast()),
+            },
+        ))
+    }
+
     /// Wraps a ModuleItem with the corresponding ResolveGenericItem.
     fn module_item_to_generic_item(
         &mut self,
@@ -563,6 +738,7 @@ impl<'db> Resolver<'db> {
             ModuleItemId::Struct(id) => ResolvedGenericItem::GenericType(GenericTypeId::Struct(id)),
             ModuleItemId::Enum(id) => ResolvedGenericItem::GenericType(GenericTypeId::Enum(id)),
             ModuleItemId::TypeAlias(id) => ResolvedGenericItem::GenericTypeAlias(id),
+            ModuleItemId::Constant(id) => ResolvedGenericItem::Constant(id),
             ModuleItemId::ExternType(id) => {
                 ResolvedGenericItem::GenericType(GenericTypeId::Extern(id))
             }
@@ -630,7 +806,8 @@ fn specialize_trait(
         .trait_generic_params(trait_id)
         .map_err(|_| diagnostics.report_by_ptr(stable_ptr, UnknownTrait))?;
 
-    conform_generic_args(db, diagnostics, &generic_params, &mut generic_args, stable_ptr);
+    // The generic args of a trait are never inferred, as they determine the impl to use.
+    conform_generic_args(db, diagnostics, None, &generic_params, &mut generic_args, stable_ptr);
 
     Ok(db.intern_concrete_trait(ConcreteTraitLongId { trait_id, generic_args }))
 }
@@ -639,6 +816,7 @@ fn specialize_trait(
 fn specialize_impl(
     db: &dyn SemanticGroup,
     diagnostics: &mut SemanticDiagnostics,
+    inference: Option<&mut Inference<'_>>,
     stable_ptr: SyntaxStablePtrId,
     impl_id: ImplId,
     mut generic_args: Vec<GenericArgumentId>,
@@ -648,7 +826,14 @@ fn specialize_impl(
         .impl_generic_params(impl_id)
         .map_err(|_| diagnostics.report_by_ptr(stable_ptr, UnknownImpl))?;
 
-    conform_generic_args(db, diagnostics, &generic_params, &mut generic_args, stable_ptr);
+    conform_generic_args(
+        db,
+        diagnostics,
+        inference,
+        &generic_params,
+        &mut generic_args,
+        stable_ptr,
+    );
 
     Ok(db.intern_concrete_impl(ConcreteImplLongId { impl_id, generic_args }))
 }
@@ -657,6 +842,7 @@ fn specialize_impl(
 pub fn specialize_function(
     db: &dyn SemanticGroup,
     diagnostics: &mut SemanticDiagnostics,
+    inference: Option<&mut Inference<'_>>,
     stable_ptr: SyntaxStablePtrId,
     generic_function: GenericFunctionId,
     mut generic_args: Vec<GenericArgumentId>,
@@ -666,17 +852,59 @@ pub fn specialize_function(
         .generic_function_generic_params(generic_function)
         .map_err(|_| diagnostics.report_by_ptr(stable_ptr, UnknownFunction))?;
 
-    conform_generic_args(db, diagnostics, &generic_params, &mut generic_args, stable_ptr);
+    conform_generic_args(
+        db,
+        diagnostics,
+        inference,
+        &generic_params,
+        &mut generic_args,
+        stable_ptr,
+    );
 
     Ok(db.intern_function(FunctionLongId {
         function: ConcreteFunction { generic_function, generic_args },
     }))
 }
 
+/// Specializes a function of a concrete impl. The generic args of the impl precede the given
+/// generic args of the function itself.
+fn specialize_impl_function(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    inference: Option<&mut Inference<'_>>,
+    stable_ptr: SyntaxStablePtrId,
+    concrete_impl_id: ConcreteImplId,
+    impl_function_id: ImplFunctionId,
+    mut generic_args: Vec<GenericArgumentId>,
+) -> Maybe<FunctionId> {
+    let impl_generic_args = db.lookup_intern_concrete_impl(concrete_impl_id).generic_args;
+    let generic_params = db
+        .impl_function_generic_params(impl_function_id)
+        .map_err(|_| diagnostics.report_by_ptr(stable_ptr, UnknownFunction))?;
+    let function_generic_params = generic_params.get(impl_generic_args.len()..).unwrap_or_default();
+
+    conform_generic_args(
+        db,
+        diagnostics,
+        inference,
+        function_generic_params,
+        &mut generic_args,
+        stable_ptr,
+    );
+
+    Ok(db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::ImplFunction(impl_function_id),
+            generic_args: chain!(impl_generic_args, generic_args).collect(),
+        },
+    }))
+}
+
 /// Specializes a generic type.
 pub fn specialize_type(
     db: &dyn SemanticGroup,
     diagnostics: &mut SemanticDiagnostics,
+    inference: Option<&mut Inference<'_>>,
     stable_ptr: SyntaxStablePtrId,
     generic_type: GenericTypeId,
     mut generic_args: Vec<GenericArgumentId>,
@@ -685,18 +913,38 @@ pub fn specialize_type(
         .generic_type_generic_params(generic_type)
         .map_err(|_| diagnostics.report_by_ptr(stable_ptr, UnknownType))?;
 
-    conform_generic_args(db, diagnostics, &generic_params, &mut generic_args, stable_ptr);
+    conform_generic_args(
+        db,
+        diagnostics,
+        inference,
+        &generic_params,
+        &mut generic_args,
+        stable_ptr,
+    );
 
     Ok(db.intern_type(TypeLongId::Concrete(ConcreteTypeId::new(db, generic_type, generic_args))))
 }
 
+/// Makes sure the number of generic args matches the number of generic params. If the generic args
+/// are omitted and an inference is given, uses new type variables for them.
 fn conform_generic_args(
     db: &dyn SemanticGroup,
     diagnostics: &mut SemanticDiagnostics,
+    inference: Option<&mut Inference<'_>>,
     generic_params: &[GenericParamId],
     generic_args: &mut Vec<GenericArgumentId>,
     stable_ptr: SyntaxStablePtrId,
 ) {
+    if let Some(inference) = inference {
+        if generic_args.is_empty() {
+            generic_args.extend(
+                generic_params
+                    .iter()
+                    .map(|_| GenericArgumentId::Type(inference.new_var(stable_ptr))),
+            );
+            return;
+        }
+    }
     if generic_args.len() != generic_params.len() {
         let diag_added = diagnostics.report_by_ptr(
             stable_ptr,
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::db::DefsGroup;
-use cairo_lang_defs::ids::{ModuleId, ModuleItemId};
+use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleId, ModuleItemId};
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
@@ -39,15 +39,16 @@ fn test_resolve_path() {
         db.module_item_by_name(module_id, "foo".into()).unwrap().unwrap(),
         ModuleItemId::FreeFunction
     );
-    let expr_formatter = ExprFormatter { db, free_function_id };
+    let expr_formatter =
+        ExprFormatter { db, function_id: FunctionWithBodyId::Free(free_function_id) };
     let body = db.free_function_definition_body(free_function_id);
     assert_eq!(
         format!("{:?}", body.to_option().debug(&expr_formatter)),
         "Some(Block(ExprBlock { statements: [Expr(StatementExpr { expr: \
-         FunctionCall(ExprFunctionCall { function: test::bar<Type((core::felt, Q)),>, ref_args: \
-         [], args: [Var(ExprVar { var: ParamId(test::value), ty: test::S::<core::felt> })], ty: \
+         FunctionCall(ExprFunctionCall { function: test::bar::<(core::felt, Q)>, ref_args: [], \
+         args: [Var(ExprVar { var: ParamId(test::value), ty: test::S::<core::felt> })], ty: \
          test::S::<()> }) }), Let(StatementLet { pattern: Variable(c), expr: Var(ExprVar { var: \
-         ParamId(test::b), ty: Q }) })], tail: None, ty: () }))"
+         ParamId(test::b), ty: Q }), else_block: None })], tail: None, ty: () }))"
     );
 }
 
@@ -75,7 +76,7 @@ fn test_resolve_path_super() {
         struct OuterStruct {}
     "},
     );
-    set_file_content(db, "src/inner1.cairo", "struct InnerStruct1 {}");
+    set_file_content(db, "src/inner1.cairo", "pub struct InnerStruct1 {}");
     set_file_content(
         db,
         "src/inner2.cairo",
@@ -98,10 +99,11 @@ fn test_resolve_path_super() {
     let members = db.struct_members(struct_id).unwrap();
     assert_eq!(
         format!("{:?}", members["a"].debug(db)),
-        "Member { id: MemberId(test::inner2::a), ty: test::inner1::InnerStruct1 }"
+        "Member { id: MemberId(test::inner2::a), ty: test::inner1::InnerStruct1, visibility: \
+         Private }"
     );
     assert_eq!(
         format!("{:?}", members["b"].debug(db)),
-        "Member { id: MemberId(test::inner2::b), ty: test::OuterStruct }"
+        "Member { id: MemberId(test::inner2::b), ty: test::OuterStruct, visibility: Private }"
     );
 }
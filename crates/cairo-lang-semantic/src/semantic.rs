@@ -12,14 +12,15 @@ pub use crate::expr::pattern::{
     PatternVariable,
 };
 pub use crate::items::enm::{ConcreteVariant, Variant};
-pub use crate::items::free_function::FreeFunctionDefinition;
+pub use crate::items::function_with_body::FunctionDefinition;
 pub use crate::items::functions::{ConcreteFunction, FunctionId, FunctionLongId, Signature};
 pub use crate::items::imp::{ConcreteImplId, ConcreteImplLongId};
 pub use crate::items::strct::Member;
 pub use crate::items::trt::{ConcreteTraitId, ConcreteTraitLongId};
 use crate::literals::LiteralId;
 pub use crate::types::{
-    ConcreteEnumId, ConcreteExternTypeId, ConcreteStructId, ConcreteTypeId, TypeId, TypeLongId,
+    ClosureTypeLongId, ConcreteEnumId, ConcreteExternTypeId, ConcreteStructId, ConcreteTypeId,
+    TypeId, TypeLongId,
 };
 
 /// Semantic model of a variable.
@@ -93,3 +94,11 @@ pub enum GenericArgumentId {
     Literal(LiteralId),
     // TODO(spapini): impls and constants as generic values.
 }
+impl GenericArgumentId {
+    pub fn format(&self, db: &dyn SemanticGroup) -> String {
+        match self {
+            GenericArgumentId::Type(ty) => ty.format(db),
+            GenericArgumentId::Literal(literal_id) => literal_id.format(db),
+        }
+    }
+}
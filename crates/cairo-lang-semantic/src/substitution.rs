@@ -0,0 +1,269 @@
+//! Rewriting of the types in the semantic model.
+//! Used both by the type inference, which substitutes the types assigned to type variables, and by
+//! the specialization of generic functions, which substitutes generic arguments for generic
+//! parameters.
+
+use std::collections::HashMap;
+
+use cairo_lang_defs::ids::GenericParamId;
+use cairo_lang_diagnostics::skip_diagnostic;
+
+use crate::db::SemanticGroup;
+use crate::expr::inference::TypeVar;
+use crate::items::enm::ConcreteVariant;
+use crate::types::{ConcreteEnumLongId, ConcreteStructLongId};
+use crate::{
+    AssignedMember, ClosureTypeLongId, ConcreteEnumId, ConcreteFunction, ConcreteStructId,
+    ConcreteTypeId, Expr, FunctionId, FunctionLongId, GenericArgumentId, Parameter, Pattern,
+    Statement, TypeId, TypeLongId,
+};
+
+/// A rewriter of the leaf types of the semantic model. All the other types are rewritten
+/// structurally, by rewriting their components.
+pub trait SemanticRewriter {
+    fn db(&self) -> &dyn SemanticGroup;
+
+    /// Rewrites a type variable. Returns the type itself by default.
+    fn rewrite_type_var(&self, ty: TypeId, _type_var: TypeVar) -> TypeId {
+        ty
+    }
+
+    /// Rewrites a generic parameter type. Returns the type itself by default.
+    fn rewrite_generic_param(&self, ty: TypeId, _generic_param: GenericParamId) -> TypeId {
+        ty
+    }
+
+    /// Rewrites a type.
+    fn rewrite_ty(&self, ty: TypeId) -> TypeId {
+        let db = self.db();
+        match db.lookup_intern_type(ty) {
+            TypeLongId::Concrete(concrete) => {
+                db.intern_type(TypeLongId::Concrete(self.rewrite_concrete_ty(concrete)))
+            }
+            TypeLongId::Tuple(tys) => db.intern_type(TypeLongId::Tuple(
+                tys.into_iter().map(|ty| self.rewrite_ty(ty)).collect(),
+            )),
+            TypeLongId::Closure(closure) => {
+                db.intern_type(TypeLongId::Closure(self.rewrite_closure_ty(closure)))
+            }
+            TypeLongId::Var(type_var) => self.rewrite_type_var(ty, type_var),
+            TypeLongId::GenericParameter(generic_param) => {
+                self.rewrite_generic_param(ty, generic_param)
+            }
+            TypeLongId::Missing(_) => ty,
+        }
+    }
+
+    /// Rewrites a concrete type.
+    fn rewrite_concrete_ty(&self, concrete: ConcreteTypeId) -> ConcreteTypeId {
+        match concrete {
+            ConcreteTypeId::Struct(concrete_struct_id) => {
+                ConcreteTypeId::Struct(self.rewrite_concrete_struct(concrete_struct_id))
+            }
+            ConcreteTypeId::Enum(concrete_enum_id) => {
+                ConcreteTypeId::Enum(self.rewrite_concrete_enum(concrete_enum_id))
+            }
+            ConcreteTypeId::Extern(_) => ConcreteTypeId::new(
+                self.db(),
+                concrete.generic_type(self.db()),
+                self.rewrite_generic_args(concrete.generic_args(self.db())),
+            ),
+        }
+    }
+
+    /// Rewrites a closure type.
+    fn rewrite_closure_ty(&self, closure: ClosureTypeLongId) -> ClosureTypeLongId {
+        ClosureTypeLongId {
+            parent: self.rewrite_function(closure.parent),
+            param_tys: closure.param_tys.into_iter().map(|ty| self.rewrite_ty(ty)).collect(),
+            ret_ty: self.rewrite_ty(closure.ret_ty),
+            captured_tys: closure.captured_tys.into_iter().map(|ty| self.rewrite_ty(ty)).collect(),
+            stable_ptr: closure.stable_ptr,
+        }
+    }
+
+    /// Rewrites a list of generic arguments.
+    fn rewrite_generic_args(&self, generic_args: Vec<GenericArgumentId>) -> Vec<GenericArgumentId> {
+        generic_args
+            .into_iter()
+            .map(|generic_arg| match generic_arg {
+                GenericArgumentId::Type(ty) => GenericArgumentId::Type(self.rewrite_ty(ty)),
+                GenericArgumentId::Literal(_) => generic_arg,
+            })
+            .collect()
+    }
+
+    /// Rewrites a concrete struct.
+    fn rewrite_concrete_struct(&self, concrete_struct_id: ConcreteStructId) -> ConcreteStructId {
+        let ConcreteStructLongId { struct_id, generic_args } =
+            self.db().lookup_intern_concrete_struct(concrete_struct_id);
+        self.db().intern_concrete_struct(ConcreteStructLongId {
+            struct_id,
+            generic_args: self.rewrite_generic_args(generic_args),
+        })
+    }
+
+    /// Rewrites a concrete enum.
+    fn rewrite_concrete_enum(&self, concrete_enum_id: ConcreteEnumId) -> ConcreteEnumId {
+        let ConcreteEnumLongId { enum_id, generic_args } =
+            self.db().lookup_intern_concrete_enum(concrete_enum_id);
+        self.db().intern_concrete_enum(ConcreteEnumLongId {
+            enum_id,
+            generic_args: self.rewrite_generic_args(generic_args),
+        })
+    }
+
+    /// Rewrites a concrete variant.
+    fn rewrite_concrete_variant(&self, concrete_variant: ConcreteVariant) -> ConcreteVariant {
+        ConcreteVariant {
+            concrete_enum_id: self.rewrite_concrete_enum(concrete_variant.concrete_enum_id),
+            ty: self.rewrite_ty(concrete_variant.ty),
+            ..concrete_variant
+        }
+    }
+
+    /// Rewrites the generic arguments of a function.
+    fn rewrite_function(&self, function_id: FunctionId) -> FunctionId {
+        let FunctionLongId { function: ConcreteFunction { generic_function, generic_args } } =
+            self.db().lookup_intern_function(function_id);
+        self.db().intern_function(FunctionLongId {
+            function: ConcreteFunction {
+                generic_function,
+                generic_args: self.rewrite_generic_args(generic_args),
+            },
+        })
+    }
+
+    /// Rewrites the type of a parameter.
+    fn rewrite_param(&self, param: &mut Parameter) {
+        param.ty = self.rewrite_ty(param.ty);
+    }
+
+    /// Rewrites all the types of an expression. Does not handle its subexpressions.
+    fn rewrite_expr(&self, expr: &mut Expr) {
+        match expr {
+            Expr::Tuple(expr) => expr.ty = self.rewrite_ty(expr.ty),
+            Expr::Assignment(expr) => {
+                for member in expr.members.iter_mut() {
+                    if let AssignedMember::Struct { concrete_struct_id, .. } = member {
+                        *concrete_struct_id = self.rewrite_concrete_struct(*concrete_struct_id);
+                    }
+                }
+                expr.ty = self.rewrite_ty(expr.ty);
+            }
+            Expr::Block(expr) => expr.ty = self.rewrite_ty(expr.ty),
+            Expr::FunctionCall(expr) => {
+                expr.function = self.rewrite_function(expr.function);
+                expr.ty = self.rewrite_ty(expr.ty);
+            }
+            Expr::Match(expr) => {
+                for arm in expr.arms.iter_mut() {
+                    self.rewrite_pattern(&mut arm.pattern);
+                }
+                expr.ty = self.rewrite_ty(expr.ty);
+            }
+            Expr::If(expr) => expr.ty = self.rewrite_ty(expr.ty),
+            Expr::IfLet(expr) => {
+                self.rewrite_pattern(&mut expr.pattern);
+                expr.ty = self.rewrite_ty(expr.ty);
+            }
+            Expr::Loop(expr) => expr.ty = self.rewrite_ty(expr.ty),
+            Expr::While(expr) => expr.ty = self.rewrite_ty(expr.ty),
+            Expr::Closure(expr) => {
+                for param in expr.params.iter_mut() {
+                    self.rewrite_param(param);
+                }
+                expr.ty = self.rewrite_ty(expr.ty);
+            }
+            Expr::Var(expr) => expr.ty = self.rewrite_ty(expr.ty),
+            Expr::Literal(expr) => expr.ty = self.rewrite_ty(expr.ty),
+            Expr::MemberAccess(expr) => {
+                expr.concrete_struct_id = self.rewrite_concrete_struct(expr.concrete_struct_id);
+                expr.ty = self.rewrite_ty(expr.ty);
+            }
+            Expr::TupleItem(expr) => expr.ty = self.rewrite_ty(expr.ty),
+            Expr::StructCtor(expr) => {
+                expr.concrete_struct_id = self.rewrite_concrete_struct(expr.concrete_struct_id);
+                expr.ty = self.rewrite_ty(expr.ty);
+            }
+            Expr::EnumVariantCtor(expr) => {
+                expr.variant = self.rewrite_concrete_variant(expr.variant.clone());
+                expr.ty = self.rewrite_ty(expr.ty);
+            }
+            Expr::PropagateError(expr) => {
+                expr.ok_variant = self.rewrite_concrete_variant(expr.ok_variant.clone());
+                expr.err_variant = self.rewrite_concrete_variant(expr.err_variant.clone());
+                expr.func_err_variant =
+                    self.rewrite_concrete_variant(expr.func_err_variant.clone());
+            }
+            Expr::Missing(expr) => expr.ty = self.rewrite_ty(expr.ty),
+        }
+    }
+
+    /// Rewrites all the types of a statement. Does not handle its subexpressions.
+    fn rewrite_statement(&self, statement: &mut Statement) {
+        if let Statement::Let(statement_let) = statement {
+            self.rewrite_pattern(&mut statement_let.pattern);
+        }
+    }
+
+    /// Rewrites all the types of a pattern.
+    fn rewrite_pattern(&self, pattern: &mut Pattern) {
+        match pattern {
+            Pattern::Literal(pattern) => {
+                pattern.literal.ty = self.rewrite_ty(pattern.literal.ty);
+                pattern.ty = self.rewrite_ty(pattern.ty);
+            }
+            Pattern::Variable(pattern) => pattern.var.ty = self.rewrite_ty(pattern.var.ty),
+            Pattern::Struct(pattern) => {
+                pattern.concrete_struct_id =
+                    self.rewrite_concrete_struct(pattern.concrete_struct_id);
+                for (member, field_pattern) in pattern.field_patterns.iter_mut() {
+                    member.ty = self.rewrite_ty(member.ty);
+                    self.rewrite_pattern(field_pattern);
+                }
+                pattern.ty = self.rewrite_ty(pattern.ty);
+            }
+            Pattern::Tuple(pattern) => {
+                for field_pattern in pattern.field_patterns.iter_mut() {
+                    self.rewrite_pattern(field_pattern);
+                }
+                pattern.ty = self.rewrite_ty(pattern.ty);
+            }
+            Pattern::EnumVariant(pattern) => {
+                pattern.variant = self.rewrite_concrete_variant(pattern.variant.clone());
+                self.rewrite_pattern(&mut pattern.inner_pattern);
+                pattern.ty = self.rewrite_ty(pattern.ty);
+            }
+            Pattern::Otherwise(pattern) => pattern.ty = self.rewrite_ty(pattern.ty),
+        }
+    }
+}
+
+/// A substitution of generic arguments for generic parameters.
+pub struct GenericSubstitution<'a> {
+    db: &'a dyn SemanticGroup,
+    substitution: &'a HashMap<GenericParamId, GenericArgumentId>,
+}
+impl<'a> GenericSubstitution<'a> {
+    pub fn new(
+        db: &'a dyn SemanticGroup,
+        substitution: &'a HashMap<GenericParamId, GenericArgumentId>,
+    ) -> Self {
+        Self { db, substitution }
+    }
+}
+impl<'a> SemanticRewriter for GenericSubstitution<'a> {
+    fn db(&self) -> &dyn SemanticGroup {
+        self.db
+    }
+
+    fn rewrite_generic_param(&self, ty: TypeId, generic_param: GenericParamId) -> TypeId {
+        match self.substitution.get(&generic_param) {
+            Some(GenericArgumentId::Type(ty)) => *ty,
+            // TODO(ilya): Add diagnostics: "Expected type. Got literal"
+            Some(GenericArgumentId::Literal(_)) => TypeId::missing(self.db, skip_diagnostic()),
+            None => ty,
+        }
+    }
+}
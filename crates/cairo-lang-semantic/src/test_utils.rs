@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use cairo_lang_defs::db::{DefsDatabase, DefsGroup, HasMacroPlugins};
-use cairo_lang_defs::ids::{FreeFunctionId, GenericFunctionId, ModuleId};
+use cairo_lang_defs::ids::{FreeFunctionId, FunctionWithBodyId, GenericFunctionId, ModuleId};
 use cairo_lang_defs::plugin::MacroPlugin;
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder};
 use cairo_lang_filesystem::db::{
@@ -179,15 +179,18 @@ pub fn setup_test_expr(
     let (test_function, diagnostics) =
         setup_test_function(db, &function_code, "test_func", module_code).split();
     let semantic::ExprBlock { statements, .. } = extract_matches!(
-        db.expr_semantic(test_function.function_id, test_function.body),
+        db.expr_semantic(FunctionWithBodyId::Free(test_function.function_id), test_function.body),
         semantic::Expr::Block
     );
     let statement_expr = extract_matches!(
-        db.statement_semantic(test_function.function_id, *statements.last().unwrap()),
+        db.statement_semantic(
+            FunctionWithBodyId::Free(test_function.function_id),
+            *statements.last().unwrap()
+        ),
         semantic::Statement::Expr
     );
     let semantic::ExprBlock { statements, tail, .. } = extract_matches!(
-        db.expr_semantic(test_function.function_id, statement_expr.expr),
+        db.expr_semantic(FunctionWithBodyId::Free(test_function.function_id), statement_expr.expr),
         semantic::Expr::Block
     );
     assert!(
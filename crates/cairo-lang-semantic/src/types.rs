@@ -1,21 +1,31 @@
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::iter::zip;
 
 use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
-    EnumId, ExternTypeId, GenericParamId, GenericTypeId, LanguageElementId, StructId,
+    EnumId, ExternTypeId, FunctionWithBodyId, GenericParamId, GenericTypeId, LanguageElementId,
+    StructId,
 };
-use cairo_lang_diagnostics::{skip_diagnostic, DiagnosticAdded, Maybe};
+use cairo_lang_diagnostics::{DiagnosticAdded, DiagnosticLocation, Maybe};
+use cairo_lang_filesystem::span::TextPosition;
+use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax::node::ast;
 use cairo_lang_utils::{define_short_id, OptionFrom};
-use itertools::Itertools;
+use itertools::{chain, Itertools};
 
 use crate::corelib::{concrete_copy_trait, concrete_drop_trait};
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
+use crate::expr::inference::TypeVar;
+use crate::items::enm::SemanticEnumEx;
 use crate::items::imp::{find_impls_at_context, ImplLookupContext};
+use crate::items::strct::SemanticStructEx;
 use crate::resolve_path::{ResolvedConcreteItem, Resolver};
-use crate::{semantic, GenericArgumentId};
+use crate::substitution::{GenericSubstitution, SemanticRewriter};
+use crate::{semantic, FunctionId, GenericArgumentId};
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum TypeLongId {
@@ -24,6 +34,11 @@ pub enum TypeLongId {
     /// during inference.
     Tuple(Vec<TypeId>),
     GenericParameter(GenericParamId),
+    /// A type that is not known yet, and should be inferred. Only exists while computing the
+    /// semantic model of a function body.
+    Var(TypeVar),
+    /// The type of a closure expression.
+    Closure(ClosureTypeLongId),
     Missing(DiagnosticAdded),
 }
 impl OptionFrom<TypeLongId> for ConcreteTypeId {
@@ -55,7 +70,38 @@ impl TypeId {
     pub fn is_missing(&self, db: &dyn SemanticGroup) -> bool {
         self.check_not_missing(db).is_err()
     }
+
+    /// Returns `true` if the type does not depend on any generic parameter.
+    pub fn is_fully_concrete(&self, db: &dyn SemanticGroup) -> bool {
+        match db.lookup_intern_type(*self) {
+            TypeLongId::Concrete(concrete_type_id) => {
+                generic_args_fully_concrete(db, &concrete_type_id.generic_args(db))
+            }
+            TypeLongId::Tuple(tys) => tys.into_iter().all(|ty| ty.is_fully_concrete(db)),
+            TypeLongId::Closure(closure) => {
+                let parent_generic_args =
+                    db.lookup_intern_function(closure.parent).function.generic_args;
+                generic_args_fully_concrete(db, &parent_generic_args)
+                    && chain!(&closure.param_tys, [&closure.ret_ty], &closure.captured_tys)
+                        .all(|ty| ty.is_fully_concrete(db))
+            }
+            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) => false,
+            TypeLongId::Missing(_) => true,
+        }
+    }
+}
+
+/// Returns `true` if none of the generic arguments depends on a generic parameter.
+pub fn generic_args_fully_concrete(
+    db: &dyn SemanticGroup,
+    generic_args: &[GenericArgumentId],
+) -> bool {
+    generic_args.iter().all(|generic_arg| match generic_arg {
+        GenericArgumentId::Type(ty) => ty.is_fully_concrete(db),
+        GenericArgumentId::Literal(_) => true,
+    })
 }
+
 impl TypeLongId {
     pub fn format(&self, db: &dyn SemanticGroup) -> String {
         match self {
@@ -70,6 +116,8 @@ impl TypeLongId {
             TypeLongId::GenericParameter(generic_param) => {
                 generic_param.name(db.upcast()).to_string()
             }
+            TypeLongId::Var(type_var) => format!("?{}", type_var.id),
+            TypeLongId::Closure(closure) => closure.format(db),
             TypeLongId::Missing(_) => "<missing>".to_string(),
         }
     }
@@ -84,6 +132,47 @@ impl DebugWithDb<dyn SemanticGroup> for TypeLongId {
     }
 }
 
+/// The type of a closure expression. Each closure expression has a distinct type, holding the
+/// types of the variables it captures.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ClosureTypeLongId {
+    /// The function whose body contains the closure expression.
+    pub parent: FunctionId,
+    pub param_tys: Vec<TypeId>,
+    pub ret_ty: TypeId,
+    /// The types of the captured variables, in the order of
+    /// [crate::ExprClosure::captured_vars].
+    pub captured_tys: Vec<TypeId>,
+    pub stable_ptr: ast::ExprPtr,
+}
+
+impl ClosureTypeLongId {
+    /// Formats the closure type by its location, as closures of the same signature are different
+    /// types.
+    pub fn format(&self, db: &dyn SemanticGroup) -> String {
+        let parent = db.lookup_intern_function(self.parent).function;
+        let mut parent_name = parent.generic_function.format(db.upcast());
+        if !parent.generic_args.is_empty() {
+            parent_name +=
+                &format!("::<{}>", parent.generic_args.iter().map(|arg| arg.format(db)).join(", "));
+        }
+        let location =
+            FunctionWithBodyId::option_from(parent.generic_function).and_then(|function_id| {
+                let stable_location =
+                    StableLocation::new(function_id.module_file(db.upcast()), self.stable_ptr.0);
+                let DiagnosticLocation { file_id, span } =
+                    stable_location.diagnostic_location(db.upcast());
+                span.start.position_in_file(db.upcast(), file_id)
+            });
+        match location {
+            Some(TextPosition { line, col }) => {
+                format!("{{closure@{parent_name}:{}:{}}}", line + 1, col + 1)
+            }
+            None => format!("{{closure@{parent_name}}}"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum ConcreteTypeId {
     Struct(ConcreteStructId),
@@ -141,13 +230,7 @@ impl ConcreteTypeId {
             format!(
                 "{}::<{}>",
                 generic_type_format,
-                generic_args
-                    .iter()
-                    .map(|arg| match arg {
-                        crate::GenericArgumentId::Type(ty) => ty.format(db),
-                        crate::GenericArgumentId::Literal(literal_id) => literal_id.format(db),
-                    })
-                    .join(", ")
+                generic_args.iter().map(|arg| arg.format(db)).join(", ")
             )
         }
     }
@@ -162,7 +245,8 @@ impl DebugWithDb<dyn SemanticGroup> for ConcreteTypeId {
     }
 }
 
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
 pub struct ConcreteStructLongId {
     pub struct_id: StructId,
     pub generic_args: Vec<semantic::GenericArgumentId>,
@@ -179,7 +263,8 @@ impl ConcreteStructId {
     }
 }
 
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
 pub struct ConcreteEnumLongId {
     pub enum_id: EnumId,
     pub generic_args: Vec<semantic::GenericArgumentId>,
@@ -270,40 +355,71 @@ pub fn substitute_generics(
     substitution: &HashMap<GenericParamId, GenericArgumentId>,
     ty: crate::TypeId,
 ) -> TypeId {
-    match db.lookup_intern_type(ty) {
-        TypeLongId::Concrete(concrete) => {
-            db.intern_type(TypeLongId::Concrete(ConcreteTypeId::new(
-                db,
-                concrete.generic_type(db),
-                concrete
-                    .generic_args(db)
-                    .iter()
-                    .map(|generic_arg| match generic_arg {
-                        GenericArgumentId::Type(ty) => {
-                            GenericArgumentId::Type(substitute_generics(db, substitution, *ty))
-                        }
-                        GenericArgumentId::Literal(_) => *generic_arg,
-                    })
-                    .collect(),
-            )))
-        }
-        TypeLongId::Tuple(tys) => db.intern_type(TypeLongId::Tuple(
-            tys.into_iter().map(|ty| substitute_generics(db, substitution, ty)).collect(),
-        )),
-        TypeLongId::GenericParameter(generic_param) => substitution
-            .get(&generic_param)
-            .map(|generic_arg| match generic_arg {
-                GenericArgumentId::Type(ty) => *ty,
-                GenericArgumentId::Literal(_) => {
-                    // TODO(ilya): Add diagnostics: "Expected type. Got literal"
-                    TypeId::missing(db, skip_diagnostic())
+    GenericSubstitution::new(db, substitution).rewrite_ty(ty)
+}
+
+/// Tries to find values for `generic_params` such that substituting them in `generic_ty` results in
+/// `concrete_ty`. The found values are added to `substitution`, which may already hold values for
+/// some of the params.
+/// Returns false if the types do not match.
+pub fn infer_generic_args(
+    db: &dyn SemanticGroup,
+    generic_params: &[GenericParamId],
+    generic_ty: TypeId,
+    concrete_ty: TypeId,
+    substitution: &mut HashMap<GenericParamId, GenericArgumentId>,
+) -> bool {
+    match (db.lookup_intern_type(generic_ty), db.lookup_intern_type(concrete_ty)) {
+        (TypeLongId::GenericParameter(generic_param), _)
+            if generic_params.contains(&generic_param) =>
+        {
+            match substitution.entry(generic_param) {
+                Entry::Occupied(entry) => *entry.get() == GenericArgumentId::Type(concrete_ty),
+                Entry::Vacant(entry) => {
+                    entry.insert(GenericArgumentId::Type(concrete_ty));
+                    true
                 }
-            })
-            .unwrap_or(ty),
-        TypeLongId::Missing(_) => ty,
+            }
+        }
+        (TypeLongId::Concrete(generic_concrete), TypeLongId::Concrete(concrete)) => {
+            generic_concrete.generic_type(db) == concrete.generic_type(db)
+                && infer_generic_args_from_args(
+                    db,
+                    generic_params,
+                    &generic_concrete.generic_args(db),
+                    &concrete.generic_args(db),
+                    substitution,
+                )
+        }
+        (TypeLongId::Tuple(generic_tys), TypeLongId::Tuple(tys)) => {
+            generic_tys.len() == tys.len()
+                && zip(generic_tys, tys).all(|(generic_ty, ty)| {
+                    infer_generic_args(db, generic_params, generic_ty, ty, substitution)
+                })
+        }
+        _ => generic_ty == concrete_ty,
     }
 }
 
+/// Same as [infer_generic_args], for lists of generic arguments.
+pub fn infer_generic_args_from_args(
+    db: &dyn SemanticGroup,
+    generic_params: &[GenericParamId],
+    generic_args: &[GenericArgumentId],
+    concrete_args: &[GenericArgumentId],
+    substitution: &mut HashMap<GenericParamId, GenericArgumentId>,
+) -> bool {
+    generic_args.len() == concrete_args.len()
+        && zip(generic_args, concrete_args).all(|(generic_arg, concrete_arg)| {
+            match (generic_arg, concrete_arg) {
+                (GenericArgumentId::Type(generic_ty), GenericArgumentId::Type(ty)) => {
+                    infer_generic_args(db, generic_params, *generic_ty, *ty, substitution)
+                }
+                _ => generic_arg == concrete_arg,
+            }
+        })
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct TypeInfo {
     /// Can the type be (trivially) dropped.
@@ -326,12 +442,34 @@ pub fn type_info(
             if !lookup_context.extra_modules.contains(&module) {
                 lookup_context.extra_modules.push(module);
             }
-            let droppable =
+            let mut droppable =
                 !find_impls_at_context(db, &lookup_context, concrete_drop_trait(db, ty))?
                     .is_empty();
-            let duplicatable =
+            let mut duplicatable =
                 !find_impls_at_context(db, &lookup_context, concrete_copy_trait(db, ty))?
                     .is_empty();
+            // Generic impls of Copy and Drop for a generic struct or enum do not constrain its
+            // generic args, so the concrete members must be checked as well.
+            if !concrete_type_id.generic_args(db).is_empty() {
+                let member_tys = match concrete_type_id {
+                    ConcreteTypeId::Struct(concrete_struct_id) => db
+                        .concrete_struct_members(concrete_struct_id)?
+                        .into_iter()
+                        .map(|(_, member)| member.ty)
+                        .collect(),
+                    ConcreteTypeId::Enum(concrete_enum_id) => db
+                        .concrete_enum_variants(concrete_enum_id)?
+                        .into_iter()
+                        .map(|variant| variant.ty)
+                        .collect(),
+                    ConcreteTypeId::Extern(_) => vec![],
+                };
+                for member_ty in member_tys {
+                    let member_info = db.type_info(lookup_context.clone(), member_ty)?;
+                    droppable &= member_info.droppable;
+                    duplicatable &= member_info.duplicatable;
+                }
+            }
             TypeInfo { droppable, duplicatable }
         }
         TypeLongId::Tuple(tys) => {
@@ -343,7 +481,20 @@ pub fn type_info(
             let duplicatable = infos.iter().all(|info| info.duplicatable);
             TypeInfo { droppable, duplicatable }
         }
-        TypeLongId::GenericParameter(_) => todo!(),
+        TypeLongId::Closure(closure) => {
+            let infos = closure
+                .captured_tys
+                .into_iter()
+                .map(|ty| db.type_info(lookup_context.clone(), ty))
+                .collect::<Maybe<Vec<_>>>()?;
+            let droppable = infos.iter().all(|info| info.droppable);
+            let duplicatable = infos.iter().all(|info| info.duplicatable);
+            TypeInfo { droppable, duplicatable }
+        }
+        // TODO: Take trait bounds into account, once generic params may have them.
+        TypeLongId::GenericParameter(_) | TypeLongId::Var(_) => {
+            TypeInfo { droppable: false, duplicatable: false }
+        }
         TypeLongId::Missing(diag_added) => {
             return Err(diag_added);
         }
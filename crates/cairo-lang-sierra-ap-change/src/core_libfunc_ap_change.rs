@@ -10,9 +10,12 @@ use cairo_lang_sierra::extensions::felt::FeltConcrete;
 use cairo_lang_sierra::extensions::gas::GasConcreteLibfunc;
 use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc;
 use cairo_lang_sierra::extensions::nullable::NullableConcreteLibfunc;
+use cairo_lang_sierra::extensions::sint::{SintConcrete, SintTraits};
 use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use cairo_lang_sierra::extensions::strct::StructConcreteLibfunc;
-use cairo_lang_sierra::extensions::uint128::{IntOperator, Uint128Concrete};
+use cairo_lang_sierra::extensions::uint::{UintConcrete, UintOperator, UintTraits};
+use cairo_lang_sierra::extensions::uint128::{IntOperator, ShiftOperator, Uint128Concrete};
+use cairo_lang_sierra::extensions::uint256::Uint256Concrete;
 
 use crate::ApChange;
 
@@ -27,6 +30,11 @@ pub fn core_libfunc_ap_change(libfunc: &CoreConcreteLibfunc) -> Vec<ApChange> {
             ArrayConcreteLibfunc::PopFront(_) => vec![ApChange::Known(1), ApChange::Known(1)],
             ArrayConcreteLibfunc::At(_) => vec![ApChange::Known(5), ApChange::Known(3)],
             ArrayConcreteLibfunc::Len(_) => vec![ApChange::Known(0)],
+            ArrayConcreteLibfunc::Slice(_) => vec![ApChange::Known(6), ApChange::Known(7)],
+            ArrayConcreteLibfunc::SlicePopFront(_) => {
+                vec![ApChange::Known(1), ApChange::Known(1)]
+            }
+            ArrayConcreteLibfunc::SliceLen(_) => vec![ApChange::Known(0)],
         },
         CoreConcreteLibfunc::Bitwise(_) => vec![ApChange::Known(0)],
         CoreConcreteLibfunc::BranchAlign(_) => vec![ApChange::FromMetadata],
@@ -60,6 +68,10 @@ pub fn core_libfunc_ap_change(libfunc: &CoreConcreteLibfunc) -> Vec<ApChange> {
             GasConcreteLibfunc::GetGas(_) => vec![ApChange::Known(2), ApChange::Known(2)],
             GasConcreteLibfunc::RefundGas(_) => vec![ApChange::Known(0)],
         },
+        CoreConcreteLibfunc::Uint8(libfunc) => uint_libfunc_ap_change(libfunc),
+        CoreConcreteLibfunc::Uint16(libfunc) => uint_libfunc_ap_change(libfunc),
+        CoreConcreteLibfunc::Uint32(libfunc) => uint_libfunc_ap_change(libfunc),
+        CoreConcreteLibfunc::Uint64(libfunc) => uint_libfunc_ap_change(libfunc),
         CoreConcreteLibfunc::Uint128(libfunc) => match libfunc {
             Uint128Concrete::Operation(libfunc) => match libfunc.operator {
                 IntOperator::OverflowingAdd | IntOperator::OverflowingSub => {
@@ -75,7 +87,20 @@ pub fn core_libfunc_ap_change(libfunc: &CoreConcreteLibfunc) -> Vec<ApChange> {
             Uint128Concrete::FromFelt(_) => vec![ApChange::Known(1), ApChange::Known(6)],
             Uint128Concrete::Const(_) | Uint128Concrete::ToFelt(_) => vec![ApChange::Known(0)],
             Uint128Concrete::JumpNotZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Uint128Concrete::Shift(libfunc) => match libfunc.operator {
+                ShiftOperator::Shl => vec![ApChange::Known(49), ApChange::Known(2)],
+                ShiftOperator::Shr => vec![ApChange::Known(46), ApChange::Known(2)],
+            },
+        },
+        CoreConcreteLibfunc::Uint256(libfunc) => match libfunc {
+            Uint256Concrete::JumpNotZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+            Uint256Concrete::DivMod(_) => vec![ApChange::Known(31)],
         },
+        CoreConcreteLibfunc::Sint8(libfunc) => sint_libfunc_ap_change(libfunc),
+        CoreConcreteLibfunc::Sint16(libfunc) => sint_libfunc_ap_change(libfunc),
+        CoreConcreteLibfunc::Sint32(libfunc) => sint_libfunc_ap_change(libfunc),
+        CoreConcreteLibfunc::Sint64(libfunc) => sint_libfunc_ap_change(libfunc),
+        CoreConcreteLibfunc::Sint128(libfunc) => sint_libfunc_ap_change(libfunc),
         CoreConcreteLibfunc::Mem(libfunc) => match libfunc {
             MemConcreteLibfunc::StoreTemp(libfunc) => {
                 vec![ApChange::KnownByTypeSize(libfunc.ty.clone())]
@@ -114,6 +139,10 @@ pub fn core_libfunc_ap_change(libfunc: &CoreConcreteLibfunc) -> Vec<ApChange> {
         CoreConcreteLibfunc::Pedersen(_) => vec![ApChange::Known(0)],
         CoreConcreteLibfunc::StarkNet(libfunc) => match libfunc {
             StarkNetConcreteLibfunc::ContractAddressConst(_) => vec![ApChange::Known(0)],
+            StarkNetConcreteLibfunc::ContractAddressTryFromFelt(_) => {
+                vec![ApChange::Known(5), ApChange::Known(7)]
+            }
+            StarkNetConcreteLibfunc::ContractAddressToFelt(_) => vec![ApChange::Known(0)],
             StarkNetConcreteLibfunc::CallContract(_) => {
                 vec![ApChange::Known(2), ApChange::Known(2)]
             }
@@ -122,6 +151,20 @@ pub fn core_libfunc_ap_change(libfunc: &CoreConcreteLibfunc) -> Vec<ApChange> {
                 vec![ApChange::Known(2), ApChange::Known(2)]
             }
             StarkNetConcreteLibfunc::StorageAddressConst(_) => vec![ApChange::Known(0)],
+            StarkNetConcreteLibfunc::StorageBaseAddressConst(_) => vec![ApChange::Known(0)],
+            StarkNetConcreteLibfunc::StorageBaseAddressFromFelt(_) => vec![ApChange::Known(10)],
+            StarkNetConcreteLibfunc::StorageAddressFromBase(_) => vec![ApChange::Known(0)],
+            StarkNetConcreteLibfunc::StorageAddressFromBaseAndOffset(_) => {
+                vec![ApChange::Known(0)]
+            }
+            StarkNetConcreteLibfunc::EmitEvent(_) => vec![ApChange::Known(2), ApChange::Known(2)],
+            StarkNetConcreteLibfunc::GetCallerAddress(_)
+            | StarkNetConcreteLibfunc::GetContractAddress(_)
+            | StarkNetConcreteLibfunc::GetBlockNumber(_)
+            | StarkNetConcreteLibfunc::GetBlockTimestamp(_)
+            | StarkNetConcreteLibfunc::GetTxInfo(_) => {
+                vec![ApChange::Known(2), ApChange::Known(2)]
+            }
         },
         CoreConcreteLibfunc::Nullable(libfunc) => match libfunc {
             NullableConcreteLibfunc::Null(_) => vec![ApChange::Known(0)],
@@ -132,3 +175,42 @@ pub fn core_libfunc_ap_change(libfunc: &CoreConcreteLibfunc) -> Vec<ApChange> {
         },
     }
 }
+
+/// Returns the ap change for the libfuncs of an unsigned integer smaller than u128.
+fn uint_libfunc_ap_change<TUintTraits: UintTraits>(
+    libfunc: &UintConcrete<TUintTraits>,
+) -> Vec<ApChange> {
+    match libfunc {
+        UintConcrete::Operation(libfunc) => match libfunc.operator {
+            UintOperator::OverflowingAdd => vec![ApChange::Known(3), ApChange::Known(3)],
+            UintOperator::OverflowingSub => vec![ApChange::Known(2), ApChange::Known(4)],
+            UintOperator::DivMod => vec![ApChange::Known(5)],
+            UintOperator::WideMul => vec![ApChange::Known(0)],
+        },
+        UintConcrete::LessThan(_) => vec![ApChange::Known(2), ApChange::Known(3)],
+        UintConcrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+        UintConcrete::LessThanOrEqual(_) => vec![ApChange::Known(3), ApChange::Known(2)],
+        UintConcrete::FromFelt(_) => vec![ApChange::Known(2), ApChange::Known(7)],
+        UintConcrete::Const(_) | UintConcrete::ToFelt(_) => vec![ApChange::Known(0)],
+        UintConcrete::JumpNotZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+    }
+}
+
+/// Returns the ap change for the libfuncs of a signed integer.
+fn sint_libfunc_ap_change<TSintTraits: SintTraits>(
+    libfunc: &SintConcrete<TSintTraits>,
+) -> Vec<ApChange> {
+    match libfunc {
+        SintConcrete::Operation(libfunc) => match libfunc.operator {
+            IntOperator::OverflowingAdd | IntOperator::OverflowingSub => {
+                vec![ApChange::Known(4), ApChange::Known(6), ApChange::Known(6)]
+            }
+            IntOperator::WideMul => vec![ApChange::Known(0)],
+            IntOperator::OverflowingMul | IntOperator::DivMod => unreachable!(),
+        },
+        SintConcrete::Diff(_) => vec![ApChange::Known(2), ApChange::Known(4)],
+        SintConcrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+        SintConcrete::FromFelt(_) => vec![ApChange::Known(3), ApChange::Known(8)],
+        SintConcrete::Const(_) | SintConcrete::ToFelt(_) => vec![ApChange::Known(0)],
+    }
+}
@@ -6,8 +6,8 @@ use cairo_lang_sierra::extensions::builtin_cost::{
 };
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc::{
     self, ApTracking, Array, Bitwise, Bool, Box, BranchAlign, BuiltinCost, DictFeltTo, Drop, Dup,
-    Ec, Enum, Felt, FunctionCall, Gas, Mem, Pedersen, Struct, Uint128, UnconditionalJump,
-    UnwrapNonZero,
+    Ec, Enum, Felt, FunctionCall, Gas, Mem, Pedersen, Sint128, Sint16, Sint32, Sint64, Sint8,
+    Struct, Uint128, Uint16, Uint256, Uint32, Uint64, Uint8, UnconditionalJump, UnwrapNonZero,
 };
 use cairo_lang_sierra::extensions::dict_felt_to::DictFeltToConcreteLibfunc;
 use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
@@ -19,10 +19,16 @@ use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc::{
     AlignTemps, AllocLocal, FinalizeLocals, Rename, StoreLocal, StoreTemp,
 };
 use cairo_lang_sierra::extensions::nullable::NullableConcreteLibfunc;
+use cairo_lang_sierra::extensions::sint::{SintConcrete, SintOperationConcreteLibfunc, SintTraits};
 use cairo_lang_sierra::extensions::strct::StructConcreteLibfunc;
+use cairo_lang_sierra::extensions::uint::{
+    UintConcrete, UintOperationConcreteLibfunc, UintOperator, UintTraits,
+};
 use cairo_lang_sierra::extensions::uint128::{
-    IntOperator, Uint128Concrete, Uint128OperationConcreteLibfunc,
+    IntOperator, ShiftOperator, Uint128Concrete, Uint128OperationConcreteLibfunc,
+    Uint128ShiftConcreteLibfunc,
 };
+use cairo_lang_sierra::extensions::uint256::Uint256Concrete;
 use cairo_lang_sierra::program::Function;
 
 use crate::starknet_libfunc_cost_base::starknet_libfunc_cost_base;
@@ -83,7 +89,23 @@ pub fn core_libfunc_cost_base<Ops: CostOperations>(
         Array(ArrayConcreteLibfunc::PopFront(_)) => vec![ops.const_cost(2), ops.const_cost(3)],
         Array(ArrayConcreteLibfunc::At(_)) => vec![ops.const_cost(4), ops.const_cost(3)],
         Array(ArrayConcreteLibfunc::Len(_)) => vec![ops.const_cost(0)],
+        Array(ArrayConcreteLibfunc::Slice(_)) => vec![ops.const_cost(7), ops.const_cost(9)],
+        Array(ArrayConcreteLibfunc::SlicePopFront(_)) => {
+            vec![ops.const_cost(2), ops.const_cost(3)]
+        }
+        Array(ArrayConcreteLibfunc::SliceLen(_)) => vec![ops.const_cost(0)],
+        Uint8(libfunc) => uint_libfunc_cost(ops, libfunc),
+        Uint16(libfunc) => uint_libfunc_cost(ops, libfunc),
+        Uint32(libfunc) => uint_libfunc_cost(ops, libfunc),
+        Uint64(libfunc) => uint_libfunc_cost(ops, libfunc),
         Uint128(libfunc) => integer_libfunc_cost(ops, libfunc),
+        Uint256(Uint256Concrete::JumpNotZero(_)) => vec![ops.const_cost(2), ops.const_cost(2)],
+        Uint256(Uint256Concrete::DivMod(_)) => vec![ops.const_cost(44)],
+        Sint8(libfunc) => sint_libfunc_cost(ops, libfunc),
+        Sint16(libfunc) => sint_libfunc_cost(ops, libfunc),
+        Sint32(libfunc) => sint_libfunc_cost(ops, libfunc),
+        Sint64(libfunc) => sint_libfunc_cost(ops, libfunc),
+        Sint128(libfunc) => sint_libfunc_cost(ops, libfunc),
         Felt(libfunc) => felt_libfunc_cost(ops, libfunc),
         Drop(_) | Dup(_) | ApTracking(_) | UnwrapNonZero(_) | Mem(Rename(_)) => {
             vec![ops.const_cost(0)]
@@ -181,6 +203,51 @@ fn integer_libfunc_cost<Ops: CostOperations>(
         Uint128Concrete::LessThanOrEqual(_) => {
             vec![ops.const_cost(3), ops.const_cost(4)]
         }
+        Uint128Concrete::Shift(Uint128ShiftConcreteLibfunc { operator, .. }) => match operator {
+            ShiftOperator::Shl => vec![ops.const_cost(54), ops.const_cost(4)],
+            ShiftOperator::Shr => vec![ops.const_cost(51), ops.const_cost(4)],
+        },
+    }
+}
+
+/// Returns costs for the libfuncs of an unsigned integer smaller than u128.
+fn uint_libfunc_cost<Ops: CostOperations, TUintTraits: UintTraits>(
+    ops: &Ops,
+    libfunc: &UintConcrete<TUintTraits>,
+) -> Vec<Ops::CostType> {
+    match libfunc {
+        UintConcrete::Operation(UintOperationConcreteLibfunc { operator, .. }) => match operator {
+            UintOperator::DivMod => vec![ops.const_cost(7)],
+            UintOperator::WideMul => vec![ops.const_cost(0)],
+            UintOperator::OverflowingAdd => vec![ops.const_cost(4), ops.const_cost(5)],
+            UintOperator::OverflowingSub => vec![ops.const_cost(3), ops.const_cost(6)],
+        },
+        UintConcrete::Const(_) | UintConcrete::ToFelt(_) => vec![ops.const_cost(0)],
+        UintConcrete::FromFelt(_) => vec![ops.const_cost(4), ops.const_cost(12)],
+        UintConcrete::JumpNotZero(_) => vec![ops.const_cost(1), ops.const_cost(1)],
+        UintConcrete::LessThan(_) => vec![ops.const_cost(4), ops.const_cost(3)],
+        UintConcrete::Equal(_) => vec![ops.const_cost(2), ops.const_cost(2)],
+        UintConcrete::LessThanOrEqual(_) => vec![ops.const_cost(3), ops.const_cost(4)],
+    }
+}
+
+/// Returns costs for the libfuncs of a signed integer.
+fn sint_libfunc_cost<Ops: CostOperations, TSintTraits: SintTraits>(
+    ops: &Ops,
+    libfunc: &SintConcrete<TSintTraits>,
+) -> Vec<Ops::CostType> {
+    match libfunc {
+        SintConcrete::Operation(SintOperationConcreteLibfunc { operator, .. }) => match operator {
+            IntOperator::OverflowingAdd | IntOperator::OverflowingSub => {
+                vec![ops.const_cost(6), ops.const_cost(8), ops.const_cost(8)]
+            }
+            IntOperator::WideMul => vec![ops.const_cost(0)],
+            IntOperator::OverflowingMul | IntOperator::DivMod => unreachable!(),
+        },
+        SintConcrete::Diff(_) => vec![ops.const_cost(3), ops.const_cost(6)],
+        SintConcrete::Equal(_) => vec![ops.const_cost(2), ops.const_cost(2)],
+        SintConcrete::FromFelt(_) => vec![ops.const_cost(5), ops.const_cost(13)],
+        SintConcrete::Const(_) | SintConcrete::ToFelt(_) => vec![ops.const_cost(0)],
     }
 }
 
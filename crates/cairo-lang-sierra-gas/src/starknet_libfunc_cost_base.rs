@@ -12,10 +12,26 @@ pub fn starknet_libfunc_cost_base<Ops: CostOperations>(
         // TODO(Ilya): Revisit the real cost.
         StarkNetConcreteLibfunc::CallContract(_) => vec![ops.const_cost(50), ops.const_cost(50)],
         StarkNetConcreteLibfunc::ContractAddressConst(_) => vec![ops.const_cost(0)],
+        StarkNetConcreteLibfunc::ContractAddressTryFromFelt(_) => {
+            vec![ops.const_cost(7), ops.const_cost(12)]
+        }
+        StarkNetConcreteLibfunc::ContractAddressToFelt(_) => vec![ops.const_cost(0)],
         // TODO(Ilya): Consider adding a `CostTokenType::StorageRead` or make storage read a branch.
         StarkNetConcreteLibfunc::StorageRead(_) => vec![ops.const_cost(50), ops.const_cost(50)],
         // TODO(yuval): Revisit the real cost.
         StarkNetConcreteLibfunc::StorageWrite(_) => vec![ops.const_cost(50), ops.const_cost(50)],
         StarkNetConcreteLibfunc::StorageAddressConst(_) => vec![ops.const_cost(0)],
+        StarkNetConcreteLibfunc::StorageBaseAddressConst(_) => vec![ops.const_cost(0)],
+        StarkNetConcreteLibfunc::StorageBaseAddressFromFelt(_) => vec![ops.const_cost(15)],
+        StarkNetConcreteLibfunc::StorageAddressFromBase(_) => vec![ops.const_cost(0)],
+        StarkNetConcreteLibfunc::StorageAddressFromBaseAndOffset(_) => vec![ops.const_cost(0)],
+        // TODO: Revisit the real cost.
+        StarkNetConcreteLibfunc::EmitEvent(_) => vec![ops.const_cost(50), ops.const_cost(50)],
+        // TODO: Revisit the real cost of the execution info system calls.
+        StarkNetConcreteLibfunc::GetCallerAddress(_)
+        | StarkNetConcreteLibfunc::GetContractAddress(_)
+        | StarkNetConcreteLibfunc::GetBlockNumber(_)
+        | StarkNetConcreteLibfunc::GetBlockTimestamp(_)
+        | StarkNetConcreteLibfunc::GetTxInfo(_) => vec![ops.const_cost(50), ops.const_cost(50)],
     }
 }
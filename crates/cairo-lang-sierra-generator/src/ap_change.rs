@@ -2,8 +2,8 @@
 #[path = "ap_change_test.rs"]
 mod test;
 
-use cairo_lang_defs::ids::FreeFunctionId;
 use cairo_lang_diagnostics::Maybe;
+use cairo_lang_lowering::ids::FunctionWithBodyId;
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::program::GenStatement;
 
@@ -12,23 +12,17 @@ use crate::pre_sierra;
 use crate::utils::get_libfunc_signature;
 
 /// Query implementation of [SierraGenGroup::contains_cycle].
-pub fn contains_cycle(db: &dyn SierraGenGroup, function_id: FreeFunctionId) -> Maybe<bool> {
-    let lowered_function = &*db.free_function_lowered(function_id)?;
+pub fn contains_cycle(db: &dyn SierraGenGroup, function_id: FunctionWithBodyId) -> Maybe<bool> {
+    let lowered_function = &*db.function_with_body_lowered(function_id)?;
     for (_, block) in &lowered_function.blocks {
         for statement in &block.statements {
             if let cairo_lang_lowering::Statement::Call(statement_call) = statement {
-                let concrete = db.lookup_intern_function(statement_call.function).function;
-                match concrete.generic_function {
-                    cairo_lang_defs::ids::GenericFunctionId::Free(free_function_id) => {
-                        if db.contains_cycle(free_function_id)? {
-                            return Ok(true);
-                        }
+                if let Some(function_with_body_id) =
+                    statement_call.function.try_get_function_with_body_id(db.upcast())
+                {
+                    if db.contains_cycle(function_with_body_id)? {
+                        return Ok(true);
                     }
-                    cairo_lang_defs::ids::GenericFunctionId::Extern(_) => {}
-                    cairo_lang_defs::ids::GenericFunctionId::TraitFunction(_) => {
-                        panic!("Trait function should be replaced with concrete functions.")
-                    }
-                    cairo_lang_defs::ids::GenericFunctionId::ImplFunction(_) => todo!(),
                 }
             }
         }
@@ -41,7 +35,7 @@ pub fn contains_cycle(db: &dyn SierraGenGroup, function_id: FreeFunctionId) -> M
 pub fn contains_cycle_handle_cycle(
     _db: &dyn SierraGenGroup,
     _cycle: &[String],
-    _function_id: &FreeFunctionId,
+    _function_id: &FunctionWithBodyId,
 ) -> Maybe<bool> {
     Ok(true)
 }
@@ -49,7 +43,7 @@ pub fn contains_cycle_handle_cycle(
 /// Query implementation of [SierraGenGroup::get_ap_change].
 pub fn get_ap_change(
     db: &dyn SierraGenGroup,
-    function_id: FreeFunctionId,
+    function_id: FunctionWithBodyId,
 ) -> Maybe<SierraApChange> {
     // The implementation of get_ap_change() may call this function recursively. To guarantee no
     // salsa query cycles are created, we first verify that there are no cycles.
@@ -57,7 +51,7 @@ pub fn get_ap_change(
         return Ok(SierraApChange::Unknown);
     }
 
-    let function = &*db.free_function_sierra(function_id)?;
+    let function = &*db.function_with_body_sierra(function_id)?;
     for statement in &function.body {
         if let pre_sierra::Statement::Sierra(GenStatement::Invocation(invocation)) = statement {
             let signature = get_libfunc_signature(db, invocation.libfunc_id.clone());
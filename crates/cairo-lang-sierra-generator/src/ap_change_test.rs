@@ -1,5 +1,7 @@
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::ids::FunctionWithBodyId;
+use cairo_lang_semantic::items::function_with_body::function_with_body_function_id;
 use cairo_lang_semantic::test_utils::setup_test_module;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::Itertools;
@@ -29,11 +31,18 @@ fn contains_cycles_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
         .iter()
         .map(|(function_id, _)| {
             let name = db.lookup_intern_free_function(*function_id).name(db);
+            let function_id = FunctionWithBodyId::Semantic(
+                function_with_body_function_id(
+                    db,
+                    cairo_lang_defs::ids::FunctionWithBodyId::Free(*function_id),
+                )
+                .unwrap(),
+            );
             format!(
                 "{}: ap_change={:?}, has_cycles={:?}",
                 name,
-                db.get_ap_change(*function_id),
-                db.contains_cycle(*function_id),
+                db.get_ap_change(function_id),
+                db.contains_cycle(function_id),
             )
         })
         .join("\n");
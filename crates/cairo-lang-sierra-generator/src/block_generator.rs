@@ -2,9 +2,9 @@
 #[path = "block_generator_test.rs"]
 mod test;
 
-use cairo_lang_defs::ids::GenericFunctionId;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_lowering as lowering;
+use cairo_lang_lowering::ids::FunctionId;
 use cairo_lang_sierra::program;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{chain, enumerate, zip_eq};
@@ -202,39 +202,33 @@ fn generate_statement_call_code(
     let outputs = context.get_sierra_variables(&statement.outputs);
 
     // Check if this is a user defined function or a libfunc.
-    let (function_long_id, libfunc_id) =
-        get_concrete_libfunc_id(context.get_db(), statement.function);
-
-    match function_long_id.generic_function {
-        GenericFunctionId::Free(_) => {
-            // Create [pre_sierra::PushValue] instances for the arguments.
-            let mut args_on_stack: Vec<cairo_lang_sierra::ids::VarId> = vec![];
-            let mut push_values_vec: Vec<pre_sierra::PushValue> = vec![];
-
-            for (var_id, var) in zip_eq(&statement.inputs, inputs) {
-                // Allocate a temporary Sierra variable that represents the argument placed on the
-                // stack.
-                let arg_on_stack = context.allocate_sierra_variable();
-                push_values_vec.push(pre_sierra::PushValue {
-                    var,
-                    var_on_stack: arg_on_stack.clone(),
-                    ty: context.get_variable_sierra_type(*var_id)?,
-                });
-                args_on_stack.push(arg_on_stack);
-            }
-
-            Ok(vec![
-                // Push the arguments.
-                pre_sierra::Statement::PushValues(push_values_vec),
-                // Call the function.
-                simple_statement(libfunc_id, &args_on_stack, &outputs),
-            ])
-        }
-        GenericFunctionId::Extern(_) => Ok(vec![simple_statement(libfunc_id, &inputs, &outputs)]),
-        GenericFunctionId::TraitFunction(_) => {
-            panic!("Trait function should be replaced with concrete functions.")
+    let libfunc_id = get_concrete_libfunc_id(context.get_db(), statement.function);
+
+    if statement.function.try_get_extern_function_id(context.get_db().upcast()).is_some() {
+        Ok(vec![simple_statement(libfunc_id, &inputs, &outputs)])
+    } else {
+        // Create [pre_sierra::PushValue] instances for the arguments.
+        let mut args_on_stack: Vec<cairo_lang_sierra::ids::VarId> = vec![];
+        let mut push_values_vec: Vec<pre_sierra::PushValue> = vec![];
+
+        for (var_id, var) in zip_eq(&statement.inputs, inputs) {
+            // Allocate a temporary Sierra variable that represents the argument placed on the
+            // stack.
+            let arg_on_stack = context.allocate_sierra_variable();
+            push_values_vec.push(pre_sierra::PushValue {
+                var,
+                var_on_stack: arg_on_stack.clone(),
+                ty: context.get_variable_sierra_type(*var_id)?,
+            });
+            args_on_stack.push(arg_on_stack);
         }
-        GenericFunctionId::ImplFunction(_) => todo!(),
+
+        Ok(vec![
+            // Push the arguments.
+            pre_sierra::Statement::PushValues(push_values_vec),
+            // Call the function.
+            simple_statement(libfunc_id, &args_on_stack, &outputs),
+        ])
     }
 }
 
@@ -253,8 +247,10 @@ fn generate_statement_match_extern_code(
     let (end_label, end_label_id) = context.new_label();
 
     // Get the [ConcreteLibfuncId].
-    let (_function_long_id, libfunc_id) =
-        get_concrete_libfunc_id(context.get_db(), statement.function);
+    let libfunc_id = get_concrete_libfunc_id(
+        context.get_db(),
+        FunctionId::from_semantic(context.get_db().upcast(), statement.function),
+    );
 
     let mut statements: Vec<pre_sierra::Statement> = vec![];
 
@@ -280,6 +276,7 @@ fn generate_statement_match_extern_code(
     )));
 
     // Generate the blocks.
+    let mut is_end_reachable = false;
     for (i, (_, block_id)) in enumerate(&statement.arms) {
         // Add a label for each of the arm blocks, except for the first.
         if i > 0 {
@@ -293,6 +290,7 @@ fn generate_statement_match_extern_code(
         statements.extend(code);
 
         if is_reachable {
+            is_end_reachable = true;
             // Add jump statement to the end of the match. The last block does not require a jump.
             if i < statement.arms.len() - 1 {
                 statements.push(jump_statement(jump_libfunc_id(context.get_db()), end_label_id));
@@ -300,8 +298,10 @@ fn generate_statement_match_extern_code(
         }
     }
 
-    // Post match.
-    statements.push(end_label);
+    // Post match. If no arm reaches the end of the match, nothing follows it.
+    if is_end_reachable {
+        statements.push(end_label);
+    }
 
     Ok(statements)
 }
@@ -394,6 +394,7 @@ fn generate_statement_match_enum(
 
     // Generate the blocks.
     // TODO(Gil): Consider unifying with the similar logic in generate_statement_match_extern_code.
+    let mut is_end_reachable = false;
     for (i, (label_statement, (_variant, arm))) in
         enumerate(zip_eq(arm_label_statements, &statement.arms))
     {
@@ -406,14 +407,17 @@ fn generate_statement_match_enum(
         statements.extend(code);
 
         if is_reachable {
+            is_end_reachable = true;
             // Add jump statement to the end of the match. The last block does not require a jump.
             if i < statement.arms.len() - 1 {
                 statements.push(jump_statement(jump_libfunc_id(context.get_db()), end_label_id));
             }
         }
     }
-    // Post match.
-    statements.push(end_label);
+    // Post match. If no arm reaches the end of the match, nothing follows it.
+    if is_end_reachable {
+        statements.push(end_label);
+    }
 
     Ok(statements)
 }
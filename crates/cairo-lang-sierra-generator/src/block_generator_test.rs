@@ -1,4 +1,6 @@
+use cairo_lang_lowering::ids::FunctionWithBodyId;
 use cairo_lang_lowering::lower::lower;
+use cairo_lang_semantic::items::function_with_body::function_with_body_function_id;
 use cairo_lang_semantic::test_utils::setup_test_function;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
@@ -33,7 +35,12 @@ fn block_generator_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
     .split();
 
     // Lower code.
-    let lowered = lower(db, test_function.function_id).unwrap();
+    let function_id = function_with_body_function_id(
+        db,
+        cairo_lang_defs::ids::FunctionWithBodyId::Free(test_function.function_id),
+    )
+    .unwrap();
+    let lowered = lower(db, function_id).unwrap().main_lowering;
 
     if lowered.root.is_err() {
         return OrderedHashMap::from([
@@ -50,8 +57,12 @@ fn block_generator_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
     // Generate (pre-)Sierra statements.
     let lifetime = find_variable_lifetime(&lowered, &OrderedHashSet::default())
         .expect("Failed to retrieve lifetime information.");
-    let mut expr_generator_context =
-        ExprGeneratorContext::new(db, &lowered, test_function.function_id, &lifetime);
+    let mut expr_generator_context = ExprGeneratorContext::new(
+        db,
+        &lowered,
+        FunctionWithBodyId::Semantic(function_id),
+        &lifetime,
+    );
     let statements_opt = generate_block_code(&mut expr_generator_context, block_id, block);
     let expected_sierra_code = statements_opt.map_or("None".into(), |statements| {
         statements
@@ -4,6 +4,7 @@ use cairo_lang_defs::ids::FreeFunctionId;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::ids::{FunctionLongId, FunctionWithBodyId};
 use cairo_lang_semantic::corelib::get_core_ty_by_name;
 use cairo_lang_semantic::{GenericArgumentId, Mutability};
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
@@ -32,13 +33,13 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         id: cairo_lang_sierra::program::ConcreteTypeLongId,
     ) -> cairo_lang_sierra::ids::ConcreteTypeId;
 
-    /// Creates a Sierra function id for a function id of the semantic model.
+    /// Creates a Sierra function id for a function id of the lowering phase.
     // TODO(lior): Can we have the short and long ids in the same place? Currently, the short
-    //   id is defined in sierra and the long id is defined in semantic.
+    //   id is defined in sierra and the long id is defined in lowering.
     #[salsa::interned]
     fn intern_sierra_function(
         &self,
-        id: cairo_lang_semantic::FunctionId,
+        id: cairo_lang_lowering::ids::FunctionId,
     ) -> cairo_lang_sierra::ids::FunctionId;
 
     /// Returns the matching sierra concrete type id for a given semantic type id.
@@ -61,12 +62,18 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         concrete_type_id: cairo_lang_sierra::ids::ConcreteTypeId,
     ) -> Maybe<Arc<cairo_lang_sierra::extensions::types::TypeInfo>>;
 
-    /// Private query to compute Sierra data about a free function.
-    #[salsa::invoke(function_generator::priv_free_function_sierra_data)]
-    fn priv_free_function_sierra_data(
+    /// Private query to compute Sierra data about a function with a body.
+    #[salsa::invoke(function_generator::priv_function_with_body_sierra_data)]
+    fn priv_function_with_body_sierra_data(
         &self,
-        function_id: FreeFunctionId,
-    ) -> function_generator::SierraFreeFunctionData;
+        function_id: FunctionWithBodyId,
+    ) -> function_generator::SierraFunctionWithBodyData;
+    /// Returns the Sierra code (as [pre_sierra::Function]) for a given function with a body.
+    #[salsa::invoke(function_generator::function_with_body_sierra)]
+    fn function_with_body_sierra(
+        &self,
+        function_id: FunctionWithBodyId,
+    ) -> Maybe<Arc<pre_sierra::Function>>;
     /// Returns the Sierra code (as [pre_sierra::Function]) for a given free function.
     #[salsa::invoke(function_generator::free_function_sierra)]
     fn free_function_sierra(&self, function_id: FreeFunctionId)
@@ -77,12 +84,12 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
     /// calls f2, then [Self::contains_cycle] will return `true` for all of these functions.
     #[salsa::invoke(ap_change::contains_cycle)]
     #[salsa::cycle(ap_change::contains_cycle_handle_cycle)]
-    fn contains_cycle(&self, function_id: FreeFunctionId) -> Maybe<bool>;
+    fn contains_cycle(&self, function_id: FunctionWithBodyId) -> Maybe<bool>;
 
     /// Returns the ap change of a given function if it is known at compile time or
     /// [SierraApChange::Unknown] otherwise.
     #[salsa::invoke(ap_change::get_ap_change)]
-    fn get_ap_change(&self, function_id: FreeFunctionId) -> Maybe<SierraApChange>;
+    fn get_ap_change(&self, function_id: FunctionWithBodyId) -> Maybe<SierraApChange>;
 
     /// Returns the [cairo_lang_sierra::program::Program] object of the requested functions.
     #[salsa::invoke(program_generator::get_sierra_program_for_functions)]
@@ -108,12 +115,11 @@ fn get_function_signature(
     // of only the explicit ret_type. Also use it for params instead of the current logic. Then use
     // it in the end of program_generator::get_sierra_program instead of calling this function from
     // there.
-    let semantic_function_id = db.lookup_intern_sierra_function(function_id);
-    let signature = db.concrete_function_signature(semantic_function_id)?;
-    let may_panic = db.function_may_panic(semantic_function_id)?;
+    let lowering_function_id = db.lookup_intern_sierra_function(function_id);
+    let may_panic = lowering_function_id.may_panic(db.upcast())?;
 
-    let implicits = db
-        .function_all_implicits(semantic_function_id)?
+    let implicits = lowering_function_id
+        .all_implicits(db.upcast())?
         .iter()
         .map(|ty| db.get_concrete_type_id(*ty))
         .collect::<Maybe<Vec<ConcreteTypeId>>>()?;
@@ -122,16 +128,32 @@ fn get_function_signature(
     let mut ret_types = implicits.clone();
     let mut all_params = implicits;
 
-    for param in signature.params {
-        let concrete_type_id = db.get_concrete_type_id(param.ty)?;
-        all_params.push(concrete_type_id.clone());
-        if param.mutability == Mutability::Reference {
-            ret_types.push(concrete_type_id);
+    let mut return_type = match db.lookup_intern_lowering_function(lowering_function_id) {
+        FunctionLongId::Semantic(semantic_function_id) => {
+            let signature = db.concrete_function_signature(semantic_function_id)?;
+            for param in signature.params {
+                let concrete_type_id = db.get_concrete_type_id(param.ty)?;
+                all_params.push(concrete_type_id.clone());
+                if param.mutability == Mutability::Reference {
+                    ret_types.push(concrete_type_id);
+                }
+            }
+            signature.return_type
         }
-    }
+        FunctionLongId::Generated(generated) => {
+            let multi_lowering = db.function_with_body_multi_lowering(generated.parent)?;
+            let generated_lowering = &multi_lowering.generated_lowerings[generated.element];
+            for ty in &generated_lowering.param_tys {
+                all_params.push(db.get_concrete_type_id(*ty)?);
+            }
+            for ty in &generated_lowering.ref_tys {
+                ret_types.push(db.get_concrete_type_id(*ty)?);
+            }
+            generated_lowering.return_type
+        }
+    };
 
     // TODO(ilya): Handle tuple and struct types.
-    let mut return_type = signature.return_type;
     if may_panic {
         return_type = get_core_ty_by_name(
             db.upcast(),
@@ -1,5 +1,5 @@
-use cairo_lang_defs::ids::FreeFunctionId;
 use cairo_lang_diagnostics::Maybe;
+use cairo_lang_lowering::ids::FunctionWithBodyId;
 use cairo_lang_sierra::extensions::uninitialized::UninitializedType;
 use cairo_lang_sierra::extensions::NamedType;
 use cairo_lang_sierra::program::{ConcreteTypeLongId, GenericArg};
@@ -15,7 +15,7 @@ use crate::pre_sierra;
 pub struct ExprGeneratorContext<'a> {
     db: &'a dyn SierraGenGroup,
     lowered: &'a cairo_lang_lowering::lower::Lowered,
-    function_id: FreeFunctionId,
+    function_id: FunctionWithBodyId,
     // TODO(lior): Remove `allow(dead_code)` once this field is used.
     #[allow(dead_code)]
     lifetime: &'a VariableLifetimeResult,
@@ -29,7 +29,7 @@ impl<'a> ExprGeneratorContext<'a> {
     pub fn new(
         db: &'a dyn SierraGenGroup,
         lowered: &'a cairo_lang_lowering::lower::Lowered,
-        function_id: FreeFunctionId,
+        function_id: FunctionWithBodyId,
         lifetime: &'a VariableLifetimeResult,
     ) -> Self {
         ExprGeneratorContext {
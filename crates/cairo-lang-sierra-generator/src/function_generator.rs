@@ -5,8 +5,10 @@ mod test;
 use std::collections::hash_map::Entry;
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::{FreeFunctionId, GenericFunctionId};
+use cairo_lang_defs::ids::FreeFunctionId;
 use cairo_lang_diagnostics::Maybe;
+use cairo_lang_lowering::ids::{FunctionId, FunctionLongId, FunctionWithBodyId};
+use cairo_lang_semantic::items::function_with_body::function_with_body_function_id;
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
 use cairo_lang_sierra::extensions::GenericLibfuncEx;
 use cairo_lang_sierra::ids::{ConcreteLibfuncId, GenericLibfuncId};
@@ -31,17 +33,25 @@ use crate::utils::{
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct SierraFreeFunctionData {
+pub struct SierraFunctionWithBodyData {
     pub function: Maybe<Arc<pre_sierra::Function>>,
 }
 
-/// Query implementation of [SierraGenGroup::priv_free_function_sierra_data].
-pub fn priv_free_function_sierra_data(
+/// Query implementation of [SierraGenGroup::priv_function_with_body_sierra_data].
+pub fn priv_function_with_body_sierra_data(
     db: &dyn SierraGenGroup,
-    function_id: FreeFunctionId,
-) -> SierraFreeFunctionData {
+    function_id: FunctionWithBodyId,
+) -> SierraFunctionWithBodyData {
     let function = get_function_code(db, function_id);
-    SierraFreeFunctionData { function }
+    SierraFunctionWithBodyData { function }
+}
+
+/// Query implementation of [SierraGenGroup::function_with_body_sierra].
+pub fn function_with_body_sierra(
+    db: &dyn SierraGenGroup,
+    function_id: FunctionWithBodyId,
+) -> Maybe<Arc<pre_sierra::Function>> {
+    db.priv_function_with_body_sierra_data(function_id).function
 }
 
 /// Query implementation of [SierraGenGroup::free_function_sierra].
@@ -49,15 +59,17 @@ pub fn free_function_sierra(
     db: &dyn SierraGenGroup,
     function_id: FreeFunctionId,
 ) -> Maybe<Arc<pre_sierra::Function>> {
-    db.priv_free_function_sierra_data(function_id).function
+    db.function_with_body_sierra(FunctionWithBodyId::Semantic(function_with_body_function_id(
+        db.upcast(),
+        cairo_lang_defs::ids::FunctionWithBodyId::Free(function_id),
+    )?))
 }
 
 fn get_function_code(
     db: &dyn SierraGenGroup,
-    function_id: FreeFunctionId,
+    function_id: FunctionWithBodyId,
 ) -> Maybe<Arc<pre_sierra::Function>> {
-    let signature = db.free_function_declaration_signature(function_id)?;
-    let lowered_function = &*db.free_function_lowered(function_id)?;
+    let lowered_function = &*db.function_with_body_lowered(function_id)?;
     let block_id = lowered_function.root?;
     let block = &lowered_function.blocks[block_id];
 
@@ -83,7 +95,20 @@ fn get_function_code(
         })
     }
 
-    let ret_types = vec![db.get_concrete_type_id(signature.return_type)?];
+    let (lowering_function_id, return_type) = match function_id {
+        FunctionWithBodyId::Semantic(semantic_function_id) => {
+            let signature = db.concrete_function_signature(semantic_function_id)?;
+            (FunctionId::from_semantic(db.upcast(), semantic_function_id), signature.return_type)
+        }
+        FunctionWithBodyId::Generated(generated) => {
+            let multi_lowering = db.function_with_body_multi_lowering(generated.parent)?;
+            (
+                db.intern_lowering_function(FunctionLongId::Generated(generated)),
+                multi_lowering.generated_lowerings[generated.element].return_type,
+            )
+        }
+    };
+    let ret_types = vec![db.get_concrete_type_id(return_type)?];
 
     let mut statements: Vec<pre_sierra::Statement> = vec![label];
 
@@ -122,16 +147,8 @@ fn get_function_code(
 
     let statements = add_dups_and_drops(&mut context, &parameters, statements);
 
-    // TODO(spapini): Don't intern objects for the semantic model outside the crate. These should
-    // be regarded as private.
     Ok(pre_sierra::Function {
-        id: db.intern_sierra_function(db.intern_function(cairo_lang_semantic::FunctionLongId {
-            function: cairo_lang_semantic::ConcreteFunction {
-                generic_function: GenericFunctionId::Free(function_id),
-                // TODO(lior): Add generic arguments.
-                generic_args: vec![],
-            },
-        })),
+        id: db.intern_sierra_function(lowering_function_id),
         prolog_size,
         body: statements,
         entry_point: label_id,
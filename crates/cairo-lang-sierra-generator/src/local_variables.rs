@@ -3,6 +3,7 @@
 mod test;
 
 use cairo_lang_diagnostics::Maybe;
+use cairo_lang_lowering::ids::FunctionId;
 use cairo_lang_lowering::lower::Lowered;
 use cairo_lang_lowering::{BlockId, VariableId};
 use cairo_lang_sierra::extensions::lib_func::OutputVarInfo;
@@ -60,8 +61,7 @@ fn inner_find_local_variables(
                 );
             }
             cairo_lang_lowering::Statement::Call(statement_call) => {
-                let (_, concrete_function_id) =
-                    get_concrete_libfunc_id(db, statement_call.function);
+                let concrete_function_id = get_concrete_libfunc_id(db, statement_call.function);
 
                 handle_function_call(
                     db,
@@ -87,8 +87,10 @@ fn inner_find_local_variables(
                 state.mark_outputs_as_temporary(statement);
             }
             cairo_lang_lowering::Statement::MatchExtern(statement_match_extern) => {
-                let (_, concrete_function_id) =
-                    get_concrete_libfunc_id(db, statement_match_extern.function);
+                let concrete_function_id = get_concrete_libfunc_id(
+                    db,
+                    FunctionId::from_semantic(db.upcast(), statement_match_extern.function),
+                );
                 let arm_blocks: Vec<_> =
                     statement_match_extern.arms.iter().map(|(_, block_id)| *block_id).collect();
                 known_ap_change &= handle_match(
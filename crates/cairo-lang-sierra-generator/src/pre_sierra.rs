@@ -1,4 +1,4 @@
-use cairo_lang_defs::ids::FreeFunctionId;
+use cairo_lang_lowering::ids::FunctionWithBodyId;
 use cairo_lang_sierra::ids::ConcreteTypeId;
 use cairo_lang_sierra::program;
 use cairo_lang_utils::{define_short_id, write_comma_separated};
@@ -10,7 +10,7 @@ use crate::db::SierraGenGroup;
 // TODO(lior): Make sure this struct can only be constructed by expr_generator_context.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct LabelLongId {
-    pub parent: FreeFunctionId,
+    pub parent: FunctionWithBodyId,
     // A unique identifier inside the function
     pub id: usize,
 }
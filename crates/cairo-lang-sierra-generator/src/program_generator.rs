@@ -4,6 +4,8 @@ use std::sync::Arc;
 use cairo_lang_defs::ids::FreeFunctionId;
 use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToMaybe};
 use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_lowering::ids::FunctionWithBodyId;
+use cairo_lang_semantic::items::function_with_body::function_with_body_function_id;
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::GenericLibfuncEx;
@@ -127,14 +129,21 @@ pub fn get_sierra_program_for_functions(
 ) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
     let mut functions: Vec<Arc<pre_sierra::Function>> = vec![];
     let mut statements: Vec<pre_sierra::Statement> = vec![];
-    let mut processed_function_ids = UnorderedHashSet::<FreeFunctionId>::default();
-    let mut function_id_queue: VecDeque<FreeFunctionId> =
-        requested_function_ids.into_iter().collect();
+    let mut processed_function_ids = UnorderedHashSet::<FunctionWithBodyId>::default();
+    let mut function_id_queue: VecDeque<FunctionWithBodyId> = requested_function_ids
+        .into_iter()
+        .map(|function_id| {
+            Ok(FunctionWithBodyId::Semantic(function_with_body_function_id(
+                db.upcast(),
+                cairo_lang_defs::ids::FunctionWithBodyId::Free(function_id),
+            )?))
+        })
+        .collect::<Maybe<_>>()?;
     while let Some(function_id) = function_id_queue.pop_front() {
         if !processed_function_ids.insert(function_id) {
             continue;
         }
-        let function: Arc<pre_sierra::Function> = db.free_function_sierra(function_id)?;
+        let function: Arc<pre_sierra::Function> = db.function_with_body_sierra(function_id)?;
         functions.push(function.clone());
         statements.extend_from_slice(&function.body[0..function.prolog_size]);
         if !matches!(db.get_ap_change(function_id), Ok(SierraApChange::Known { .. })) {
@@ -144,7 +153,7 @@ pub fn get_sierra_program_for_functions(
         }
         statements.extend_from_slice(&function.body[function.prolog_size..]);
         for statement in &function.body {
-            if let Ok(related_function_id) = try_get_free_function_id(db, statement) {
+            if let Ok(related_function_id) = try_get_function_with_body_id(db, statement) {
                 function_id_queue.push_back(related_function_id);
             }
         }
@@ -177,11 +186,11 @@ pub fn get_sierra_program_for_functions(
     }))
 }
 
-/// Tries extracting a free function id from a pre-Sierra statement.
-fn try_get_free_function_id(
+/// Tries extracting a function with a body from a pre-Sierra statement.
+fn try_get_function_with_body_id(
     db: &dyn SierraGenGroup,
     statement: &pre_sierra::Statement,
-) -> Maybe<FreeFunctionId> {
+) -> Maybe<FunctionWithBodyId> {
     let invc = try_extract_matches!(
         try_extract_matches!(statement, pre_sierra::Statement::Sierra).to_maybe()?,
         program::GenStatement::Invocation
@@ -191,21 +200,15 @@ fn try_get_free_function_id(
     if libfunc.generic_id != "function_call".into() {
         return Err(skip_diagnostic());
     }
-    let function = db
-        .lookup_intern_function(
-            db.lookup_intern_sierra_function(
-                try_extract_matches!(
-                    libfunc.generic_args.get(0).to_maybe()?,
-                    cairo_lang_sierra::program::GenericArg::UserFunc
-                )
-                .to_maybe()?
-                .clone(),
-            ),
+    let lowering_function_id = db.lookup_intern_sierra_function(
+        try_extract_matches!(
+            libfunc.generic_args.get(0).to_maybe()?,
+            cairo_lang_sierra::program::GenericArg::UserFunc
         )
-        .function;
-    assert!(function.generic_args.is_empty(), "Generic args are not yet supported");
-    try_extract_matches!(function.generic_function, cairo_lang_defs::ids::GenericFunctionId::Free)
-        .to_maybe()
+        .to_maybe()?
+        .clone(),
+    );
+    lowering_function_id.try_get_function_with_body_id(db.upcast()).to_maybe()
 }
 
 pub fn get_sierra_program(
@@ -216,7 +219,11 @@ pub fn get_sierra_program(
     for crate_id in requested_crate_ids {
         for module_id in db.crate_modules(crate_id).iter() {
             for (free_func_id, _) in db.module_free_functions(*module_id)? {
-                requested_function_ids.push(free_func_id)
+                // Generic functions are only compiled for the concrete generic arguments they are
+                // called with.
+                if db.free_function_declaration_generic_params(free_func_id)?.is_empty() {
+                    requested_function_ids.push(free_func_id)
+                }
             }
         }
     }
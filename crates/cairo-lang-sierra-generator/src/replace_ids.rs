@@ -125,8 +125,12 @@ impl SierraIdReplacer for DebugReplacer<'_> {
         &self,
         sierra_id: &cairo_lang_sierra::ids::FunctionId,
     ) -> cairo_lang_sierra::ids::FunctionId {
-        let semantic_id = self.db.lookup_intern_sierra_function(sierra_id.clone());
-        format!("{:?}", self.db.lookup_intern_function(semantic_id).debug(self.db.upcast())).into()
+        let lowering_id = self.db.lookup_intern_sierra_function(sierra_id.clone());
+        format!(
+            "{:?}",
+            self.db.lookup_intern_lowering_function(lowering_id).debug(self.db.upcast())
+        )
+        .into()
     }
 }
 
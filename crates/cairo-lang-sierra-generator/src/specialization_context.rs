@@ -1,4 +1,3 @@
-use cairo_lang_defs::ids::GenericFunctionId;
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_sierra::extensions::lib_func::{SierraApChange, SignatureSpecializationContext};
 use cairo_lang_sierra::extensions::type_specialization_context::TypeSpecializationContext;
@@ -50,19 +49,14 @@ impl SignatureSpecializationContext for SierraSignatureSpecializationContext<'_>
         &self,
         function_id: &cairo_lang_sierra::ids::FunctionId,
     ) -> Option<SierraApChange> {
-        let concrete_function = self
+        let function_with_body_id = self
             .0
-            .lookup_intern_function(self.0.lookup_intern_sierra_function(function_id.clone()))
-            .function;
-        match concrete_function.generic_function {
-            GenericFunctionId::Free(free_function_id) => {
-                self.0.get_ap_change(free_function_id).to_option()
-            }
-            GenericFunctionId::Extern(_) | GenericFunctionId::TraitFunction(_) => panic!(
+            .lookup_intern_sierra_function(function_id.clone())
+            .try_get_function_with_body_id(self.0.upcast())
+            .expect(
                 "Internal compiler error: get_function_ap_change() should only be used for user \
-                 defined functions."
-            ),
-            GenericFunctionId::ImplFunction(_) => todo!(),
-        }
+                 defined functions.",
+            );
+        self.0.get_ap_change(function_with_body_id).to_option()
     }
 }
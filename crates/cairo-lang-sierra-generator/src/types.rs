@@ -89,7 +89,27 @@ pub fn get_concrete_type_id(
                 .collect(),
             }))
         }
-        cairo_lang_semantic::TypeLongId::GenericParameter(_) => Err(skip_diagnostic()),
+        cairo_lang_semantic::TypeLongId::Closure(closure) => {
+            Ok(db.intern_concrete_type(ConcreteTypeLongId {
+                generic_id: "Struct".into(),
+                generic_args: chain!(
+                    [cairo_lang_sierra::program::GenericArg::UserType(
+                        closure.format(db.upcast()).into()
+                    )],
+                    closure
+                        .captured_tys
+                        .into_iter()
+                        .map(|ty| {
+                            db.get_concrete_type_id(ty)
+                                .map(cairo_lang_sierra::program::GenericArg::Type)
+                        })
+                        .collect::<Maybe<Vec<_>>>()?
+                )
+                .collect(),
+            }))
+        }
+        cairo_lang_semantic::TypeLongId::GenericParameter(_)
+        | cairo_lang_semantic::TypeLongId::Var(_) => Err(skip_diagnostic()),
         cairo_lang_semantic::TypeLongId::Missing(diag_added) => Err(diag_added),
     }
 }
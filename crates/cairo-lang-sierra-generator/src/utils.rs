@@ -1,4 +1,5 @@
 use cairo_lang_defs::ids::GenericFunctionId;
+use cairo_lang_lowering::ids::FunctionLongId;
 use cairo_lang_semantic::corelib::get_const_libfunc_name_by_type;
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
 use cairo_lang_sierra::extensions::lib_func::LibfuncSignature;
@@ -202,7 +203,7 @@ pub fn get_libfunc_signature(
 /// Returns the [ConcreteLibfuncId] for calling a user-defined function.
 pub fn function_call_libfunc_id(
     db: &dyn SierraGenGroup,
-    func: cairo_lang_semantic::FunctionId,
+    func: cairo_lang_lowering::ids::FunctionId,
 ) -> ConcreteLibfuncId {
     db.intern_concrete_lib_func(cairo_lang_sierra::program::ConcreteLibfuncLongId {
         generic_id: GenericLibfuncId::from_string("function_call"),
@@ -227,12 +228,19 @@ pub fn generic_libfunc_id(
 /// Returns the [ConcreteLibfuncId] used for calling a function (either user-defined or libfunc).
 pub fn get_concrete_libfunc_id(
     db: &dyn SierraGenGroup,
-    function: cairo_lang_semantic::FunctionId,
-) -> (cairo_lang_semantic::ConcreteFunction, ConcreteLibfuncId) {
+    function: cairo_lang_lowering::ids::FunctionId,
+) -> ConcreteLibfuncId {
+    let semantic_function = match db.lookup_intern_lowering_function(function) {
+        FunctionLongId::Semantic(semantic_function) => semantic_function,
+        // A function generated by the lowering phase is always user-defined.
+        FunctionLongId::Generated(_) => return function_call_libfunc_id(db, function),
+    };
     // Check if this is a user-defined function or a libfunc.
-    let concrete_function = db.lookup_intern_function(function).function;
+    let concrete_function = db.lookup_intern_function(semantic_function).function;
     match concrete_function.generic_function {
-        GenericFunctionId::Free(_) => (concrete_function, function_call_libfunc_id(db, function)),
+        GenericFunctionId::Free(_) | GenericFunctionId::ImplFunction(_) => {
+            function_call_libfunc_id(db, function)
+        }
         GenericFunctionId::Extern(extern_id) => {
             let mut generic_args = vec![];
             for generic_arg in &concrete_function.generic_args {
@@ -251,11 +259,10 @@ pub fn get_concrete_libfunc_id(
                 });
             }
 
-            (concrete_function, generic_libfunc_id(db, extern_id, generic_args))
+            generic_libfunc_id(db, extern_id, generic_args)
         }
         GenericFunctionId::TraitFunction(_) => {
             panic!("Trait function should be replaced with concrete functions.")
         }
-        GenericFunctionId::ImplFunction(_) => todo!(),
     }
 }
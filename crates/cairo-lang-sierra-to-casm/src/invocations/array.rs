@@ -20,6 +20,11 @@ pub fn build(
         ArrayConcreteLibfunc::PopFront(libfunc) => build_pop_front(&libfunc.ty, builder),
         ArrayConcreteLibfunc::At(libfunc) => build_array_at(&libfunc.ty, builder),
         ArrayConcreteLibfunc::Len(libfunc) => build_array_len(&libfunc.ty, builder),
+        ArrayConcreteLibfunc::Slice(libfunc) => build_array_slice(&libfunc.ty, builder),
+        // Slices have the same representation as arrays - the start and end addresses of their
+        // elements.
+        ArrayConcreteLibfunc::SlicePopFront(libfunc) => build_pop_front(&libfunc.ty, builder),
+        ArrayConcreteLibfunc::SliceLen(libfunc) => build_array_len(&libfunc.ty, builder),
     }
 }
 
@@ -176,6 +181,66 @@ fn build_array_at(
     ))
 }
 
+/// Handles a Sierra statement for taking a slice of an array.
+fn build_array_slice(
+    elem_ty: &ConcreteTypeId,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_range_check, expr_arr, expr_start, expr_length] = builder.try_get_refs()?;
+    let range_check = expr_range_check.try_unpack_single()?.to_deref()?;
+    let [arr_start, arr_end] = expr_arr.try_unpack()?;
+    let arr_start = arr_start.to_deref()?;
+    let arr_end = arr_end.to_deref()?;
+    let start = expr_start.try_unpack_single()?.to_deref()?;
+    let length = expr_length.try_unpack_single()?.to_deref()?;
+
+    let element_size = builder.program_info.type_sizes[elem_ty];
+
+    let mut casm_builder = CasmBuilder::default();
+    let start = casm_builder.add_var(ResOperand::Deref(start));
+    let length = casm_builder.add_var(ResOperand::Deref(length));
+    let arr_start = casm_builder.add_var(ResOperand::Deref(arr_start));
+    let arr_end = casm_builder.add_var(ResOperand::Deref(arr_end));
+    let range_check = casm_builder.add_var(ResOperand::Deref(range_check));
+    casm_build_extend! {casm_builder,
+        // Compute the length of the array (in felts).
+        tempvar array_cell_size = arr_end - arr_start;
+        // Compute the offsets of the slice boundaries (in felts). Note that since the start and the
+        // length are smaller than 2^128, `end_offset` is smaller than `2^16 * 2^128`.
+        const element_size = element_size;
+        tempvar start_offset = start * element_size;
+        tempvar length_cell_size = length * element_size;
+        tempvar end_offset = start_offset + length_cell_size;
+        tempvar is_in_range;
+        hint TestLessThanOrEqual {lhs: end_offset, rhs: array_cell_size} into {dst: is_in_range};
+        jump InRange if is_in_range != 0;
+        // Slice out of bounds. Assert `end_offset - array_cell_size - 1` is in [0, 2^128).
+        const one = 1;
+        tempvar end_offset_excess = end_offset - array_cell_size;
+        tempvar end_offset_excess_minus_1 = end_offset_excess - one;
+        assert end_offset_excess_minus_1 = *(range_check++);
+        jump FailureHandle;
+        InRange:
+        // Assert `array_cell_size - end_offset` is in [0, 2^128).
+        tempvar remaining_cell_size = array_cell_size - end_offset;
+        assert remaining_cell_size = *(range_check++);
+        let slice_start = arr_start + start_offset;
+        let slice_end = arr_start + end_offset;
+    };
+    let failure_handle = get_non_fallthrough_statement_id(&builder);
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            (
+                "Fallthrough",
+                &[&[range_check], &[arr_start, arr_end], &[slice_start, slice_end]],
+                None,
+            ),
+            ("FailureHandle", &[&[range_check], &[arr_start, arr_end]], Some(failure_handle)),
+        ],
+    ))
+}
+
 /// Handles a Sierra statement for getting the length of an array.
 fn build_array_len(
     elem_ty: &ConcreteTypeId,
@@ -37,10 +37,13 @@ mod mem;
 mod misc;
 mod nullable;
 mod pedersen;
+mod sint;
 mod starknet;
 
 mod strct;
+mod uint;
 mod uint128;
+mod uint256;
 
 #[cfg(test)]
 mod test_utils;
@@ -355,7 +358,17 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Bitwise(_) => bitwise::build(builder),
         CoreConcreteLibfunc::Bool(libfunc) => boolean::build(libfunc, builder),
         CoreConcreteLibfunc::Ec(libfunc) => ec::build(libfunc, builder),
+        CoreConcreteLibfunc::Uint8(libfunc) => uint::build(libfunc, builder),
+        CoreConcreteLibfunc::Uint16(libfunc) => uint::build(libfunc, builder),
+        CoreConcreteLibfunc::Uint32(libfunc) => uint::build(libfunc, builder),
+        CoreConcreteLibfunc::Uint64(libfunc) => uint::build(libfunc, builder),
+        CoreConcreteLibfunc::Sint8(libfunc) => sint::build(libfunc, builder),
+        CoreConcreteLibfunc::Sint16(libfunc) => sint::build(libfunc, builder),
+        CoreConcreteLibfunc::Sint32(libfunc) => sint::build(libfunc, builder),
+        CoreConcreteLibfunc::Sint64(libfunc) => sint::build(libfunc, builder),
+        CoreConcreteLibfunc::Sint128(libfunc) => sint::build(libfunc, builder),
         CoreConcreteLibfunc::Uint128(libfunc) => uint128::build(libfunc, builder),
+        CoreConcreteLibfunc::Uint256(libfunc) => uint256::build(libfunc, builder),
         CoreConcreteLibfunc::Gas(libfunc) => gas::build(libfunc, builder),
         CoreConcreteLibfunc::BranchAlign(_) => misc::build_branch_align(builder),
         CoreConcreteLibfunc::Array(libfunc) => array::build(libfunc, builder),
@@ -0,0 +1,231 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::operand::ResOperand;
+use cairo_lang_sierra::extensions::felt::FeltBinaryOperator;
+use cairo_lang_sierra::extensions::sint::{SintConcrete, SintOperationConcreteLibfunc, SintTraits};
+use cairo_lang_sierra::extensions::uint128::IntOperator;
+use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
+use num_bigint::BigInt;
+
+use super::uint::add_felt_at_least_bound_validation;
+use super::uint128::unwrap_range_check_based_binary_op_refs;
+use super::{misc, CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::get_non_fallthrough_statement_id;
+use crate::references::{BinOpExpression, CellExpression, ReferenceExpression};
+
+/// Builds instructions for Sierra signed integer operations.
+pub fn build<TSintTraits: SintTraits>(
+    libfunc: &SintConcrete<TSintTraits>,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        SintConcrete::Operation(SintOperationConcreteLibfunc { operator, .. }) => match operator {
+            IntOperator::OverflowingAdd | IntOperator::OverflowingSub => {
+                build_sint_overflowing_op(builder, *operator, TSintTraits::BITS)
+            }
+            IntOperator::WideMul => build_sint_wide_mul(builder),
+            IntOperator::OverflowingMul | IntOperator::DivMod => {
+                Err(InvocationError::NotImplemented(builder.invocation.clone()))
+            }
+        },
+        SintConcrete::Diff(_) => build_sint_diff(builder, TSintTraits::BITS),
+        SintConcrete::Const(libfunc) => Ok(builder.build_only_reference_changes(
+            [ReferenceExpression::from_cell(CellExpression::Immediate(BigInt::from(libfunc.c)))]
+                .into_iter(),
+        )),
+        SintConcrete::FromFelt(_) => build_sint_from_felt(builder, TSintTraits::BITS),
+        SintConcrete::ToFelt(_) => misc::build_identity(builder),
+        SintConcrete::Equal(_) => misc::build_cell_eq(builder),
+    }
+}
+
+/// Handles the multiplication of two signed integers into the wider signed integer type.
+fn build_sint_wide_mul(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    // The product of two values in `[-2**(bits-1), 2**(bits-1))` is in the range of the signed
+    // integer type of `2 * bits` bits, so no validation is required.
+    let [expr_a, expr_b] = builder.try_get_refs()?;
+    let a = expr_a.try_unpack_single()?.to_deref()?;
+    let b = expr_b.try_unpack_single()?.to_deref_or_immediate()?;
+    Ok(builder.build_only_reference_changes(
+        [ReferenceExpression::from_cell(CellExpression::BinOp(BinOpExpression {
+            op: FeltBinaryOperator::Mul,
+            a,
+            b,
+        }))]
+        .into_iter(),
+    ))
+}
+
+/// Handles an overflowing add or sub of signed integers of `bits` bits.
+/// The branches are the in range case, the underflow case and the overflow case.
+fn build_sint_overflowing_op(
+    builder: CompiledInvocationBuilder<'_>,
+    op: IntOperator,
+    bits: u32,
+) -> Result<CompiledInvocation, InvocationError> {
+    let (range_check, a, b) = unwrap_range_check_based_binary_op_refs(&builder)?;
+    let [underflow_statement_id, overflow_statement_id] =
+        match builder.invocation.branches.as_slice() {
+            [
+                BranchInfo { target: BranchTarget::Fallthrough, .. },
+                BranchInfo { target: BranchTarget::Statement(underflow_statement_id), .. },
+                BranchInfo { target: BranchTarget::Statement(overflow_statement_id), .. },
+            ] => [*underflow_statement_id, *overflow_statement_id],
+            _ => panic!("malformed invocation"),
+        };
+    let limit: BigInt = BigInt::from(1) << bits;
+    let half: BigInt = BigInt::from(1) << (bits - 1);
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1;
+    let fixer_value = u128_bound.clone() - limit.clone();
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let a = casm_builder.add_var(ResOperand::Deref(a));
+    let b = casm_builder.add_var(ResOperand::Deref(b));
+    let value = match op {
+        IntOperator::OverflowingAdd => {
+            casm_build_extend! {casm_builder,
+                tempvar value = a + b;
+            };
+            value
+        }
+        IntOperator::OverflowingSub => {
+            casm_build_extend! {casm_builder,
+                tempvar value = a - b;
+            };
+            value
+        }
+        _ => unreachable!("Only supported options in arm."),
+    };
+    casm_build_extend! {casm_builder,
+            // Here we know that `-2**bits <= value < 2**bits`.
+            // The value is in range iff `0 <= value + 2**(bits-1) < 2**bits`.
+            const half = half;
+            tempvar biased_value = value + half;
+            tempvar in_range;
+            const limit = limit.clone();
+            hint TestLessThan {lhs: biased_value, rhs: limit} into {dst: in_range};
+            jump InRange if in_range != 0;
+            tempvar is_overflow;
+            hint TestLessThan {lhs: value, rhs: limit} into {dst: is_overflow};
+            jump IsOverflow if is_overflow != 0;
+            // Underflow:
+            // Here we know that `-2**bits <= value < -2**(bits-1)`.
+            tempvar wrapping_up_value = value + limit;
+            // Verify that `biased_value < 0` by constraining `biased_value + 2**128 < 2**128`.
+            const u128_limit = u128_bound;
+            tempvar rc_underflow_value = biased_value + u128_limit;
+            assert rc_underflow_value = *(range_check++);
+            jump Underflow;
+        IsOverflow:
+            // Here we know that `2**(bits-1) <= value < 2**bits`.
+            tempvar wrapping_down_value = value - limit;
+            // Verify that `biased_value >= 2**bits`.
+            tempvar rc_overflow_value = biased_value - limit;
+            assert rc_overflow_value = *(range_check++);
+            jump Overflow;
+        InRange:
+            // Verify that `0 <= biased_value < 2**bits`.
+            assert biased_value = *(range_check++);
+            const fixer = fixer_value;
+            tempvar fixed_biased_value = biased_value + fixer;
+            assert fixed_biased_value = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("Underflow", &[&[range_check], &[wrapping_up_value]], Some(underflow_statement_id)),
+            ("Overflow", &[&[range_check], &[wrapping_down_value]], Some(overflow_statement_id)),
+        ],
+    ))
+}
+
+/// Handles the diff of two signed integers of `bits` bits, into the unsigned integer of the same
+/// size.
+fn build_sint_diff(
+    builder: CompiledInvocationBuilder<'_>,
+    bits: u32,
+) -> Result<CompiledInvocation, InvocationError> {
+    let (range_check, a, b) = unwrap_range_check_based_binary_op_refs(&builder)?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let limit: BigInt = BigInt::from(1) << bits;
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1;
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let a = casm_builder.add_var(ResOperand::Deref(a));
+    let b = casm_builder.add_var(ResOperand::Deref(b));
+    casm_build_extend! {casm_builder,
+            tempvar a_ge_b;
+            // Here we know that `-2**bits < a - b < 2**bits`.
+            tempvar a_minus_b = a - b;
+            const limit = limit;
+            hint TestLessThan {lhs: a_minus_b, rhs: limit} into {dst: a_ge_b};
+            jump NonNegative if a_ge_b != 0;
+            tempvar wrapping_a_minus_b = a_minus_b + limit;
+            // Verify that `a - b < 0` by constraining `a - b + 2**128 < 2**128`.
+            const u128_limit = u128_bound;
+            tempvar rc_a_minus_b = a_minus_b + u128_limit;
+            assert rc_a_minus_b = *(range_check++);
+            jump Negative;
+        NonNegative:
+            assert a_minus_b = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[a_minus_b]], None),
+            (
+                "Negative",
+                &[&[range_check], &[wrapping_a_minus_b]],
+                Some(failure_handle_statement_id),
+            ),
+        ],
+    ))
+}
+
+/// Handles a casting a felt into a signed integer of `bits` bits.
+fn build_sint_from_felt(
+    builder: CompiledInvocationBuilder<'_>,
+    bits: u32,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check_expression, expr_value] = builder.try_get_refs()?;
+    let range_check = range_check_expression.try_unpack_single()?.to_buffer(3)?;
+    let value = expr_value.try_unpack_single()?.to_deref()?;
+
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let limit_value: BigInt = BigInt::from(1) << bits;
+    let half: BigInt = BigInt::from(1) << (bits - 1);
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1; // = 2**128.
+    let fixer_value = u128_bound - limit_value.clone();
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let value = casm_builder.add_var(ResOperand::Deref(value));
+    casm_build_extend! {casm_builder,
+        // The value is in range iff `0 <= value + 2**(bits-1) < 2**bits`.
+        const half = half;
+        tempvar biased_value = value + half;
+        tempvar in_range;
+        const limit = limit_value.clone();
+        hint TestLessThan { lhs: biased_value, rhs: limit } into { dst: in_range };
+        jump InRange if in_range != 0;
+    };
+    add_felt_at_least_bound_validation(&mut casm_builder, range_check, biased_value, limit_value);
+    casm_build_extend! {casm_builder,
+            jump FailureHandle;
+        InRange:
+            // Verify that `0 <= biased_value < 2**bits`.
+            assert biased_value = *(range_check++);
+            const fixer = fixer_value;
+            tempvar fixed_biased_value = biased_value + fixer;
+            assert fixed_biased_value = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("FailureHandle", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+    ))
+}
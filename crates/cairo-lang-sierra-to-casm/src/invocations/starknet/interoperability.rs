@@ -6,6 +6,7 @@ use num_bigint::BigInt;
 
 use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
 use crate::invocations::get_non_fallthrough_statement_id;
+use crate::invocations::uint::add_felt_at_least_bound_validation;
 use crate::references::{CellExpression, ReferenceExpression};
 
 #[cfg(test)]
@@ -78,3 +79,55 @@ pub fn build_contract_address_const(
         [ReferenceExpression::from_cell(CellExpression::Immediate(libfunc.c.clone()))].into_iter(),
     ))
 }
+
+/// Handles the contract_address_try_from_felt libfunc.
+pub fn build_contract_address_try_from_felt(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check_expression, expr_value] = builder.try_get_refs()?;
+    let range_check = range_check_expression.try_unpack_single()?.to_buffer(3)?;
+    let value = expr_value.try_unpack_single()?.to_deref()?;
+
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let addr_bound: BigInt = BigInt::from(1) << 251;
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1; // = 2**128.
+    // `value < 2**251` iff `value = 2**128 * x + y` where `x < 2**123` and `y < 2**128`.
+    let x_bound: BigInt = BigInt::from(1) << 123;
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let value = casm_builder.add_var(ResOperand::Deref(value));
+    casm_build_extend! {casm_builder,
+        tempvar is_small;
+        const limit = addr_bound.clone();
+        hint TestLessThan { lhs: value, rhs: limit } into { dst: is_small };
+        jump IsSmall if is_small != 0;
+    };
+    add_felt_at_least_bound_validation(&mut casm_builder, range_check, value, addr_bound);
+    casm_build_extend! {casm_builder,
+            jump FailureHandle;
+        IsSmall:
+            tempvar x;
+            tempvar y;
+            const u128_limit = u128_bound.clone();
+            // Write value as 2**128 * x + y.
+            hint DivMod { lhs: value, rhs: u128_limit } into { quotient: x, remainder: y };
+            // Check y in [0, 2**128).
+            assert y = *(range_check++);
+            // Check x in [0, 2**123), by constraining `x + (2**128 - 2**123) < 2**128`.
+            assert x = *(range_check++);
+            const fixer = u128_bound - x_bound;
+            tempvar fixed_x = x + fixer;
+            assert fixed_x = *(range_check++);
+            // Check that value = 2**128 * x + y. No overflow is possible, as the result is smaller
+            // than 2**251.
+            tempvar x_2_128 = x * u128_limit;
+            assert value = x_2_128 + y;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("FailureHandle", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+    ))
+}
@@ -1,17 +1,26 @@
 use cairo_lang_sierra::extensions::consts::SignatureAndConstConcreteLibfunc;
+use cairo_lang_sierra::extensions::felt::FeltBinaryOperator;
 use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use num_bigint::BigInt;
 
-use self::interoperability::{build_call_contract, build_contract_address_const};
-use super::{CompiledInvocation, CompiledInvocationBuilder};
+use self::interoperability::{
+    build_call_contract, build_contract_address_const, build_contract_address_try_from_felt,
+};
+use super::{misc, CompiledInvocation, CompiledInvocationBuilder};
 use crate::invocations::InvocationError;
-use crate::references::{CellExpression, ReferenceExpression};
+use crate::references::{BinOpExpression, CellExpression, ReferenceExpression};
 
 mod storage;
-use storage::{build_storage_read, build_storage_write};
+use storage::{build_storage_base_address_from_felt, build_storage_read, build_storage_write};
 
 mod interoperability;
 
+mod emit_event;
+use emit_event::build_emit_event;
+
+mod syscalls;
+use syscalls::build_execution_info_syscall;
+
 /// Builds instructions for Sierra array operations.
 pub fn build(
     libfunc: &StarkNetConcreteLibfunc,
@@ -22,11 +31,42 @@ pub fn build(
         StarkNetConcreteLibfunc::ContractAddressConst(libfunc) => {
             build_contract_address_const(builder, libfunc)
         }
+        StarkNetConcreteLibfunc::ContractAddressTryFromFelt(_) => {
+            build_contract_address_try_from_felt(builder)
+        }
+        StarkNetConcreteLibfunc::ContractAddressToFelt(_) => misc::build_identity(builder),
         StarkNetConcreteLibfunc::StorageRead(_) => build_storage_read(builder),
         StarkNetConcreteLibfunc::StorageWrite(_) => build_storage_write(builder),
         StarkNetConcreteLibfunc::StorageAddressConst(libfunc) => {
             build_storage_address_const(builder, libfunc)
         }
+        StarkNetConcreteLibfunc::StorageBaseAddressConst(libfunc) => {
+            build_storage_base_address_const(builder, libfunc)
+        }
+        StarkNetConcreteLibfunc::StorageBaseAddressFromFelt(_) => {
+            build_storage_base_address_from_felt(builder)
+        }
+        StarkNetConcreteLibfunc::StorageAddressFromBase(_) => misc::build_identity(builder),
+        StarkNetConcreteLibfunc::StorageAddressFromBaseAndOffset(_) => {
+            build_storage_address_from_base_and_offset(builder)
+        }
+        StarkNetConcreteLibfunc::EmitEvent(_) => build_emit_event(builder),
+        StarkNetConcreteLibfunc::GetCallerAddress(_) => {
+            build_execution_info_syscall(builder, "GetCallerAddress", &[1])
+        }
+        StarkNetConcreteLibfunc::GetContractAddress(_) => {
+            build_execution_info_syscall(builder, "GetContractAddress", &[1])
+        }
+        StarkNetConcreteLibfunc::GetBlockNumber(_) => {
+            build_execution_info_syscall(builder, "GetBlockNumber", &[1])
+        }
+        StarkNetConcreteLibfunc::GetBlockTimestamp(_) => {
+            build_execution_info_syscall(builder, "GetBlockTimestamp", &[1])
+        }
+        // The signature is an array, which takes two cells.
+        StarkNetConcreteLibfunc::GetTxInfo(_) => {
+            build_execution_info_syscall(builder, "GetTxInfo", &[1, 1, 2, 1, 1, 1])
+        }
     }
 }
 
@@ -44,3 +84,36 @@ fn build_storage_address_const(
         [ReferenceExpression::from_cell(CellExpression::Immediate(libfunc.c.clone()))].into_iter(),
     ))
 }
+
+/// Handles the storage_base_address_const libfunc.
+fn build_storage_base_address_const(
+    builder: CompiledInvocationBuilder<'_>,
+    libfunc: &SignatureAndConstConcreteLibfunc,
+) -> Result<CompiledInvocation, InvocationError> {
+    // The base address leaves room for an offset in the range [0, 256).
+    let addr_bound = (BigInt::from(1) << 251) - 256;
+    if libfunc.c >= addr_bound {
+        return Err(InvocationError::InvalidGenericArg);
+    }
+
+    Ok(builder.build_only_reference_changes(
+        [ReferenceExpression::from_cell(CellExpression::Immediate(libfunc.c.clone()))].into_iter(),
+    ))
+}
+
+/// Handles the storage_address_from_base_and_offset libfunc.
+fn build_storage_address_from_base_and_offset(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_base, expr_offset] = builder.try_get_refs()?;
+    let base = expr_base.try_unpack_single()?.to_deref()?;
+    let offset = expr_offset.try_unpack_single()?.to_deref_or_immediate()?;
+    Ok(builder.build_only_reference_changes(
+        [ReferenceExpression::from_cell(CellExpression::BinOp(BinOpExpression {
+            op: FeltBinaryOperator::Add,
+            a: base,
+            b: offset,
+        }))]
+        .into_iter(),
+    ))
+}
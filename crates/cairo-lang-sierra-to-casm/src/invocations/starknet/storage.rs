@@ -107,3 +107,88 @@ pub fn build_storage_write(
         ],
     ))
 }
+
+/// Handles the storage_base_address_from_felt libfunc.
+/// The result is `addr % addr_bound`, where `addr_bound = 2**251 - 256`.
+/// As `PRIME < 2 * addr_bound`, this is either `addr` or `addr - addr_bound`.
+pub fn build_storage_base_address_from_felt(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check_expression, expr_addr] = builder.try_get_refs()?;
+    let range_check = range_check_expression.try_unpack_single()?.to_buffer(3)?;
+    let addr = expr_addr.try_unpack_single()?.to_deref()?;
+
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1; // = 2**128.
+    let prime: BigInt = (BigInt::from(1) << 251) + 17 * (BigInt::from(1) << 192) + 1;
+    let addr_bound: BigInt = (BigInt::from(1) << 251) - 256;
+    // The maximal possible value of the result, per branch. When `addr < addr_bound` the result is
+    // `addr` and is at most `addr_bound - 1`. Otherwise, it is `addr - addr_bound` and is at most
+    // `PRIME - 1 - addr_bound`. Bounding the result this way also validates the taken branch.
+    let [(small_max_x, small_max_y), (big_max_x, big_max_y)] =
+        [&addr_bound - 1, &prime - 1 - &addr_bound]
+            .map(|max_value: BigInt| (&max_value / &u128_bound, max_value % &u128_bound));
+
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let addr = casm_builder.add_var(ResOperand::Deref(addr));
+    casm_build_extend! {casm_builder,
+            const addr_bound = addr_bound;
+            tempvar is_small;
+            hint TestLessThan { lhs: addr, rhs: addr_bound } into { dst: is_small };
+            // Allocating the values that depend on the branch: the result, and the constants
+            // describing its maximal value as `2**128 * max_x + max_y`.
+            tempvar res;
+            tempvar minus_max_x;
+            tempvar le_max_y_fix;
+            tempvar lt_max_x_fix;
+            jump IsSmall if is_small != 0;
+            assert res = addr - addr_bound;
+            const big_minus_max_x = -big_max_x.clone();
+            assert minus_max_x = big_minus_max_x;
+            const big_le_max_y_fix = (u128_bound.clone() - big_max_y - 1) as BigInt;
+            assert le_max_y_fix = big_le_max_y_fix;
+            const big_lt_max_x_fix = (u128_bound.clone() - big_max_x) as BigInt;
+            assert lt_max_x_fix = big_lt_max_x_fix;
+            jump Validate;
+        IsSmall:
+            assert res = addr;
+            const small_minus_max_x = -small_max_x.clone();
+            assert minus_max_x = small_minus_max_x;
+            const small_le_max_y_fix = (u128_bound.clone() - small_max_y - 1) as BigInt;
+            assert le_max_y_fix = small_le_max_y_fix;
+            const small_lt_max_x_fix = (u128_bound.clone() - small_max_x) as BigInt;
+            assert lt_max_x_fix = small_lt_max_x_fix;
+        Validate:
+            // Allocating all values required so that `x` and `y` would be last.
+            tempvar x_2_128;
+            tempvar x_minus_max_x;
+            tempvar rced_value;
+            tempvar x;
+            tempvar y;
+            const u128_limit = u128_bound;
+            // Write res as 2**128 * x + y.
+            hint DivMod { lhs: res, rhs: u128_limit } into { quotient: x, remainder: y };
+            // Check x in [0, 2**128).
+            assert x = *(range_check++);
+            // Check y in [0, 2**128).
+            assert y = *(range_check++);
+            // Check that res = 2**128 * x + y (mod PRIME).
+            assert x_2_128 = x * u128_limit;
+            assert res = x_2_128 + y;
+            // Check that there is no overflow in the computation of 2**128 * x + y, and that the
+            // result is at most 2**128 * max_x + max_y. Start by checking if x==max_x.
+            assert x_minus_max_x = x + minus_max_x;
+            jump XNotMaxX if x_minus_max_x != 0;
+            // If x == max_x, check that y <= max_y.
+            assert rced_value = y + le_max_y_fix;
+            jump WriteRcedValue;
+        XNotMaxX:
+            // If x != max_x, check that x < max_x.
+            assert rced_value = x + lt_max_x_fix;
+        WriteRcedValue:
+            // In both cases, range-check the calculated value.
+            assert rced_value = *(range_check++);
+    };
+    Ok(builder
+        .build_from_casm_builder(casm_builder, [("Fallthrough", &[&[range_check], &[res]], None)]))
+}
@@ -117,3 +117,21 @@ fn test_storage_write() {
         }
     );
 }
+
+#[test]
+fn test_storage_address_from_base_and_offset() {
+    assert_eq!(
+        compile_libfunc(
+            "storage_address_from_base_and_offset",
+            vec![ref_expr!([fp + 1]), ref_expr!([fp + 2])]
+        ),
+        ReducedCompiledInvocation {
+            instructions: vec![],
+            relocations: vec![],
+            results: vec![ReducedBranchChanges {
+                refs: vec![ref_expr!([fp + 1] + [fp + 2])],
+                ap_change: ApChange::Known(0)
+            }]
+        }
+    );
+}
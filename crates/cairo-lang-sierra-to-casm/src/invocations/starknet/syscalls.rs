@@ -0,0 +1,59 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::operand::ResOperand;
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::get_non_fallthrough_statement_id;
+
+/// Builds instructions for StarkNet system calls that return values from the execution info.
+/// The system call with the given selector takes no arguments, and on success returns values of
+/// the sizes given by `output_sizes`.
+pub fn build_execution_info_syscall(
+    builder: CompiledInvocationBuilder<'_>,
+    selector: &str,
+    output_sizes: &[i16],
+) -> Result<CompiledInvocation, InvocationError> {
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let selector_imm = BigInt::from_bytes_be(num_bigint::Sign::Plus, selector.as_bytes());
+
+    let [expr_gas_builtin, expr_system] = builder.try_get_refs()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?.to_deref()?;
+    let system =
+        expr_system.try_unpack_single()?.to_buffer(3 + output_sizes.iter().sum::<i16>())?;
+
+    let mut casm_builder = CasmBuilder::default();
+    let system = casm_builder.add_var(system);
+    let gas_builtin = casm_builder.add_var(ResOperand::Deref(gas_builtin));
+    casm_build_extend! {casm_builder,
+        let original_system = system;
+        const selector_imm = selector_imm;
+        tempvar selector = selector_imm;
+        assert selector = *(system++);
+        assert gas_builtin = *(system++);
+        hint SystemCall { system: original_system };
+        let updated_gas_builtin = *(system++);
+        // `revert_reason` is 0 on success, nonzero on failure/revert.
+        tempvar revert_reason = *(system++);
+    };
+    let mut success_vars = vec![vec![updated_gas_builtin], vec![system]];
+    for size in output_sizes {
+        success_vars.push((0..*size).map(|_| casm_builder.get_ref_and_inc(system)).collect());
+    }
+    casm_build_extend! {casm_builder,
+        jump Failure if revert_reason != 0;
+    };
+
+    let success_vars = success_vars.iter().map(|vars| &vars[..]).collect::<Vec<_>>();
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &success_vars, None),
+            (
+                "Failure",
+                &[&[updated_gas_builtin], &[system], &[revert_reason]],
+                Some(failure_handle_statement_id),
+            ),
+        ],
+    ))
+}
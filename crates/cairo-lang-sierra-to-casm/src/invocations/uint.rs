@@ -0,0 +1,281 @@
+use cairo_lang_casm::builder::{CasmBuilder, Var};
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::operand::ResOperand;
+use cairo_lang_sierra::extensions::felt::FeltBinaryOperator;
+use cairo_lang_sierra::extensions::uint::{
+    UintConcrete, UintOperationConcreteLibfunc, UintOperator, UintTraits,
+};
+use num_bigint::BigInt;
+
+use super::uint128::{build_u128_le, build_u128_lt, unwrap_range_check_based_binary_op_refs};
+use super::{misc, CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::get_non_fallthrough_statement_id;
+use crate::references::{BinOpExpression, CellExpression, ReferenceExpression};
+
+/// Builds instructions for Sierra operations on unsigned integers smaller than u128.
+pub fn build<TUintTraits: UintTraits>(
+    libfunc: &UintConcrete<TUintTraits>,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        UintConcrete::Operation(UintOperationConcreteLibfunc { operator, .. }) => {
+            build_small_uint_op(builder, *operator, TUintTraits::BITS)
+        }
+        UintConcrete::JumpNotZero(_) => misc::build_jump_nz(builder),
+        UintConcrete::Const(libfunc) => Ok(builder.build_only_reference_changes(
+            [ReferenceExpression::from_cell(CellExpression::Immediate(BigInt::from(libfunc.c)))]
+                .into_iter(),
+        )),
+        UintConcrete::FromFelt(_) => build_small_uint_from_felt(builder, TUintTraits::BITS),
+        UintConcrete::ToFelt(_) => misc::build_identity(builder),
+        // Comparisons of values smaller than 2**128 are identical to u128 comparisons.
+        UintConcrete::LessThan(_) => build_u128_lt(builder),
+        UintConcrete::Equal(_) => misc::build_cell_eq(builder),
+        UintConcrete::LessThanOrEqual(_) => build_u128_le(builder),
+    }
+}
+
+/// Handles an operation with the given op on unsigned integers of `bits` bits.
+fn build_small_uint_op(
+    builder: CompiledInvocationBuilder<'_>,
+    op: UintOperator,
+    bits: u32,
+) -> Result<CompiledInvocation, InvocationError> {
+    match op {
+        UintOperator::OverflowingAdd => build_small_uint_overflowing_add(builder, bits),
+        UintOperator::OverflowingSub => build_small_uint_overflowing_sub(builder, bits),
+        UintOperator::DivMod => build_small_uint_divmod(builder),
+        UintOperator::WideMul => build_small_uint_wide_mul(builder),
+    }
+}
+
+/// Handles an overflowing addition of unsigned integers of `bits` bits.
+fn build_small_uint_overflowing_add(
+    builder: CompiledInvocationBuilder<'_>,
+    bits: u32,
+) -> Result<CompiledInvocation, InvocationError> {
+    let (range_check, a, b) = unwrap_range_check_based_binary_op_refs(&builder)?;
+    let limit: BigInt = BigInt::from(1) << bits;
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1;
+    let fixer_value = u128_bound - limit.clone();
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let a = casm_builder.add_var(ResOperand::Deref(a));
+    let b = casm_builder.add_var(ResOperand::Deref(b));
+    casm_build_extend! {casm_builder,
+            tempvar no_overflow;
+            tempvar a_plus_b = a + b;
+            const limit = limit;
+            hint TestLessThan {lhs: a_plus_b, rhs: limit} into {dst: no_overflow};
+            jump NoOverflow if no_overflow != 0;
+            // Overflow:
+            // Here we know that 2**bits <= a + b < 2 * (2**bits - 1).
+            tempvar wrapping_a_plus_b = a_plus_b - limit;
+            assert wrapping_a_plus_b = *(range_check++);
+            jump Target;
+        NoOverflow:
+            // Verify that `a + b < 2**bits` by constraining
+            // `a + b + (2**128 - 2**bits) < 2**128`.
+            const fixer = fixer_value;
+            tempvar fixed_a_plus_b = a_plus_b + fixer;
+            assert fixed_a_plus_b = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[a_plus_b]], None),
+            ("Target", &[&[range_check], &[wrapping_a_plus_b]], Some(failure_handle_statement_id)),
+        ],
+    ))
+}
+
+/// Handles an overflowing subtraction of unsigned integers of `bits` bits.
+fn build_small_uint_overflowing_sub(
+    builder: CompiledInvocationBuilder<'_>,
+    bits: u32,
+) -> Result<CompiledInvocation, InvocationError> {
+    let (range_check, a, b) = unwrap_range_check_based_binary_op_refs(&builder)?;
+    let limit: BigInt = BigInt::from(1) << bits;
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let a = casm_builder.add_var(ResOperand::Deref(a));
+    let b = casm_builder.add_var(ResOperand::Deref(b));
+    casm_build_extend! {casm_builder,
+            tempvar no_overflow;
+            tempvar a_minus_b = a - b;
+            const limit = limit;
+            hint TestLessThan {lhs: a_minus_b, rhs: limit} into {dst: no_overflow};
+            jump NoOverflow if no_overflow != 0;
+            // Underflow:
+            // Here we know that 0 - (2**bits - 1) <= a - b < 0.
+            tempvar wrapping_a_minus_b = a_minus_b + limit;
+            // Verify that `a - b < 0` by constraining `a - b + 2**128 < 2**128`.
+            const u128_limit = u128_bound;
+            tempvar fixed_a_minus_b = a_minus_b + u128_limit;
+            assert fixed_a_minus_b = *(range_check++);
+            jump Target;
+        NoOverflow:
+            assert a_minus_b = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[a_minus_b]], None),
+            ("Target", &[&[range_check], &[wrapping_a_minus_b]], Some(failure_handle_statement_id)),
+        ],
+    ))
+}
+
+/// Handles a divmod of unsigned integers smaller than u128.
+fn build_small_uint_divmod(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let (range_check, a, b) = unwrap_range_check_based_binary_op_refs(&builder)?;
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let a = casm_builder.add_var(ResOperand::Deref(a));
+    let b = casm_builder.add_var(ResOperand::Deref(b));
+    casm_build_extend! {casm_builder,
+        tempvar r_plus_1;
+        tempvar b_minus_r_minus_1;
+        tempvar bq;
+        tempvar q;
+        tempvar r;
+        hint DivMod { lhs: a, rhs: b } into { quotient: q, remainder: r };
+        // Both `q` and `r` must be uint128, see the u128 divmod for the reasoning.
+        assert q = *(range_check++);
+        assert r = *(range_check++);
+        // Verify `r < b` by constraining `0 <= b - (r + 1)`.
+        const one = 1;
+        assert r_plus_1 = r + one;
+        assert b = b_minus_r_minus_1 + r_plus_1;
+        assert b_minus_r_minus_1 = *(range_check++);
+        // Verify `b * q + r = a`.
+        // Since `b < 2**64` and `q < 2**128`, `b * q + r` cannot overflow.
+        assert bq = b * q;
+        assert a = bq + r;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[range_check], &[q], &[r]], None)],
+    ))
+}
+
+/// Handles a wide multiplication of unsigned integers of at most 64 bits.
+fn build_small_uint_wide_mul(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    // The multiplication of two values smaller than 2**64 is smaller than 2**128, so it can
+    // never overflow, and no validation is required.
+    let [expr_a, expr_b] = builder.try_get_refs()?;
+    let a = expr_a.try_unpack_single()?.to_deref()?;
+    let b = expr_b.try_unpack_single()?.to_deref_or_immediate()?;
+    Ok(builder.build_only_reference_changes(
+        [ReferenceExpression::from_cell(CellExpression::BinOp(BinOpExpression {
+            op: FeltBinaryOperator::Mul,
+            a,
+            b,
+        }))]
+        .into_iter(),
+    ))
+}
+
+/// Handles a casting a felt into an unsigned integer of `bits` bits.
+fn build_small_uint_from_felt(
+    builder: CompiledInvocationBuilder<'_>,
+    bits: u32,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check_expression, expr_value] = builder.try_get_refs()?;
+    let range_check = range_check_expression.try_unpack_single()?.to_buffer(3)?;
+    let value = expr_value.try_unpack_single()?.to_deref()?;
+
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let limit_value: BigInt = BigInt::from(1) << bits;
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1; // = 2**128.
+    let fixer_value = u128_bound - limit_value.clone();
+    let mut casm_builder = CasmBuilder::default();
+    // Defining params and constants.
+    let range_check = casm_builder.add_var(range_check);
+    let value = casm_builder.add_var(ResOperand::Deref(value));
+    casm_build_extend! {casm_builder,
+        tempvar is_small;
+        const limit = limit_value.clone();
+        hint TestLessThan { lhs: value, rhs: limit } into { dst: is_small };
+        jump IsSmall if is_small != 0;
+    };
+    add_felt_at_least_bound_validation(&mut casm_builder, range_check, value, limit_value);
+    casm_build_extend! {casm_builder,
+            jump FailureHandle;
+        IsSmall:
+            // Verify that `value < 2**bits` by constraining `value + (2**128 - 2**bits) < 2**128`.
+            const fixer = fixer_value;
+            tempvar fixed_value = value + fixer;
+            assert fixed_value = *(range_check++);
+            assert value = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("FailureHandle", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+    ))
+}
+
+/// Adds the instructions validating that the felt `value` is at least `bound` (as an integer in
+/// `[0, PRIME)`), where `0 < bound < PRIME`. Uses 3 range checks.
+pub fn add_felt_at_least_bound_validation(
+    casm_builder: &mut CasmBuilder,
+    range_check: Var,
+    value: Var,
+    bound: BigInt,
+) {
+    let u128_bound: BigInt = BigInt::from(u128::MAX) + 1; // = 2**128.
+    let prime: BigInt = (BigInt::from(1) << 251) + 17 * (BigInt::from(1) << 192) + 1;
+    // Represent the maximal possible value of `value - bound` (PRIME - 1 - bound) as
+    // 2**128 * max_x + max_y.
+    let max_shifted_value = prime - 1 - bound.clone();
+    let max_x: BigInt = &max_shifted_value / &u128_bound;
+    let max_y = max_shifted_value % &u128_bound;
+    casm_build_extend! {casm_builder,
+            // Here we need to prove that `value >= bound`, meaning that `value - bound` is in
+            // [0, PRIME - bound).
+            const bound = bound;
+            tempvar shifted_value = value - bound;
+            // Allocating all values required so that `x` and `y` would be last.
+            tempvar x_2_128;
+            tempvar x_minus_max_x;
+            tempvar rced_value;
+            tempvar x;
+            tempvar y;
+            const u128_limit = u128_bound.clone();
+            // Write shifted_value as 2**128 * x + y.
+            hint DivMod { lhs: shifted_value, rhs: u128_limit } into { quotient: x, remainder: y };
+            // Check x in [0, 2**128).
+            assert x = *(range_check++);
+            // Check y in [0, 2**128).
+            assert y = *(range_check++);
+            // Check that shifted_value = 2**128 * x + y (mod PRIME).
+            assert x_2_128 = x * u128_limit;
+            assert shifted_value = x_2_128 + y;
+            // Check that there is no overflow in the computation of 2**128 * x + y.
+            // Start by checking if x==max_x.
+            const minus_max_x = -max_x.clone();
+            assert x_minus_max_x = x + minus_max_x;
+            jump XNotMaxX if x_minus_max_x != 0;
+            // If x == max_x, check that y <= max_y.
+            const le_max_y_fix = (u128_bound.clone() - max_y - 1) as BigInt;
+            assert rced_value = y + le_max_y_fix;
+            jump WriteRcedValue;
+        XNotMaxX:
+            // If x != max_x, check that x < max_x.
+            const lt_max_x_fix = (u128_bound - max_x) as BigInt;
+            assert rced_value = x + lt_max_x_fix;
+        WriteRcedValue:
+            // In both cases, range-check the calculated value.
+            assert rced_value = *(range_check++);
+    };
+}
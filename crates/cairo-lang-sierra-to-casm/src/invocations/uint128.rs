@@ -1,8 +1,9 @@
-use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::builder::{CasmBuilder, Var};
 use cairo_lang_casm::casm_build_extend;
 use cairo_lang_casm::operand::{CellRef, ResOperand};
 use cairo_lang_sierra::extensions::uint128::{
-    IntOperator, Uint128Concrete, Uint128OperationConcreteLibfunc,
+    IntOperator, ShiftOperator, Uint128Concrete, Uint128OperationConcreteLibfunc,
+    Uint128ShiftConcreteLibfunc,
 };
 use num_bigint::BigInt;
 
@@ -19,6 +20,9 @@ pub fn build(
         Uint128Concrete::Operation(Uint128OperationConcreteLibfunc { operator, .. }) => {
             build_u128_op(builder, *operator)
         }
+        Uint128Concrete::Shift(Uint128ShiftConcreteLibfunc { operator, .. }) => {
+            build_u128_shift(builder, *operator)
+        }
         Uint128Concrete::JumpNotZero(_) => misc::build_jump_nz(builder),
         Uint128Concrete::Const(libfunc) => Ok(builder.build_only_reference_changes(
             [ReferenceExpression::from_cell(CellExpression::Immediate(BigInt::from(libfunc.c)))]
@@ -109,53 +113,7 @@ fn build_u128_op(
             let range_check = casm_builder.add_var(range_check);
             let a = casm_builder.add_var(ResOperand::Deref(a));
             let b = casm_builder.add_var(ResOperand::Deref(b));
-            casm_build_extend! {casm_builder,
-                tempvar r_plus_1;
-                tempvar b_minus_r_minus_1;
-                tempvar q_is_small;
-                tempvar b_or_q_bound_rc_value;
-                tempvar bq;
-                tempvar q;
-                tempvar r;
-                hint DivMod { lhs: a, rhs: b } into { quotient: q, remainder: r };
-                // Both `q` and `r` must be uint128.
-                // We must check `r` explicitly: we later check that `0 <= b - (r + 1)` and
-                // `b * q + r = a`, however, if `r = -1` we may pass both of these checks (say, if
-                // `b = a + 1` and `q = 1`).
-                // We must also check `q` explicitly; the only arithmetic constraint on `q` is
-                // `b * q + r = a`, and if `b = 2`, `a = 1` and `r = 0`, we can take `q` to be the
-                // inverse of 2 (`(PRIME + 1) / 2`, much larger than 2^128) and pass this
-                // constraint.
-                assert q = *(range_check++);
-                assert r = *(range_check++);
-                // Verify `r < b` by constraining `0 <= b - (r + 1)`.
-                const one = 1;
-                assert r_plus_1 = r + one;
-                assert b = b_minus_r_minus_1 + r_plus_1;
-                assert b_minus_r_minus_1 = *(range_check++);
-                // Verify `b * q + r = a`.
-                // Since both `b` and `q` can be 2^128-1, we may overflow on `b * q`. To verify this
-                // is not the case, use the fact that `b * q` must be less than 2^128. We know
-                // `min(b, q)` must be less than 2^64. We guess which is less and verify.
-                const u64_bound = u64::MAX as u128 + 1;
-                hint TestLessThan {lhs: q, rhs: u64_bound} into {dst: q_is_small};
-                const u128_bound_minus_u64_bound = u128::MAX - u64::MAX as u128;
-                jump QIsSmall if q_is_small != 0;
-                // `q >= 2^64`, so to verify `b < 2^64` we assert `2^128 - 2^64 + b` is in the range
-                // check bound.
-                assert b_or_q_bound_rc_value = b + u128_bound_minus_u64_bound;
-                jump VerifyBQ;
-                QIsSmall:
-                // `q < 2^64`, compute `2^64 - q`.
-                assert b_or_q_bound_rc_value = q + u128_bound_minus_u64_bound;
-                VerifyBQ:
-                // Now, b_or_q_bound_rc_value contains either `2^128 - 2^64 + q` or
-                // `2^128 - 2^64 + b`. Verify this value is in [0, 2^128).
-                assert b_or_q_bound_rc_value = *(range_check++);
-                // Range validations done; verify `b * q + r = a` and that's it.
-                assert bq = b * q;
-                assert a = bq + r;
-            };
+            let (q, r) = add_u128_divmod(&mut casm_builder, range_check, a, b);
             Ok(builder.build_from_casm_builder(
                 casm_builder,
                 [("Fallthrough", &[&[range_check], &[q], &[r]], None)],
@@ -263,6 +221,166 @@ fn build_u128_op(
     }
 }
 
+/// Handles a u128 shift with the given op.
+fn build_u128_shift(
+    builder: CompiledInvocationBuilder<'_>,
+    op: ShiftOperator,
+) -> Result<CompiledInvocation, InvocationError> {
+    let (range_check, a, b) = unwrap_range_check_based_binary_op_refs(&builder)?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let a = casm_builder.add_var(ResOperand::Deref(a));
+    let b = casm_builder.add_var(ResOperand::Deref(b));
+    casm_build_extend! {casm_builder,
+        tempvar b_is_small;
+        const shift_bound = 128;
+        hint TestLessThan {lhs: b, rhs: shift_bound} into {dst: b_is_small};
+        jump Small if b_is_small != 0;
+        // `b >= 128`, verify by range checking `b - 128`.
+        const minus_shift_bound = -128;
+        tempvar b_minus_shift_bound = b + minus_shift_bound;
+        assert b_minus_shift_bound = *(range_check++);
+        jump TooLarge;
+        Small:
+    };
+    let pow = add_u7_pow2(&mut casm_builder, b);
+    let result = match op {
+        ShiftOperator::Shr => {
+            // `a >> b` is the quotient of `a` divided by `2**b`.
+            let (q, _) = add_u128_divmod(&mut casm_builder, range_check, a, pow);
+            q
+        }
+        ShiftOperator::Shl => {
+            // Write `a = hi * 2**(128 - b) + lo`, so that `(a << b) % 2**128 = lo * 2**b`.
+            casm_build_extend! {casm_builder,
+                const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+                tempvar u128_limit_value = u128_limit;
+                // Since `pow` is a power of 2 smaller than `2**128`, this is exactly
+                // `2**(128 - b)`.
+                tempvar pow_inv = u128_limit_value / pow;
+            };
+            let (_, lo) = add_u128_divmod(&mut casm_builder, range_check, a, pow_inv);
+            casm_build_extend! {casm_builder,
+                tempvar shifted = lo * pow;
+            };
+            shifted
+        }
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[result]], None),
+            ("TooLarge", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+    ))
+}
+
+/// Adds the instructions computing `2**b` for a value `b` and verifying that `b < 128`.
+/// Returns the variable holding `2**b`.
+fn add_u7_pow2(casm_builder: &mut CasmBuilder, b: Var) -> Var {
+    // Decompose `b` into its 7 bits: `b = bit0 + 2 * (bit1 + 2 * (... + 2 * bit6))`.
+    // As all the bits are verified to be boolean, this also verifies that `b < 128`.
+    let mut bits = vec![];
+    let mut rest = b;
+    for _ in 0..6 {
+        casm_build_extend! {casm_builder,
+            tempvar next_rest;
+            tempvar bit;
+            const two = 2;
+            hint DivMod {lhs: rest, rhs: two} into {quotient: next_rest, remainder: bit};
+            assert bit = bit * bit;
+            tempvar double_next_rest = next_rest + next_rest;
+            assert rest = double_next_rest + bit;
+        };
+        bits.push(bit);
+        rest = next_rest;
+    }
+    casm_build_extend! {casm_builder,
+        assert rest = rest * rest;
+    };
+    bits.push(rest);
+    // Compute `2**b` as the product of `1 + bit_i * (2**(2**i) - 1)` over all bits.
+    let mut pow = None;
+    for (i, bit) in bits.into_iter().enumerate() {
+        let factor_minus_one: BigInt = (BigInt::from(1) << (1 << i)) - 1;
+        casm_build_extend! {casm_builder,
+            const one = 1;
+            const factor_minus_one = factor_minus_one;
+            tempvar scaled_bit = bit * factor_minus_one;
+            tempvar factor = scaled_bit + one;
+        };
+        pow = Some(match pow {
+            None => factor,
+            Some(pow) => {
+                casm_build_extend! {casm_builder,
+                    tempvar next_pow = pow * factor;
+                };
+                next_pow
+            }
+        });
+    }
+    pow.unwrap()
+}
+
+/// Adds the instructions computing the quotient and remainder of the division of `a` by `b`,
+/// which must both be u128 values with `b` non-zero. Returns `(q, r)`.
+pub fn add_u128_divmod(
+    casm_builder: &mut CasmBuilder,
+    range_check: Var,
+    a: Var,
+    b: Var,
+) -> (Var, Var) {
+    casm_build_extend! {casm_builder,
+        tempvar r_plus_1;
+        tempvar b_minus_r_minus_1;
+        tempvar q_is_small;
+        tempvar b_or_q_bound_rc_value;
+        tempvar bq;
+        tempvar q;
+        tempvar r;
+        hint DivMod { lhs: a, rhs: b } into { quotient: q, remainder: r };
+        // Both `q` and `r` must be uint128.
+        // We must check `r` explicitly: we later check that `0 <= b - (r + 1)` and
+        // `b * q + r = a`, however, if `r = -1` we may pass both of these checks (say, if
+        // `b = a + 1` and `q = 1`).
+        // We must also check `q` explicitly; the only arithmetic constraint on `q` is
+        // `b * q + r = a`, and if `b = 2`, `a = 1` and `r = 0`, we can take `q` to be the
+        // inverse of 2 (`(PRIME + 1) / 2`, much larger than 2^128) and pass this
+        // constraint.
+        assert q = *(range_check++);
+        assert r = *(range_check++);
+        // Verify `r < b` by constraining `0 <= b - (r + 1)`.
+        const one = 1;
+        assert r_plus_1 = r + one;
+        assert b = b_minus_r_minus_1 + r_plus_1;
+        assert b_minus_r_minus_1 = *(range_check++);
+        // Verify `b * q + r = a`.
+        // Since both `b` and `q` can be 2^128-1, we may overflow on `b * q`. To verify this
+        // is not the case, use the fact that `b * q` must be less than 2^128. We know
+        // `min(b, q)` must be less than 2^64. We guess which is less and verify.
+        const u64_bound = u64::MAX as u128 + 1;
+        hint TestLessThan {lhs: q, rhs: u64_bound} into {dst: q_is_small};
+        const u128_bound_minus_u64_bound = u128::MAX - u64::MAX as u128;
+        jump QIsSmall if q_is_small != 0;
+        // `q >= 2^64`, so to verify `b < 2^64` we assert `2^128 - 2^64 + b` is in the range
+        // check bound.
+        assert b_or_q_bound_rc_value = b + u128_bound_minus_u64_bound;
+        jump VerifyBQ;
+        QIsSmall:
+        // `q < 2^64`, compute `2^64 - q`.
+        assert b_or_q_bound_rc_value = q + u128_bound_minus_u64_bound;
+        VerifyBQ:
+        // Now, b_or_q_bound_rc_value contains either `2^128 - 2^64 + q` or
+        // `2^128 - 2^64 + b`. Verify this value is in [0, 2^128).
+        assert b_or_q_bound_rc_value = *(range_check++);
+        // Range validations done; verify `b * q + r = a` and that's it.
+        assert bq = b * q;
+        assert a = bq + r;
+    };
+    (q, r)
+}
+
 /// Handles a casting a felt into u128.
 fn build_u128_from_felt(
     builder: CompiledInvocationBuilder<'_>,
@@ -333,7 +451,7 @@ fn build_u128_from_felt(
     ))
 }
 
-fn build_u128_lt(
+pub fn build_u128_lt(
     builder: CompiledInvocationBuilder<'_>,
 ) -> Result<CompiledInvocation, InvocationError> {
     let (range_check, a, b) = unwrap_range_check_based_binary_op_refs(&builder)?;
@@ -363,7 +481,7 @@ fn build_u128_lt(
     ))
 }
 
-fn build_u128_le(
+pub fn build_u128_le(
     builder: CompiledInvocationBuilder<'_>,
 ) -> Result<CompiledInvocation, InvocationError> {
     let (range_check, a, b) = unwrap_range_check_based_binary_op_refs(&builder)?;
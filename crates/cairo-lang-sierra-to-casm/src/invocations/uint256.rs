@@ -0,0 +1,184 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::operand::ResOperand;
+use cairo_lang_sierra::extensions::uint256::Uint256Concrete;
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::get_non_fallthrough_statement_id;
+
+/// Builds instructions for Sierra u256 operations.
+pub fn build(
+    libfunc: &Uint256Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Uint256Concrete::JumpNotZero(_) => build_u256_jump_nz(builder),
+        Uint256Concrete::DivMod(_) => build_u256_divmod(builder),
+    }
+}
+
+/// Handles a jump non-zero on a u256 value.
+fn build_u256_jump_nz(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [low, high] = builder.try_get_refs::<1>()?[0].try_unpack()?;
+    let target_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    let low = casm_builder.add_var(ResOperand::Deref(low.to_deref()?));
+    let high = casm_builder.add_var(ResOperand::Deref(high.to_deref()?));
+    casm_build_extend! {casm_builder,
+        jump Target if low != 0;
+        jump Target if high != 0;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[], None), ("Target", &[&[low, high]], Some(target_statement_id))],
+    ))
+}
+
+/// Handles a u256 division with remainder.
+fn build_u256_divmod(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, dividend, divisor] = builder.try_get_refs()?;
+    let range_check = range_check.try_unpack_single()?.to_buffer(14)?;
+    let [dividend0, dividend1] = dividend.try_unpack()?;
+    let [divisor0, divisor1] = divisor.try_unpack()?;
+    let mut casm_builder = CasmBuilder::default();
+    let range_check = casm_builder.add_var(range_check);
+    let dividend0 = casm_builder.add_var(ResOperand::Deref(dividend0.to_deref()?));
+    let dividend1 = casm_builder.add_var(ResOperand::Deref(dividend1.to_deref()?));
+    let divisor0 = casm_builder.add_var(ResOperand::Deref(divisor0.to_deref()?));
+    let divisor1 = casm_builder.add_var(ResOperand::Deref(divisor1.to_deref()?));
+    casm_build_extend! {casm_builder,
+        tempvar quotient0;
+        tempvar quotient1;
+        tempvar remainder0;
+        tempvar remainder1;
+        hint Uint256DivMod {
+            dividend0: dividend0,
+            dividend1: dividend1,
+            divisor0: divisor0,
+            divisor1: divisor1
+        } into {
+            quotient0: quotient0,
+            quotient1: quotient1,
+            remainder0: remainder0,
+            remainder1: remainder1
+        };
+        // All the limbs of the quotient and the remainder must be uint128.
+        assert quotient0 = *(range_check++);
+        assert quotient1 = *(range_check++);
+        assert remainder0 = *(range_check++);
+        assert remainder1 = *(range_check++);
+        // Verify `remainder < divisor`: either `remainder1 < divisor1`, or `remainder1 = divisor1`
+        // and `remainder0 < divisor0`. In both cases constrain `0 <= divisor_i - (remainder_i + 1)`
+        // on the relevant limb.
+        tempvar remainder1_eq_divisor1;
+        tempvar remainder_plus_1;
+        tempvar divisor_minus_remainder_minus_1;
+        const one = 1;
+        hint TestLessThanOrEqual {lhs: divisor1, rhs: remainder1} into {
+            dst: remainder1_eq_divisor1
+        };
+        jump HighLimbsEqual if remainder1_eq_divisor1 != 0;
+        assert remainder_plus_1 = remainder1 + one;
+        assert divisor1 = divisor_minus_remainder_minus_1 + remainder_plus_1;
+        jump VerifyRemainder;
+        HighLimbsEqual:
+        assert remainder1 = divisor1;
+        assert remainder_plus_1 = remainder0 + one;
+        assert divisor0 = divisor_minus_remainder_minus_1 + remainder_plus_1;
+        VerifyRemainder:
+        assert divisor_minus_remainder_minus_1 = *(range_check++);
+        // Verify `quotient * divisor + remainder = dividend`.
+        // Since `quotient * divisor` must be less than 2**256, either `quotient1` or `divisor1` is
+        // zero. We write the product as `x * y`, where `x` is a u128 and `y` is a u256.
+        tempvar x;
+        tempvar y0;
+        tempvar y1;
+        jump QuotientIsBig if quotient1 != 0;
+        // `quotient1 = 0`, so take `x = quotient0` and `y = divisor`.
+        assert x = quotient0;
+        assert y0 = divisor0;
+        assert y1 = divisor1;
+        jump MulVerify;
+        QuotientIsBig:
+        // `quotient1 != 0`, so `divisor1` must be 0. Take `x = divisor0` and `y = quotient`.
+        const zero = 0;
+        assert divisor1 = zero;
+        assert x = divisor0;
+        assert y0 = quotient0;
+        assert y1 = quotient1;
+        MulVerify:
+        // Break `x` into two 64bit halves s.t. `x = x1 * 2**64 + x0`.
+        tempvar x1;
+        tempvar x0;
+        const u64_limit = u64::MAX as u128 + 1;
+        hint DivMod { lhs: x, rhs: u64_limit } into { quotient: x1, remainder: x0 };
+        // Verify that `x0 < 2**64` by constraining `x0 + (2**128-1) - (2**64-1) < 2**128`.
+        const u64_upper_fixer = u128::MAX - u64::MAX as u128;
+        tempvar fixed_x0 = x0 + u64_upper_fixer;
+        assert fixed_x0 = *(range_check++);
+        // Verify that `x0`, `x1` are in [0, 2**128).
+        assert x0 = *(range_check++);
+        assert x1 = *(range_check++);
+        // Check the break. As `x` is a uint128, this also verifies that `x1 < 2**64`.
+        tempvar x1_times_2_64 = x1 * u64_limit;
+        assert x = x1_times_2_64 + x0;
+        // Break `x1 * y0` (which is less than 2**192) into `t1_hi * 2**64 + t1_lo`, where
+        // `t1_lo < 2**64`.
+        tempvar x1_y0 = x1 * y0;
+        tempvar t1_hi;
+        tempvar t1_lo;
+        hint DivMod { lhs: x1_y0, rhs: u64_limit } into { quotient: t1_hi, remainder: t1_lo };
+        tempvar fixed_t1_lo = t1_lo + u64_upper_fixer;
+        assert fixed_t1_lo = *(range_check++);
+        assert t1_lo = *(range_check++);
+        assert t1_hi = *(range_check++);
+        tempvar t1_hi_times_2_64 = t1_hi * u64_limit;
+        assert x1_y0 = t1_hi_times_2_64 + t1_lo;
+        // Now `x * y0 = x0 * y0 + t1_lo * 2**64 + t1_hi * 2**128`.
+        // Compute the carry of the lower 128 bits:
+        // `x0 * y0 + t1_lo * 2**64 + remainder0 = dividend0 + carry * 2**128`.
+        // The left hand side is less than 2**193, so no wrap-around occurs.
+        tempvar x0_y0 = x0 * y0;
+        tempvar t1_lo_times_2_64 = t1_lo * u64_limit;
+        tempvar partial_low = x0_y0 + t1_lo_times_2_64;
+        tempvar low_with_carry = partial_low + remainder0;
+        tempvar shifted_carry = low_with_carry - dividend0;
+        const u128_limit = (BigInt::from(u128::MAX) + 1) as BigInt;
+        tempvar carry = shifted_carry / u128_limit;
+        // Verify that `carry` is in [0, 2**65).
+        const carry_range_fixer = u128::MAX - (2u128.pow(65) - 1);
+        tempvar fixed_carry = carry + carry_range_fixer;
+        assert fixed_carry = *(range_check++);
+        assert carry = *(range_check++);
+        // Verify the upper 128 bits: `dividend1 = x * y1 + t1_hi + carry + remainder1`.
+        // Since the result is less than 2**128, `min(x, y1)` must be less than 2**64, which
+        // guarantees no wrap-around in `x * y1`. We guess which is less and verify.
+        tempvar x_is_small;
+        tempvar x_or_y1_bound_rc_value;
+        hint TestLessThan {lhs: x, rhs: u64_limit} into {dst: x_is_small};
+        jump XIsSmall if x_is_small != 0;
+        assert x_or_y1_bound_rc_value = y1 + u64_upper_fixer;
+        jump VerifyHigh;
+        XIsSmall:
+        assert x_or_y1_bound_rc_value = x + u64_upper_fixer;
+        VerifyHigh:
+        assert x_or_y1_bound_rc_value = *(range_check++);
+        tempvar x_y1 = x * y1;
+        tempvar partial_high1 = x_y1 + t1_hi;
+        tempvar partial_high2 = partial_high1 + carry;
+        assert dividend1 = partial_high2 + remainder1;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [(
+            "Fallthrough",
+            &[&[range_check], &[quotient0, quotient1], &[remainder0, remainder1]],
+            None,
+        )],
+    ))
+}
@@ -23,23 +23,42 @@ pub fn get_type_size_map(
             | CoreTypeConcrete::Bitwise(_)
             | CoreTypeConcrete::BuiltinCosts(_)
             | CoreTypeConcrete::Nullable(_)
+            | CoreTypeConcrete::Uint8(_)
+            | CoreTypeConcrete::Uint16(_)
+            | CoreTypeConcrete::Uint32(_)
+            | CoreTypeConcrete::Uint64(_)
             | CoreTypeConcrete::Uint128(_)
+            | CoreTypeConcrete::Sint8(_)
+            | CoreTypeConcrete::Sint16(_)
+            | CoreTypeConcrete::Sint32(_)
+            | CoreTypeConcrete::Sint64(_)
+            | CoreTypeConcrete::Sint128(_)
             | CoreTypeConcrete::RangeCheck(_)
             | CoreTypeConcrete::Box(_)
             | CoreTypeConcrete::StarkNet(StarkNetTypeConcrete::System(_))
             | CoreTypeConcrete::StarkNet(StarkNetTypeConcrete::StorageAddress(_))
+            | CoreTypeConcrete::StarkNet(StarkNetTypeConcrete::StorageBaseAddress(_))
             | CoreTypeConcrete::StarkNet(StarkNetTypeConcrete::ContractAddress(_))
             | CoreTypeConcrete::Pedersen(_)
             | CoreTypeConcrete::DictFeltTo(_)
             | CoreTypeConcrete::DictManager(_) => Some(1),
             CoreTypeConcrete::Array(_)
+            | CoreTypeConcrete::ArraySlice(_)
             | CoreTypeConcrete::EcPoint(_)
             | CoreTypeConcrete::SquashedDictFeltTo(_) => Some(2),
             CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. }) => {
                 type_sizes.get(ty).cloned()
             }
             CoreTypeConcrete::Enum(enum_type) => {
-                Some(1 + enum_type.variants.iter().map(|variant| type_sizes[variant]).max()?)
+                // An enum without variants (such as `never`) has no values, so it takes no space.
+                Some(
+                    enum_type
+                        .variants
+                        .iter()
+                        .map(|variant| type_sizes[variant])
+                        .max()
+                        .map_or(0, |max_variant_size| 1 + max_variant_size),
+                )
             }
             CoreTypeConcrete::Struct(struct_type) => {
                 Some(struct_type.members.iter().map(|member| type_sizes[member]).sum())
@@ -1,5 +1,5 @@
 use super::ap_tracking::RevokeApTrackingLibfunc;
-use super::array::{ArrayLibfunc, ArrayType};
+use super::array::{ArrayLibfunc, ArraySliceType, ArrayType};
 use super::bitwise::{BitwiseLibfunc, BitwiseType};
 use super::boolean::BoolLibfunc;
 use super::branch_align::BranchAlignLibfunc;
@@ -16,7 +16,16 @@ use super::modules::function_call::FunctionCallLibfunc;
 use super::modules::gas::{GasBuiltinType, GasLibfunc};
 use super::modules::mem::MemLibfunc;
 use super::modules::non_zero::{NonZeroType, UnwrapNonZeroLibfunc};
+use super::modules::sint::{
+    Sint128Libfunc, Sint128Type, Sint16Libfunc, Sint16Type, Sint32Libfunc, Sint32Type,
+    Sint64Libfunc, Sint64Type, Sint8Libfunc, Sint8Type,
+};
+use super::modules::uint::{
+    Uint16Libfunc, Uint16Type, Uint32Libfunc, Uint32Type, Uint64Libfunc, Uint64Type, Uint8Libfunc,
+    Uint8Type,
+};
 use super::modules::uint128::{Uint128Libfunc, Uint128Type};
+use super::modules::uint256::Uint256Libfunc;
 use super::modules::unconditional_jump::UnconditionalJumpLibfunc;
 use super::nullable::{NullableLibfunc, NullableType};
 use super::pedersen::{PedersenLibfunc, PedersenType};
@@ -30,13 +39,23 @@ use crate::{define_libfunc_hierarchy, define_type_hierarchy};
 define_type_hierarchy! {
     pub enum CoreType {
         Array(ArrayType),
+        ArraySlice(ArraySliceType),
         Bitwise(BitwiseType),
         Box(BoxType),
         EcPoint(EcPointType),
         Felt(FeltType),
         GasBuiltin(GasBuiltinType),
         BuiltinCosts(BuiltinCostsType),
+        Uint8(Uint8Type),
+        Uint16(Uint16Type),
+        Uint32(Uint32Type),
+        Uint64(Uint64Type),
         Uint128(Uint128Type),
+        Sint8(Sint8Type),
+        Sint16(Sint16Type),
+        Sint32(Sint32Type),
+        Sint64(Sint64Type),
+        Sint128(Sint128Type),
         NonZero(NonZeroType),
         Nullable(NullableType),
         RangeCheck(RangeCheckType),
@@ -66,7 +85,17 @@ define_libfunc_hierarchy! {
         Felt(FeltLibfunc),
         FunctionCall(FunctionCallLibfunc),
         Gas(GasLibfunc),
+        Uint8(Uint8Libfunc),
+        Uint16(Uint16Libfunc),
+        Uint32(Uint32Libfunc),
+        Uint64(Uint64Libfunc),
         Uint128(Uint128Libfunc),
+        Uint256(Uint256Libfunc),
+        Sint8(Sint8Libfunc),
+        Sint16(Sint16Libfunc),
+        Sint32(Sint32Libfunc),
+        Sint64(Sint64Libfunc),
+        Sint128(Sint128Libfunc),
         Mem(MemLibfunc),
         Nullable(NullableLibfunc),
         UnwrapNonZero(UnwrapNonZeroLibfunc),
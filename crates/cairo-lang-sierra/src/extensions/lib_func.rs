@@ -498,12 +498,16 @@ impl SignatureBasedConcreteLibfunc for SignatureOnlyConcreteLibfunc {
 /// ```
 #[macro_export]
 macro_rules! define_concrete_libfunc_hierarchy {
-    (pub enum $name:ident { $($variant_name:ident ($variant:ty),)* }) => {
+    (pub enum $name:ident $(< $generic_arg:ident : $generic_bound:path >)? {
+        $($variant_name:ident ($variant:ty),)*
+    }) => {
         #[allow(clippy::enum_variant_names)]
-        pub enum $name {
+        pub enum $name $(< $generic_arg : $generic_bound >)? {
             $($variant_name ($variant),)*
         }
-        impl $crate::extensions::ConcreteLibfunc for $name {
+        impl $(< $generic_arg : $generic_bound >)? $crate::extensions::ConcreteLibfunc
+            for $name $(< $generic_arg >)?
+        {
             $crate::extensions::lib_func::concrete_method_impl! {
                 fn param_signatures(&self) -> &[$crate::extensions::lib_func::ParamSignature] {
                     $($variant_name => $variant,)*
@@ -541,6 +545,7 @@ pub(crate) use concrete_method_impl;
 /// Forms a libfunc type from an enum of libfuncs.
 /// The new enum implements [GenericLibfunc].
 /// All the variant types must also implement [GenericLibfunc].
+/// The enum may have a single generic parameter, shared by the variants and by the concrete enum.
 /// Usage example:
 /// ```ignore
 /// define_libfunc_hierarchy! {
@@ -549,18 +554,27 @@ pub(crate) use concrete_method_impl;
 ///       LF1(Libfunc1),
 ///     }, MyLibfuncConcrete
 /// }
+/// define_libfunc_hierarchy! {
+///     pub enum MyGenericLibfunc<T: MyTraits> {
+///       LF0(GenericLibfunc0<T>),
+///       LF1(GenericLibfunc1<T>),
+///     }, MyGenericLibfuncConcrete
+/// }
 /// ```
 #[macro_export]
 macro_rules! define_libfunc_hierarchy {
-    (pub enum $name:ident { $($variant_name:ident ($variant:ty),)* },
-    $concrete_name:ident) => {
+    (pub enum $name:ident $(< $generic_arg:ident : $generic_bound:path >)? {
+        $($variant_name:ident ($variant:ty),)*
+    }, $concrete_name:ident) => {
         #[allow(clippy::enum_variant_names)]
-        pub enum $name {
+        pub enum $name $(< $generic_arg : $generic_bound >)? {
             $($variant_name ($variant)),*
         }
 
-        impl $crate::extensions::GenericLibfunc for $name {
-            type Concrete = $concrete_name;
+        impl $(< $generic_arg : $generic_bound >)? $crate::extensions::GenericLibfunc
+            for $name $(< $generic_arg >)?
+        {
+            type Concrete = $concrete_name $(< $generic_arg >)?;
             fn by_id(id: &$crate::ids::GenericLibfuncId) -> Option<Self> {
                 $(
                     if let Some(res) = <$variant>::by_id(id){
@@ -608,7 +622,7 @@ macro_rules! define_libfunc_hierarchy {
         }
 
         $crate::define_concrete_libfunc_hierarchy! {
-            pub enum $concrete_name {
+            pub enum $concrete_name $(< $generic_arg : $generic_bound >)? {
                 $($variant_name (<$variant as $crate::extensions::GenericLibfunc> ::Concrete),)*
             }
         }
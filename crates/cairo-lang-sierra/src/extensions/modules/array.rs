@@ -41,6 +41,30 @@ impl GenericTypeArgGenericType for ArrayTypeWrapped {
 }
 pub type ArrayType = GenericTypeArgGenericTypeWrapper<ArrayTypeWrapped>;
 
+/// Type representing a read-only view of a contiguous part of an array.
+///
+/// A slice shares the memory of the array it was taken from, so creating and duplicating it does
+/// not copy the elements. Since Casm memory is write-once, the viewed elements never change.
+#[derive(Default)]
+pub struct ArraySliceTypeWrapped {}
+impl GenericTypeArgGenericType for ArraySliceTypeWrapped {
+    const ID: GenericTypeId = GenericTypeId::new_inline("ArraySlice");
+
+    fn calc_info(
+        &self,
+        long_id: crate::program::ConcreteTypeLongId,
+        wrapped_info: TypeInfo,
+    ) -> Result<TypeInfo, SpecializationError> {
+        // The elements are shared by all the copies of a slice, so they must be duplicatable.
+        if !wrapped_info.storable || !wrapped_info.duplicatable {
+            Err(SpecializationError::UnsupportedGenericArg)
+        } else {
+            Ok(TypeInfo { long_id, duplicatable: true, droppable: true, storable: true, size: 2 })
+        }
+    }
+}
+pub type ArraySliceType = GenericTypeArgGenericTypeWrapper<ArraySliceTypeWrapped>;
+
 define_libfunc_hierarchy! {
     pub enum ArrayLibfunc {
         New(ArrayNewLibfunc),
@@ -48,6 +72,9 @@ define_libfunc_hierarchy! {
         PopFront(ArrayPopFrontLibfunc),
         At(ArrayAtLibfunc),
         Len(ArrayLenLibfunc),
+        Slice(ArraySliceLibfunc),
+        SlicePopFront(ArraySlicePopFrontLibfunc),
+        SliceLen(ArraySliceLenLibfunc),
     }, ArrayConcreteLibfunc
 }
 
@@ -247,3 +274,142 @@ impl SignatureAndTypeGenericLibfunc for ArrayAtLibfuncWrapped {
     }
 }
 pub type ArrayAtLibfunc = WrapSignatureAndTypeGenericLibfunc<ArrayAtLibfuncWrapped>;
+
+/// Libfunc for taking a slice of an array, given the index of its first element and its length.
+#[derive(Default)]
+pub struct ArraySliceLibfuncWrapped {}
+impl SignatureAndTypeGenericLibfunc for ArraySliceLibfuncWrapped {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("array_slice");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        ty: ConcreteTypeId,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let arr_type = context.get_wrapped_concrete_type(ArrayType::id(), ty.clone())?;
+        let slice_type = context.get_wrapped_concrete_type(ArraySliceType::id(), ty)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        let uint128_type = context.get_concrete_type(Uint128Type::id(), &[])?;
+        let param_signatures = vec![
+            ParamSignature::new(range_check_type.clone()),
+            ParamSignature::new(arr_type.clone()),
+            ParamSignature::new(uint128_type.clone()),
+            ParamSignature::new(uint128_type),
+        ];
+        let branch_signatures = vec![
+            // First (success) branch returns rc, array and slice; failure branch does not return
+            // a slice.
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo {
+                        ty: range_check_type.clone(),
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                            param_idx: 0,
+                        }),
+                    },
+                    OutputVarInfo {
+                        ty: arr_type.clone(),
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                    },
+                    OutputVarInfo {
+                        ty: slice_type,
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+            BranchSignature {
+                vars: vec![
+                    OutputVarInfo {
+                        ty: range_check_type,
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                            param_idx: 0,
+                        }),
+                    },
+                    OutputVarInfo {
+                        ty: arr_type,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+        ];
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+pub type ArraySliceLibfunc = WrapSignatureAndTypeGenericLibfunc<ArraySliceLibfuncWrapped>;
+
+/// Libfunc for popping the first value from the begining of an array slice.
+#[derive(Default)]
+pub struct ArraySlicePopFrontLibfuncWrapped {}
+impl SignatureAndTypeGenericLibfunc for ArraySlicePopFrontLibfuncWrapped {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("array_slice_pop_front");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        ty: ConcreteTypeId,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let slice_ty = context.get_wrapped_concrete_type(ArraySliceType::id(), ty.clone())?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![ParamSignature::new(slice_ty.clone())],
+            branch_signatures: vec![
+                BranchSignature {
+                    vars: vec![
+                        OutputVarInfo {
+                            ty: slice_ty.clone(),
+                            ref_info: OutputVarReferenceInfo::Deferred(
+                                DeferredOutputKind::AddConst { param_idx: 0 },
+                            ),
+                        },
+                        OutputVarInfo {
+                            ty,
+                            ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                BranchSignature {
+                    vars: vec![OutputVarInfo {
+                        ty: slice_ty,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+                    }],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+pub type ArraySlicePopFrontLibfunc =
+    WrapSignatureAndTypeGenericLibfunc<ArraySlicePopFrontLibfuncWrapped>;
+
+/// Libfunc for getting the length of an array slice.
+#[derive(Default)]
+pub struct ArraySliceLenLibfuncWrapped {}
+impl SignatureAndTypeGenericLibfunc for ArraySliceLenLibfuncWrapped {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("array_slice_len");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        ty: ConcreteTypeId,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let slice_type = context.get_wrapped_concrete_type(ArraySliceType::id(), ty)?;
+        Ok(LibfuncSignature::new_non_branch(
+            vec![slice_type.clone()],
+            vec![
+                OutputVarInfo {
+                    ty: slice_type,
+                    ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+                },
+                OutputVarInfo {
+                    ty: context.get_concrete_type(Uint128Type::id(), &[])?,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                },
+            ],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+pub type ArraySliceLenLibfunc = WrapSignatureAndTypeGenericLibfunc<ArraySliceLenLibfuncWrapped>;
@@ -28,10 +28,13 @@ pub mod non_zero;
 pub mod nullable;
 pub mod pedersen;
 pub mod range_check;
+pub mod sint;
 pub mod squashed_dict_felt_to;
 pub mod starknet;
 pub mod strct;
+pub mod uint;
 pub mod uint128;
+pub mod uint256;
 pub mod unconditional_jump;
 pub mod uninitialized;
 
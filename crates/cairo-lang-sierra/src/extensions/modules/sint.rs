@@ -0,0 +1,450 @@
+use std::marker::PhantomData;
+
+use super::felt::FeltType;
+use super::range_check::RangeCheckType;
+use super::uint128::IntOperator;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::{
+    GenericLibfunc, NamedLibfunc, NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType,
+    OutputVarReferenceInfo, SignatureBasedConcreteLibfunc, SpecializationError,
+};
+use crate::ids::{GenericLibfuncId, GenericTypeId};
+use crate::program::GenericArg;
+
+/// Trait for implementing the library functions of a signed integer type.
+pub trait SintTraits: Default {
+    /// The rust type matching this type.
+    type SintType: TryFrom<i128> + Into<i128> + Copy;
+    /// The number of bits in the type.
+    const BITS: u32;
+    /// The generic type id for this type.
+    const GENERIC_TYPE_ID: GenericTypeId;
+    /// The id of the unsigned integer type of the same size, holding the result of a diff.
+    const UNSIGNED_TYPE_ID: GenericTypeId;
+    /// The id of the generic type holding the result of a wide multiplication of this type, if
+    /// there is a wider signed integer type.
+    const WIDE_MUL_RES_TYPE_ID: Option<GenericTypeId>;
+    /// The const library function id.
+    const CONST: GenericLibfuncId;
+    /// The overflowing add library function id.
+    const OVERFLOWING_ADD: GenericLibfuncId;
+    /// The overflowing sub library function id.
+    const OVERFLOWING_SUB: GenericLibfuncId;
+    /// The wide mul library function id, if there is a wider signed integer type.
+    const WIDE_MUL: Option<GenericLibfuncId>;
+    /// The diff library function id.
+    const DIFF: GenericLibfuncId;
+    /// The equal library function id.
+    const EQUAL: GenericLibfuncId;
+    /// The try from felt library function id.
+    const TRY_FROM_FELT: GenericLibfuncId;
+    /// The to felt library function id.
+    const TO_FELT: GenericLibfuncId;
+}
+
+/// Defines the traits struct of a signed integer type.
+macro_rules! define_sint_traits {
+    ($traits_name:ident, $rust_type:ident, $type_name:literal, $unsigned_type_name:literal,
+     $wide_type_name:literal) => {
+        define_sint_traits!(
+            @impl $traits_name, $rust_type, $type_name, $unsigned_type_name,
+            Some(GenericTypeId::new_inline($wide_type_name)),
+            Some(GenericLibfuncId::new_inline(concat!($type_name, "_wide_mul")))
+        );
+    };
+    ($traits_name:ident, $rust_type:ident, $type_name:literal, $unsigned_type_name:literal) => {
+        define_sint_traits!(
+            @impl $traits_name, $rust_type, $type_name, $unsigned_type_name, None, None
+        );
+    };
+    (@impl $traits_name:ident, $rust_type:ident, $type_name:literal,
+     $unsigned_type_name:literal, $wide_type_id:expr, $wide_mul_id:expr) => {
+        #[derive(Default)]
+        pub struct $traits_name {}
+        impl SintTraits for $traits_name {
+            type SintType = $rust_type;
+            const BITS: u32 = $rust_type::BITS;
+            const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline($type_name);
+            const UNSIGNED_TYPE_ID: GenericTypeId = GenericTypeId::new_inline($unsigned_type_name);
+            const WIDE_MUL_RES_TYPE_ID: Option<GenericTypeId> = $wide_type_id;
+            const CONST: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_const"));
+            const OVERFLOWING_ADD: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_overflow_add"));
+            const OVERFLOWING_SUB: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_overflow_sub"));
+            const WIDE_MUL: Option<GenericLibfuncId> = $wide_mul_id;
+            const DIFF: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_diff"));
+            const EQUAL: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_eq"));
+            const TRY_FROM_FELT: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_try_from_felt"));
+            const TO_FELT: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_to_felt"));
+        }
+    };
+}
+
+define_sint_traits!(Sint8Traits, i8, "i8", "u8", "i16");
+define_sint_traits!(Sint16Traits, i16, "i16", "u16", "i32");
+define_sint_traits!(Sint32Traits, i32, "i32", "u32", "i64");
+define_sint_traits!(Sint64Traits, i64, "i64", "u64", "i128");
+define_sint_traits!(Sint128Traits, i128, "i128", "u128");
+
+/// Type for a signed integer.
+#[derive(Default)]
+pub struct SintType<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericType for SintType<TSintTraits> {
+    const ID: GenericTypeId = TSintTraits::GENERIC_TYPE_ID;
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = true;
+    const DROPPABLE: bool = true;
+    const SIZE: i16 = 1;
+}
+pub type Sint8Type = SintType<Sint8Traits>;
+pub type Sint16Type = SintType<Sint16Traits>;
+pub type Sint32Type = SintType<Sint32Traits>;
+pub type Sint64Type = SintType<Sint64Traits>;
+pub type Sint128Type = SintType<Sint128Traits>;
+
+define_libfunc_hierarchy! {
+    pub enum SintLibfunc<TSintTraits: SintTraits> {
+        Operation(SintOperationLibfunc<TSintTraits>),
+        Diff(SintDiffLibfunc<TSintTraits>),
+        Equal(SintEqualLibfunc<TSintTraits>),
+        Const(SintConstLibfunc<TSintTraits>),
+        FromFelt(SintFromFeltLibfunc<TSintTraits>),
+        ToFelt(SintToFeltLibfunc<TSintTraits>),
+    }, SintConcrete
+}
+pub type Sint8Libfunc = SintLibfunc<Sint8Traits>;
+pub type Sint16Libfunc = SintLibfunc<Sint16Traits>;
+pub type Sint32Libfunc = SintLibfunc<Sint32Traits>;
+pub type Sint64Libfunc = SintLibfunc<Sint64Traits>;
+pub type Sint128Libfunc = SintLibfunc<Sint128Traits>;
+
+/// Returns the signature of the range check param of the signed integer libfuncs.
+fn range_check_param_signature(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ParamSignature, SpecializationError> {
+    Ok(ParamSignature {
+        ty: context.get_concrete_type(RangeCheckType::id(), &[])?,
+        allow_deferred: false,
+        allow_add_const: true,
+        allow_const: false,
+    })
+}
+
+/// Returns the output info of the range check of the signed integer libfuncs.
+fn range_check_output_info(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<OutputVarInfo, SpecializationError> {
+    Ok(OutputVarInfo {
+        ty: context.get_concrete_type(RangeCheckType::id(), &[])?,
+        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst { param_idx: 0 }),
+    })
+}
+
+/// Libfunc for signed integer operations.
+pub struct SintOperationLibfunc<TSintTraits: SintTraits> {
+    pub operator: IntOperator,
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> SintOperationLibfunc<TSintTraits> {
+    fn new(operator: IntOperator) -> Self {
+        Self { operator, _phantom: PhantomData::default() }
+    }
+}
+impl<TSintTraits: SintTraits> GenericLibfunc for SintOperationLibfunc<TSintTraits> {
+    type Concrete = SintOperationConcreteLibfunc;
+
+    fn by_id(id: &GenericLibfuncId) -> Option<Self> {
+        match id {
+            id if id == &TSintTraits::OVERFLOWING_ADD => {
+                Some(Self::new(IntOperator::OverflowingAdd))
+            }
+            id if id == &TSintTraits::OVERFLOWING_SUB => {
+                Some(Self::new(IntOperator::OverflowingSub))
+            }
+            id if Some(id) == TSintTraits::WIDE_MUL.as_ref() => {
+                Some(Self::new(IntOperator::WideMul))
+            }
+            _ => None,
+        }
+    }
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        if !args.is_empty() {
+            return Err(SpecializationError::WrongNumberOfGenericArgs);
+        }
+        let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+        match self.operator {
+            // The product of two values is always in the range of the wider type, so no range
+            // check is required.
+            IntOperator::WideMul => Ok(LibfuncSignature::new_non_branch(
+                vec![ty.clone(), ty],
+                vec![OutputVarInfo {
+                    ty: context.get_concrete_type(
+                        TSintTraits::WIDE_MUL_RES_TYPE_ID
+                            .ok_or(SpecializationError::UnsupportedGenericArg)?,
+                        &[],
+                    )?,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                }],
+                SierraApChange::Known { new_vars_only: true },
+            )),
+            // The branches are the cases where the result is in range, underflows or overflows.
+            // In the last two cases the result is wrapped into the range of the type.
+            IntOperator::OverflowingAdd | IntOperator::OverflowingSub => Ok(LibfuncSignature {
+                param_signatures: vec![
+                    range_check_param_signature(context)?,
+                    ParamSignature::new(ty.clone()),
+                    ParamSignature::new(ty.clone()),
+                ],
+                branch_signatures: (0..3)
+                    .map(|_| {
+                        Ok(BranchSignature {
+                            vars: vec![
+                                range_check_output_info(context)?,
+                                OutputVarInfo {
+                                    ty: ty.clone(),
+                                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+                                },
+                            ],
+                            ap_change: SierraApChange::Known { new_vars_only: false },
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+                fallthrough: Some(0),
+            }),
+            IntOperator::OverflowingMul | IntOperator::DivMod => {
+                Err(SpecializationError::UnsupportedId)
+            }
+        }
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        Ok(SintOperationConcreteLibfunc {
+            operator: self.operator,
+            signature: self.specialize_signature(context.upcast(), args)?,
+        })
+    }
+}
+
+pub struct SintOperationConcreteLibfunc {
+    pub operator: IntOperator,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for SintOperationConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for computing the difference of two signed integers, as the unsigned integer of the
+/// same size. The first branch is the case `a >= b`, returning `a - b`, and the second is the
+/// case `a < b`, returning the wrapped value `a - b + 2**BITS`.
+#[derive(Default)]
+pub struct SintDiffLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintDiffLibfunc<TSintTraits> {
+    const ID: GenericLibfuncId = TSintTraits::DIFF;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+        let unsigned_ty = context.get_concrete_type(TSintTraits::UNSIGNED_TYPE_ID, &[])?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                range_check_param_signature(context)?,
+                ParamSignature::new(ty.clone()),
+                ParamSignature::new(ty),
+            ],
+            branch_signatures: (0..2)
+                .map(|_| {
+                    Ok(BranchSignature {
+                        vars: vec![
+                            range_check_output_info(context)?,
+                            OutputVarInfo {
+                                ty: unsigned_ty.clone(),
+                                ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+                            },
+                        ],
+                        ap_change: SierraApChange::Known { new_vars_only: false },
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for creating a constant signed integer.
+#[derive(Default)]
+pub struct SintConstLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NamedLibfunc for SintConstLibfunc<TSintTraits> {
+    type Concrete = SintConstConcreteLibfunc;
+    const ID: GenericLibfuncId = TSintTraits::CONST;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        _args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Const),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        match args {
+            [GenericArg::Value(c)] => Ok(SintConstConcreteLibfunc {
+                c: i128::try_from(c)
+                    .ok()
+                    .and_then(|c| TSintTraits::SintType::try_from(c).ok())
+                    .ok_or(SpecializationError::UnsupportedGenericArg)?
+                    .into(),
+                signature: <Self as NamedLibfunc>::specialize_signature(
+                    self,
+                    context.upcast(),
+                    args,
+                )?,
+            }),
+            _ => Err(SpecializationError::UnsupportedGenericArg),
+        }
+    }
+}
+
+pub struct SintConstConcreteLibfunc {
+    pub c: i128,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for SintConstConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for comparing signed integers' equality.
+#[derive(Default)]
+pub struct SintEqualLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintEqualLibfunc<TSintTraits> {
+    const ID: GenericLibfuncId = TSintTraits::EQUAL;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+        let branch_signatures = (0..2)
+            .map(|_| BranchSignature {
+                vars: vec![],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            })
+            .collect();
+        let param_signature_a = ParamSignature {
+            ty: ty.clone(),
+            allow_deferred: false,
+            allow_add_const: false,
+            allow_const: true,
+        };
+        let param_signature_b = ParamSignature { ty, ..param_signature_a };
+        Ok(LibfuncSignature {
+            param_signatures: vec![param_signature_a, param_signature_b],
+            branch_signatures,
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for converting a felt into a signed integer, if it is in range.
+#[derive(Default)]
+pub struct SintFromFeltLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintFromFeltLibfunc<TSintTraits> {
+    const ID: GenericLibfuncId = TSintTraits::TRY_FROM_FELT;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                range_check_param_signature(context)?,
+                ParamSignature::new(context.get_concrete_type(FeltType::id(), &[])?),
+            ],
+            branch_signatures: vec![
+                BranchSignature {
+                    vars: vec![
+                        range_check_output_info(context)?,
+                        OutputVarInfo {
+                            ty: context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?,
+                            ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                BranchSignature {
+                    vars: vec![range_check_output_info(context)?],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for converting a signed integer into a felt.
+#[derive(Default)]
+pub struct SintToFeltLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintToFeltLibfunc<TSintTraits> {
+    const ID: GenericLibfuncId = TSintTraits::TO_FELT;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(FeltType::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
@@ -7,6 +7,7 @@ use crate::extensions::lib_func::{
     BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
     SierraApChange, SignatureSpecializationContext,
 };
+use crate::extensions::range_check::RangeCheckType;
 use crate::extensions::{
     NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
     SpecializationError,
@@ -35,6 +36,81 @@ impl ConstGenLibfunc for ContractAddressConstLibfuncWrapped {
 
 pub type ContractAddressConstLibfunc = WrapConstGenLibfunc<ContractAddressConstLibfuncWrapped>;
 
+/// Libfunc for attempting to convert a felt into a contract address.
+#[derive(Default)]
+pub struct ContractAddressTryFromFeltLibfunc {}
+impl NoGenericArgsGenericLibfunc for ContractAddressTryFromFeltLibfunc {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("contract_address_try_from_felt");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_ty = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature {
+                    ty: range_check_ty.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(context.get_concrete_type(FeltType::id(), &[])?),
+            ],
+            branch_signatures: vec![
+                // Success branch.
+                BranchSignature {
+                    vars: vec![
+                        OutputVarInfo {
+                            ty: range_check_ty.clone(),
+                            ref_info: OutputVarReferenceInfo::Deferred(
+                                DeferredOutputKind::AddConst { param_idx: 0 },
+                            ),
+                        },
+                        OutputVarInfo {
+                            ty: context.get_concrete_type(ContractAddressType::id(), &[])?,
+                            ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Failure branch.
+                BranchSignature {
+                    vars: vec![OutputVarInfo {
+                        ty: range_check_ty,
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                            param_idx: 0,
+                        }),
+                    }],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for converting a contract address into a felt.
+#[derive(Default)]
+pub struct ContractAddressToFeltLibfunc {}
+impl NoGenericArgsGenericLibfunc for ContractAddressToFeltLibfunc {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("contract_address_to_felt");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![context.get_concrete_type(ContractAddressType::id(), &[])?],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(FeltType::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
 /// Libfunc for a storage call contract system call.
 #[derive(Default)]
 pub struct CallContractLibfunc {}
@@ -2,18 +2,31 @@ use crate::{define_libfunc_hierarchy, define_type_hierarchy};
 
 pub mod storage;
 use storage::{
-    StorageAddressConstLibfunc, StorageAddressType, StorageReadLibfunc, StorageWriteLibfunc,
+    StorageAddressConstLibfunc, StorageAddressFromBaseAndOffsetLibfunc,
+    StorageAddressFromBaseLibfunc, StorageAddressType, StorageBaseAddressConstLibfunc,
+    StorageBaseAddressFromFeltLibfunc, StorageBaseAddressType, StorageReadLibfunc,
+    StorageWriteLibfunc,
 };
 
 pub mod syscalls;
-use syscalls::SystemType;
+use syscalls::{
+    GetBlockNumberLibfunc, GetBlockTimestampLibfunc, GetCallerAddressLibfunc,
+    GetContractAddressLibfunc, GetTxInfoLibfunc, SystemType,
+};
+
+pub mod emit_event;
+use emit_event::EmitEventLibfunc;
 
 pub mod interoperability;
-use interoperability::{CallContractLibfunc, ContractAddressConstLibfunc, ContractAddressType};
+use interoperability::{
+    CallContractLibfunc, ContractAddressConstLibfunc, ContractAddressToFeltLibfunc,
+    ContractAddressTryFromFeltLibfunc, ContractAddressType,
+};
 
 define_type_hierarchy! {
     pub enum StarkNetType {
         ContractAddress(ContractAddressType),
+        StorageBaseAddress(StorageBaseAddressType),
         StorageAddress(StorageAddressType),
         System(SystemType),
     }, StarkNetTypeConcrete
@@ -23,8 +36,20 @@ define_libfunc_hierarchy! {
     pub enum StarkNetLibfunc {
          CallContract(CallContractLibfunc),
          ContractAddressConst(ContractAddressConstLibfunc),
+         ContractAddressTryFromFelt(ContractAddressTryFromFeltLibfunc),
+         ContractAddressToFelt(ContractAddressToFeltLibfunc),
          StorageRead(StorageReadLibfunc),
          StorageWrite(StorageWriteLibfunc),
          StorageAddressConst(StorageAddressConstLibfunc),
+         StorageBaseAddressConst(StorageBaseAddressConstLibfunc),
+         StorageBaseAddressFromFelt(StorageBaseAddressFromFeltLibfunc),
+         StorageAddressFromBase(StorageAddressFromBaseLibfunc),
+         StorageAddressFromBaseAndOffset(StorageAddressFromBaseAndOffsetLibfunc),
+         EmitEvent(EmitEventLibfunc),
+         GetCallerAddress(GetCallerAddressLibfunc),
+         GetContractAddress(GetContractAddressLibfunc),
+         GetBlockNumber(GetBlockNumberLibfunc),
+         GetBlockTimestamp(GetBlockTimestampLibfunc),
+         GetTxInfo(GetTxInfoLibfunc),
     }, StarkNetConcreteLibfunc
 }
@@ -6,6 +6,8 @@ use crate::extensions::lib_func::{
     BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
     SierraApChange, SignatureSpecializationContext,
 };
+use crate::extensions::range_check::RangeCheckType;
+use crate::extensions::uint::Uint8Type;
 use crate::extensions::{
     NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
     SpecializationError,
@@ -33,6 +35,125 @@ impl ConstGenLibfunc for StorageAddressConstLibfuncWrapped {
 
 pub type StorageAddressConstLibfunc = WrapConstGenLibfunc<StorageAddressConstLibfuncWrapped>;
 
+/// Type for StarkNet storage base address, a value in the range [0, 2 ** 251 - 256).
+/// Storage variables spanning several storage addresses are stored at consecutive addresses,
+/// starting at a base address.
+#[derive(Default)]
+pub struct StorageBaseAddressType {}
+impl NoGenericArgsGenericType for StorageBaseAddressType {
+    const ID: GenericTypeId = GenericTypeId::new_inline("StorageBaseAddress");
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = true;
+    const DROPPABLE: bool = true;
+    const SIZE: i16 = 1;
+}
+
+/// Libfunc for creating a constant storage base address.
+#[derive(Default)]
+pub struct StorageBaseAddressConstLibfuncWrapped {}
+impl ConstGenLibfunc for StorageBaseAddressConstLibfuncWrapped {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("storage_base_address_const");
+    const GENERIC_TYPE_ID: GenericTypeId = <StorageBaseAddressType as NoGenericArgsGenericType>::ID;
+}
+
+pub type StorageBaseAddressConstLibfunc =
+    WrapConstGenLibfunc<StorageBaseAddressConstLibfuncWrapped>;
+
+/// Libfunc for converting a felt into a storage base address, by reducing it modulo the base
+/// address bound. Used for addresses computed at runtime, such as the addresses of mapping entries.
+#[derive(Default)]
+pub struct StorageBaseAddressFromFeltLibfunc {}
+impl NoGenericArgsGenericLibfunc for StorageBaseAddressFromFeltLibfunc {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("storage_base_address_from_felt");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let range_check_ty = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature {
+                    ty: range_check_ty.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(context.get_concrete_type(FeltType::id(), &[])?),
+            ],
+            vec![
+                OutputVarInfo {
+                    ty: range_check_ty,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                        param_idx: 0,
+                    }),
+                },
+                OutputVarInfo {
+                    ty: context.get_concrete_type(StorageBaseAddressType::id(), &[])?,
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: None },
+                },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
+
+/// Libfunc for converting a base address into a storage address.
+#[derive(Default)]
+pub struct StorageAddressFromBaseLibfunc {}
+impl NoGenericArgsGenericLibfunc for StorageAddressFromBaseLibfunc {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("storage_address_from_base");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature {
+                ty: context.get_concrete_type(StorageBaseAddressType::id(), &[])?,
+                allow_deferred: true,
+                allow_add_const: true,
+                allow_const: true,
+            }],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(StorageAddressType::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for converting a base address and an offset into a storage address.
+#[derive(Default)]
+pub struct StorageAddressFromBaseAndOffsetLibfunc {}
+impl NoGenericArgsGenericLibfunc for StorageAddressFromBaseAndOffsetLibfunc {
+    const ID: GenericLibfuncId =
+        GenericLibfuncId::new_inline("storage_address_from_base_and_offset");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature::new(context.get_concrete_type(StorageBaseAddressType::id(), &[])?),
+                ParamSignature {
+                    ty: context.get_concrete_type(Uint8Type::id(), &[])?,
+                    allow_deferred: false,
+                    allow_add_const: false,
+                    allow_const: true,
+                },
+            ],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(StorageAddressType::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
 /// Libfunc for a storage read system call.
 #[derive(Default)]
 pub struct StorageReadLibfunc {}
@@ -1,5 +1,19 @@
-use crate::extensions::NoGenericArgsGenericType;
-use crate::ids::GenericTypeId;
+use super::interoperability::ContractAddressType;
+use crate::extensions::array::ArrayType;
+use crate::extensions::felt::FeltType;
+use crate::extensions::gas::GasBuiltinType;
+use crate::extensions::lib_func::{
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext,
+};
+use crate::extensions::uint::Uint64Type;
+use crate::extensions::uint128::Uint128Type;
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
+    SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, GenericLibfuncId, GenericTypeId};
+use crate::program::GenericArg;
 
 /// Type for StarkNet system object.
 /// Used to make system calls.
@@ -12,3 +26,191 @@ impl NoGenericArgsGenericType for SystemType {
     const DROPPABLE: bool = false;
     const SIZE: i16 = 1;
 }
+
+/// Trait for implementing a library function for a system call that takes no arguments besides
+/// the gas builtin and the system, and returns values from the execution info on success.
+pub trait ExecutionInfoSyscallGenericLibfunc: Default {
+    /// The library function id.
+    const ID: GenericLibfuncId;
+    /// The types of the values returned on success.
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError>;
+}
+
+/// Wrapper to prevent implementation collisions for `NoGenericArgsGenericLibfunc`.
+#[derive(Default)]
+pub struct WrapExecutionInfoSyscallGenericLibfunc<T: ExecutionInfoSyscallGenericLibfunc>(T);
+
+impl<T: ExecutionInfoSyscallGenericLibfunc> NoGenericArgsGenericLibfunc
+    for WrapExecutionInfoSyscallGenericLibfunc<T>
+{
+    const ID: GenericLibfuncId = <T as ExecutionInfoSyscallGenericLibfunc>::ID;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let gas_builtin_ty = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let system_ty = context.get_concrete_type(SystemType::id(), &[])?;
+        let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+
+        let mut success_vars = vec![
+            // Gas builtin
+            OutputVarInfo {
+                ty: gas_builtin_ty.clone(),
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            },
+            // System
+            OutputVarInfo {
+                ty: system_ty.clone(),
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                    param_idx: 1,
+                }),
+            },
+        ];
+        success_vars.extend(
+            <T as ExecutionInfoSyscallGenericLibfunc>::success_output_tys(context)?
+                .into_iter()
+                .map(|ty| OutputVarInfo {
+                    ty,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                }),
+        );
+
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                // Gas builtin
+                ParamSignature::new(gas_builtin_ty.clone()),
+                // System
+                ParamSignature {
+                    ty: system_ty.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+            ],
+            branch_signatures: vec![
+                // Success branch.
+                BranchSignature {
+                    vars: success_vars,
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Failure branch.
+                BranchSignature {
+                    vars: vec![
+                        // Gas builtin
+                        OutputVarInfo {
+                            ty: gas_builtin_ty,
+                            ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                        },
+                        // System
+                        OutputVarInfo {
+                            ty: system_ty,
+                            ref_info: OutputVarReferenceInfo::Deferred(
+                                DeferredOutputKind::AddConst { param_idx: 1 },
+                            ),
+                        },
+                        // Revert reason
+                        OutputVarInfo {
+                            ty: felt_ty,
+                            ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for a get caller address system call.
+#[derive(Default)]
+pub struct GetCallerAddressLibfuncWrapped {}
+impl ExecutionInfoSyscallGenericLibfunc for GetCallerAddressLibfuncWrapped {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("get_caller_address_syscall");
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(ContractAddressType::id(), &[])?])
+    }
+}
+pub type GetCallerAddressLibfunc =
+    WrapExecutionInfoSyscallGenericLibfunc<GetCallerAddressLibfuncWrapped>;
+
+/// Libfunc for a get contract address system call.
+#[derive(Default)]
+pub struct GetContractAddressLibfuncWrapped {}
+impl ExecutionInfoSyscallGenericLibfunc for GetContractAddressLibfuncWrapped {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("get_contract_address_syscall");
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(ContractAddressType::id(), &[])?])
+    }
+}
+pub type GetContractAddressLibfunc =
+    WrapExecutionInfoSyscallGenericLibfunc<GetContractAddressLibfuncWrapped>;
+
+/// Libfunc for a get block number system call.
+#[derive(Default)]
+pub struct GetBlockNumberLibfuncWrapped {}
+impl ExecutionInfoSyscallGenericLibfunc for GetBlockNumberLibfuncWrapped {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("get_block_number_syscall");
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(Uint64Type::id(), &[])?])
+    }
+}
+pub type GetBlockNumberLibfunc =
+    WrapExecutionInfoSyscallGenericLibfunc<GetBlockNumberLibfuncWrapped>;
+
+/// Libfunc for a get block timestamp system call.
+#[derive(Default)]
+pub struct GetBlockTimestampLibfuncWrapped {}
+impl ExecutionInfoSyscallGenericLibfunc for GetBlockTimestampLibfuncWrapped {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("get_block_timestamp_syscall");
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        Ok(vec![context.get_concrete_type(Uint64Type::id(), &[])?])
+    }
+}
+pub type GetBlockTimestampLibfunc =
+    WrapExecutionInfoSyscallGenericLibfunc<GetBlockTimestampLibfuncWrapped>;
+
+/// Libfunc for a get transaction info system call.
+/// Returns the account contract address, the max fee, the signature, the transaction hash, the
+/// chain id and the nonce of the current transaction.
+#[derive(Default)]
+pub struct GetTxInfoLibfuncWrapped {}
+impl ExecutionInfoSyscallGenericLibfunc for GetTxInfoLibfuncWrapped {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("get_tx_info_syscall");
+
+    fn success_output_tys(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<Vec<ConcreteTypeId>, SpecializationError> {
+        let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+        Ok(vec![
+            // Account contract address
+            context.get_concrete_type(ContractAddressType::id(), &[])?,
+            // Max fee
+            context.get_concrete_type(Uint128Type::id(), &[])?,
+            // Signature
+            context.get_concrete_type(ArrayType::id(), &[GenericArg::Type(felt_ty.clone())])?,
+            // Transaction hash
+            felt_ty.clone(),
+            // Chain id
+            felt_ty.clone(),
+            // Nonce
+            felt_ty,
+        ])
+    }
+}
+pub type GetTxInfoLibfunc = WrapExecutionInfoSyscallGenericLibfunc<GetTxInfoLibfuncWrapped>;
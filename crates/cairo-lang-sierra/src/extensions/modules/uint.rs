@@ -0,0 +1,498 @@
+use std::marker::PhantomData;
+
+use super::felt::FeltType;
+use super::jump_not_zero::{JumpNotZeroLibfunc, JumpNotZeroTraits};
+use super::non_zero::NonZeroType;
+use super::range_check::RangeCheckType;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::{
+    GenericLibfunc, NamedLibfunc, NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType,
+    OutputVarReferenceInfo, SignatureBasedConcreteLibfunc, SpecializationError,
+};
+use crate::ids::{GenericLibfuncId, GenericTypeId};
+use crate::program::GenericArg;
+
+/// Trait for implementing the library functions of an unsigned integer type smaller than u128.
+pub trait UintTraits: JumpNotZeroTraits {
+    /// The rust type matching this type.
+    type UintType: TryFrom<u128> + Into<u128> + Copy;
+    /// The number of bits in the type.
+    const BITS: u32;
+    /// The id of the generic type holding the result of a wide multiplication of this type.
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId;
+    /// The const library function id.
+    const CONST: GenericLibfuncId;
+    /// The overflowing add library function id.
+    const OVERFLOWING_ADD: GenericLibfuncId;
+    /// The overflowing sub library function id.
+    const OVERFLOWING_SUB: GenericLibfuncId;
+    /// The wide mul library function id.
+    const WIDE_MUL: GenericLibfuncId;
+    /// The divmod library function id.
+    const DIVMOD: GenericLibfuncId;
+    /// The less than library function id.
+    const LESS_THAN: GenericLibfuncId;
+    /// The equal library function id.
+    const EQUAL: GenericLibfuncId;
+    /// The less than or equal library function id.
+    const LESS_THAN_OR_EQUAL: GenericLibfuncId;
+    /// The try from felt library function id.
+    const TRY_FROM_FELT: GenericLibfuncId;
+    /// The to felt library function id.
+    const TO_FELT: GenericLibfuncId;
+}
+
+/// Defines the traits struct of an unsigned integer type.
+macro_rules! define_uint_traits {
+    ($traits_name:ident, $rust_type:ident, $type_name:literal, $wide_type_name:literal) => {
+        #[derive(Default)]
+        pub struct $traits_name {}
+        impl JumpNotZeroTraits for $traits_name {
+            const JUMP_NOT_ZERO: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_jump_nz"));
+            const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline($type_name);
+        }
+        impl UintTraits for $traits_name {
+            type UintType = $rust_type;
+            const BITS: u32 = $rust_type::BITS;
+            const WIDE_MUL_RES_TYPE_ID: GenericTypeId = GenericTypeId::new_inline($wide_type_name);
+            const CONST: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_const"));
+            const OVERFLOWING_ADD: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_overflow_add"));
+            const OVERFLOWING_SUB: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_overflow_sub"));
+            const WIDE_MUL: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_wide_mul"));
+            const DIVMOD: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_safe_divmod"));
+            const LESS_THAN: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_lt"));
+            const EQUAL: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_eq"));
+            const LESS_THAN_OR_EQUAL: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_le"));
+            const TRY_FROM_FELT: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_try_from_felt"));
+            const TO_FELT: GenericLibfuncId =
+                GenericLibfuncId::new_inline(concat!($type_name, "_to_felt"));
+        }
+    };
+}
+
+define_uint_traits!(Uint8Traits, u8, "u8", "u16");
+define_uint_traits!(Uint16Traits, u16, "u16", "u32");
+define_uint_traits!(Uint32Traits, u32, "u32", "u64");
+define_uint_traits!(Uint64Traits, u64, "u64", "u128");
+
+/// Type for an unsigned integer smaller than u128.
+#[derive(Default)]
+pub struct UintType<TUintTraits: UintTraits> {
+    _phantom: PhantomData<TUintTraits>,
+}
+impl<TUintTraits: UintTraits> NoGenericArgsGenericType for UintType<TUintTraits> {
+    const ID: GenericTypeId = TUintTraits::GENERIC_TYPE_ID;
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = true;
+    const DROPPABLE: bool = true;
+    const SIZE: i16 = 1;
+}
+pub type Uint8Type = UintType<Uint8Traits>;
+pub type Uint16Type = UintType<Uint16Traits>;
+pub type Uint32Type = UintType<Uint32Traits>;
+pub type Uint64Type = UintType<Uint64Traits>;
+
+define_libfunc_hierarchy! {
+    pub enum UintLibfunc<TUintTraits: UintTraits> {
+        Operation(UintOperationLibfunc<TUintTraits>),
+        LessThan(UintLessThanLibfunc<TUintTraits>),
+        Equal(UintEqualLibfunc<TUintTraits>),
+        LessThanOrEqual(UintLessThanOrEqualLibfunc<TUintTraits>),
+        Const(UintConstLibfunc<TUintTraits>),
+        FromFelt(UintFromFeltLibfunc<TUintTraits>),
+        ToFelt(UintToFeltLibfunc<TUintTraits>),
+        JumpNotZero(JumpNotZeroLibfunc<TUintTraits>),
+    }, UintConcrete
+}
+pub type Uint8Libfunc = UintLibfunc<Uint8Traits>;
+pub type Uint16Libfunc = UintLibfunc<Uint16Traits>;
+pub type Uint32Libfunc = UintLibfunc<Uint32Traits>;
+pub type Uint64Libfunc = UintLibfunc<Uint64Traits>;
+
+/// Returns the signature of the range check param of the unsigned integer libfuncs.
+fn range_check_param_signature(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ParamSignature, SpecializationError> {
+    Ok(ParamSignature {
+        ty: context.get_concrete_type(RangeCheckType::id(), &[])?,
+        allow_deferred: false,
+        allow_add_const: true,
+        allow_const: false,
+    })
+}
+
+/// Returns the output info of the range check of the unsigned integer libfuncs.
+fn range_check_output_info(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<OutputVarInfo, SpecializationError> {
+    Ok(OutputVarInfo {
+        ty: context.get_concrete_type(RangeCheckType::id(), &[])?,
+        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst { param_idx: 0 }),
+    })
+}
+
+/// Operators for unsigned integers smaller than u128.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UintOperator {
+    OverflowingAdd,
+    OverflowingSub,
+    DivMod,
+    WideMul,
+}
+
+/// Libfunc for unsigned integer operations.
+pub struct UintOperationLibfunc<TUintTraits: UintTraits> {
+    pub operator: UintOperator,
+    _phantom: PhantomData<TUintTraits>,
+}
+impl<TUintTraits: UintTraits> UintOperationLibfunc<TUintTraits> {
+    fn new(operator: UintOperator) -> Self {
+        Self { operator, _phantom: PhantomData::default() }
+    }
+}
+impl<TUintTraits: UintTraits> GenericLibfunc for UintOperationLibfunc<TUintTraits> {
+    type Concrete = UintOperationConcreteLibfunc;
+
+    fn by_id(id: &GenericLibfuncId) -> Option<Self> {
+        match id {
+            id if id == &TUintTraits::OVERFLOWING_ADD => {
+                Some(Self::new(UintOperator::OverflowingAdd))
+            }
+            id if id == &TUintTraits::OVERFLOWING_SUB => {
+                Some(Self::new(UintOperator::OverflowingSub))
+            }
+            id if id == &TUintTraits::DIVMOD => Some(Self::new(UintOperator::DivMod)),
+            id if id == &TUintTraits::WIDE_MUL => Some(Self::new(UintOperator::WideMul)),
+            _ => None,
+        }
+    }
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        if !args.is_empty() {
+            return Err(SpecializationError::WrongNumberOfGenericArgs);
+        }
+        let ty = context.get_concrete_type(TUintTraits::GENERIC_TYPE_ID, &[])?;
+        match self.operator {
+            UintOperator::DivMod => Ok(LibfuncSignature::new_non_branch_ex(
+                vec![
+                    range_check_param_signature(context)?,
+                    ParamSignature::new(ty.clone()),
+                    ParamSignature::new(
+                        context.get_wrapped_concrete_type(NonZeroType::id(), ty.clone())?,
+                    ),
+                ],
+                vec![
+                    range_check_output_info(context)?,
+                    OutputVarInfo {
+                        ty: ty.clone(),
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+                    },
+                    OutputVarInfo {
+                        ty,
+                        ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(1) },
+                    },
+                ],
+                SierraApChange::Known { new_vars_only: false },
+            )),
+            // The product of two values is always smaller than the limit of the wider type, so no
+            // range check is required.
+            UintOperator::WideMul => Ok(LibfuncSignature::new_non_branch(
+                vec![ty.clone(), ty],
+                vec![OutputVarInfo {
+                    ty: context.get_concrete_type(TUintTraits::WIDE_MUL_RES_TYPE_ID, &[])?,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                }],
+                SierraApChange::Known { new_vars_only: true },
+            )),
+            UintOperator::OverflowingAdd | UintOperator::OverflowingSub => Ok(LibfuncSignature {
+                param_signatures: vec![
+                    range_check_param_signature(context)?,
+                    ParamSignature::new(ty.clone()),
+                    ParamSignature::new(ty.clone()),
+                ],
+                branch_signatures: vec![
+                    BranchSignature {
+                        vars: vec![
+                            range_check_output_info(context)?,
+                            OutputVarInfo {
+                                ty: ty.clone(),
+                                ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+                            },
+                        ],
+                        ap_change: SierraApChange::Known { new_vars_only: false },
+                    },
+                    BranchSignature {
+                        vars: vec![
+                            range_check_output_info(context)?,
+                            OutputVarInfo {
+                                ty,
+                                ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+                            },
+                        ],
+                        ap_change: SierraApChange::Known { new_vars_only: false },
+                    },
+                ],
+                fallthrough: Some(0),
+            }),
+        }
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        Ok(UintOperationConcreteLibfunc {
+            operator: self.operator,
+            signature: self.specialize_signature(context.upcast(), args)?,
+        })
+    }
+}
+
+pub struct UintOperationConcreteLibfunc {
+    pub operator: UintOperator,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for UintOperationConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for creating a constant unsigned integer.
+#[derive(Default)]
+pub struct UintConstLibfunc<TUintTraits: UintTraits> {
+    _phantom: PhantomData<TUintTraits>,
+}
+impl<TUintTraits: UintTraits> NamedLibfunc for UintConstLibfunc<TUintTraits> {
+    type Concrete = UintConstConcreteLibfunc;
+    const ID: GenericLibfuncId = TUintTraits::CONST;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        _args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(TUintTraits::GENERIC_TYPE_ID, &[])?,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Const),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        match args {
+            [GenericArg::Value(c)] => Ok(UintConstConcreteLibfunc {
+                c: u128::try_from(c)
+                    .ok()
+                    .and_then(|c| TUintTraits::UintType::try_from(c).ok())
+                    .ok_or(SpecializationError::UnsupportedGenericArg)?
+                    .into(),
+                signature: <Self as NamedLibfunc>::specialize_signature(
+                    self,
+                    context.upcast(),
+                    args,
+                )?,
+            }),
+            _ => Err(SpecializationError::UnsupportedGenericArg),
+        }
+    }
+}
+
+pub struct UintConstConcreteLibfunc {
+    pub c: u128,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for UintConstConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Utility method to output the two branches of unsigned integer comparison signatures.
+fn get_uint_comparison_branch_signatures(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<Vec<BranchSignature>, SpecializationError> {
+    (0..2)
+        .map(|_| {
+            Ok(BranchSignature {
+                vars: vec![range_check_output_info(context)?],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            })
+        })
+        .collect()
+}
+
+/// Utility method to output the parameter signatures of unsigned integer comparison signatures.
+fn get_uint_comparison_param_signatures<TUintTraits: UintTraits>(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<Vec<ParamSignature>, SpecializationError> {
+    let ty = context.get_concrete_type(TUintTraits::GENERIC_TYPE_ID, &[])?;
+    Ok(vec![
+        range_check_param_signature(context)?,
+        ParamSignature::new(ty.clone()),
+        ParamSignature::new(ty),
+    ])
+}
+
+/// Libfunc for comparing unsigned integers.
+#[derive(Default)]
+pub struct UintLessThanLibfunc<TUintTraits: UintTraits> {
+    _phantom: PhantomData<TUintTraits>,
+}
+impl<TUintTraits: UintTraits> NoGenericArgsGenericLibfunc for UintLessThanLibfunc<TUintTraits> {
+    const ID: GenericLibfuncId = TUintTraits::LESS_THAN;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature {
+            param_signatures: get_uint_comparison_param_signatures::<TUintTraits>(context)?,
+            branch_signatures: get_uint_comparison_branch_signatures(context)?,
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for comparing unsigned integers' equality.
+#[derive(Default)]
+pub struct UintEqualLibfunc<TUintTraits: UintTraits> {
+    _phantom: PhantomData<TUintTraits>,
+}
+impl<TUintTraits: UintTraits> NoGenericArgsGenericLibfunc for UintEqualLibfunc<TUintTraits> {
+    const ID: GenericLibfuncId = TUintTraits::EQUAL;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(TUintTraits::GENERIC_TYPE_ID, &[])?;
+        let branch_signatures = (0..2)
+            .map(|_| BranchSignature {
+                vars: vec![],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            })
+            .collect();
+        let param_signature_a = ParamSignature {
+            ty: ty.clone(),
+            allow_deferred: false,
+            allow_add_const: false,
+            allow_const: true,
+        };
+        let param_signature_b = ParamSignature { ty, ..param_signature_a };
+        Ok(LibfuncSignature {
+            param_signatures: vec![param_signature_a, param_signature_b],
+            branch_signatures,
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for comparing unsigned integers.
+#[derive(Default)]
+pub struct UintLessThanOrEqualLibfunc<TUintTraits: UintTraits> {
+    _phantom: PhantomData<TUintTraits>,
+}
+impl<TUintTraits: UintTraits> NoGenericArgsGenericLibfunc
+    for UintLessThanOrEqualLibfunc<TUintTraits>
+{
+    const ID: GenericLibfuncId = TUintTraits::LESS_THAN_OR_EQUAL;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature {
+            param_signatures: get_uint_comparison_param_signatures::<TUintTraits>(context)?,
+            branch_signatures: get_uint_comparison_branch_signatures(context)?,
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for converting a felt into an unsigned integer, if it is in range.
+#[derive(Default)]
+pub struct UintFromFeltLibfunc<TUintTraits: UintTraits> {
+    _phantom: PhantomData<TUintTraits>,
+}
+impl<TUintTraits: UintTraits> NoGenericArgsGenericLibfunc for UintFromFeltLibfunc<TUintTraits> {
+    const ID: GenericLibfuncId = TUintTraits::TRY_FROM_FELT;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                range_check_param_signature(context)?,
+                ParamSignature::new(context.get_concrete_type(FeltType::id(), &[])?),
+            ],
+            branch_signatures: vec![
+                BranchSignature {
+                    vars: vec![
+                        range_check_output_info(context)?,
+                        OutputVarInfo {
+                            ty: context.get_concrete_type(TUintTraits::GENERIC_TYPE_ID, &[])?,
+                            ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 1 },
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                BranchSignature {
+                    vars: vec![range_check_output_info(context)?],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for converting an unsigned integer into a felt.
+#[derive(Default)]
+pub struct UintToFeltLibfunc<TUintTraits: UintTraits> {
+    _phantom: PhantomData<TUintTraits>,
+}
+impl<TUintTraits: UintTraits> NoGenericArgsGenericLibfunc for UintToFeltLibfunc<TUintTraits> {
+    const ID: GenericLibfuncId = TUintTraits::TO_FELT;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![context.get_concrete_type(TUintTraits::GENERIC_TYPE_ID, &[])?],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(FeltType::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
@@ -28,6 +28,7 @@ impl NoGenericArgsGenericType for Uint128Type {
 define_libfunc_hierarchy! {
     pub enum Uint128Libfunc {
         Operation(Uint128OperationLibfunc),
+        Shift(Uint128ShiftLibfunc),
         LessThan(Uint128LessThanLibfunc),
         Equal(Uint128EqualLibfunc),
         LessThanOrEqual(Uint128LessThanOrEqualLibfunc),
@@ -227,6 +228,107 @@ impl SignatureBasedConcreteLibfunc for Uint128OperationConcreteLibfunc {
     }
 }
 
+/// Shift operators for u128.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShiftOperator {
+    Shl,
+    Shr,
+}
+
+/// Libfunc for u128 shifts.
+/// Returns the shifted value if the shift amount is smaller than 128, and branches otherwise.
+pub struct Uint128ShiftLibfunc {
+    pub operator: ShiftOperator,
+}
+impl Uint128ShiftLibfunc {
+    fn new(operator: ShiftOperator) -> Self {
+        Self { operator }
+    }
+}
+impl GenericLibfunc for Uint128ShiftLibfunc {
+    type Concrete = Uint128ShiftConcreteLibfunc;
+
+    fn by_id(id: &GenericLibfuncId) -> Option<Self> {
+        const SHL: GenericLibfuncId = GenericLibfuncId::new_inline("u128_shl");
+        const SHR: GenericLibfuncId = GenericLibfuncId::new_inline("u128_shr");
+        match id {
+            id if id == &SHL => Some(Self::new(ShiftOperator::Shl)),
+            id if id == &SHR => Some(Self::new(ShiftOperator::Shr)),
+            _ => None,
+        }
+    }
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        if !args.is_empty() {
+            return Err(SpecializationError::WrongNumberOfGenericArgs);
+        }
+        let (ty, range_check_type) = get_u128_comparison_types(context)?;
+        // For `u128_shr` the result is not at the top of the stack, as the remainder is allocated
+        // after it.
+        let result_idx = match self.operator {
+            ShiftOperator::Shl => Some(0),
+            ShiftOperator::Shr => None,
+        };
+        Ok(LibfuncSignature {
+            param_signatures: get_u128_comparison_param_signatures(context)?,
+            branch_signatures: vec![
+                // The shift amount is smaller than 128.
+                BranchSignature {
+                    vars: vec![
+                        OutputVarInfo {
+                            ty: range_check_type.clone(),
+                            ref_info: OutputVarReferenceInfo::Deferred(
+                                DeferredOutputKind::AddConst { param_idx: 0 },
+                            ),
+                        },
+                        OutputVarInfo {
+                            ty,
+                            ref_info: OutputVarReferenceInfo::NewTempVar { idx: result_idx },
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // The shift amount is at least 128.
+                BranchSignature {
+                    vars: vec![OutputVarInfo {
+                        ty: range_check_type,
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                            param_idx: 0,
+                        }),
+                    }],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        Ok(Uint128ShiftConcreteLibfunc {
+            operator: self.operator,
+            signature: self.specialize_signature(context.upcast(), args)?,
+        })
+    }
+}
+
+pub struct Uint128ShiftConcreteLibfunc {
+    pub operator: ShiftOperator,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for Uint128ShiftConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
 /// Libfunc for creating a constant u128.
 #[derive(Default)]
 pub struct Uint128ConstLibfunc {}
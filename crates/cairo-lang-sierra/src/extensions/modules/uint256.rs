@@ -0,0 +1,113 @@
+use super::non_zero::NonZeroType;
+use super::range_check::RangeCheckType;
+use super::strct::StructType;
+use super::uint128::Uint128Type;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::lib_func::{
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo, SpecializationError,
+};
+use crate::ids::{ConcreteTypeId, GenericLibfuncId, UserTypeId};
+use crate::program::GenericArg;
+
+define_libfunc_hierarchy! {
+    pub enum Uint256Libfunc {
+        JumpNotZero(Uint256JumpNotZeroLibfunc),
+        DivMod(Uint256DivModLibfunc),
+    }, Uint256Concrete
+}
+
+/// Helper for u256 type def.
+fn get_u256_type(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    let u128_ty = context.get_concrete_type(Uint128Type::id(), &[])?;
+    context.get_concrete_type(
+        StructType::id(),
+        &[
+            GenericArg::UserType(UserTypeId::from_string("core::integer::u256")),
+            GenericArg::Type(u128_ty.clone()),
+            GenericArg::Type(u128_ty),
+        ],
+    )
+}
+
+/// Libfunc for jump non-zero on a u256 value, and returning a non-zero wrapped value in case of
+/// success.
+#[derive(Default)]
+pub struct Uint256JumpNotZeroLibfunc {}
+impl NoGenericArgsGenericLibfunc for Uint256JumpNotZeroLibfunc {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("u256_jump_nz");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = get_u256_type(context)?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![ParamSignature::new(ty.clone())],
+            branch_signatures: vec![
+                // Zero.
+                BranchSignature {
+                    vars: vec![],
+                    ap_change: SierraApChange::Known { new_vars_only: true },
+                },
+                // NonZero.
+                BranchSignature {
+                    vars: vec![OutputVarInfo {
+                        ty: context.get_wrapped_concrete_type(NonZeroType::id(), ty)?,
+                        ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+                    }],
+                    ap_change: SierraApChange::Known { new_vars_only: true },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for u256 division with remainder.
+#[derive(Default)]
+pub struct Uint256DivModLibfunc {}
+impl NoGenericArgsGenericLibfunc for Uint256DivModLibfunc {
+    const ID: GenericLibfuncId = GenericLibfuncId::new_inline("u256_safe_divmod");
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = get_u256_type(context)?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![
+                ParamSignature {
+                    ty: range_check_type.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                ParamSignature::new(ty.clone()),
+                ParamSignature::new(
+                    context.get_wrapped_concrete_type(NonZeroType::id(), ty.clone())?,
+                ),
+            ],
+            vec![
+                OutputVarInfo {
+                    ty: range_check_type,
+                    ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                        param_idx: 0,
+                    }),
+                },
+                OutputVarInfo {
+                    ty: ty.clone(),
+                    ref_info: OutputVarReferenceInfo::NewTempVar { idx: None },
+                },
+                OutputVarInfo { ty, ref_info: OutputVarReferenceInfo::NewTempVar { idx: None } },
+            ],
+            SierraApChange::Known { new_vars_only: false },
+        ))
+    }
+}
@@ -141,6 +141,10 @@ impl SpecializationContext for MockSpecializationContext {
 #[test_case("Array", vec![value_arg(5)] => Err(UnsupportedGenericArg); "Array<5>")]
 #[test_case("Array", vec![type_arg("UninitializedFelt")] => Err(UnsupportedGenericArg);
             "Array<UninitializedFelt>")]
+#[test_case("ArraySlice", vec![type_arg("u128")] => Ok(()); "ArraySlice<u128>")]
+#[test_case("ArraySlice", vec![] => Err(WrongNumberOfGenericArgs); "ArraySlice")]
+#[test_case("ArraySlice", vec![type_arg("ArrayU128")] => Err(UnsupportedGenericArg);
+            "ArraySlice<ArrayU128>")]
 #[test_case("NonZero", vec![type_arg("T")] => Ok(()); "NonZero<T>")]
 #[test_case("NonZero", vec![] => Err(WrongNumberOfGenericArgs); "NonZero")]
 #[test_case("NonZero", vec![value_arg(5)] => Err(UnsupportedGenericArg); "NonZero<5>")]
@@ -199,6 +203,11 @@ fn find_type_specialization(
 #[test_case("array_at", vec![type_arg("u128")] => Ok(()); "array_at<u128>")]
 #[test_case("array_len", vec![] => Err(WrongNumberOfGenericArgs); "array_len")]
 #[test_case("array_len", vec![type_arg("u128")] => Ok(()); "array_len<u128>")]
+#[test_case("array_slice", vec![] => Err(WrongNumberOfGenericArgs); "array_slice")]
+#[test_case("array_slice", vec![type_arg("u128")] => Ok(()); "array_slice<u128>")]
+#[test_case("array_slice_pop_front", vec![type_arg("u128")] => Ok(());
+            "array_slice_pop_front<u128>")]
+#[test_case("array_slice_len", vec![type_arg("u128")] => Ok(()); "array_slice_len<u128>")]
 #[test_case("get_gas", vec![value_arg(0)] => Err(WrongNumberOfGenericArgs); "get_gas<0>")]
 #[test_case("get_gas", vec![] => Ok(()); "get_gas")]
 #[test_case("refund_gas", vec![value_arg(0)] => Err(WrongNumberOfGenericArgs); "refund_gas<0>")]
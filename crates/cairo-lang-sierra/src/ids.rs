@@ -29,8 +29,12 @@ macro_rules! define_identity {
                 Self::new(id.try_into().unwrap())
             }
 
+            /// Creates an id from a name known at compile time. Names longer than the inline
+            /// capacity of [SmolStr] are not kept as debug names.
             pub const fn new_inline(name: &'static str) -> Self {
-                Self { id: id_from_string(name), debug_name: Some(SmolStr::new_inline(name)) }
+                let debug_name =
+                    if name.len() <= 22 { Some(SmolStr::new_inline(name)) } else { None };
+                Self { id: id_from_string(name), debug_name }
             }
 
             pub fn from_string(name: impl Into<SmolStr>) -> Self {
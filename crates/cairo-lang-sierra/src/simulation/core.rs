@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 
-use cairo_lang_utils::extract_matches;
+use cairo_lang_utils::{extract_matches, try_extract_matches};
 use num_bigint::{BigInt, ToBigInt};
-use num_traits::{ToPrimitive, Zero};
+use num_traits::{Signed, ToPrimitive, Zero};
 
 use super::value::CoreValue;
 use super::LibfuncSimulationError;
@@ -11,7 +11,8 @@ use crate::extensions::array::ArrayConcreteLibfunc;
 use crate::extensions::boolean::BoolConcreteLibfunc;
 use crate::extensions::core::CoreConcreteLibfunc::{
     self, ApTracking, Array, Bitwise, Bool, BranchAlign, Drop, Dup, Ec, Enum, Felt, FunctionCall,
-    Gas, Mem, Struct, Uint128, UnconditionalJump, UnwrapNonZero,
+    Gas, Mem, Sint128, Sint16, Sint32, Sint64, Sint8, Struct, Uint128, Uint16, Uint256, Uint32,
+    Uint64, Uint8, UnconditionalJump, UnwrapNonZero,
 };
 use crate::extensions::dict_felt_to::DictFeltToConcreteLibfunc;
 use crate::extensions::ec::EcConcreteLibfunc::{CreatePoint, UnwrapPoint};
@@ -25,10 +26,20 @@ use crate::extensions::gas::GasConcreteLibfunc::{GetGas, RefundGas};
 use crate::extensions::mem::MemConcreteLibfunc::{
     AlignTemps, AllocLocal, FinalizeLocals, Rename, StoreLocal, StoreTemp,
 };
+use crate::extensions::sint::{
+    Sint128Traits, Sint16Traits, Sint32Traits, Sint64Traits, Sint8Traits, SintConcrete,
+    SintConstConcreteLibfunc, SintOperationConcreteLibfunc, SintTraits,
+};
 use crate::extensions::strct::StructConcreteLibfunc;
+use crate::extensions::uint::{
+    Uint16Traits, Uint32Traits, Uint64Traits, Uint8Traits, UintConcrete, UintConstConcreteLibfunc,
+    UintOperationConcreteLibfunc, UintOperator, UintTraits,
+};
 use crate::extensions::uint128::{
-    IntOperator, Uint128Concrete, Uint128ConstConcreteLibfunc, Uint128OperationConcreteLibfunc,
+    IntOperator, ShiftOperator, Uint128Concrete, Uint128ConstConcreteLibfunc,
+    Uint128OperationConcreteLibfunc, Uint128ShiftConcreteLibfunc,
 };
+use crate::extensions::uint256::Uint256Concrete;
 use crate::ids::FunctionId;
 
 // TODO(orizi): This def is duplicated.
@@ -143,20 +154,23 @@ pub fn simulate<
             [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        Array(ArrayConcreteLibfunc::PopFront(_)) => match &inputs[..] {
-            [CoreValue::Array(_)] => {
-                let mut iter = inputs.into_iter();
-                let mut arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
-                if arr.is_empty() {
-                    Ok((vec![CoreValue::Array(arr)], 1))
-                } else {
-                    let front = arr.remove(0);
-                    Ok((vec![CoreValue::Array(arr), front], 0))
+        // Slices are simulated as the arrays holding their elements.
+        Array(ArrayConcreteLibfunc::PopFront(_) | ArrayConcreteLibfunc::SlicePopFront(_)) => {
+            match &inputs[..] {
+                [CoreValue::Array(_)] => {
+                    let mut iter = inputs.into_iter();
+                    let mut arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
+                    if arr.is_empty() {
+                        Ok((vec![CoreValue::Array(arr)], 1))
+                    } else {
+                        let front = arr.remove(0);
+                        Ok((vec![CoreValue::Array(arr), front], 0))
+                    }
                 }
+                [_] => Err(LibfuncSimulationError::WrongArgType),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
             }
-            [_] => Err(LibfuncSimulationError::WrongArgType),
-            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-        },
+        }
         Array(ArrayConcreteLibfunc::At(_)) => match &inputs[..] {
             [CoreValue::RangeCheck, CoreValue::Array(_), CoreValue::Uint128(_)] => {
                 let mut iter = inputs.into_iter();
@@ -173,16 +187,59 @@ pub fn simulate<
             [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        Array(ArrayConcreteLibfunc::Len(_)) => match &inputs[..] {
-            [CoreValue::Array(_)] => {
-                let arr = extract_matches!(inputs.into_iter().next().unwrap(), CoreValue::Array);
-                let len = arr.len();
-                Ok((vec![CoreValue::Array(arr), CoreValue::Uint128(len as u128)], 0))
+        Array(ArrayConcreteLibfunc::Len(_) | ArrayConcreteLibfunc::SliceLen(_)) => {
+            match &inputs[..] {
+                [CoreValue::Array(_)] => {
+                    let arr =
+                        extract_matches!(inputs.into_iter().next().unwrap(), CoreValue::Array);
+                    let len = arr.len();
+                    Ok((vec![CoreValue::Array(arr), CoreValue::Uint128(len as u128)], 0))
+                }
+                [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
             }
-            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        }
+        Array(ArrayConcreteLibfunc::Slice(_)) => match &inputs[..] {
+            [
+                CoreValue::RangeCheck,
+                CoreValue::Array(_),
+                CoreValue::Uint128(_),
+                CoreValue::Uint128(_),
+            ] => {
+                let mut iter = inputs.into_iter();
+                iter.next(); // Ignore range check.
+                let arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
+                let start = extract_matches!(iter.next().unwrap(), CoreValue::Uint128);
+                let length = extract_matches!(iter.next().unwrap(), CoreValue::Uint128);
+                match start.checked_add(length) {
+                    Some(end) if end <= arr.len() as u128 => {
+                        let slice = arr[start as usize..end as usize].to_vec();
+                        Ok((
+                            vec![
+                                CoreValue::RangeCheck,
+                                CoreValue::Array(arr),
+                                CoreValue::Array(slice),
+                            ],
+                            0,
+                        ))
+                    }
+                    _ => Ok((vec![CoreValue::RangeCheck, CoreValue::Array(arr)], 1)),
+                }
+            }
+            [_, _, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Uint8(libfunc) => simulate_uint_libfunc(libfunc, &inputs),
+        Uint16(libfunc) => simulate_uint_libfunc(libfunc, &inputs),
+        Uint32(libfunc) => simulate_uint_libfunc(libfunc, &inputs),
+        Uint64(libfunc) => simulate_uint_libfunc(libfunc, &inputs),
         Uint128(libfunc) => simulate_integer_libfunc(libfunc, &inputs),
+        Uint256(libfunc) => simulate_u256_libfunc(libfunc, &inputs),
+        Sint8(libfunc) => simulate_sint_libfunc(libfunc, &inputs),
+        Sint16(libfunc) => simulate_sint_libfunc(libfunc, &inputs),
+        Sint32(libfunc) => simulate_sint_libfunc(libfunc, &inputs),
+        Sint64(libfunc) => simulate_sint_libfunc(libfunc, &inputs),
+        Sint128(libfunc) => simulate_sint_libfunc(libfunc, &inputs),
         Bool(libfunc) => simulate_bool_libfunc(libfunc, &inputs),
         Felt(libfunc) => simulate_felt_libfunc(libfunc, &inputs),
         UnwrapNonZero(_) => match &inputs[..] {
@@ -441,6 +498,22 @@ fn simulate_integer_libfunc(
                 _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
             }
         }
+        Uint128Concrete::Shift(Uint128ShiftConcreteLibfunc { operator, .. }) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Uint128(a), CoreValue::Uint128(b)] => {
+                if *b < 128 {
+                    let value = match operator {
+                        ShiftOperator::Shl => a << b,
+                        ShiftOperator::Shr => a >> b,
+                    };
+                    Ok((vec![CoreValue::RangeCheck, CoreValue::Uint128(value)], 0))
+                } else {
+                    // The shift amount is too large - jumping to the failure branch.
+                    Ok((vec![CoreValue::RangeCheck], 1))
+                }
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint128Concrete::JumpNotZero(_) => {
             match inputs {
                 [CoreValue::Uint128(value)] if *value == 0 => {
@@ -486,6 +559,362 @@ fn simulate_integer_libfunc(
     }
 }
 
+/// Returns the value of a simulated u256, given as a struct of its low and high words.
+fn try_unwrap_u256(value: &CoreValue) -> Option<BigInt> {
+    match value {
+        CoreValue::Struct(members) => match &members[..] {
+            [CoreValue::Uint128(low), CoreValue::Uint128(high)] => {
+                Some((BigInt::from(*high) << 128) + BigInt::from(*low))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the simulated representation of a u256 value.
+fn wrap_u256(value: BigInt) -> CoreValue {
+    let u128_limit = BigInt::from(u128::MAX) + BigInt::from(1);
+    CoreValue::Struct(vec![
+        CoreValue::Uint128((&value % &u128_limit).to_u128().unwrap()),
+        CoreValue::Uint128((value / u128_limit).to_u128().unwrap()),
+    ])
+}
+
+/// Simulate u256 library functions.
+fn simulate_u256_libfunc(
+    libfunc: &Uint256Concrete,
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        Uint256Concrete::JumpNotZero(_) => match inputs {
+            [value] => match try_unwrap_u256(value) {
+                Some(x) if x.is_zero() => Ok((vec![], 0)),
+                Some(_) => Ok((vec![CoreValue::NonZero(Box::new(value.clone()))], 1)),
+                None => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            },
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Uint256Concrete::DivMod(_) => match inputs {
+            [CoreValue::RangeCheck, lhs, CoreValue::NonZero(rhs)] => {
+                match (try_unwrap_u256(lhs), try_unwrap_u256(rhs)) {
+                    (Some(lhs), Some(rhs)) => Ok((
+                        vec![CoreValue::RangeCheck, wrap_u256(&lhs / &rhs), wrap_u256(lhs % rhs)],
+                        0,
+                    )),
+                    _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                }
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+    }
+}
+
+/// Conversions between the values of an unsigned integer type and their simulated
+/// representation.
+trait UintValue: UintTraits {
+    /// Wraps a value of the type.
+    fn wrap(value: Self::UintType) -> CoreValue;
+    /// Wraps the result of a wide multiplication of values of the type.
+    fn wrap_wide(value: u128) -> CoreValue;
+    /// Unwraps a value of the type, if it is of the type.
+    fn unwrap(value: &CoreValue) -> Option<Self::UintType>;
+}
+
+/// Implements [UintValue] for the traits of an unsigned integer type.
+macro_rules! impl_uint_value {
+    ($traits_name:ident, $variant:ident, $wide_variant:ident) => {
+        impl UintValue for $traits_name {
+            fn wrap(value: Self::UintType) -> CoreValue {
+                CoreValue::$variant(value)
+            }
+            fn wrap_wide(value: u128) -> CoreValue {
+                CoreValue::$wide_variant(value.try_into().unwrap())
+            }
+            fn unwrap(value: &CoreValue) -> Option<Self::UintType> {
+                try_extract_matches!(value, CoreValue::$variant).copied()
+            }
+        }
+    };
+}
+impl_uint_value!(Uint8Traits, Uint8, Uint16);
+impl_uint_value!(Uint16Traits, Uint16, Uint32);
+impl_uint_value!(Uint32Traits, Uint32, Uint64);
+impl_uint_value!(Uint64Traits, Uint64, Uint128);
+
+/// Simulate unsigned integer library functions, for the types smaller than u128.
+fn simulate_uint_libfunc<TUintTraits: UintValue>(
+    libfunc: &UintConcrete<TUintTraits>,
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let wrap = |value: u128| TUintTraits::wrap(value.try_into().ok().unwrap());
+    match libfunc {
+        UintConcrete::Const(UintConstConcreteLibfunc { c, .. }) => {
+            if inputs.is_empty() {
+                Ok((vec![wrap(*c)], 0))
+            } else {
+                Err(LibfuncSimulationError::WrongNumberOfArgs)
+            }
+        }
+        UintConcrete::FromFelt(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Felt(value)] => {
+                Ok(match u128::try_from(value).ok().and_then(|value| value.try_into().ok()) {
+                    Some(value) => (vec![CoreValue::RangeCheck, TUintTraits::wrap(value)], 0),
+                    None => (vec![CoreValue::RangeCheck], 1),
+                })
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        UintConcrete::ToFelt(_) => match inputs {
+            [value] => match TUintTraits::unwrap(value) {
+                Some(value) => Ok((vec![CoreValue::Felt(value.into().into())], 0)),
+                None => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            },
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        UintConcrete::Operation(UintOperationConcreteLibfunc { operator, .. }) => {
+            let limit = 1u128 << TUintTraits::BITS;
+            match (inputs, operator) {
+                ([CoreValue::RangeCheck, lhs, CoreValue::NonZero(rhs)], UintOperator::DivMod) => {
+                    let (Some(lhs), Some(rhs)) =
+                        (TUintTraits::unwrap(lhs), TUintTraits::unwrap(rhs))
+                    else {
+                        return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                    };
+                    let (lhs, rhs): (u128, u128) = (lhs.into(), rhs.into());
+                    Ok((vec![CoreValue::RangeCheck, wrap(lhs / rhs), wrap(lhs % rhs)], 0))
+                }
+                ([lhs, rhs], UintOperator::WideMul) => {
+                    let (Some(lhs), Some(rhs)) =
+                        (TUintTraits::unwrap(lhs), TUintTraits::unwrap(rhs))
+                    else {
+                        return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                    };
+                    Ok((vec![TUintTraits::wrap_wide(lhs.into() * rhs.into())], 0))
+                }
+                (
+                    [CoreValue::RangeCheck, lhs, rhs],
+                    UintOperator::OverflowingAdd | UintOperator::OverflowingSub,
+                ) => {
+                    let (Some(lhs), Some(rhs)) =
+                        (TUintTraits::unwrap(lhs), TUintTraits::unwrap(rhs))
+                    else {
+                        return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                    };
+                    let (lhs, rhs): (u128, u128) = (lhs.into(), rhs.into());
+                    let (value, overflow) = match operator {
+                        UintOperator::OverflowingAdd => {
+                            let sum = lhs + rhs;
+                            if sum < limit { (sum, false) } else { (sum - limit, true) }
+                        }
+                        UintOperator::OverflowingSub => {
+                            if lhs >= rhs {
+                                (lhs - rhs, false)
+                            } else {
+                                (lhs + limit - rhs, true)
+                            }
+                        }
+                        _ => unreachable!("Arm only handles these cases."),
+                    };
+                    Ok((vec![CoreValue::RangeCheck, wrap(value)], usize::from(overflow)))
+                }
+                ([_, _, _] | [_, _], _) => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            }
+        }
+        UintConcrete::JumpNotZero(_) => match inputs {
+            [value] => match TUintTraits::unwrap(value) {
+                // Zero - jumping to the failure branch.
+                Some(value) if value.into() == 0 => Ok((vec![], 0)),
+                // Non-zero - jumping to the success branch and providing a NonZero wrap to the
+                // given value.
+                Some(value) => {
+                    Ok((vec![CoreValue::NonZero(Box::new(TUintTraits::wrap(value)))], 1))
+                }
+                None => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            },
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        UintConcrete::LessThan(_) | UintConcrete::LessThanOrEqual(_) => match inputs {
+            [CoreValue::RangeCheck, a, b] => {
+                let (Some(a), Some(b)) = (TUintTraits::unwrap(a), TUintTraits::unwrap(b)) else {
+                    return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                };
+                let (a, b): (u128, u128) = (a.into(), b.into());
+                // "False" branch (branch 0) is the case the comparison does not hold.
+                // "True" branch (branch 1) is the case the comparison holds.
+                let holds =
+                    if matches!(libfunc, UintConcrete::LessThan(_)) { a < b } else { a <= b };
+                Ok((vec![CoreValue::RangeCheck], usize::from(holds)))
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        UintConcrete::Equal(_) => match inputs {
+            [a, b] => {
+                let (Some(a), Some(b)) = (TUintTraits::unwrap(a), TUintTraits::unwrap(b)) else {
+                    return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                };
+                // "False" branch (branch 0) is the case a != b.
+                // "True" branch (branch 1) is the case a == b.
+                Ok((vec![], usize::from(a.into() == b.into())))
+            }
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+    }
+}
+
+/// Conversions between the values of a signed integer type and their simulated representation.
+trait SintValue: SintTraits {
+    /// Wraps a value of the type.
+    fn wrap(value: i128) -> CoreValue;
+    /// Wraps the result of a wide multiplication of values of the type.
+    fn wrap_wide(value: i128) -> CoreValue;
+    /// Wraps a value of the unsigned integer type of the same size.
+    fn wrap_unsigned(value: u128) -> CoreValue;
+    /// Unwraps a value of the type, if it is of the type.
+    fn unwrap(value: &CoreValue) -> Option<i128>;
+}
+
+/// Implements [SintValue] for the traits of a signed integer type.
+macro_rules! impl_sint_value {
+    ($traits_name:ident, $variant:ident, $wide_variant:ident, $unsigned_variant:ident) => {
+        impl SintValue for $traits_name {
+            fn wrap(value: i128) -> CoreValue {
+                CoreValue::$variant(value.try_into().unwrap())
+            }
+            fn wrap_wide(value: i128) -> CoreValue {
+                CoreValue::$wide_variant(value.try_into().unwrap())
+            }
+            fn wrap_unsigned(value: u128) -> CoreValue {
+                CoreValue::$unsigned_variant(value.try_into().unwrap())
+            }
+            fn unwrap(value: &CoreValue) -> Option<i128> {
+                try_extract_matches!(value, CoreValue::$variant).map(|value| (*value).into())
+            }
+        }
+    };
+}
+impl_sint_value!(Sint8Traits, Sint8, Sint16, Uint8);
+impl_sint_value!(Sint16Traits, Sint16, Sint32, Uint16);
+impl_sint_value!(Sint32Traits, Sint32, Sint64, Uint32);
+impl_sint_value!(Sint64Traits, Sint64, Sint128, Uint64);
+// i128 has no wider type, so it is never used for wide multiplication.
+impl_sint_value!(Sint128Traits, Sint128, Sint128, Uint128);
+
+/// Simulate signed integer library functions.
+fn simulate_sint_libfunc<TSintTraits: SintValue>(
+    libfunc: &SintConcrete<TSintTraits>,
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    // Using `BigInt` to avoid overflows of the intermediate values of i128 operations.
+    let limit = BigInt::from(1) << TSintTraits::BITS;
+    let max = (BigInt::from(1) << (TSintTraits::BITS - 1)) - 1;
+    let min = -(BigInt::from(1) << (TSintTraits::BITS - 1));
+    match libfunc {
+        SintConcrete::Const(SintConstConcreteLibfunc { c, .. }) => {
+            if inputs.is_empty() {
+                Ok((vec![TSintTraits::wrap(*c)], 0))
+            } else {
+                Err(LibfuncSimulationError::WrongNumberOfArgs)
+            }
+        }
+        SintConcrete::FromFelt(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Felt(value)] => {
+                Ok(if (&min..=&max).contains(&value) {
+                    (vec![CoreValue::RangeCheck, TSintTraits::wrap(value.try_into().unwrap())], 0)
+                } else {
+                    (vec![CoreValue::RangeCheck], 1)
+                })
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        SintConcrete::ToFelt(_) => match inputs {
+            [value] => match TSintTraits::unwrap(value) {
+                Some(value) => Ok((vec![CoreValue::Felt(value.into())], 0)),
+                None => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            },
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        SintConcrete::Operation(SintOperationConcreteLibfunc { operator, .. }) => {
+            match (inputs, operator) {
+                ([lhs, rhs], IntOperator::WideMul) => {
+                    let (Some(lhs), Some(rhs)) =
+                        (TSintTraits::unwrap(lhs), TSintTraits::unwrap(rhs))
+                    else {
+                        return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                    };
+                    Ok((vec![TSintTraits::wrap_wide(lhs * rhs)], 0))
+                }
+                (
+                    [CoreValue::RangeCheck, lhs, rhs],
+                    IntOperator::OverflowingAdd | IntOperator::OverflowingSub,
+                ) => {
+                    let (Some(lhs), Some(rhs)) =
+                        (TSintTraits::unwrap(lhs), TSintTraits::unwrap(rhs))
+                    else {
+                        return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                    };
+                    let value = match operator {
+                        IntOperator::OverflowingAdd => BigInt::from(lhs) + rhs,
+                        IntOperator::OverflowingSub => BigInt::from(lhs) - rhs,
+                        _ => unreachable!("Arm only handles these cases."),
+                    };
+                    // Branch 0 is the in range case, branch 1 is the underflow case and branch 2
+                    // is the overflow case.
+                    let (value, branch) = if value < min {
+                        (value + limit, 1)
+                    } else if value > max {
+                        (value - limit, 2)
+                    } else {
+                        (value, 0)
+                    };
+                    Ok((
+                        vec![CoreValue::RangeCheck, TSintTraits::wrap(value.try_into().unwrap())],
+                        branch,
+                    ))
+                }
+                ([_, _, _] | [_, _], _) => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            }
+        }
+        SintConcrete::Diff(_) => match inputs {
+            [CoreValue::RangeCheck, a, b] => {
+                let (Some(a), Some(b)) = (TSintTraits::unwrap(a), TSintTraits::unwrap(b)) else {
+                    return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                };
+                let diff = BigInt::from(a) - b;
+                // Branch 0 is the case `a >= b`, and branch 1 is the case `a < b`.
+                let (value, branch) =
+                    if diff.is_negative() { (diff + limit, 1) } else { (diff, 0) };
+                Ok((
+                    vec![
+                        CoreValue::RangeCheck,
+                        TSintTraits::wrap_unsigned(value.try_into().unwrap()),
+                    ],
+                    branch,
+                ))
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        SintConcrete::Equal(_) => match inputs {
+            [a, b] => {
+                let (Some(a), Some(b)) = (TSintTraits::unwrap(a), TSintTraits::unwrap(b)) else {
+                    return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                };
+                // "False" branch (branch 0) is the case a != b.
+                // "True" branch (branch 1) is the case a == b.
+                Ok((vec![], usize::from(a == b)))
+            }
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+    }
+}
+
 /// Simulate felt library functions.
 fn simulate_felt_libfunc(
     libfunc: &FeltConcrete,
@@ -3,7 +3,8 @@ use num_bigint::BigInt;
 use test_case::test_case;
 
 use super::value::CoreValue::{
-    self, Array, GasBuiltin, NonZero, RangeCheck, Uint128, Uninitialized,
+    self, Array, Felt, GasBuiltin, NonZero, RangeCheck, Sint16, Sint8, Uint128, Uint16, Uint8,
+    Uninitialized,
 };
 use super::LibfuncSimulationError::{
     self, FunctionSimulationError, MemoryLayoutMismatch, WrongNumberOfArgs,
@@ -155,6 +156,52 @@ fn simulate(
 #[test_case("u128_overflow_mul", vec![], vec![RangeCheck, Uint128(u128::MAX), Uint128(u128::MAX)]
              => Ok((vec![RangeCheck, Uint128(1)], 1));
             "u128_overflow_mul(-1, -1)")]
+#[test_case("u128_shl", vec![], vec![RangeCheck, Uint128(3), Uint128(127)]
+             => Ok((vec![RangeCheck, Uint128(1 << 127)], 0));
+            "u128_shl(3, 127)")]
+#[test_case("u128_shl", vec![], vec![RangeCheck, Uint128(3), Uint128(128)]
+             => Ok((vec![RangeCheck], 1));
+            "u128_shl(3, 128)")]
+#[test_case("u128_shr", vec![], vec![RangeCheck, Uint128(12), Uint128(2)]
+             => Ok((vec![RangeCheck, Uint128(3)], 0));
+            "u128_shr(12, 2)")]
+#[test_case("u8_overflow_add", vec![], vec![RangeCheck, Uint8(2), Uint8(3)]
+             => Ok((vec![RangeCheck, Uint8(5)], 0));
+            "u8_overflow_add(2, 3)")]
+#[test_case("u8_overflow_add", vec![], vec![RangeCheck, Uint8(200), Uint8(100)]
+             => Ok((vec![RangeCheck, Uint8(44)], 1));
+            "u8_overflow_add(200, 100)")]
+#[test_case("u8_overflow_sub", vec![], vec![RangeCheck, Uint8(3), Uint8(5)]
+             => Ok((vec![RangeCheck, Uint8(254)], 1));
+            "u8_overflow_sub(3, 5)")]
+#[test_case("u8_try_from_felt", vec![], vec![RangeCheck, Felt(BigInt::from(255))]
+             => Ok((vec![RangeCheck, Uint8(255)], 0));
+            "u8_try_from_felt(255)")]
+#[test_case("u8_try_from_felt", vec![], vec![RangeCheck, Felt(BigInt::from(256))]
+             => Ok((vec![RangeCheck], 1));
+            "u8_try_from_felt(256)")]
+#[test_case("u8_lt", vec![], vec![RangeCheck, Uint8(2), Uint8(3)] => Ok((vec![RangeCheck], 1));
+            "u8_lt(2, 3)")]
+#[test_case("u8_jump_nz", vec![], vec![Uint8(0)] => Ok((vec![], 0)); "u8_jump_nz(0)")]
+#[test_case("i8_overflow_add", vec![], vec![RangeCheck, Sint8(-2), Sint8(3)]
+             => Ok((vec![RangeCheck, Sint8(1)], 0));
+            "i8_overflow_add(-2, 3)")]
+#[test_case("i8_overflow_add", vec![], vec![RangeCheck, Sint8(-100), Sint8(-100)]
+             => Ok((vec![RangeCheck, Sint8(56)], 1));
+            "i8_overflow_add(-100, -100)")]
+#[test_case("i8_overflow_sub", vec![], vec![RangeCheck, Sint8(100), Sint8(-100)]
+             => Ok((vec![RangeCheck, Sint8(-56)], 2));
+            "i8_overflow_sub(100, -100)")]
+#[test_case("i8_diff", vec![], vec![RangeCheck, Sint8(-3), Sint8(5)]
+             => Ok((vec![RangeCheck, Uint8(248)], 1));
+            "i8_diff(-3, 5)")]
+#[test_case("i8_try_from_felt", vec![], vec![RangeCheck, Felt(BigInt::from(-128))]
+             => Ok((vec![RangeCheck, Sint8(-128)], 0));
+            "i8_try_from_felt(minus 128)")]
+#[test_case("i8_try_from_felt", vec![], vec![RangeCheck, Felt(BigInt::from(128))]
+             => Ok((vec![RangeCheck], 1));
+            "i8_try_from_felt(128)")]
+#[test_case("i8_eq", vec![], vec![Sint8(-1), Sint8(-1)] => Ok((vec![], 1)); "i8_eq(-1, -1)")]
 fn simulate_branch(
     id: &str,
     generic_args: Vec<GenericArg>,
@@ -172,8 +219,26 @@ fn simulate_branch(
              => Ok(vec![RangeCheck, Array(vec![Uint128(5)]), Uint128(5)]); "array_at([5], 0)")]
 #[test_case("array_len", vec![type_arg("u128")], vec![Array(vec![])] =>
             Ok(vec![Array(vec![]), Uint128(0)]); "array_len([])")]
+#[test_case("array_slice", vec![type_arg("u128")],
+            vec![RangeCheck, Array(vec![Uint128(5), Uint128(6), Uint128(7)]), Uint128(1), Uint128(2)]
+             => Ok(vec![
+                RangeCheck,
+                Array(vec![Uint128(5), Uint128(6), Uint128(7)]),
+                Array(vec![Uint128(6), Uint128(7)]),
+             ]); "array_slice([5, 6, 7], 1, 2)")]
+#[test_case("array_slice_len", vec![type_arg("u128")], vec![Array(vec![Uint128(5)])] =>
+            Ok(vec![Array(vec![Uint128(5)]), Uint128(1)]); "array_slice_len([5])")]
 #[test_case("u128_safe_divmod", vec![], vec![RangeCheck, Uint128(32), NonZero(Box::new(Uint128(5)))]
              => Ok(vec![RangeCheck, Uint128(6), Uint128(2)]); "u128_safe_divmod(32, 5)")]
+#[test_case("u8_safe_divmod", vec![], vec![RangeCheck, Uint8(32), NonZero(Box::new(Uint8(5)))]
+             => Ok(vec![RangeCheck, Uint8(6), Uint8(2)]); "u8_safe_divmod(32, 5)")]
+#[test_case("u8_wide_mul", vec![], vec![Uint8(200), Uint8(200)] => Ok(vec![Uint16(40000)]);
+            "u8_wide_mul(200, 200)")]
+#[test_case("u8_const", vec![value_arg(3)], vec![] => Ok(vec![Uint8(3)]); "u8_const<3>()")]
+#[test_case("i8_wide_mul", vec![], vec![Sint8(-128), Sint8(-128)] => Ok(vec![Sint16(16384)]);
+            "i8_wide_mul(-128, -128)")]
+#[test_case("i8_to_felt", vec![], vec![Sint8(-5)] => Ok(vec![Felt(BigInt::from(-5))]);
+            "i8_to_felt(-5)")]
 #[test_case("u128_const", vec![value_arg(3)], vec![] => Ok(vec![Uint128(3)]);
             "u128_const<3>()")]
 #[test_case("dup", vec![type_arg("u128")], vec![Uint128(24)]
@@ -10,7 +10,16 @@ pub enum CoreValue {
     Felt(BigInt),
     GasBuiltin(i64),
     RangeCheck,
+    Uint8(u8),
+    Uint16(u16),
+    Uint32(u32),
+    Uint64(u64),
     Uint128(u128),
+    Sint8(i8),
+    Sint16(i16),
+    Sint32(i32),
+    Sint64(i64),
+    Sint128(i128),
     NonZero(Box<CoreValue>),
     Ref(Box<CoreValue>),
     Array(Vec<CoreValue>),
@@ -7,7 +7,11 @@ use crate::program::{ConcreteTypeLongId, GenericArg};
 pub fn build_bijective_mapping() -> BiMap<ConcreteTypeId, ConcreteTypeLongId> {
     let mut elements = BiMap::new();
     elements.insert("T".into(), as_type_long_id("T", &[]));
+    elements.insert("u8".into(), as_type_long_id("u8", &[]));
+    elements.insert("u16".into(), as_type_long_id("u16", &[]));
     elements.insert("u128".into(), as_type_long_id("u128", &[]));
+    elements.insert("i8".into(), as_type_long_id("i8", &[]));
+    elements.insert("i16".into(), as_type_long_id("i16", &[]));
     elements.insert("felt".into(), as_type_long_id("felt", &[]));
     elements.insert("Tuple<>".into(), as_named_type_long_id("Struct", "Tuple", &[]));
     elements.insert(
@@ -16,9 +20,11 @@ pub fn build_bijective_mapping() -> BiMap<ConcreteTypeId, ConcreteTypeLongId> {
     );
     elements.insert("Option".into(), as_named_type_long_id("Enum", "Option", &["felt", "Tuple<>"]));
     elements.insert("NonZeroFelt".into(), as_type_long_id("NonZero", &["felt"]));
+    elements.insert("NonZeroU8".into(), as_type_long_id("NonZero", &["u8"]));
     elements.insert("NonZeroU128".into(), as_type_long_id("NonZero", &["u128"]));
     elements.insert("ArrayFelt".into(), as_type_long_id("Array", &["felt"]));
     elements.insert("ArrayU128".into(), as_type_long_id("Array", &["u128"]));
+    elements.insert("ArraySliceU128".into(), as_type_long_id("ArraySlice", &["u128"]));
     elements.insert("UninitializedFelt".into(), as_type_long_id("Uninitialized", &["felt"]));
     elements.insert("Uninitializedu128".into(), as_type_long_id("Uninitialized", &["u128"]));
     elements.insert("GasBuiltin".into(), as_type_long_id("GasBuiltin", &[]));
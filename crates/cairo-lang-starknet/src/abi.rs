@@ -3,6 +3,8 @@ use cairo_lang_semantic::db::SemanticGroup;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::plugin::{CONSTRUCTOR_ATTR, EVENT_ATTR, L1_HANDLER_ATTR};
+
 #[cfg(test)]
 #[path = "abi_test.rs"]
 mod test;
@@ -24,24 +26,37 @@ impl Contract {
         let mut contract = Self::default();
 
         for trait_function_id in db.trait_functions(trait_id).unwrap_or_default().values() {
-            contract.add_function(db, *trait_function_id)?;
+            let attributes = db
+                .trait_function_attributes(*trait_function_id)
+                .map_err(|_| ABIError::CompilationError)?;
+            let has_attr = |name: &str| attributes.iter().any(|attr| attr.id == name);
+            if has_attr(EVENT_ATTR) {
+                contract.add_event(db, *trait_function_id)?;
+            } else if has_attr(CONSTRUCTOR_ATTR) {
+                contract.add_function(db, *trait_function_id, Item::Constructor)?;
+            } else if has_attr(L1_HANDLER_ATTR) {
+                contract.add_function(db, *trait_function_id, Item::L1Handler)?;
+            } else {
+                contract.add_function(db, *trait_function_id, Item::Function)?;
+            }
         }
 
         Ok(contract)
     }
 
-    /// Adds a function to the ABI from a TraitFunctionId.
+    /// Adds a function to the ABI from a TraitFunctionId, as the kind of item given by `to_item`.
     fn add_function(
         &mut self,
         db: &dyn SemanticGroup,
         trait_function_id: TraitFunctionId,
+        to_item: fn(Function) -> Item,
     ) -> Result<(), ABIError> {
         let defs_db = db.upcast();
         let name = trait_function_id.name(defs_db).into();
         let signature = db
             .trait_function_signature(trait_function_id)
             .map_err(|_| ABIError::CompilationError)?;
-        self.items.push(Item::Function(Function {
+        self.items.push(to_item(Function {
             name,
             inputs: signature
                 .params
@@ -58,6 +73,32 @@ impl Contract {
         Ok(())
     }
 
+    /// Adds an event to the ABI from a TraitFunctionId.
+    fn add_event(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_function_id: TraitFunctionId,
+    ) -> Result<(), ABIError> {
+        let defs_db = db.upcast();
+        let name = trait_function_id.name(defs_db).into();
+        let signature = db
+            .trait_function_signature(trait_function_id)
+            .map_err(|_| ABIError::CompilationError)?;
+        self.items.push(Item::Event(Event {
+            name,
+            inputs: signature
+                .params
+                .into_iter()
+                .map(|param| Input {
+                    name: param.id.name(db.upcast()).into(),
+                    ty: param.ty.format(db),
+                })
+                .collect(),
+        }));
+
+        Ok(())
+    }
+
     pub fn json(&self) -> String {
         serde_json::to_string_pretty(&self).unwrap()
     }
@@ -77,9 +118,15 @@ pub enum ABIError {
 pub enum Item {
     #[serde(rename = "function")]
     Function(Function),
+    #[serde(rename = "constructor")]
+    Constructor(Function),
+    #[serde(rename = "l1_handler")]
+    L1Handler(Function),
+    #[serde(rename = "event")]
+    Event(Event),
 }
 
-/// Contract function ABI.
+/// Contract function ABI. Used for external functions, the constructor and L1 handlers.
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Function {
     pub name: String,
@@ -87,7 +134,14 @@ pub struct Function {
     pub output_ty: String,
 }
 
-/// Function input ABI.
+/// Contract event ABI.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Event {
+    pub name: String,
+    pub inputs: Vec<Input>,
+}
+
+/// Function or event input ABI.
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Input {
     pub name: String,
@@ -15,6 +15,15 @@ fn test_abi() {
         indoc! {"
             trait MyAbi {
                 fn foo(a: felt, b: u128) -> Option::<()>;
+
+                #[event]
+                fn foo_event(x: felt);
+
+                #[constructor]
+                fn constructor(x: felt);
+
+                #[l1_handler]
+                fn foo_l1_handler(from_address: felt) -> felt;
             }
         "},
     )
@@ -46,6 +55,38 @@ fn test_abi() {
                 }
               ],
               "output_ty": "core::option::Option::<()>"
+            },
+            {
+              "type": "event",
+              "name": "foo_event",
+              "inputs": [
+                {
+                  "name": "x",
+                  "ty": "core::felt"
+                }
+              ]
+            },
+            {
+              "type": "constructor",
+              "name": "constructor",
+              "inputs": [
+                {
+                  "name": "x",
+                  "ty": "core::felt"
+                }
+              ],
+              "output_ty": "()"
+            },
+            {
+              "type": "l1_handler",
+              "name": "foo_l1_handler",
+              "inputs": [
+                {
+                  "name": "from_address",
+                  "ty": "core::felt"
+                }
+              ],
+              "output_ty": "core::felt"
             }
           ]"#}
     );
@@ -6,6 +6,7 @@ use crate::test_utils::{get_example_file_path, get_test_contract};
 
 #[test_case("test_contract")]
 #[test_case("hello_starknet")]
+#[test_case("user_storage")]
 fn test_casm_contract_from_contract_class(example_file_name: &str) {
     let contract_class = get_test_contract(format!("{}.cairo", example_file_name).as_str());
     let casm_contract = CasmContractClass::from_contract_class(contract_class).unwrap();
@@ -8,7 +8,9 @@ use cairo_lang_semantic::db::SemanticGroup;
 use num_bigint::BigUint;
 use sha3::{Digest, Keccak256};
 
-use crate::plugin::{ABI_TRAIT, EXTERNAL_MODULE, GENERATED_CONTRACT_ATTR};
+use crate::plugin::{
+    ABI_TRAIT, CONSTRUCTOR_MODULE, EXTERNAL_MODULE, GENERATED_CONTRACT_ATTR, L1_HANDLER_MODULE,
+};
 
 #[cfg(test)]
 #[path = "contract_test.rs"]
@@ -64,6 +66,31 @@ pub fn find_contracts(db: &dyn SemanticGroup, crate_ids: &[CrateId]) -> Vec<Cont
 pub fn get_external_functions(
     db: &(dyn SemanticGroup + 'static),
     contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<FreeFunctionId>> {
+    get_submodule_functions(db, contract, EXTERNAL_MODULE)
+}
+
+/// Returns the list of L1 handler functions for a given contract.
+pub fn get_l1_handler_functions(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<FreeFunctionId>> {
+    get_submodule_functions(db, contract, L1_HANDLER_MODULE)
+}
+
+/// Returns the list of constructor functions for a given contract (at most one).
+pub fn get_constructor_functions(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<FreeFunctionId>> {
+    get_submodule_functions(db, contract, CONSTRUCTOR_MODULE)
+}
+
+/// Returns the list of functions in the given submodule of the generated contract module.
+fn get_submodule_functions(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+    submodule_name: &str,
 ) -> anyhow::Result<Vec<FreeFunctionId>> {
     let generated_module_id = get_generated_contract_module(db, contract)?;
     match db
@@ -71,13 +98,13 @@ pub fn get_external_functions(
         .to_option()
         .with_context(|| "Failed to get generated module items.")?
         .items
-        .get(EXTERNAL_MODULE)
+        .get(submodule_name)
     {
-        Some(ModuleItemId::Submodule(external_module_id)) => Ok(db
-            .module_free_functions_ids(ModuleId::Submodule(*external_module_id))
+        Some(ModuleItemId::Submodule(submodule_id)) => Ok(db
+            .module_free_functions_ids(ModuleId::Submodule(*submodule_id))
             .to_option()
-            .with_context(|| "Failed to get external module functions.")?),
-        _ => anyhow::bail!("Failed to get the external module."),
+            .with_context(|| format!("Failed to get {submodule_name} module functions."))?),
+        _ => anyhow::bail!("Failed to get the {submodule_name} module."),
     }
 }
 
@@ -12,13 +12,17 @@ use cairo_lang_sierra::{self};
 use cairo_lang_sierra_generator::canonical_id_replacer::CanonicalReplacer;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::{replace_sierra_ids_in_program, SierraIdReplacer};
+use itertools::chain;
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::abi::{self, Contract};
 use crate::casm_contract_class::{deserialize_big_uint, serialize_big_uint, BigIntAsHex};
-use crate::contract::{find_contracts, get_abi, get_external_functions, starknet_keccak};
+use crate::contract::{
+    find_contracts, get_abi, get_constructor_functions, get_external_functions,
+    get_l1_handler_functions, starknet_keccak,
+};
 use crate::db::get_starknet_database;
 use crate::felt_serde::sierra_to_felts;
 
@@ -83,8 +87,14 @@ pub fn compile_path(path: &Path, replace_ids: bool) -> anyhow::Result<ContractCl
     };
 
     let external_functions = get_external_functions(db, contract)?;
+    let l1_handler_functions = get_l1_handler_functions(db, contract)?;
+    let constructor_functions = get_constructor_functions(db, contract)?;
     let sierra_program = db
-        .get_sierra_program_for_functions(external_functions.clone())
+        .get_sierra_program_for_functions(
+            chain!(&external_functions, &l1_handler_functions, &constructor_functions)
+                .cloned()
+                .collect(),
+        )
         .to_option()
         .with_context(|| "Compilation failed without any diagnostics.")?;
 
@@ -95,7 +105,11 @@ pub fn compile_path(path: &Path, replace_ids: bool) -> anyhow::Result<ContractCl
         replacer.apply(&sierra_program)
     };
 
-    let entry_points_by_type = get_entry_points(db, &external_functions, &replacer)?;
+    let entry_points_by_type = ContractEntryPoints {
+        external: get_entry_points(db, &external_functions, &replacer)?,
+        l1_handler: get_entry_points(db, &l1_handler_functions, &replacer)?,
+        constructor: get_entry_points(db, &constructor_functions, &replacer)?,
+    };
     Ok(ContractClass {
         sierra_program: sierra_to_felts(&sierra_program)?,
         sierra_program_debug_info: cairo_lang_sierra::debug_info::DebugInfo::extract(
@@ -109,11 +123,11 @@ pub fn compile_path(path: &Path, replace_ids: bool) -> anyhow::Result<ContractCl
 /// Returns the entry points given their IDs.
 fn get_entry_points(
     db: &mut RootDatabase,
-    external_functions: &[FreeFunctionId],
+    entry_point_functions: &[FreeFunctionId],
     replacer: &CanonicalReplacer,
-) -> Result<ContractEntryPoints, anyhow::Error> {
-    let mut entry_points_by_type = ContractEntryPoints::default();
-    for free_func_id in external_functions {
+) -> Result<Vec<ContractEntryPoint>, anyhow::Error> {
+    let mut entry_points = vec![];
+    for free_func_id in entry_point_functions {
         let func_id = db.intern_function(FunctionLongId {
             function: ConcreteFunction {
                 generic_function: GenericFunctionId::Free(*free_func_id),
@@ -121,12 +135,14 @@ fn get_entry_points(
             },
         });
 
-        let sierra_id = db.intern_sierra_function(func_id);
+        let sierra_id = db.intern_sierra_function(
+            cairo_lang_lowering::ids::FunctionId::from_semantic(db, func_id),
+        );
 
-        entry_points_by_type.external.push(ContractEntryPoint {
+        entry_points.push(ContractEntryPoint {
             selector: starknet_keccak(free_func_id.name(db).as_bytes()),
             function_idx: replacer.replace_function_id(&sierra_id).id as usize,
         });
     }
-    Ok(entry_points_by_type)
+    Ok(entry_points)
 }
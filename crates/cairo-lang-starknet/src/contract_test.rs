@@ -4,7 +4,10 @@ use indoc::indoc;
 use itertools::Itertools;
 use pretty_assertions::assert_eq;
 
-use crate::contract::{find_contracts, get_external_functions, starknet_keccak};
+use crate::contract::{
+    find_contracts, get_constructor_functions, get_external_functions, get_l1_handler_functions,
+    starknet_keccak,
+};
 
 #[test]
 fn test_contract_resolving() {
@@ -27,14 +30,30 @@ fn test_contract_resolving() {
                 #[external]
                 fn ep2() {}
 
+                #[l1_handler]
+                fn l1_ep(from_address: felt) {}
+
+                #[constructor]
+                fn constructor() {}
+
                 trait __abi {
                     fn ep1();
                     fn ep2();
+                    #[l1_handler]
+                    fn l1_ep(from_address: felt);
+                    #[constructor]
+                    fn constructor();
                 }
                 mod __external {
                     fn ep1() {}
                     fn ep2() {}
                 }
+                mod __l1_handler {
+                    fn l1_ep() {}
+                }
+                mod __constructor {
+                    fn constructor() {}
+                }
             }
         "},
     );
@@ -50,6 +69,22 @@ fn test_contract_resolving() {
             .collect_vec(),
         vec!["ep1", "ep2"]
     );
+    assert_eq!(
+        get_l1_handler_functions(db, &contracts[0])
+            .unwrap()
+            .into_iter()
+            .map(|func_id| func_id.name(db))
+            .collect_vec(),
+        vec!["l1_ep"]
+    );
+    assert_eq!(
+        get_constructor_functions(db, &contracts[0])
+            .unwrap()
+            .into_iter()
+            .map(|func_id| func_id.name(db))
+            .collect_vec(),
+        vec!["constructor"]
+    );
 }
 
 #[test]
@@ -26,11 +26,17 @@ use crate::contract::starknet_keccak;
 
 const ABI_ATTR: &str = "abi";
 const CONTRACT_ATTR: &str = "contract";
+pub const CONSTRUCTOR_ATTR: &str = "constructor";
+pub const EVENT_ATTR: &str = "event";
 const EXTERNAL_ATTR: &str = "external";
+pub const L1_HANDLER_ATTR: &str = "l1_handler";
 const VIEW_ATTR: &str = "view";
+const LEGACY_MAP_TYPE: &str = "LegacyMap";
 pub const GENERATED_CONTRACT_ATTR: &str = "generated_contract";
 pub const ABI_TRAIT: &str = "__abi";
 pub const EXTERNAL_MODULE: &str = "__external";
+pub const L1_HANDLER_MODULE: &str = "__l1_handler";
+pub const CONSTRUCTOR_MODULE: &str = "__constructor";
 
 /// The diagnostics remapper of the plugin.
 #[derive(Debug, PartialEq, Eq)]
@@ -56,7 +62,9 @@ impl DiagnosticMapper for DiagnosticRemapper {
         db: &(dyn SemanticGroup + 'static),
         diag: &dyn std::any::Any,
     ) -> Option<PluginMappedDiagnostic> {
-        let Some(diag) = diag.downcast_ref::<SemanticDiagnostic>() else {return None;};
+        let Some(diag) = diag.downcast_ref::<SemanticDiagnostic>() else {
+            return None;
+        };
         let span = self
             .patches
             .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
@@ -116,6 +124,13 @@ fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginResult
     for item_ast in body.items(db).elements(db) {
         match item_ast {
             ast::TraitItem::Function(func) => {
+                if func.has_attr(db, EVENT_ATTR)
+                    || func.has_attr(db, CONSTRUCTOR_ATTR)
+                    || func.has_attr(db, L1_HANDLER_ATTR)
+                {
+                    // Only external functions can be called through a dispatcher.
+                    continue;
+                }
                 let declaration = func.declaration(db);
 
                 let mut skip_generation = false;
@@ -166,7 +181,8 @@ fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginResult
                             diagnostics.push(PluginDiagnostic {
                                 stable_ptr: ret_type_ast.stable_ptr().untyped(),
                                 message: format!(
-                                    "Could not find deserialization for type `{type_name}`"),
+                                    "Could not find deserialization for type `{type_name}`"
+                                ),
                             });
                             continue;
                         };
@@ -188,6 +204,13 @@ fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginResult
                             RewriteNode::Text(", ".to_string()),
                         ],
                     );
+                // Make the dispatcher function public, keeping the leading trivia of the `fn`
+                // keyword (the terminal's child at index 0) in place.
+                func_declaration
+                    .modify_child(db, ast::FunctionDeclaration::INDEX_FUNCTION_KW)
+                    .modify(db)
+                    .children
+                    .insert(1, RewriteNode::Text("pub ".to_string()));
 
                 functions.push(RewriteNode::interpolate_patched(
                     "$func_decl$ {
@@ -226,14 +249,18 @@ $deserialization_code$
     let dispatcher_name = format!("{}Dispatcher", trait_ast.name(db).text(db));
     builder.add_modified(RewriteNode::interpolate_patched(
         &formatdoc!(
-            "mod {dispatcher_name} {{
+            "$visibility$mod {dispatcher_name} {{
                 $body$
             }}",
         ),
-        HashMap::from([(
-            "body".to_string(),
-            RewriteNode::Modified(ModifiedNode { children: functions }),
-        )]),
+        HashMap::from([
+            // The dispatcher is as visible as the ABI it is generated for.
+            (
+                "visibility".to_string(),
+                RewriteNode::Copied(trait_ast.visibility(db).as_syntax_node()),
+            ),
+            ("body".to_string(), RewriteNode::Modified(ModifiedNode { children: functions })),
+        ]),
     ));
     PluginResult {
         code: Some(PluginGeneratedFile {
@@ -271,8 +298,13 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
     let mut diagnostics = vec![];
 
     let mut generated_external_functions = Vec::new();
+    let mut generated_l1_handler_functions = Vec::new();
+    let mut generated_constructor_functions = Vec::new();
+    let mut has_constructor = false;
 
-    let mut storage_code = RewriteNode::Text("".to_string());
+    let mut storage_struct = None;
+    // `use` lines through which the generated storage modules see the items of the contract.
+    let mut extra_uses = Vec::new();
     let mut original_items = Vec::new();
     let mut abi_functions = Vec::new();
     for item in body.items(db).elements(db) {
@@ -282,49 +314,136 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
                     || item_function.has_attr(db, VIEW_ATTR) =>
             {
                 // TODO(yuval): keep track of whether the function is external/view.
+                handle_entry_point(
+                    db,
+                    item_function,
+                    None,
+                    &mut abi_functions,
+                    &mut generated_external_functions,
+                    &mut diagnostics,
+                );
+            }
+            ast::Item::FreeFunction(item_function)
+                if item_function.has_attr(db, CONSTRUCTOR_ATTR) =>
+            {
+                if has_constructor {
+                    diagnostics.push(PluginDiagnostic {
+                        stable_ptr: item_function.declaration(db).name(db).stable_ptr().untyped(),
+                        message: "A contract may have at most one constructor.".to_string(),
+                    });
+                } else {
+                    has_constructor = true;
+                    handle_entry_point(
+                        db,
+                        item_function,
+                        Some(CONSTRUCTOR_ATTR),
+                        &mut abi_functions,
+                        &mut generated_constructor_functions,
+                        &mut diagnostics,
+                    );
+                }
+            }
+            ast::Item::FreeFunction(item_function)
+                if item_function.has_attr(db, L1_HANDLER_ATTR) =>
+            {
+                match validate_l1_handler_first_parameter(db, item_function) {
+                    Ok(()) => handle_entry_point(
+                        db,
+                        item_function,
+                        Some(L1_HANDLER_ATTR),
+                        &mut abi_functions,
+                        &mut generated_l1_handler_functions,
+                        &mut diagnostics,
+                    ),
+                    Err(diagnostic) => diagnostics.push(diagnostic),
+                }
+            }
+            ast::Item::FreeFunction(item_function) if item_function.has_attr(db, EVENT_ATTR) => {
                 abi_functions.push(RewriteNode::Modified(ModifiedNode {
                     children: vec![
+                        RewriteNode::Text(format!("#[{EVENT_ATTR}]\n")),
                         RewriteNode::Copied(item_function.declaration(db).as_syntax_node()),
                         RewriteNode::Text(";".to_string()),
                     ],
                 }));
 
-                match generate_entry_point_wrapper(db, item_function) {
-                    Ok(generated_function) => {
-                        generated_external_functions.push(generated_function);
-                    }
-                    Err(entry_point_diagnostics) => {
-                        diagnostics.extend(entry_point_diagnostics);
-                    }
+                // The event function replaces the original (empty) function.
+                match generate_event_code(db, item_function) {
+                    Ok(event_function) => original_items.push(event_function),
+                    Err(event_diagnostics) => diagnostics.extend(event_diagnostics),
                 }
+                continue;
             }
             ast::Item::Struct(item_struct) if item_struct.name(db).text(db) == "Storage" => {
-                storage_code = handle_storage_struct(db, item_struct.clone());
+                storage_struct = Some(item_struct.clone());
+            }
+            ast::Item::Struct(item_struct) => {
+                extra_uses.push(format!("use super::{};", item_struct.name(db).text(db)));
+            }
+            ast::Item::Enum(item_enum) => {
+                extra_uses.push(format!("use super::{};", item_enum.name(db).text(db)));
+            }
+            ast::Item::TypeAlias(item_type_alias) => {
+                extra_uses.push(format!("use super::{};", item_type_alias.name(db).text(db)));
+            }
+            ast::Item::Constant(item_constant) => {
+                extra_uses.push(format!("use super::{};", item_constant.name(db).text(db)));
+            }
+            ast::Item::Trait(item_trait) => {
+                extra_uses.push(format!("use super::{};", item_trait.name(db).text(db)));
+            }
+            ast::Item::Impl(item_impl) => {
+                extra_uses.push(format!("use super::{};", item_impl.name(db).text(db)));
+            }
+            ast::Item::ExternType(item_extern_type) => {
+                extra_uses.push(format!("use super::{};", item_extern_type.name(db).text(db)));
+            }
+            ast::Item::Use(item_use) => {
+                extra_uses.push(format!("use super::{};", item_use.name(db).identifier(db)));
             }
             _ => {}
         };
         original_items.push(RewriteNode::Copied(item.as_syntax_node()));
     }
 
+    let storage_code = match storage_struct {
+        Some(storage_struct) => {
+            handle_storage_struct(db, storage_struct, &extra_uses, &mut diagnostics)
+        }
+        None => RewriteNode::Text("".to_string()),
+    };
+
     let generated_contract_mod = RewriteNode::interpolate_patched(
         formatdoc!(
             "
             #[{GENERATED_CONTRACT_ATTR}]
-            mod $contract_name$ {{
+            $visibility$mod $contract_name$ {{
                 $original_items$
                 $storage_code$
-                trait {ABI_TRAIT} {{
+                pub trait {ABI_TRAIT} {{
                     $abi_functions$
                 }}
 
-                mod {EXTERNAL_MODULE} {{
+                pub mod {EXTERNAL_MODULE} {{
                     $generated_external_functions$
                 }}
+
+                pub mod {L1_HANDLER_MODULE} {{
+                    $generated_l1_handler_functions$
+                }}
+
+                pub mod {CONSTRUCTOR_MODULE} {{
+                    $generated_constructor_functions$
+                }}
             }}
         "
         )
         .as_str(),
         HashMap::from([
+            (
+                "visibility".to_string(),
+                RewriteNode::Copied(module_ast.visibility(db).as_syntax_node()),
+            ),
             (
                 "contract_name".to_string(),
                 RewriteNode::Copied(module_ast.name(db).as_syntax_node()),
@@ -342,6 +461,14 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
                 "generated_external_functions".to_string(),
                 RewriteNode::Modified(ModifiedNode { children: generated_external_functions }),
             ),
+            (
+                "generated_l1_handler_functions".to_string(),
+                RewriteNode::Modified(ModifiedNode { children: generated_l1_handler_functions }),
+            ),
+            (
+                "generated_constructor_functions".to_string(),
+                RewriteNode::Modified(ModifiedNode { children: generated_constructor_functions }),
+            ),
         ]),
     );
 
@@ -361,61 +488,354 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
     }
 }
 
+/// Adds the declaration of an entry point to the ABI functions, annotated with `abi_attr` if
+/// given, and adds its generated wrapper to `generated_functions`.
+fn handle_entry_point(
+    db: &dyn SyntaxGroup,
+    item_function: &ItemFreeFunction,
+    abi_attr: Option<&str>,
+    abi_functions: &mut Vec<RewriteNode>,
+    generated_functions: &mut Vec<RewriteNode>,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let mut abi_declaration = vec![];
+    if let Some(abi_attr) = abi_attr {
+        abi_declaration.push(RewriteNode::Text(format!("#[{abi_attr}]\n")));
+    }
+    abi_declaration.extend([
+        RewriteNode::Copied(item_function.declaration(db).as_syntax_node()),
+        RewriteNode::Text(";".to_string()),
+    ]);
+    abi_functions.push(RewriteNode::Modified(ModifiedNode { children: abi_declaration }));
+
+    match generate_entry_point_wrapper(db, item_function) {
+        Ok(generated_function) => {
+            generated_functions.push(generated_function);
+        }
+        Err(entry_point_diagnostics) => {
+            diagnostics.extend(entry_point_diagnostics);
+        }
+    }
+}
+
+/// Checks that the first parameter of an L1 handler is `from_address: felt`, the address of the
+/// L1 contract that sent the message.
+fn validate_l1_handler_first_parameter(
+    db: &dyn SyntaxGroup,
+    item_function: &ItemFreeFunction,
+) -> Result<(), PluginDiagnostic> {
+    let parameters = item_function.declaration(db).signature(db).parameters(db);
+    if let Some(first_param) = parameters.elements(db).first() {
+        let param_type = first_param.type_clause(db).ty(db).as_syntax_node().get_text(db);
+        if first_param.name(db).identifier(db) == "from_address"
+            && param_type.trim() == "felt"
+            && first_param.modifiers(db).elements(db).is_empty()
+        {
+            return Ok(());
+        }
+    }
+    Err(PluginDiagnostic {
+        stable_ptr: parameters.stable_ptr().untyped(),
+        message: "The first parameter of an L1 handler must be `from_address: felt`.".to_string(),
+    })
+}
+
 /// Generate getters and setters for the variables in the storage struct.
-fn handle_storage_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> RewriteNode {
+/// The values are accessed through `starknet::StorageAccess`, according to the type of each
+/// variable. The getters and setters of mappings (`LegacyMap::<K, V>`) also take the key.
+/// `extra_uses` are added to the generated modules, to make the contract's items visible from them.
+fn handle_storage_struct(
+    db: &dyn SyntaxGroup,
+    struct_ast: ast::ItemStruct,
+    extra_uses: &[String],
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> RewriteNode {
     let mut members_code = Vec::new();
+    let extra_uses = extra_uses.join("\n");
 
     for member in struct_ast.members(db).elements(db) {
         let name = member.name(db).text(db).to_string();
         let address = format!("0x{:x}", starknet_keccak(name.as_bytes()));
+        let type_ast = member.type_clause(db).ty(db);
+        let generated_submodule = match try_extract_mapping_types(db, &type_ast) {
+            Some(Ok((key_type_ast, value_type_ast))) => handle_mapping_storage_var(
+                db,
+                &member,
+                &address,
+                &extra_uses,
+                key_type_ast,
+                value_type_ast,
+            ),
+            Some(Err(diagnostic)) => {
+                diagnostics.push(diagnostic);
+                continue;
+            }
+            None => handle_simple_storage_var(db, &member, &address, &extra_uses),
+        };
 
-        let generated_submodule = RewriteNode::interpolate_patched(
-            formatdoc!(
-                "
-                mod $name$ {{
-                    fn read() -> felt {{
-                        // Only address_domain 0 is currently supported.
-                        let address_domain = 0;
-                        match starknet::storage_read_syscall(
-                            address_domain,
-                            starknet::storage_address_const::<{address}>(),
-                        ) {{
-                            Result::Ok(x) => x,
-                            Result::Err(revert_reason) => {{
-                                let mut err_data = array_new::<felt>();
-                                array_append::<felt>(err_data, revert_reason);
-                                panic(err_data)
-                            }},
-                        }}
+        members_code.push(generated_submodule)
+    }
+    RewriteNode::Modified(ModifiedNode { children: members_code })
+}
+
+/// Given the type of a storage variable, returns the key and value types if it is a mapping
+/// (`LegacyMap::<K, V>`), or a diagnostic if it is a malformed mapping.
+fn try_extract_mapping_types(
+    db: &dyn SyntaxGroup,
+    type_ast: &ast::Expr,
+) -> Option<Result<(ast::Expr, ast::Expr), PluginDiagnostic>> {
+    let ast::Expr::Path(type_path) = type_ast else {
+        return None;
+    };
+    let [ast::PathSegment::WithGenericArgs(segment)] = &type_path.elements(db)[..] else {
+        return None;
+    };
+    if segment.ident(db).text(db) != LEGACY_MAP_TYPE {
+        return None;
+    }
+    Some(match &segment.generic_args(db).generic_args(db).elements(db)[..] {
+        [key_type_ast, value_type_ast] => Ok((key_type_ast.clone(), value_type_ast.clone())),
+        _ => Err(PluginDiagnostic {
+            stable_ptr: type_ast.stable_ptr().untyped(),
+            message: format!("`{LEGACY_MAP_TYPE}` must have exactly two generic arguments."),
+        }),
+    })
+}
+
+/// Generates the getter and setter of a storage variable that is not a mapping.
+fn handle_simple_storage_var(
+    db: &dyn SyntaxGroup,
+    member: &ast::Member,
+    address: &str,
+    extra_uses: &str,
+) -> RewriteNode {
+    RewriteNode::interpolate_patched(
+        formatdoc!(
+            "
+            mod $name$ {{
+                {extra_uses}
+                pub fn read() -> $type$ {{
+                    // Only address_domain 0 is currently supported.
+                    let address_domain = 0;
+                    match starknet::StorageAccess::<$type$>::read(
+                        address_domain,
+                        starknet::storage_base_address_const::<{address}>(),
+                    ) {{
+                        Result::Ok(x) => x,
+                        Result::Err(revert_reason) => {{
+                            let mut err_data = array_new::<felt>();
+                            array_append::<felt>(err_data, revert_reason);
+                            panic(err_data)
+                        }},
                     }}
-                    fn write(value: felt) {{
-                        // Only address_domain 0 is currently supported.
-                        let address_domain = 0;
-                        match starknet::storage_write_syscall(
-                            address_domain,
-                            starknet::storage_address_const::<{address}>(),
-                            value,
-                        ) {{
-                            Result::Ok(()) => {{}},
-                            Result::Err(revert_reason) => {{
-                                let mut err_data = array_new::<felt>();
-                                array_append::<felt>(err_data, revert_reason);
-                                panic(err_data)
-                            }},
-                        }}
+                }}
+                pub fn write(value: $type$) {{
+                    // Only address_domain 0 is currently supported.
+                    let address_domain = 0;
+                    match starknet::StorageAccess::<$type$>::write(
+                        address_domain,
+                        starknet::storage_base_address_const::<{address}>(),
+                        value,
+                    ) {{
+                        Result::Ok(()) => {{}},
+                        Result::Err(revert_reason) => {{
+                            let mut err_data = array_new::<felt>();
+                            array_append::<felt>(err_data, revert_reason);
+                            panic(err_data)
+                        }},
                     }}
-                }}"
-            )
-            .as_str(),
+                }}
+            }}"
+        )
+        .as_str(),
+        HashMap::from([
+            ("name".to_string(), RewriteNode::Copied(member.name(db).as_syntax_node())),
+            (
+                "type".to_string(),
+                RewriteNode::Copied(member.type_clause(db).ty(db).as_syntax_node()),
+            ),
+        ]),
+    )
+}
+
+/// Generates the getter and setter of a mapping storage variable.
+/// The value of `key` is stored starting at `pedersen(address, key)`, where tuple keys are hashed
+/// element by element: `pedersen(pedersen(address, key.0), key.1)`.
+fn handle_mapping_storage_var(
+    db: &dyn SyntaxGroup,
+    member: &ast::Member,
+    address: &str,
+    extra_uses: &str,
+    key_type_ast: ast::Expr,
+    value_type_ast: ast::Expr,
+) -> RewriteNode {
+    let key_types = match &key_type_ast {
+        ast::Expr::Tuple(key_tuple) => key_tuple.expressions(db).elements(db),
+        _ => vec![key_type_ast.clone()],
+    };
+    let (destructure_key, key_names) = if key_types.len() == 1 {
+        (String::new(), vec!["key".to_string()])
+    } else {
+        let key_names = (0..key_types.len()).map(|i| format!("key_{i}")).collect::<Vec<_>>();
+        (format!("let ({}) = key;", key_names.join(", ")), key_names)
+    };
+    let mut hash_code = vec![];
+    for (key_type, key_name) in key_types.into_iter().zip(key_names) {
+        if !hash_code.is_empty() {
+            hash_code.push(RewriteNode::Text("\n".to_string()));
+        }
+        hash_code.push(RewriteNode::interpolate_patched(
+            &format!("let state = hash::LegacyHash::<$key_type$>::hash(state, {key_name});"),
             HashMap::from([(
-                "name".to_string(),
-                RewriteNode::Copied(member.name(db).as_syntax_node()),
+                "key_type".to_string(),
+                RewriteNode::Copied(key_type.as_syntax_node()),
             )]),
-        );
+        ));
+    }
 
-        members_code.push(generated_submodule)
+    RewriteNode::interpolate_patched(
+        formatdoc!(
+            "
+            mod $name$ {{
+                {extra_uses}
+                pub fn address(key: $key_type$) -> starknet::StorageBaseAddress {{
+                    {destructure_key}
+                    let state = {address};
+                    $hash_code$
+                    starknet::storage_base_address_from_felt(state)
+                }}
+                pub fn read(key: $key_type$) -> $value_type$ {{
+                    // Only address_domain 0 is currently supported.
+                    let address_domain = 0;
+                    match starknet::StorageAccess::<$value_type$>::read(
+                        address_domain,
+                        address(key),
+                    ) {{
+                        Result::Ok(x) => x,
+                        Result::Err(revert_reason) => {{
+                            let mut err_data = array_new::<felt>();
+                            array_append::<felt>(err_data, revert_reason);
+                            panic(err_data)
+                        }},
+                    }}
+                }}
+                pub fn write(key: $key_type$, value: $value_type$) {{
+                    // Only address_domain 0 is currently supported.
+                    let address_domain = 0;
+                    match starknet::StorageAccess::<$value_type$>::write(
+                        address_domain,
+                        address(key),
+                        value,
+                    ) {{
+                        Result::Ok(()) => {{}},
+                        Result::Err(revert_reason) => {{
+                            let mut err_data = array_new::<felt>();
+                            array_append::<felt>(err_data, revert_reason);
+                            panic(err_data)
+                        }},
+                    }}
+                }}
+            }}"
+        )
+        .as_str(),
+        HashMap::from([
+            ("name".to_string(), RewriteNode::Copied(member.name(db).as_syntax_node())),
+            ("key_type".to_string(), RewriteNode::Copied(key_type_ast.as_syntax_node())),
+            ("value_type".to_string(), RewriteNode::Copied(value_type_ast.as_syntax_node())),
+            ("hash_code".to_string(), RewriteNode::Modified(ModifiedNode { children: hash_code })),
+        ]),
+    )
+}
+
+/// Generates the code of an event function, which emits the event given its parameters.
+/// The first key of the event is `sn_keccak` of its name, and its data is the serialization of the
+/// parameters.
+fn generate_event_code(
+    db: &dyn SyntaxGroup,
+    function: &ItemFreeFunction,
+) -> Result<RewriteNode, Vec<PluginDiagnostic>> {
+    let declaration = function.declaration(db);
+    let signature = declaration.signature(db);
+    let mut diagnostics = vec![];
+    if let OptionReturnTypeClause::ReturnTypeClause(ret_ty) = signature.ret_ty(db) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: ret_ty.stable_ptr().untyped(),
+            message: "Event functions must not return a value.".to_string(),
+        });
     }
-    RewriteNode::Modified(ModifiedNode { children: members_code })
+    let body = function.body(db);
+    if !body.statements(db).elements(db).is_empty() {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: body.stable_ptr().untyped(),
+            message: "Event function body must be empty.".to_string(),
+        });
+    }
+
+    let mut serialization_code = vec![];
+    for param in signature.parameters(db).elements(db) {
+        if is_ref_param(db, &param) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: param.modifiers(db).stable_ptr().untyped(),
+                message: "`ref` parameters are not supported in events.".to_string(),
+            });
+        }
+
+        let param_type = param.type_clause(db).ty(db);
+        let type_name = param_type.as_syntax_node().get_text(db);
+        let Some((ser_func, _)) = get_type_serde_funcs(&type_name) else {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: param_type.stable_ptr().untyped(),
+                message: format!("Could not find serialization for type `{type_name}`"),
+            });
+            continue;
+        };
+        if !serialization_code.is_empty() {
+            serialization_code.push(RewriteNode::Text("\n".to_string()));
+        }
+        serialization_code.push(RewriteNode::interpolate_patched(
+            &format!("{ser_func}(data, $arg_name$);"),
+            HashMap::from([(
+                "arg_name".to_string(),
+                RewriteNode::Copied(param.name(db).as_syntax_node()),
+            )]),
+        ));
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    let event_key = format!("0x{:x}", starknet_keccak(declaration.name(db).text(db).as_bytes()));
+    Ok(RewriteNode::interpolate_patched(
+        formatdoc!(
+            "
+            $visibility$$declaration$ {{
+                let mut keys = array_new::<felt>();
+                array_append::<felt>(keys, {event_key});
+                let mut data = array_new::<felt>();
+                $serialization_code$
+                match starknet::emit_event_syscall(keys, data) {{
+                    Result::Ok(()) => {{}},
+                    Result::Err(revert_reason) => {{
+                        let mut err_data = array_new::<felt>();
+                        array_append::<felt>(err_data, revert_reason);
+                        panic(err_data)
+                    }},
+                }}
+            }}"
+        )
+        .as_str(),
+        HashMap::from([
+            (
+                "visibility".to_string(),
+                RewriteNode::Copied(function.visibility(db).as_syntax_node()),
+            ),
+            ("declaration".to_string(), RewriteNode::Copied(declaration.as_syntax_node())),
+            (
+                "serialization_code".to_string(),
+                RewriteNode::Modified(ModifiedNode { children: serialization_code }),
+            ),
+        ]),
+    ))
 }
 
 /// Returns the serde functions for a type.
@@ -428,6 +848,10 @@ fn get_type_serde_funcs(name: &str) -> Option<(&str, &str)> {
         "u128" => Some(("serde::serialize_u128", "serde::deserialize_u128")),
         "u256" => Some(("serde::serialize_u256", "serde::deserialize_u256")),
         "Array::<felt>" => Some(("serde::serialize_array_felt", "serde::deserialize_array_felt")),
+        "ByteArray" => Some(("serde::serialize_byte_array", "serde::deserialize_byte_array")),
+        "ContractAddress" => {
+            Some(("serde::serialize_contract_address", "serde::deserialize_contract_address"))
+        }
         _ => None,
     }
 }
@@ -512,7 +936,7 @@ fn generate_entry_point_wrapper(
     let arg_definitions = arg_definitions.join("\n");
     Ok(RewriteNode::interpolate_patched(
         formatdoc!(
-            "fn $function_name$(mut data: Array::<felt>) -> Array::<felt> {{
+            "pub fn $function_name$(mut data: Array::<felt>) -> Array::<felt> {{
                         match get_gas() {{
                             Option::Some(_) => {{
                             }},
@@ -57,6 +57,8 @@ cairo_lang_test_utils::test_file_test!(
         storage: "storage",
         hello_starknet: "hello_starknet",
         dispatcher: "dispatcher",
+        events: "events",
+        entry_points: "entry_points",
     },
     test_expand_contract
 );
@@ -1,6 +1,7 @@
 #[abi]
 trait IAnotherContract {
-fn foo(); }
+    fn foo();
+}
 
 
 #[contract]
@@ -11,6 +12,16 @@ mod TestContract {
         1
     }
 
+    #[constructor]
+    fn constructor(initial_value: felt) {
+        my_storage_var::write(initial_value);
+    }
+
+    #[l1_handler]
+    fn l1_handle(from_address: felt, arg: felt) -> felt {
+        from_address + arg
+    }
+
     #[external]
     fn test(ref arg: felt, arg1: felt, arg2: felt) -> felt {
         let x = my_storage_var::read();
@@ -0,0 +1,38 @@
+use starknet::StorageAccess;
+use starknet::StorageBaseAddress;
+
+#[derive(Copy, Drop)]
+struct Point { x: felt, y: felt, }
+
+// Stores a point over two consecutive storage addresses.
+impl StorageAccessPoint of StorageAccess::<Point> {
+    fn read(address_domain: felt, base: StorageBaseAddress) -> Result::<Point, felt> {
+        let x = StorageAccess::<felt>::read(address_domain, base)?;
+        let y_address = starknet::storage_address_from_base_and_offset(base, 1_u8);
+        let y = starknet::storage_read_syscall(address_domain, y_address)?;
+        Result::Ok(Point { x, y })
+    }
+    fn write(address_domain: felt, base: StorageBaseAddress, value: Point) -> Result::<(), felt> {
+        StorageAccess::<felt>::write(address_domain, base, value.x)?;
+        let y_address = starknet::storage_address_from_base_and_offset(base, 1_u8);
+        starknet::storage_write_syscall(address_domain, y_address, value.y)
+    }
+}
+
+#[contract]
+mod UserStorage {
+    use super::Point;
+
+    struct Storage { location: Point, }
+
+    #[external]
+    fn set_location(x: felt, y: felt) {
+        location::write(Point { x, y });
+    }
+
+    #[view]
+    fn get_location_sum() -> felt {
+        let location = location::read();
+        location.x + location.y
+    }
+}
@@ -18,6 +18,7 @@ pub fn get_spec() -> Vec<Node> {
             .node("Path")
             .node_with_explicit_kind("Literal", "TerminalLiteralNumber")
             .node_with_explicit_kind("ShortString", "TerminalShortString")
+            .node_with_explicit_kind("String", "TerminalString")
             .node_with_explicit_kind("False", "TerminalFalse")
             .node_with_explicit_kind("True", "TerminalTrue")
             .node("Parenthesized")
@@ -29,7 +30,12 @@ pub fn get_spec() -> Vec<Node> {
             .node("Block")
             .node("Match")
             .node("If")
-            .node("ErrorPropagate"),
+            .node("IfLet")
+            .node("Loop")
+            .node("While")
+            .node("ErrorPropagate")
+            .node("Indexed")
+            .node("Closure"),
     )
     .add_separated_list("ExprList", "Expr", "TerminalComma")
     .add_struct(StructBuilder::new("ExprMissing"))
@@ -67,10 +73,20 @@ pub fn get_spec() -> Vec<Node> {
             .node_with_explicit_kind("EqEq", "TerminalEqEq")
             .node_with_explicit_kind("Neq", "TerminalNeq")
             .node_with_explicit_kind("Eq", "TerminalEq")
+            .node_with_explicit_kind("PlusEq", "TerminalPlusEq")
+            .node_with_explicit_kind("MinusEq", "TerminalMinusEq")
+            .node_with_explicit_kind("MulEq", "TerminalMulEq")
+            .node_with_explicit_kind("DivEq", "TerminalDivEq")
+            .node_with_explicit_kind("ModEq", "TerminalModEq")
+            .node_with_explicit_kind("AndEq", "TerminalAndEq")
+            .node_with_explicit_kind("OrEq", "TerminalOrEq")
+            .node_with_explicit_kind("XorEq", "TerminalXorEq")
             // TODO(yuval): not yet implemented in parser.
             .node_with_explicit_kind("And", "TerminalAnd")
             .node_with_explicit_kind("Or", "TerminalOr")
             .node_with_explicit_kind("Xor", "TerminalXor")
+            .node_with_explicit_kind("Shl", "TerminalShl")
+            .node_with_explicit_kind("Shr", "TerminalShr")
             .node_with_explicit_kind("LE", "TerminalLE")
             .node_with_explicit_kind("GE", "TerminalGE")
             .node_with_explicit_kind("LT", "TerminalLT")
@@ -119,16 +135,54 @@ pub fn get_spec() -> Vec<Node> {
         .node("if_block", "ExprBlock")
         .node("else_clause", "OptionElseClause")
         )
+    .add_struct(StructBuilder::new("ExprIfLet")
+        .node("if_kw", "TerminalIf")
+        .node("let_kw", "TerminalLet")
+        .node("pattern", "Pattern")
+        .node("eq", "TerminalEq")
+        .node("expr", "Expr")
+        .node("if_block", "ExprBlock")
+        .node("else_clause", "OptionElseClause")
+        )
     .add_enum(EnumBuilder::new("BlockOrIf")
         .node_with_explicit_kind("Block", "ExprBlock")
         .node_with_explicit_kind("If", "ExprIf")
+        .node_with_explicit_kind("IfLet", "ExprIfLet")
         )
     .add_struct(StructBuilder::new("ElseClause")
         .node("else_kw", "TerminalElse")
         .node("else_block_or_if", "BlockOrIf")
         )
     .add_option("ElseClause")
+    .add_struct(StructBuilder::new("ExprLoop")
+        .node("loop_kw", "TerminalLoop")
+        .node("body", "ExprBlock")
+        )
+    .add_struct(StructBuilder::new("ExprWhile")
+        .node("while_kw", "TerminalWhile")
+        .node("condition", "Expr")
+        .node("body", "ExprBlock")
+        )
     .add_struct(StructBuilder::new("ExprErrorPropagate").node("expr", "Expr").node("op", "TerminalQuestionMark"))
+    .add_struct(StructBuilder::new("ExprIndexed")
+        .node("expr", "Expr")
+        .node("lbrack", "TerminalLBrack")
+        .node("index_expr", "Expr")
+        .node("rbrack", "TerminalRBrack")
+        )
+    .add_struct(StructBuilder::new("ExprClosure")
+        .node("params", "ClosureParams")
+        .node("body", "Expr")
+        )
+    .add_enum(EnumBuilder::new("ClosureParams")
+        .node_with_explicit_kind("Empty", "TerminalOrOr")
+        .node("Wrapped")
+        )
+    .add_struct(StructBuilder::new("ClosureParamsWrapped")
+        .node("lor", "TerminalOr")
+        .node("params", "ParamList")
+        .node("ror", "TerminalOr")
+        )
     // --- Struct ctor ---
     .add_struct(StructBuilder::new("StructArgExpr")
         .node("colon", "TerminalColon")
@@ -160,6 +214,8 @@ pub fn get_spec() -> Vec<Node> {
         .node_with_explicit_kind("Underscore", "TerminalUnderscore")
         .node_with_explicit_kind("Literal", "TerminalLiteralNumber")
         .node_with_explicit_kind("ShortString", "TerminalShortString")
+        .node_with_explicit_kind("True", "TerminalTrue")
+        .node_with_explicit_kind("False", "TerminalFalse")
         .node("Identifier")
         .node("Struct")
         .node("Tuple")
@@ -215,6 +271,8 @@ pub fn get_spec() -> Vec<Node> {
         .node("Let")
         .node("Expr")
         .node("Return")
+        .node("Break")
+        .node("Continue")
         )
     .add_list("StatementList", "Statement")
     .add_struct(StructBuilder::new("StatementMissing"))
@@ -224,8 +282,14 @@ pub fn get_spec() -> Vec<Node> {
         .node("type_clause", "OptionTypeClause")
         .node("eq", "TerminalEq")
         .node("rhs", "Expr")
+        .node("let_else_clause", "OptionLetElseClause")
         .node("semicolon", "TerminalSemicolon")
         )
+    .add_struct(StructBuilder::new("LetElseClause")
+        .node("else_kw", "TerminalElse")
+        .node("else_block", "ExprBlock")
+        )
+    .add_option("LetElseClause")
     .add_option("TerminalSemicolon")
     .add_struct(StructBuilder::new("StatementExpr")
         .node("expr", "Expr")
@@ -236,6 +300,17 @@ pub fn get_spec() -> Vec<Node> {
         .node("expr", "Expr")
         .node("semicolon", "TerminalSemicolon")
         )
+    .add_struct(StructBuilder::new("ExprClause").node("expr", "Expr"))
+    .add_option("ExprClause")
+    .add_struct(StructBuilder::new("StatementBreak")
+        .node("break_kw", "TerminalBreak")
+        .node("expr_clause", "OptionExprClause")
+        .node("semicolon", "TerminalSemicolon")
+        )
+    .add_struct(StructBuilder::new("StatementContinue")
+        .node("continue_kw", "TerminalContinue")
+        .node("semicolon", "TerminalSemicolon")
+        )
     // --- Functions ---
     .add_enum(EnumBuilder::new("ParamName")
         .node_with_explicit_kind("Underscore", "TerminalUnderscore")
@@ -274,6 +349,7 @@ pub fn get_spec() -> Vec<Node> {
     // --- Struct Members ---
     // Struct member and enum variant have the same structure.
     .add_struct(StructBuilder::new("Member")
+        .node("visibility", "Visibility")
         .key_node("name", "TerminalIdentifier")
         .node("type_clause", "TypeClause")
         )
@@ -290,8 +366,17 @@ pub fn get_spec() -> Vec<Node> {
         .node("Struct")
         .node("Enum")
         .node("TypeAlias")
+        .node("Constant")
         )
     .add_list("ItemList", "Item")
+    .add_enum(EnumBuilder::new("Visibility")
+        .node("Default")
+        .node("Pub")
+        )
+    .add_struct(StructBuilder::new("VisibilityDefault"))
+    .add_struct(StructBuilder::new("VisibilityPub")
+        .node("pub_kw", "TerminalPub")
+        )
     .add_struct(StructBuilder::new("Attribute")
          .node("hash", "TerminalHash")
          .node("lbrack", "TerminalLBrack")
@@ -302,6 +387,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_list("AttributeList", "Attribute")
     .add_struct(StructBuilder::new("ItemModule")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("module_kw", "TerminalModule")
         .key_node("name", "TerminalIdentifier")
         .node("body", "MaybeModuleBody")
@@ -330,12 +416,14 @@ pub fn get_spec() -> Vec<Node> {
         )
     .add_struct(StructBuilder::new("ItemFreeFunction")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
          // TODO(ilya): Use only the name as key node.
         .key_node("declaration", "FunctionDeclaration")
         .node("body", "ExprBlock")
         )
     .add_struct(StructBuilder::new("ItemExternFunction")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("extern_kw", "TerminalExtern")
          // TODO(ilya): Use only the name as key node.
         .key_node("declaration", "FunctionDeclaration")
@@ -343,6 +431,7 @@ pub fn get_spec() -> Vec<Node> {
         )
     .add_struct(StructBuilder::new("ItemExternType")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("extern_kw", "TerminalExtern")
         .node("type_kw", "TerminalType")
         .key_node("name", "TerminalIdentifier")
@@ -352,6 +441,7 @@ pub fn get_spec() -> Vec<Node> {
     // TODO(spapini): consider having specific ItemLists here.
     .add_struct(StructBuilder::new("ItemTrait")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("trait_kw", "TerminalTrait")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -383,6 +473,7 @@ pub fn get_spec() -> Vec<Node> {
         )
     .add_struct(StructBuilder::new("ItemImpl")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("impl_kw", "TerminalImpl")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -401,6 +492,7 @@ pub fn get_spec() -> Vec<Node> {
         )
     .add_struct(StructBuilder::new("ItemStruct")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("struct_kw", "TerminalStruct")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -410,6 +502,7 @@ pub fn get_spec() -> Vec<Node> {
         )
     .add_struct(StructBuilder::new("ItemEnum")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("enum_kw", "TerminalEnum")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -419,6 +512,7 @@ pub fn get_spec() -> Vec<Node> {
         )
     .add_struct(StructBuilder::new("ItemTypeAlias")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("type_kw", "TerminalType")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -426,8 +520,19 @@ pub fn get_spec() -> Vec<Node> {
         .node("ty", "Expr")
         .node("semicolon", "TerminalSemicolon")
         )
+    .add_struct(StructBuilder::new("ItemConstant")
+        .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
+        .node("const_kw", "TerminalConst")
+        .key_node("name", "TerminalIdentifier")
+        .node("type_clause", "TypeClause")
+        .node("eq", "TerminalEq")
+        .node("value", "Expr")
+        .node("semicolon", "TerminalSemicolon")
+        )
     .add_struct(StructBuilder::new("ItemUse")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("use_kw", "TerminalUse")
         .key_node("name", "ExprPath")
         .node("semicolon", "TerminalSemicolon")
@@ -452,10 +557,12 @@ pub fn get_spec() -> Vec<Node> {
     .add_token_and_terminal("Identifier")
     .add_token_and_terminal("LiteralNumber")
     .add_token_and_terminal("ShortString")
+    .add_token_and_terminal("String")
     .add_keyword_token_and_terminal("False")
     .add_keyword_token_and_terminal("True")
     .add_keyword_token_and_terminal("Extern")
     .add_keyword_token_and_terminal("Type")
+    .add_keyword_token_and_terminal("Const")
     .add_keyword_token_and_terminal("Function")
     .add_keyword_token_and_terminal("Module")
     .add_keyword_token_and_terminal("Enum")
@@ -468,7 +575,12 @@ pub fn get_spec() -> Vec<Node> {
     .add_keyword_token_and_terminal("Match")
     .add_keyword_token_and_terminal("If")
     .add_keyword_token_and_terminal("Else")
+    .add_keyword_token_and_terminal("Loop")
+    .add_keyword_token_and_terminal("While")
+    .add_keyword_token_and_terminal("Break")
+    .add_keyword_token_and_terminal("Continue")
     .add_keyword_token_and_terminal("Use")
+    .add_keyword_token_and_terminal("Pub")
     .add_keyword_token_and_terminal("Implicits")
     .add_keyword_token_and_terminal("Ref")
     .add_keyword_token_and_terminal("Mut")
@@ -478,6 +590,8 @@ pub fn get_spec() -> Vec<Node> {
     .add_token_and_terminal("Or")
     .add_token_and_terminal("OrOr")
     .add_token_and_terminal("Xor")
+    .add_token_and_terminal("Shl")
+    .add_token_and_terminal("Shr")
     .add_token_and_terminal("EqEq")
     .add_token_and_terminal("Neq")
     .add_token_and_terminal("GE")
@@ -496,6 +610,14 @@ pub fn get_spec() -> Vec<Node> {
     .add_token_and_terminal("Dot")
     .add_token_and_terminal("DotDot")
     .add_token_and_terminal("Eq")
+    .add_token_and_terminal("PlusEq")
+    .add_token_and_terminal("MinusEq")
+    .add_token_and_terminal("MulEq")
+    .add_token_and_terminal("DivEq")
+    .add_token_and_terminal("ModEq")
+    .add_token_and_terminal("AndEq")
+    .add_token_and_terminal("OrEq")
+    .add_token_and_terminal("XorEq")
     .add_token_and_terminal("Semicolon")
     .add_token_and_terminal("QuestionMark")
     .add_token_and_terminal("Underscore")
@@ -164,6 +164,7 @@ pub enum Expr {
     Path(ExprPath),
     Literal(TerminalLiteralNumber),
     ShortString(TerminalShortString),
+    String(TerminalString),
     False(TerminalFalse),
     True(TerminalTrue),
     Parenthesized(ExprParenthesized),
@@ -175,7 +176,12 @@ pub enum Expr {
     Block(ExprBlock),
     Match(ExprMatch),
     If(ExprIf),
+    IfLet(ExprIfLet),
+    Loop(ExprLoop),
+    While(ExprWhile),
     ErrorPropagate(ExprErrorPropagate),
+    Indexed(ExprIndexed),
+    Closure(ExprClosure),
     Missing(ExprMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -200,6 +206,11 @@ impl From<TerminalShortStringPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<TerminalStringPtr> for ExprPtr {
+    fn from(value: TerminalStringPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalFalsePtr> for ExprPtr {
     fn from(value: TerminalFalsePtr) -> Self {
         Self(value.0)
@@ -255,11 +266,36 @@ impl From<ExprIfPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<ExprIfLetPtr> for ExprPtr {
+    fn from(value: ExprIfLetPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprLoopPtr> for ExprPtr {
+    fn from(value: ExprLoopPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprWhilePtr> for ExprPtr {
+    fn from(value: ExprWhilePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagatePtr> for ExprPtr {
     fn from(value: ExprErrorPropagatePtr) -> Self {
         Self(value.0)
     }
 }
+impl From<ExprIndexedPtr> for ExprPtr {
+    fn from(value: ExprIndexedPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprClosurePtr> for ExprPtr {
+    fn from(value: ExprClosurePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprMissingPtr> for ExprPtr {
     fn from(value: ExprMissingPtr) -> Self {
         Self(value.0)
@@ -280,6 +316,11 @@ impl From<TerminalShortStringGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<TerminalStringGreen> for ExprGreen {
+    fn from(value: TerminalStringGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalFalseGreen> for ExprGreen {
     fn from(value: TerminalFalseGreen) -> Self {
         Self(value.0)
@@ -335,11 +376,36 @@ impl From<ExprIfGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<ExprIfLetGreen> for ExprGreen {
+    fn from(value: ExprIfLetGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprLoopGreen> for ExprGreen {
+    fn from(value: ExprLoopGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprWhileGreen> for ExprGreen {
+    fn from(value: ExprWhileGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagateGreen> for ExprGreen {
     fn from(value: ExprErrorPropagateGreen) -> Self {
         Self(value.0)
     }
 }
+impl From<ExprIndexedGreen> for ExprGreen {
+    fn from(value: ExprIndexedGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprClosureGreen> for ExprGreen {
+    fn from(value: ExprClosureGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprMissingGreen> for ExprGreen {
     fn from(value: ExprMissingGreen) -> Self {
         Self(value.0)
@@ -364,6 +430,7 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::TerminalShortString => {
                 Expr::ShortString(TerminalShortString::from_syntax_node(db, node))
             }
+            SyntaxKind::TerminalString => Expr::String(TerminalString::from_syntax_node(db, node)),
             SyntaxKind::TerminalFalse => Expr::False(TerminalFalse::from_syntax_node(db, node)),
             SyntaxKind::TerminalTrue => Expr::True(TerminalTrue::from_syntax_node(db, node)),
             SyntaxKind::ExprParenthesized => {
@@ -381,9 +448,14 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::ExprBlock => Expr::Block(ExprBlock::from_syntax_node(db, node)),
             SyntaxKind::ExprMatch => Expr::Match(ExprMatch::from_syntax_node(db, node)),
             SyntaxKind::ExprIf => Expr::If(ExprIf::from_syntax_node(db, node)),
+            SyntaxKind::ExprIfLet => Expr::IfLet(ExprIfLet::from_syntax_node(db, node)),
+            SyntaxKind::ExprLoop => Expr::Loop(ExprLoop::from_syntax_node(db, node)),
+            SyntaxKind::ExprWhile => Expr::While(ExprWhile::from_syntax_node(db, node)),
             SyntaxKind::ExprErrorPropagate => {
                 Expr::ErrorPropagate(ExprErrorPropagate::from_syntax_node(db, node))
             }
+            SyntaxKind::ExprIndexed => Expr::Indexed(ExprIndexed::from_syntax_node(db, node)),
+            SyntaxKind::ExprClosure => Expr::Closure(ExprClosure::from_syntax_node(db, node)),
             SyntaxKind::ExprMissing => Expr::Missing(ExprMissing::from_syntax_node(db, node)),
             _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Expr"),
         }
@@ -393,6 +465,7 @@ impl TypedSyntaxNode for Expr {
             Expr::Path(x) => x.as_syntax_node(),
             Expr::Literal(x) => x.as_syntax_node(),
             Expr::ShortString(x) => x.as_syntax_node(),
+            Expr::String(x) => x.as_syntax_node(),
             Expr::False(x) => x.as_syntax_node(),
             Expr::True(x) => x.as_syntax_node(),
             Expr::Parenthesized(x) => x.as_syntax_node(),
@@ -404,7 +477,12 @@ impl TypedSyntaxNode for Expr {
             Expr::Block(x) => x.as_syntax_node(),
             Expr::Match(x) => x.as_syntax_node(),
             Expr::If(x) => x.as_syntax_node(),
+            Expr::IfLet(x) => x.as_syntax_node(),
+            Expr::Loop(x) => x.as_syntax_node(),
+            Expr::While(x) => x.as_syntax_node(),
             Expr::ErrorPropagate(x) => x.as_syntax_node(),
+            Expr::Indexed(x) => x.as_syntax_node(),
+            Expr::Closure(x) => x.as_syntax_node(),
             Expr::Missing(x) => x.as_syntax_node(),
         }
     }
@@ -1157,9 +1235,19 @@ pub enum BinaryOperator {
     EqEq(TerminalEqEq),
     Neq(TerminalNeq),
     Eq(TerminalEq),
+    PlusEq(TerminalPlusEq),
+    MinusEq(TerminalMinusEq),
+    MulEq(TerminalMulEq),
+    DivEq(TerminalDivEq),
+    ModEq(TerminalModEq),
+    AndEq(TerminalAndEq),
+    OrEq(TerminalOrEq),
+    XorEq(TerminalXorEq),
     And(TerminalAnd),
     Or(TerminalOr),
     Xor(TerminalXor),
+    Shl(TerminalShl),
+    Shr(TerminalShr),
     LE(TerminalLE),
     GE(TerminalGE),
     LT(TerminalLT),
@@ -1222,6 +1310,46 @@ impl From<TerminalEqPtr> for BinaryOperatorPtr {
         Self(value.0)
     }
 }
+impl From<TerminalPlusEqPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalPlusEqPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalMinusEqPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalMinusEqPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalMulEqPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalMulEqPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalDivEqPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalDivEqPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalModEqPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalModEqPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalAndEqPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalAndEqPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalOrEqPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalOrEqPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalXorEqPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalXorEqPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalAndPtr> for BinaryOperatorPtr {
     fn from(value: TerminalAndPtr) -> Self {
         Self(value.0)
@@ -1237,6 +1365,16 @@ impl From<TerminalXorPtr> for BinaryOperatorPtr {
         Self(value.0)
     }
 }
+impl From<TerminalShlPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalShlPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalShrPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalShrPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalLEPtr> for BinaryOperatorPtr {
     fn from(value: TerminalLEPtr) -> Self {
         Self(value.0)
@@ -1307,6 +1445,46 @@ impl From<TerminalEqGreen> for BinaryOperatorGreen {
         Self(value.0)
     }
 }
+impl From<TerminalPlusEqGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalPlusEqGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalMinusEqGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalMinusEqGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalMulEqGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalMulEqGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalDivEqGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalDivEqGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalModEqGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalModEqGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalAndEqGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalAndEqGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalOrEqGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalOrEqGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalXorEqGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalXorEqGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalAndGreen> for BinaryOperatorGreen {
     fn from(value: TerminalAndGreen) -> Self {
         Self(value.0)
@@ -1322,6 +1500,16 @@ impl From<TerminalXorGreen> for BinaryOperatorGreen {
         Self(value.0)
     }
 }
+impl From<TerminalShlGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalShlGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalShrGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalShrGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalLEGreen> for BinaryOperatorGreen {
     fn from(value: TerminalLEGreen) -> Self {
         Self(value.0)
@@ -1370,9 +1558,35 @@ impl TypedSyntaxNode for BinaryOperator {
             }
             SyntaxKind::TerminalNeq => BinaryOperator::Neq(TerminalNeq::from_syntax_node(db, node)),
             SyntaxKind::TerminalEq => BinaryOperator::Eq(TerminalEq::from_syntax_node(db, node)),
+            SyntaxKind::TerminalPlusEq => {
+                BinaryOperator::PlusEq(TerminalPlusEq::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalMinusEq => {
+                BinaryOperator::MinusEq(TerminalMinusEq::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalMulEq => {
+                BinaryOperator::MulEq(TerminalMulEq::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalDivEq => {
+                BinaryOperator::DivEq(TerminalDivEq::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalModEq => {
+                BinaryOperator::ModEq(TerminalModEq::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalAndEq => {
+                BinaryOperator::AndEq(TerminalAndEq::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalOrEq => {
+                BinaryOperator::OrEq(TerminalOrEq::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalXorEq => {
+                BinaryOperator::XorEq(TerminalXorEq::from_syntax_node(db, node))
+            }
             SyntaxKind::TerminalAnd => BinaryOperator::And(TerminalAnd::from_syntax_node(db, node)),
             SyntaxKind::TerminalOr => BinaryOperator::Or(TerminalOr::from_syntax_node(db, node)),
             SyntaxKind::TerminalXor => BinaryOperator::Xor(TerminalXor::from_syntax_node(db, node)),
+            SyntaxKind::TerminalShl => BinaryOperator::Shl(TerminalShl::from_syntax_node(db, node)),
+            SyntaxKind::TerminalShr => BinaryOperator::Shr(TerminalShr::from_syntax_node(db, node)),
             SyntaxKind::TerminalLE => BinaryOperator::LE(TerminalLE::from_syntax_node(db, node)),
             SyntaxKind::TerminalGE => BinaryOperator::GE(TerminalGE::from_syntax_node(db, node)),
             SyntaxKind::TerminalLT => BinaryOperator::LT(TerminalLT::from_syntax_node(db, node)),
@@ -1394,9 +1608,19 @@ impl TypedSyntaxNode for BinaryOperator {
             BinaryOperator::EqEq(x) => x.as_syntax_node(),
             BinaryOperator::Neq(x) => x.as_syntax_node(),
             BinaryOperator::Eq(x) => x.as_syntax_node(),
+            BinaryOperator::PlusEq(x) => x.as_syntax_node(),
+            BinaryOperator::MinusEq(x) => x.as_syntax_node(),
+            BinaryOperator::MulEq(x) => x.as_syntax_node(),
+            BinaryOperator::DivEq(x) => x.as_syntax_node(),
+            BinaryOperator::ModEq(x) => x.as_syntax_node(),
+            BinaryOperator::AndEq(x) => x.as_syntax_node(),
+            BinaryOperator::OrEq(x) => x.as_syntax_node(),
+            BinaryOperator::XorEq(x) => x.as_syntax_node(),
             BinaryOperator::And(x) => x.as_syntax_node(),
             BinaryOperator::Or(x) => x.as_syntax_node(),
             BinaryOperator::Xor(x) => x.as_syntax_node(),
+            BinaryOperator::Shl(x) => x.as_syntax_node(),
+            BinaryOperator::Shr(x) => x.as_syntax_node(),
             BinaryOperator::LE(x) => x.as_syntax_node(),
             BinaryOperator::GE(x) => x.as_syntax_node(),
             BinaryOperator::LT(x) => x.as_syntax_node(),
@@ -2145,9 +2369,117 @@ impl TypedSyntaxNode for ExprIf {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprIfLet {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprIfLet {
+    pub const INDEX_IF_KW: usize = 0;
+    pub const INDEX_LET_KW: usize = 1;
+    pub const INDEX_PATTERN: usize = 2;
+    pub const INDEX_EQ: usize = 3;
+    pub const INDEX_EXPR: usize = 4;
+    pub const INDEX_IF_BLOCK: usize = 5;
+    pub const INDEX_ELSE_CLAUSE: usize = 6;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        if_kw: TerminalIfGreen,
+        let_kw: TerminalLetGreen,
+        pattern: PatternGreen,
+        eq: TerminalEqGreen,
+        expr: ExprGreen,
+        if_block: ExprBlockGreen,
+        else_clause: OptionElseClauseGreen,
+    ) -> ExprIfLetGreen {
+        let children: Vec<GreenId> =
+            vec![if_kw.0, let_kw.0, pattern.0, eq.0, expr.0, if_block.0, else_clause.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprIfLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprIfLet,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprIfLet {
+    pub fn if_kw(&self, db: &dyn SyntaxGroup) -> TerminalIf {
+        TerminalIf::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn let_kw(&self, db: &dyn SyntaxGroup) -> TerminalLet {
+        TerminalLet::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
+        Pattern::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
+        TerminalEq::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn if_block(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn else_clause(&self, db: &dyn SyntaxGroup) -> OptionElseClause {
+        OptionElseClause::from_syntax_node(db, self.children[6].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprIfLetPtr(pub SyntaxStablePtrId);
+impl ExprIfLetPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprIfLetGreen(pub GreenId);
+impl TypedSyntaxNode for ExprIfLet {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprIfLet);
+    type StablePtr = ExprIfLetPtr;
+    type Green = ExprIfLetGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprIfLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprIfLet,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalIf::missing(db).0,
+                    TerminalLet::missing(db).0,
+                    Pattern::missing(db).0,
+                    TerminalEq::missing(db).0,
+                    Expr::missing(db).0,
+                    ExprBlock::missing(db).0,
+                    OptionElseClause::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprIfLet,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprIfLet
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprIfLetPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum BlockOrIf {
     Block(ExprBlock),
     If(ExprIf),
+    IfLet(ExprIfLet),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct BlockOrIfPtr(pub SyntaxStablePtrId);
@@ -2166,6 +2498,11 @@ impl From<ExprIfPtr> for BlockOrIfPtr {
         Self(value.0)
     }
 }
+impl From<ExprIfLetPtr> for BlockOrIfPtr {
+    fn from(value: ExprIfLetPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprBlockGreen> for BlockOrIfGreen {
     fn from(value: ExprBlockGreen) -> Self {
         Self(value.0)
@@ -2176,6 +2513,11 @@ impl From<ExprIfGreen> for BlockOrIfGreen {
         Self(value.0)
     }
 }
+impl From<ExprIfLetGreen> for BlockOrIfGreen {
+    fn from(value: ExprIfLetGreen) -> Self {
+        Self(value.0)
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct BlockOrIfGreen(pub GreenId);
 impl TypedSyntaxNode for BlockOrIf {
@@ -2190,6 +2532,7 @@ impl TypedSyntaxNode for BlockOrIf {
         match kind {
             SyntaxKind::ExprBlock => BlockOrIf::Block(ExprBlock::from_syntax_node(db, node)),
             SyntaxKind::ExprIf => BlockOrIf::If(ExprIf::from_syntax_node(db, node)),
+            SyntaxKind::ExprIfLet => BlockOrIf::IfLet(ExprIfLet::from_syntax_node(db, node)),
             _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "BlockOrIf"),
         }
     }
@@ -2197,6 +2540,7 @@ impl TypedSyntaxNode for BlockOrIf {
         match self {
             BlockOrIf::Block(x) => x.as_syntax_node(),
             BlockOrIf::If(x) => x.as_syntax_node(),
+            BlockOrIf::IfLet(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
@@ -2406,52 +2750,52 @@ impl TypedSyntaxNode for OptionElseClauseEmpty {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ExprErrorPropagate {
+pub struct ExprLoop {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ExprErrorPropagate {
-    pub const INDEX_EXPR: usize = 0;
-    pub const INDEX_OP: usize = 1;
+impl ExprLoop {
+    pub const INDEX_LOOP_KW: usize = 0;
+    pub const INDEX_BODY: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        expr: ExprGreen,
-        op: TerminalQuestionMarkGreen,
-    ) -> ExprErrorPropagateGreen {
-        let children: Vec<GreenId> = vec![expr.0, op.0];
+        loop_kw: TerminalLoopGreen,
+        body: ExprBlockGreen,
+    ) -> ExprLoopGreen {
+        let children: Vec<GreenId> = vec![loop_kw.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ExprErrorPropagateGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ExprErrorPropagate,
+        ExprLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprLoop,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ExprErrorPropagate {
-    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[0].clone())
+impl ExprLoop {
+    pub fn loop_kw(&self, db: &dyn SyntaxGroup) -> TerminalLoop {
+        TerminalLoop::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn op(&self, db: &dyn SyntaxGroup) -> TerminalQuestionMark {
-        TerminalQuestionMark::from_syntax_node(db, self.children[1].clone())
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ExprErrorPropagatePtr(pub SyntaxStablePtrId);
-impl ExprErrorPropagatePtr {
+pub struct ExprLoopPtr(pub SyntaxStablePtrId);
+impl ExprLoopPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ExprErrorPropagateGreen(pub GreenId);
-impl TypedSyntaxNode for ExprErrorPropagate {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprErrorPropagate);
-    type StablePtr = ExprErrorPropagatePtr;
-    type Green = ExprErrorPropagateGreen;
+pub struct ExprLoopGreen(pub GreenId);
+impl TypedSyntaxNode for ExprLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprLoop);
+    type StablePtr = ExprLoopPtr;
+    type Green = ExprLoopGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ExprErrorPropagateGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ExprErrorPropagate,
+        ExprLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprLoop,
             details: GreenNodeDetails::Node {
-                children: vec![Expr::missing(db).0, TerminalQuestionMark::missing(db).0],
+                children: vec![TerminalLoop::missing(db).0, ExprBlock::missing(db).0],
                 width: 0,
             },
         }))
@@ -2460,10 +2804,10 @@ impl TypedSyntaxNode for ExprErrorPropagate {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ExprErrorPropagate,
+            SyntaxKind::ExprLoop,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ExprErrorPropagate
+            SyntaxKind::ExprLoop
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -2475,68 +2819,77 @@ impl TypedSyntaxNode for ExprErrorPropagate {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ExprErrorPropagatePtr(self.node.0.stable_ptr)
+        ExprLoopPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StructArgExpr {
+pub struct ExprWhile {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl StructArgExpr {
-    pub const INDEX_COLON: usize = 0;
-    pub const INDEX_EXPR: usize = 1;
+impl ExprWhile {
+    pub const INDEX_WHILE_KW: usize = 0;
+    pub const INDEX_CONDITION: usize = 1;
+    pub const INDEX_BODY: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        colon: TerminalColonGreen,
-        expr: ExprGreen,
-    ) -> StructArgExprGreen {
-        let children: Vec<GreenId> = vec![colon.0, expr.0];
+        while_kw: TerminalWhileGreen,
+        condition: ExprGreen,
+        body: ExprBlockGreen,
+    ) -> ExprWhileGreen {
+        let children: Vec<GreenId> = vec![while_kw.0, condition.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StructArgExprGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgExpr,
+        ExprWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprWhile,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl StructArgExpr {
-    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
-        TerminalColon::from_syntax_node(db, self.children[0].clone())
+impl ExprWhile {
+    pub fn while_kw(&self, db: &dyn SyntaxGroup) -> TerminalWhile {
+        TerminalWhile::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+    pub fn condition(&self, db: &dyn SyntaxGroup) -> Expr {
         Expr::from_syntax_node(db, self.children[1].clone())
     }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[2].clone())
+    }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgExprPtr(pub SyntaxStablePtrId);
-impl StructArgExprPtr {
+pub struct ExprWhilePtr(pub SyntaxStablePtrId);
+impl ExprWhilePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgExprGreen(pub GreenId);
-impl TypedSyntaxNode for StructArgExpr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgExpr);
-    type StablePtr = StructArgExprPtr;
-    type Green = StructArgExprGreen;
+pub struct ExprWhileGreen(pub GreenId);
+impl TypedSyntaxNode for ExprWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprWhile);
+    type StablePtr = ExprWhilePtr;
+    type Green = ExprWhileGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StructArgExprGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgExpr,
+        ExprWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprWhile,
             details: GreenNodeDetails::Node {
-                children: vec![TerminalColon::missing(db).0, Expr::missing(db).0],
-                width: 0,
-            },
-        }))
+                children: vec![
+                    TerminalWhile::missing(db).0,
+                    Expr::missing(db).0,
+                    ExprBlock::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::StructArgExpr,
+            SyntaxKind::ExprWhile,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::StructArgExpr
+            SyntaxKind::ExprWhile
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -2548,121 +2901,68 @@ impl TypedSyntaxNode for StructArgExpr {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StructArgExprPtr(self.node.0.stable_ptr)
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionStructArgExpr {
-    Empty(OptionStructArgExprEmpty),
-    StructArgExpr(StructArgExpr),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprPtr(pub SyntaxStablePtrId);
-impl OptionStructArgExprPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-impl From<OptionStructArgExprEmptyPtr> for OptionStructArgExprPtr {
-    fn from(value: OptionStructArgExprEmptyPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<StructArgExprPtr> for OptionStructArgExprPtr {
-    fn from(value: StructArgExprPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<OptionStructArgExprEmptyGreen> for OptionStructArgExprGreen {
-    fn from(value: OptionStructArgExprEmptyGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<StructArgExprGreen> for OptionStructArgExprGreen {
-    fn from(value: StructArgExprGreen) -> Self {
-        Self(value.0)
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprGreen(pub GreenId);
-impl TypedSyntaxNode for OptionStructArgExpr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionStructArgExprPtr;
-    type Green = OptionStructArgExprGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::OptionStructArgExprEmpty => {
-                OptionStructArgExpr::Empty(OptionStructArgExprEmpty::from_syntax_node(db, node))
-            }
-            SyntaxKind::StructArgExpr => {
-                OptionStructArgExpr::StructArgExpr(StructArgExpr::from_syntax_node(db, node))
-            }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionStructArgExpr"
-            ),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            OptionStructArgExpr::Empty(x) => x.as_syntax_node(),
-            OptionStructArgExpr::StructArgExpr(x) => x.as_syntax_node(),
-        }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        OptionStructArgExprPtr(self.as_syntax_node().0.stable_ptr)
+        ExprWhilePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionStructArgExprEmpty {
+pub struct ExprErrorPropagate {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl OptionStructArgExprEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionStructArgExprEmptyGreen {
-        let children: Vec<GreenId> = vec![];
+impl ExprErrorPropagate {
+    pub const INDEX_EXPR: usize = 0;
+    pub const INDEX_OP: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        expr: ExprGreen,
+        op: TerminalQuestionMarkGreen,
+    ) -> ExprErrorPropagateGreen {
+        let children: Vec<GreenId> = vec![expr.0, op.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionStructArgExprEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionStructArgExprEmpty,
+        ExprErrorPropagateGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprErrorPropagate,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl OptionStructArgExprEmpty {}
+impl ExprErrorPropagate {
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn op(&self, db: &dyn SyntaxGroup) -> TerminalQuestionMark {
+        TerminalQuestionMark::from_syntax_node(db, self.children[1].clone())
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprEmptyPtr(pub SyntaxStablePtrId);
-impl OptionStructArgExprEmptyPtr {
+pub struct ExprErrorPropagatePtr(pub SyntaxStablePtrId);
+impl ExprErrorPropagatePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionStructArgExprEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionStructArgExprEmpty);
-    type StablePtr = OptionStructArgExprEmptyPtr;
-    type Green = OptionStructArgExprEmptyGreen;
+pub struct ExprErrorPropagateGreen(pub GreenId);
+impl TypedSyntaxNode for ExprErrorPropagate {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprErrorPropagate);
+    type StablePtr = ExprErrorPropagatePtr;
+    type Green = ExprErrorPropagateGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionStructArgExprEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionStructArgExprEmpty,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        ExprErrorPropagateGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprErrorPropagate,
+            details: GreenNodeDetails::Node {
+                children: vec![Expr::missing(db).0, TerminalQuestionMark::missing(db).0],
+                width: 0,
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::OptionStructArgExprEmpty,
+            SyntaxKind::ExprErrorPropagate,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::OptionStructArgExprEmpty
+            SyntaxKind::ExprErrorPropagate
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -2674,66 +2974,70 @@ impl TypedSyntaxNode for OptionStructArgExprEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionStructArgExprEmptyPtr(self.node.0.stable_ptr)
+        ExprErrorPropagatePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StructArgSingle {
+pub struct ExprIndexed {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl StructArgSingle {
-    pub const INDEX_IDENTIFIER: usize = 0;
-    pub const INDEX_ARG_EXPR: usize = 1;
+impl ExprIndexed {
+    pub const INDEX_EXPR: usize = 0;
+    pub const INDEX_LBRACK: usize = 1;
+    pub const INDEX_INDEX_EXPR: usize = 2;
+    pub const INDEX_RBRACK: usize = 3;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        identifier: TerminalIdentifierGreen,
-        arg_expr: OptionStructArgExprGreen,
-    ) -> StructArgSingleGreen {
-        let children: Vec<GreenId> = vec![identifier.0, arg_expr.0];
+        expr: ExprGreen,
+        lbrack: TerminalLBrackGreen,
+        index_expr: ExprGreen,
+        rbrack: TerminalRBrackGreen,
+    ) -> ExprIndexedGreen {
+        let children: Vec<GreenId> = vec![expr.0, lbrack.0, index_expr.0, rbrack.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StructArgSingleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgSingle,
+        ExprIndexedGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprIndexed,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl StructArgSingle {
-    pub fn identifier(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+impl ExprIndexed {
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn arg_expr(&self, db: &dyn SyntaxGroup) -> OptionStructArgExpr {
-        OptionStructArgExpr::from_syntax_node(db, self.children[1].clone())
+    pub fn lbrack(&self, db: &dyn SyntaxGroup) -> TerminalLBrack {
+        TerminalLBrack::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn index_expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn rbrack(&self, db: &dyn SyntaxGroup) -> TerminalRBrack {
+        TerminalRBrack::from_syntax_node(db, self.children[3].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgSinglePtr(pub SyntaxStablePtrId);
-impl StructArgSinglePtr {
-    pub fn identifier_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct ExprIndexedPtr(pub SyntaxStablePtrId);
+impl ExprIndexedPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgSingleGreen(pub GreenId);
-impl TypedSyntaxNode for StructArgSingle {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgSingle);
-    type StablePtr = StructArgSinglePtr;
-    type Green = StructArgSingleGreen;
+pub struct ExprIndexedGreen(pub GreenId);
+impl TypedSyntaxNode for ExprIndexed {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprIndexed);
+    type StablePtr = ExprIndexedPtr;
+    type Green = ExprIndexedGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StructArgSingleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgSingle,
+        ExprIndexedGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprIndexed,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalIdentifier::missing(db).0,
-                    OptionStructArgExpr::missing(db).0,
+                    Expr::missing(db).0,
+                    TerminalLBrack::missing(db).0,
+                    Expr::missing(db).0,
+                    TerminalRBrack::missing(db).0,
                 ],
                 width: 0,
             },
@@ -2743,10 +3047,10 @@ impl TypedSyntaxNode for StructArgSingle {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::StructArgSingle,
+            SyntaxKind::ExprIndexed,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::StructArgSingle
+            SyntaxKind::ExprIndexed
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -2758,56 +3062,56 @@ impl TypedSyntaxNode for StructArgSingle {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StructArgSinglePtr(self.node.0.stable_ptr)
+        ExprIndexedPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StructArgTail {
+pub struct ExprClosure {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl StructArgTail {
-    pub const INDEX_DOTDOT: usize = 0;
-    pub const INDEX_EXPRESSION: usize = 1;
+impl ExprClosure {
+    pub const INDEX_PARAMS: usize = 0;
+    pub const INDEX_BODY: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        dotdot: TerminalDotDotGreen,
-        expression: ExprGreen,
-    ) -> StructArgTailGreen {
-        let children: Vec<GreenId> = vec![dotdot.0, expression.0];
+        params: ClosureParamsGreen,
+        body: ExprGreen,
+    ) -> ExprClosureGreen {
+        let children: Vec<GreenId> = vec![params.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StructArgTailGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgTail,
+        ExprClosureGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClosure,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl StructArgTail {
-    pub fn dotdot(&self, db: &dyn SyntaxGroup) -> TerminalDotDot {
-        TerminalDotDot::from_syntax_node(db, self.children[0].clone())
+impl ExprClosure {
+    pub fn params(&self, db: &dyn SyntaxGroup) -> ClosureParams {
+        ClosureParams::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn expression(&self, db: &dyn SyntaxGroup) -> Expr {
+    pub fn body(&self, db: &dyn SyntaxGroup) -> Expr {
         Expr::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgTailPtr(pub SyntaxStablePtrId);
-impl StructArgTailPtr {
+pub struct ExprClosurePtr(pub SyntaxStablePtrId);
+impl ExprClosurePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgTailGreen(pub GreenId);
-impl TypedSyntaxNode for StructArgTail {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgTail);
-    type StablePtr = StructArgTailPtr;
-    type Green = StructArgTailGreen;
+pub struct ExprClosureGreen(pub GreenId);
+impl TypedSyntaxNode for ExprClosure {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprClosure);
+    type StablePtr = ExprClosurePtr;
+    type Green = ExprClosureGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StructArgTailGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgTail,
+        ExprClosureGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClosure,
             details: GreenNodeDetails::Node {
-                children: vec![TerminalDotDot::missing(db).0, Expr::missing(db).0],
+                children: vec![ClosureParams::missing(db).0, Expr::missing(db).0],
                 width: 0,
             },
         }))
@@ -2816,10 +3120,10 @@ impl TypedSyntaxNode for StructArgTail {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::StructArgTail,
+            SyntaxKind::ExprClosure,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::StructArgTail
+            SyntaxKind::ExprClosure
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -2831,209 +3135,204 @@ impl TypedSyntaxNode for StructArgTail {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StructArgTailPtr(self.node.0.stable_ptr)
+        ExprClosurePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum StructArg {
-    StructArgSingle(StructArgSingle),
-    StructArgTail(StructArgTail),
+pub enum ClosureParams {
+    Empty(TerminalOrOr),
+    Wrapped(ClosureParamsWrapped),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgPtr(pub SyntaxStablePtrId);
-impl StructArgPtr {
+pub struct ClosureParamsPtr(pub SyntaxStablePtrId);
+impl ClosureParamsPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<StructArgSinglePtr> for StructArgPtr {
-    fn from(value: StructArgSinglePtr) -> Self {
+impl From<TerminalOrOrPtr> for ClosureParamsPtr {
+    fn from(value: TerminalOrOrPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<StructArgTailPtr> for StructArgPtr {
-    fn from(value: StructArgTailPtr) -> Self {
+impl From<ClosureParamsWrappedPtr> for ClosureParamsPtr {
+    fn from(value: ClosureParamsWrappedPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<StructArgSingleGreen> for StructArgGreen {
-    fn from(value: StructArgSingleGreen) -> Self {
+impl From<TerminalOrOrGreen> for ClosureParamsGreen {
+    fn from(value: TerminalOrOrGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<StructArgTailGreen> for StructArgGreen {
-    fn from(value: StructArgTailGreen) -> Self {
+impl From<ClosureParamsWrappedGreen> for ClosureParamsGreen {
+    fn from(value: ClosureParamsWrappedGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgGreen(pub GreenId);
-impl TypedSyntaxNode for StructArg {
+pub struct ClosureParamsGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParams {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = StructArgPtr;
-    type Green = StructArgGreen;
+    type StablePtr = ClosureParamsPtr;
+    type Green = ClosureParamsGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::StructArgSingle => {
-                StructArg::StructArgSingle(StructArgSingle::from_syntax_node(db, node))
+            SyntaxKind::TerminalOrOr => {
+                ClosureParams::Empty(TerminalOrOr::from_syntax_node(db, node))
             }
-            SyntaxKind::StructArgTail => {
-                StructArg::StructArgTail(StructArgTail::from_syntax_node(db, node))
+            SyntaxKind::ClosureParamsWrapped => {
+                ClosureParams::Wrapped(ClosureParamsWrapped::from_syntax_node(db, node))
             }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "StructArg"),
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "ClosureParams"),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            StructArg::StructArgSingle(x) => x.as_syntax_node(),
-            StructArg::StructArgTail(x) => x.as_syntax_node(),
+            ClosureParams::Empty(x) => x.as_syntax_node(),
+            ClosureParams::Wrapped(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StructArgPtr(self.as_syntax_node().0.stable_ptr)
+        ClosureParamsPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StructArgList(ElementList<StructArg, 2>);
-impl Deref for StructArgList {
-    type Target = ElementList<StructArg, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct ClosureParamsWrapped {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl StructArgList {
+impl ClosureParamsWrapped {
+    pub const INDEX_LOR: usize = 0;
+    pub const INDEX_PARAMS: usize = 1;
+    pub const INDEX_ROR: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        children: Vec<StructArgListElementOrSeparatorGreen>,
-    ) -> StructArgListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        StructArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
+        lor: TerminalOrGreen,
+        params: ParamListGreen,
+        ror: TerminalOrGreen,
+    ) -> ClosureParamsWrappedGreen {
+        let children: Vec<GreenId> = vec![lor.0, params.0, ror.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ClosureParamsWrappedGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ClosureParamsWrapped,
+            details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgListPtr(pub SyntaxStablePtrId);
-impl StructArgListPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl ClosureParamsWrapped {
+    pub fn lor(&self, db: &dyn SyntaxGroup) -> TerminalOr {
+        TerminalOr::from_syntax_node(db, self.children[0].clone())
     }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum StructArgListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(StructArgGreen),
-}
-impl From<TerminalCommaGreen> for StructArgListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        StructArgListElementOrSeparatorGreen::Separator(value)
+    pub fn params(&self, db: &dyn SyntaxGroup) -> ParamList {
+        ParamList::from_syntax_node(db, self.children[1].clone())
     }
-}
-impl From<StructArgGreen> for StructArgListElementOrSeparatorGreen {
-    fn from(value: StructArgGreen) -> Self {
-        StructArgListElementOrSeparatorGreen::Element(value)
+    pub fn ror(&self, db: &dyn SyntaxGroup) -> TerminalOr {
+        TerminalOr::from_syntax_node(db, self.children[2].clone())
     }
 }
-impl StructArgListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            StructArgListElementOrSeparatorGreen::Separator(green) => green.0,
-            StructArgListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamsWrappedPtr(pub SyntaxStablePtrId);
+impl ClosureParamsWrappedPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgListGreen(pub GreenId);
-impl TypedSyntaxNode for StructArgList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgList);
-    type StablePtr = StructArgListPtr;
-    type Green = StructArgListGreen;
+pub struct ClosureParamsWrappedGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParamsWrapped {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ClosureParamsWrapped);
+    type StablePtr = ClosureParamsWrappedPtr;
+    type Green = ClosureParamsWrappedGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StructArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        ClosureParamsWrappedGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ClosureParamsWrapped,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalOr::missing(db).0,
+                    ParamList::missing(db).0,
+                    TerminalOr::missing(db).0,
+                ],
+                width: 0,
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ClosureParamsWrapped,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ClosureParamsWrapped
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StructArgListPtr(self.node.0.stable_ptr)
+        ClosureParamsWrappedPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ArgListBraced {
+pub struct StructArgExpr {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ArgListBraced {
-    pub const INDEX_LBRACE: usize = 0;
-    pub const INDEX_ARGUMENTS: usize = 1;
-    pub const INDEX_RBRACE: usize = 2;
+impl StructArgExpr {
+    pub const INDEX_COLON: usize = 0;
+    pub const INDEX_EXPR: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        lbrace: TerminalLBraceGreen,
-        arguments: StructArgListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ArgListBracedGreen {
-        let children: Vec<GreenId> = vec![lbrace.0, arguments.0, rbrace.0];
+        colon: TerminalColonGreen,
+        expr: ExprGreen,
+    ) -> StructArgExprGreen {
+        let children: Vec<GreenId> = vec![colon.0, expr.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ArgListBracedGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ArgListBraced,
+        StructArgExprGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgExpr,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ArgListBraced {
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn arguments(&self, db: &dyn SyntaxGroup) -> StructArgList {
-        StructArgList::from_syntax_node(db, self.children[1].clone())
+impl StructArgExpr {
+    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
+        TerminalColon::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ArgListBracedPtr(pub SyntaxStablePtrId);
-impl ArgListBracedPtr {
+pub struct StructArgExprPtr(pub SyntaxStablePtrId);
+impl StructArgExprPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ArgListBracedGreen(pub GreenId);
-impl TypedSyntaxNode for ArgListBraced {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ArgListBraced);
-    type StablePtr = ArgListBracedPtr;
-    type Green = ArgListBracedGreen;
+pub struct StructArgExprGreen(pub GreenId);
+impl TypedSyntaxNode for StructArgExpr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgExpr);
+    type StablePtr = StructArgExprPtr;
+    type Green = StructArgExprGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ArgListBracedGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ArgListBraced,
+        StructArgExprGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgExpr,
             details: GreenNodeDetails::Node {
-                children: vec![
-                    TerminalLBrace::missing(db).0,
-                    StructArgList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
-                ],
+                children: vec![TerminalColon::missing(db).0, Expr::missing(db).0],
                 width: 0,
             },
         }))
@@ -3042,10 +3341,10 @@ impl TypedSyntaxNode for ArgListBraced {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ArgListBraced,
+            SyntaxKind::StructArgExpr,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ArgListBraced
+            SyntaxKind::StructArgExpr
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -3057,190 +3356,168 @@ impl TypedSyntaxNode for ArgListBraced {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ArgListBracedPtr(self.node.0.stable_ptr)
+        StructArgExprPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum Pattern {
-    Underscore(TerminalUnderscore),
-    Literal(TerminalLiteralNumber),
-    ShortString(TerminalShortString),
-    Identifier(PatternIdentifier),
-    Struct(PatternStruct),
-    Tuple(PatternTuple),
-    Enum(PatternEnum),
-    Path(ExprPath),
+pub enum OptionStructArgExpr {
+    Empty(OptionStructArgExprEmpty),
+    StructArgExpr(StructArgExpr),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternPtr(pub SyntaxStablePtrId);
-impl PatternPtr {
+pub struct OptionStructArgExprPtr(pub SyntaxStablePtrId);
+impl OptionStructArgExprPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<TerminalUnderscorePtr> for PatternPtr {
-    fn from(value: TerminalUnderscorePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalLiteralNumberPtr> for PatternPtr {
-    fn from(value: TerminalLiteralNumberPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalShortStringPtr> for PatternPtr {
-    fn from(value: TerminalShortStringPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<PatternIdentifierPtr> for PatternPtr {
-    fn from(value: PatternIdentifierPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<PatternStructPtr> for PatternPtr {
-    fn from(value: PatternStructPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<PatternTuplePtr> for PatternPtr {
-    fn from(value: PatternTuplePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<PatternEnumPtr> for PatternPtr {
-    fn from(value: PatternEnumPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ExprPathPtr> for PatternPtr {
-    fn from(value: ExprPathPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalUnderscoreGreen> for PatternGreen {
-    fn from(value: TerminalUnderscoreGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalLiteralNumberGreen> for PatternGreen {
-    fn from(value: TerminalLiteralNumberGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalShortStringGreen> for PatternGreen {
-    fn from(value: TerminalShortStringGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<PatternIdentifierGreen> for PatternGreen {
-    fn from(value: PatternIdentifierGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<PatternStructGreen> for PatternGreen {
-    fn from(value: PatternStructGreen) -> Self {
+impl From<OptionStructArgExprEmptyPtr> for OptionStructArgExprPtr {
+    fn from(value: OptionStructArgExprEmptyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<PatternTupleGreen> for PatternGreen {
-    fn from(value: PatternTupleGreen) -> Self {
+impl From<StructArgExprPtr> for OptionStructArgExprPtr {
+    fn from(value: StructArgExprPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<PatternEnumGreen> for PatternGreen {
-    fn from(value: PatternEnumGreen) -> Self {
+impl From<OptionStructArgExprEmptyGreen> for OptionStructArgExprGreen {
+    fn from(value: OptionStructArgExprEmptyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<ExprPathGreen> for PatternGreen {
-    fn from(value: ExprPathGreen) -> Self {
+impl From<StructArgExprGreen> for OptionStructArgExprGreen {
+    fn from(value: StructArgExprGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternGreen(pub GreenId);
-impl TypedSyntaxNode for Pattern {
+pub struct OptionStructArgExprGreen(pub GreenId);
+impl TypedSyntaxNode for OptionStructArgExpr {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = PatternPtr;
-    type Green = PatternGreen;
+    type StablePtr = OptionStructArgExprPtr;
+    type Green = OptionStructArgExprGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::TerminalUnderscore => {
-                Pattern::Underscore(TerminalUnderscore::from_syntax_node(db, node))
-            }
-            SyntaxKind::TerminalLiteralNumber => {
-                Pattern::Literal(TerminalLiteralNumber::from_syntax_node(db, node))
-            }
-            SyntaxKind::TerminalShortString => {
-                Pattern::ShortString(TerminalShortString::from_syntax_node(db, node))
+            SyntaxKind::OptionStructArgExprEmpty => {
+                OptionStructArgExpr::Empty(OptionStructArgExprEmpty::from_syntax_node(db, node))
             }
-            SyntaxKind::PatternIdentifier => {
-                Pattern::Identifier(PatternIdentifier::from_syntax_node(db, node))
+            SyntaxKind::StructArgExpr => {
+                OptionStructArgExpr::StructArgExpr(StructArgExpr::from_syntax_node(db, node))
             }
-            SyntaxKind::PatternStruct => Pattern::Struct(PatternStruct::from_syntax_node(db, node)),
-            SyntaxKind::PatternTuple => Pattern::Tuple(PatternTuple::from_syntax_node(db, node)),
-            SyntaxKind::PatternEnum => Pattern::Enum(PatternEnum::from_syntax_node(db, node)),
-            SyntaxKind::ExprPath => Pattern::Path(ExprPath::from_syntax_node(db, node)),
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Pattern"),
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionStructArgExpr"
+            ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            Pattern::Underscore(x) => x.as_syntax_node(),
-            Pattern::Literal(x) => x.as_syntax_node(),
-            Pattern::ShortString(x) => x.as_syntax_node(),
-            Pattern::Identifier(x) => x.as_syntax_node(),
-            Pattern::Struct(x) => x.as_syntax_node(),
-            Pattern::Tuple(x) => x.as_syntax_node(),
-            Pattern::Enum(x) => x.as_syntax_node(),
-            Pattern::Path(x) => x.as_syntax_node(),
+            OptionStructArgExpr::Empty(x) => x.as_syntax_node(),
+            OptionStructArgExpr::StructArgExpr(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        PatternPtr(self.as_syntax_node().0.stable_ptr)
+        OptionStructArgExprPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct PatternIdentifier {
+pub struct OptionStructArgExprEmpty {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl PatternIdentifier {
-    pub const INDEX_MODIFIERS: usize = 0;
-    pub const INDEX_NAME: usize = 1;
+impl OptionStructArgExprEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionStructArgExprEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionStructArgExprEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionStructArgExprEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionStructArgExprEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionStructArgExprEmptyPtr(pub SyntaxStablePtrId);
+impl OptionStructArgExprEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionStructArgExprEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionStructArgExprEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionStructArgExprEmpty);
+    type StablePtr = OptionStructArgExprEmptyPtr;
+    type Green = OptionStructArgExprEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionStructArgExprEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionStructArgExprEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionStructArgExprEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionStructArgExprEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionStructArgExprEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StructArgSingle {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl StructArgSingle {
+    pub const INDEX_IDENTIFIER: usize = 0;
+    pub const INDEX_ARG_EXPR: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        modifiers: ModifierListGreen,
-        name: TerminalIdentifierGreen,
-    ) -> PatternIdentifierGreen {
-        let children: Vec<GreenId> = vec![modifiers.0, name.0];
+        identifier: TerminalIdentifierGreen,
+        arg_expr: OptionStructArgExprGreen,
+    ) -> StructArgSingleGreen {
+        let children: Vec<GreenId> = vec![identifier.0, arg_expr.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        PatternIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternIdentifier,
+        StructArgSingleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgSingle,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl PatternIdentifier {
-    pub fn modifiers(&self, db: &dyn SyntaxGroup) -> ModifierList {
-        ModifierList::from_syntax_node(db, self.children[0].clone())
+impl StructArgSingle {
+    pub fn identifier(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+    pub fn arg_expr(&self, db: &dyn SyntaxGroup) -> OptionStructArgExpr {
+        OptionStructArgExpr::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternIdentifierPtr(pub SyntaxStablePtrId);
-impl PatternIdentifierPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+pub struct StructArgSinglePtr(pub SyntaxStablePtrId);
+impl StructArgSinglePtr {
+    pub fn identifier_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
             TerminalIdentifierGreen(key_fields[0])
@@ -3253,16 +3530,19 @@ impl PatternIdentifierPtr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternIdentifierGreen(pub GreenId);
-impl TypedSyntaxNode for PatternIdentifier {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternIdentifier);
-    type StablePtr = PatternIdentifierPtr;
-    type Green = PatternIdentifierGreen;
+pub struct StructArgSingleGreen(pub GreenId);
+impl TypedSyntaxNode for StructArgSingle {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgSingle);
+    type StablePtr = StructArgSinglePtr;
+    type Green = StructArgSingleGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        PatternIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternIdentifier,
+        StructArgSingleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgSingle,
             details: GreenNodeDetails::Node {
-                children: vec![ModifierList::missing(db).0, TerminalIdentifier::missing(db).0],
+                children: vec![
+                    TerminalIdentifier::missing(db).0,
+                    OptionStructArgExpr::missing(db).0,
+                ],
                 width: 0,
             },
         }))
@@ -3271,10 +3551,10 @@ impl TypedSyntaxNode for PatternIdentifier {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::PatternIdentifier,
+            SyntaxKind::StructArgSingle,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::PatternIdentifier
+            SyntaxKind::StructArgSingle
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -3286,71 +3566,56 @@ impl TypedSyntaxNode for PatternIdentifier {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        PatternIdentifierPtr(self.node.0.stable_ptr)
+        StructArgSinglePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct PatternStruct {
+pub struct StructArgTail {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl PatternStruct {
-    pub const INDEX_PATH: usize = 0;
-    pub const INDEX_LBRACE: usize = 1;
-    pub const INDEX_PARAMS: usize = 2;
-    pub const INDEX_RBRACE: usize = 3;
+impl StructArgTail {
+    pub const INDEX_DOTDOT: usize = 0;
+    pub const INDEX_EXPRESSION: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        path: ExprPathGreen,
-        lbrace: TerminalLBraceGreen,
-        params: PatternStructParamListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> PatternStructGreen {
-        let children: Vec<GreenId> = vec![path.0, lbrace.0, params.0, rbrace.0];
+        dotdot: TerminalDotDotGreen,
+        expression: ExprGreen,
+    ) -> StructArgTailGreen {
+        let children: Vec<GreenId> = vec![dotdot.0, expression.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        PatternStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternStruct,
+        StructArgTailGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgTail,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl PatternStruct {
-    pub fn path(&self, db: &dyn SyntaxGroup) -> ExprPath {
-        ExprPath::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn params(&self, db: &dyn SyntaxGroup) -> PatternStructParamList {
-        PatternStructParamList::from_syntax_node(db, self.children[2].clone())
+impl StructArgTail {
+    pub fn dotdot(&self, db: &dyn SyntaxGroup) -> TerminalDotDot {
+        TerminalDotDot::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[3].clone())
+    pub fn expression(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternStructPtr(pub SyntaxStablePtrId);
-impl PatternStructPtr {
+pub struct StructArgTailPtr(pub SyntaxStablePtrId);
+impl StructArgTailPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternStructGreen(pub GreenId);
-impl TypedSyntaxNode for PatternStruct {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternStruct);
-    type StablePtr = PatternStructPtr;
-    type Green = PatternStructGreen;
+pub struct StructArgTailGreen(pub GreenId);
+impl TypedSyntaxNode for StructArgTail {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgTail);
+    type StablePtr = StructArgTailPtr;
+    type Green = StructArgTailGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        PatternStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternStruct,
+        StructArgTailGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgTail,
             details: GreenNodeDetails::Node {
-                children: vec![
-                    ExprPath::missing(db).0,
-                    TerminalLBrace::missing(db).0,
-                    PatternStructParamList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
-                ],
+                children: vec![TerminalDotDot::missing(db).0, Expr::missing(db).0],
                 width: 0,
             },
         }))
@@ -3359,10 +3624,10 @@ impl TypedSyntaxNode for PatternStruct {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::PatternStruct,
+            SyntaxKind::StructArgTail,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::PatternStruct
+            SyntaxKind::StructArgTail
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -3374,71 +3639,137 @@ impl TypedSyntaxNode for PatternStruct {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        PatternStructPtr(self.node.0.stable_ptr)
+        StructArgTailPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct PatternStructParamList(ElementList<PatternStructParam, 2>);
-impl Deref for PatternStructParamList {
-    type Target = ElementList<PatternStructParam, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-impl PatternStructParamList {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        children: Vec<PatternStructParamListElementOrSeparatorGreen>,
-    ) -> PatternStructParamListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        PatternStructParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternStructParamList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
-        }))
-    }
+pub enum StructArg {
+    StructArgSingle(StructArgSingle),
+    StructArgTail(StructArgTail),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternStructParamListPtr(pub SyntaxStablePtrId);
-impl PatternStructParamListPtr {
+pub struct StructArgPtr(pub SyntaxStablePtrId);
+impl StructArgPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum PatternStructParamListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(PatternStructParamGreen),
+impl From<StructArgSinglePtr> for StructArgPtr {
+    fn from(value: StructArgSinglePtr) -> Self {
+        Self(value.0)
+    }
 }
-impl From<TerminalCommaGreen> for PatternStructParamListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        PatternStructParamListElementOrSeparatorGreen::Separator(value)
+impl From<StructArgTailPtr> for StructArgPtr {
+    fn from(value: StructArgTailPtr) -> Self {
+        Self(value.0)
     }
 }
-impl From<PatternStructParamGreen> for PatternStructParamListElementOrSeparatorGreen {
-    fn from(value: PatternStructParamGreen) -> Self {
-        PatternStructParamListElementOrSeparatorGreen::Element(value)
+impl From<StructArgSingleGreen> for StructArgGreen {
+    fn from(value: StructArgSingleGreen) -> Self {
+        Self(value.0)
     }
 }
-impl PatternStructParamListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            PatternStructParamListElementOrSeparatorGreen::Separator(green) => green.0,
-            PatternStructParamListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+impl From<StructArgTailGreen> for StructArgGreen {
+    fn from(value: StructArgTailGreen) -> Self {
+        Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternStructParamListGreen(pub GreenId);
-impl TypedSyntaxNode for PatternStructParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternStructParamList);
-    type StablePtr = PatternStructParamListPtr;
-    type Green = PatternStructParamListGreen;
+pub struct StructArgGreen(pub GreenId);
+impl TypedSyntaxNode for StructArg {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = StructArgPtr;
+    type Green = StructArgGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        PatternStructParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternStructParamList,
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::StructArgSingle => {
+                StructArg::StructArgSingle(StructArgSingle::from_syntax_node(db, node))
+            }
+            SyntaxKind::StructArgTail => {
+                StructArg::StructArgTail(StructArgTail::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "StructArg"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            StructArg::StructArgSingle(x) => x.as_syntax_node(),
+            StructArg::StructArgTail(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StructArgPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StructArgList(ElementList<StructArg, 2>);
+impl Deref for StructArgList {
+    type Target = ElementList<StructArg, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl StructArgList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<StructArgListElementOrSeparatorGreen>,
+    ) -> StructArgListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        StructArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructArgListPtr(pub SyntaxStablePtrId);
+impl StructArgListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum StructArgListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(StructArgGreen),
+}
+impl From<TerminalCommaGreen> for StructArgListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        StructArgListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<StructArgGreen> for StructArgListElementOrSeparatorGreen {
+    fn from(value: StructArgGreen) -> Self {
+        StructArgListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl StructArgListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            StructArgListElementOrSeparatorGreen::Separator(green) => green.0,
+            StructArgListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructArgListGreen(pub GreenId);
+impl TypedSyntaxNode for StructArgList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgList);
+    type StablePtr = StructArgListPtr;
+    type Green = StructArgListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StructArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgList,
             details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
@@ -3452,64 +3783,64 @@ impl TypedSyntaxNode for PatternStructParamList {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        PatternStructParamListPtr(self.node.0.stable_ptr)
+        StructArgListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct PatternTuple {
+pub struct ArgListBraced {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl PatternTuple {
-    pub const INDEX_LPAREN: usize = 0;
-    pub const INDEX_PATTERNS: usize = 1;
-    pub const INDEX_RPAREN: usize = 2;
+impl ArgListBraced {
+    pub const INDEX_LBRACE: usize = 0;
+    pub const INDEX_ARGUMENTS: usize = 1;
+    pub const INDEX_RBRACE: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        lparen: TerminalLParenGreen,
-        patterns: PatternListGreen,
-        rparen: TerminalRParenGreen,
-    ) -> PatternTupleGreen {
-        let children: Vec<GreenId> = vec![lparen.0, patterns.0, rparen.0];
+        lbrace: TerminalLBraceGreen,
+        arguments: StructArgListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ArgListBracedGreen {
+        let children: Vec<GreenId> = vec![lbrace.0, arguments.0, rbrace.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        PatternTupleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternTuple,
+        ArgListBracedGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ArgListBraced,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl PatternTuple {
-    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
-        TerminalLParen::from_syntax_node(db, self.children[0].clone())
+impl ArgListBraced {
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn patterns(&self, db: &dyn SyntaxGroup) -> PatternList {
-        PatternList::from_syntax_node(db, self.children[1].clone())
+    pub fn arguments(&self, db: &dyn SyntaxGroup) -> StructArgList {
+        StructArgList::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
-        TerminalRParen::from_syntax_node(db, self.children[2].clone())
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternTuplePtr(pub SyntaxStablePtrId);
-impl PatternTuplePtr {
+pub struct ArgListBracedPtr(pub SyntaxStablePtrId);
+impl ArgListBracedPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternTupleGreen(pub GreenId);
-impl TypedSyntaxNode for PatternTuple {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternTuple);
-    type StablePtr = PatternTuplePtr;
-    type Green = PatternTupleGreen;
+pub struct ArgListBracedGreen(pub GreenId);
+impl TypedSyntaxNode for ArgListBraced {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ArgListBraced);
+    type StablePtr = ArgListBracedPtr;
+    type Green = ArgListBracedGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        PatternTupleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternTuple,
+        ArgListBracedGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ArgListBraced,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLParen::missing(db).0,
-                    PatternList::missing(db).0,
-                    TerminalRParen::missing(db).0,
+                    TerminalLBrace::missing(db).0,
+                    StructArgList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
                 ],
                 width: 0,
             },
@@ -3519,10 +3850,10 @@ impl TypedSyntaxNode for PatternTuple {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::PatternTuple,
+            SyntaxKind::ArgListBraced,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::PatternTuple
+            SyntaxKind::ArgListBraced
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -3534,227 +3865,238 @@ impl TypedSyntaxNode for PatternTuple {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        PatternTuplePtr(self.node.0.stable_ptr)
+        ArgListBracedPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct PatternList(ElementList<Pattern, 2>);
-impl Deref for PatternList {
-    type Target = ElementList<Pattern, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-impl PatternList {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        children: Vec<PatternListElementOrSeparatorGreen>,
-    ) -> PatternListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        PatternListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
-        }))
-    }
+pub enum Pattern {
+    Underscore(TerminalUnderscore),
+    Literal(TerminalLiteralNumber),
+    ShortString(TerminalShortString),
+    True(TerminalTrue),
+    False(TerminalFalse),
+    Identifier(PatternIdentifier),
+    Struct(PatternStruct),
+    Tuple(PatternTuple),
+    Enum(PatternEnum),
+    Path(ExprPath),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternListPtr(pub SyntaxStablePtrId);
-impl PatternListPtr {
+pub struct PatternPtr(pub SyntaxStablePtrId);
+impl PatternPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum PatternListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(PatternGreen),
-}
-impl From<TerminalCommaGreen> for PatternListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        PatternListElementOrSeparatorGreen::Separator(value)
+impl From<TerminalUnderscorePtr> for PatternPtr {
+    fn from(value: TerminalUnderscorePtr) -> Self {
+        Self(value.0)
     }
 }
-impl From<PatternGreen> for PatternListElementOrSeparatorGreen {
-    fn from(value: PatternGreen) -> Self {
-        PatternListElementOrSeparatorGreen::Element(value)
+impl From<TerminalLiteralNumberPtr> for PatternPtr {
+    fn from(value: TerminalLiteralNumberPtr) -> Self {
+        Self(value.0)
     }
 }
-impl PatternListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            PatternListElementOrSeparatorGreen::Separator(green) => green.0,
-            PatternListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+impl From<TerminalShortStringPtr> for PatternPtr {
+    fn from(value: TerminalShortStringPtr) -> Self {
+        Self(value.0)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternListGreen(pub GreenId);
-impl TypedSyntaxNode for PatternList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternList);
-    type StablePtr = PatternListPtr;
-    type Green = PatternListGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        PatternListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
-        }))
+impl From<TerminalTruePtr> for PatternPtr {
+    fn from(value: TerminalTruePtr) -> Self {
+        Self(value.0)
     }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
+}
+impl From<TerminalFalsePtr> for PatternPtr {
+    fn from(value: TerminalFalsePtr) -> Self {
+        Self(value.0)
     }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+}
+impl From<PatternIdentifierPtr> for PatternPtr {
+    fn from(value: PatternIdentifierPtr) -> Self {
+        Self(value.0)
     }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+}
+impl From<PatternStructPtr> for PatternPtr {
+    fn from(value: PatternStructPtr) -> Self {
+        Self(value.0)
     }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        PatternListPtr(self.node.0.stable_ptr)
+}
+impl From<PatternTuplePtr> for PatternPtr {
+    fn from(value: PatternTuplePtr) -> Self {
+        Self(value.0)
     }
 }
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum PatternStructParam {
-    Single(TerminalIdentifier),
-    WithExpr(PatternStructParamWithExpr),
-    Tail(TerminalDotDot),
+impl From<PatternEnumPtr> for PatternPtr {
+    fn from(value: PatternEnumPtr) -> Self {
+        Self(value.0)
+    }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternStructParamPtr(pub SyntaxStablePtrId);
-impl PatternStructParamPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl From<ExprPathPtr> for PatternPtr {
+    fn from(value: ExprPathPtr) -> Self {
+        Self(value.0)
     }
 }
-impl From<TerminalIdentifierPtr> for PatternStructParamPtr {
-    fn from(value: TerminalIdentifierPtr) -> Self {
+impl From<TerminalUnderscoreGreen> for PatternGreen {
+    fn from(value: TerminalUnderscoreGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<PatternStructParamWithExprPtr> for PatternStructParamPtr {
-    fn from(value: PatternStructParamWithExprPtr) -> Self {
+impl From<TerminalLiteralNumberGreen> for PatternGreen {
+    fn from(value: TerminalLiteralNumberGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalDotDotPtr> for PatternStructParamPtr {
-    fn from(value: TerminalDotDotPtr) -> Self {
+impl From<TerminalShortStringGreen> for PatternGreen {
+    fn from(value: TerminalShortStringGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalIdentifierGreen> for PatternStructParamGreen {
-    fn from(value: TerminalIdentifierGreen) -> Self {
+impl From<TerminalTrueGreen> for PatternGreen {
+    fn from(value: TerminalTrueGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<PatternStructParamWithExprGreen> for PatternStructParamGreen {
-    fn from(value: PatternStructParamWithExprGreen) -> Self {
+impl From<TerminalFalseGreen> for PatternGreen {
+    fn from(value: TerminalFalseGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalDotDotGreen> for PatternStructParamGreen {
-    fn from(value: TerminalDotDotGreen) -> Self {
+impl From<PatternIdentifierGreen> for PatternGreen {
+    fn from(value: PatternIdentifierGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<PatternStructGreen> for PatternGreen {
+    fn from(value: PatternStructGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<PatternTupleGreen> for PatternGreen {
+    fn from(value: PatternTupleGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<PatternEnumGreen> for PatternGreen {
+    fn from(value: PatternEnumGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprPathGreen> for PatternGreen {
+    fn from(value: ExprPathGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternStructParamGreen(pub GreenId);
-impl TypedSyntaxNode for PatternStructParam {
+pub struct PatternGreen(pub GreenId);
+impl TypedSyntaxNode for Pattern {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = PatternStructParamPtr;
-    type Green = PatternStructParamGreen;
+    type StablePtr = PatternPtr;
+    type Green = PatternGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::TerminalIdentifier => {
-                PatternStructParam::Single(TerminalIdentifier::from_syntax_node(db, node))
+            SyntaxKind::TerminalUnderscore => {
+                Pattern::Underscore(TerminalUnderscore::from_syntax_node(db, node))
             }
-            SyntaxKind::PatternStructParamWithExpr => {
-                PatternStructParam::WithExpr(PatternStructParamWithExpr::from_syntax_node(db, node))
+            SyntaxKind::TerminalLiteralNumber => {
+                Pattern::Literal(TerminalLiteralNumber::from_syntax_node(db, node))
             }
-            SyntaxKind::TerminalDotDot => {
-                PatternStructParam::Tail(TerminalDotDot::from_syntax_node(db, node))
+            SyntaxKind::TerminalShortString => {
+                Pattern::ShortString(TerminalShortString::from_syntax_node(db, node))
             }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "PatternStructParam"
-            ),
+            SyntaxKind::TerminalTrue => Pattern::True(TerminalTrue::from_syntax_node(db, node)),
+            SyntaxKind::TerminalFalse => Pattern::False(TerminalFalse::from_syntax_node(db, node)),
+            SyntaxKind::PatternIdentifier => {
+                Pattern::Identifier(PatternIdentifier::from_syntax_node(db, node))
+            }
+            SyntaxKind::PatternStruct => Pattern::Struct(PatternStruct::from_syntax_node(db, node)),
+            SyntaxKind::PatternTuple => Pattern::Tuple(PatternTuple::from_syntax_node(db, node)),
+            SyntaxKind::PatternEnum => Pattern::Enum(PatternEnum::from_syntax_node(db, node)),
+            SyntaxKind::ExprPath => Pattern::Path(ExprPath::from_syntax_node(db, node)),
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Pattern"),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            PatternStructParam::Single(x) => x.as_syntax_node(),
-            PatternStructParam::WithExpr(x) => x.as_syntax_node(),
-            PatternStructParam::Tail(x) => x.as_syntax_node(),
+            Pattern::Underscore(x) => x.as_syntax_node(),
+            Pattern::Literal(x) => x.as_syntax_node(),
+            Pattern::ShortString(x) => x.as_syntax_node(),
+            Pattern::True(x) => x.as_syntax_node(),
+            Pattern::False(x) => x.as_syntax_node(),
+            Pattern::Identifier(x) => x.as_syntax_node(),
+            Pattern::Struct(x) => x.as_syntax_node(),
+            Pattern::Tuple(x) => x.as_syntax_node(),
+            Pattern::Enum(x) => x.as_syntax_node(),
+            Pattern::Path(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        PatternStructParamPtr(self.as_syntax_node().0.stable_ptr)
+        PatternPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct PatternStructParamWithExpr {
+pub struct PatternIdentifier {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl PatternStructParamWithExpr {
-    pub const INDEX_NAME: usize = 0;
-    pub const INDEX_COLON: usize = 1;
-    pub const INDEX_PATTERN: usize = 2;
+impl PatternIdentifier {
+    pub const INDEX_MODIFIERS: usize = 0;
+    pub const INDEX_NAME: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
+        modifiers: ModifierListGreen,
         name: TerminalIdentifierGreen,
-        colon: TerminalColonGreen,
-        pattern: PatternGreen,
-    ) -> PatternStructParamWithExprGreen {
-        let children: Vec<GreenId> = vec![name.0, colon.0, pattern.0];
+    ) -> PatternIdentifierGreen {
+        let children: Vec<GreenId> = vec![modifiers.0, name.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        PatternStructParamWithExprGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternStructParamWithExpr,
+        PatternIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternIdentifier,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl PatternStructParamWithExpr {
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
-        TerminalColon::from_syntax_node(db, self.children[1].clone())
+impl PatternIdentifier {
+    pub fn modifiers(&self, db: &dyn SyntaxGroup) -> ModifierList {
+        ModifierList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
-        Pattern::from_syntax_node(db, self.children[2].clone())
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternStructParamWithExprPtr(pub SyntaxStablePtrId);
-impl PatternStructParamWithExprPtr {
+pub struct PatternIdentifierPtr(pub SyntaxStablePtrId);
+impl PatternIdentifierPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternStructParamWithExprGreen(pub GreenId);
-impl TypedSyntaxNode for PatternStructParamWithExpr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternStructParamWithExpr);
-    type StablePtr = PatternStructParamWithExprPtr;
-    type Green = PatternStructParamWithExprGreen;
+pub struct PatternIdentifierGreen(pub GreenId);
+impl TypedSyntaxNode for PatternIdentifier {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternIdentifier);
+    type StablePtr = PatternIdentifierPtr;
+    type Green = PatternIdentifierGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        PatternStructParamWithExprGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternStructParamWithExpr,
+        PatternIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternIdentifier,
             details: GreenNodeDetails::Node {
-                children: vec![
-                    TerminalIdentifier::missing(db).0,
-                    TerminalColon::missing(db).0,
-                    Pattern::missing(db).0,
-                ],
+                children: vec![ModifierList::missing(db).0, TerminalIdentifier::missing(db).0],
                 width: 0,
             },
         }))
@@ -3763,10 +4105,10 @@ impl TypedSyntaxNode for PatternStructParamWithExpr {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::PatternStructParamWithExpr,
+            SyntaxKind::PatternIdentifier,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::PatternStructParamWithExpr
+            SyntaxKind::PatternIdentifier
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -3778,70 +4120,70 @@ impl TypedSyntaxNode for PatternStructParamWithExpr {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        PatternStructParamWithExprPtr(self.node.0.stable_ptr)
+        PatternIdentifierPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct PatternEnum {
+pub struct PatternStruct {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl PatternEnum {
+impl PatternStruct {
     pub const INDEX_PATH: usize = 0;
-    pub const INDEX_LPAREN: usize = 1;
-    pub const INDEX_PATTERN: usize = 2;
-    pub const INDEX_RPAREN: usize = 3;
+    pub const INDEX_LBRACE: usize = 1;
+    pub const INDEX_PARAMS: usize = 2;
+    pub const INDEX_RBRACE: usize = 3;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         path: ExprPathGreen,
-        lparen: TerminalLParenGreen,
-        pattern: PatternGreen,
-        rparen: TerminalRParenGreen,
-    ) -> PatternEnumGreen {
-        let children: Vec<GreenId> = vec![path.0, lparen.0, pattern.0, rparen.0];
+        lbrace: TerminalLBraceGreen,
+        params: PatternStructParamListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> PatternStructGreen {
+        let children: Vec<GreenId> = vec![path.0, lbrace.0, params.0, rbrace.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        PatternEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternEnum,
+        PatternStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternStruct,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl PatternEnum {
+impl PatternStruct {
     pub fn path(&self, db: &dyn SyntaxGroup) -> ExprPath {
         ExprPath::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
-        TerminalLParen::from_syntax_node(db, self.children[1].clone())
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
-        Pattern::from_syntax_node(db, self.children[2].clone())
+    pub fn params(&self, db: &dyn SyntaxGroup) -> PatternStructParamList {
+        PatternStructParamList::from_syntax_node(db, self.children[2].clone())
     }
-    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
-        TerminalRParen::from_syntax_node(db, self.children[3].clone())
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[3].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternEnumPtr(pub SyntaxStablePtrId);
-impl PatternEnumPtr {
+pub struct PatternStructPtr(pub SyntaxStablePtrId);
+impl PatternStructPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternEnumGreen(pub GreenId);
-impl TypedSyntaxNode for PatternEnum {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternEnum);
-    type StablePtr = PatternEnumPtr;
-    type Green = PatternEnumGreen;
+pub struct PatternStructGreen(pub GreenId);
+impl TypedSyntaxNode for PatternStruct {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternStruct);
+    type StablePtr = PatternStructPtr;
+    type Green = PatternStructGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        PatternEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::PatternEnum,
+        PatternStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternStruct,
             details: GreenNodeDetails::Node {
                 children: vec![
                     ExprPath::missing(db).0,
-                    TerminalLParen::missing(db).0,
-                    Pattern::missing(db).0,
-                    TerminalRParen::missing(db).0,
+                    TerminalLBrace::missing(db).0,
+                    PatternStructParamList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
                 ],
                 width: 0,
             },
@@ -3851,10 +4193,10 @@ impl TypedSyntaxNode for PatternEnum {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::PatternEnum,
+            SyntaxKind::PatternStruct,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::PatternEnum
+            SyntaxKind::PatternStruct
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -3866,194 +4208,155 @@ impl TypedSyntaxNode for PatternEnum {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        PatternEnumPtr(self.node.0.stable_ptr)
+        PatternStructPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TypeClause {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
+pub struct PatternStructParamList(ElementList<PatternStructParam, 2>);
+impl Deref for PatternStructParamList {
+    type Target = ElementList<PatternStructParam, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
 }
-impl TypeClause {
-    pub const INDEX_COLON: usize = 0;
-    pub const INDEX_TY: usize = 1;
+impl PatternStructParamList {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        colon: TerminalColonGreen,
-        ty: ExprGreen,
-    ) -> TypeClauseGreen {
-        let children: Vec<GreenId> = vec![colon.0, ty.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TypeClauseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TypeClause,
-            details: GreenNodeDetails::Node { children, width },
-        }))
-    }
-}
-impl TypeClause {
-    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
-        TerminalColon::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[1].clone())
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TypeClausePtr(pub SyntaxStablePtrId);
-impl TypeClausePtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TypeClauseGreen(pub GreenId);
-impl TypedSyntaxNode for TypeClause {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TypeClause);
-    type StablePtr = TypeClausePtr;
-    type Green = TypeClauseGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TypeClauseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TypeClause,
+        children: Vec<PatternStructParamListElementOrSeparatorGreen>,
+    ) -> PatternStructParamListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        PatternStructParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternStructParamList,
             details: GreenNodeDetails::Node {
-                children: vec![TerminalColon::missing(db).0, Expr::missing(db).0],
-                width: 0,
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
             },
         }))
     }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::TypeClause,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::TypeClause
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        TypeClausePtr(self.node.0.stable_ptr)
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionTypeClause {
-    Empty(OptionTypeClauseEmpty),
-    TypeClause(TypeClause),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTypeClausePtr(pub SyntaxStablePtrId);
-impl OptionTypeClausePtr {
+pub struct PatternStructParamListPtr(pub SyntaxStablePtrId);
+impl PatternStructParamListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<OptionTypeClauseEmptyPtr> for OptionTypeClausePtr {
-    fn from(value: OptionTypeClauseEmptyPtr) -> Self {
-        Self(value.0)
-    }
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PatternStructParamListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(PatternStructParamGreen),
 }
-impl From<TypeClausePtr> for OptionTypeClausePtr {
-    fn from(value: TypeClausePtr) -> Self {
-        Self(value.0)
+impl From<TerminalCommaGreen> for PatternStructParamListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        PatternStructParamListElementOrSeparatorGreen::Separator(value)
     }
 }
-impl From<OptionTypeClauseEmptyGreen> for OptionTypeClauseGreen {
-    fn from(value: OptionTypeClauseEmptyGreen) -> Self {
-        Self(value.0)
+impl From<PatternStructParamGreen> for PatternStructParamListElementOrSeparatorGreen {
+    fn from(value: PatternStructParamGreen) -> Self {
+        PatternStructParamListElementOrSeparatorGreen::Element(value)
     }
 }
-impl From<TypeClauseGreen> for OptionTypeClauseGreen {
-    fn from(value: TypeClauseGreen) -> Self {
-        Self(value.0)
+impl PatternStructParamListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            PatternStructParamListElementOrSeparatorGreen::Separator(green) => green.0,
+            PatternStructParamListElementOrSeparatorGreen::Element(green) => green.0,
+        }
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTypeClauseGreen(pub GreenId);
-impl TypedSyntaxNode for OptionTypeClause {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionTypeClausePtr;
-    type Green = OptionTypeClauseGreen;
+pub struct PatternStructParamListGreen(pub GreenId);
+impl TypedSyntaxNode for PatternStructParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternStructParamList);
+    type StablePtr = PatternStructParamListPtr;
+    type Green = PatternStructParamListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        PatternStructParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternStructParamList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::OptionTypeClauseEmpty => {
-                OptionTypeClause::Empty(OptionTypeClauseEmpty::from_syntax_node(db, node))
-            }
-            SyntaxKind::TypeClause => {
-                OptionTypeClause::TypeClause(TypeClause::from_syntax_node(db, node))
-            }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionTypeClause"
-            ),
-        }
+        Self(ElementList::new(node))
     }
     fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            OptionTypeClause::Empty(x) => x.as_syntax_node(),
-            OptionTypeClause::TypeClause(x) => x.as_syntax_node(),
-        }
+        self.node.clone()
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionTypeClausePtr(self.as_syntax_node().0.stable_ptr)
+        PatternStructParamListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionTypeClauseEmpty {
+pub struct PatternTuple {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl OptionTypeClauseEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionTypeClauseEmptyGreen {
-        let children: Vec<GreenId> = vec![];
+impl PatternTuple {
+    pub const INDEX_LPAREN: usize = 0;
+    pub const INDEX_PATTERNS: usize = 1;
+    pub const INDEX_RPAREN: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lparen: TerminalLParenGreen,
+        patterns: PatternListGreen,
+        rparen: TerminalRParenGreen,
+    ) -> PatternTupleGreen {
+        let children: Vec<GreenId> = vec![lparen.0, patterns.0, rparen.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionTypeClauseEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionTypeClauseEmpty,
+        PatternTupleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternTuple,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl OptionTypeClauseEmpty {}
+impl PatternTuple {
+    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
+        TerminalLParen::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn patterns(&self, db: &dyn SyntaxGroup) -> PatternList {
+        PatternList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
+        TerminalRParen::from_syntax_node(db, self.children[2].clone())
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTypeClauseEmptyPtr(pub SyntaxStablePtrId);
-impl OptionTypeClauseEmptyPtr {
+pub struct PatternTuplePtr(pub SyntaxStablePtrId);
+impl PatternTuplePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTypeClauseEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionTypeClauseEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionTypeClauseEmpty);
-    type StablePtr = OptionTypeClauseEmptyPtr;
-    type Green = OptionTypeClauseEmptyGreen;
+pub struct PatternTupleGreen(pub GreenId);
+impl TypedSyntaxNode for PatternTuple {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternTuple);
+    type StablePtr = PatternTuplePtr;
+    type Green = PatternTupleGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionTypeClauseEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionTypeClauseEmpty,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        PatternTupleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternTuple,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLParen::missing(db).0,
+                    PatternList::missing(db).0,
+                    TerminalRParen::missing(db).0,
+                ],
+                width: 0,
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::OptionTypeClauseEmpty,
+            SyntaxKind::PatternTuple,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::OptionTypeClauseEmpty
+            SyntaxKind::PatternTuple
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -4065,194 +4368,239 @@ impl TypedSyntaxNode for OptionTypeClauseEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionTypeClauseEmptyPtr(self.node.0.stable_ptr)
+        PatternTuplePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ReturnTypeClause {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
+pub struct PatternList(ElementList<Pattern, 2>);
+impl Deref for PatternList {
+    type Target = ElementList<Pattern, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
 }
-impl ReturnTypeClause {
-    pub const INDEX_ARROW: usize = 0;
-    pub const INDEX_TY: usize = 1;
+impl PatternList {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        arrow: TerminalArrowGreen,
-        ty: ExprGreen,
-    ) -> ReturnTypeClauseGreen {
-        let children: Vec<GreenId> = vec![arrow.0, ty.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ReturnTypeClauseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ReturnTypeClause,
-            details: GreenNodeDetails::Node { children, width },
+        children: Vec<PatternListElementOrSeparatorGreen>,
+    ) -> PatternListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        PatternListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
         }))
     }
 }
-impl ReturnTypeClause {
-    pub fn arrow(&self, db: &dyn SyntaxGroup) -> TerminalArrow {
-        TerminalArrow::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[1].clone())
-    }
-}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ReturnTypeClausePtr(pub SyntaxStablePtrId);
-impl ReturnTypeClausePtr {
+pub struct PatternListPtr(pub SyntaxStablePtrId);
+impl PatternListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ReturnTypeClauseGreen(pub GreenId);
-impl TypedSyntaxNode for ReturnTypeClause {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ReturnTypeClause);
-    type StablePtr = ReturnTypeClausePtr;
-    type Green = ReturnTypeClauseGreen;
+pub enum PatternListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(PatternGreen),
+}
+impl From<TerminalCommaGreen> for PatternListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        PatternListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<PatternGreen> for PatternListElementOrSeparatorGreen {
+    fn from(value: PatternGreen) -> Self {
+        PatternListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl PatternListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            PatternListElementOrSeparatorGreen::Separator(green) => green.0,
+            PatternListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternListGreen(pub GreenId);
+impl TypedSyntaxNode for PatternList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternList);
+    type StablePtr = PatternListPtr;
+    type Green = PatternListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ReturnTypeClauseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ReturnTypeClause,
-            details: GreenNodeDetails::Node {
-                children: vec![TerminalArrow::missing(db).0, Expr::missing(db).0],
-                width: 0,
-            },
+        PatternListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::ReturnTypeClause,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::ReturnTypeClause
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+        Self(ElementList::new(node))
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         self.node.clone()
     }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ReturnTypeClausePtr(self.node.0.stable_ptr)
+        PatternListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionReturnTypeClause {
-    Empty(OptionReturnTypeClauseEmpty),
-    ReturnTypeClause(ReturnTypeClause),
+pub enum PatternStructParam {
+    Single(TerminalIdentifier),
+    WithExpr(PatternStructParamWithExpr),
+    Tail(TerminalDotDot),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionReturnTypeClausePtr(pub SyntaxStablePtrId);
-impl OptionReturnTypeClausePtr {
+pub struct PatternStructParamPtr(pub SyntaxStablePtrId);
+impl PatternStructParamPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<OptionReturnTypeClauseEmptyPtr> for OptionReturnTypeClausePtr {
-    fn from(value: OptionReturnTypeClauseEmptyPtr) -> Self {
+impl From<TerminalIdentifierPtr> for PatternStructParamPtr {
+    fn from(value: TerminalIdentifierPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ReturnTypeClausePtr> for OptionReturnTypeClausePtr {
-    fn from(value: ReturnTypeClausePtr) -> Self {
+impl From<PatternStructParamWithExprPtr> for PatternStructParamPtr {
+    fn from(value: PatternStructParamWithExprPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<OptionReturnTypeClauseEmptyGreen> for OptionReturnTypeClauseGreen {
-    fn from(value: OptionReturnTypeClauseEmptyGreen) -> Self {
+impl From<TerminalDotDotPtr> for PatternStructParamPtr {
+    fn from(value: TerminalDotDotPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ReturnTypeClauseGreen> for OptionReturnTypeClauseGreen {
-    fn from(value: ReturnTypeClauseGreen) -> Self {
+impl From<TerminalIdentifierGreen> for PatternStructParamGreen {
+    fn from(value: TerminalIdentifierGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<PatternStructParamWithExprGreen> for PatternStructParamGreen {
+    fn from(value: PatternStructParamWithExprGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalDotDotGreen> for PatternStructParamGreen {
+    fn from(value: TerminalDotDotGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionReturnTypeClauseGreen(pub GreenId);
-impl TypedSyntaxNode for OptionReturnTypeClause {
+pub struct PatternStructParamGreen(pub GreenId);
+impl TypedSyntaxNode for PatternStructParam {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionReturnTypeClausePtr;
-    type Green = OptionReturnTypeClauseGreen;
+    type StablePtr = PatternStructParamPtr;
+    type Green = PatternStructParamGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::OptionReturnTypeClauseEmpty => OptionReturnTypeClause::Empty(
-                OptionReturnTypeClauseEmpty::from_syntax_node(db, node),
-            ),
-            SyntaxKind::ReturnTypeClause => OptionReturnTypeClause::ReturnTypeClause(
-                ReturnTypeClause::from_syntax_node(db, node),
-            ),
+            SyntaxKind::TerminalIdentifier => {
+                PatternStructParam::Single(TerminalIdentifier::from_syntax_node(db, node))
+            }
+            SyntaxKind::PatternStructParamWithExpr => {
+                PatternStructParam::WithExpr(PatternStructParamWithExpr::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalDotDot => {
+                PatternStructParam::Tail(TerminalDotDot::from_syntax_node(db, node))
+            }
             _ => panic!(
                 "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionReturnTypeClause"
+                kind, "PatternStructParam"
             ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            OptionReturnTypeClause::Empty(x) => x.as_syntax_node(),
-            OptionReturnTypeClause::ReturnTypeClause(x) => x.as_syntax_node(),
+            PatternStructParam::Single(x) => x.as_syntax_node(),
+            PatternStructParam::WithExpr(x) => x.as_syntax_node(),
+            PatternStructParam::Tail(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionReturnTypeClausePtr(self.as_syntax_node().0.stable_ptr)
+        PatternStructParamPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionReturnTypeClauseEmpty {
+pub struct PatternStructParamWithExpr {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl OptionReturnTypeClauseEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionReturnTypeClauseEmptyGreen {
-        let children: Vec<GreenId> = vec![];
+impl PatternStructParamWithExpr {
+    pub const INDEX_NAME: usize = 0;
+    pub const INDEX_COLON: usize = 1;
+    pub const INDEX_PATTERN: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        name: TerminalIdentifierGreen,
+        colon: TerminalColonGreen,
+        pattern: PatternGreen,
+    ) -> PatternStructParamWithExprGreen {
+        let children: Vec<GreenId> = vec![name.0, colon.0, pattern.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionReturnTypeClauseEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionReturnTypeClauseEmpty,
+        PatternStructParamWithExprGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternStructParamWithExpr,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl OptionReturnTypeClauseEmpty {}
+impl PatternStructParamWithExpr {
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
+        TerminalColon::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
+        Pattern::from_syntax_node(db, self.children[2].clone())
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionReturnTypeClauseEmptyPtr(pub SyntaxStablePtrId);
-impl OptionReturnTypeClauseEmptyPtr {
+pub struct PatternStructParamWithExprPtr(pub SyntaxStablePtrId);
+impl PatternStructParamWithExprPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionReturnTypeClauseEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionReturnTypeClauseEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionReturnTypeClauseEmpty);
-    type StablePtr = OptionReturnTypeClauseEmptyPtr;
-    type Green = OptionReturnTypeClauseEmptyGreen;
+pub struct PatternStructParamWithExprGreen(pub GreenId);
+impl TypedSyntaxNode for PatternStructParamWithExpr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternStructParamWithExpr);
+    type StablePtr = PatternStructParamWithExprPtr;
+    type Green = PatternStructParamWithExprGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionReturnTypeClauseEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionReturnTypeClauseEmpty,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        PatternStructParamWithExprGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternStructParamWithExpr,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalIdentifier::missing(db).0,
+                    TerminalColon::missing(db).0,
+                    Pattern::missing(db).0,
+                ],
+                width: 0,
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::OptionReturnTypeClauseEmpty,
+            SyntaxKind::PatternStructParamWithExpr,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::OptionReturnTypeClauseEmpty
+            SyntaxKind::PatternStructParamWithExpr
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -4264,185 +4612,271 @@ impl TypedSyntaxNode for OptionReturnTypeClauseEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionReturnTypeClauseEmptyPtr(self.node.0.stable_ptr)
+        PatternStructParamWithExprPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum Statement {
-    Let(StatementLet),
-    Expr(StatementExpr),
-    Return(StatementReturn),
-    Missing(StatementMissing),
+pub struct PatternEnum {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementPtr(pub SyntaxStablePtrId);
-impl StatementPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl PatternEnum {
+    pub const INDEX_PATH: usize = 0;
+    pub const INDEX_LPAREN: usize = 1;
+    pub const INDEX_PATTERN: usize = 2;
+    pub const INDEX_RPAREN: usize = 3;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        path: ExprPathGreen,
+        lparen: TerminalLParenGreen,
+        pattern: PatternGreen,
+        rparen: TerminalRParenGreen,
+    ) -> PatternEnumGreen {
+        let children: Vec<GreenId> = vec![path.0, lparen.0, pattern.0, rparen.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        PatternEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternEnum,
+            details: GreenNodeDetails::Node { children, width },
+        }))
     }
 }
-impl From<StatementLetPtr> for StatementPtr {
-    fn from(value: StatementLetPtr) -> Self {
-        Self(value.0)
+impl PatternEnum {
+    pub fn path(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[0].clone())
     }
-}
-impl From<StatementExprPtr> for StatementPtr {
-    fn from(value: StatementExprPtr) -> Self {
-        Self(value.0)
+    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
+        TerminalLParen::from_syntax_node(db, self.children[1].clone())
     }
-}
-impl From<StatementReturnPtr> for StatementPtr {
-    fn from(value: StatementReturnPtr) -> Self {
-        Self(value.0)
+    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
+        Pattern::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
+        TerminalRParen::from_syntax_node(db, self.children[3].clone())
     }
 }
-impl From<StatementMissingPtr> for StatementPtr {
-    fn from(value: StatementMissingPtr) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternEnumPtr(pub SyntaxStablePtrId);
+impl PatternEnumPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl From<StatementLetGreen> for StatementGreen {
-    fn from(value: StatementLetGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternEnumGreen(pub GreenId);
+impl TypedSyntaxNode for PatternEnum {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternEnum);
+    type StablePtr = PatternEnumPtr;
+    type Green = PatternEnumGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        PatternEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternEnum,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    ExprPath::missing(db).0,
+                    TerminalLParen::missing(db).0,
+                    Pattern::missing(db).0,
+                    TerminalRParen::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::PatternEnum,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::PatternEnum
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        PatternEnumPtr(self.node.0.stable_ptr)
     }
 }
-impl From<StatementExprGreen> for StatementGreen {
-    fn from(value: StatementExprGreen) -> Self {
-        Self(value.0)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TypeClause {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl TypeClause {
+    pub const INDEX_COLON: usize = 0;
+    pub const INDEX_TY: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        colon: TerminalColonGreen,
+        ty: ExprGreen,
+    ) -> TypeClauseGreen {
+        let children: Vec<GreenId> = vec![colon.0, ty.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TypeClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TypeClause,
+            details: GreenNodeDetails::Node { children, width },
+        }))
     }
 }
-impl From<StatementReturnGreen> for StatementGreen {
-    fn from(value: StatementReturnGreen) -> Self {
-        Self(value.0)
+impl TypeClause {
+    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
+        TerminalColon::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
     }
 }
-impl From<StatementMissingGreen> for StatementGreen {
-    fn from(value: StatementMissingGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TypeClausePtr(pub SyntaxStablePtrId);
+impl TypeClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementGreen(pub GreenId);
-impl TypedSyntaxNode for Statement {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = StatementPtr;
-    type Green = StatementGreen;
+pub struct TypeClauseGreen(pub GreenId);
+impl TypedSyntaxNode for TypeClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TypeClause);
+    type StablePtr = TypeClausePtr;
+    type Green = TypeClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StatementGreen(StatementMissing::missing(db).0)
+        TypeClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TypeClause,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalColon::missing(db).0, Expr::missing(db).0],
+                width: 0,
+            },
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
-        match kind {
-            SyntaxKind::StatementLet => Statement::Let(StatementLet::from_syntax_node(db, node)),
-            SyntaxKind::StatementExpr => Statement::Expr(StatementExpr::from_syntax_node(db, node)),
-            SyntaxKind::StatementReturn => {
-                Statement::Return(StatementReturn::from_syntax_node(db, node))
-            }
-            SyntaxKind::StatementMissing => {
-                Statement::Missing(StatementMissing::from_syntax_node(db, node))
-            }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Statement"),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            Statement::Let(x) => x.as_syntax_node(),
-            Statement::Expr(x) => x.as_syntax_node(),
-            Statement::Return(x) => x.as_syntax_node(),
-            Statement::Missing(x) => x.as_syntax_node(),
-        }
+        assert_eq!(
+            kind,
+            SyntaxKind::TypeClause,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TypeClause
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StatementPtr(self.as_syntax_node().0.stable_ptr)
+        TypeClausePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StatementList(ElementList<Statement, 1>);
-impl Deref for StatementList {
-    type Target = ElementList<Statement, 1>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-impl StatementList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<StatementGreen>) -> StatementListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        StatementListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.0).collect(),
-                width,
-            },
-        }))
-    }
+pub enum OptionTypeClause {
+    Empty(OptionTypeClauseEmpty),
+    TypeClause(TypeClause),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementListPtr(pub SyntaxStablePtrId);
-impl StatementListPtr {
+pub struct OptionTypeClausePtr(pub SyntaxStablePtrId);
+impl OptionTypeClausePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
+impl From<OptionTypeClauseEmptyPtr> for OptionTypeClausePtr {
+    fn from(value: OptionTypeClauseEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TypeClausePtr> for OptionTypeClausePtr {
+    fn from(value: TypeClausePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionTypeClauseEmptyGreen> for OptionTypeClauseGreen {
+    fn from(value: OptionTypeClauseEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TypeClauseGreen> for OptionTypeClauseGreen {
+    fn from(value: TypeClauseGreen) -> Self {
+        Self(value.0)
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementListGreen(pub GreenId);
-impl TypedSyntaxNode for StatementList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementList);
-    type StablePtr = StatementListPtr;
-    type Green = StatementListGreen;
+pub struct OptionTypeClauseGreen(pub GreenId);
+impl TypedSyntaxNode for OptionTypeClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionTypeClausePtr;
+    type Green = OptionTypeClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StatementListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
-        }))
+        panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionTypeClauseEmpty => {
+                OptionTypeClause::Empty(OptionTypeClauseEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::TypeClause => {
+                OptionTypeClause::TypeClause(TypeClause::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionTypeClause"
+            ),
+        }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        match self {
+            OptionTypeClause::Empty(x) => x.as_syntax_node(),
+            OptionTypeClause::TypeClause(x) => x.as_syntax_node(),
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StatementListPtr(self.node.0.stable_ptr)
+        OptionTypeClausePtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StatementMissing {
+pub struct OptionTypeClauseEmpty {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl StatementMissing {
-    pub fn new_green(db: &dyn SyntaxGroup) -> StatementMissingGreen {
+impl OptionTypeClauseEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionTypeClauseEmptyGreen {
         let children: Vec<GreenId> = vec![];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StatementMissingGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementMissing,
+        OptionTypeClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionTypeClauseEmpty,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl StatementMissing {}
+impl OptionTypeClauseEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementMissingPtr(pub SyntaxStablePtrId);
-impl StatementMissingPtr {
+pub struct OptionTypeClauseEmptyPtr(pub SyntaxStablePtrId);
+impl OptionTypeClauseEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementMissingGreen(pub GreenId);
-impl TypedSyntaxNode for StatementMissing {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementMissing);
-    type StablePtr = StatementMissingPtr;
-    type Green = StatementMissingGreen;
+pub struct OptionTypeClauseEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionTypeClauseEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionTypeClauseEmpty);
+    type StablePtr = OptionTypeClauseEmptyPtr;
+    type Green = OptionTypeClauseEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StatementMissingGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementMissing,
+        OptionTypeClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionTypeClauseEmpty,
             details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
@@ -4450,10 +4884,10 @@ impl TypedSyntaxNode for StatementMissing {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::StatementMissing,
+            SyntaxKind::OptionTypeClauseEmpty,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::StatementMissing
+            SyntaxKind::OptionTypeClauseEmpty
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -4465,92 +4899,56 @@ impl TypedSyntaxNode for StatementMissing {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StatementMissingPtr(self.node.0.stable_ptr)
+        OptionTypeClauseEmptyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StatementLet {
+pub struct ReturnTypeClause {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl StatementLet {
-    pub const INDEX_LET_KW: usize = 0;
-    pub const INDEX_PATTERN: usize = 1;
-    pub const INDEX_TYPE_CLAUSE: usize = 2;
-    pub const INDEX_EQ: usize = 3;
-    pub const INDEX_RHS: usize = 4;
-    pub const INDEX_SEMICOLON: usize = 5;
+impl ReturnTypeClause {
+    pub const INDEX_ARROW: usize = 0;
+    pub const INDEX_TY: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        let_kw: TerminalLetGreen,
-        pattern: PatternGreen,
-        type_clause: OptionTypeClauseGreen,
-        eq: TerminalEqGreen,
-        rhs: ExprGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> StatementLetGreen {
-        let children: Vec<GreenId> =
-            vec![let_kw.0, pattern.0, type_clause.0, eq.0, rhs.0, semicolon.0];
+        arrow: TerminalArrowGreen,
+        ty: ExprGreen,
+    ) -> ReturnTypeClauseGreen {
+        let children: Vec<GreenId> = vec![arrow.0, ty.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StatementLetGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementLet,
+        ReturnTypeClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ReturnTypeClause,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl StatementLet {
-    pub fn let_kw(&self, db: &dyn SyntaxGroup) -> TerminalLet {
-        TerminalLet::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
-        Pattern::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> OptionTypeClause {
-        OptionTypeClause::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
-        TerminalEq::from_syntax_node(db, self.children[3].clone())
-    }
-    pub fn rhs(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[4].clone())
+impl ReturnTypeClause {
+    pub fn arrow(&self, db: &dyn SyntaxGroup) -> TerminalArrow {
+        TerminalArrow::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[5].clone())
+    pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementLetPtr(pub SyntaxStablePtrId);
-impl StatementLetPtr {
-    pub fn pattern_green(self, db: &dyn SyntaxGroup) -> PatternGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            PatternGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct ReturnTypeClausePtr(pub SyntaxStablePtrId);
+impl ReturnTypeClausePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementLetGreen(pub GreenId);
-impl TypedSyntaxNode for StatementLet {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementLet);
-    type StablePtr = StatementLetPtr;
-    type Green = StatementLetGreen;
+pub struct ReturnTypeClauseGreen(pub GreenId);
+impl TypedSyntaxNode for ReturnTypeClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ReturnTypeClause);
+    type StablePtr = ReturnTypeClausePtr;
+    type Green = ReturnTypeClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StatementLetGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementLet,
+        ReturnTypeClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ReturnTypeClause,
             details: GreenNodeDetails::Node {
-                children: vec![
-                    TerminalLet::missing(db).0,
-                    Pattern::missing(db).0,
-                    OptionTypeClause::missing(db).0,
-                    TerminalEq::missing(db).0,
-                    Expr::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
-                ],
+                children: vec![TerminalArrow::missing(db).0, Expr::missing(db).0],
                 width: 0,
             },
         }))
@@ -4559,10 +4957,10 @@ impl TypedSyntaxNode for StatementLet {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::StatementLet,
+            SyntaxKind::ReturnTypeClause,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::StatementLet
+            SyntaxKind::ReturnTypeClause
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -4574,110 +4972,110 @@ impl TypedSyntaxNode for StatementLet {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StatementLetPtr(self.node.0.stable_ptr)
+        ReturnTypeClausePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionTerminalSemicolon {
-    Empty(OptionTerminalSemicolonEmpty),
-    TerminalSemicolon(TerminalSemicolon),
+pub enum OptionReturnTypeClause {
+    Empty(OptionReturnTypeClauseEmpty),
+    ReturnTypeClause(ReturnTypeClause),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTerminalSemicolonPtr(pub SyntaxStablePtrId);
-impl OptionTerminalSemicolonPtr {
+pub struct OptionReturnTypeClausePtr(pub SyntaxStablePtrId);
+impl OptionReturnTypeClausePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<OptionTerminalSemicolonEmptyPtr> for OptionTerminalSemicolonPtr {
-    fn from(value: OptionTerminalSemicolonEmptyPtr) -> Self {
+impl From<OptionReturnTypeClauseEmptyPtr> for OptionReturnTypeClausePtr {
+    fn from(value: OptionReturnTypeClauseEmptyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalSemicolonPtr> for OptionTerminalSemicolonPtr {
-    fn from(value: TerminalSemicolonPtr) -> Self {
+impl From<ReturnTypeClausePtr> for OptionReturnTypeClausePtr {
+    fn from(value: ReturnTypeClausePtr) -> Self {
         Self(value.0)
     }
 }
-impl From<OptionTerminalSemicolonEmptyGreen> for OptionTerminalSemicolonGreen {
-    fn from(value: OptionTerminalSemicolonEmptyGreen) -> Self {
+impl From<OptionReturnTypeClauseEmptyGreen> for OptionReturnTypeClauseGreen {
+    fn from(value: OptionReturnTypeClauseEmptyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalSemicolonGreen> for OptionTerminalSemicolonGreen {
-    fn from(value: TerminalSemicolonGreen) -> Self {
+impl From<ReturnTypeClauseGreen> for OptionReturnTypeClauseGreen {
+    fn from(value: ReturnTypeClauseGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTerminalSemicolonGreen(pub GreenId);
-impl TypedSyntaxNode for OptionTerminalSemicolon {
+pub struct OptionReturnTypeClauseGreen(pub GreenId);
+impl TypedSyntaxNode for OptionReturnTypeClause {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionTerminalSemicolonPtr;
-    type Green = OptionTerminalSemicolonGreen;
+    type StablePtr = OptionReturnTypeClausePtr;
+    type Green = OptionReturnTypeClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::OptionTerminalSemicolonEmpty => OptionTerminalSemicolon::Empty(
-                OptionTerminalSemicolonEmpty::from_syntax_node(db, node),
+            SyntaxKind::OptionReturnTypeClauseEmpty => OptionReturnTypeClause::Empty(
+                OptionReturnTypeClauseEmpty::from_syntax_node(db, node),
             ),
-            SyntaxKind::TerminalSemicolon => OptionTerminalSemicolon::TerminalSemicolon(
-                TerminalSemicolon::from_syntax_node(db, node),
+            SyntaxKind::ReturnTypeClause => OptionReturnTypeClause::ReturnTypeClause(
+                ReturnTypeClause::from_syntax_node(db, node),
             ),
             _ => panic!(
                 "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionTerminalSemicolon"
+                kind, "OptionReturnTypeClause"
             ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            OptionTerminalSemicolon::Empty(x) => x.as_syntax_node(),
-            OptionTerminalSemicolon::TerminalSemicolon(x) => x.as_syntax_node(),
+            OptionReturnTypeClause::Empty(x) => x.as_syntax_node(),
+            OptionReturnTypeClause::ReturnTypeClause(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionTerminalSemicolonPtr(self.as_syntax_node().0.stable_ptr)
+        OptionReturnTypeClausePtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionTerminalSemicolonEmpty {
+pub struct OptionReturnTypeClauseEmpty {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl OptionTerminalSemicolonEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionTerminalSemicolonEmptyGreen {
+impl OptionReturnTypeClauseEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionReturnTypeClauseEmptyGreen {
         let children: Vec<GreenId> = vec![];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionTerminalSemicolonEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionTerminalSemicolonEmpty,
+        OptionReturnTypeClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionReturnTypeClauseEmpty,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl OptionTerminalSemicolonEmpty {}
+impl OptionReturnTypeClauseEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTerminalSemicolonEmptyPtr(pub SyntaxStablePtrId);
-impl OptionTerminalSemicolonEmptyPtr {
+pub struct OptionReturnTypeClauseEmptyPtr(pub SyntaxStablePtrId);
+impl OptionReturnTypeClauseEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTerminalSemicolonEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionTerminalSemicolonEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionTerminalSemicolonEmpty);
-    type StablePtr = OptionTerminalSemicolonEmptyPtr;
-    type Green = OptionTerminalSemicolonEmptyGreen;
+pub struct OptionReturnTypeClauseEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionReturnTypeClauseEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionReturnTypeClauseEmpty);
+    type StablePtr = OptionReturnTypeClauseEmptyPtr;
+    type Green = OptionReturnTypeClauseEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionTerminalSemicolonEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionTerminalSemicolonEmpty,
+        OptionReturnTypeClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionReturnTypeClauseEmpty,
             details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
@@ -4685,10 +5083,10 @@ impl TypedSyntaxNode for OptionTerminalSemicolonEmpty {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::OptionTerminalSemicolonEmpty,
+            SyntaxKind::OptionReturnTypeClauseEmpty,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::OptionTerminalSemicolonEmpty
+            SyntaxKind::OptionReturnTypeClauseEmpty
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -4700,306 +5098,226 @@ impl TypedSyntaxNode for OptionTerminalSemicolonEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionTerminalSemicolonEmptyPtr(self.node.0.stable_ptr)
+        OptionReturnTypeClauseEmptyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StatementExpr {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
-}
-impl StatementExpr {
-    pub const INDEX_EXPR: usize = 0;
-    pub const INDEX_SEMICOLON: usize = 1;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        expr: ExprGreen,
-        semicolon: OptionTerminalSemicolonGreen,
-    ) -> StatementExprGreen {
-        let children: Vec<GreenId> = vec![expr.0, semicolon.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StatementExprGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementExpr,
-            details: GreenNodeDetails::Node { children, width },
-        }))
-    }
-}
-impl StatementExpr {
-    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> OptionTerminalSemicolon {
-        OptionTerminalSemicolon::from_syntax_node(db, self.children[1].clone())
-    }
+pub enum Statement {
+    Let(StatementLet),
+    Expr(StatementExpr),
+    Return(StatementReturn),
+    Break(StatementBreak),
+    Continue(StatementContinue),
+    Missing(StatementMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementExprPtr(pub SyntaxStablePtrId);
-impl StatementExprPtr {
+pub struct StatementPtr(pub SyntaxStablePtrId);
+impl StatementPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementExprGreen(pub GreenId);
-impl TypedSyntaxNode for StatementExpr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementExpr);
-    type StablePtr = StatementExprPtr;
-    type Green = StatementExprGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StatementExprGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementExpr,
-            details: GreenNodeDetails::Node {
-                children: vec![Expr::missing(db).0, OptionTerminalSemicolon::missing(db).0],
-                width: 0,
-            },
-        }))
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::StatementExpr,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::StatementExpr
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+impl From<StatementLetPtr> for StatementPtr {
+    fn from(value: StatementLetPtr) -> Self {
+        Self(value.0)
     }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+}
+impl From<StatementExprPtr> for StatementPtr {
+    fn from(value: StatementExprPtr) -> Self {
+        Self(value.0)
     }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+}
+impl From<StatementReturnPtr> for StatementPtr {
+    fn from(value: StatementReturnPtr) -> Self {
+        Self(value.0)
     }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        StatementExprPtr(self.node.0.stable_ptr)
+}
+impl From<StatementBreakPtr> for StatementPtr {
+    fn from(value: StatementBreakPtr) -> Self {
+        Self(value.0)
     }
 }
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StatementReturn {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
+impl From<StatementContinuePtr> for StatementPtr {
+    fn from(value: StatementContinuePtr) -> Self {
+        Self(value.0)
+    }
 }
-impl StatementReturn {
-    pub const INDEX_RETURN_KW: usize = 0;
-    pub const INDEX_EXPR: usize = 1;
-    pub const INDEX_SEMICOLON: usize = 2;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        return_kw: TerminalReturnGreen,
-        expr: ExprGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> StatementReturnGreen {
-        let children: Vec<GreenId> = vec![return_kw.0, expr.0, semicolon.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StatementReturnGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementReturn,
-            details: GreenNodeDetails::Node { children, width },
-        }))
+impl From<StatementMissingPtr> for StatementPtr {
+    fn from(value: StatementMissingPtr) -> Self {
+        Self(value.0)
     }
 }
-impl StatementReturn {
-    pub fn return_kw(&self, db: &dyn SyntaxGroup) -> TerminalReturn {
-        TerminalReturn::from_syntax_node(db, self.children[0].clone())
+impl From<StatementLetGreen> for StatementGreen {
+    fn from(value: StatementLetGreen) -> Self {
+        Self(value.0)
     }
-    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[1].clone())
+}
+impl From<StatementExprGreen> for StatementGreen {
+    fn from(value: StatementExprGreen) -> Self {
+        Self(value.0)
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[2].clone())
+}
+impl From<StatementReturnGreen> for StatementGreen {
+    fn from(value: StatementReturnGreen) -> Self {
+        Self(value.0)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementReturnPtr(pub SyntaxStablePtrId);
-impl StatementReturnPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl From<StatementBreakGreen> for StatementGreen {
+    fn from(value: StatementBreakGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StatementContinueGreen> for StatementGreen {
+    fn from(value: StatementContinueGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StatementMissingGreen> for StatementGreen {
+    fn from(value: StatementMissingGreen) -> Self {
+        Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementReturnGreen(pub GreenId);
-impl TypedSyntaxNode for StatementReturn {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementReturn);
-    type StablePtr = StatementReturnPtr;
-    type Green = StatementReturnGreen;
+pub struct StatementGreen(pub GreenId);
+impl TypedSyntaxNode for Statement {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = StatementPtr;
+    type Green = StatementGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StatementReturnGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StatementReturn,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    TerminalReturn::missing(db).0,
-                    Expr::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
-                ],
-                width: 0,
-            },
-        }))
+        StatementGreen(StatementMissing::missing(db).0)
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::StatementReturn,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::StatementReturn
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match kind {
+            SyntaxKind::StatementLet => Statement::Let(StatementLet::from_syntax_node(db, node)),
+            SyntaxKind::StatementExpr => Statement::Expr(StatementExpr::from_syntax_node(db, node)),
+            SyntaxKind::StatementReturn => {
+                Statement::Return(StatementReturn::from_syntax_node(db, node))
+            }
+            SyntaxKind::StatementBreak => {
+                Statement::Break(StatementBreak::from_syntax_node(db, node))
+            }
+            SyntaxKind::StatementContinue => {
+                Statement::Continue(StatementContinue::from_syntax_node(db, node))
+            }
+            SyntaxKind::StatementMissing => {
+                Statement::Missing(StatementMissing::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Statement"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            Statement::Let(x) => x.as_syntax_node(),
+            Statement::Expr(x) => x.as_syntax_node(),
+            Statement::Return(x) => x.as_syntax_node(),
+            Statement::Break(x) => x.as_syntax_node(),
+            Statement::Continue(x) => x.as_syntax_node(),
+            Statement::Missing(x) => x.as_syntax_node(),
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StatementReturnPtr(self.node.0.stable_ptr)
+        StatementPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum ParamName {
-    Underscore(TerminalUnderscore),
-    Name(TerminalIdentifier),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamNamePtr(pub SyntaxStablePtrId);
-impl ParamNamePtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-impl From<TerminalUnderscorePtr> for ParamNamePtr {
-    fn from(value: TerminalUnderscorePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalIdentifierPtr> for ParamNamePtr {
-    fn from(value: TerminalIdentifierPtr) -> Self {
-        Self(value.0)
+pub struct StatementList(ElementList<Statement, 1>);
+impl Deref for StatementList {
+    type Target = ElementList<Statement, 1>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
 }
-impl From<TerminalUnderscoreGreen> for ParamNameGreen {
-    fn from(value: TerminalUnderscoreGreen) -> Self {
-        Self(value.0)
+impl StatementList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<StatementGreen>) -> StatementListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
+        StatementListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.0).collect(),
+                width,
+            },
+        }))
     }
 }
-impl From<TerminalIdentifierGreen> for ParamNameGreen {
-    fn from(value: TerminalIdentifierGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementListPtr(pub SyntaxStablePtrId);
+impl StatementListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamNameGreen(pub GreenId);
-impl TypedSyntaxNode for ParamName {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = ParamNamePtr;
-    type Green = ParamNameGreen;
+pub struct StatementListGreen(pub GreenId);
+impl TypedSyntaxNode for StatementList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementList);
+    type StablePtr = StatementListPtr;
+    type Green = StatementListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        StatementListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::TerminalUnderscore => {
-                ParamName::Underscore(TerminalUnderscore::from_syntax_node(db, node))
-            }
-            SyntaxKind::TerminalIdentifier => {
-                ParamName::Name(TerminalIdentifier::from_syntax_node(db, node))
-            }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "ParamName"),
-        }
+        Self(ElementList::new(node))
     }
     fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            ParamName::Underscore(x) => x.as_syntax_node(),
-            ParamName::Name(x) => x.as_syntax_node(),
-        }
+        self.node.clone()
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ParamNamePtr(self.as_syntax_node().0.stable_ptr)
+        StatementListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Param {
+pub struct StatementMissing {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Param {
-    pub const INDEX_MODIFIERS: usize = 0;
-    pub const INDEX_NAME: usize = 1;
-    pub const INDEX_TYPE_CLAUSE: usize = 2;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        modifiers: ModifierListGreen,
-        name: ParamNameGreen,
-        type_clause: TypeClauseGreen,
-    ) -> ParamGreen {
-        let children: Vec<GreenId> = vec![modifiers.0, name.0, type_clause.0];
+impl StatementMissing {
+    pub fn new_green(db: &dyn SyntaxGroup) -> StatementMissingGreen {
+        let children: Vec<GreenId> = vec![];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Param,
+        StatementMissingGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementMissing,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl Param {
-    pub fn modifiers(&self, db: &dyn SyntaxGroup) -> ModifierList {
-        ModifierList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> ParamName {
-        ParamName::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
-        TypeClause::from_syntax_node(db, self.children[2].clone())
-    }
-}
+impl StatementMissing {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamPtr(pub SyntaxStablePtrId);
-impl ParamPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> ParamNameGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            ParamNameGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct StatementMissingPtr(pub SyntaxStablePtrId);
+impl StatementMissingPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamGreen(pub GreenId);
-impl TypedSyntaxNode for Param {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Param);
-    type StablePtr = ParamPtr;
-    type Green = ParamGreen;
+pub struct StatementMissingGreen(pub GreenId);
+impl TypedSyntaxNode for StatementMissing {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementMissing);
+    type StablePtr = StatementMissingPtr;
+    type Green = StatementMissingGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Param,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    ModifierList::missing(db).0,
-                    ParamName::missing(db).0,
-                    TypeClause::missing(db).0,
-                ],
-                width: 0,
-            },
+        StatementMissingGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementMissing,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::Param,
+            SyntaxKind::StatementMissing,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::Param
+            SyntaxKind::StatementMissing
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -5011,275 +5329,435 @@ impl TypedSyntaxNode for Param {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ParamPtr(self.node.0.stable_ptr)
+        StatementMissingPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ModifierList(ElementList<Modifier, 1>);
-impl Deref for ModifierList {
-    type Target = ElementList<Modifier, 1>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct StatementLet {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl ModifierList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ModifierGreen>) -> ModifierListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        ModifierListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ModifierList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.0).collect(),
-                width,
-            },
+impl StatementLet {
+    pub const INDEX_LET_KW: usize = 0;
+    pub const INDEX_PATTERN: usize = 1;
+    pub const INDEX_TYPE_CLAUSE: usize = 2;
+    pub const INDEX_EQ: usize = 3;
+    pub const INDEX_RHS: usize = 4;
+    pub const INDEX_LET_ELSE_CLAUSE: usize = 5;
+    pub const INDEX_SEMICOLON: usize = 6;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        let_kw: TerminalLetGreen,
+        pattern: PatternGreen,
+        type_clause: OptionTypeClauseGreen,
+        eq: TerminalEqGreen,
+        rhs: ExprGreen,
+        let_else_clause: OptionLetElseClauseGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StatementLetGreen {
+        let children: Vec<GreenId> =
+            vec![let_kw.0, pattern.0, type_clause.0, eq.0, rhs.0, let_else_clause.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementLet,
+            details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
+impl StatementLet {
+    pub fn let_kw(&self, db: &dyn SyntaxGroup) -> TerminalLet {
+        TerminalLet::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
+        Pattern::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> OptionTypeClause {
+        OptionTypeClause::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
+        TerminalEq::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn rhs(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn let_else_clause(&self, db: &dyn SyntaxGroup) -> OptionLetElseClause {
+        OptionLetElseClause::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierListPtr(pub SyntaxStablePtrId);
-impl ModifierListPtr {
+pub struct StatementLetPtr(pub SyntaxStablePtrId);
+impl StatementLetPtr {
+    pub fn pattern_green(self, db: &dyn SyntaxGroup) -> PatternGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            PatternGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierListGreen(pub GreenId);
-impl TypedSyntaxNode for ModifierList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ModifierList);
-    type StablePtr = ModifierListPtr;
-    type Green = ModifierListGreen;
+pub struct StatementLetGreen(pub GreenId);
+impl TypedSyntaxNode for StatementLet {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementLet);
+    type StablePtr = StatementLetPtr;
+    type Green = StatementLetGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ModifierListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ModifierList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        StatementLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementLet,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLet::missing(db).0,
+                    Pattern::missing(db).0,
+                    OptionTypeClause::missing(db).0,
+                    TerminalEq::missing(db).0,
+                    Expr::missing(db).0,
+                    OptionLetElseClause::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: 0,
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementLet,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementLet
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         self.node.clone()
     }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StatementLetPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LetElseClause {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl LetElseClause {
+    pub const INDEX_ELSE_KW: usize = 0;
+    pub const INDEX_ELSE_BLOCK: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        else_kw: TerminalElseGreen,
+        else_block: ExprBlockGreen,
+    ) -> LetElseClauseGreen {
+        let children: Vec<GreenId> = vec![else_kw.0, else_block.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        LetElseClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::LetElseClause,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl LetElseClause {
+    pub fn else_kw(&self, db: &dyn SyntaxGroup) -> TerminalElse {
+        TerminalElse::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn else_block(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LetElseClausePtr(pub SyntaxStablePtrId);
+impl LetElseClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LetElseClauseGreen(pub GreenId);
+impl TypedSyntaxNode for LetElseClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::LetElseClause);
+    type StablePtr = LetElseClausePtr;
+    type Green = LetElseClauseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        LetElseClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::LetElseClause,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalElse::missing(db).0, ExprBlock::missing(db).0],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::LetElseClause,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::LetElseClause
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ModifierListPtr(self.node.0.stable_ptr)
+        LetElseClausePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum Modifier {
-    Ref(TerminalRef),
-    Mut(TerminalMut),
+pub enum OptionLetElseClause {
+    Empty(OptionLetElseClauseEmpty),
+    LetElseClause(LetElseClause),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierPtr(pub SyntaxStablePtrId);
-impl ModifierPtr {
+pub struct OptionLetElseClausePtr(pub SyntaxStablePtrId);
+impl OptionLetElseClausePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<TerminalRefPtr> for ModifierPtr {
-    fn from(value: TerminalRefPtr) -> Self {
+impl From<OptionLetElseClauseEmptyPtr> for OptionLetElseClausePtr {
+    fn from(value: OptionLetElseClauseEmptyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalMutPtr> for ModifierPtr {
-    fn from(value: TerminalMutPtr) -> Self {
+impl From<LetElseClausePtr> for OptionLetElseClausePtr {
+    fn from(value: LetElseClausePtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalRefGreen> for ModifierGreen {
-    fn from(value: TerminalRefGreen) -> Self {
+impl From<OptionLetElseClauseEmptyGreen> for OptionLetElseClauseGreen {
+    fn from(value: OptionLetElseClauseEmptyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalMutGreen> for ModifierGreen {
-    fn from(value: TerminalMutGreen) -> Self {
+impl From<LetElseClauseGreen> for OptionLetElseClauseGreen {
+    fn from(value: LetElseClauseGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierGreen(pub GreenId);
-impl TypedSyntaxNode for Modifier {
+pub struct OptionLetElseClauseGreen(pub GreenId);
+impl TypedSyntaxNode for OptionLetElseClause {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = ModifierPtr;
-    type Green = ModifierGreen;
+    type StablePtr = OptionLetElseClausePtr;
+    type Green = OptionLetElseClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::TerminalRef => Modifier::Ref(TerminalRef::from_syntax_node(db, node)),
-            SyntaxKind::TerminalMut => Modifier::Mut(TerminalMut::from_syntax_node(db, node)),
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Modifier"),
+            SyntaxKind::OptionLetElseClauseEmpty => {
+                OptionLetElseClause::Empty(OptionLetElseClauseEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::LetElseClause => {
+                OptionLetElseClause::LetElseClause(LetElseClause::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionLetElseClause"
+            ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            Modifier::Ref(x) => x.as_syntax_node(),
-            Modifier::Mut(x) => x.as_syntax_node(),
+            OptionLetElseClause::Empty(x) => x.as_syntax_node(),
+            OptionLetElseClause::LetElseClause(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ModifierPtr(self.as_syntax_node().0.stable_ptr)
+        OptionLetElseClausePtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ParamList(ElementList<Param, 2>);
-impl Deref for ParamList {
-    type Target = ElementList<Param, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct OptionLetElseClauseEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl ParamList {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        children: Vec<ParamListElementOrSeparatorGreen>,
-    ) -> ParamListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        ParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ParamList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
+impl OptionLetElseClauseEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionLetElseClauseEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionLetElseClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionLetElseClauseEmpty,
+            details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
+impl OptionLetElseClauseEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamListPtr(pub SyntaxStablePtrId);
-impl ParamListPtr {
+pub struct OptionLetElseClauseEmptyPtr(pub SyntaxStablePtrId);
+impl OptionLetElseClauseEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum ParamListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(ParamGreen),
-}
-impl From<TerminalCommaGreen> for ParamListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        ParamListElementOrSeparatorGreen::Separator(value)
-    }
-}
-impl From<ParamGreen> for ParamListElementOrSeparatorGreen {
-    fn from(value: ParamGreen) -> Self {
-        ParamListElementOrSeparatorGreen::Element(value)
-    }
-}
-impl ParamListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            ParamListElementOrSeparatorGreen::Separator(green) => green.0,
-            ParamListElementOrSeparatorGreen::Element(green) => green.0,
-        }
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamListGreen(pub GreenId);
-impl TypedSyntaxNode for ParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ParamList);
-    type StablePtr = ParamListPtr;
-    type Green = ParamListGreen;
+pub struct OptionLetElseClauseEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionLetElseClauseEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionLetElseClauseEmpty);
+    type StablePtr = OptionLetElseClauseEmptyPtr;
+    type Green = OptionLetElseClauseEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ParamList,
+        OptionLetElseClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionLetElseClauseEmpty,
             details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionLetElseClauseEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionLetElseClauseEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionLetElseClauseEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionTerminalSemicolon {
+    Empty(OptionTerminalSemicolonEmpty),
+    TerminalSemicolon(TerminalSemicolon),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalSemicolonPtr(pub SyntaxStablePtrId);
+impl OptionTerminalSemicolonPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionTerminalSemicolonEmptyPtr> for OptionTerminalSemicolonPtr {
+    fn from(value: OptionTerminalSemicolonEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonPtr> for OptionTerminalSemicolonPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionTerminalSemicolonEmptyGreen> for OptionTerminalSemicolonGreen {
+    fn from(value: OptionTerminalSemicolonEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonGreen> for OptionTerminalSemicolonGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalSemicolonGreen(pub GreenId);
+impl TypedSyntaxNode for OptionTerminalSemicolon {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionTerminalSemicolonPtr;
+    type Green = OptionTerminalSemicolonGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionTerminalSemicolonEmpty => OptionTerminalSemicolon::Empty(
+                OptionTerminalSemicolonEmpty::from_syntax_node(db, node),
+            ),
+            SyntaxKind::TerminalSemicolon => OptionTerminalSemicolon::TerminalSemicolon(
+                TerminalSemicolon::from_syntax_node(db, node),
+            ),
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionTerminalSemicolon"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionTerminalSemicolon::Empty(x) => x.as_syntax_node(),
+            OptionTerminalSemicolon::TerminalSemicolon(x) => x.as_syntax_node(),
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ParamListPtr(self.node.0.stable_ptr)
+        OptionTerminalSemicolonPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ImplicitsClause {
+pub struct OptionTerminalSemicolonEmpty {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ImplicitsClause {
-    pub const INDEX_IMPLICITS_KW: usize = 0;
-    pub const INDEX_LPAREN: usize = 1;
-    pub const INDEX_IMPLICITS: usize = 2;
-    pub const INDEX_RPAREN: usize = 3;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        implicits_kw: TerminalImplicitsGreen,
-        lparen: TerminalLParenGreen,
-        implicits: ImplicitsListGreen,
-        rparen: TerminalRParenGreen,
-    ) -> ImplicitsClauseGreen {
-        let children: Vec<GreenId> = vec![implicits_kw.0, lparen.0, implicits.0, rparen.0];
+impl OptionTerminalSemicolonEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionTerminalSemicolonEmptyGreen {
+        let children: Vec<GreenId> = vec![];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ImplicitsClauseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplicitsClause,
+        OptionTerminalSemicolonEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionTerminalSemicolonEmpty,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ImplicitsClause {
-    pub fn implicits_kw(&self, db: &dyn SyntaxGroup) -> TerminalImplicits {
-        TerminalImplicits::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
-        TerminalLParen::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn implicits(&self, db: &dyn SyntaxGroup) -> ImplicitsList {
-        ImplicitsList::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
-        TerminalRParen::from_syntax_node(db, self.children[3].clone())
-    }
-}
+impl OptionTerminalSemicolonEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplicitsClausePtr(pub SyntaxStablePtrId);
-impl ImplicitsClausePtr {
+pub struct OptionTerminalSemicolonEmptyPtr(pub SyntaxStablePtrId);
+impl OptionTerminalSemicolonEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplicitsClauseGreen(pub GreenId);
-impl TypedSyntaxNode for ImplicitsClause {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplicitsClause);
-    type StablePtr = ImplicitsClausePtr;
-    type Green = ImplicitsClauseGreen;
+pub struct OptionTerminalSemicolonEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionTerminalSemicolonEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionTerminalSemicolonEmpty);
+    type StablePtr = OptionTerminalSemicolonEmptyPtr;
+    type Green = OptionTerminalSemicolonEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ImplicitsClauseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplicitsClause,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    TerminalImplicits::missing(db).0,
-                    TerminalLParen::missing(db).0,
-                    ImplicitsList::missing(db).0,
-                    TerminalRParen::missing(db).0,
-                ],
-                width: 0,
-            },
+        OptionTerminalSemicolonEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionTerminalSemicolonEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ImplicitsClause,
+            SyntaxKind::OptionTerminalSemicolonEmpty,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ImplicitsClause
+            SyntaxKind::OptionTerminalSemicolonEmpty
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -5291,188 +5769,327 @@ impl TypedSyntaxNode for ImplicitsClause {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ImplicitsClausePtr(self.node.0.stable_ptr)
+        OptionTerminalSemicolonEmptyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ImplicitsList(ElementList<ExprPath, 2>);
-impl Deref for ImplicitsList {
-    type Target = ElementList<ExprPath, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct StatementExpr {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl ImplicitsList {
+impl StatementExpr {
+    pub const INDEX_EXPR: usize = 0;
+    pub const INDEX_SEMICOLON: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        children: Vec<ImplicitsListElementOrSeparatorGreen>,
-    ) -> ImplicitsListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        ImplicitsListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplicitsList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
+        expr: ExprGreen,
+        semicolon: OptionTerminalSemicolonGreen,
+    ) -> StatementExprGreen {
+        let children: Vec<GreenId> = vec![expr.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementExprGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementExpr,
+            details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplicitsListPtr(pub SyntaxStablePtrId);
-impl ImplicitsListPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum ImplicitsListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(ExprPathGreen),
-}
-impl From<TerminalCommaGreen> for ImplicitsListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        ImplicitsListElementOrSeparatorGreen::Separator(value)
+impl StatementExpr {
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[0].clone())
     }
-}
-impl From<ExprPathGreen> for ImplicitsListElementOrSeparatorGreen {
-    fn from(value: ExprPathGreen) -> Self {
-        ImplicitsListElementOrSeparatorGreen::Element(value)
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> OptionTerminalSemicolon {
+        OptionTerminalSemicolon::from_syntax_node(db, self.children[1].clone())
     }
 }
-impl ImplicitsListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            ImplicitsListElementOrSeparatorGreen::Separator(green) => green.0,
-            ImplicitsListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementExprPtr(pub SyntaxStablePtrId);
+impl StatementExprPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplicitsListGreen(pub GreenId);
-impl TypedSyntaxNode for ImplicitsList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplicitsList);
-    type StablePtr = ImplicitsListPtr;
-    type Green = ImplicitsListGreen;
+pub struct StatementExprGreen(pub GreenId);
+impl TypedSyntaxNode for StatementExpr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementExpr);
+    type StablePtr = StatementExprPtr;
+    type Green = StatementExprGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ImplicitsListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplicitsList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        StatementExprGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementExpr,
+            details: GreenNodeDetails::Node {
+                children: vec![Expr::missing(db).0, OptionTerminalSemicolon::missing(db).0],
+                width: 0,
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementExpr,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementExpr
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ImplicitsListPtr(self.node.0.stable_ptr)
+        StatementExprPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionImplicitsClause {
-    Empty(OptionImplicitsClauseEmpty),
-    ImplicitsClause(ImplicitsClause),
+pub struct StatementReturn {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionImplicitsClausePtr(pub SyntaxStablePtrId);
-impl OptionImplicitsClausePtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
+impl StatementReturn {
+    pub const INDEX_RETURN_KW: usize = 0;
+    pub const INDEX_EXPR: usize = 1;
+    pub const INDEX_SEMICOLON: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        return_kw: TerminalReturnGreen,
+        expr: ExprGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StatementReturnGreen {
+        let children: Vec<GreenId> = vec![return_kw.0, expr.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementReturnGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementReturn,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl StatementReturn {
+    pub fn return_kw(&self, db: &dyn SyntaxGroup) -> TerminalReturn {
+        TerminalReturn::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementReturnPtr(pub SyntaxStablePtrId);
+impl StatementReturnPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<OptionImplicitsClauseEmptyPtr> for OptionImplicitsClausePtr {
-    fn from(value: OptionImplicitsClauseEmptyPtr) -> Self {
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementReturnGreen(pub GreenId);
+impl TypedSyntaxNode for StatementReturn {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementReturn);
+    type StablePtr = StatementReturnPtr;
+    type Green = StatementReturnGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StatementReturnGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementReturn,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalReturn::missing(db).0,
+                    Expr::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementReturn,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementReturn
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StatementReturnPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprClause {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprClause {
+    pub const INDEX_EXPR: usize = 0;
+    pub fn new_green(db: &dyn SyntaxGroup, expr: ExprGreen) -> ExprClauseGreen {
+        let children: Vec<GreenId> = vec![expr.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClause,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprClause {
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[0].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprClausePtr(pub SyntaxStablePtrId);
+impl ExprClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprClauseGreen(pub GreenId);
+impl TypedSyntaxNode for ExprClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprClause);
+    type StablePtr = ExprClausePtr;
+    type Green = ExprClauseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClause,
+            details: GreenNodeDetails::Node { children: vec![Expr::missing(db).0], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprClause,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprClause
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprClausePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionExprClause {
+    Empty(OptionExprClauseEmpty),
+    ExprClause(ExprClause),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionExprClausePtr(pub SyntaxStablePtrId);
+impl OptionExprClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionExprClauseEmptyPtr> for OptionExprClausePtr {
+    fn from(value: OptionExprClauseEmptyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ImplicitsClausePtr> for OptionImplicitsClausePtr {
-    fn from(value: ImplicitsClausePtr) -> Self {
+impl From<ExprClausePtr> for OptionExprClausePtr {
+    fn from(value: ExprClausePtr) -> Self {
         Self(value.0)
     }
 }
-impl From<OptionImplicitsClauseEmptyGreen> for OptionImplicitsClauseGreen {
-    fn from(value: OptionImplicitsClauseEmptyGreen) -> Self {
+impl From<OptionExprClauseEmptyGreen> for OptionExprClauseGreen {
+    fn from(value: OptionExprClauseEmptyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<ImplicitsClauseGreen> for OptionImplicitsClauseGreen {
-    fn from(value: ImplicitsClauseGreen) -> Self {
+impl From<ExprClauseGreen> for OptionExprClauseGreen {
+    fn from(value: ExprClauseGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionImplicitsClauseGreen(pub GreenId);
-impl TypedSyntaxNode for OptionImplicitsClause {
+pub struct OptionExprClauseGreen(pub GreenId);
+impl TypedSyntaxNode for OptionExprClause {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionImplicitsClausePtr;
-    type Green = OptionImplicitsClauseGreen;
+    type StablePtr = OptionExprClausePtr;
+    type Green = OptionExprClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::OptionImplicitsClauseEmpty => {
-                OptionImplicitsClause::Empty(OptionImplicitsClauseEmpty::from_syntax_node(db, node))
+            SyntaxKind::OptionExprClauseEmpty => {
+                OptionExprClause::Empty(OptionExprClauseEmpty::from_syntax_node(db, node))
             }
-            SyntaxKind::ImplicitsClause => {
-                OptionImplicitsClause::ImplicitsClause(ImplicitsClause::from_syntax_node(db, node))
+            SyntaxKind::ExprClause => {
+                OptionExprClause::ExprClause(ExprClause::from_syntax_node(db, node))
             }
             _ => panic!(
                 "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionImplicitsClause"
+                kind, "OptionExprClause"
             ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            OptionImplicitsClause::Empty(x) => x.as_syntax_node(),
-            OptionImplicitsClause::ImplicitsClause(x) => x.as_syntax_node(),
+            OptionExprClause::Empty(x) => x.as_syntax_node(),
+            OptionExprClause::ExprClause(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionImplicitsClausePtr(self.as_syntax_node().0.stable_ptr)
+        OptionExprClausePtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionImplicitsClauseEmpty {
+pub struct OptionExprClauseEmpty {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl OptionImplicitsClauseEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionImplicitsClauseEmptyGreen {
+impl OptionExprClauseEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionExprClauseEmptyGreen {
         let children: Vec<GreenId> = vec![];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionImplicitsClauseEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionImplicitsClauseEmpty,
+        OptionExprClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionExprClauseEmpty,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl OptionImplicitsClauseEmpty {}
+impl OptionExprClauseEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionImplicitsClauseEmptyPtr(pub SyntaxStablePtrId);
-impl OptionImplicitsClauseEmptyPtr {
+pub struct OptionExprClauseEmptyPtr(pub SyntaxStablePtrId);
+impl OptionExprClauseEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionImplicitsClauseEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionImplicitsClauseEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionImplicitsClauseEmpty);
-    type StablePtr = OptionImplicitsClauseEmptyPtr;
-    type Green = OptionImplicitsClauseEmptyGreen;
+pub struct OptionExprClauseEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionExprClauseEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionExprClauseEmpty);
+    type StablePtr = OptionExprClauseEmptyPtr;
+    type Green = OptionExprClauseEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionImplicitsClauseEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionImplicitsClauseEmpty,
+        OptionExprClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionExprClauseEmpty,
             details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
@@ -5480,10 +6097,3780 @@ impl TypedSyntaxNode for OptionImplicitsClauseEmpty {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::OptionImplicitsClauseEmpty,
+            SyntaxKind::OptionExprClauseEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionExprClauseEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionExprClauseEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StatementBreak {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl StatementBreak {
+    pub const INDEX_BREAK_KW: usize = 0;
+    pub const INDEX_EXPR_CLAUSE: usize = 1;
+    pub const INDEX_SEMICOLON: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        break_kw: TerminalBreakGreen,
+        expr_clause: OptionExprClauseGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StatementBreakGreen {
+        let children: Vec<GreenId> = vec![break_kw.0, expr_clause.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementBreak,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl StatementBreak {
+    pub fn break_kw(&self, db: &dyn SyntaxGroup) -> TerminalBreak {
+        TerminalBreak::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn expr_clause(&self, db: &dyn SyntaxGroup) -> OptionExprClause {
+        OptionExprClause::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementBreakPtr(pub SyntaxStablePtrId);
+impl StatementBreakPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementBreakGreen(pub GreenId);
+impl TypedSyntaxNode for StatementBreak {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementBreak);
+    type StablePtr = StatementBreakPtr;
+    type Green = StatementBreakGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StatementBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementBreak,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalBreak::missing(db).0,
+                    OptionExprClause::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementBreak,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementBreak
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StatementBreakPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StatementContinue {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl StatementContinue {
+    pub const INDEX_CONTINUE_KW: usize = 0;
+    pub const INDEX_SEMICOLON: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        continue_kw: TerminalContinueGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StatementContinueGreen {
+        let children: Vec<GreenId> = vec![continue_kw.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementContinue,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl StatementContinue {
+    pub fn continue_kw(&self, db: &dyn SyntaxGroup) -> TerminalContinue {
+        TerminalContinue::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementContinuePtr(pub SyntaxStablePtrId);
+impl StatementContinuePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementContinueGreen(pub GreenId);
+impl TypedSyntaxNode for StatementContinue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementContinue);
+    type StablePtr = StatementContinuePtr;
+    type Green = StatementContinueGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StatementContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementContinue,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalContinue::missing(db).0, TerminalSemicolon::missing(db).0],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementContinue,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementContinue
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StatementContinuePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParamName {
+    Underscore(TerminalUnderscore),
+    Name(TerminalIdentifier),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamNamePtr(pub SyntaxStablePtrId);
+impl ParamNamePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<TerminalUnderscorePtr> for ParamNamePtr {
+    fn from(value: TerminalUnderscorePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalIdentifierPtr> for ParamNamePtr {
+    fn from(value: TerminalIdentifierPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalUnderscoreGreen> for ParamNameGreen {
+    fn from(value: TerminalUnderscoreGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalIdentifierGreen> for ParamNameGreen {
+    fn from(value: TerminalIdentifierGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamNameGreen(pub GreenId);
+impl TypedSyntaxNode for ParamName {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = ParamNamePtr;
+    type Green = ParamNameGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TerminalUnderscore => {
+                ParamName::Underscore(TerminalUnderscore::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalIdentifier => {
+                ParamName::Name(TerminalIdentifier::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "ParamName"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            ParamName::Underscore(x) => x.as_syntax_node(),
+            ParamName::Name(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ParamNamePtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Param {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Param {
+    pub const INDEX_MODIFIERS: usize = 0;
+    pub const INDEX_NAME: usize = 1;
+    pub const INDEX_TYPE_CLAUSE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        modifiers: ModifierListGreen,
+        name: ParamNameGreen,
+        type_clause: TypeClauseGreen,
+    ) -> ParamGreen {
+        let children: Vec<GreenId> = vec![modifiers.0, name.0, type_clause.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Param,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl Param {
+    pub fn modifiers(&self, db: &dyn SyntaxGroup) -> ModifierList {
+        ModifierList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> ParamName {
+        ParamName::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
+        TypeClause::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamPtr(pub SyntaxStablePtrId);
+impl ParamPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> ParamNameGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            ParamNameGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamGreen(pub GreenId);
+impl TypedSyntaxNode for Param {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Param);
+    type StablePtr = ParamPtr;
+    type Green = ParamGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Param,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    ModifierList::missing(db).0,
+                    ParamName::missing(db).0,
+                    TypeClause::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::Param,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::Param
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ParamPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ModifierList(ElementList<Modifier, 1>);
+impl Deref for ModifierList {
+    type Target = ElementList<Modifier, 1>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ModifierList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ModifierGreen>) -> ModifierListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
+        ModifierListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ModifierList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.0).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierListPtr(pub SyntaxStablePtrId);
+impl ModifierListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierListGreen(pub GreenId);
+impl TypedSyntaxNode for ModifierList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ModifierList);
+    type StablePtr = ModifierListPtr;
+    type Green = ModifierListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ModifierListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ModifierList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ModifierListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Modifier {
+    Ref(TerminalRef),
+    Mut(TerminalMut),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierPtr(pub SyntaxStablePtrId);
+impl ModifierPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<TerminalRefPtr> for ModifierPtr {
+    fn from(value: TerminalRefPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalMutPtr> for ModifierPtr {
+    fn from(value: TerminalMutPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalRefGreen> for ModifierGreen {
+    fn from(value: TerminalRefGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalMutGreen> for ModifierGreen {
+    fn from(value: TerminalMutGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierGreen(pub GreenId);
+impl TypedSyntaxNode for Modifier {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = ModifierPtr;
+    type Green = ModifierGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TerminalRef => Modifier::Ref(TerminalRef::from_syntax_node(db, node)),
+            SyntaxKind::TerminalMut => Modifier::Mut(TerminalMut::from_syntax_node(db, node)),
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Modifier"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            Modifier::Ref(x) => x.as_syntax_node(),
+            Modifier::Mut(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ModifierPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ParamList(ElementList<Param, 2>);
+impl Deref for ParamList {
+    type Target = ElementList<Param, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ParamList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<ParamListElementOrSeparatorGreen>,
+    ) -> ParamListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        ParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ParamList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamListPtr(pub SyntaxStablePtrId);
+impl ParamListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ParamListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(ParamGreen),
+}
+impl From<TerminalCommaGreen> for ParamListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        ParamListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<ParamGreen> for ParamListElementOrSeparatorGreen {
+    fn from(value: ParamGreen) -> Self {
+        ParamListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl ParamListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            ParamListElementOrSeparatorGreen::Separator(green) => green.0,
+            ParamListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamListGreen(pub GreenId);
+impl TypedSyntaxNode for ParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ParamList);
+    type StablePtr = ParamListPtr;
+    type Green = ParamListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ParamList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ParamListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ImplicitsClause {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ImplicitsClause {
+    pub const INDEX_IMPLICITS_KW: usize = 0;
+    pub const INDEX_LPAREN: usize = 1;
+    pub const INDEX_IMPLICITS: usize = 2;
+    pub const INDEX_RPAREN: usize = 3;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        implicits_kw: TerminalImplicitsGreen,
+        lparen: TerminalLParenGreen,
+        implicits: ImplicitsListGreen,
+        rparen: TerminalRParenGreen,
+    ) -> ImplicitsClauseGreen {
+        let children: Vec<GreenId> = vec![implicits_kw.0, lparen.0, implicits.0, rparen.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ImplicitsClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplicitsClause,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ImplicitsClause {
+    pub fn implicits_kw(&self, db: &dyn SyntaxGroup) -> TerminalImplicits {
+        TerminalImplicits::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
+        TerminalLParen::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn implicits(&self, db: &dyn SyntaxGroup) -> ImplicitsList {
+        ImplicitsList::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
+        TerminalRParen::from_syntax_node(db, self.children[3].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplicitsClausePtr(pub SyntaxStablePtrId);
+impl ImplicitsClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplicitsClauseGreen(pub GreenId);
+impl TypedSyntaxNode for ImplicitsClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplicitsClause);
+    type StablePtr = ImplicitsClausePtr;
+    type Green = ImplicitsClauseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ImplicitsClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplicitsClause,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalImplicits::missing(db).0,
+                    TerminalLParen::missing(db).0,
+                    ImplicitsList::missing(db).0,
+                    TerminalRParen::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ImplicitsClause,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ImplicitsClause
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ImplicitsClausePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ImplicitsList(ElementList<ExprPath, 2>);
+impl Deref for ImplicitsList {
+    type Target = ElementList<ExprPath, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ImplicitsList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<ImplicitsListElementOrSeparatorGreen>,
+    ) -> ImplicitsListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        ImplicitsListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplicitsList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplicitsListPtr(pub SyntaxStablePtrId);
+impl ImplicitsListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ImplicitsListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(ExprPathGreen),
+}
+impl From<TerminalCommaGreen> for ImplicitsListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        ImplicitsListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<ExprPathGreen> for ImplicitsListElementOrSeparatorGreen {
+    fn from(value: ExprPathGreen) -> Self {
+        ImplicitsListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl ImplicitsListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            ImplicitsListElementOrSeparatorGreen::Separator(green) => green.0,
+            ImplicitsListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplicitsListGreen(pub GreenId);
+impl TypedSyntaxNode for ImplicitsList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplicitsList);
+    type StablePtr = ImplicitsListPtr;
+    type Green = ImplicitsListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ImplicitsListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplicitsList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ImplicitsListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionImplicitsClause {
+    Empty(OptionImplicitsClauseEmpty),
+    ImplicitsClause(ImplicitsClause),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionImplicitsClausePtr(pub SyntaxStablePtrId);
+impl OptionImplicitsClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionImplicitsClauseEmptyPtr> for OptionImplicitsClausePtr {
+    fn from(value: OptionImplicitsClauseEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ImplicitsClausePtr> for OptionImplicitsClausePtr {
+    fn from(value: ImplicitsClausePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionImplicitsClauseEmptyGreen> for OptionImplicitsClauseGreen {
+    fn from(value: OptionImplicitsClauseEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ImplicitsClauseGreen> for OptionImplicitsClauseGreen {
+    fn from(value: ImplicitsClauseGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionImplicitsClauseGreen(pub GreenId);
+impl TypedSyntaxNode for OptionImplicitsClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionImplicitsClausePtr;
+    type Green = OptionImplicitsClauseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionImplicitsClauseEmpty => {
+                OptionImplicitsClause::Empty(OptionImplicitsClauseEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::ImplicitsClause => {
+                OptionImplicitsClause::ImplicitsClause(ImplicitsClause::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionImplicitsClause"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionImplicitsClause::Empty(x) => x.as_syntax_node(),
+            OptionImplicitsClause::ImplicitsClause(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionImplicitsClausePtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionImplicitsClauseEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl OptionImplicitsClauseEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionImplicitsClauseEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionImplicitsClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionImplicitsClauseEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionImplicitsClauseEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionImplicitsClauseEmptyPtr(pub SyntaxStablePtrId);
+impl OptionImplicitsClauseEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionImplicitsClauseEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionImplicitsClauseEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionImplicitsClauseEmpty);
+    type StablePtr = OptionImplicitsClauseEmptyPtr;
+    type Green = OptionImplicitsClauseEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionImplicitsClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionImplicitsClauseEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionImplicitsClauseEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionImplicitsClauseEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionImplicitsClauseEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionTerminalNoPanic {
+    Empty(OptionTerminalNoPanicEmpty),
+    TerminalNoPanic(TerminalNoPanic),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalNoPanicPtr(pub SyntaxStablePtrId);
+impl OptionTerminalNoPanicPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionTerminalNoPanicEmptyPtr> for OptionTerminalNoPanicPtr {
+    fn from(value: OptionTerminalNoPanicEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalNoPanicPtr> for OptionTerminalNoPanicPtr {
+    fn from(value: TerminalNoPanicPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionTerminalNoPanicEmptyGreen> for OptionTerminalNoPanicGreen {
+    fn from(value: OptionTerminalNoPanicEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalNoPanicGreen> for OptionTerminalNoPanicGreen {
+    fn from(value: TerminalNoPanicGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalNoPanicGreen(pub GreenId);
+impl TypedSyntaxNode for OptionTerminalNoPanic {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionTerminalNoPanicPtr;
+    type Green = OptionTerminalNoPanicGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionTerminalNoPanicEmpty => {
+                OptionTerminalNoPanic::Empty(OptionTerminalNoPanicEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalNoPanic => {
+                OptionTerminalNoPanic::TerminalNoPanic(TerminalNoPanic::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionTerminalNoPanic"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionTerminalNoPanic::Empty(x) => x.as_syntax_node(),
+            OptionTerminalNoPanic::TerminalNoPanic(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionTerminalNoPanicPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionTerminalNoPanicEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl OptionTerminalNoPanicEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionTerminalNoPanicEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionTerminalNoPanicEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionTerminalNoPanicEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionTerminalNoPanicEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalNoPanicEmptyPtr(pub SyntaxStablePtrId);
+impl OptionTerminalNoPanicEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionTerminalNoPanicEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionTerminalNoPanicEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionTerminalNoPanicEmpty);
+    type StablePtr = OptionTerminalNoPanicEmptyPtr;
+    type Green = OptionTerminalNoPanicEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionTerminalNoPanicEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionTerminalNoPanicEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionTerminalNoPanicEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionTerminalNoPanicEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionTerminalNoPanicEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FunctionSignature {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl FunctionSignature {
+    pub const INDEX_LPAREN: usize = 0;
+    pub const INDEX_PARAMETERS: usize = 1;
+    pub const INDEX_RPAREN: usize = 2;
+    pub const INDEX_RET_TY: usize = 3;
+    pub const INDEX_IMPLICITS_CLAUSE: usize = 4;
+    pub const INDEX_OPTIONAL_NO_PANIC: usize = 5;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lparen: TerminalLParenGreen,
+        parameters: ParamListGreen,
+        rparen: TerminalRParenGreen,
+        ret_ty: OptionReturnTypeClauseGreen,
+        implicits_clause: OptionImplicitsClauseGreen,
+        optional_no_panic: OptionTerminalNoPanicGreen,
+    ) -> FunctionSignatureGreen {
+        let children: Vec<GreenId> = vec![
+            lparen.0,
+            parameters.0,
+            rparen.0,
+            ret_ty.0,
+            implicits_clause.0,
+            optional_no_panic.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        FunctionSignatureGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::FunctionSignature,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl FunctionSignature {
+    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
+        TerminalLParen::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn parameters(&self, db: &dyn SyntaxGroup) -> ParamList {
+        ParamList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
+        TerminalRParen::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn ret_ty(&self, db: &dyn SyntaxGroup) -> OptionReturnTypeClause {
+        OptionReturnTypeClause::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn implicits_clause(&self, db: &dyn SyntaxGroup) -> OptionImplicitsClause {
+        OptionImplicitsClause::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn optional_no_panic(&self, db: &dyn SyntaxGroup) -> OptionTerminalNoPanic {
+        OptionTerminalNoPanic::from_syntax_node(db, self.children[5].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FunctionSignaturePtr(pub SyntaxStablePtrId);
+impl FunctionSignaturePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FunctionSignatureGreen(pub GreenId);
+impl TypedSyntaxNode for FunctionSignature {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::FunctionSignature);
+    type StablePtr = FunctionSignaturePtr;
+    type Green = FunctionSignatureGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        FunctionSignatureGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::FunctionSignature,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLParen::missing(db).0,
+                    ParamList::missing(db).0,
+                    TerminalRParen::missing(db).0,
+                    OptionReturnTypeClause::missing(db).0,
+                    OptionImplicitsClause::missing(db).0,
+                    OptionTerminalNoPanic::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::FunctionSignature,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::FunctionSignature
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        FunctionSignaturePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Member {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Member {
+    pub const INDEX_VISIBILITY: usize = 0;
+    pub const INDEX_NAME: usize = 1;
+    pub const INDEX_TYPE_CLAUSE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        visibility: VisibilityGreen,
+        name: TerminalIdentifierGreen,
+        type_clause: TypeClauseGreen,
+    ) -> MemberGreen {
+        let children: Vec<GreenId> = vec![visibility.0, name.0, type_clause.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        MemberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Member,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl Member {
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
+        TypeClause::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MemberPtr(pub SyntaxStablePtrId);
+impl MemberPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MemberGreen(pub GreenId);
+impl TypedSyntaxNode for Member {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Member);
+    type StablePtr = MemberPtr;
+    type Green = MemberGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        MemberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Member,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Visibility::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    TypeClause::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::Member,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::Member
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        MemberPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MemberList(ElementList<Member, 2>);
+impl Deref for MemberList {
+    type Target = ElementList<Member, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl MemberList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<MemberListElementOrSeparatorGreen>,
+    ) -> MemberListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        MemberListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::MemberList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MemberListPtr(pub SyntaxStablePtrId);
+impl MemberListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum MemberListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(MemberGreen),
+}
+impl From<TerminalCommaGreen> for MemberListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        MemberListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<MemberGreen> for MemberListElementOrSeparatorGreen {
+    fn from(value: MemberGreen) -> Self {
+        MemberListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl MemberListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            MemberListElementOrSeparatorGreen::Separator(green) => green.0,
+            MemberListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MemberListGreen(pub GreenId);
+impl TypedSyntaxNode for MemberList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::MemberList);
+    type StablePtr = MemberListPtr;
+    type Green = MemberListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        MemberListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::MemberList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        MemberListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Item {
+    Module(ItemModule),
+    Use(ItemUse),
+    FreeFunction(ItemFreeFunction),
+    ExternFunction(ItemExternFunction),
+    ExternType(ItemExternType),
+    Trait(ItemTrait),
+    Impl(ItemImpl),
+    Struct(ItemStruct),
+    Enum(ItemEnum),
+    TypeAlias(ItemTypeAlias),
+    Constant(ItemConstant),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemPtr(pub SyntaxStablePtrId);
+impl ItemPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<ItemModulePtr> for ItemPtr {
+    fn from(value: ItemModulePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemUsePtr> for ItemPtr {
+    fn from(value: ItemUsePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemFreeFunctionPtr> for ItemPtr {
+    fn from(value: ItemFreeFunctionPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemExternFunctionPtr> for ItemPtr {
+    fn from(value: ItemExternFunctionPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemExternTypePtr> for ItemPtr {
+    fn from(value: ItemExternTypePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemTraitPtr> for ItemPtr {
+    fn from(value: ItemTraitPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemImplPtr> for ItemPtr {
+    fn from(value: ItemImplPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemStructPtr> for ItemPtr {
+    fn from(value: ItemStructPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemEnumPtr> for ItemPtr {
+    fn from(value: ItemEnumPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemTypeAliasPtr> for ItemPtr {
+    fn from(value: ItemTypeAliasPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemConstantPtr> for ItemPtr {
+    fn from(value: ItemConstantPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemModuleGreen> for ItemGreen {
+    fn from(value: ItemModuleGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemUseGreen> for ItemGreen {
+    fn from(value: ItemUseGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemFreeFunctionGreen> for ItemGreen {
+    fn from(value: ItemFreeFunctionGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemExternFunctionGreen> for ItemGreen {
+    fn from(value: ItemExternFunctionGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemExternTypeGreen> for ItemGreen {
+    fn from(value: ItemExternTypeGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemTraitGreen> for ItemGreen {
+    fn from(value: ItemTraitGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemImplGreen> for ItemGreen {
+    fn from(value: ItemImplGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemStructGreen> for ItemGreen {
+    fn from(value: ItemStructGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemEnumGreen> for ItemGreen {
+    fn from(value: ItemEnumGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemTypeAliasGreen> for ItemGreen {
+    fn from(value: ItemTypeAliasGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemConstantGreen> for ItemGreen {
+    fn from(value: ItemConstantGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemGreen(pub GreenId);
+impl TypedSyntaxNode for Item {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = ItemPtr;
+    type Green = ItemGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::ItemModule => Item::Module(ItemModule::from_syntax_node(db, node)),
+            SyntaxKind::ItemUse => Item::Use(ItemUse::from_syntax_node(db, node)),
+            SyntaxKind::ItemFreeFunction => {
+                Item::FreeFunction(ItemFreeFunction::from_syntax_node(db, node))
+            }
+            SyntaxKind::ItemExternFunction => {
+                Item::ExternFunction(ItemExternFunction::from_syntax_node(db, node))
+            }
+            SyntaxKind::ItemExternType => {
+                Item::ExternType(ItemExternType::from_syntax_node(db, node))
+            }
+            SyntaxKind::ItemTrait => Item::Trait(ItemTrait::from_syntax_node(db, node)),
+            SyntaxKind::ItemImpl => Item::Impl(ItemImpl::from_syntax_node(db, node)),
+            SyntaxKind::ItemStruct => Item::Struct(ItemStruct::from_syntax_node(db, node)),
+            SyntaxKind::ItemEnum => Item::Enum(ItemEnum::from_syntax_node(db, node)),
+            SyntaxKind::ItemTypeAlias => Item::TypeAlias(ItemTypeAlias::from_syntax_node(db, node)),
+            SyntaxKind::ItemConstant => Item::Constant(ItemConstant::from_syntax_node(db, node)),
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Item"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            Item::Module(x) => x.as_syntax_node(),
+            Item::Use(x) => x.as_syntax_node(),
+            Item::FreeFunction(x) => x.as_syntax_node(),
+            Item::ExternFunction(x) => x.as_syntax_node(),
+            Item::ExternType(x) => x.as_syntax_node(),
+            Item::Trait(x) => x.as_syntax_node(),
+            Item::Impl(x) => x.as_syntax_node(),
+            Item::Struct(x) => x.as_syntax_node(),
+            Item::Enum(x) => x.as_syntax_node(),
+            Item::TypeAlias(x) => x.as_syntax_node(),
+            Item::Constant(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemList(ElementList<Item, 1>);
+impl Deref for ItemList {
+    type Target = ElementList<Item, 1>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ItemList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ItemGreen>) -> ItemListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
+        ItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.0).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemListPtr(pub SyntaxStablePtrId);
+impl ItemListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemListGreen(pub GreenId);
+impl TypedSyntaxNode for ItemList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemList);
+    type StablePtr = ItemListPtr;
+    type Green = ItemListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Visibility {
+    Default(VisibilityDefault),
+    Pub(VisibilityPub),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityPtr(pub SyntaxStablePtrId);
+impl VisibilityPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<VisibilityDefaultPtr> for VisibilityPtr {
+    fn from(value: VisibilityDefaultPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<VisibilityPubPtr> for VisibilityPtr {
+    fn from(value: VisibilityPubPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<VisibilityDefaultGreen> for VisibilityGreen {
+    fn from(value: VisibilityDefaultGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<VisibilityPubGreen> for VisibilityGreen {
+    fn from(value: VisibilityPubGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityGreen(pub GreenId);
+impl TypedSyntaxNode for Visibility {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = VisibilityPtr;
+    type Green = VisibilityGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::VisibilityDefault => {
+                Visibility::Default(VisibilityDefault::from_syntax_node(db, node))
+            }
+            SyntaxKind::VisibilityPub => Visibility::Pub(VisibilityPub::from_syntax_node(db, node)),
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Visibility"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            Visibility::Default(x) => x.as_syntax_node(),
+            Visibility::Pub(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        VisibilityPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VisibilityDefault {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl VisibilityDefault {
+    pub fn new_green(db: &dyn SyntaxGroup) -> VisibilityDefaultGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        VisibilityDefaultGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityDefault,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl VisibilityDefault {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityDefaultPtr(pub SyntaxStablePtrId);
+impl VisibilityDefaultPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityDefaultGreen(pub GreenId);
+impl TypedSyntaxNode for VisibilityDefault {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::VisibilityDefault);
+    type StablePtr = VisibilityDefaultPtr;
+    type Green = VisibilityDefaultGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        VisibilityDefaultGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityDefault,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::VisibilityDefault,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::VisibilityDefault
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        VisibilityDefaultPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VisibilityPub {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl VisibilityPub {
+    pub const INDEX_PUB_KW: usize = 0;
+    pub fn new_green(db: &dyn SyntaxGroup, pub_kw: TerminalPubGreen) -> VisibilityPubGreen {
+        let children: Vec<GreenId> = vec![pub_kw.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        VisibilityPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityPub,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl VisibilityPub {
+    pub fn pub_kw(&self, db: &dyn SyntaxGroup) -> TerminalPub {
+        TerminalPub::from_syntax_node(db, self.children[0].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityPubPtr(pub SyntaxStablePtrId);
+impl VisibilityPubPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityPubGreen(pub GreenId);
+impl TypedSyntaxNode for VisibilityPub {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::VisibilityPub);
+    type StablePtr = VisibilityPubPtr;
+    type Green = VisibilityPubGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        VisibilityPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityPub,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalPub::missing(db).0],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::VisibilityPub,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::VisibilityPub
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        VisibilityPubPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Attribute {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Attribute {
+    pub const INDEX_HASH: usize = 0;
+    pub const INDEX_LBRACK: usize = 1;
+    pub const INDEX_ATTR: usize = 2;
+    pub const INDEX_ARGS: usize = 3;
+    pub const INDEX_RBRACK: usize = 4;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        hash: TerminalHashGreen,
+        lbrack: TerminalLBrackGreen,
+        attr: TerminalIdentifierGreen,
+        args: OptionAttributeArgsGreen,
+        rbrack: TerminalRBrackGreen,
+    ) -> AttributeGreen {
+        let children: Vec<GreenId> = vec![hash.0, lbrack.0, attr.0, args.0, rbrack.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        AttributeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Attribute,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl Attribute {
+    pub fn hash(&self, db: &dyn SyntaxGroup) -> TerminalHash {
+        TerminalHash::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn lbrack(&self, db: &dyn SyntaxGroup) -> TerminalLBrack {
+        TerminalLBrack::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn attr(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn args(&self, db: &dyn SyntaxGroup) -> OptionAttributeArgs {
+        OptionAttributeArgs::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn rbrack(&self, db: &dyn SyntaxGroup) -> TerminalRBrack {
+        TerminalRBrack::from_syntax_node(db, self.children[4].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributePtr(pub SyntaxStablePtrId);
+impl AttributePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributeGreen(pub GreenId);
+impl TypedSyntaxNode for Attribute {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Attribute);
+    type StablePtr = AttributePtr;
+    type Green = AttributeGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        AttributeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Attribute,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalHash::missing(db).0,
+                    TerminalLBrack::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionAttributeArgs::missing(db).0,
+                    TerminalRBrack::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::Attribute,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::Attribute
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        AttributePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AttributeList(ElementList<Attribute, 1>);
+impl Deref for AttributeList {
+    type Target = ElementList<Attribute, 1>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl AttributeList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<AttributeGreen>) -> AttributeListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
+        AttributeListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.0).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributeListPtr(pub SyntaxStablePtrId);
+impl AttributeListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributeListGreen(pub GreenId);
+impl TypedSyntaxNode for AttributeList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::AttributeList);
+    type StablePtr = AttributeListPtr;
+    type Green = AttributeListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        AttributeListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        AttributeListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemModule {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemModule {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_MODULE_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_BODY: usize = 4;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        module_kw: TerminalModuleGreen,
+        name: TerminalIdentifierGreen,
+        body: MaybeModuleBodyGreen,
+    ) -> ItemModuleGreen {
+        let children: Vec<GreenId> = vec![attributes.0, visibility.0, module_kw.0, name.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemModule,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemModule {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn module_kw(&self, db: &dyn SyntaxGroup) -> TerminalModule {
+        TerminalModule::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeModuleBody {
+        MaybeModuleBody::from_syntax_node(db, self.children[4].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemModulePtr(pub SyntaxStablePtrId);
+impl ItemModulePtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemModuleGreen(pub GreenId);
+impl TypedSyntaxNode for ItemModule {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemModule);
+    type StablePtr = ItemModulePtr;
+    type Green = ItemModuleGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemModule,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalModule::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    MaybeModuleBody::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemModule,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemModule
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemModulePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MaybeModuleBody {
+    Some(ModuleBody),
+    None(TerminalSemicolon),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeModuleBodyPtr(pub SyntaxStablePtrId);
+impl MaybeModuleBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<ModuleBodyPtr> for MaybeModuleBodyPtr {
+    fn from(value: ModuleBodyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonPtr> for MaybeModuleBodyPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ModuleBodyGreen> for MaybeModuleBodyGreen {
+    fn from(value: ModuleBodyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonGreen> for MaybeModuleBodyGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeModuleBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeModuleBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = MaybeModuleBodyPtr;
+    type Green = MaybeModuleBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::ModuleBody => MaybeModuleBody::Some(ModuleBody::from_syntax_node(db, node)),
+            SyntaxKind::TerminalSemicolon => {
+                MaybeModuleBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => {
+                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeModuleBody")
+            }
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            MaybeModuleBody::Some(x) => x.as_syntax_node(),
+            MaybeModuleBody::None(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        MaybeModuleBodyPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ModuleBody {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ModuleBody {
+    pub const INDEX_LBRACE: usize = 0;
+    pub const INDEX_ITEMS: usize = 1;
+    pub const INDEX_RBRACE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lbrace: TerminalLBraceGreen,
+        items: ItemListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ModuleBodyGreen {
+        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ModuleBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ModuleBody,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ModuleBody {
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn items(&self, db: &dyn SyntaxGroup) -> ItemList {
+        ItemList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModuleBodyPtr(pub SyntaxStablePtrId);
+impl ModuleBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModuleBodyGreen(pub GreenId);
+impl TypedSyntaxNode for ModuleBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ModuleBody);
+    type StablePtr = ModuleBodyPtr;
+    type Green = ModuleBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ModuleBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ModuleBody,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLBrace::missing(db).0,
+                    ItemList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ModuleBody,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ModuleBody
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ModuleBodyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionAttributeArgs {
+    Empty(OptionAttributeArgsEmpty),
+    AttributeArgs(AttributeArgs),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionAttributeArgsPtr(pub SyntaxStablePtrId);
+impl OptionAttributeArgsPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionAttributeArgsEmptyPtr> for OptionAttributeArgsPtr {
+    fn from(value: OptionAttributeArgsEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<AttributeArgsPtr> for OptionAttributeArgsPtr {
+    fn from(value: AttributeArgsPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionAttributeArgsEmptyGreen> for OptionAttributeArgsGreen {
+    fn from(value: OptionAttributeArgsEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<AttributeArgsGreen> for OptionAttributeArgsGreen {
+    fn from(value: AttributeArgsGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionAttributeArgsGreen(pub GreenId);
+impl TypedSyntaxNode for OptionAttributeArgs {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionAttributeArgsPtr;
+    type Green = OptionAttributeArgsGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionAttributeArgsEmpty => {
+                OptionAttributeArgs::Empty(OptionAttributeArgsEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::AttributeArgs => {
+                OptionAttributeArgs::AttributeArgs(AttributeArgs::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionAttributeArgs"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionAttributeArgs::Empty(x) => x.as_syntax_node(),
+            OptionAttributeArgs::AttributeArgs(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionAttributeArgsPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionAttributeArgsEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl OptionAttributeArgsEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionAttributeArgsEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionAttributeArgsEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionAttributeArgsEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionAttributeArgsEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionAttributeArgsEmptyPtr(pub SyntaxStablePtrId);
+impl OptionAttributeArgsEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionAttributeArgsEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionAttributeArgsEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionAttributeArgsEmpty);
+    type StablePtr = OptionAttributeArgsEmptyPtr;
+    type Green = OptionAttributeArgsEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionAttributeArgsEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionAttributeArgsEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionAttributeArgsEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionAttributeArgsEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionAttributeArgsEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AttributeArgs {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl AttributeArgs {
+    pub const INDEX_LPAREN: usize = 0;
+    pub const INDEX_ARG_LIST: usize = 1;
+    pub const INDEX_RANGLE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lparen: TerminalLParenGreen,
+        arg_list: AttributeArgListGreen,
+        rangle: TerminalRParenGreen,
+    ) -> AttributeArgsGreen {
+        let children: Vec<GreenId> = vec![lparen.0, arg_list.0, rangle.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        AttributeArgsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeArgs,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl AttributeArgs {
+    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
+        TerminalLParen::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn arg_list(&self, db: &dyn SyntaxGroup) -> AttributeArgList {
+        AttributeArgList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
+        TerminalRParen::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributeArgsPtr(pub SyntaxStablePtrId);
+impl AttributeArgsPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributeArgsGreen(pub GreenId);
+impl TypedSyntaxNode for AttributeArgs {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::AttributeArgs);
+    type StablePtr = AttributeArgsPtr;
+    type Green = AttributeArgsGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        AttributeArgsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeArgs,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLParen::missing(db).0,
+                    AttributeArgList::missing(db).0,
+                    TerminalRParen::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::AttributeArgs,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::AttributeArgs
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        AttributeArgsPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AttributeArgList(ElementList<Expr, 2>);
+impl Deref for AttributeArgList {
+    type Target = ElementList<Expr, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl AttributeArgList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<AttributeArgListElementOrSeparatorGreen>,
+    ) -> AttributeArgListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        AttributeArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeArgList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributeArgListPtr(pub SyntaxStablePtrId);
+impl AttributeArgListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum AttributeArgListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(ExprGreen),
+}
+impl From<TerminalCommaGreen> for AttributeArgListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        AttributeArgListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<ExprGreen> for AttributeArgListElementOrSeparatorGreen {
+    fn from(value: ExprGreen) -> Self {
+        AttributeArgListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl AttributeArgListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            AttributeArgListElementOrSeparatorGreen::Separator(green) => green.0,
+            AttributeArgListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AttributeArgListGreen(pub GreenId);
+impl TypedSyntaxNode for AttributeArgList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::AttributeArgList);
+    type StablePtr = AttributeArgListPtr;
+    type Green = AttributeArgListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        AttributeArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::AttributeArgList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        AttributeArgListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FunctionDeclaration {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl FunctionDeclaration {
+    pub const INDEX_FUNCTION_KW: usize = 0;
+    pub const INDEX_NAME: usize = 1;
+    pub const INDEX_GENERIC_PARAMS: usize = 2;
+    pub const INDEX_SIGNATURE: usize = 3;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        function_kw: TerminalFunctionGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        signature: FunctionSignatureGreen,
+    ) -> FunctionDeclarationGreen {
+        let children: Vec<GreenId> = vec![function_kw.0, name.0, generic_params.0, signature.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        FunctionDeclarationGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::FunctionDeclaration,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl FunctionDeclaration {
+    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
+        TerminalFunction::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
+        FunctionSignature::from_syntax_node(db, self.children[3].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FunctionDeclarationPtr(pub SyntaxStablePtrId);
+impl FunctionDeclarationPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FunctionDeclarationGreen(pub GreenId);
+impl TypedSyntaxNode for FunctionDeclaration {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::FunctionDeclaration);
+    type StablePtr = FunctionDeclarationPtr;
+    type Green = FunctionDeclarationGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        FunctionDeclarationGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::FunctionDeclaration,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalFunction::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    FunctionSignature::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::FunctionDeclaration,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::FunctionDeclaration
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        FunctionDeclarationPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemFreeFunction {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemFreeFunction {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_DECLARATION: usize = 2;
+    pub const INDEX_BODY: usize = 3;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        declaration: FunctionDeclarationGreen,
+        body: ExprBlockGreen,
+    ) -> ItemFreeFunctionGreen {
+        let children: Vec<GreenId> = vec![attributes.0, visibility.0, declaration.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemFreeFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemFreeFunction,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemFreeFunction {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
+        FunctionDeclaration::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[3].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemFreeFunctionPtr(pub SyntaxStablePtrId);
+impl ItemFreeFunctionPtr {
+    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            FunctionDeclarationGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemFreeFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for ItemFreeFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemFreeFunction);
+    type StablePtr = ItemFreeFunctionPtr;
+    type Green = ItemFreeFunctionGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemFreeFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemFreeFunction,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    FunctionDeclaration::missing(db).0,
+                    ExprBlock::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemFreeFunction,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemFreeFunction
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemFreeFunctionPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemExternFunction {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemExternFunction {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_EXTERN_KW: usize = 2;
+    pub const INDEX_DECLARATION: usize = 3;
+    pub const INDEX_SEMICOLON: usize = 4;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        extern_kw: TerminalExternGreen,
+        declaration: FunctionDeclarationGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemExternFunctionGreen {
+        let children: Vec<GreenId> =
+            vec![attributes.0, visibility.0, extern_kw.0, declaration.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemExternFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternFunction,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemExternFunction {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
+        TerminalExtern::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
+        FunctionDeclaration::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[4].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemExternFunctionPtr(pub SyntaxStablePtrId);
+impl ItemExternFunctionPtr {
+    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            FunctionDeclarationGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemExternFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for ItemExternFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternFunction);
+    type StablePtr = ItemExternFunctionPtr;
+    type Green = ItemExternFunctionGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemExternFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternFunction,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalExtern::missing(db).0,
+                    FunctionDeclaration::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemExternFunction,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemExternFunction
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemExternFunctionPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemExternType {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemExternType {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_EXTERN_KW: usize = 2;
+    pub const INDEX_TYPE_KW: usize = 3;
+    pub const INDEX_NAME: usize = 4;
+    pub const INDEX_GENERIC_PARAMS: usize = 5;
+    pub const INDEX_SEMICOLON: usize = 6;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        extern_kw: TerminalExternGreen,
+        type_kw: TerminalTypeGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemExternTypeGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            extern_kw.0,
+            type_kw.0,
+            name.0,
+            generic_params.0,
+            semicolon.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemExternTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternType,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemExternType {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
+        TerminalExtern::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
+        TerminalType::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemExternTypePtr(pub SyntaxStablePtrId);
+impl ItemExternTypePtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemExternTypeGreen(pub GreenId);
+impl TypedSyntaxNode for ItemExternType {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternType);
+    type StablePtr = ItemExternTypePtr;
+    type Green = ItemExternTypeGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemExternTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternType,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalExtern::missing(db).0,
+                    TerminalType::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemExternType,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemExternType
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemExternTypePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemTrait {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemTrait {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_TRAIT_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_BODY: usize = 5;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        trait_kw: TerminalTraitGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        body: MaybeTraitBodyGreen,
+    ) -> ItemTraitGreen {
+        let children: Vec<GreenId> =
+            vec![attributes.0, visibility.0, trait_kw.0, name.0, generic_params.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemTrait,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemTrait {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trait_kw(&self, db: &dyn SyntaxGroup) -> TerminalTrait {
+        TerminalTrait::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitBody {
+        MaybeTraitBody::from_syntax_node(db, self.children[5].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemTraitPtr(pub SyntaxStablePtrId);
+impl ItemTraitPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemTraitGreen(pub GreenId);
+impl TypedSyntaxNode for ItemTrait {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemTrait);
+    type StablePtr = ItemTraitPtr;
+    type Green = ItemTraitGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemTrait,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalTrait::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    MaybeTraitBody::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemTrait,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemTrait
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemTraitPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MaybeTraitBody {
+    Some(TraitBody),
+    None(TerminalSemicolon),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeTraitBodyPtr(pub SyntaxStablePtrId);
+impl MaybeTraitBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<TraitBodyPtr> for MaybeTraitBodyPtr {
+    fn from(value: TraitBodyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonPtr> for MaybeTraitBodyPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TraitBodyGreen> for MaybeTraitBodyGreen {
+    fn from(value: TraitBodyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonGreen> for MaybeTraitBodyGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeTraitBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeTraitBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = MaybeTraitBodyPtr;
+    type Green = MaybeTraitBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TraitBody => MaybeTraitBody::Some(TraitBody::from_syntax_node(db, node)),
+            SyntaxKind::TerminalSemicolon => {
+                MaybeTraitBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => {
+                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeTraitBody")
+            }
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            MaybeTraitBody::Some(x) => x.as_syntax_node(),
+            MaybeTraitBody::None(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        MaybeTraitBodyPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TraitBody {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl TraitBody {
+    pub const INDEX_LBRACE: usize = 0;
+    pub const INDEX_ITEMS: usize = 1;
+    pub const INDEX_RBRACE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lbrace: TerminalLBraceGreen,
+        items: TraitItemListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> TraitBodyGreen {
+        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TraitBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitBody,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl TraitBody {
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn items(&self, db: &dyn SyntaxGroup) -> TraitItemList {
+        TraitItemList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitBodyPtr(pub SyntaxStablePtrId);
+impl TraitBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitBodyGreen(pub GreenId);
+impl TypedSyntaxNode for TraitBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitBody);
+    type StablePtr = TraitBodyPtr;
+    type Green = TraitBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TraitBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitBody,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLBrace::missing(db).0,
+                    TraitItemList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TraitBody,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TraitBody
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TraitBodyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TraitItemList(ElementList<TraitItem, 1>);
+impl Deref for TraitItemList {
+    type Target = ElementList<TraitItem, 1>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl TraitItemList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<TraitItemGreen>) -> TraitItemListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
+        TraitItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.0).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemListPtr(pub SyntaxStablePtrId);
+impl TraitItemListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemListGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItemList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemList);
+    type StablePtr = TraitItemListPtr;
+    type Green = TraitItemListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TraitItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TraitItemListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TraitItem {
+    Function(TraitItemFunction),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemPtr(pub SyntaxStablePtrId);
+impl TraitItemPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<TraitItemFunctionPtr> for TraitItemPtr {
+    fn from(value: TraitItemFunctionPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TraitItemFunctionGreen> for TraitItemGreen {
+    fn from(value: TraitItemFunctionGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItem {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = TraitItemPtr;
+    type Green = TraitItemGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TraitItemFunction => {
+                TraitItem::Function(TraitItemFunction::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "TraitItem"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            TraitItem::Function(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TraitItemPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TraitItemFunction {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl TraitItemFunction {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_DECLARATION: usize = 1;
+    pub const INDEX_BODY: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        declaration: FunctionDeclarationGreen,
+        body: MaybeTraitFunctionBodyGreen,
+    ) -> TraitItemFunctionGreen {
+        let children: Vec<GreenId> = vec![attributes.0, declaration.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TraitItemFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemFunction,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl TraitItemFunction {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
+        FunctionDeclaration::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitFunctionBody {
+        MaybeTraitFunctionBody::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemFunctionPtr(pub SyntaxStablePtrId);
+impl TraitItemFunctionPtr {
+    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            FunctionDeclarationGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TraitItemFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItemFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemFunction);
+    type StablePtr = TraitItemFunctionPtr;
+    type Green = TraitItemFunctionGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TraitItemFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemFunction,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    FunctionDeclaration::missing(db).0,
+                    MaybeTraitFunctionBody::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TraitItemFunction,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TraitItemFunction
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TraitItemFunctionPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MaybeTraitFunctionBody {
+    Some(ExprBlock),
+    None(TerminalSemicolon),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeTraitFunctionBodyPtr(pub SyntaxStablePtrId);
+impl MaybeTraitFunctionBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<ExprBlockPtr> for MaybeTraitFunctionBodyPtr {
+    fn from(value: ExprBlockPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonPtr> for MaybeTraitFunctionBodyPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprBlockGreen> for MaybeTraitFunctionBodyGreen {
+    fn from(value: ExprBlockGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonGreen> for MaybeTraitFunctionBodyGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeTraitFunctionBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeTraitFunctionBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = MaybeTraitFunctionBodyPtr;
+    type Green = MaybeTraitFunctionBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::ExprBlock => {
+                MaybeTraitFunctionBody::Some(ExprBlock::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalSemicolon => {
+                MaybeTraitFunctionBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "MaybeTraitFunctionBody"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            MaybeTraitFunctionBody::Some(x) => x.as_syntax_node(),
+            MaybeTraitFunctionBody::None(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        MaybeTraitFunctionBodyPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemImpl {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemImpl {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_IMPL_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_OF_KW: usize = 5;
+    pub const INDEX_TRAIT_PATH: usize = 6;
+    pub const INDEX_BODY: usize = 7;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        impl_kw: TerminalImplGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        of_kw: TerminalOfGreen,
+        trait_path: ExprPathGreen,
+        body: MaybeImplBodyGreen,
+    ) -> ItemImplGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            impl_kw.0,
+            name.0,
+            generic_params.0,
+            of_kw.0,
+            trait_path.0,
+            body.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemImpl,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemImpl {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
+        TerminalImpl::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn of_kw(&self, db: &dyn SyntaxGroup) -> TerminalOf {
+        TerminalOf::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn trait_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[6].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeImplBody {
+        MaybeImplBody::from_syntax_node(db, self.children[7].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemImplPtr(pub SyntaxStablePtrId);
+impl ItemImplPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemImplGreen(pub GreenId);
+impl TypedSyntaxNode for ItemImpl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemImpl);
+    type StablePtr = ItemImplPtr;
+    type Green = ItemImplGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemImpl,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalImpl::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalOf::missing(db).0,
+                    ExprPath::missing(db).0,
+                    MaybeImplBody::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemImpl,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemImpl
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemImplPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MaybeImplBody {
+    Some(ImplBody),
+    None(TerminalSemicolon),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeImplBodyPtr(pub SyntaxStablePtrId);
+impl MaybeImplBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<ImplBodyPtr> for MaybeImplBodyPtr {
+    fn from(value: ImplBodyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonPtr> for MaybeImplBodyPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ImplBodyGreen> for MaybeImplBodyGreen {
+    fn from(value: ImplBodyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonGreen> for MaybeImplBodyGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeImplBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeImplBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = MaybeImplBodyPtr;
+    type Green = MaybeImplBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::ImplBody => MaybeImplBody::Some(ImplBody::from_syntax_node(db, node)),
+            SyntaxKind::TerminalSemicolon => {
+                MaybeImplBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeImplBody"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            MaybeImplBody::Some(x) => x.as_syntax_node(),
+            MaybeImplBody::None(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        MaybeImplBodyPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ImplBody {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ImplBody {
+    pub const INDEX_LBRACE: usize = 0;
+    pub const INDEX_ITEMS: usize = 1;
+    pub const INDEX_RBRACE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lbrace: TerminalLBraceGreen,
+        items: ItemListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ImplBodyGreen {
+        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ImplBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplBody,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ImplBody {
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn items(&self, db: &dyn SyntaxGroup) -> ItemList {
+        ItemList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplBodyPtr(pub SyntaxStablePtrId);
+impl ImplBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplBodyGreen(pub GreenId);
+impl TypedSyntaxNode for ImplBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplBody);
+    type StablePtr = ImplBodyPtr;
+    type Green = ImplBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ImplBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplBody,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLBrace::missing(db).0,
+                    ItemList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ImplBody,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ImplBody
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ImplBodyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemStruct {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemStruct {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_STRUCT_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_LBRACE: usize = 5;
+    pub const INDEX_MEMBERS: usize = 6;
+    pub const INDEX_RBRACE: usize = 7;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        struct_kw: TerminalStructGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        lbrace: TerminalLBraceGreen,
+        members: MemberListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ItemStructGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            struct_kw.0,
+            name.0,
+            generic_params.0,
+            lbrace.0,
+            members.0,
+            rbrace.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemStruct,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemStruct {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn struct_kw(&self, db: &dyn SyntaxGroup) -> TerminalStruct {
+        TerminalStruct::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn members(&self, db: &dyn SyntaxGroup) -> MemberList {
+        MemberList::from_syntax_node(db, self.children[6].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[7].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemStructPtr(pub SyntaxStablePtrId);
+impl ItemStructPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemStructGreen(pub GreenId);
+impl TypedSyntaxNode for ItemStruct {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemStruct);
+    type StablePtr = ItemStructPtr;
+    type Green = ItemStructGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemStruct,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalStruct::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalLBrace::missing(db).0,
+                    MemberList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemStruct,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemStruct
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemStructPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemEnum {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemEnum {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_ENUM_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_LBRACE: usize = 5;
+    pub const INDEX_VARIANTS: usize = 6;
+    pub const INDEX_RBRACE: usize = 7;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        enum_kw: TerminalEnumGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        lbrace: TerminalLBraceGreen,
+        variants: MemberListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ItemEnumGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            enum_kw.0,
+            name.0,
+            generic_params.0,
+            lbrace.0,
+            variants.0,
+            rbrace.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemEnum,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemEnum {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn enum_kw(&self, db: &dyn SyntaxGroup) -> TerminalEnum {
+        TerminalEnum::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn variants(&self, db: &dyn SyntaxGroup) -> MemberList {
+        MemberList::from_syntax_node(db, self.children[6].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[7].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemEnumPtr(pub SyntaxStablePtrId);
+impl ItemEnumPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemEnumGreen(pub GreenId);
+impl TypedSyntaxNode for ItemEnum {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemEnum);
+    type StablePtr = ItemEnumPtr;
+    type Green = ItemEnumGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemEnum,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalEnum::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalLBrace::missing(db).0,
+                    MemberList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemEnum,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::OptionImplicitsClauseEmpty
+            SyntaxKind::ItemEnum
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -5495,121 +9882,253 @@ impl TypedSyntaxNode for OptionImplicitsClauseEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionImplicitsClauseEmptyPtr(self.node.0.stable_ptr)
+        ItemEnumPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionTerminalNoPanic {
-    Empty(OptionTerminalNoPanicEmpty),
-    TerminalNoPanic(TerminalNoPanic),
+pub struct ItemTypeAlias {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTerminalNoPanicPtr(pub SyntaxStablePtrId);
-impl OptionTerminalNoPanicPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl ItemTypeAlias {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_TYPE_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_EQ: usize = 5;
+    pub const INDEX_TY: usize = 6;
+    pub const INDEX_SEMICOLON: usize = 7;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        type_kw: TerminalTypeGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        eq: TerminalEqGreen,
+        ty: ExprGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemTypeAliasGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            type_kw.0,
+            name.0,
+            generic_params.0,
+            eq.0,
+            ty.0,
+            semicolon.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemTypeAliasGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemTypeAlias,
+            details: GreenNodeDetails::Node { children, width },
+        }))
     }
 }
-impl From<OptionTerminalNoPanicEmptyPtr> for OptionTerminalNoPanicPtr {
-    fn from(value: OptionTerminalNoPanicEmptyPtr) -> Self {
-        Self(value.0)
+impl ItemTypeAlias {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-}
-impl From<TerminalNoPanicPtr> for OptionTerminalNoPanicPtr {
-    fn from(value: TerminalNoPanicPtr) -> Self {
-        Self(value.0)
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
     }
-}
-impl From<OptionTerminalNoPanicEmptyGreen> for OptionTerminalNoPanicGreen {
-    fn from(value: OptionTerminalNoPanicEmptyGreen) -> Self {
-        Self(value.0)
+    pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
+        TerminalType::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
+        TerminalEq::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[6].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[7].clone())
     }
 }
-impl From<TerminalNoPanicGreen> for OptionTerminalNoPanicGreen {
-    fn from(value: TerminalNoPanicGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemTypeAliasPtr(pub SyntaxStablePtrId);
+impl ItemTypeAliasPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTerminalNoPanicGreen(pub GreenId);
-impl TypedSyntaxNode for OptionTerminalNoPanic {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionTerminalNoPanicPtr;
-    type Green = OptionTerminalNoPanicGreen;
+pub struct ItemTypeAliasGreen(pub GreenId);
+impl TypedSyntaxNode for ItemTypeAlias {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemTypeAlias);
+    type StablePtr = ItemTypeAliasPtr;
+    type Green = ItemTypeAliasGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        ItemTypeAliasGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemTypeAlias,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalType::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalEq::missing(db).0,
+                    Expr::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
-        match kind {
-            SyntaxKind::OptionTerminalNoPanicEmpty => {
-                OptionTerminalNoPanic::Empty(OptionTerminalNoPanicEmpty::from_syntax_node(db, node))
-            }
-            SyntaxKind::TerminalNoPanic => {
-                OptionTerminalNoPanic::TerminalNoPanic(TerminalNoPanic::from_syntax_node(db, node))
-            }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionTerminalNoPanic"
-            ),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            OptionTerminalNoPanic::Empty(x) => x.as_syntax_node(),
-            OptionTerminalNoPanic::TerminalNoPanic(x) => x.as_syntax_node(),
-        }
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemTypeAlias,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemTypeAlias
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionTerminalNoPanicPtr(self.as_syntax_node().0.stable_ptr)
+        ItemTypeAliasPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionTerminalNoPanicEmpty {
+pub struct ItemConstant {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl OptionTerminalNoPanicEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionTerminalNoPanicEmptyGreen {
-        let children: Vec<GreenId> = vec![];
+impl ItemConstant {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_CONST_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_TYPE_CLAUSE: usize = 4;
+    pub const INDEX_EQ: usize = 5;
+    pub const INDEX_VALUE: usize = 6;
+    pub const INDEX_SEMICOLON: usize = 7;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        const_kw: TerminalConstGreen,
+        name: TerminalIdentifierGreen,
+        type_clause: TypeClauseGreen,
+        eq: TerminalEqGreen,
+        value: ExprGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemConstantGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            const_kw.0,
+            name.0,
+            type_clause.0,
+            eq.0,
+            value.0,
+            semicolon.0,
+        ];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionTerminalNoPanicEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionTerminalNoPanicEmpty,
+        ItemConstantGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemConstant,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl OptionTerminalNoPanicEmpty {}
+impl ItemConstant {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn const_kw(&self, db: &dyn SyntaxGroup) -> TerminalConst {
+        TerminalConst::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
+        TypeClause::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
+        TerminalEq::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn value(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[6].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[7].clone())
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTerminalNoPanicEmptyPtr(pub SyntaxStablePtrId);
-impl OptionTerminalNoPanicEmptyPtr {
+pub struct ItemConstantPtr(pub SyntaxStablePtrId);
+impl ItemConstantPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionTerminalNoPanicEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionTerminalNoPanicEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionTerminalNoPanicEmpty);
-    type StablePtr = OptionTerminalNoPanicEmptyPtr;
-    type Green = OptionTerminalNoPanicEmptyGreen;
+pub struct ItemConstantGreen(pub GreenId);
+impl TypedSyntaxNode for ItemConstant {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemConstant);
+    type StablePtr = ItemConstantPtr;
+    type Green = ItemConstantGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionTerminalNoPanicEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionTerminalNoPanicEmpty,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        ItemConstantGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemConstant,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalConst::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    TypeClause::missing(db).0,
+                    TerminalEq::missing(db).0,
+                    Expr::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: 0,
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::OptionTerminalNoPanicEmpty,
+            SyntaxKind::ItemConstant,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::OptionTerminalNoPanicEmpty
+            SyntaxKind::ItemConstant
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -5621,89 +10140,85 @@ impl TypedSyntaxNode for OptionTerminalNoPanicEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionTerminalNoPanicEmptyPtr(self.node.0.stable_ptr)
+        ItemConstantPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct FunctionSignature {
+pub struct ItemUse {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl FunctionSignature {
-    pub const INDEX_LPAREN: usize = 0;
-    pub const INDEX_PARAMETERS: usize = 1;
-    pub const INDEX_RPAREN: usize = 2;
-    pub const INDEX_RET_TY: usize = 3;
-    pub const INDEX_IMPLICITS_CLAUSE: usize = 4;
-    pub const INDEX_OPTIONAL_NO_PANIC: usize = 5;
+impl ItemUse {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_USE_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_SEMICOLON: usize = 4;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        lparen: TerminalLParenGreen,
-        parameters: ParamListGreen,
-        rparen: TerminalRParenGreen,
-        ret_ty: OptionReturnTypeClauseGreen,
-        implicits_clause: OptionImplicitsClauseGreen,
-        optional_no_panic: OptionTerminalNoPanicGreen,
-    ) -> FunctionSignatureGreen {
-        let children: Vec<GreenId> = vec![
-            lparen.0,
-            parameters.0,
-            rparen.0,
-            ret_ty.0,
-            implicits_clause.0,
-            optional_no_panic.0,
-        ];
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        use_kw: TerminalUseGreen,
+        name: ExprPathGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemUseGreen {
+        let children: Vec<GreenId> =
+            vec![attributes.0, visibility.0, use_kw.0, name.0, semicolon.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        FunctionSignatureGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::FunctionSignature,
-            details: GreenNodeDetails::Node { children, width },
-        }))
-    }
-}
-impl FunctionSignature {
-    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
-        TerminalLParen::from_syntax_node(db, self.children[0].clone())
+        ItemUseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemUse,
+            details: GreenNodeDetails::Node { children, width },
+        }))
     }
-    pub fn parameters(&self, db: &dyn SyntaxGroup) -> ParamList {
-        ParamList::from_syntax_node(db, self.children[1].clone())
+}
+impl ItemUse {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
-        TerminalRParen::from_syntax_node(db, self.children[2].clone())
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn ret_ty(&self, db: &dyn SyntaxGroup) -> OptionReturnTypeClause {
-        OptionReturnTypeClause::from_syntax_node(db, self.children[3].clone())
+    pub fn use_kw(&self, db: &dyn SyntaxGroup) -> TerminalUse {
+        TerminalUse::from_syntax_node(db, self.children[2].clone())
     }
-    pub fn implicits_clause(&self, db: &dyn SyntaxGroup) -> OptionImplicitsClause {
-        OptionImplicitsClause::from_syntax_node(db, self.children[4].clone())
+    pub fn name(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[3].clone())
     }
-    pub fn optional_no_panic(&self, db: &dyn SyntaxGroup) -> OptionTerminalNoPanic {
-        OptionTerminalNoPanic::from_syntax_node(db, self.children[5].clone())
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[4].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct FunctionSignaturePtr(pub SyntaxStablePtrId);
-impl FunctionSignaturePtr {
+pub struct ItemUsePtr(pub SyntaxStablePtrId);
+impl ItemUsePtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> ExprPathGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            ExprPathGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct FunctionSignatureGreen(pub GreenId);
-impl TypedSyntaxNode for FunctionSignature {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::FunctionSignature);
-    type StablePtr = FunctionSignaturePtr;
-    type Green = FunctionSignatureGreen;
+pub struct ItemUseGreen(pub GreenId);
+impl TypedSyntaxNode for ItemUse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemUse);
+    type StablePtr = ItemUsePtr;
+    type Green = ItemUseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        FunctionSignatureGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::FunctionSignature,
+        ItemUseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemUse,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLParen::missing(db).0,
-                    ParamList::missing(db).0,
-                    TerminalRParen::missing(db).0,
-                    OptionReturnTypeClause::missing(db).0,
-                    OptionImplicitsClause::missing(db).0,
-                    OptionTerminalNoPanic::missing(db).0,
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalUse::missing(db).0,
+                    ExprPath::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
                 ],
                 width: 0,
             },
@@ -5713,10 +10228,10 @@ impl TypedSyntaxNode for FunctionSignature {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::FunctionSignature,
+            SyntaxKind::ItemUse,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::FunctionSignature
+            SyntaxKind::ItemUse
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -5728,64 +10243,65 @@ impl TypedSyntaxNode for FunctionSignature {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        FunctionSignaturePtr(self.node.0.stable_ptr)
+        ItemUsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Member {
+pub struct GenericArgs {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Member {
-    pub const INDEX_NAME: usize = 0;
-    pub const INDEX_TYPE_CLAUSE: usize = 1;
+impl GenericArgs {
+    pub const INDEX_LANGLE: usize = 0;
+    pub const INDEX_GENERIC_ARGS: usize = 1;
+    pub const INDEX_RANGLE: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        name: TerminalIdentifierGreen,
-        type_clause: TypeClauseGreen,
-    ) -> MemberGreen {
-        let children: Vec<GreenId> = vec![name.0, type_clause.0];
+        langle: TerminalLTGreen,
+        generic_args: GenericArgListGreen,
+        rangle: TerminalGTGreen,
+    ) -> GenericArgsGreen {
+        let children: Vec<GreenId> = vec![langle.0, generic_args.0, rangle.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        MemberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Member,
+        GenericArgsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgs,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl Member {
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+impl GenericArgs {
+    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
+        TerminalLT::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
-        TypeClause::from_syntax_node(db, self.children[1].clone())
+    pub fn generic_args(&self, db: &dyn SyntaxGroup) -> GenericArgList {
+        GenericArgList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
+        TerminalGT::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MemberPtr(pub SyntaxStablePtrId);
-impl MemberPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct GenericArgsPtr(pub SyntaxStablePtrId);
+impl GenericArgsPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MemberGreen(pub GreenId);
-impl TypedSyntaxNode for Member {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Member);
-    type StablePtr = MemberPtr;
-    type Green = MemberGreen;
+pub struct GenericArgsGreen(pub GreenId);
+impl TypedSyntaxNode for GenericArgs {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgs);
+    type StablePtr = GenericArgsPtr;
+    type Green = GenericArgsGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        MemberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Member,
+        GenericArgsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgs,
             details: GreenNodeDetails::Node {
-                children: vec![TerminalIdentifier::missing(db).0, TypeClause::missing(db).0],
+                children: vec![
+                    TerminalLT::missing(db).0,
+                    GenericArgList::missing(db).0,
+                    TerminalGT::missing(db).0,
+                ],
                 width: 0,
             },
         }))
@@ -5794,10 +10310,10 @@ impl TypedSyntaxNode for Member {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::Member,
+            SyntaxKind::GenericArgs,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::Member
+            SyntaxKind::GenericArgs
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -5809,25 +10325,25 @@ impl TypedSyntaxNode for Member {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MemberPtr(self.node.0.stable_ptr)
+        GenericArgsPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct MemberList(ElementList<Member, 2>);
-impl Deref for MemberList {
-    type Target = ElementList<Member, 2>;
+pub struct GenericArgList(ElementList<Expr, 2>);
+impl Deref for GenericArgList {
+    type Target = ElementList<Expr, 2>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl MemberList {
+impl GenericArgList {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        children: Vec<MemberListElementOrSeparatorGreen>,
-    ) -> MemberListGreen {
+        children: Vec<GenericArgListElementOrSeparatorGreen>,
+    ) -> GenericArgListGreen {
         let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        MemberListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::MemberList,
+        GenericArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgList,
             details: GreenNodeDetails::Node {
                 children: children.iter().map(|x| x.id()).collect(),
                 width,
@@ -5836,351 +10352,243 @@ impl MemberList {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MemberListPtr(pub SyntaxStablePtrId);
-impl MemberListPtr {
+pub struct GenericArgListPtr(pub SyntaxStablePtrId);
+impl GenericArgListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum MemberListElementOrSeparatorGreen {
+pub enum GenericArgListElementOrSeparatorGreen {
     Separator(TerminalCommaGreen),
-    Element(MemberGreen),
-}
-impl From<TerminalCommaGreen> for MemberListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        MemberListElementOrSeparatorGreen::Separator(value)
-    }
-}
-impl From<MemberGreen> for MemberListElementOrSeparatorGreen {
-    fn from(value: MemberGreen) -> Self {
-        MemberListElementOrSeparatorGreen::Element(value)
-    }
-}
-impl MemberListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            MemberListElementOrSeparatorGreen::Separator(green) => green.0,
-            MemberListElementOrSeparatorGreen::Element(green) => green.0,
-        }
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MemberListGreen(pub GreenId);
-impl TypedSyntaxNode for MemberList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::MemberList);
-    type StablePtr = MemberListPtr;
-    type Green = MemberListGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        MemberListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::MemberList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
-        }))
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        MemberListPtr(self.node.0.stable_ptr)
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum Item {
-    Module(ItemModule),
-    Use(ItemUse),
-    FreeFunction(ItemFreeFunction),
-    ExternFunction(ItemExternFunction),
-    ExternType(ItemExternType),
-    Trait(ItemTrait),
-    Impl(ItemImpl),
-    Struct(ItemStruct),
-    Enum(ItemEnum),
-    TypeAlias(ItemTypeAlias),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemPtr(pub SyntaxStablePtrId);
-impl ItemPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-impl From<ItemModulePtr> for ItemPtr {
-    fn from(value: ItemModulePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemUsePtr> for ItemPtr {
-    fn from(value: ItemUsePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemFreeFunctionPtr> for ItemPtr {
-    fn from(value: ItemFreeFunctionPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemExternFunctionPtr> for ItemPtr {
-    fn from(value: ItemExternFunctionPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemExternTypePtr> for ItemPtr {
-    fn from(value: ItemExternTypePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemTraitPtr> for ItemPtr {
-    fn from(value: ItemTraitPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemImplPtr> for ItemPtr {
-    fn from(value: ItemImplPtr) -> Self {
-        Self(value.0)
-    }
+    Element(ExprGreen),
 }
-impl From<ItemStructPtr> for ItemPtr {
-    fn from(value: ItemStructPtr) -> Self {
-        Self(value.0)
+impl From<TerminalCommaGreen> for GenericArgListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        GenericArgListElementOrSeparatorGreen::Separator(value)
     }
 }
-impl From<ItemEnumPtr> for ItemPtr {
-    fn from(value: ItemEnumPtr) -> Self {
-        Self(value.0)
+impl From<ExprGreen> for GenericArgListElementOrSeparatorGreen {
+    fn from(value: ExprGreen) -> Self {
+        GenericArgListElementOrSeparatorGreen::Element(value)
     }
 }
-impl From<ItemTypeAliasPtr> for ItemPtr {
-    fn from(value: ItemTypeAliasPtr) -> Self {
-        Self(value.0)
+impl GenericArgListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            GenericArgListElementOrSeparatorGreen::Separator(green) => green.0,
+            GenericArgListElementOrSeparatorGreen::Element(green) => green.0,
+        }
     }
 }
-impl From<ItemModuleGreen> for ItemGreen {
-    fn from(value: ItemModuleGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgListGreen(pub GreenId);
+impl TypedSyntaxNode for GenericArgList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgList);
+    type StablePtr = GenericArgListPtr;
+    type Green = GenericArgListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgList,
+            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        }))
     }
-}
-impl From<ItemUseGreen> for ItemGreen {
-    fn from(value: ItemUseGreen) -> Self {
-        Self(value.0)
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
     }
-}
-impl From<ItemFreeFunctionGreen> for ItemGreen {
-    fn from(value: ItemFreeFunctionGreen) -> Self {
-        Self(value.0)
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
     }
-}
-impl From<ItemExternFunctionGreen> for ItemGreen {
-    fn from(value: ItemExternFunctionGreen) -> Self {
-        Self(value.0)
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
-}
-impl From<ItemExternTypeGreen> for ItemGreen {
-    fn from(value: ItemExternTypeGreen) -> Self {
-        Self(value.0)
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericArgListPtr(self.node.0.stable_ptr)
     }
 }
-impl From<ItemTraitGreen> for ItemGreen {
-    fn from(value: ItemTraitGreen) -> Self {
-        Self(value.0)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionWrappedGenericParamList {
+    Empty(OptionWrappedGenericParamListEmpty),
+    WrappedGenericParamList(WrappedGenericParamList),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionWrappedGenericParamListPtr(pub SyntaxStablePtrId);
+impl OptionWrappedGenericParamListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl From<ItemImplGreen> for ItemGreen {
-    fn from(value: ItemImplGreen) -> Self {
+impl From<OptionWrappedGenericParamListEmptyPtr> for OptionWrappedGenericParamListPtr {
+    fn from(value: OptionWrappedGenericParamListEmptyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ItemStructGreen> for ItemGreen {
-    fn from(value: ItemStructGreen) -> Self {
+impl From<WrappedGenericParamListPtr> for OptionWrappedGenericParamListPtr {
+    fn from(value: WrappedGenericParamListPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ItemEnumGreen> for ItemGreen {
-    fn from(value: ItemEnumGreen) -> Self {
+impl From<OptionWrappedGenericParamListEmptyGreen> for OptionWrappedGenericParamListGreen {
+    fn from(value: OptionWrappedGenericParamListEmptyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<ItemTypeAliasGreen> for ItemGreen {
-    fn from(value: ItemTypeAliasGreen) -> Self {
+impl From<WrappedGenericParamListGreen> for OptionWrappedGenericParamListGreen {
+    fn from(value: WrappedGenericParamListGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemGreen(pub GreenId);
-impl TypedSyntaxNode for Item {
+pub struct OptionWrappedGenericParamListGreen(pub GreenId);
+impl TypedSyntaxNode for OptionWrappedGenericParamList {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = ItemPtr;
-    type Green = ItemGreen;
+    type StablePtr = OptionWrappedGenericParamListPtr;
+    type Green = OptionWrappedGenericParamListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::ItemModule => Item::Module(ItemModule::from_syntax_node(db, node)),
-            SyntaxKind::ItemUse => Item::Use(ItemUse::from_syntax_node(db, node)),
-            SyntaxKind::ItemFreeFunction => {
-                Item::FreeFunction(ItemFreeFunction::from_syntax_node(db, node))
-            }
-            SyntaxKind::ItemExternFunction => {
-                Item::ExternFunction(ItemExternFunction::from_syntax_node(db, node))
-            }
-            SyntaxKind::ItemExternType => {
-                Item::ExternType(ItemExternType::from_syntax_node(db, node))
+            SyntaxKind::OptionWrappedGenericParamListEmpty => OptionWrappedGenericParamList::Empty(
+                OptionWrappedGenericParamListEmpty::from_syntax_node(db, node),
+            ),
+            SyntaxKind::WrappedGenericParamList => {
+                OptionWrappedGenericParamList::WrappedGenericParamList(
+                    WrappedGenericParamList::from_syntax_node(db, node),
+                )
             }
-            SyntaxKind::ItemTrait => Item::Trait(ItemTrait::from_syntax_node(db, node)),
-            SyntaxKind::ItemImpl => Item::Impl(ItemImpl::from_syntax_node(db, node)),
-            SyntaxKind::ItemStruct => Item::Struct(ItemStruct::from_syntax_node(db, node)),
-            SyntaxKind::ItemEnum => Item::Enum(ItemEnum::from_syntax_node(db, node)),
-            SyntaxKind::ItemTypeAlias => Item::TypeAlias(ItemTypeAlias::from_syntax_node(db, node)),
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Item"),
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionWrappedGenericParamList"
+            ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            Item::Module(x) => x.as_syntax_node(),
-            Item::Use(x) => x.as_syntax_node(),
-            Item::FreeFunction(x) => x.as_syntax_node(),
-            Item::ExternFunction(x) => x.as_syntax_node(),
-            Item::ExternType(x) => x.as_syntax_node(),
-            Item::Trait(x) => x.as_syntax_node(),
-            Item::Impl(x) => x.as_syntax_node(),
-            Item::Struct(x) => x.as_syntax_node(),
-            Item::Enum(x) => x.as_syntax_node(),
-            Item::TypeAlias(x) => x.as_syntax_node(),
+            OptionWrappedGenericParamList::Empty(x) => x.as_syntax_node(),
+            OptionWrappedGenericParamList::WrappedGenericParamList(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemPtr(self.as_syntax_node().0.stable_ptr)
+        OptionWrappedGenericParamListPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemList(ElementList<Item, 1>);
-impl Deref for ItemList {
-    type Target = ElementList<Item, 1>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct OptionWrappedGenericParamListEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl ItemList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ItemGreen>) -> ItemListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        ItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.0).collect(),
-                width,
-            },
+impl OptionWrappedGenericParamListEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionWrappedGenericParamListEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
+            details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
+impl OptionWrappedGenericParamListEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemListPtr(pub SyntaxStablePtrId);
-impl ItemListPtr {
+pub struct OptionWrappedGenericParamListEmptyPtr(pub SyntaxStablePtrId);
+impl OptionWrappedGenericParamListEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemListGreen(pub GreenId);
-impl TypedSyntaxNode for ItemList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemList);
-    type StablePtr = ItemListPtr;
-    type Green = ItemListGreen;
+pub struct OptionWrappedGenericParamListEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionWrappedGenericParamListEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionWrappedGenericParamListEmpty);
+    type StablePtr = OptionWrappedGenericParamListEmptyPtr;
+    type Green = OptionWrappedGenericParamListEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemList,
+        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
             details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionWrappedGenericParamListEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionWrappedGenericParamListEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemListPtr(self.node.0.stable_ptr)
+        OptionWrappedGenericParamListEmptyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Attribute {
+pub struct WrappedGenericParamList {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Attribute {
-    pub const INDEX_HASH: usize = 0;
-    pub const INDEX_LBRACK: usize = 1;
-    pub const INDEX_ATTR: usize = 2;
-    pub const INDEX_ARGS: usize = 3;
-    pub const INDEX_RBRACK: usize = 4;
+impl WrappedGenericParamList {
+    pub const INDEX_LANGLE: usize = 0;
+    pub const INDEX_GENERIC_PARAMS: usize = 1;
+    pub const INDEX_RANGLE: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        hash: TerminalHashGreen,
-        lbrack: TerminalLBrackGreen,
-        attr: TerminalIdentifierGreen,
-        args: OptionAttributeArgsGreen,
-        rbrack: TerminalRBrackGreen,
-    ) -> AttributeGreen {
-        let children: Vec<GreenId> = vec![hash.0, lbrack.0, attr.0, args.0, rbrack.0];
+        langle: TerminalLTGreen,
+        generic_params: GenericParamListGreen,
+        rangle: TerminalGTGreen,
+    ) -> WrappedGenericParamListGreen {
+        let children: Vec<GreenId> = vec![langle.0, generic_params.0, rangle.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        AttributeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Attribute,
+        WrappedGenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::WrappedGenericParamList,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl Attribute {
-    pub fn hash(&self, db: &dyn SyntaxGroup) -> TerminalHash {
-        TerminalHash::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn lbrack(&self, db: &dyn SyntaxGroup) -> TerminalLBrack {
-        TerminalLBrack::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn attr(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+impl WrappedGenericParamList {
+    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
+        TerminalLT::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn args(&self, db: &dyn SyntaxGroup) -> OptionAttributeArgs {
-        OptionAttributeArgs::from_syntax_node(db, self.children[3].clone())
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> GenericParamList {
+        GenericParamList::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rbrack(&self, db: &dyn SyntaxGroup) -> TerminalRBrack {
-        TerminalRBrack::from_syntax_node(db, self.children[4].clone())
+    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
+        TerminalGT::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct AttributePtr(pub SyntaxStablePtrId);
-impl AttributePtr {
+pub struct WrappedGenericParamListPtr(pub SyntaxStablePtrId);
+impl WrappedGenericParamListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct AttributeGreen(pub GreenId);
-impl TypedSyntaxNode for Attribute {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Attribute);
-    type StablePtr = AttributePtr;
-    type Green = AttributeGreen;
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WrappedGenericParamListGreen(pub GreenId);
+impl TypedSyntaxNode for WrappedGenericParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::WrappedGenericParamList);
+    type StablePtr = WrappedGenericParamListPtr;
+    type Green = WrappedGenericParamListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        AttributeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Attribute,
+        WrappedGenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::WrappedGenericParamList,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalHash::missing(db).0,
-                    TerminalLBrack::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionAttributeArgs::missing(db).0,
-                    TerminalRBrack::missing(db).0,
+                    TerminalLT::missing(db).0,
+                    GenericParamList::missing(db).0,
+                    TerminalGT::missing(db).0,
                 ],
                 width: 0,
             },
@@ -6190,10 +10598,10 @@ impl TypedSyntaxNode for Attribute {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::Attribute,
+            SyntaxKind::WrappedGenericParamList,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::Attribute
+            SyntaxKind::WrappedGenericParamList
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6205,45 +10613,71 @@ impl TypedSyntaxNode for Attribute {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        AttributePtr(self.node.0.stable_ptr)
+        WrappedGenericParamListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct AttributeList(ElementList<Attribute, 1>);
-impl Deref for AttributeList {
-    type Target = ElementList<Attribute, 1>;
+pub struct GenericParamList(ElementList<GenericParam, 2>);
+impl Deref for GenericParamList {
+    type Target = ElementList<GenericParam, 2>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl AttributeList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<AttributeGreen>) -> AttributeListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        AttributeListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::AttributeList,
+impl GenericParamList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<GenericParamListElementOrSeparatorGreen>,
+    ) -> GenericParamListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        GenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamList,
             details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.0).collect(),
+                children: children.iter().map(|x| x.id()).collect(),
                 width,
             },
         }))
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct AttributeListPtr(pub SyntaxStablePtrId);
-impl AttributeListPtr {
+pub struct GenericParamListPtr(pub SyntaxStablePtrId);
+impl GenericParamListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct AttributeListGreen(pub GreenId);
-impl TypedSyntaxNode for AttributeList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::AttributeList);
-    type StablePtr = AttributeListPtr;
-    type Green = AttributeListGreen;
+pub enum GenericParamListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(GenericParamGreen),
+}
+impl From<TerminalCommaGreen> for GenericParamListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        GenericParamListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<GenericParamGreen> for GenericParamListElementOrSeparatorGreen {
+    fn from(value: GenericParamGreen) -> Self {
+        GenericParamListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl GenericParamListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            GenericParamListElementOrSeparatorGreen::Separator(green) => green.0,
+            GenericParamListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamListGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamList);
+    type StablePtr = GenericParamListPtr;
+    type Green = GenericParamListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        AttributeListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::AttributeList,
+        GenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamList,
             details: GreenNodeDetails::Node { children: vec![], width: 0 },
         }))
     }
@@ -6257,51 +10691,33 @@ impl TypedSyntaxNode for AttributeList {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        AttributeListPtr(self.node.0.stable_ptr)
+        GenericParamListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemModule {
+pub struct GenericParam {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemModule {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_MODULE_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_BODY: usize = 3;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        module_kw: TerminalModuleGreen,
-        name: TerminalIdentifierGreen,
-        body: MaybeModuleBodyGreen,
-    ) -> ItemModuleGreen {
-        let children: Vec<GreenId> = vec![attributes.0, module_kw.0, name.0, body.0];
+impl GenericParam {
+    pub const INDEX_NAME: usize = 0;
+    pub fn new_green(db: &dyn SyntaxGroup, name: TerminalIdentifierGreen) -> GenericParamGreen {
+        let children: Vec<GreenId> = vec![name.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemModule,
+        GenericParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParam,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemModule {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn module_kw(&self, db: &dyn SyntaxGroup) -> TerminalModule {
-        TerminalModule::from_syntax_node(db, self.children[1].clone())
-    }
+impl GenericParam {
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeModuleBody {
-        MaybeModuleBody::from_syntax_node(db, self.children[3].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemModulePtr(pub SyntaxStablePtrId);
-impl ItemModulePtr {
+pub struct GenericParamPtr(pub SyntaxStablePtrId);
+impl GenericParamPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -6315,21 +10731,16 @@ impl ItemModulePtr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemModuleGreen(pub GreenId);
-impl TypedSyntaxNode for ItemModule {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemModule);
-    type StablePtr = ItemModulePtr;
-    type Green = ItemModuleGreen;
+pub struct GenericParamGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParam {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParam);
+    type StablePtr = GenericParamPtr;
+    type Green = GenericParamGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemModule,
+        GenericParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParam,
             details: GreenNodeDetails::Node {
-                children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalModule::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    MaybeModuleBody::missing(db).0,
-                ],
+                children: vec![TerminalIdentifier::missing(db).0],
                 width: 0,
             },
         }))
@@ -6338,10 +10749,10 @@ impl TypedSyntaxNode for ItemModule {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemModule,
+            SyntaxKind::GenericParam,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemModule
+            SyntaxKind::GenericParam
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6353,130 +10764,123 @@ impl TypedSyntaxNode for ItemModule {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemModulePtr(self.node.0.stable_ptr)
+        GenericParamPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeModuleBody {
-    Some(ModuleBody),
-    None(TerminalSemicolon),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeModuleBodyPtr(pub SyntaxStablePtrId);
-impl MaybeModuleBodyPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
+pub struct TokenIdentifier {
+    node: SyntaxNode,
 }
-impl From<ModuleBodyPtr> for MaybeModuleBodyPtr {
-    fn from(value: ModuleBodyPtr) -> Self {
-        Self(value.0)
+impl Token for TokenIdentifier {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenIdentifier,
+            details: GreenNodeDetails::Token(text),
+        }))
     }
-}
-impl From<TerminalSemicolonPtr> for MaybeModuleBodyPtr {
-    fn from(value: TerminalSemicolonPtr) -> Self {
-        Self(value.0)
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
-impl From<ModuleBodyGreen> for MaybeModuleBodyGreen {
-    fn from(value: ModuleBodyGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenIdentifierPtr(pub SyntaxStablePtrId);
+impl TokenIdentifierPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl From<TerminalSemicolonGreen> for MaybeModuleBodyGreen {
-    fn from(value: TerminalSemicolonGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenIdentifierGreen(pub GreenId);
+impl TokenIdentifierGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeModuleBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeModuleBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeModuleBodyPtr;
-    type Green = MaybeModuleBodyGreen;
+impl TypedSyntaxNode for TokenIdentifier {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIdentifier);
+    type StablePtr = TokenIdentifierPtr;
+    type Green = TokenIdentifierGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        TokenIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::ModuleBody => MaybeModuleBody::Some(ModuleBody::from_syntax_node(db, node)),
-            SyntaxKind::TerminalSemicolon => {
-                MaybeModuleBody::None(TerminalSemicolon::from_syntax_node(db, node))
-            }
-            _ => {
-                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeModuleBody")
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIdentifier)
             }
         }
     }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            MaybeModuleBody::Some(x) => x.as_syntax_node(),
-            MaybeModuleBody::None(x) => x.as_syntax_node(),
-        }
-    }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeModuleBodyPtr(self.as_syntax_node().0.stable_ptr)
+        TokenIdentifierPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ModuleBody {
+pub struct TerminalIdentifier {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ModuleBody {
-    pub const INDEX_LBRACE: usize = 0;
-    pub const INDEX_ITEMS: usize = 1;
-    pub const INDEX_RBRACE: usize = 2;
-    pub fn new_green(
+impl Terminal for TerminalIdentifier {
+    const KIND: SyntaxKind = SyntaxKind::TerminalIdentifier;
+    type TokenType = TokenIdentifier;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        lbrace: TerminalLBraceGreen,
-        items: ItemListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ModuleBodyGreen {
-        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalIdentifier as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ModuleBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ModuleBody,
+        TerminalIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIdentifier,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl ModuleBody {
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+impl TerminalIdentifier {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn items(&self, db: &dyn SyntaxGroup) -> ItemList {
-        ItemList::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIdentifier {
+        TokenIdentifier::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModuleBodyPtr(pub SyntaxStablePtrId);
-impl ModuleBodyPtr {
+pub struct TerminalIdentifierPtr(pub SyntaxStablePtrId);
+impl TerminalIdentifierPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModuleBodyGreen(pub GreenId);
-impl TypedSyntaxNode for ModuleBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ModuleBody);
-    type StablePtr = ModuleBodyPtr;
-    type Green = ModuleBodyGreen;
+pub struct TerminalIdentifierGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalIdentifier {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIdentifier);
+    type StablePtr = TerminalIdentifierPtr;
+    type Green = TerminalIdentifierGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ModuleBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ModuleBody,
+        TerminalIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIdentifier,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLBrace::missing(db).0,
-                    ItemList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenIdentifier::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -6486,10 +10890,10 @@ impl TypedSyntaxNode for ModuleBody {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ModuleBody,
+            SyntaxKind::TerminalIdentifier,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ModuleBody
+            SyntaxKind::TerminalIdentifier
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6501,121 +10905,137 @@ impl TypedSyntaxNode for ModuleBody {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ModuleBodyPtr(self.node.0.stable_ptr)
+        TerminalIdentifierPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionAttributeArgs {
-    Empty(OptionAttributeArgsEmpty),
-    AttributeArgs(AttributeArgs),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionAttributeArgsPtr(pub SyntaxStablePtrId);
-impl OptionAttributeArgsPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
+pub struct TokenLiteralNumber {
+    node: SyntaxNode,
 }
-impl From<OptionAttributeArgsEmptyPtr> for OptionAttributeArgsPtr {
-    fn from(value: OptionAttributeArgsEmptyPtr) -> Self {
-        Self(value.0)
+impl Token for TokenLiteralNumber {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLiteralNumber,
+            details: GreenNodeDetails::Token(text),
+        }))
     }
-}
-impl From<AttributeArgsPtr> for OptionAttributeArgsPtr {
-    fn from(value: AttributeArgsPtr) -> Self {
-        Self(value.0)
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
-impl From<OptionAttributeArgsEmptyGreen> for OptionAttributeArgsGreen {
-    fn from(value: OptionAttributeArgsEmptyGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenLiteralNumberPtr(pub SyntaxStablePtrId);
+impl TokenLiteralNumberPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl From<AttributeArgsGreen> for OptionAttributeArgsGreen {
-    fn from(value: AttributeArgsGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenLiteralNumberGreen(pub GreenId);
+impl TokenLiteralNumberGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionAttributeArgsGreen(pub GreenId);
-impl TypedSyntaxNode for OptionAttributeArgs {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionAttributeArgsPtr;
-    type Green = OptionAttributeArgsGreen;
+impl TypedSyntaxNode for TokenLiteralNumber {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLiteralNumber);
+    type StablePtr = TokenLiteralNumberPtr;
+    type Green = TokenLiteralNumberGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        TokenLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::OptionAttributeArgsEmpty => {
-                OptionAttributeArgs::Empty(OptionAttributeArgsEmpty::from_syntax_node(db, node))
-            }
-            SyntaxKind::AttributeArgs => {
-                OptionAttributeArgs::AttributeArgs(AttributeArgs::from_syntax_node(db, node))
-            }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionAttributeArgs"
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => panic!(
+                "Expected a token {:?}, not an internal node",
+                SyntaxKind::TokenLiteralNumber
             ),
         }
     }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            OptionAttributeArgs::Empty(x) => x.as_syntax_node(),
-            OptionAttributeArgs::AttributeArgs(x) => x.as_syntax_node(),
-        }
-    }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionAttributeArgsPtr(self.as_syntax_node().0.stable_ptr)
+        TokenLiteralNumberPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionAttributeArgsEmpty {
+pub struct TerminalLiteralNumber {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl OptionAttributeArgsEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionAttributeArgsEmptyGreen {
-        let children: Vec<GreenId> = vec![];
+impl Terminal for TerminalLiteralNumber {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLiteralNumber;
+    type TokenType = TokenLiteralNumber;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalLiteralNumber as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionAttributeArgsEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionAttributeArgsEmpty,
+        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLiteralNumber,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalLiteralNumber {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLiteralNumber {
+        TokenLiteralNumber::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
 }
-impl OptionAttributeArgsEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionAttributeArgsEmptyPtr(pub SyntaxStablePtrId);
-impl OptionAttributeArgsEmptyPtr {
+pub struct TerminalLiteralNumberPtr(pub SyntaxStablePtrId);
+impl TerminalLiteralNumberPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionAttributeArgsEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionAttributeArgsEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionAttributeArgsEmpty);
-    type StablePtr = OptionAttributeArgsEmptyPtr;
-    type Green = OptionAttributeArgsEmptyGreen;
+pub struct TerminalLiteralNumberGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLiteralNumber {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLiteralNumber);
+    type StablePtr = TerminalLiteralNumberPtr;
+    type Green = TerminalLiteralNumberGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionAttributeArgsEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionAttributeArgsEmpty,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLiteralNumber,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenLiteralNumber::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: 0,
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::OptionAttributeArgsEmpty,
+            SyntaxKind::TerminalLiteralNumber,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::OptionAttributeArgsEmpty
+            SyntaxKind::TerminalLiteralNumber
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6627,64 +11047,123 @@ impl TypedSyntaxNode for OptionAttributeArgsEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionAttributeArgsEmptyPtr(self.node.0.stable_ptr)
+        TerminalLiteralNumberPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenShortString {
+    node: SyntaxNode,
+}
+impl Token for TokenShortString {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenShortString,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenShortStringPtr(pub SyntaxStablePtrId);
+impl TokenShortStringPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenShortStringGreen(pub GreenId);
+impl TokenShortStringGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenShortString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenShortString);
+    type StablePtr = TokenShortStringPtr;
+    type Green = TokenShortStringGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenShortString)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenShortStringPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct AttributeArgs {
+pub struct TerminalShortString {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl AttributeArgs {
-    pub const INDEX_LPAREN: usize = 0;
-    pub const INDEX_ARG_LIST: usize = 1;
-    pub const INDEX_RANGLE: usize = 2;
-    pub fn new_green(
+impl Terminal for TerminalShortString {
+    const KIND: SyntaxKind = SyntaxKind::TerminalShortString;
+    type TokenType = TokenShortString;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        lparen: TerminalLParenGreen,
-        arg_list: AttributeArgListGreen,
-        rangle: TerminalRParenGreen,
-    ) -> AttributeArgsGreen {
-        let children: Vec<GreenId> = vec![lparen.0, arg_list.0, rangle.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalShortString as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        AttributeArgsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::AttributeArgs,
+        TerminalShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShortString,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl AttributeArgs {
-    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
-        TerminalLParen::from_syntax_node(db, self.children[0].clone())
+impl TerminalShortString {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn arg_list(&self, db: &dyn SyntaxGroup) -> AttributeArgList {
-        AttributeArgList::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenShortString {
+        TokenShortString::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
-        TerminalRParen::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct AttributeArgsPtr(pub SyntaxStablePtrId);
-impl AttributeArgsPtr {
+pub struct TerminalShortStringPtr(pub SyntaxStablePtrId);
+impl TerminalShortStringPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct AttributeArgsGreen(pub GreenId);
-impl TypedSyntaxNode for AttributeArgs {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::AttributeArgs);
-    type StablePtr = AttributeArgsPtr;
-    type Green = AttributeArgsGreen;
+pub struct TerminalShortStringGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalShortString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalShortString);
+    type StablePtr = TerminalShortStringPtr;
+    type Green = TerminalShortStringGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        AttributeArgsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::AttributeArgs,
+        TerminalShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShortString,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLParen::missing(db).0,
-                    AttributeArgList::missing(db).0,
-                    TerminalRParen::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenShortString::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -6694,10 +11173,10 @@ impl TypedSyntaxNode for AttributeArgs {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::AttributeArgs,
+            SyntaxKind::TerminalShortString,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::AttributeArgs
+            SyntaxKind::TerminalShortString
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6709,172 +11188,196 @@ impl TypedSyntaxNode for AttributeArgs {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        AttributeArgsPtr(self.node.0.stable_ptr)
+        TerminalShortStringPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct AttributeArgList(ElementList<Expr, 2>);
-impl Deref for AttributeArgList {
-    type Target = ElementList<Expr, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct TokenString {
+    node: SyntaxNode,
 }
-impl AttributeArgList {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        children: Vec<AttributeArgListElementOrSeparatorGreen>,
-    ) -> AttributeArgListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        AttributeArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::AttributeArgList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
+impl Token for TokenString {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenString,
+            details: GreenNodeDetails::Token(text),
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct AttributeArgListPtr(pub SyntaxStablePtrId);
-impl AttributeArgListPtr {
+pub struct TokenStringPtr(pub SyntaxStablePtrId);
+impl TokenStringPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum AttributeArgListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(ExprGreen),
-}
-impl From<TerminalCommaGreen> for AttributeArgListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        AttributeArgListElementOrSeparatorGreen::Separator(value)
-    }
-}
-impl From<ExprGreen> for AttributeArgListElementOrSeparatorGreen {
-    fn from(value: ExprGreen) -> Self {
-        AttributeArgListElementOrSeparatorGreen::Element(value)
-    }
-}
-impl AttributeArgListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            AttributeArgListElementOrSeparatorGreen::Separator(green) => green.0,
-            AttributeArgListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+pub struct TokenStringGreen(pub GreenId);
+impl TokenStringGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct AttributeArgListGreen(pub GreenId);
-impl TypedSyntaxNode for AttributeArgList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::AttributeArgList);
-    type StablePtr = AttributeArgListPtr;
-    type Green = AttributeArgListGreen;
+impl TypedSyntaxNode for TokenString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenString);
+    type StablePtr = TokenStringPtr;
+    type Green = TokenStringGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        AttributeArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::AttributeArgList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        TokenStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenString)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        AttributeArgListPtr(self.node.0.stable_ptr)
+        TokenStringPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct FunctionDeclaration {
+pub struct TerminalString {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl FunctionDeclaration {
-    pub const INDEX_FUNCTION_KW: usize = 0;
-    pub const INDEX_NAME: usize = 1;
-    pub const INDEX_GENERIC_PARAMS: usize = 2;
-    pub const INDEX_SIGNATURE: usize = 3;
-    pub fn new_green(
+impl Terminal for TerminalString {
+    const KIND: SyntaxKind = SyntaxKind::TerminalString;
+    type TokenType = TokenString;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        function_kw: TerminalFunctionGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        signature: FunctionSignatureGreen,
-    ) -> FunctionDeclarationGreen {
-        let children: Vec<GreenId> = vec![function_kw.0, name.0, generic_params.0, signature.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalString as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        FunctionDeclarationGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::FunctionDeclaration,
+        TerminalStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalString,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl FunctionDeclaration {
-    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
-        TerminalFunction::from_syntax_node(db, self.children[0].clone())
+impl TerminalString {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenString {
+        TokenString::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
-    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
-        FunctionSignature::from_syntax_node(db, self.children[3].clone())
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalStringPtr(pub SyntaxStablePtrId);
+impl TerminalStringPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct FunctionDeclarationPtr(pub SyntaxStablePtrId);
-impl FunctionDeclarationPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
+pub struct TerminalStringGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalString);
+    type StablePtr = TerminalStringPtr;
+    type Green = TerminalStringGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalString,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenString::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalString,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalString
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalStringPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenFalse {
+    node: SyntaxNode,
+}
+impl Token for TokenFalse {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenFalse,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenFalsePtr(pub SyntaxStablePtrId);
+impl TokenFalsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct FunctionDeclarationGreen(pub GreenId);
-impl TypedSyntaxNode for FunctionDeclaration {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::FunctionDeclaration);
-    type StablePtr = FunctionDeclarationPtr;
-    type Green = FunctionDeclarationGreen;
+pub struct TokenFalseGreen(pub GreenId);
+impl TokenFalseGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenFalse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFalse);
+    type StablePtr = TokenFalsePtr;
+    type Green = TokenFalseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        FunctionDeclarationGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::FunctionDeclaration,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    TerminalFunction::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    FunctionSignature::missing(db).0,
-                ],
-                width: 0,
-            },
+        TokenFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::FunctionDeclaration,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::FunctionDeclaration
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFalse)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
@@ -6883,72 +11386,66 @@ impl TypedSyntaxNode for FunctionDeclaration {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        FunctionDeclarationPtr(self.node.0.stable_ptr)
+        TokenFalsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemFreeFunction {
+pub struct TerminalFalse {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemFreeFunction {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_DECLARATION: usize = 1;
-    pub const INDEX_BODY: usize = 2;
-    pub fn new_green(
+impl Terminal for TerminalFalse {
+    const KIND: SyntaxKind = SyntaxKind::TerminalFalse;
+    type TokenType = TokenFalse;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        declaration: FunctionDeclarationGreen,
-        body: ExprBlockGreen,
-    ) -> ItemFreeFunctionGreen {
-        let children: Vec<GreenId> = vec![attributes.0, declaration.0, body.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalFalse as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemFreeFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemFreeFunction,
+        TerminalFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFalse,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl ItemFreeFunction {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
+impl TerminalFalse {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
-        FunctionDeclaration::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFalse {
+        TokenFalse::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
-        ExprBlock::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemFreeFunctionPtr(pub SyntaxStablePtrId);
-impl ItemFreeFunctionPtr {
-    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            FunctionDeclarationGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalFalsePtr(pub SyntaxStablePtrId);
+impl TerminalFalsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemFreeFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for ItemFreeFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemFreeFunction);
-    type StablePtr = ItemFreeFunctionPtr;
-    type Green = ItemFreeFunctionGreen;
+pub struct TerminalFalseGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalFalse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFalse);
+    type StablePtr = TerminalFalsePtr;
+    type Green = TerminalFalseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemFreeFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemFreeFunction,
+        TerminalFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFalse,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    FunctionDeclaration::missing(db).0,
-                    ExprBlock::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenFalse::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -6958,10 +11455,10 @@ impl TypedSyntaxNode for ItemFreeFunction {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemFreeFunction,
+            SyntaxKind::TerminalFalse,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemFreeFunction
+            SyntaxKind::TerminalFalse
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -6973,94 +11470,55 @@ impl TypedSyntaxNode for ItemFreeFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemFreeFunctionPtr(self.node.0.stable_ptr)
+        TerminalFalsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemExternFunction {
+pub struct TokenTrue {
     node: SyntaxNode,
-    children: Vec<SyntaxNode>,
 }
-impl ItemExternFunction {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_EXTERN_KW: usize = 1;
-    pub const INDEX_DECLARATION: usize = 2;
-    pub const INDEX_SEMICOLON: usize = 3;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        extern_kw: TerminalExternGreen,
-        declaration: FunctionDeclarationGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemExternFunctionGreen {
-        let children: Vec<GreenId> = vec![attributes.0, extern_kw.0, declaration.0, semicolon.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemExternFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternFunction,
-            details: GreenNodeDetails::Node { children, width },
+impl Token for TokenTrue {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenTrueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenTrue,
+            details: GreenNodeDetails::Token(text),
         }))
     }
-}
-impl ItemExternFunction {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
-        TerminalExtern::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
-        FunctionDeclaration::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[3].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternFunctionPtr(pub SyntaxStablePtrId);
-impl ItemExternFunctionPtr {
-    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            FunctionDeclarationGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TokenTruePtr(pub SyntaxStablePtrId);
+impl TokenTruePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for ItemExternFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternFunction);
-    type StablePtr = ItemExternFunctionPtr;
-    type Green = ItemExternFunctionGreen;
+pub struct TokenTrueGreen(pub GreenId);
+impl TokenTrueGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenTrue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenTrue);
+    type StablePtr = TokenTruePtr;
+    type Green = TokenTrueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemExternFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternFunction,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalExtern::missing(db).0,
-                    FunctionDeclaration::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
-                ],
-                width: 0,
-            },
+        TokenTrueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::ItemExternFunction,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::ItemExternFunction
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenTrue)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
@@ -7069,91 +11527,66 @@ impl TypedSyntaxNode for ItemExternFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemExternFunctionPtr(self.node.0.stable_ptr)
+        TokenTruePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemExternType {
+pub struct TerminalTrue {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemExternType {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_EXTERN_KW: usize = 1;
-    pub const INDEX_TYPE_KW: usize = 2;
-    pub const INDEX_NAME: usize = 3;
-    pub const INDEX_GENERIC_PARAMS: usize = 4;
-    pub const INDEX_SEMICOLON: usize = 5;
-    pub fn new_green(
+impl Terminal for TerminalTrue {
+    const KIND: SyntaxKind = SyntaxKind::TerminalTrue;
+    type TokenType = TokenTrue;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        extern_kw: TerminalExternGreen,
-        type_kw: TerminalTypeGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemExternTypeGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, extern_kw.0, type_kw.0, name.0, generic_params.0, semicolon.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalTrue as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemExternTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternType,
+        TerminalTrueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalTrue,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
-}
-impl ItemExternType {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
-        TerminalExtern::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
-        TerminalType::from_syntax_node(db, self.children[2].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
     }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+}
+impl TerminalTrue {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenTrue {
+        TokenTrue::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[5].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternTypePtr(pub SyntaxStablePtrId);
-impl ItemExternTypePtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalTruePtr(pub SyntaxStablePtrId);
+impl TerminalTruePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternTypeGreen(pub GreenId);
-impl TypedSyntaxNode for ItemExternType {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternType);
-    type StablePtr = ItemExternTypePtr;
-    type Green = ItemExternTypeGreen;
+pub struct TerminalTrueGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalTrue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalTrue);
+    type StablePtr = TerminalTruePtr;
+    type Green = TerminalTrueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemExternTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternType,
+        TerminalTrueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalTrue,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalExtern::missing(db).0,
-                    TerminalType::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenTrue::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7163,10 +11596,10 @@ impl TypedSyntaxNode for ItemExternType {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemExternType,
+            SyntaxKind::TerminalTrue,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemExternType
+            SyntaxKind::TerminalTrue
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7178,85 +11611,123 @@ impl TypedSyntaxNode for ItemExternType {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemExternTypePtr(self.node.0.stable_ptr)
+        TerminalTruePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemTrait {
+pub struct TokenExtern {
+    node: SyntaxNode,
+}
+impl Token for TokenExtern {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenExtern,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenExternPtr(pub SyntaxStablePtrId);
+impl TokenExternPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenExternGreen(pub GreenId);
+impl TokenExternGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenExtern {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenExtern);
+    type StablePtr = TokenExternPtr;
+    type Green = TokenExternGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenExtern)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenExternPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalExtern {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemTrait {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_TRAIT_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_BODY: usize = 4;
-    pub fn new_green(
+impl Terminal for TerminalExtern {
+    const KIND: SyntaxKind = SyntaxKind::TerminalExtern;
+    type TokenType = TokenExtern;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        trait_kw: TerminalTraitGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        body: MaybeTraitBodyGreen,
-    ) -> ItemTraitGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, trait_kw.0, name.0, generic_params.0, body.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalExtern as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemTrait,
+        TerminalExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalExtern,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
-}
-impl ItemTrait {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn trait_kw(&self, db: &dyn SyntaxGroup) -> TerminalTrait {
-        TerminalTrait::from_syntax_node(db, self.children[1].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
     }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+}
+impl TerminalExtern {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenExtern {
+        TokenExtern::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitBody {
-        MaybeTraitBody::from_syntax_node(db, self.children[4].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemTraitPtr(pub SyntaxStablePtrId);
-impl ItemTraitPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalExternPtr(pub SyntaxStablePtrId);
+impl TerminalExternPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemTraitGreen(pub GreenId);
-impl TypedSyntaxNode for ItemTrait {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemTrait);
-    type StablePtr = ItemTraitPtr;
-    type Green = ItemTraitGreen;
+pub struct TerminalExternGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalExtern {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalExtern);
+    type StablePtr = TerminalExternPtr;
+    type Green = TerminalExternGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemTrait,
+        TerminalExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalExtern,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalTrait::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    MaybeTraitBody::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenExtern::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7266,10 +11737,10 @@ impl TypedSyntaxNode for ItemTrait {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemTrait,
+            SyntaxKind::TerminalExtern,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemTrait
+            SyntaxKind::TerminalExtern
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7281,130 +11752,123 @@ impl TypedSyntaxNode for ItemTrait {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemTraitPtr(self.node.0.stable_ptr)
+        TerminalExternPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeTraitBody {
-    Some(TraitBody),
-    None(TerminalSemicolon),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitBodyPtr(pub SyntaxStablePtrId);
-impl MaybeTraitBodyPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
+pub struct TokenType {
+    node: SyntaxNode,
 }
-impl From<TraitBodyPtr> for MaybeTraitBodyPtr {
-    fn from(value: TraitBodyPtr) -> Self {
-        Self(value.0)
+impl Token for TokenType {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenType,
+            details: GreenNodeDetails::Token(text),
+        }))
     }
-}
-impl From<TerminalSemicolonPtr> for MaybeTraitBodyPtr {
-    fn from(value: TerminalSemicolonPtr) -> Self {
-        Self(value.0)
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
-impl From<TraitBodyGreen> for MaybeTraitBodyGreen {
-    fn from(value: TraitBodyGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenTypePtr(pub SyntaxStablePtrId);
+impl TokenTypePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl From<TerminalSemicolonGreen> for MaybeTraitBodyGreen {
-    fn from(value: TerminalSemicolonGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenTypeGreen(pub GreenId);
+impl TokenTypeGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeTraitBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeTraitBodyPtr;
-    type Green = MaybeTraitBodyGreen;
+impl TypedSyntaxNode for TokenType {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenType);
+    type StablePtr = TokenTypePtr;
+    type Green = TokenTypeGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::TraitBody => MaybeTraitBody::Some(TraitBody::from_syntax_node(db, node)),
-            SyntaxKind::TerminalSemicolon => {
-                MaybeTraitBody::None(TerminalSemicolon::from_syntax_node(db, node))
-            }
-            _ => {
-                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeTraitBody")
-            }
-        }
+        TokenTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
     }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            MaybeTraitBody::Some(x) => x.as_syntax_node(),
-            MaybeTraitBody::None(x) => x.as_syntax_node(),
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenType)
+            }
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeTraitBodyPtr(self.as_syntax_node().0.stable_ptr)
+        TokenTypePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitBody {
+pub struct TerminalType {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl TraitBody {
-    pub const INDEX_LBRACE: usize = 0;
-    pub const INDEX_ITEMS: usize = 1;
-    pub const INDEX_RBRACE: usize = 2;
-    pub fn new_green(
+impl Terminal for TerminalType {
+    const KIND: SyntaxKind = SyntaxKind::TerminalType;
+    type TokenType = TokenType;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        lbrace: TerminalLBraceGreen,
-        items: TraitItemListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> TraitBodyGreen {
-        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalType as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TraitBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitBody,
+        TerminalTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalType,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl TraitBody {
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+impl TerminalType {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn items(&self, db: &dyn SyntaxGroup) -> TraitItemList {
-        TraitItemList::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenType {
+        TokenType::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitBodyPtr(pub SyntaxStablePtrId);
-impl TraitBodyPtr {
+pub struct TerminalTypePtr(pub SyntaxStablePtrId);
+impl TerminalTypePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitBodyGreen(pub GreenId);
-impl TypedSyntaxNode for TraitBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitBody);
-    type StablePtr = TraitBodyPtr;
-    type Green = TraitBodyGreen;
+pub struct TerminalTypeGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalType {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalType);
+    type StablePtr = TerminalTypePtr;
+    type Green = TerminalTypeGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitBody,
+        TerminalTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalType,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLBrace::missing(db).0,
-                    TraitItemList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenType::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7414,10 +11878,10 @@ impl TypedSyntaxNode for TraitBody {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TraitBody,
+            SyntaxKind::TerminalType,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TraitBody
+            SyntaxKind::TerminalType
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7429,175 +11893,123 @@ impl TypedSyntaxNode for TraitBody {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TraitBodyPtr(self.node.0.stable_ptr)
+        TerminalTypePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitItemList(ElementList<TraitItem, 1>);
-impl Deref for TraitItemList {
-    type Target = ElementList<TraitItem, 1>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-impl TraitItemList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<TraitItemGreen>) -> TraitItemListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        TraitItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.0).collect(),
-                width,
-            },
-        }))
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemListPtr(pub SyntaxStablePtrId);
-impl TraitItemListPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
+pub struct TokenConst {
+    node: SyntaxNode,
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemListGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItemList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemList);
-    type StablePtr = TraitItemListPtr;
-    type Green = TraitItemListGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+impl Token for TokenConst {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenConst,
+            details: GreenNodeDetails::Token(text),
         }))
     }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemListPtr(self.node.0.stable_ptr)
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum TraitItem {
-    Function(TraitItemFunction),
-}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemPtr(pub SyntaxStablePtrId);
-impl TraitItemPtr {
+pub struct TokenConstPtr(pub SyntaxStablePtrId);
+impl TokenConstPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<TraitItemFunctionPtr> for TraitItemPtr {
-    fn from(value: TraitItemFunctionPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TraitItemFunctionGreen> for TraitItemGreen {
-    fn from(value: TraitItemFunctionGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenConstGreen(pub GreenId);
+impl TokenConstGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItem {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = TraitItemPtr;
-    type Green = TraitItemGreen;
+impl TypedSyntaxNode for TokenConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenConst);
+    type StablePtr = TokenConstPtr;
+    type Green = TokenConstGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        TokenConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::TraitItemFunction => {
-                TraitItem::Function(TraitItemFunction::from_syntax_node(db, node))
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenConst)
             }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "TraitItem"),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            TraitItem::Function(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemPtr(self.as_syntax_node().0.stable_ptr)
+        TokenConstPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitItemFunction {
+pub struct TerminalConst {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl TraitItemFunction {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_DECLARATION: usize = 1;
-    pub const INDEX_BODY: usize = 2;
-    pub fn new_green(
+impl Terminal for TerminalConst {
+    const KIND: SyntaxKind = SyntaxKind::TerminalConst;
+    type TokenType = TokenConst;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        declaration: FunctionDeclarationGreen,
-        body: MaybeTraitFunctionBodyGreen,
-    ) -> TraitItemFunctionGreen {
-        let children: Vec<GreenId> = vec![attributes.0, declaration.0, body.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalConst as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TraitItemFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemFunction,
+        TerminalConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalConst,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl TraitItemFunction {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
+impl TerminalConst {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
-        FunctionDeclaration::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenConst {
+        TokenConst::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitFunctionBody {
-        MaybeTraitFunctionBody::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemFunctionPtr(pub SyntaxStablePtrId);
-impl TraitItemFunctionPtr {
-    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            FunctionDeclarationGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalConstPtr(pub SyntaxStablePtrId);
+impl TerminalConstPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItemFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemFunction);
-    type StablePtr = TraitItemFunctionPtr;
-    type Green = TraitItemFunctionGreen;
+pub struct TerminalConstGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalConst);
+    type StablePtr = TerminalConstPtr;
+    type Green = TerminalConstGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitItemFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemFunction,
+        TerminalConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalConst,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    FunctionDeclaration::missing(db).0,
-                    MaybeTraitFunctionBody::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenConst::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7607,10 +12019,10 @@ impl TypedSyntaxNode for TraitItemFunction {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TraitItemFunction,
+            SyntaxKind::TerminalConst,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TraitItemFunction
+            SyntaxKind::TerminalConst
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7622,166 +12034,123 @@ impl TypedSyntaxNode for TraitItemFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemFunctionPtr(self.node.0.stable_ptr)
+        TerminalConstPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeTraitFunctionBody {
-    Some(ExprBlock),
-    None(TerminalSemicolon),
+pub struct TokenFunction {
+    node: SyntaxNode,
+}
+impl Token for TokenFunction {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenFunction,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitFunctionBodyPtr(pub SyntaxStablePtrId);
-impl MaybeTraitFunctionBodyPtr {
+pub struct TokenFunctionPtr(pub SyntaxStablePtrId);
+impl TokenFunctionPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<ExprBlockPtr> for MaybeTraitFunctionBodyPtr {
-    fn from(value: ExprBlockPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalSemicolonPtr> for MaybeTraitFunctionBodyPtr {
-    fn from(value: TerminalSemicolonPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ExprBlockGreen> for MaybeTraitFunctionBodyGreen {
-    fn from(value: ExprBlockGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalSemicolonGreen> for MaybeTraitFunctionBodyGreen {
-    fn from(value: TerminalSemicolonGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenFunctionGreen(pub GreenId);
+impl TokenFunctionGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitFunctionBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeTraitFunctionBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeTraitFunctionBodyPtr;
-    type Green = MaybeTraitFunctionBodyGreen;
+impl TypedSyntaxNode for TokenFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFunction);
+    type StablePtr = TokenFunctionPtr;
+    type Green = TokenFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        TokenFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::ExprBlock => {
-                MaybeTraitFunctionBody::Some(ExprBlock::from_syntax_node(db, node))
-            }
-            SyntaxKind::TerminalSemicolon => {
-                MaybeTraitFunctionBody::None(TerminalSemicolon::from_syntax_node(db, node))
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFunction)
             }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "MaybeTraitFunctionBody"
-            ),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            MaybeTraitFunctionBody::Some(x) => x.as_syntax_node(),
-            MaybeTraitFunctionBody::None(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeTraitFunctionBodyPtr(self.as_syntax_node().0.stable_ptr)
+        TokenFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemImpl {
+pub struct TerminalFunction {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemImpl {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_IMPL_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_OF_KW: usize = 4;
-    pub const INDEX_TRAIT_PATH: usize = 5;
-    pub const INDEX_BODY: usize = 6;
-    pub fn new_green(
+impl Terminal for TerminalFunction {
+    const KIND: SyntaxKind = SyntaxKind::TerminalFunction;
+    type TokenType = TokenFunction;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        impl_kw: TerminalImplGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        of_kw: TerminalOfGreen,
-        trait_path: ExprPathGreen,
-        body: MaybeImplBodyGreen,
-    ) -> ItemImplGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, impl_kw.0, name.0, generic_params.0, of_kw.0, trait_path.0, body.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalFunction as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemImpl,
+        TerminalFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFunction,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
-}
-impl ItemImpl {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
-        TerminalImpl::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
     }
-    pub fn of_kw(&self, db: &dyn SyntaxGroup) -> TerminalOf {
-        TerminalOf::from_syntax_node(db, self.children[4].clone())
+}
+impl TerminalFunction {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn trait_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
-        ExprPath::from_syntax_node(db, self.children[5].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFunction {
+        TokenFunction::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeImplBody {
-        MaybeImplBody::from_syntax_node(db, self.children[6].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemImplPtr(pub SyntaxStablePtrId);
-impl ItemImplPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalFunctionPtr(pub SyntaxStablePtrId);
+impl TerminalFunctionPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemImplGreen(pub GreenId);
-impl TypedSyntaxNode for ItemImpl {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemImpl);
-    type StablePtr = ItemImplPtr;
-    type Green = ItemImplGreen;
+pub struct TerminalFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFunction);
+    type StablePtr = TerminalFunctionPtr;
+    type Green = TerminalFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemImpl,
+        TerminalFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFunction,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalImpl::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    TerminalOf::missing(db).0,
-                    ExprPath::missing(db).0,
-                    MaybeImplBody::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenFunction::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7791,10 +12160,10 @@ impl TypedSyntaxNode for ItemImpl {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemImpl,
+            SyntaxKind::TerminalFunction,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemImpl
+            SyntaxKind::TerminalFunction
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7806,128 +12175,123 @@ impl TypedSyntaxNode for ItemImpl {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemImplPtr(self.node.0.stable_ptr)
+        TerminalFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeImplBody {
-    Some(ImplBody),
-    None(TerminalSemicolon),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeImplBodyPtr(pub SyntaxStablePtrId);
-impl MaybeImplBodyPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
+pub struct TokenModule {
+    node: SyntaxNode,
 }
-impl From<ImplBodyPtr> for MaybeImplBodyPtr {
-    fn from(value: ImplBodyPtr) -> Self {
-        Self(value.0)
+impl Token for TokenModule {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenModule,
+            details: GreenNodeDetails::Token(text),
+        }))
     }
-}
-impl From<TerminalSemicolonPtr> for MaybeImplBodyPtr {
-    fn from(value: TerminalSemicolonPtr) -> Self {
-        Self(value.0)
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
-impl From<ImplBodyGreen> for MaybeImplBodyGreen {
-    fn from(value: ImplBodyGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenModulePtr(pub SyntaxStablePtrId);
+impl TokenModulePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl From<TerminalSemicolonGreen> for MaybeImplBodyGreen {
-    fn from(value: TerminalSemicolonGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenModuleGreen(pub GreenId);
+impl TokenModuleGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeImplBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeImplBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeImplBodyPtr;
-    type Green = MaybeImplBodyGreen;
+impl TypedSyntaxNode for TokenModule {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenModule);
+    type StablePtr = TokenModulePtr;
+    type Green = TokenModuleGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        TokenModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::ImplBody => MaybeImplBody::Some(ImplBody::from_syntax_node(db, node)),
-            SyntaxKind::TerminalSemicolon => {
-                MaybeImplBody::None(TerminalSemicolon::from_syntax_node(db, node))
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenModule)
             }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeImplBody"),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            MaybeImplBody::Some(x) => x.as_syntax_node(),
-            MaybeImplBody::None(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeImplBodyPtr(self.as_syntax_node().0.stable_ptr)
+        TokenModulePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ImplBody {
+pub struct TerminalModule {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ImplBody {
-    pub const INDEX_LBRACE: usize = 0;
-    pub const INDEX_ITEMS: usize = 1;
-    pub const INDEX_RBRACE: usize = 2;
-    pub fn new_green(
+impl Terminal for TerminalModule {
+    const KIND: SyntaxKind = SyntaxKind::TerminalModule;
+    type TokenType = TokenModule;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        lbrace: TerminalLBraceGreen,
-        items: ItemListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ImplBodyGreen {
-        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalModule as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ImplBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplBody,
+        TerminalModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalModule,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl ImplBody {
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+impl TerminalModule {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn items(&self, db: &dyn SyntaxGroup) -> ItemList {
-        ItemList::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenModule {
+        TokenModule::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplBodyPtr(pub SyntaxStablePtrId);
-impl ImplBodyPtr {
+pub struct TerminalModulePtr(pub SyntaxStablePtrId);
+impl TerminalModulePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplBodyGreen(pub GreenId);
-impl TypedSyntaxNode for ImplBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplBody);
-    type StablePtr = ImplBodyPtr;
-    type Green = ImplBodyGreen;
+pub struct TerminalModuleGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalModule {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalModule);
+    type StablePtr = TerminalModulePtr;
+    type Green = TerminalModuleGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ImplBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplBody,
+        TerminalModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalModule,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLBrace::missing(db).0,
-                    ItemList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenModule::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -7937,10 +12301,10 @@ impl TypedSyntaxNode for ImplBody {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ImplBody,
+            SyntaxKind::TerminalModule,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ImplBody
+            SyntaxKind::TerminalModule
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -7952,120 +12316,55 @@ impl TypedSyntaxNode for ImplBody {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ImplBodyPtr(self.node.0.stable_ptr)
+        TerminalModulePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemStruct {
+pub struct TokenEnum {
     node: SyntaxNode,
-    children: Vec<SyntaxNode>,
 }
-impl ItemStruct {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_STRUCT_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_LBRACE: usize = 4;
-    pub const INDEX_MEMBERS: usize = 5;
-    pub const INDEX_RBRACE: usize = 6;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        struct_kw: TerminalStructGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        lbrace: TerminalLBraceGreen,
-        members: MemberListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ItemStructGreen {
-        let children: Vec<GreenId> = vec![
-            attributes.0,
-            struct_kw.0,
-            name.0,
-            generic_params.0,
-            lbrace.0,
-            members.0,
-            rbrace.0,
-        ];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemStruct,
-            details: GreenNodeDetails::Node { children, width },
+impl Token for TokenEnum {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenEnum,
+            details: GreenNodeDetails::Token(text),
         }))
     }
-}
-impl ItemStruct {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn struct_kw(&self, db: &dyn SyntaxGroup) -> TerminalStruct {
-        TerminalStruct::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
-    }
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[4].clone())
-    }
-    pub fn members(&self, db: &dyn SyntaxGroup) -> MemberList {
-        MemberList::from_syntax_node(db, self.children[5].clone())
-    }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[6].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemStructPtr(pub SyntaxStablePtrId);
-impl ItemStructPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TokenEnumPtr(pub SyntaxStablePtrId);
+impl TokenEnumPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemStructGreen(pub GreenId);
-impl TypedSyntaxNode for ItemStruct {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemStruct);
-    type StablePtr = ItemStructPtr;
-    type Green = ItemStructGreen;
+pub struct TokenEnumGreen(pub GreenId);
+impl TokenEnumGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenEnum {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEnum);
+    type StablePtr = TokenEnumPtr;
+    type Green = TokenEnumGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemStruct,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalStruct::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    TerminalLBrace::missing(db).0,
-                    MemberList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
-                ],
-                width: 0,
-            },
+        TokenEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::ItemStruct,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::ItemStruct
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEnum)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
@@ -8074,97 +12373,66 @@ impl TypedSyntaxNode for ItemStruct {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemStructPtr(self.node.0.stable_ptr)
+        TokenEnumPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemEnum {
+pub struct TerminalEnum {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemEnum {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_ENUM_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_LBRACE: usize = 4;
-    pub const INDEX_VARIANTS: usize = 5;
-    pub const INDEX_RBRACE: usize = 6;
-    pub fn new_green(
+impl Terminal for TerminalEnum {
+    const KIND: SyntaxKind = SyntaxKind::TerminalEnum;
+    type TokenType = TokenEnum;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        enum_kw: TerminalEnumGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        lbrace: TerminalLBraceGreen,
-        variants: MemberListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ItemEnumGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, enum_kw.0, name.0, generic_params.0, lbrace.0, variants.0, rbrace.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalEnum as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemEnum,
+        TerminalEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEnum,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
-}
-impl ItemEnum {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn enum_kw(&self, db: &dyn SyntaxGroup) -> TerminalEnum {
-        TerminalEnum::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
     }
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[4].clone())
+}
+impl TerminalEnum {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn variants(&self, db: &dyn SyntaxGroup) -> MemberList {
-        MemberList::from_syntax_node(db, self.children[5].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEnum {
+        TokenEnum::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[6].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemEnumPtr(pub SyntaxStablePtrId);
-impl ItemEnumPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalEnumPtr(pub SyntaxStablePtrId);
+impl TerminalEnumPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemEnumGreen(pub GreenId);
-impl TypedSyntaxNode for ItemEnum {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemEnum);
-    type StablePtr = ItemEnumPtr;
-    type Green = ItemEnumGreen;
+pub struct TerminalEnumGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalEnum {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEnum);
+    type StablePtr = TerminalEnumPtr;
+    type Green = TerminalEnumGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemEnum,
+        TerminalEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEnum,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalEnum::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    TerminalLBrace::missing(db).0,
-                    MemberList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenEnum::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -8174,10 +12442,10 @@ impl TypedSyntaxNode for ItemEnum {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemEnum,
+            SyntaxKind::TerminalEnum,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemEnum
+            SyntaxKind::TerminalEnum
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8189,113 +12457,55 @@ impl TypedSyntaxNode for ItemEnum {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemEnumPtr(self.node.0.stable_ptr)
+        TerminalEnumPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemTypeAlias {
+pub struct TokenStruct {
     node: SyntaxNode,
-    children: Vec<SyntaxNode>,
 }
-impl ItemTypeAlias {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_TYPE_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_EQ: usize = 4;
-    pub const INDEX_TY: usize = 5;
-    pub const INDEX_SEMICOLON: usize = 6;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        type_kw: TerminalTypeGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        eq: TerminalEqGreen,
-        ty: ExprGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemTypeAliasGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, type_kw.0, name.0, generic_params.0, eq.0, ty.0, semicolon.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemTypeAliasGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemTypeAlias,
-            details: GreenNodeDetails::Node { children, width },
+impl Token for TokenStruct {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenStruct,
+            details: GreenNodeDetails::Token(text),
         }))
     }
-}
-impl ItemTypeAlias {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
-        TerminalType::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
-    }
-    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
-        TerminalEq::from_syntax_node(db, self.children[4].clone())
-    }
-    pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[5].clone())
-    }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemTypeAliasPtr(pub SyntaxStablePtrId);
-impl ItemTypeAliasPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TokenStructPtr(pub SyntaxStablePtrId);
+impl TokenStructPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemTypeAliasGreen(pub GreenId);
-impl TypedSyntaxNode for ItemTypeAlias {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemTypeAlias);
-    type StablePtr = ItemTypeAliasPtr;
-    type Green = ItemTypeAliasGreen;
+pub struct TokenStructGreen(pub GreenId);
+impl TokenStructGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenStruct {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenStruct);
+    type StablePtr = TokenStructPtr;
+    type Green = TokenStructGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemTypeAliasGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemTypeAlias,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalType::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    TerminalEq::missing(db).0,
-                    Expr::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
-                ],
-                width: 0,
-            },
+        TokenStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::ItemTypeAlias,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::ItemTypeAlias
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenStruct)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
@@ -8304,78 +12514,66 @@ impl TypedSyntaxNode for ItemTypeAlias {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemTypeAliasPtr(self.node.0.stable_ptr)
+        TokenStructPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemUse {
+pub struct TerminalStruct {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemUse {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_USE_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_SEMICOLON: usize = 3;
-    pub fn new_green(
+impl Terminal for TerminalStruct {
+    const KIND: SyntaxKind = SyntaxKind::TerminalStruct;
+    type TokenType = TokenStruct;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        use_kw: TerminalUseGreen,
-        name: ExprPathGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemUseGreen {
-        let children: Vec<GreenId> = vec![attributes.0, use_kw.0, name.0, semicolon.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalStruct as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemUseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemUse,
+        TerminalStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalStruct,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
-}
-impl ItemUse {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
     }
-    pub fn use_kw(&self, db: &dyn SyntaxGroup) -> TerminalUse {
-        TerminalUse::from_syntax_node(db, self.children[1].clone())
+}
+impl TerminalStruct {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> ExprPath {
-        ExprPath::from_syntax_node(db, self.children[2].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenStruct {
+        TokenStruct::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[3].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemUsePtr(pub SyntaxStablePtrId);
-impl ItemUsePtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> ExprPathGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            ExprPathGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalStructPtr(pub SyntaxStablePtrId);
+impl TerminalStructPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemUseGreen(pub GreenId);
-impl TypedSyntaxNode for ItemUse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemUse);
-    type StablePtr = ItemUsePtr;
-    type Green = ItemUseGreen;
+pub struct TerminalStructGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalStruct {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalStruct);
+    type StablePtr = TerminalStructPtr;
+    type Green = TerminalStructGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemUseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemUse,
+        TerminalStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalStruct,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalUse::missing(db).0,
-                    ExprPath::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenStruct::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -8385,10 +12583,10 @@ impl TypedSyntaxNode for ItemUse {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemUse,
+            SyntaxKind::TerminalStruct,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemUse
+            SyntaxKind::TerminalStruct
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8400,64 +12598,123 @@ impl TypedSyntaxNode for ItemUse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemUsePtr(self.node.0.stable_ptr)
+        TerminalStructPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericArgs {
+pub struct TokenTrait {
     node: SyntaxNode,
-    children: Vec<SyntaxNode>,
 }
-impl GenericArgs {
-    pub const INDEX_LANGLE: usize = 0;
-    pub const INDEX_GENERIC_ARGS: usize = 1;
-    pub const INDEX_RANGLE: usize = 2;
-    pub fn new_green(
+impl Token for TokenTrait {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenTrait,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenTraitPtr(pub SyntaxStablePtrId);
+impl TokenTraitPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenTraitGreen(pub GreenId);
+impl TokenTraitGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenTrait {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenTrait);
+    type StablePtr = TokenTraitPtr;
+    type Green = TokenTraitGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenTrait)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenTraitPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalTrait {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalTrait {
+    const KIND: SyntaxKind = SyntaxKind::TerminalTrait;
+    type TokenType = TokenTrait;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        langle: TerminalLTGreen,
-        generic_args: GenericArgListGreen,
-        rangle: TerminalGTGreen,
-    ) -> GenericArgsGreen {
-        let children: Vec<GreenId> = vec![langle.0, generic_args.0, rangle.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalTrait as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        GenericArgsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgs,
+        TerminalTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalTrait,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl GenericArgs {
-    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
-        TerminalLT::from_syntax_node(db, self.children[0].clone())
+impl TerminalTrait {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn generic_args(&self, db: &dyn SyntaxGroup) -> GenericArgList {
-        GenericArgList::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenTrait {
+        TokenTrait::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
-        TerminalGT::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgsPtr(pub SyntaxStablePtrId);
-impl GenericArgsPtr {
+pub struct TerminalTraitPtr(pub SyntaxStablePtrId);
+impl TerminalTraitPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgsGreen(pub GreenId);
-impl TypedSyntaxNode for GenericArgs {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgs);
-    type StablePtr = GenericArgsPtr;
-    type Green = GenericArgsGreen;
+pub struct TerminalTraitGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalTrait {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalTrait);
+    type StablePtr = TerminalTraitPtr;
+    type Green = TerminalTraitGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericArgsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgs,
+        TerminalTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalTrait,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLT::missing(db).0,
-                    GenericArgList::missing(db).0,
-                    TerminalGT::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenTrait::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -8467,10 +12724,10 @@ impl TypedSyntaxNode for GenericArgs {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::GenericArgs,
+            SyntaxKind::TerminalTrait,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::GenericArgs
+            SyntaxKind::TerminalTrait
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8482,204 +12739,196 @@ impl TypedSyntaxNode for GenericArgs {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericArgsPtr(self.node.0.stable_ptr)
+        TerminalTraitPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericArgList(ElementList<Expr, 2>);
-impl Deref for GenericArgList {
-    type Target = ElementList<Expr, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct TokenImpl {
+    node: SyntaxNode,
 }
-impl GenericArgList {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        children: Vec<GenericArgListElementOrSeparatorGreen>,
-    ) -> GenericArgListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        GenericArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
+impl Token for TokenImpl {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenImpl,
+            details: GreenNodeDetails::Token(text),
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgListPtr(pub SyntaxStablePtrId);
-impl GenericArgListPtr {
+pub struct TokenImplPtr(pub SyntaxStablePtrId);
+impl TokenImplPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum GenericArgListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(ExprGreen),
-}
-impl From<TerminalCommaGreen> for GenericArgListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        GenericArgListElementOrSeparatorGreen::Separator(value)
-    }
-}
-impl From<ExprGreen> for GenericArgListElementOrSeparatorGreen {
-    fn from(value: ExprGreen) -> Self {
-        GenericArgListElementOrSeparatorGreen::Element(value)
-    }
-}
-impl GenericArgListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            GenericArgListElementOrSeparatorGreen::Separator(green) => green.0,
-            GenericArgListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+pub struct TokenImplGreen(pub GreenId);
+impl TokenImplGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgListGreen(pub GreenId);
-impl TypedSyntaxNode for GenericArgList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgList);
-    type StablePtr = GenericArgListPtr;
-    type Green = GenericArgListGreen;
+impl TypedSyntaxNode for TokenImpl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenImpl);
+    type StablePtr = TokenImplPtr;
+    type Green = TokenImplGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        TokenImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenImpl)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericArgListPtr(self.node.0.stable_ptr)
+        TokenImplPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionWrappedGenericParamList {
-    Empty(OptionWrappedGenericParamListEmpty),
-    WrappedGenericParamList(WrappedGenericParamList),
+pub struct TerminalImpl {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListPtr(pub SyntaxStablePtrId);
-impl OptionWrappedGenericParamListPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl Terminal for TerminalImpl {
+    const KIND: SyntaxKind = SyntaxKind::TerminalImpl;
+    type TokenType = TokenImpl;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalImpl as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalImpl,
+            details: GreenNodeDetails::Node { children, width },
+        }))
     }
-}
-impl From<OptionWrappedGenericParamListEmptyPtr> for OptionWrappedGenericParamListPtr {
-    fn from(value: OptionWrappedGenericParamListEmptyPtr) -> Self {
-        Self(value.0)
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
     }
 }
-impl From<WrappedGenericParamListPtr> for OptionWrappedGenericParamListPtr {
-    fn from(value: WrappedGenericParamListPtr) -> Self {
-        Self(value.0)
+impl TerminalImpl {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-}
-impl From<OptionWrappedGenericParamListEmptyGreen> for OptionWrappedGenericParamListGreen {
-    fn from(value: OptionWrappedGenericParamListEmptyGreen) -> Self {
-        Self(value.0)
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenImpl {
+        TokenImpl::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
-impl From<WrappedGenericParamListGreen> for OptionWrappedGenericParamListGreen {
-    fn from(value: WrappedGenericParamListGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalImplPtr(pub SyntaxStablePtrId);
+impl TerminalImplPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListGreen(pub GreenId);
-impl TypedSyntaxNode for OptionWrappedGenericParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionWrappedGenericParamListPtr;
-    type Green = OptionWrappedGenericParamListGreen;
+pub struct TerminalImplGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalImpl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalImpl);
+    type StablePtr = TerminalImplPtr;
+    type Green = TerminalImplGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::OptionWrappedGenericParamListEmpty => OptionWrappedGenericParamList::Empty(
-                OptionWrappedGenericParamListEmpty::from_syntax_node(db, node),
-            ),
-            SyntaxKind::WrappedGenericParamList => {
-                OptionWrappedGenericParamList::WrappedGenericParamList(
-                    WrappedGenericParamList::from_syntax_node(db, node),
-                )
-            }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionWrappedGenericParamList"
-            ),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            OptionWrappedGenericParamList::Empty(x) => x.as_syntax_node(),
-            OptionWrappedGenericParamList::WrappedGenericParamList(x) => x.as_syntax_node(),
-        }
+        TerminalImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalImpl,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenImpl::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: 0,
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalImpl,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalImpl
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionWrappedGenericParamListPtr(self.as_syntax_node().0.stable_ptr)
+        TerminalImplPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionWrappedGenericParamListEmpty {
+pub struct TokenOf {
     node: SyntaxNode,
-    children: Vec<SyntaxNode>,
 }
-impl OptionWrappedGenericParamListEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionWrappedGenericParamListEmptyGreen {
-        let children: Vec<GreenId> = vec![];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
-            details: GreenNodeDetails::Node { children, width },
+impl Token for TokenOf {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenOfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenOf,
+            details: GreenNodeDetails::Token(text),
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
 }
-impl OptionWrappedGenericParamListEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListEmptyPtr(pub SyntaxStablePtrId);
-impl OptionWrappedGenericParamListEmptyPtr {
+pub struct TokenOfPtr(pub SyntaxStablePtrId);
+impl TokenOfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionWrappedGenericParamListEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionWrappedGenericParamListEmpty);
-    type StablePtr = OptionWrappedGenericParamListEmptyPtr;
-    type Green = OptionWrappedGenericParamListEmptyGreen;
+pub struct TokenOfGreen(pub GreenId);
+impl TokenOfGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenOf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenOf);
+    type StablePtr = TokenOfPtr;
+    type Green = TokenOfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        TokenOfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::OptionWrappedGenericParamListEmpty,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::OptionWrappedGenericParamListEmpty
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenOf)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
@@ -8688,64 +12937,66 @@ impl TypedSyntaxNode for OptionWrappedGenericParamListEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionWrappedGenericParamListEmptyPtr(self.node.0.stable_ptr)
+        TokenOfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct WrappedGenericParamList {
+pub struct TerminalOf {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl WrappedGenericParamList {
-    pub const INDEX_LANGLE: usize = 0;
-    pub const INDEX_GENERIC_PARAMS: usize = 1;
-    pub const INDEX_RANGLE: usize = 2;
-    pub fn new_green(
+impl Terminal for TerminalOf {
+    const KIND: SyntaxKind = SyntaxKind::TerminalOf;
+    type TokenType = TokenOf;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        langle: TerminalLTGreen,
-        generic_params: GenericParamListGreen,
-        rangle: TerminalGTGreen,
-    ) -> WrappedGenericParamListGreen {
-        let children: Vec<GreenId> = vec![langle.0, generic_params.0, rangle.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalOf as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        WrappedGenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::WrappedGenericParamList,
+        TerminalOfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOf,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl WrappedGenericParamList {
-    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
-        TerminalLT::from_syntax_node(db, self.children[0].clone())
+impl TerminalOf {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> GenericParamList {
-        GenericParamList::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenOf {
+        TokenOf::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
-        TerminalGT::from_syntax_node(db, self.children[2].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct WrappedGenericParamListPtr(pub SyntaxStablePtrId);
-impl WrappedGenericParamListPtr {
+pub struct TerminalOfPtr(pub SyntaxStablePtrId);
+impl TerminalOfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct WrappedGenericParamListGreen(pub GreenId);
-impl TypedSyntaxNode for WrappedGenericParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::WrappedGenericParamList);
-    type StablePtr = WrappedGenericParamListPtr;
-    type Green = WrappedGenericParamListGreen;
+pub struct TerminalOfGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalOf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalOf);
+    type StablePtr = TerminalOfPtr;
+    type Green = TerminalOfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        WrappedGenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::WrappedGenericParamList,
+        TerminalOfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOf,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLT::missing(db).0,
-                    GenericParamList::missing(db).0,
-                    TerminalGT::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenOf::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: 0,
             },
@@ -8755,10 +13006,10 @@ impl TypedSyntaxNode for WrappedGenericParamList {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::WrappedGenericParamList,
+            SyntaxKind::TerminalOf,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::WrappedGenericParamList
+            SyntaxKind::TerminalOf
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8770,134 +13021,124 @@ impl TypedSyntaxNode for WrappedGenericParamList {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        WrappedGenericParamListPtr(self.node.0.stable_ptr)
+        TerminalOfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericParamList(ElementList<GenericParam, 2>);
-impl Deref for GenericParamList {
-    type Target = ElementList<GenericParam, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct TokenLet {
+    node: SyntaxNode,
 }
-impl GenericParamList {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        children: Vec<GenericParamListElementOrSeparatorGreen>,
-    ) -> GenericParamListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        GenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
+impl Token for TokenLet {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLet,
+            details: GreenNodeDetails::Token(text),
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamListPtr(pub SyntaxStablePtrId);
-impl GenericParamListPtr {
+pub struct TokenLetPtr(pub SyntaxStablePtrId);
+impl TokenLetPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum GenericParamListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(GenericParamGreen),
-}
-impl From<TerminalCommaGreen> for GenericParamListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        GenericParamListElementOrSeparatorGreen::Separator(value)
-    }
-}
-impl From<GenericParamGreen> for GenericParamListElementOrSeparatorGreen {
-    fn from(value: GenericParamGreen) -> Self {
-        GenericParamListElementOrSeparatorGreen::Element(value)
-    }
-}
-impl GenericParamListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            GenericParamListElementOrSeparatorGreen::Separator(green) => green.0,
-            GenericParamListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+pub struct TokenLetGreen(pub GreenId);
+impl TokenLetGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamListGreen(pub GreenId);
-impl TypedSyntaxNode for GenericParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamList);
-    type StablePtr = GenericParamListPtr;
-    type Green = GenericParamListGreen;
+impl TypedSyntaxNode for TokenLet {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLet);
+    type StablePtr = TokenLetPtr;
+    type Green = TokenLetGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamList,
-            details: GreenNodeDetails::Node { children: vec![], width: 0 },
+        TokenLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLet)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericParamListPtr(self.node.0.stable_ptr)
+        TokenLetPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericParam {
+pub struct TerminalLet {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl GenericParam {
-    pub const INDEX_NAME: usize = 0;
-    pub fn new_green(db: &dyn SyntaxGroup, name: TerminalIdentifierGreen) -> GenericParamGreen {
-        let children: Vec<GreenId> = vec![name.0];
+impl Terminal for TerminalLet {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLet;
+    type TokenType = TokenLet;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalLet as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        GenericParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParam,
+        TerminalLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLet,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl GenericParam {
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+impl TerminalLet {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLet {
+        TokenLet::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamPtr(pub SyntaxStablePtrId);
-impl GenericParamPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalLetPtr(pub SyntaxStablePtrId);
+impl TerminalLetPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamGreen(pub GreenId);
-impl TypedSyntaxNode for GenericParam {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParam);
-    type StablePtr = GenericParamPtr;
-    type Green = GenericParamGreen;
+pub struct TerminalLetGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLet {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLet);
+    type StablePtr = TerminalLetPtr;
+    type Green = TerminalLetGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParam,
+        TerminalLetGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLet,
             details: GreenNodeDetails::Node {
-                children: vec![TerminalIdentifier::missing(db).0],
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenLet::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
                 width: 0,
             },
         }))
@@ -8906,10 +13147,10 @@ impl TypedSyntaxNode for GenericParam {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::GenericParam,
+            SyntaxKind::TerminalLet,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::GenericParam
+            SyntaxKind::TerminalLet
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8921,17 +13162,17 @@ impl TypedSyntaxNode for GenericParam {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericParamPtr(self.node.0.stable_ptr)
+        TerminalLetPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenIdentifier {
+pub struct TokenReturn {
     node: SyntaxNode,
 }
-impl Token for TokenIdentifier {
+impl Token for TokenReturn {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenIdentifier,
+        TokenReturnGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenReturn,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -8940,25 +13181,25 @@ impl Token for TokenIdentifier {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIdentifierPtr(pub SyntaxStablePtrId);
-impl TokenIdentifierPtr {
+pub struct TokenReturnPtr(pub SyntaxStablePtrId);
+impl TokenReturnPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIdentifierGreen(pub GreenId);
-impl TokenIdentifierGreen {
+pub struct TokenReturnGreen(pub GreenId);
+impl TokenReturnGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenIdentifier {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIdentifier);
-    type StablePtr = TokenIdentifierPtr;
-    type Green = TokenIdentifierGreen;
+impl TypedSyntaxNode for TokenReturn {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenReturn);
+    type StablePtr = TokenReturnPtr;
+    type Green = TokenReturnGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenIdentifierGreen(db.intern_green(GreenNode {
+        TokenReturnGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -8967,7 +13208,7 @@ impl TypedSyntaxNode for TokenIdentifier {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIdentifier)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenReturn)
             }
         }
     }
@@ -8978,27 +13219,27 @@ impl TypedSyntaxNode for TokenIdentifier {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenIdentifierPtr(self.node.0.stable_ptr)
+        TokenReturnPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalIdentifier {
+pub struct TerminalReturn {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalIdentifier {
-    const KIND: SyntaxKind = SyntaxKind::TerminalIdentifier;
-    type TokenType = TokenIdentifier;
+impl Terminal for TerminalReturn {
+    const KIND: SyntaxKind = SyntaxKind::TerminalReturn;
+    type TokenType = TokenReturn;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalIdentifier as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalReturn as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIdentifier,
+        TerminalReturnGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalReturn,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9006,37 +13247,37 @@ impl Terminal for TerminalIdentifier {
         self.token(db).text(db)
     }
 }
-impl TerminalIdentifier {
+impl TerminalReturn {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIdentifier {
-        TokenIdentifier::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenReturn {
+        TokenReturn::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIdentifierPtr(pub SyntaxStablePtrId);
-impl TerminalIdentifierPtr {
+pub struct TerminalReturnPtr(pub SyntaxStablePtrId);
+impl TerminalReturnPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIdentifierGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalIdentifier {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIdentifier);
-    type StablePtr = TerminalIdentifierPtr;
-    type Green = TerminalIdentifierGreen;
+pub struct TerminalReturnGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalReturn {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalReturn);
+    type StablePtr = TerminalReturnPtr;
+    type Green = TerminalReturnGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIdentifier,
+        TerminalReturnGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalReturn,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenIdentifier::missing(db).0,
+                    TokenReturn::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9047,10 +13288,10 @@ impl TypedSyntaxNode for TerminalIdentifier {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalIdentifier,
+            SyntaxKind::TerminalReturn,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalIdentifier
+            SyntaxKind::TerminalReturn
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9062,17 +13303,17 @@ impl TypedSyntaxNode for TerminalIdentifier {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalIdentifierPtr(self.node.0.stable_ptr)
+        TerminalReturnPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenLiteralNumber {
+pub struct TokenMatch {
     node: SyntaxNode,
 }
-impl Token for TokenLiteralNumber {
+impl Token for TokenMatch {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenLiteralNumberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenLiteralNumber,
+        TokenMatchGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMatch,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9081,25 +13322,25 @@ impl Token for TokenLiteralNumber {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLiteralNumberPtr(pub SyntaxStablePtrId);
-impl TokenLiteralNumberPtr {
+pub struct TokenMatchPtr(pub SyntaxStablePtrId);
+impl TokenMatchPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLiteralNumberGreen(pub GreenId);
-impl TokenLiteralNumberGreen {
+pub struct TokenMatchGreen(pub GreenId);
+impl TokenMatchGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenLiteralNumber {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLiteralNumber);
-    type StablePtr = TokenLiteralNumberPtr;
-    type Green = TokenLiteralNumberGreen;
+impl TypedSyntaxNode for TokenMatch {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMatch);
+    type StablePtr = TokenMatchPtr;
+    type Green = TokenMatchGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenLiteralNumberGreen(db.intern_green(GreenNode {
+        TokenMatchGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9107,10 +13348,9 @@ impl TypedSyntaxNode for TokenLiteralNumber {
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
-            GreenNodeDetails::Node { .. } => panic!(
-                "Expected a token {:?}, not an internal node",
-                SyntaxKind::TokenLiteralNumber
-            ),
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMatch)
+            }
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
@@ -9120,27 +13360,27 @@ impl TypedSyntaxNode for TokenLiteralNumber {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenLiteralNumberPtr(self.node.0.stable_ptr)
+        TokenMatchPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalLiteralNumber {
+pub struct TerminalMatch {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalLiteralNumber {
-    const KIND: SyntaxKind = SyntaxKind::TerminalLiteralNumber;
-    type TokenType = TokenLiteralNumber;
+impl Terminal for TerminalMatch {
+    const KIND: SyntaxKind = SyntaxKind::TerminalMatch;
+    type TokenType = TokenMatch;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalLiteralNumber as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalMatch as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLiteralNumber,
+        TerminalMatchGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMatch,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9148,37 +13388,37 @@ impl Terminal for TerminalLiteralNumber {
         self.token(db).text(db)
     }
 }
-impl TerminalLiteralNumber {
+impl TerminalMatch {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLiteralNumber {
-        TokenLiteralNumber::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMatch {
+        TokenMatch::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLiteralNumberPtr(pub SyntaxStablePtrId);
-impl TerminalLiteralNumberPtr {
+pub struct TerminalMatchPtr(pub SyntaxStablePtrId);
+impl TerminalMatchPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLiteralNumberGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalLiteralNumber {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLiteralNumber);
-    type StablePtr = TerminalLiteralNumberPtr;
-    type Green = TerminalLiteralNumberGreen;
+pub struct TerminalMatchGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalMatch {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMatch);
+    type StablePtr = TerminalMatchPtr;
+    type Green = TerminalMatchGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLiteralNumber,
+        TerminalMatchGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMatch,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenLiteralNumber::missing(db).0,
+                    TokenMatch::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9189,10 +13429,10 @@ impl TypedSyntaxNode for TerminalLiteralNumber {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalLiteralNumber,
+            SyntaxKind::TerminalMatch,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalLiteralNumber
+            SyntaxKind::TerminalMatch
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9204,17 +13444,17 @@ impl TypedSyntaxNode for TerminalLiteralNumber {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalLiteralNumberPtr(self.node.0.stable_ptr)
+        TerminalMatchPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenShortString {
+pub struct TokenIf {
     node: SyntaxNode,
 }
-impl Token for TokenShortString {
+impl Token for TokenIf {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenShortStringGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenShortString,
+        TokenIfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenIf,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9223,25 +13463,25 @@ impl Token for TokenShortString {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenShortStringPtr(pub SyntaxStablePtrId);
-impl TokenShortStringPtr {
+pub struct TokenIfPtr(pub SyntaxStablePtrId);
+impl TokenIfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenShortStringGreen(pub GreenId);
-impl TokenShortStringGreen {
+pub struct TokenIfGreen(pub GreenId);
+impl TokenIfGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenShortString {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenShortString);
-    type StablePtr = TokenShortStringPtr;
-    type Green = TokenShortStringGreen;
+impl TypedSyntaxNode for TokenIf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIf);
+    type StablePtr = TokenIfPtr;
+    type Green = TokenIfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenShortStringGreen(db.intern_green(GreenNode {
+        TokenIfGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9250,7 +13490,7 @@ impl TypedSyntaxNode for TokenShortString {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenShortString)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIf)
             }
         }
     }
@@ -9261,27 +13501,27 @@ impl TypedSyntaxNode for TokenShortString {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenShortStringPtr(self.node.0.stable_ptr)
+        TokenIfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalShortString {
+pub struct TerminalIf {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalShortString {
-    const KIND: SyntaxKind = SyntaxKind::TerminalShortString;
-    type TokenType = TokenShortString;
+impl Terminal for TerminalIf {
+    const KIND: SyntaxKind = SyntaxKind::TerminalIf;
+    type TokenType = TokenIf;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalShortString as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalIf as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalShortStringGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalShortString,
+        TerminalIfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIf,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9289,37 +13529,37 @@ impl Terminal for TerminalShortString {
         self.token(db).text(db)
     }
 }
-impl TerminalShortString {
+impl TerminalIf {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenShortString {
-        TokenShortString::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIf {
+        TokenIf::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalShortStringPtr(pub SyntaxStablePtrId);
-impl TerminalShortStringPtr {
+pub struct TerminalIfPtr(pub SyntaxStablePtrId);
+impl TerminalIfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalShortStringGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalShortString {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalShortString);
-    type StablePtr = TerminalShortStringPtr;
-    type Green = TerminalShortStringGreen;
+pub struct TerminalIfGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalIf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIf);
+    type StablePtr = TerminalIfPtr;
+    type Green = TerminalIfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalShortStringGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalShortString,
+        TerminalIfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIf,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenShortString::missing(db).0,
+                    TokenIf::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9330,10 +13570,10 @@ impl TypedSyntaxNode for TerminalShortString {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalShortString,
+            SyntaxKind::TerminalIf,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalShortString
+            SyntaxKind::TerminalIf
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9345,17 +13585,17 @@ impl TypedSyntaxNode for TerminalShortString {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalShortStringPtr(self.node.0.stable_ptr)
+        TerminalIfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenFalse {
+pub struct TokenElse {
     node: SyntaxNode,
 }
-impl Token for TokenFalse {
+impl Token for TokenElse {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenFalseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenFalse,
+        TokenElseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenElse,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9364,25 +13604,25 @@ impl Token for TokenFalse {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFalsePtr(pub SyntaxStablePtrId);
-impl TokenFalsePtr {
+pub struct TokenElsePtr(pub SyntaxStablePtrId);
+impl TokenElsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFalseGreen(pub GreenId);
-impl TokenFalseGreen {
+pub struct TokenElseGreen(pub GreenId);
+impl TokenElseGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenFalse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFalse);
-    type StablePtr = TokenFalsePtr;
-    type Green = TokenFalseGreen;
+impl TypedSyntaxNode for TokenElse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenElse);
+    type StablePtr = TokenElsePtr;
+    type Green = TokenElseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenFalseGreen(db.intern_green(GreenNode {
+        TokenElseGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9391,7 +13631,7 @@ impl TypedSyntaxNode for TokenFalse {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFalse)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenElse)
             }
         }
     }
@@ -9402,27 +13642,27 @@ impl TypedSyntaxNode for TokenFalse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenFalsePtr(self.node.0.stable_ptr)
+        TokenElsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalFalse {
+pub struct TerminalElse {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalFalse {
-    const KIND: SyntaxKind = SyntaxKind::TerminalFalse;
-    type TokenType = TokenFalse;
+impl Terminal for TerminalElse {
+    const KIND: SyntaxKind = SyntaxKind::TerminalElse;
+    type TokenType = TokenElse;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalFalse as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalElse as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalFalseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFalse,
+        TerminalElseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalElse,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9430,37 +13670,37 @@ impl Terminal for TerminalFalse {
         self.token(db).text(db)
     }
 }
-impl TerminalFalse {
+impl TerminalElse {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFalse {
-        TokenFalse::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenElse {
+        TokenElse::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFalsePtr(pub SyntaxStablePtrId);
-impl TerminalFalsePtr {
+pub struct TerminalElsePtr(pub SyntaxStablePtrId);
+impl TerminalElsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFalseGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalFalse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFalse);
-    type StablePtr = TerminalFalsePtr;
-    type Green = TerminalFalseGreen;
+pub struct TerminalElseGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalElse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalElse);
+    type StablePtr = TerminalElsePtr;
+    type Green = TerminalElseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalFalseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFalse,
+        TerminalElseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalElse,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenFalse::missing(db).0,
+                    TokenElse::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9471,10 +13711,10 @@ impl TypedSyntaxNode for TerminalFalse {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalFalse,
+            SyntaxKind::TerminalElse,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalFalse
+            SyntaxKind::TerminalElse
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9486,17 +13726,17 @@ impl TypedSyntaxNode for TerminalFalse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalFalsePtr(self.node.0.stable_ptr)
+        TerminalElsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenTrue {
+pub struct TokenLoop {
     node: SyntaxNode,
 }
-impl Token for TokenTrue {
+impl Token for TokenLoop {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenTrueGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenTrue,
+        TokenLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLoop,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9505,25 +13745,25 @@ impl Token for TokenTrue {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTruePtr(pub SyntaxStablePtrId);
-impl TokenTruePtr {
+pub struct TokenLoopPtr(pub SyntaxStablePtrId);
+impl TokenLoopPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTrueGreen(pub GreenId);
-impl TokenTrueGreen {
+pub struct TokenLoopGreen(pub GreenId);
+impl TokenLoopGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenTrue {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenTrue);
-    type StablePtr = TokenTruePtr;
-    type Green = TokenTrueGreen;
+impl TypedSyntaxNode for TokenLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLoop);
+    type StablePtr = TokenLoopPtr;
+    type Green = TokenLoopGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenTrueGreen(db.intern_green(GreenNode {
+        TokenLoopGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9532,7 +13772,7 @@ impl TypedSyntaxNode for TokenTrue {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenTrue)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLoop)
             }
         }
     }
@@ -9543,27 +13783,27 @@ impl TypedSyntaxNode for TokenTrue {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenTruePtr(self.node.0.stable_ptr)
+        TokenLoopPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalTrue {
+pub struct TerminalLoop {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalTrue {
-    const KIND: SyntaxKind = SyntaxKind::TerminalTrue;
-    type TokenType = TokenTrue;
+impl Terminal for TerminalLoop {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLoop;
+    type TokenType = TokenLoop;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalTrue as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalLoop as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalTrueGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalTrue,
+        TerminalLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLoop,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9571,37 +13811,37 @@ impl Terminal for TerminalTrue {
         self.token(db).text(db)
     }
 }
-impl TerminalTrue {
+impl TerminalLoop {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenTrue {
-        TokenTrue::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLoop {
+        TokenLoop::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTruePtr(pub SyntaxStablePtrId);
-impl TerminalTruePtr {
+pub struct TerminalLoopPtr(pub SyntaxStablePtrId);
+impl TerminalLoopPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTrueGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalTrue {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalTrue);
-    type StablePtr = TerminalTruePtr;
-    type Green = TerminalTrueGreen;
+pub struct TerminalLoopGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLoop);
+    type StablePtr = TerminalLoopPtr;
+    type Green = TerminalLoopGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalTrueGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalTrue,
+        TerminalLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLoop,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenTrue::missing(db).0,
+                    TokenLoop::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9612,10 +13852,10 @@ impl TypedSyntaxNode for TerminalTrue {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalTrue,
+            SyntaxKind::TerminalLoop,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalTrue
+            SyntaxKind::TerminalLoop
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9627,17 +13867,17 @@ impl TypedSyntaxNode for TerminalTrue {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalTruePtr(self.node.0.stable_ptr)
+        TerminalLoopPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenExtern {
+pub struct TokenWhile {
     node: SyntaxNode,
 }
-impl Token for TokenExtern {
+impl Token for TokenWhile {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenExternGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenExtern,
+        TokenWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenWhile,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9646,25 +13886,25 @@ impl Token for TokenExtern {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenExternPtr(pub SyntaxStablePtrId);
-impl TokenExternPtr {
+pub struct TokenWhilePtr(pub SyntaxStablePtrId);
+impl TokenWhilePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenExternGreen(pub GreenId);
-impl TokenExternGreen {
+pub struct TokenWhileGreen(pub GreenId);
+impl TokenWhileGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenExtern {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenExtern);
-    type StablePtr = TokenExternPtr;
-    type Green = TokenExternGreen;
+impl TypedSyntaxNode for TokenWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenWhile);
+    type StablePtr = TokenWhilePtr;
+    type Green = TokenWhileGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenExternGreen(db.intern_green(GreenNode {
+        TokenWhileGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9673,7 +13913,7 @@ impl TypedSyntaxNode for TokenExtern {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenExtern)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenWhile)
             }
         }
     }
@@ -9684,27 +13924,27 @@ impl TypedSyntaxNode for TokenExtern {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenExternPtr(self.node.0.stable_ptr)
+        TokenWhilePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalExtern {
+pub struct TerminalWhile {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalExtern {
-    const KIND: SyntaxKind = SyntaxKind::TerminalExtern;
-    type TokenType = TokenExtern;
+impl Terminal for TerminalWhile {
+    const KIND: SyntaxKind = SyntaxKind::TerminalWhile;
+    type TokenType = TokenWhile;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalExtern as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalWhile as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalExternGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalExtern,
+        TerminalWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalWhile,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9712,37 +13952,37 @@ impl Terminal for TerminalExtern {
         self.token(db).text(db)
     }
 }
-impl TerminalExtern {
+impl TerminalWhile {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenExtern {
-        TokenExtern::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenWhile {
+        TokenWhile::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalExternPtr(pub SyntaxStablePtrId);
-impl TerminalExternPtr {
+pub struct TerminalWhilePtr(pub SyntaxStablePtrId);
+impl TerminalWhilePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalExternGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalExtern {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalExtern);
-    type StablePtr = TerminalExternPtr;
-    type Green = TerminalExternGreen;
+pub struct TerminalWhileGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalWhile);
+    type StablePtr = TerminalWhilePtr;
+    type Green = TerminalWhileGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalExternGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalExtern,
+        TerminalWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalWhile,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenExtern::missing(db).0,
+                    TokenWhile::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9753,10 +13993,10 @@ impl TypedSyntaxNode for TerminalExtern {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalExtern,
+            SyntaxKind::TerminalWhile,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalExtern
+            SyntaxKind::TerminalWhile
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9768,17 +14008,17 @@ impl TypedSyntaxNode for TerminalExtern {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalExternPtr(self.node.0.stable_ptr)
+        TerminalWhilePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenType {
+pub struct TokenBreak {
     node: SyntaxNode,
 }
-impl Token for TokenType {
+impl Token for TokenBreak {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenType,
+        TokenBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenBreak,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9787,25 +14027,25 @@ impl Token for TokenType {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTypePtr(pub SyntaxStablePtrId);
-impl TokenTypePtr {
+pub struct TokenBreakPtr(pub SyntaxStablePtrId);
+impl TokenBreakPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTypeGreen(pub GreenId);
-impl TokenTypeGreen {
+pub struct TokenBreakGreen(pub GreenId);
+impl TokenBreakGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenType {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenType);
-    type StablePtr = TokenTypePtr;
-    type Green = TokenTypeGreen;
+impl TypedSyntaxNode for TokenBreak {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenBreak);
+    type StablePtr = TokenBreakPtr;
+    type Green = TokenBreakGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenTypeGreen(db.intern_green(GreenNode {
+        TokenBreakGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9814,7 +14054,7 @@ impl TypedSyntaxNode for TokenType {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenType)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenBreak)
             }
         }
     }
@@ -9825,27 +14065,27 @@ impl TypedSyntaxNode for TokenType {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenTypePtr(self.node.0.stable_ptr)
+        TokenBreakPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalType {
+pub struct TerminalBreak {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalType {
-    const KIND: SyntaxKind = SyntaxKind::TerminalType;
-    type TokenType = TokenType;
+impl Terminal for TerminalBreak {
+    const KIND: SyntaxKind = SyntaxKind::TerminalBreak;
+    type TokenType = TokenBreak;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalType as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalBreak as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalType,
+        TerminalBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalBreak,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9853,37 +14093,37 @@ impl Terminal for TerminalType {
         self.token(db).text(db)
     }
 }
-impl TerminalType {
+impl TerminalBreak {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenType {
-        TokenType::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenBreak {
+        TokenBreak::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTypePtr(pub SyntaxStablePtrId);
-impl TerminalTypePtr {
+pub struct TerminalBreakPtr(pub SyntaxStablePtrId);
+impl TerminalBreakPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTypeGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalType {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalType);
-    type StablePtr = TerminalTypePtr;
-    type Green = TerminalTypeGreen;
+pub struct TerminalBreakGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalBreak {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalBreak);
+    type StablePtr = TerminalBreakPtr;
+    type Green = TerminalBreakGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalType,
+        TerminalBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalBreak,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenType::missing(db).0,
+                    TokenBreak::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -9894,10 +14134,10 @@ impl TypedSyntaxNode for TerminalType {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalType,
+            SyntaxKind::TerminalBreak,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalType
+            SyntaxKind::TerminalBreak
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9909,17 +14149,17 @@ impl TypedSyntaxNode for TerminalType {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalTypePtr(self.node.0.stable_ptr)
+        TerminalBreakPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenFunction {
+pub struct TokenContinue {
     node: SyntaxNode,
 }
-impl Token for TokenFunction {
+impl Token for TokenContinue {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenFunction,
+        TokenContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenContinue,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -9928,25 +14168,25 @@ impl Token for TokenFunction {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFunctionPtr(pub SyntaxStablePtrId);
-impl TokenFunctionPtr {
+pub struct TokenContinuePtr(pub SyntaxStablePtrId);
+impl TokenContinuePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFunctionGreen(pub GreenId);
-impl TokenFunctionGreen {
+pub struct TokenContinueGreen(pub GreenId);
+impl TokenContinueGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFunction);
-    type StablePtr = TokenFunctionPtr;
-    type Green = TokenFunctionGreen;
+impl TypedSyntaxNode for TokenContinue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenContinue);
+    type StablePtr = TokenContinuePtr;
+    type Green = TokenContinueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenFunctionGreen(db.intern_green(GreenNode {
+        TokenContinueGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -9955,7 +14195,7 @@ impl TypedSyntaxNode for TokenFunction {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFunction)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenContinue)
             }
         }
     }
@@ -9966,27 +14206,27 @@ impl TypedSyntaxNode for TokenFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenFunctionPtr(self.node.0.stable_ptr)
+        TokenContinuePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalFunction {
+pub struct TerminalContinue {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalFunction {
-    const KIND: SyntaxKind = SyntaxKind::TerminalFunction;
-    type TokenType = TokenFunction;
+impl Terminal for TerminalContinue {
+    const KIND: SyntaxKind = SyntaxKind::TerminalContinue;
+    type TokenType = TokenContinue;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalFunction as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalContinue as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFunction,
+        TerminalContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalContinue,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -9994,37 +14234,37 @@ impl Terminal for TerminalFunction {
         self.token(db).text(db)
     }
 }
-impl TerminalFunction {
+impl TerminalContinue {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFunction {
-        TokenFunction::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenContinue {
+        TokenContinue::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFunctionPtr(pub SyntaxStablePtrId);
-impl TerminalFunctionPtr {
+pub struct TerminalContinuePtr(pub SyntaxStablePtrId);
+impl TerminalContinuePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFunction);
-    type StablePtr = TerminalFunctionPtr;
-    type Green = TerminalFunctionGreen;
+pub struct TerminalContinueGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalContinue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalContinue);
+    type StablePtr = TerminalContinuePtr;
+    type Green = TerminalContinueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFunction,
+        TerminalContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalContinue,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenFunction::missing(db).0,
+                    TokenContinue::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10035,10 +14275,10 @@ impl TypedSyntaxNode for TerminalFunction {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalFunction,
+            SyntaxKind::TerminalContinue,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalFunction
+            SyntaxKind::TerminalContinue
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10050,17 +14290,17 @@ impl TypedSyntaxNode for TerminalFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalFunctionPtr(self.node.0.stable_ptr)
+        TerminalContinuePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenModule {
+pub struct TokenUse {
     node: SyntaxNode,
 }
-impl Token for TokenModule {
+impl Token for TokenUse {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenModule,
+        TokenUseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenUse,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10069,25 +14309,25 @@ impl Token for TokenModule {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenModulePtr(pub SyntaxStablePtrId);
-impl TokenModulePtr {
+pub struct TokenUsePtr(pub SyntaxStablePtrId);
+impl TokenUsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenModuleGreen(pub GreenId);
-impl TokenModuleGreen {
+pub struct TokenUseGreen(pub GreenId);
+impl TokenUseGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenModule {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenModule);
-    type StablePtr = TokenModulePtr;
-    type Green = TokenModuleGreen;
+impl TypedSyntaxNode for TokenUse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenUse);
+    type StablePtr = TokenUsePtr;
+    type Green = TokenUseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenModuleGreen(db.intern_green(GreenNode {
+        TokenUseGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10096,7 +14336,7 @@ impl TypedSyntaxNode for TokenModule {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenModule)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenUse)
             }
         }
     }
@@ -10107,27 +14347,27 @@ impl TypedSyntaxNode for TokenModule {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenModulePtr(self.node.0.stable_ptr)
+        TokenUsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalModule {
+pub struct TerminalUse {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalModule {
-    const KIND: SyntaxKind = SyntaxKind::TerminalModule;
-    type TokenType = TokenModule;
+impl Terminal for TerminalUse {
+    const KIND: SyntaxKind = SyntaxKind::TerminalUse;
+    type TokenType = TokenUse;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalModule as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalUse as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalModule,
+        TerminalUseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalUse,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10135,37 +14375,37 @@ impl Terminal for TerminalModule {
         self.token(db).text(db)
     }
 }
-impl TerminalModule {
+impl TerminalUse {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenModule {
-        TokenModule::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenUse {
+        TokenUse::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalModulePtr(pub SyntaxStablePtrId);
-impl TerminalModulePtr {
+pub struct TerminalUsePtr(pub SyntaxStablePtrId);
+impl TerminalUsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalModuleGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalModule {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalModule);
-    type StablePtr = TerminalModulePtr;
-    type Green = TerminalModuleGreen;
+pub struct TerminalUseGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalUse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalUse);
+    type StablePtr = TerminalUsePtr;
+    type Green = TerminalUseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalModule,
+        TerminalUseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalUse,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenModule::missing(db).0,
+                    TokenUse::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10176,10 +14416,10 @@ impl TypedSyntaxNode for TerminalModule {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalModule,
+            SyntaxKind::TerminalUse,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalModule
+            SyntaxKind::TerminalUse
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10191,17 +14431,17 @@ impl TypedSyntaxNode for TerminalModule {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalModulePtr(self.node.0.stable_ptr)
+        TerminalUsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenEnum {
+pub struct TokenPub {
     node: SyntaxNode,
 }
-impl Token for TokenEnum {
+impl Token for TokenPub {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenEnum,
+        TokenPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenPub,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10210,25 +14450,25 @@ impl Token for TokenEnum {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEnumPtr(pub SyntaxStablePtrId);
-impl TokenEnumPtr {
+pub struct TokenPubPtr(pub SyntaxStablePtrId);
+impl TokenPubPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEnumGreen(pub GreenId);
-impl TokenEnumGreen {
+pub struct TokenPubGreen(pub GreenId);
+impl TokenPubGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenEnum {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEnum);
-    type StablePtr = TokenEnumPtr;
-    type Green = TokenEnumGreen;
+impl TypedSyntaxNode for TokenPub {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenPub);
+    type StablePtr = TokenPubPtr;
+    type Green = TokenPubGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenEnumGreen(db.intern_green(GreenNode {
+        TokenPubGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10237,7 +14477,7 @@ impl TypedSyntaxNode for TokenEnum {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEnum)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenPub)
             }
         }
     }
@@ -10248,27 +14488,27 @@ impl TypedSyntaxNode for TokenEnum {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenEnumPtr(self.node.0.stable_ptr)
+        TokenPubPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalEnum {
+pub struct TerminalPub {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalEnum {
-    const KIND: SyntaxKind = SyntaxKind::TerminalEnum;
-    type TokenType = TokenEnum;
+impl Terminal for TerminalPub {
+    const KIND: SyntaxKind = SyntaxKind::TerminalPub;
+    type TokenType = TokenPub;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalEnum as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalPub as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEnum,
+        TerminalPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalPub,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10276,37 +14516,37 @@ impl Terminal for TerminalEnum {
         self.token(db).text(db)
     }
 }
-impl TerminalEnum {
+impl TerminalPub {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEnum {
-        TokenEnum::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenPub {
+        TokenPub::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEnumPtr(pub SyntaxStablePtrId);
-impl TerminalEnumPtr {
+pub struct TerminalPubPtr(pub SyntaxStablePtrId);
+impl TerminalPubPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEnumGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalEnum {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEnum);
-    type StablePtr = TerminalEnumPtr;
-    type Green = TerminalEnumGreen;
+pub struct TerminalPubGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalPub {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalPub);
+    type StablePtr = TerminalPubPtr;
+    type Green = TerminalPubGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEnum,
+        TerminalPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalPub,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenEnum::missing(db).0,
+                    TokenPub::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10317,10 +14557,10 @@ impl TypedSyntaxNode for TerminalEnum {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalEnum,
+            SyntaxKind::TerminalPub,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalEnum
+            SyntaxKind::TerminalPub
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10332,17 +14572,17 @@ impl TypedSyntaxNode for TerminalEnum {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalEnumPtr(self.node.0.stable_ptr)
+        TerminalPubPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenStruct {
+pub struct TokenImplicits {
     node: SyntaxNode,
 }
-impl Token for TokenStruct {
+impl Token for TokenImplicits {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenStruct,
+        TokenImplicitsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenImplicits,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10351,25 +14591,25 @@ impl Token for TokenStruct {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenStructPtr(pub SyntaxStablePtrId);
-impl TokenStructPtr {
+pub struct TokenImplicitsPtr(pub SyntaxStablePtrId);
+impl TokenImplicitsPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenStructGreen(pub GreenId);
-impl TokenStructGreen {
+pub struct TokenImplicitsGreen(pub GreenId);
+impl TokenImplicitsGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenStruct {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenStruct);
-    type StablePtr = TokenStructPtr;
-    type Green = TokenStructGreen;
+impl TypedSyntaxNode for TokenImplicits {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenImplicits);
+    type StablePtr = TokenImplicitsPtr;
+    type Green = TokenImplicitsGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenStructGreen(db.intern_green(GreenNode {
+        TokenImplicitsGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10378,7 +14618,7 @@ impl TypedSyntaxNode for TokenStruct {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenStruct)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenImplicits)
             }
         }
     }
@@ -10389,27 +14629,27 @@ impl TypedSyntaxNode for TokenStruct {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenStructPtr(self.node.0.stable_ptr)
+        TokenImplicitsPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalStruct {
+pub struct TerminalImplicits {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalStruct {
-    const KIND: SyntaxKind = SyntaxKind::TerminalStruct;
-    type TokenType = TokenStruct;
+impl Terminal for TerminalImplicits {
+    const KIND: SyntaxKind = SyntaxKind::TerminalImplicits;
+    type TokenType = TokenImplicits;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalStruct as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalImplicits as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalStruct,
+        TerminalImplicitsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalImplicits,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10417,37 +14657,37 @@ impl Terminal for TerminalStruct {
         self.token(db).text(db)
     }
 }
-impl TerminalStruct {
+impl TerminalImplicits {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenStruct {
-        TokenStruct::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenImplicits {
+        TokenImplicits::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalStructPtr(pub SyntaxStablePtrId);
-impl TerminalStructPtr {
+pub struct TerminalImplicitsPtr(pub SyntaxStablePtrId);
+impl TerminalImplicitsPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalStructGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalStruct {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalStruct);
-    type StablePtr = TerminalStructPtr;
-    type Green = TerminalStructGreen;
+pub struct TerminalImplicitsGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalImplicits {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalImplicits);
+    type StablePtr = TerminalImplicitsPtr;
+    type Green = TerminalImplicitsGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalStruct,
+        TerminalImplicitsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalImplicits,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenStruct::missing(db).0,
+                    TokenImplicits::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10458,10 +14698,10 @@ impl TypedSyntaxNode for TerminalStruct {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalStruct,
+            SyntaxKind::TerminalImplicits,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalStruct
+            SyntaxKind::TerminalImplicits
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10473,17 +14713,17 @@ impl TypedSyntaxNode for TerminalStruct {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalStructPtr(self.node.0.stable_ptr)
+        TerminalImplicitsPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenTrait {
+pub struct TokenRef {
     node: SyntaxNode,
 }
-impl Token for TokenTrait {
+impl Token for TokenRef {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenTrait,
+        TokenRefGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenRef,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10492,25 +14732,25 @@ impl Token for TokenTrait {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTraitPtr(pub SyntaxStablePtrId);
-impl TokenTraitPtr {
+pub struct TokenRefPtr(pub SyntaxStablePtrId);
+impl TokenRefPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenTraitGreen(pub GreenId);
-impl TokenTraitGreen {
+pub struct TokenRefGreen(pub GreenId);
+impl TokenRefGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenTrait {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenTrait);
-    type StablePtr = TokenTraitPtr;
-    type Green = TokenTraitGreen;
+impl TypedSyntaxNode for TokenRef {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenRef);
+    type StablePtr = TokenRefPtr;
+    type Green = TokenRefGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenTraitGreen(db.intern_green(GreenNode {
+        TokenRefGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10519,7 +14759,7 @@ impl TypedSyntaxNode for TokenTrait {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenTrait)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenRef)
             }
         }
     }
@@ -10530,27 +14770,27 @@ impl TypedSyntaxNode for TokenTrait {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenTraitPtr(self.node.0.stable_ptr)
+        TokenRefPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalTrait {
+pub struct TerminalRef {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalTrait {
-    const KIND: SyntaxKind = SyntaxKind::TerminalTrait;
-    type TokenType = TokenTrait;
+impl Terminal for TerminalRef {
+    const KIND: SyntaxKind = SyntaxKind::TerminalRef;
+    type TokenType = TokenRef;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalTrait as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalRef as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalTrait,
+        TerminalRefGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalRef,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10558,37 +14798,37 @@ impl Terminal for TerminalTrait {
         self.token(db).text(db)
     }
 }
-impl TerminalTrait {
+impl TerminalRef {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenTrait {
-        TokenTrait::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenRef {
+        TokenRef::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTraitPtr(pub SyntaxStablePtrId);
-impl TerminalTraitPtr {
+pub struct TerminalRefPtr(pub SyntaxStablePtrId);
+impl TerminalRefPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalTraitGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalTrait {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalTrait);
-    type StablePtr = TerminalTraitPtr;
-    type Green = TerminalTraitGreen;
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalRefGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalRef {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalRef);
+    type StablePtr = TerminalRefPtr;
+    type Green = TerminalRefGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalTrait,
+        TerminalRefGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalRef,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenTrait::missing(db).0,
+                    TokenRef::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10599,10 +14839,10 @@ impl TypedSyntaxNode for TerminalTrait {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalTrait,
+            SyntaxKind::TerminalRef,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalTrait
+            SyntaxKind::TerminalRef
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10614,17 +14854,17 @@ impl TypedSyntaxNode for TerminalTrait {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalTraitPtr(self.node.0.stable_ptr)
+        TerminalRefPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenImpl {
+pub struct TokenMut {
     node: SyntaxNode,
 }
-impl Token for TokenImpl {
+impl Token for TokenMut {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenImpl,
+        TokenMutGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMut,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10633,25 +14873,25 @@ impl Token for TokenImpl {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenImplPtr(pub SyntaxStablePtrId);
-impl TokenImplPtr {
+pub struct TokenMutPtr(pub SyntaxStablePtrId);
+impl TokenMutPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenImplGreen(pub GreenId);
-impl TokenImplGreen {
+pub struct TokenMutGreen(pub GreenId);
+impl TokenMutGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenImpl {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenImpl);
-    type StablePtr = TokenImplPtr;
-    type Green = TokenImplGreen;
+impl TypedSyntaxNode for TokenMut {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMut);
+    type StablePtr = TokenMutPtr;
+    type Green = TokenMutGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenImplGreen(db.intern_green(GreenNode {
+        TokenMutGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10660,7 +14900,7 @@ impl TypedSyntaxNode for TokenImpl {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenImpl)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMut)
             }
         }
     }
@@ -10671,27 +14911,27 @@ impl TypedSyntaxNode for TokenImpl {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenImplPtr(self.node.0.stable_ptr)
+        TokenMutPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalImpl {
+pub struct TerminalMut {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalImpl {
-    const KIND: SyntaxKind = SyntaxKind::TerminalImpl;
-    type TokenType = TokenImpl;
+impl Terminal for TerminalMut {
+    const KIND: SyntaxKind = SyntaxKind::TerminalMut;
+    type TokenType = TokenMut;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalImpl as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalMut as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalImpl,
+        TerminalMutGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMut,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10699,37 +14939,37 @@ impl Terminal for TerminalImpl {
         self.token(db).text(db)
     }
 }
-impl TerminalImpl {
+impl TerminalMut {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenImpl {
-        TokenImpl::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMut {
+        TokenMut::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalImplPtr(pub SyntaxStablePtrId);
-impl TerminalImplPtr {
+pub struct TerminalMutPtr(pub SyntaxStablePtrId);
+impl TerminalMutPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalImplGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalImpl {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalImpl);
-    type StablePtr = TerminalImplPtr;
-    type Green = TerminalImplGreen;
+pub struct TerminalMutGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalMut {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMut);
+    type StablePtr = TerminalMutPtr;
+    type Green = TerminalMutGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalImpl,
+        TerminalMutGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMut,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenImpl::missing(db).0,
+                    TokenMut::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10740,10 +14980,10 @@ impl TypedSyntaxNode for TerminalImpl {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalImpl,
+            SyntaxKind::TerminalMut,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalImpl
+            SyntaxKind::TerminalMut
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10755,17 +14995,17 @@ impl TypedSyntaxNode for TerminalImpl {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalImplPtr(self.node.0.stable_ptr)
+        TerminalMutPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenOf {
+pub struct TokenNoPanic {
     node: SyntaxNode,
 }
-impl Token for TokenOf {
+impl Token for TokenNoPanic {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenOfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenOf,
+        TokenNoPanicGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenNoPanic,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10774,25 +15014,25 @@ impl Token for TokenOf {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenOfPtr(pub SyntaxStablePtrId);
-impl TokenOfPtr {
+pub struct TokenNoPanicPtr(pub SyntaxStablePtrId);
+impl TokenNoPanicPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenOfGreen(pub GreenId);
-impl TokenOfGreen {
+pub struct TokenNoPanicGreen(pub GreenId);
+impl TokenNoPanicGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenOf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenOf);
-    type StablePtr = TokenOfPtr;
-    type Green = TokenOfGreen;
+impl TypedSyntaxNode for TokenNoPanic {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenNoPanic);
+    type StablePtr = TokenNoPanicPtr;
+    type Green = TokenNoPanicGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenOfGreen(db.intern_green(GreenNode {
+        TokenNoPanicGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10801,7 +15041,7 @@ impl TypedSyntaxNode for TokenOf {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenOf)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenNoPanic)
             }
         }
     }
@@ -10812,27 +15052,27 @@ impl TypedSyntaxNode for TokenOf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenOfPtr(self.node.0.stable_ptr)
+        TokenNoPanicPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalOf {
+pub struct TerminalNoPanic {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalOf {
-    const KIND: SyntaxKind = SyntaxKind::TerminalOf;
-    type TokenType = TokenOf;
+impl Terminal for TerminalNoPanic {
+    const KIND: SyntaxKind = SyntaxKind::TerminalNoPanic;
+    type TokenType = TokenNoPanic;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalOf as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalNoPanic as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalOfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalOf,
+        TerminalNoPanicGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalNoPanic,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10840,37 +15080,37 @@ impl Terminal for TerminalOf {
         self.token(db).text(db)
     }
 }
-impl TerminalOf {
+impl TerminalNoPanic {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenOf {
-        TokenOf::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenNoPanic {
+        TokenNoPanic::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalOfPtr(pub SyntaxStablePtrId);
-impl TerminalOfPtr {
+pub struct TerminalNoPanicPtr(pub SyntaxStablePtrId);
+impl TerminalNoPanicPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalOfGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalOf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalOf);
-    type StablePtr = TerminalOfPtr;
-    type Green = TerminalOfGreen;
+pub struct TerminalNoPanicGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalNoPanic {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalNoPanic);
+    type StablePtr = TerminalNoPanicPtr;
+    type Green = TerminalNoPanicGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalOfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalOf,
+        TerminalNoPanicGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalNoPanic,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenOf::missing(db).0,
+                    TokenNoPanic::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -10881,10 +15121,10 @@ impl TypedSyntaxNode for TerminalOf {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalOf,
+            SyntaxKind::TerminalNoPanic,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalOf
+            SyntaxKind::TerminalNoPanic
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10896,17 +15136,17 @@ impl TypedSyntaxNode for TerminalOf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalOfPtr(self.node.0.stable_ptr)
+        TerminalNoPanicPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenLet {
+pub struct TokenAnd {
     node: SyntaxNode,
 }
-impl Token for TokenLet {
+impl Token for TokenAnd {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenLetGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenLet,
+        TokenAndGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenAnd,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10915,25 +15155,25 @@ impl Token for TokenLet {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLetPtr(pub SyntaxStablePtrId);
-impl TokenLetPtr {
+pub struct TokenAndPtr(pub SyntaxStablePtrId);
+impl TokenAndPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLetGreen(pub GreenId);
-impl TokenLetGreen {
+pub struct TokenAndGreen(pub GreenId);
+impl TokenAndGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenLet {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLet);
-    type StablePtr = TokenLetPtr;
-    type Green = TokenLetGreen;
+impl TypedSyntaxNode for TokenAnd {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenAnd);
+    type StablePtr = TokenAndPtr;
+    type Green = TokenAndGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenLetGreen(db.intern_green(GreenNode {
+        TokenAndGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10942,7 +15182,7 @@ impl TypedSyntaxNode for TokenLet {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLet)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenAnd)
             }
         }
     }
@@ -10953,27 +15193,27 @@ impl TypedSyntaxNode for TokenLet {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenLetPtr(self.node.0.stable_ptr)
+        TokenAndPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalLet {
+pub struct TerminalAnd {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalLet {
-    const KIND: SyntaxKind = SyntaxKind::TerminalLet;
-    type TokenType = TokenLet;
+impl Terminal for TerminalAnd {
+    const KIND: SyntaxKind = SyntaxKind::TerminalAnd;
+    type TokenType = TokenAnd;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalLet as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalAnd as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalLetGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLet,
+        TerminalAndGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalAnd,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10981,37 +15221,37 @@ impl Terminal for TerminalLet {
         self.token(db).text(db)
     }
 }
-impl TerminalLet {
+impl TerminalAnd {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLet {
-        TokenLet::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenAnd {
+        TokenAnd::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLetPtr(pub SyntaxStablePtrId);
-impl TerminalLetPtr {
+pub struct TerminalAndPtr(pub SyntaxStablePtrId);
+impl TerminalAndPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLetGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalLet {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLet);
-    type StablePtr = TerminalLetPtr;
-    type Green = TerminalLetGreen;
+pub struct TerminalAndGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalAnd {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalAnd);
+    type StablePtr = TerminalAndPtr;
+    type Green = TerminalAndGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalLetGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLet,
+        TerminalAndGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalAnd,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenLet::missing(db).0,
+                    TokenAnd::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -11022,10 +15262,10 @@ impl TypedSyntaxNode for TerminalLet {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalLet,
+            SyntaxKind::TerminalAnd,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalLet
+            SyntaxKind::TerminalAnd
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11037,17 +15277,17 @@ impl TypedSyntaxNode for TerminalLet {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalLetPtr(self.node.0.stable_ptr)
+        TerminalAndPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenReturn {
+pub struct TokenAndAnd {
     node: SyntaxNode,
 }
-impl Token for TokenReturn {
+impl Token for TokenAndAnd {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenReturnGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenReturn,
+        TokenAndAndGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenAndAnd,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -11056,25 +15296,25 @@ impl Token for TokenReturn {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenReturnPtr(pub SyntaxStablePtrId);
-impl TokenReturnPtr {
+pub struct TokenAndAndPtr(pub SyntaxStablePtrId);
+impl TokenAndAndPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenReturnGreen(pub GreenId);
-impl TokenReturnGreen {
+pub struct TokenAndAndGreen(pub GreenId);
+impl TokenAndAndGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenReturn {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenReturn);
-    type StablePtr = TokenReturnPtr;
-    type Green = TokenReturnGreen;
+impl TypedSyntaxNode for TokenAndAnd {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenAndAnd);
+    type StablePtr = TokenAndAndPtr;
+    type Green = TokenAndAndGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenReturnGreen(db.intern_green(GreenNode {
+        TokenAndAndGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -11083,7 +15323,7 @@ impl TypedSyntaxNode for TokenReturn {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenReturn)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenAndAnd)
             }
         }
     }
@@ -11094,27 +15334,27 @@ impl TypedSyntaxNode for TokenReturn {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenReturnPtr(self.node.0.stable_ptr)
+        TokenAndAndPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalReturn {
+pub struct TerminalAndAnd {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalReturn {
-    const KIND: SyntaxKind = SyntaxKind::TerminalReturn;
-    type TokenType = TokenReturn;
+impl Terminal for TerminalAndAnd {
+    const KIND: SyntaxKind = SyntaxKind::TerminalAndAnd;
+    type TokenType = TokenAndAnd;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalReturn as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalAndAnd as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalReturnGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalReturn,
+        TerminalAndAndGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalAndAnd,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -11122,37 +15362,37 @@ impl Terminal for TerminalReturn {
         self.token(db).text(db)
     }
 }
-impl TerminalReturn {
+impl TerminalAndAnd {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenReturn {
-        TokenReturn::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenAndAnd {
+        TokenAndAnd::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalReturnPtr(pub SyntaxStablePtrId);
-impl TerminalReturnPtr {
+pub struct TerminalAndAndPtr(pub SyntaxStablePtrId);
+impl TerminalAndAndPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalReturnGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalReturn {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalReturn);
-    type StablePtr = TerminalReturnPtr;
-    type Green = TerminalReturnGreen;
+pub struct TerminalAndAndGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalAndAnd {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalAndAnd);
+    type StablePtr = TerminalAndAndPtr;
+    type Green = TerminalAndAndGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalReturnGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalReturn,
+        TerminalAndAndGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalAndAnd,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenReturn::missing(db).0,
+                    TokenAndAnd::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -11163,10 +15403,10 @@ impl TypedSyntaxNode for TerminalReturn {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalReturn,
+            SyntaxKind::TerminalAndAnd,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalReturn
+            SyntaxKind::TerminalAndAnd
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11178,17 +15418,17 @@ impl TypedSyntaxNode for TerminalReturn {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalReturnPtr(self.node.0.stable_ptr)
+        TerminalAndAndPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenMatch {
+pub struct TokenOr {
     node: SyntaxNode,
 }
-impl Token for TokenMatch {
+impl Token for TokenOr {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenMatchGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenMatch,
+        TokenOrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenOr,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -11197,25 +15437,25 @@ impl Token for TokenMatch {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMatchPtr(pub SyntaxStablePtrId);
-impl TokenMatchPtr {
+pub struct TokenOrPtr(pub SyntaxStablePtrId);
+impl TokenOrPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMatchGreen(pub GreenId);
-impl TokenMatchGreen {
+pub struct TokenOrGreen(pub GreenId);
+impl TokenOrGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenMatch {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMatch);
-    type StablePtr = TokenMatchPtr;
-    type Green = TokenMatchGreen;
+impl TypedSyntaxNode for TokenOr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenOr);
+    type StablePtr = TokenOrPtr;
+    type Green = TokenOrGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenMatchGreen(db.intern_green(GreenNode {
+        TokenOrGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -11224,7 +15464,7 @@ impl TypedSyntaxNode for TokenMatch {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMatch)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenOr)
             }
         }
     }
@@ -11235,27 +15475,27 @@ impl TypedSyntaxNode for TokenMatch {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenMatchPtr(self.node.0.stable_ptr)
+        TokenOrPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalMatch {
+pub struct TerminalOr {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalMatch {
-    const KIND: SyntaxKind = SyntaxKind::TerminalMatch;
-    type TokenType = TokenMatch;
+impl Terminal for TerminalOr {
+    const KIND: SyntaxKind = SyntaxKind::TerminalOr;
+    type TokenType = TokenOr;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalMatch as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalOr as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalMatchGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMatch,
+        TerminalOrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOr,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -11263,37 +15503,37 @@ impl Terminal for TerminalMatch {
         self.token(db).text(db)
     }
 }
-impl TerminalMatch {
+impl TerminalOr {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMatch {
-        TokenMatch::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenOr {
+        TokenOr::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMatchPtr(pub SyntaxStablePtrId);
-impl TerminalMatchPtr {
+pub struct TerminalOrPtr(pub SyntaxStablePtrId);
+impl TerminalOrPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMatchGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalMatch {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMatch);
-    type StablePtr = TerminalMatchPtr;
-    type Green = TerminalMatchGreen;
+pub struct TerminalOrGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalOr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalOr);
+    type StablePtr = TerminalOrPtr;
+    type Green = TerminalOrGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalMatchGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMatch,
+        TerminalOrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOr,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenMatch::missing(db).0,
+                    TokenOr::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -11304,10 +15544,10 @@ impl TypedSyntaxNode for TerminalMatch {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalMatch,
+            SyntaxKind::TerminalOr,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalMatch
+            SyntaxKind::TerminalOr
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11319,17 +15559,17 @@ impl TypedSyntaxNode for TerminalMatch {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalMatchPtr(self.node.0.stable_ptr)
+        TerminalOrPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenIf {
+pub struct TokenOrOr {
     node: SyntaxNode,
 }
-impl Token for TokenIf {
+impl Token for TokenOrOr {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenIfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenIf,
+        TokenOrOrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenOrOr,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -11338,25 +15578,25 @@ impl Token for TokenIf {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIfPtr(pub SyntaxStablePtrId);
-impl TokenIfPtr {
+pub struct TokenOrOrPtr(pub SyntaxStablePtrId);
+impl TokenOrOrPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIfGreen(pub GreenId);
-impl TokenIfGreen {
+pub struct TokenOrOrGreen(pub GreenId);
+impl TokenOrOrGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenIf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIf);
-    type StablePtr = TokenIfPtr;
-    type Green = TokenIfGreen;
+impl TypedSyntaxNode for TokenOrOr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenOrOr);
+    type StablePtr = TokenOrOrPtr;
+    type Green = TokenOrOrGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenIfGreen(db.intern_green(GreenNode {
+        TokenOrOrGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -11365,7 +15605,7 @@ impl TypedSyntaxNode for TokenIf {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIf)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenOrOr)
             }
         }
     }
@@ -11376,27 +15616,27 @@ impl TypedSyntaxNode for TokenIf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenIfPtr(self.node.0.stable_ptr)
+        TokenOrOrPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalIf {
+pub struct TerminalOrOr {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalIf {
-    const KIND: SyntaxKind = SyntaxKind::TerminalIf;
-    type TokenType = TokenIf;
+impl Terminal for TerminalOrOr {
+    const KIND: SyntaxKind = SyntaxKind::TerminalOrOr;
+    type TokenType = TokenOrOr;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalIf as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalOrOr as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalIfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIf,
+        TerminalOrOrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOrOr,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -11404,37 +15644,37 @@ impl Terminal for TerminalIf {
         self.token(db).text(db)
     }
 }
-impl TerminalIf {
+impl TerminalOrOr {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIf {
-        TokenIf::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenOrOr {
+        TokenOrOr::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIfPtr(pub SyntaxStablePtrId);
-impl TerminalIfPtr {
+pub struct TerminalOrOrPtr(pub SyntaxStablePtrId);
+impl TerminalOrOrPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIfGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalIf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIf);
-    type StablePtr = TerminalIfPtr;
-    type Green = TerminalIfGreen;
+pub struct TerminalOrOrGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalOrOr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalOrOr);
+    type StablePtr = TerminalOrOrPtr;
+    type Green = TerminalOrOrGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalIfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIf,
+        TerminalOrOrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOrOr,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenIf::missing(db).0,
+                    TokenOrOr::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -11445,10 +15685,10 @@ impl TypedSyntaxNode for TerminalIf {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalIf,
+            SyntaxKind::TerminalOrOr,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalIf
+            SyntaxKind::TerminalOrOr
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11460,17 +15700,17 @@ impl TypedSyntaxNode for TerminalIf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalIfPtr(self.node.0.stable_ptr)
+        TerminalOrOrPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenElse {
+pub struct TokenXor {
     node: SyntaxNode,
 }
-impl Token for TokenElse {
+impl Token for TokenXor {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenElseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenElse,
+        TokenXorGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenXor,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -11479,25 +15719,25 @@ impl Token for TokenElse {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenElsePtr(pub SyntaxStablePtrId);
-impl TokenElsePtr {
+pub struct TokenXorPtr(pub SyntaxStablePtrId);
+impl TokenXorPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenElseGreen(pub GreenId);
-impl TokenElseGreen {
+pub struct TokenXorGreen(pub GreenId);
+impl TokenXorGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenElse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenElse);
-    type StablePtr = TokenElsePtr;
-    type Green = TokenElseGreen;
+impl TypedSyntaxNode for TokenXor {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenXor);
+    type StablePtr = TokenXorPtr;
+    type Green = TokenXorGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenElseGreen(db.intern_green(GreenNode {
+        TokenXorGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -11506,7 +15746,7 @@ impl TypedSyntaxNode for TokenElse {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenElse)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenXor)
             }
         }
     }
@@ -11517,27 +15757,27 @@ impl TypedSyntaxNode for TokenElse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenElsePtr(self.node.0.stable_ptr)
+        TokenXorPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalElse {
+pub struct TerminalXor {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalElse {
-    const KIND: SyntaxKind = SyntaxKind::TerminalElse;
-    type TokenType = TokenElse;
+impl Terminal for TerminalXor {
+    const KIND: SyntaxKind = SyntaxKind::TerminalXor;
+    type TokenType = TokenXor;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalElse as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalXor as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalElseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalElse,
+        TerminalXorGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalXor,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -11545,37 +15785,37 @@ impl Terminal for TerminalElse {
         self.token(db).text(db)
     }
 }
-impl TerminalElse {
+impl TerminalXor {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenElse {
-        TokenElse::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenXor {
+        TokenXor::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalElsePtr(pub SyntaxStablePtrId);
-impl TerminalElsePtr {
+pub struct TerminalXorPtr(pub SyntaxStablePtrId);
+impl TerminalXorPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalElseGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalElse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalElse);
-    type StablePtr = TerminalElsePtr;
-    type Green = TerminalElseGreen;
+pub struct TerminalXorGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalXor {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalXor);
+    type StablePtr = TerminalXorPtr;
+    type Green = TerminalXorGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalElseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalElse,
+        TerminalXorGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalXor,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenElse::missing(db).0,
+                    TokenXor::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -11586,10 +15826,10 @@ impl TypedSyntaxNode for TerminalElse {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalElse,
+            SyntaxKind::TerminalXor,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalElse
+            SyntaxKind::TerminalXor
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11601,17 +15841,17 @@ impl TypedSyntaxNode for TerminalElse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalElsePtr(self.node.0.stable_ptr)
+        TerminalXorPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenUse {
+pub struct TokenShl {
     node: SyntaxNode,
 }
-impl Token for TokenUse {
+impl Token for TokenShl {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenUseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenUse,
+        TokenShlGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenShl,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -11620,25 +15860,25 @@ impl Token for TokenUse {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenUsePtr(pub SyntaxStablePtrId);
-impl TokenUsePtr {
+pub struct TokenShlPtr(pub SyntaxStablePtrId);
+impl TokenShlPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenUseGreen(pub GreenId);
-impl TokenUseGreen {
+pub struct TokenShlGreen(pub GreenId);
+impl TokenShlGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenUse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenUse);
-    type StablePtr = TokenUsePtr;
-    type Green = TokenUseGreen;
+impl TypedSyntaxNode for TokenShl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenShl);
+    type StablePtr = TokenShlPtr;
+    type Green = TokenShlGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenUseGreen(db.intern_green(GreenNode {
+        TokenShlGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -11647,7 +15887,7 @@ impl TypedSyntaxNode for TokenUse {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenUse)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenShl)
             }
         }
     }
@@ -11658,27 +15898,27 @@ impl TypedSyntaxNode for TokenUse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenUsePtr(self.node.0.stable_ptr)
+        TokenShlPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalUse {
+pub struct TerminalShl {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalUse {
-    const KIND: SyntaxKind = SyntaxKind::TerminalUse;
-    type TokenType = TokenUse;
+impl Terminal for TerminalShl {
+    const KIND: SyntaxKind = SyntaxKind::TerminalShl;
+    type TokenType = TokenShl;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalUse as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalShl as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalUseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalUse,
+        TerminalShlGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShl,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -11686,37 +15926,37 @@ impl Terminal for TerminalUse {
         self.token(db).text(db)
     }
 }
-impl TerminalUse {
+impl TerminalShl {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenUse {
-        TokenUse::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenShl {
+        TokenShl::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalUsePtr(pub SyntaxStablePtrId);
-impl TerminalUsePtr {
+pub struct TerminalShlPtr(pub SyntaxStablePtrId);
+impl TerminalShlPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalUseGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalUse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalUse);
-    type StablePtr = TerminalUsePtr;
-    type Green = TerminalUseGreen;
+pub struct TerminalShlGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalShl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalShl);
+    type StablePtr = TerminalShlPtr;
+    type Green = TerminalShlGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalUseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalUse,
+        TerminalShlGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShl,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenUse::missing(db).0,
+                    TokenShl::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -11727,10 +15967,10 @@ impl TypedSyntaxNode for TerminalUse {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalUse,
+            SyntaxKind::TerminalShl,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalUse
+            SyntaxKind::TerminalShl
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11742,17 +15982,17 @@ impl TypedSyntaxNode for TerminalUse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalUsePtr(self.node.0.stable_ptr)
+        TerminalShlPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenImplicits {
+pub struct TokenShr {
     node: SyntaxNode,
 }
-impl Token for TokenImplicits {
+impl Token for TokenShr {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenImplicitsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenImplicits,
+        TokenShrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenShr,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -11761,25 +16001,25 @@ impl Token for TokenImplicits {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenImplicitsPtr(pub SyntaxStablePtrId);
-impl TokenImplicitsPtr {
+pub struct TokenShrPtr(pub SyntaxStablePtrId);
+impl TokenShrPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenImplicitsGreen(pub GreenId);
-impl TokenImplicitsGreen {
+pub struct TokenShrGreen(pub GreenId);
+impl TokenShrGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenImplicits {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenImplicits);
-    type StablePtr = TokenImplicitsPtr;
-    type Green = TokenImplicitsGreen;
+impl TypedSyntaxNode for TokenShr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenShr);
+    type StablePtr = TokenShrPtr;
+    type Green = TokenShrGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenImplicitsGreen(db.intern_green(GreenNode {
+        TokenShrGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -11788,7 +16028,7 @@ impl TypedSyntaxNode for TokenImplicits {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenImplicits)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenShr)
             }
         }
     }
@@ -11799,27 +16039,27 @@ impl TypedSyntaxNode for TokenImplicits {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenImplicitsPtr(self.node.0.stable_ptr)
+        TokenShrPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalImplicits {
+pub struct TerminalShr {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalImplicits {
-    const KIND: SyntaxKind = SyntaxKind::TerminalImplicits;
-    type TokenType = TokenImplicits;
+impl Terminal for TerminalShr {
+    const KIND: SyntaxKind = SyntaxKind::TerminalShr;
+    type TokenType = TokenShr;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalImplicits as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalShr as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalImplicitsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalImplicits,
+        TerminalShrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShr,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -11827,37 +16067,37 @@ impl Terminal for TerminalImplicits {
         self.token(db).text(db)
     }
 }
-impl TerminalImplicits {
+impl TerminalShr {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenImplicits {
-        TokenImplicits::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenShr {
+        TokenShr::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalImplicitsPtr(pub SyntaxStablePtrId);
-impl TerminalImplicitsPtr {
+pub struct TerminalShrPtr(pub SyntaxStablePtrId);
+impl TerminalShrPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalImplicitsGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalImplicits {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalImplicits);
-    type StablePtr = TerminalImplicitsPtr;
-    type Green = TerminalImplicitsGreen;
+pub struct TerminalShrGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalShr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalShr);
+    type StablePtr = TerminalShrPtr;
+    type Green = TerminalShrGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalImplicitsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalImplicits,
+        TerminalShrGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShr,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenImplicits::missing(db).0,
+                    TokenShr::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -11868,10 +16108,10 @@ impl TypedSyntaxNode for TerminalImplicits {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalImplicits,
+            SyntaxKind::TerminalShr,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalImplicits
+            SyntaxKind::TerminalShr
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11883,17 +16123,17 @@ impl TypedSyntaxNode for TerminalImplicits {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalImplicitsPtr(self.node.0.stable_ptr)
+        TerminalShrPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenRef {
+pub struct TokenEqEq {
     node: SyntaxNode,
 }
-impl Token for TokenRef {
+impl Token for TokenEqEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenRefGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenRef,
+        TokenEqEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenEqEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -11902,25 +16142,25 @@ impl Token for TokenRef {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenRefPtr(pub SyntaxStablePtrId);
-impl TokenRefPtr {
+pub struct TokenEqEqPtr(pub SyntaxStablePtrId);
+impl TokenEqEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenRefGreen(pub GreenId);
-impl TokenRefGreen {
+pub struct TokenEqEqGreen(pub GreenId);
+impl TokenEqEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenRef {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenRef);
-    type StablePtr = TokenRefPtr;
-    type Green = TokenRefGreen;
+impl TypedSyntaxNode for TokenEqEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEqEq);
+    type StablePtr = TokenEqEqPtr;
+    type Green = TokenEqEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenRefGreen(db.intern_green(GreenNode {
+        TokenEqEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -11929,7 +16169,7 @@ impl TypedSyntaxNode for TokenRef {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenRef)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEqEq)
             }
         }
     }
@@ -11940,27 +16180,27 @@ impl TypedSyntaxNode for TokenRef {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenRefPtr(self.node.0.stable_ptr)
+        TokenEqEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalRef {
+pub struct TerminalEqEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalRef {
-    const KIND: SyntaxKind = SyntaxKind::TerminalRef;
-    type TokenType = TokenRef;
+impl Terminal for TerminalEqEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalEqEq;
+    type TokenType = TokenEqEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalRef as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalEqEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalRefGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalRef,
+        TerminalEqEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEqEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -11968,37 +16208,37 @@ impl Terminal for TerminalRef {
         self.token(db).text(db)
     }
 }
-impl TerminalRef {
+impl TerminalEqEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenRef {
-        TokenRef::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEqEq {
+        TokenEqEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalRefPtr(pub SyntaxStablePtrId);
-impl TerminalRefPtr {
+pub struct TerminalEqEqPtr(pub SyntaxStablePtrId);
+impl TerminalEqEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalRefGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalRef {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalRef);
-    type StablePtr = TerminalRefPtr;
-    type Green = TerminalRefGreen;
+pub struct TerminalEqEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalEqEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEqEq);
+    type StablePtr = TerminalEqEqPtr;
+    type Green = TerminalEqEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalRefGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalRef,
+        TerminalEqEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEqEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenRef::missing(db).0,
+                    TokenEqEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -12009,10 +16249,10 @@ impl TypedSyntaxNode for TerminalRef {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalRef,
+            SyntaxKind::TerminalEqEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalRef
+            SyntaxKind::TerminalEqEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12024,17 +16264,17 @@ impl TypedSyntaxNode for TerminalRef {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalRefPtr(self.node.0.stable_ptr)
+        TerminalEqEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenMut {
+pub struct TokenNeq {
     node: SyntaxNode,
 }
-impl Token for TokenMut {
+impl Token for TokenNeq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenMutGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenMut,
+        TokenNeqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenNeq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12043,25 +16283,25 @@ impl Token for TokenMut {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMutPtr(pub SyntaxStablePtrId);
-impl TokenMutPtr {
+pub struct TokenNeqPtr(pub SyntaxStablePtrId);
+impl TokenNeqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMutGreen(pub GreenId);
-impl TokenMutGreen {
+pub struct TokenNeqGreen(pub GreenId);
+impl TokenNeqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenMut {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMut);
-    type StablePtr = TokenMutPtr;
-    type Green = TokenMutGreen;
+impl TypedSyntaxNode for TokenNeq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenNeq);
+    type StablePtr = TokenNeqPtr;
+    type Green = TokenNeqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenMutGreen(db.intern_green(GreenNode {
+        TokenNeqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12070,7 +16310,7 @@ impl TypedSyntaxNode for TokenMut {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMut)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenNeq)
             }
         }
     }
@@ -12081,27 +16321,27 @@ impl TypedSyntaxNode for TokenMut {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenMutPtr(self.node.0.stable_ptr)
+        TokenNeqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalMut {
+pub struct TerminalNeq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalMut {
-    const KIND: SyntaxKind = SyntaxKind::TerminalMut;
-    type TokenType = TokenMut;
+impl Terminal for TerminalNeq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalNeq;
+    type TokenType = TokenNeq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalMut as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalNeq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalMutGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMut,
+        TerminalNeqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalNeq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12109,37 +16349,37 @@ impl Terminal for TerminalMut {
         self.token(db).text(db)
     }
 }
-impl TerminalMut {
+impl TerminalNeq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMut {
-        TokenMut::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenNeq {
+        TokenNeq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMutPtr(pub SyntaxStablePtrId);
-impl TerminalMutPtr {
+pub struct TerminalNeqPtr(pub SyntaxStablePtrId);
+impl TerminalNeqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMutGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalMut {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMut);
-    type StablePtr = TerminalMutPtr;
-    type Green = TerminalMutGreen;
+pub struct TerminalNeqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalNeq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalNeq);
+    type StablePtr = TerminalNeqPtr;
+    type Green = TerminalNeqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalMutGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMut,
+        TerminalNeqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalNeq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenMut::missing(db).0,
+                    TokenNeq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -12150,10 +16390,10 @@ impl TypedSyntaxNode for TerminalMut {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalMut,
+            SyntaxKind::TerminalNeq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalMut
+            SyntaxKind::TerminalNeq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12165,17 +16405,17 @@ impl TypedSyntaxNode for TerminalMut {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalMutPtr(self.node.0.stable_ptr)
+        TerminalNeqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenNoPanic {
+pub struct TokenGE {
     node: SyntaxNode,
 }
-impl Token for TokenNoPanic {
+impl Token for TokenGE {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenNoPanicGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenNoPanic,
+        TokenGEGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenGE,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12184,25 +16424,25 @@ impl Token for TokenNoPanic {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenNoPanicPtr(pub SyntaxStablePtrId);
-impl TokenNoPanicPtr {
+pub struct TokenGEPtr(pub SyntaxStablePtrId);
+impl TokenGEPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenNoPanicGreen(pub GreenId);
-impl TokenNoPanicGreen {
+pub struct TokenGEGreen(pub GreenId);
+impl TokenGEGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenNoPanic {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenNoPanic);
-    type StablePtr = TokenNoPanicPtr;
-    type Green = TokenNoPanicGreen;
+impl TypedSyntaxNode for TokenGE {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenGE);
+    type StablePtr = TokenGEPtr;
+    type Green = TokenGEGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenNoPanicGreen(db.intern_green(GreenNode {
+        TokenGEGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12211,7 +16451,7 @@ impl TypedSyntaxNode for TokenNoPanic {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenNoPanic)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenGE)
             }
         }
     }
@@ -12222,27 +16462,27 @@ impl TypedSyntaxNode for TokenNoPanic {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenNoPanicPtr(self.node.0.stable_ptr)
+        TokenGEPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalNoPanic {
+pub struct TerminalGE {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalNoPanic {
-    const KIND: SyntaxKind = SyntaxKind::TerminalNoPanic;
-    type TokenType = TokenNoPanic;
+impl Terminal for TerminalGE {
+    const KIND: SyntaxKind = SyntaxKind::TerminalGE;
+    type TokenType = TokenGE;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalNoPanic as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalGE as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalNoPanicGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalNoPanic,
+        TerminalGEGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalGE,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12250,37 +16490,37 @@ impl Terminal for TerminalNoPanic {
         self.token(db).text(db)
     }
 }
-impl TerminalNoPanic {
+impl TerminalGE {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenNoPanic {
-        TokenNoPanic::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenGE {
+        TokenGE::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalNoPanicPtr(pub SyntaxStablePtrId);
-impl TerminalNoPanicPtr {
+pub struct TerminalGEPtr(pub SyntaxStablePtrId);
+impl TerminalGEPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalNoPanicGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalNoPanic {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalNoPanic);
-    type StablePtr = TerminalNoPanicPtr;
-    type Green = TerminalNoPanicGreen;
+pub struct TerminalGEGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalGE {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalGE);
+    type StablePtr = TerminalGEPtr;
+    type Green = TerminalGEGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalNoPanicGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalNoPanic,
+        TerminalGEGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalGE,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenNoPanic::missing(db).0,
+                    TokenGE::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -12291,10 +16531,10 @@ impl TypedSyntaxNode for TerminalNoPanic {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalNoPanic,
+            SyntaxKind::TerminalGE,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalNoPanic
+            SyntaxKind::TerminalGE
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12306,17 +16546,17 @@ impl TypedSyntaxNode for TerminalNoPanic {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalNoPanicPtr(self.node.0.stable_ptr)
+        TerminalGEPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenAnd {
+pub struct TokenGT {
     node: SyntaxNode,
 }
-impl Token for TokenAnd {
+impl Token for TokenGT {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenAndGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenAnd,
+        TokenGTGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenGT,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12325,25 +16565,25 @@ impl Token for TokenAnd {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenAndPtr(pub SyntaxStablePtrId);
-impl TokenAndPtr {
+pub struct TokenGTPtr(pub SyntaxStablePtrId);
+impl TokenGTPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenAndGreen(pub GreenId);
-impl TokenAndGreen {
+pub struct TokenGTGreen(pub GreenId);
+impl TokenGTGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenAnd {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenAnd);
-    type StablePtr = TokenAndPtr;
-    type Green = TokenAndGreen;
+impl TypedSyntaxNode for TokenGT {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenGT);
+    type StablePtr = TokenGTPtr;
+    type Green = TokenGTGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenAndGreen(db.intern_green(GreenNode {
+        TokenGTGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12352,7 +16592,7 @@ impl TypedSyntaxNode for TokenAnd {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenAnd)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenGT)
             }
         }
     }
@@ -12363,27 +16603,27 @@ impl TypedSyntaxNode for TokenAnd {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenAndPtr(self.node.0.stable_ptr)
+        TokenGTPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalAnd {
+pub struct TerminalGT {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalAnd {
-    const KIND: SyntaxKind = SyntaxKind::TerminalAnd;
-    type TokenType = TokenAnd;
+impl Terminal for TerminalGT {
+    const KIND: SyntaxKind = SyntaxKind::TerminalGT;
+    type TokenType = TokenGT;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalAnd as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalGT as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalAndGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalAnd,
+        TerminalGTGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalGT,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12391,37 +16631,37 @@ impl Terminal for TerminalAnd {
         self.token(db).text(db)
     }
 }
-impl TerminalAnd {
+impl TerminalGT {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenAnd {
-        TokenAnd::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenGT {
+        TokenGT::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalAndPtr(pub SyntaxStablePtrId);
-impl TerminalAndPtr {
+pub struct TerminalGTPtr(pub SyntaxStablePtrId);
+impl TerminalGTPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalAndGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalAnd {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalAnd);
-    type StablePtr = TerminalAndPtr;
-    type Green = TerminalAndGreen;
+pub struct TerminalGTGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalGT {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalGT);
+    type StablePtr = TerminalGTPtr;
+    type Green = TerminalGTGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalAndGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalAnd,
+        TerminalGTGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalGT,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenAnd::missing(db).0,
+                    TokenGT::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -12432,10 +16672,10 @@ impl TypedSyntaxNode for TerminalAnd {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalAnd,
+            SyntaxKind::TerminalGT,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalAnd
+            SyntaxKind::TerminalGT
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12447,17 +16687,17 @@ impl TypedSyntaxNode for TerminalAnd {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalAndPtr(self.node.0.stable_ptr)
+        TerminalGTPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenAndAnd {
+pub struct TokenLE {
     node: SyntaxNode,
 }
-impl Token for TokenAndAnd {
+impl Token for TokenLE {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenAndAndGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenAndAnd,
+        TokenLEGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLE,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12466,25 +16706,25 @@ impl Token for TokenAndAnd {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenAndAndPtr(pub SyntaxStablePtrId);
-impl TokenAndAndPtr {
+pub struct TokenLEPtr(pub SyntaxStablePtrId);
+impl TokenLEPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenAndAndGreen(pub GreenId);
-impl TokenAndAndGreen {
+pub struct TokenLEGreen(pub GreenId);
+impl TokenLEGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenAndAnd {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenAndAnd);
-    type StablePtr = TokenAndAndPtr;
-    type Green = TokenAndAndGreen;
+impl TypedSyntaxNode for TokenLE {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLE);
+    type StablePtr = TokenLEPtr;
+    type Green = TokenLEGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenAndAndGreen(db.intern_green(GreenNode {
+        TokenLEGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12493,7 +16733,7 @@ impl TypedSyntaxNode for TokenAndAnd {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenAndAnd)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLE)
             }
         }
     }
@@ -12504,27 +16744,27 @@ impl TypedSyntaxNode for TokenAndAnd {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenAndAndPtr(self.node.0.stable_ptr)
+        TokenLEPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalAndAnd {
+pub struct TerminalLE {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalAndAnd {
-    const KIND: SyntaxKind = SyntaxKind::TerminalAndAnd;
-    type TokenType = TokenAndAnd;
+impl Terminal for TerminalLE {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLE;
+    type TokenType = TokenLE;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalAndAnd as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalLE as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalAndAndGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalAndAnd,
+        TerminalLEGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLE,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12532,37 +16772,37 @@ impl Terminal for TerminalAndAnd {
         self.token(db).text(db)
     }
 }
-impl TerminalAndAnd {
+impl TerminalLE {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenAndAnd {
-        TokenAndAnd::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLE {
+        TokenLE::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalAndAndPtr(pub SyntaxStablePtrId);
-impl TerminalAndAndPtr {
+pub struct TerminalLEPtr(pub SyntaxStablePtrId);
+impl TerminalLEPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalAndAndGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalAndAnd {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalAndAnd);
-    type StablePtr = TerminalAndAndPtr;
-    type Green = TerminalAndAndGreen;
+pub struct TerminalLEGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLE {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLE);
+    type StablePtr = TerminalLEPtr;
+    type Green = TerminalLEGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalAndAndGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalAndAnd,
+        TerminalLEGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLE,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenAndAnd::missing(db).0,
+                    TokenLE::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -12573,10 +16813,10 @@ impl TypedSyntaxNode for TerminalAndAnd {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalAndAnd,
+            SyntaxKind::TerminalLE,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalAndAnd
+            SyntaxKind::TerminalLE
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12588,17 +16828,17 @@ impl TypedSyntaxNode for TerminalAndAnd {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalAndAndPtr(self.node.0.stable_ptr)
+        TerminalLEPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenOr {
+pub struct TokenLT {
     node: SyntaxNode,
 }
-impl Token for TokenOr {
+impl Token for TokenLT {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenOrGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenOr,
+        TokenLTGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLT,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12607,25 +16847,25 @@ impl Token for TokenOr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenOrPtr(pub SyntaxStablePtrId);
-impl TokenOrPtr {
+pub struct TokenLTPtr(pub SyntaxStablePtrId);
+impl TokenLTPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenOrGreen(pub GreenId);
-impl TokenOrGreen {
+pub struct TokenLTGreen(pub GreenId);
+impl TokenLTGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenOr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenOr);
-    type StablePtr = TokenOrPtr;
-    type Green = TokenOrGreen;
+impl TypedSyntaxNode for TokenLT {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLT);
+    type StablePtr = TokenLTPtr;
+    type Green = TokenLTGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenOrGreen(db.intern_green(GreenNode {
+        TokenLTGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12634,7 +16874,7 @@ impl TypedSyntaxNode for TokenOr {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenOr)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLT)
             }
         }
     }
@@ -12645,27 +16885,27 @@ impl TypedSyntaxNode for TokenOr {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenOrPtr(self.node.0.stable_ptr)
+        TokenLTPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalOr {
+pub struct TerminalLT {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalOr {
-    const KIND: SyntaxKind = SyntaxKind::TerminalOr;
-    type TokenType = TokenOr;
+impl Terminal for TerminalLT {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLT;
+    type TokenType = TokenLT;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalOr as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalLT as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalOrGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalOr,
+        TerminalLTGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLT,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12673,37 +16913,37 @@ impl Terminal for TerminalOr {
         self.token(db).text(db)
     }
 }
-impl TerminalOr {
+impl TerminalLT {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenOr {
-        TokenOr::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLT {
+        TokenLT::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalOrPtr(pub SyntaxStablePtrId);
-impl TerminalOrPtr {
+pub struct TerminalLTPtr(pub SyntaxStablePtrId);
+impl TerminalLTPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalOrGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalOr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalOr);
-    type StablePtr = TerminalOrPtr;
-    type Green = TerminalOrGreen;
+pub struct TerminalLTGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLT {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLT);
+    type StablePtr = TerminalLTPtr;
+    type Green = TerminalLTGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalOrGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalOr,
+        TerminalLTGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLT,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenOr::missing(db).0,
+                    TokenLT::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -12714,10 +16954,10 @@ impl TypedSyntaxNode for TerminalOr {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalOr,
+            SyntaxKind::TerminalLT,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalOr
+            SyntaxKind::TerminalLT
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12729,17 +16969,17 @@ impl TypedSyntaxNode for TerminalOr {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalOrPtr(self.node.0.stable_ptr)
+        TerminalLTPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenOrOr {
+pub struct TokenNot {
     node: SyntaxNode,
 }
-impl Token for TokenOrOr {
+impl Token for TokenNot {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenOrOrGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenOrOr,
+        TokenNotGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenNot,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12748,25 +16988,25 @@ impl Token for TokenOrOr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenOrOrPtr(pub SyntaxStablePtrId);
-impl TokenOrOrPtr {
+pub struct TokenNotPtr(pub SyntaxStablePtrId);
+impl TokenNotPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenOrOrGreen(pub GreenId);
-impl TokenOrOrGreen {
+pub struct TokenNotGreen(pub GreenId);
+impl TokenNotGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenOrOr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenOrOr);
-    type StablePtr = TokenOrOrPtr;
-    type Green = TokenOrOrGreen;
+impl TypedSyntaxNode for TokenNot {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenNot);
+    type StablePtr = TokenNotPtr;
+    type Green = TokenNotGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenOrOrGreen(db.intern_green(GreenNode {
+        TokenNotGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12775,7 +17015,7 @@ impl TypedSyntaxNode for TokenOrOr {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenOrOr)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenNot)
             }
         }
     }
@@ -12786,27 +17026,27 @@ impl TypedSyntaxNode for TokenOrOr {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenOrOrPtr(self.node.0.stable_ptr)
+        TokenNotPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalOrOr {
+pub struct TerminalNot {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalOrOr {
-    const KIND: SyntaxKind = SyntaxKind::TerminalOrOr;
-    type TokenType = TokenOrOr;
+impl Terminal for TerminalNot {
+    const KIND: SyntaxKind = SyntaxKind::TerminalNot;
+    type TokenType = TokenNot;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalOrOr as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalNot as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalOrOrGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalOrOr,
+        TerminalNotGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalNot,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12814,37 +17054,37 @@ impl Terminal for TerminalOrOr {
         self.token(db).text(db)
     }
 }
-impl TerminalOrOr {
+impl TerminalNot {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenOrOr {
-        TokenOrOr::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenNot {
+        TokenNot::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalOrOrPtr(pub SyntaxStablePtrId);
-impl TerminalOrOrPtr {
+pub struct TerminalNotPtr(pub SyntaxStablePtrId);
+impl TerminalNotPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalOrOrGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalOrOr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalOrOr);
-    type StablePtr = TerminalOrOrPtr;
-    type Green = TerminalOrOrGreen;
+pub struct TerminalNotGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalNot {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalNot);
+    type StablePtr = TerminalNotPtr;
+    type Green = TerminalNotGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalOrOrGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalOrOr,
+        TerminalNotGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalNot,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenOrOr::missing(db).0,
+                    TokenNot::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -12855,10 +17095,10 @@ impl TypedSyntaxNode for TerminalOrOr {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalOrOr,
+            SyntaxKind::TerminalNot,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalOrOr
+            SyntaxKind::TerminalNot
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12870,17 +17110,17 @@ impl TypedSyntaxNode for TerminalOrOr {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalOrOrPtr(self.node.0.stable_ptr)
+        TerminalNotPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenXor {
+pub struct TokenPlus {
     node: SyntaxNode,
 }
-impl Token for TokenXor {
+impl Token for TokenPlus {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenXorGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenXor,
+        TokenPlusGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenPlus,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12889,25 +17129,25 @@ impl Token for TokenXor {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenXorPtr(pub SyntaxStablePtrId);
-impl TokenXorPtr {
+pub struct TokenPlusPtr(pub SyntaxStablePtrId);
+impl TokenPlusPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenXorGreen(pub GreenId);
-impl TokenXorGreen {
+pub struct TokenPlusGreen(pub GreenId);
+impl TokenPlusGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenXor {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenXor);
-    type StablePtr = TokenXorPtr;
-    type Green = TokenXorGreen;
+impl TypedSyntaxNode for TokenPlus {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenPlus);
+    type StablePtr = TokenPlusPtr;
+    type Green = TokenPlusGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenXorGreen(db.intern_green(GreenNode {
+        TokenPlusGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12916,7 +17156,7 @@ impl TypedSyntaxNode for TokenXor {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenXor)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenPlus)
             }
         }
     }
@@ -12927,27 +17167,27 @@ impl TypedSyntaxNode for TokenXor {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenXorPtr(self.node.0.stable_ptr)
+        TokenPlusPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalXor {
+pub struct TerminalPlus {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalXor {
-    const KIND: SyntaxKind = SyntaxKind::TerminalXor;
-    type TokenType = TokenXor;
+impl Terminal for TerminalPlus {
+    const KIND: SyntaxKind = SyntaxKind::TerminalPlus;
+    type TokenType = TokenPlus;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalXor as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalPlus as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalXorGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalXor,
+        TerminalPlusGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalPlus,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12955,37 +17195,37 @@ impl Terminal for TerminalXor {
         self.token(db).text(db)
     }
 }
-impl TerminalXor {
+impl TerminalPlus {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenXor {
-        TokenXor::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenPlus {
+        TokenPlus::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalXorPtr(pub SyntaxStablePtrId);
-impl TerminalXorPtr {
+pub struct TerminalPlusPtr(pub SyntaxStablePtrId);
+impl TerminalPlusPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalXorGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalXor {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalXor);
-    type StablePtr = TerminalXorPtr;
-    type Green = TerminalXorGreen;
+pub struct TerminalPlusGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalPlus {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalPlus);
+    type StablePtr = TerminalPlusPtr;
+    type Green = TerminalPlusGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalXorGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalXor,
+        TerminalPlusGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalPlus,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenXor::missing(db).0,
+                    TokenPlus::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -12996,10 +17236,10 @@ impl TypedSyntaxNode for TerminalXor {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalXor,
+            SyntaxKind::TerminalPlus,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalXor
+            SyntaxKind::TerminalPlus
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13011,17 +17251,17 @@ impl TypedSyntaxNode for TerminalXor {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalXorPtr(self.node.0.stable_ptr)
+        TerminalPlusPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenEqEq {
+pub struct TokenMinus {
     node: SyntaxNode,
 }
-impl Token for TokenEqEq {
+impl Token for TokenMinus {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenEqEqGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenEqEq,
+        TokenMinusGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMinus,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13030,25 +17270,25 @@ impl Token for TokenEqEq {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEqEqPtr(pub SyntaxStablePtrId);
-impl TokenEqEqPtr {
+pub struct TokenMinusPtr(pub SyntaxStablePtrId);
+impl TokenMinusPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEqEqGreen(pub GreenId);
-impl TokenEqEqGreen {
+pub struct TokenMinusGreen(pub GreenId);
+impl TokenMinusGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenEqEq {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEqEq);
-    type StablePtr = TokenEqEqPtr;
-    type Green = TokenEqEqGreen;
+impl TypedSyntaxNode for TokenMinus {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMinus);
+    type StablePtr = TokenMinusPtr;
+    type Green = TokenMinusGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenEqEqGreen(db.intern_green(GreenNode {
+        TokenMinusGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13057,7 +17297,7 @@ impl TypedSyntaxNode for TokenEqEq {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEqEq)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMinus)
             }
         }
     }
@@ -13068,27 +17308,27 @@ impl TypedSyntaxNode for TokenEqEq {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenEqEqPtr(self.node.0.stable_ptr)
+        TokenMinusPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalEqEq {
+pub struct TerminalMinus {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalEqEq {
-    const KIND: SyntaxKind = SyntaxKind::TerminalEqEq;
-    type TokenType = TokenEqEq;
+impl Terminal for TerminalMinus {
+    const KIND: SyntaxKind = SyntaxKind::TerminalMinus;
+    type TokenType = TokenMinus;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalEqEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalMinus as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalEqEqGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEqEq,
+        TerminalMinusGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMinus,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13096,37 +17336,37 @@ impl Terminal for TerminalEqEq {
         self.token(db).text(db)
     }
 }
-impl TerminalEqEq {
+impl TerminalMinus {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEqEq {
-        TokenEqEq::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMinus {
+        TokenMinus::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEqEqPtr(pub SyntaxStablePtrId);
-impl TerminalEqEqPtr {
+pub struct TerminalMinusPtr(pub SyntaxStablePtrId);
+impl TerminalMinusPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEqEqGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalEqEq {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEqEq);
-    type StablePtr = TerminalEqEqPtr;
-    type Green = TerminalEqEqGreen;
+pub struct TerminalMinusGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalMinus {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMinus);
+    type StablePtr = TerminalMinusPtr;
+    type Green = TerminalMinusGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalEqEqGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEqEq,
+        TerminalMinusGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMinus,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenEqEq::missing(db).0,
+                    TokenMinus::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -13137,10 +17377,10 @@ impl TypedSyntaxNode for TerminalEqEq {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalEqEq,
+            SyntaxKind::TerminalMinus,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalEqEq
+            SyntaxKind::TerminalMinus
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13152,17 +17392,17 @@ impl TypedSyntaxNode for TerminalEqEq {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalEqEqPtr(self.node.0.stable_ptr)
+        TerminalMinusPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenNeq {
+pub struct TokenMul {
     node: SyntaxNode,
 }
-impl Token for TokenNeq {
+impl Token for TokenMul {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenNeqGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenNeq,
+        TokenMulGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMul,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13171,25 +17411,25 @@ impl Token for TokenNeq {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenNeqPtr(pub SyntaxStablePtrId);
-impl TokenNeqPtr {
+pub struct TokenMulPtr(pub SyntaxStablePtrId);
+impl TokenMulPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenNeqGreen(pub GreenId);
-impl TokenNeqGreen {
+pub struct TokenMulGreen(pub GreenId);
+impl TokenMulGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenNeq {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenNeq);
-    type StablePtr = TokenNeqPtr;
-    type Green = TokenNeqGreen;
+impl TypedSyntaxNode for TokenMul {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMul);
+    type StablePtr = TokenMulPtr;
+    type Green = TokenMulGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenNeqGreen(db.intern_green(GreenNode {
+        TokenMulGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13198,7 +17438,7 @@ impl TypedSyntaxNode for TokenNeq {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenNeq)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMul)
             }
         }
     }
@@ -13209,27 +17449,27 @@ impl TypedSyntaxNode for TokenNeq {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenNeqPtr(self.node.0.stable_ptr)
+        TokenMulPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalNeq {
+pub struct TerminalMul {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalNeq {
-    const KIND: SyntaxKind = SyntaxKind::TerminalNeq;
-    type TokenType = TokenNeq;
+impl Terminal for TerminalMul {
+    const KIND: SyntaxKind = SyntaxKind::TerminalMul;
+    type TokenType = TokenMul;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalNeq as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalMul as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalNeqGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalNeq,
+        TerminalMulGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMul,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13237,37 +17477,37 @@ impl Terminal for TerminalNeq {
         self.token(db).text(db)
     }
 }
-impl TerminalNeq {
+impl TerminalMul {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenNeq {
-        TokenNeq::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMul {
+        TokenMul::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalNeqPtr(pub SyntaxStablePtrId);
-impl TerminalNeqPtr {
+pub struct TerminalMulPtr(pub SyntaxStablePtrId);
+impl TerminalMulPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalNeqGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalNeq {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalNeq);
-    type StablePtr = TerminalNeqPtr;
-    type Green = TerminalNeqGreen;
+pub struct TerminalMulGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalMul {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMul);
+    type StablePtr = TerminalMulPtr;
+    type Green = TerminalMulGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalNeqGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalNeq,
+        TerminalMulGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMul,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenNeq::missing(db).0,
+                    TokenMul::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -13278,10 +17518,10 @@ impl TypedSyntaxNode for TerminalNeq {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalNeq,
+            SyntaxKind::TerminalMul,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalNeq
+            SyntaxKind::TerminalMul
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13293,17 +17533,17 @@ impl TypedSyntaxNode for TerminalNeq {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalNeqPtr(self.node.0.stable_ptr)
+        TerminalMulPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenGE {
+pub struct TokenDiv {
     node: SyntaxNode,
 }
-impl Token for TokenGE {
+impl Token for TokenDiv {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenGEGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenGE,
+        TokenDivGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenDiv,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13312,25 +17552,25 @@ impl Token for TokenGE {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenGEPtr(pub SyntaxStablePtrId);
-impl TokenGEPtr {
+pub struct TokenDivPtr(pub SyntaxStablePtrId);
+impl TokenDivPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenGEGreen(pub GreenId);
-impl TokenGEGreen {
+pub struct TokenDivGreen(pub GreenId);
+impl TokenDivGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenGE {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenGE);
-    type StablePtr = TokenGEPtr;
-    type Green = TokenGEGreen;
+impl TypedSyntaxNode for TokenDiv {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenDiv);
+    type StablePtr = TokenDivPtr;
+    type Green = TokenDivGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenGEGreen(db.intern_green(GreenNode {
+        TokenDivGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13339,7 +17579,7 @@ impl TypedSyntaxNode for TokenGE {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenGE)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenDiv)
             }
         }
     }
@@ -13350,27 +17590,27 @@ impl TypedSyntaxNode for TokenGE {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenGEPtr(self.node.0.stable_ptr)
+        TokenDivPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalGE {
+pub struct TerminalDiv {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalGE {
-    const KIND: SyntaxKind = SyntaxKind::TerminalGE;
-    type TokenType = TokenGE;
+impl Terminal for TerminalDiv {
+    const KIND: SyntaxKind = SyntaxKind::TerminalDiv;
+    type TokenType = TokenDiv;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalGE as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalDiv as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalGEGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalGE,
+        TerminalDivGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDiv,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13378,37 +17618,37 @@ impl Terminal for TerminalGE {
         self.token(db).text(db)
     }
 }
-impl TerminalGE {
+impl TerminalDiv {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenGE {
-        TokenGE::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenDiv {
+        TokenDiv::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalGEPtr(pub SyntaxStablePtrId);
-impl TerminalGEPtr {
+pub struct TerminalDivPtr(pub SyntaxStablePtrId);
+impl TerminalDivPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalGEGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalGE {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalGE);
-    type StablePtr = TerminalGEPtr;
-    type Green = TerminalGEGreen;
+pub struct TerminalDivGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalDiv {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalDiv);
+    type StablePtr = TerminalDivPtr;
+    type Green = TerminalDivGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalGEGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalGE,
+        TerminalDivGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDiv,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenGE::missing(db).0,
+                    TokenDiv::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -13419,10 +17659,10 @@ impl TypedSyntaxNode for TerminalGE {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalGE,
+            SyntaxKind::TerminalDiv,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalGE
+            SyntaxKind::TerminalDiv
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13434,17 +17674,17 @@ impl TypedSyntaxNode for TerminalGE {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalGEPtr(self.node.0.stable_ptr)
+        TerminalDivPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenGT {
+pub struct TokenMod {
     node: SyntaxNode,
 }
-impl Token for TokenGT {
+impl Token for TokenMod {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenGTGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenGT,
+        TokenModGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMod,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13453,25 +17693,25 @@ impl Token for TokenGT {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenGTPtr(pub SyntaxStablePtrId);
-impl TokenGTPtr {
+pub struct TokenModPtr(pub SyntaxStablePtrId);
+impl TokenModPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenGTGreen(pub GreenId);
-impl TokenGTGreen {
+pub struct TokenModGreen(pub GreenId);
+impl TokenModGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenGT {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenGT);
-    type StablePtr = TokenGTPtr;
-    type Green = TokenGTGreen;
+impl TypedSyntaxNode for TokenMod {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMod);
+    type StablePtr = TokenModPtr;
+    type Green = TokenModGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenGTGreen(db.intern_green(GreenNode {
+        TokenModGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13480,7 +17720,7 @@ impl TypedSyntaxNode for TokenGT {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenGT)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMod)
             }
         }
     }
@@ -13491,27 +17731,27 @@ impl TypedSyntaxNode for TokenGT {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenGTPtr(self.node.0.stable_ptr)
+        TokenModPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalGT {
+pub struct TerminalMod {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalGT {
-    const KIND: SyntaxKind = SyntaxKind::TerminalGT;
-    type TokenType = TokenGT;
+impl Terminal for TerminalMod {
+    const KIND: SyntaxKind = SyntaxKind::TerminalMod;
+    type TokenType = TokenMod;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalGT as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalMod as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalGTGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalGT,
+        TerminalModGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMod,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13519,37 +17759,37 @@ impl Terminal for TerminalGT {
         self.token(db).text(db)
     }
 }
-impl TerminalGT {
+impl TerminalMod {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenGT {
-        TokenGT::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMod {
+        TokenMod::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalGTPtr(pub SyntaxStablePtrId);
-impl TerminalGTPtr {
+pub struct TerminalModPtr(pub SyntaxStablePtrId);
+impl TerminalModPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalGTGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalGT {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalGT);
-    type StablePtr = TerminalGTPtr;
-    type Green = TerminalGTGreen;
+pub struct TerminalModGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalMod {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMod);
+    type StablePtr = TerminalModPtr;
+    type Green = TerminalModGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalGTGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalGT,
+        TerminalModGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMod,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenGT::missing(db).0,
+                    TokenMod::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -13560,10 +17800,10 @@ impl TypedSyntaxNode for TerminalGT {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalGT,
+            SyntaxKind::TerminalMod,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalGT
+            SyntaxKind::TerminalMod
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13575,17 +17815,17 @@ impl TypedSyntaxNode for TerminalGT {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalGTPtr(self.node.0.stable_ptr)
+        TerminalModPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenLE {
+pub struct TokenColon {
     node: SyntaxNode,
 }
-impl Token for TokenLE {
+impl Token for TokenColon {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenLEGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenLE,
+        TokenColonGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenColon,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13594,25 +17834,25 @@ impl Token for TokenLE {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLEPtr(pub SyntaxStablePtrId);
-impl TokenLEPtr {
+pub struct TokenColonPtr(pub SyntaxStablePtrId);
+impl TokenColonPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLEGreen(pub GreenId);
-impl TokenLEGreen {
+pub struct TokenColonGreen(pub GreenId);
+impl TokenColonGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenLE {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLE);
-    type StablePtr = TokenLEPtr;
-    type Green = TokenLEGreen;
+impl TypedSyntaxNode for TokenColon {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenColon);
+    type StablePtr = TokenColonPtr;
+    type Green = TokenColonGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenLEGreen(db.intern_green(GreenNode {
+        TokenColonGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13621,7 +17861,7 @@ impl TypedSyntaxNode for TokenLE {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLE)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenColon)
             }
         }
     }
@@ -13632,27 +17872,27 @@ impl TypedSyntaxNode for TokenLE {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenLEPtr(self.node.0.stable_ptr)
+        TokenColonPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalLE {
+pub struct TerminalColon {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalLE {
-    const KIND: SyntaxKind = SyntaxKind::TerminalLE;
-    type TokenType = TokenLE;
+impl Terminal for TerminalColon {
+    const KIND: SyntaxKind = SyntaxKind::TerminalColon;
+    type TokenType = TokenColon;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalLE as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalColon as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalLEGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLE,
+        TerminalColonGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalColon,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13660,37 +17900,37 @@ impl Terminal for TerminalLE {
         self.token(db).text(db)
     }
 }
-impl TerminalLE {
+impl TerminalColon {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLE {
-        TokenLE::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenColon {
+        TokenColon::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLEPtr(pub SyntaxStablePtrId);
-impl TerminalLEPtr {
+pub struct TerminalColonPtr(pub SyntaxStablePtrId);
+impl TerminalColonPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLEGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalLE {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLE);
-    type StablePtr = TerminalLEPtr;
-    type Green = TerminalLEGreen;
+pub struct TerminalColonGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalColon {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalColon);
+    type StablePtr = TerminalColonPtr;
+    type Green = TerminalColonGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalLEGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLE,
+        TerminalColonGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalColon,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenLE::missing(db).0,
+                    TokenColon::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -13701,10 +17941,10 @@ impl TypedSyntaxNode for TerminalLE {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalLE,
+            SyntaxKind::TerminalColon,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalLE
+            SyntaxKind::TerminalColon
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13716,17 +17956,17 @@ impl TypedSyntaxNode for TerminalLE {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalLEPtr(self.node.0.stable_ptr)
+        TerminalColonPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenLT {
+pub struct TokenColonColon {
     node: SyntaxNode,
 }
-impl Token for TokenLT {
+impl Token for TokenColonColon {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenLTGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenLT,
+        TokenColonColonGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenColonColon,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13735,25 +17975,25 @@ impl Token for TokenLT {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLTPtr(pub SyntaxStablePtrId);
-impl TokenLTPtr {
+pub struct TokenColonColonPtr(pub SyntaxStablePtrId);
+impl TokenColonColonPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLTGreen(pub GreenId);
-impl TokenLTGreen {
+pub struct TokenColonColonGreen(pub GreenId);
+impl TokenColonColonGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenLT {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLT);
-    type StablePtr = TokenLTPtr;
-    type Green = TokenLTGreen;
+impl TypedSyntaxNode for TokenColonColon {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenColonColon);
+    type StablePtr = TokenColonColonPtr;
+    type Green = TokenColonColonGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenLTGreen(db.intern_green(GreenNode {
+        TokenColonColonGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13762,7 +18002,7 @@ impl TypedSyntaxNode for TokenLT {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLT)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenColonColon)
             }
         }
     }
@@ -13773,27 +18013,27 @@ impl TypedSyntaxNode for TokenLT {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenLTPtr(self.node.0.stable_ptr)
+        TokenColonColonPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalLT {
+pub struct TerminalColonColon {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalLT {
-    const KIND: SyntaxKind = SyntaxKind::TerminalLT;
-    type TokenType = TokenLT;
+impl Terminal for TerminalColonColon {
+    const KIND: SyntaxKind = SyntaxKind::TerminalColonColon;
+    type TokenType = TokenColonColon;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalLT as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalColonColon as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalLTGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLT,
+        TerminalColonColonGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalColonColon,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13801,37 +18041,37 @@ impl Terminal for TerminalLT {
         self.token(db).text(db)
     }
 }
-impl TerminalLT {
+impl TerminalColonColon {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLT {
-        TokenLT::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenColonColon {
+        TokenColonColon::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLTPtr(pub SyntaxStablePtrId);
-impl TerminalLTPtr {
+pub struct TerminalColonColonPtr(pub SyntaxStablePtrId);
+impl TerminalColonColonPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLTGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalLT {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLT);
-    type StablePtr = TerminalLTPtr;
-    type Green = TerminalLTGreen;
+pub struct TerminalColonColonGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalColonColon {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalColonColon);
+    type StablePtr = TerminalColonColonPtr;
+    type Green = TerminalColonColonGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalLTGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLT,
+        TerminalColonColonGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalColonColon,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenLT::missing(db).0,
+                    TokenColonColon::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -13842,10 +18082,10 @@ impl TypedSyntaxNode for TerminalLT {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalLT,
+            SyntaxKind::TerminalColonColon,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalLT
+            SyntaxKind::TerminalColonColon
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13857,17 +18097,17 @@ impl TypedSyntaxNode for TerminalLT {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalLTPtr(self.node.0.stable_ptr)
+        TerminalColonColonPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenNot {
+pub struct TokenComma {
     node: SyntaxNode,
 }
-impl Token for TokenNot {
+impl Token for TokenComma {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenNotGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenNot,
+        TokenCommaGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenComma,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13876,25 +18116,25 @@ impl Token for TokenNot {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenNotPtr(pub SyntaxStablePtrId);
-impl TokenNotPtr {
+pub struct TokenCommaPtr(pub SyntaxStablePtrId);
+impl TokenCommaPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenNotGreen(pub GreenId);
-impl TokenNotGreen {
+pub struct TokenCommaGreen(pub GreenId);
+impl TokenCommaGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenNot {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenNot);
-    type StablePtr = TokenNotPtr;
-    type Green = TokenNotGreen;
+impl TypedSyntaxNode for TokenComma {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenComma);
+    type StablePtr = TokenCommaPtr;
+    type Green = TokenCommaGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenNotGreen(db.intern_green(GreenNode {
+        TokenCommaGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13903,7 +18143,7 @@ impl TypedSyntaxNode for TokenNot {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenNot)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenComma)
             }
         }
     }
@@ -13914,27 +18154,27 @@ impl TypedSyntaxNode for TokenNot {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenNotPtr(self.node.0.stable_ptr)
+        TokenCommaPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalNot {
+pub struct TerminalComma {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalNot {
-    const KIND: SyntaxKind = SyntaxKind::TerminalNot;
-    type TokenType = TokenNot;
+impl Terminal for TerminalComma {
+    const KIND: SyntaxKind = SyntaxKind::TerminalComma;
+    type TokenType = TokenComma;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalNot as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalComma as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalNotGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalNot,
+        TerminalCommaGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalComma,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13942,37 +18182,37 @@ impl Terminal for TerminalNot {
         self.token(db).text(db)
     }
 }
-impl TerminalNot {
+impl TerminalComma {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenNot {
-        TokenNot::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenComma {
+        TokenComma::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalNotPtr(pub SyntaxStablePtrId);
-impl TerminalNotPtr {
+pub struct TerminalCommaPtr(pub SyntaxStablePtrId);
+impl TerminalCommaPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalNotGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalNot {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalNot);
-    type StablePtr = TerminalNotPtr;
-    type Green = TerminalNotGreen;
+pub struct TerminalCommaGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalComma {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalComma);
+    type StablePtr = TerminalCommaPtr;
+    type Green = TerminalCommaGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalNotGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalNot,
+        TerminalCommaGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalComma,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenNot::missing(db).0,
+                    TokenComma::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -13983,10 +18223,10 @@ impl TypedSyntaxNode for TerminalNot {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalNot,
+            SyntaxKind::TerminalComma,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalNot
+            SyntaxKind::TerminalComma
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13998,17 +18238,17 @@ impl TypedSyntaxNode for TerminalNot {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalNotPtr(self.node.0.stable_ptr)
+        TerminalCommaPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenPlus {
+pub struct TokenDot {
     node: SyntaxNode,
 }
-impl Token for TokenPlus {
+impl Token for TokenDot {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenPlusGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenPlus,
+        TokenDotGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenDot,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -14017,25 +18257,25 @@ impl Token for TokenPlus {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenPlusPtr(pub SyntaxStablePtrId);
-impl TokenPlusPtr {
+pub struct TokenDotPtr(pub SyntaxStablePtrId);
+impl TokenDotPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenPlusGreen(pub GreenId);
-impl TokenPlusGreen {
+pub struct TokenDotGreen(pub GreenId);
+impl TokenDotGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenPlus {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenPlus);
-    type StablePtr = TokenPlusPtr;
-    type Green = TokenPlusGreen;
+impl TypedSyntaxNode for TokenDot {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenDot);
+    type StablePtr = TokenDotPtr;
+    type Green = TokenDotGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenPlusGreen(db.intern_green(GreenNode {
+        TokenDotGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -14044,7 +18284,7 @@ impl TypedSyntaxNode for TokenPlus {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenPlus)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenDot)
             }
         }
     }
@@ -14055,27 +18295,27 @@ impl TypedSyntaxNode for TokenPlus {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenPlusPtr(self.node.0.stable_ptr)
+        TokenDotPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalPlus {
+pub struct TerminalDot {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalPlus {
-    const KIND: SyntaxKind = SyntaxKind::TerminalPlus;
-    type TokenType = TokenPlus;
+impl Terminal for TerminalDot {
+    const KIND: SyntaxKind = SyntaxKind::TerminalDot;
+    type TokenType = TokenDot;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalPlus as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalDot as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalPlusGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalPlus,
+        TerminalDotGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDot,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -14083,37 +18323,37 @@ impl Terminal for TerminalPlus {
         self.token(db).text(db)
     }
 }
-impl TerminalPlus {
+impl TerminalDot {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenPlus {
-        TokenPlus::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenDot {
+        TokenDot::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalPlusPtr(pub SyntaxStablePtrId);
-impl TerminalPlusPtr {
+pub struct TerminalDotPtr(pub SyntaxStablePtrId);
+impl TerminalDotPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalPlusGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalPlus {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalPlus);
-    type StablePtr = TerminalPlusPtr;
-    type Green = TerminalPlusGreen;
+pub struct TerminalDotGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalDot {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalDot);
+    type StablePtr = TerminalDotPtr;
+    type Green = TerminalDotGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalPlusGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalPlus,
+        TerminalDotGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDot,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenPlus::missing(db).0,
+                    TokenDot::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -14124,10 +18364,10 @@ impl TypedSyntaxNode for TerminalPlus {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalPlus,
+            SyntaxKind::TerminalDot,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalPlus
+            SyntaxKind::TerminalDot
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -14139,17 +18379,17 @@ impl TypedSyntaxNode for TerminalPlus {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalPlusPtr(self.node.0.stable_ptr)
+        TerminalDotPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenMinus {
+pub struct TokenDotDot {
     node: SyntaxNode,
 }
-impl Token for TokenMinus {
+impl Token for TokenDotDot {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenMinusGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenMinus,
+        TokenDotDotGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenDotDot,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -14158,25 +18398,25 @@ impl Token for TokenMinus {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMinusPtr(pub SyntaxStablePtrId);
-impl TokenMinusPtr {
+pub struct TokenDotDotPtr(pub SyntaxStablePtrId);
+impl TokenDotDotPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMinusGreen(pub GreenId);
-impl TokenMinusGreen {
+pub struct TokenDotDotGreen(pub GreenId);
+impl TokenDotDotGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenMinus {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMinus);
-    type StablePtr = TokenMinusPtr;
-    type Green = TokenMinusGreen;
+impl TypedSyntaxNode for TokenDotDot {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenDotDot);
+    type StablePtr = TokenDotDotPtr;
+    type Green = TokenDotDotGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenMinusGreen(db.intern_green(GreenNode {
+        TokenDotDotGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -14185,7 +18425,7 @@ impl TypedSyntaxNode for TokenMinus {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMinus)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenDotDot)
             }
         }
     }
@@ -14196,27 +18436,27 @@ impl TypedSyntaxNode for TokenMinus {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenMinusPtr(self.node.0.stable_ptr)
+        TokenDotDotPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalMinus {
+pub struct TerminalDotDot {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalMinus {
-    const KIND: SyntaxKind = SyntaxKind::TerminalMinus;
-    type TokenType = TokenMinus;
+impl Terminal for TerminalDotDot {
+    const KIND: SyntaxKind = SyntaxKind::TerminalDotDot;
+    type TokenType = TokenDotDot;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalMinus as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalDotDot as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalMinusGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMinus,
+        TerminalDotDotGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDotDot,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -14224,37 +18464,37 @@ impl Terminal for TerminalMinus {
         self.token(db).text(db)
     }
 }
-impl TerminalMinus {
+impl TerminalDotDot {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMinus {
-        TokenMinus::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenDotDot {
+        TokenDotDot::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMinusPtr(pub SyntaxStablePtrId);
-impl TerminalMinusPtr {
+pub struct TerminalDotDotPtr(pub SyntaxStablePtrId);
+impl TerminalDotDotPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMinusGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalMinus {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMinus);
-    type StablePtr = TerminalMinusPtr;
-    type Green = TerminalMinusGreen;
+pub struct TerminalDotDotGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalDotDot {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalDotDot);
+    type StablePtr = TerminalDotDotPtr;
+    type Green = TerminalDotDotGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalMinusGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMinus,
+        TerminalDotDotGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDotDot,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenMinus::missing(db).0,
+                    TokenDotDot::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -14265,10 +18505,10 @@ impl TypedSyntaxNode for TerminalMinus {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalMinus,
+            SyntaxKind::TerminalDotDot,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalMinus
+            SyntaxKind::TerminalDotDot
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -14280,17 +18520,17 @@ impl TypedSyntaxNode for TerminalMinus {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalMinusPtr(self.node.0.stable_ptr)
+        TerminalDotDotPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenMul {
+pub struct TokenEq {
     node: SyntaxNode,
 }
-impl Token for TokenMul {
+impl Token for TokenEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenMulGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenMul,
+        TokenEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -14299,25 +18539,25 @@ impl Token for TokenMul {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMulPtr(pub SyntaxStablePtrId);
-impl TokenMulPtr {
+pub struct TokenEqPtr(pub SyntaxStablePtrId);
+impl TokenEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenMulGreen(pub GreenId);
-impl TokenMulGreen {
+pub struct TokenEqGreen(pub GreenId);
+impl TokenEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenMul {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMul);
-    type StablePtr = TokenMulPtr;
-    type Green = TokenMulGreen;
+impl TypedSyntaxNode for TokenEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEq);
+    type StablePtr = TokenEqPtr;
+    type Green = TokenEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenMulGreen(db.intern_green(GreenNode {
+        TokenEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -14326,7 +18566,7 @@ impl TypedSyntaxNode for TokenMul {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMul)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEq)
             }
         }
     }
@@ -14337,27 +18577,27 @@ impl TypedSyntaxNode for TokenMul {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenMulPtr(self.node.0.stable_ptr)
+        TokenEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalMul {
+pub struct TerminalEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalMul {
-    const KIND: SyntaxKind = SyntaxKind::TerminalMul;
-    type TokenType = TokenMul;
+impl Terminal for TerminalEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalEq;
+    type TokenType = TokenEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalMul as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalMulGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMul,
+        TerminalEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -14365,37 +18605,37 @@ impl Terminal for TerminalMul {
         self.token(db).text(db)
     }
 }
-impl TerminalMul {
+impl TerminalEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMul {
-        TokenMul::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEq {
+        TokenEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMulPtr(pub SyntaxStablePtrId);
-impl TerminalMulPtr {
+pub struct TerminalEqPtr(pub SyntaxStablePtrId);
+impl TerminalEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalMulGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalMul {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMul);
-    type StablePtr = TerminalMulPtr;
-    type Green = TerminalMulGreen;
+pub struct TerminalEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEq);
+    type StablePtr = TerminalEqPtr;
+    type Green = TerminalEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalMulGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMul,
+        TerminalEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenMul::missing(db).0,
+                    TokenEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -14406,10 +18646,10 @@ impl TypedSyntaxNode for TerminalMul {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalMul,
+            SyntaxKind::TerminalEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalMul
+            SyntaxKind::TerminalEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -14421,17 +18661,17 @@ impl TypedSyntaxNode for TerminalMul {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalMulPtr(self.node.0.stable_ptr)
+        TerminalEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenDiv {
+pub struct TokenPlusEq {
     node: SyntaxNode,
 }
-impl Token for TokenDiv {
+impl Token for TokenPlusEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenDivGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenDiv,
+        TokenPlusEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenPlusEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -14440,25 +18680,25 @@ impl Token for TokenDiv {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenDivPtr(pub SyntaxStablePtrId);
-impl TokenDivPtr {
+pub struct TokenPlusEqPtr(pub SyntaxStablePtrId);
+impl TokenPlusEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenDivGreen(pub GreenId);
-impl TokenDivGreen {
+pub struct TokenPlusEqGreen(pub GreenId);
+impl TokenPlusEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenDiv {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenDiv);
-    type StablePtr = TokenDivPtr;
-    type Green = TokenDivGreen;
+impl TypedSyntaxNode for TokenPlusEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenPlusEq);
+    type StablePtr = TokenPlusEqPtr;
+    type Green = TokenPlusEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenDivGreen(db.intern_green(GreenNode {
+        TokenPlusEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -14467,7 +18707,7 @@ impl TypedSyntaxNode for TokenDiv {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenDiv)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenPlusEq)
             }
         }
     }
@@ -14478,27 +18718,27 @@ impl TypedSyntaxNode for TokenDiv {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenDivPtr(self.node.0.stable_ptr)
+        TokenPlusEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalDiv {
+pub struct TerminalPlusEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalDiv {
-    const KIND: SyntaxKind = SyntaxKind::TerminalDiv;
-    type TokenType = TokenDiv;
+impl Terminal for TerminalPlusEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalPlusEq;
+    type TokenType = TokenPlusEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalDiv as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalPlusEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalDivGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalDiv,
+        TerminalPlusEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalPlusEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -14506,37 +18746,37 @@ impl Terminal for TerminalDiv {
         self.token(db).text(db)
     }
 }
-impl TerminalDiv {
+impl TerminalPlusEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenDiv {
-        TokenDiv::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenPlusEq {
+        TokenPlusEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalDivPtr(pub SyntaxStablePtrId);
-impl TerminalDivPtr {
+pub struct TerminalPlusEqPtr(pub SyntaxStablePtrId);
+impl TerminalPlusEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalDivGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalDiv {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalDiv);
-    type StablePtr = TerminalDivPtr;
-    type Green = TerminalDivGreen;
+pub struct TerminalPlusEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalPlusEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalPlusEq);
+    type StablePtr = TerminalPlusEqPtr;
+    type Green = TerminalPlusEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalDivGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalDiv,
+        TerminalPlusEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalPlusEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenDiv::missing(db).0,
+                    TokenPlusEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -14547,10 +18787,10 @@ impl TypedSyntaxNode for TerminalDiv {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalDiv,
+            SyntaxKind::TerminalPlusEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalDiv
+            SyntaxKind::TerminalPlusEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -14562,17 +18802,17 @@ impl TypedSyntaxNode for TerminalDiv {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalDivPtr(self.node.0.stable_ptr)
+        TerminalPlusEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenMod {
+pub struct TokenMinusEq {
     node: SyntaxNode,
 }
-impl Token for TokenMod {
+impl Token for TokenMinusEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenModGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenMod,
+        TokenMinusEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMinusEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -14581,25 +18821,25 @@ impl Token for TokenMod {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenModPtr(pub SyntaxStablePtrId);
-impl TokenModPtr {
+pub struct TokenMinusEqPtr(pub SyntaxStablePtrId);
+impl TokenMinusEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenModGreen(pub GreenId);
-impl TokenModGreen {
+pub struct TokenMinusEqGreen(pub GreenId);
+impl TokenMinusEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
-    }
-}
-impl TypedSyntaxNode for TokenMod {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMod);
-    type StablePtr = TokenModPtr;
-    type Green = TokenModGreen;
+    }
+}
+impl TypedSyntaxNode for TokenMinusEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMinusEq);
+    type StablePtr = TokenMinusEqPtr;
+    type Green = TokenMinusEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenModGreen(db.intern_green(GreenNode {
+        TokenMinusEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -14608,7 +18848,7 @@ impl TypedSyntaxNode for TokenMod {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMod)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMinusEq)
             }
         }
     }
@@ -14619,27 +18859,27 @@ impl TypedSyntaxNode for TokenMod {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenModPtr(self.node.0.stable_ptr)
+        TokenMinusEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalMod {
+pub struct TerminalMinusEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalMod {
-    const KIND: SyntaxKind = SyntaxKind::TerminalMod;
-    type TokenType = TokenMod;
+impl Terminal for TerminalMinusEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalMinusEq;
+    type TokenType = TokenMinusEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalMod as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalMinusEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalModGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMod,
+        TerminalMinusEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMinusEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -14647,37 +18887,37 @@ impl Terminal for TerminalMod {
         self.token(db).text(db)
     }
 }
-impl TerminalMod {
+impl TerminalMinusEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMod {
-        TokenMod::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMinusEq {
+        TokenMinusEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalModPtr(pub SyntaxStablePtrId);
-impl TerminalModPtr {
+pub struct TerminalMinusEqPtr(pub SyntaxStablePtrId);
+impl TerminalMinusEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalModGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalMod {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMod);
-    type StablePtr = TerminalModPtr;
-    type Green = TerminalModGreen;
+pub struct TerminalMinusEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalMinusEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMinusEq);
+    type StablePtr = TerminalMinusEqPtr;
+    type Green = TerminalMinusEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalModGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalMod,
+        TerminalMinusEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMinusEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenMod::missing(db).0,
+                    TokenMinusEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -14688,10 +18928,10 @@ impl TypedSyntaxNode for TerminalMod {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalMod,
+            SyntaxKind::TerminalMinusEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalMod
+            SyntaxKind::TerminalMinusEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -14703,17 +18943,17 @@ impl TypedSyntaxNode for TerminalMod {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalModPtr(self.node.0.stable_ptr)
+        TerminalMinusEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenColon {
+pub struct TokenMulEq {
     node: SyntaxNode,
 }
-impl Token for TokenColon {
+impl Token for TokenMulEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenColonGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenColon,
+        TokenMulEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMulEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -14722,25 +18962,25 @@ impl Token for TokenColon {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenColonPtr(pub SyntaxStablePtrId);
-impl TokenColonPtr {
+pub struct TokenMulEqPtr(pub SyntaxStablePtrId);
+impl TokenMulEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenColonGreen(pub GreenId);
-impl TokenColonGreen {
+pub struct TokenMulEqGreen(pub GreenId);
+impl TokenMulEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenColon {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenColon);
-    type StablePtr = TokenColonPtr;
-    type Green = TokenColonGreen;
+impl TypedSyntaxNode for TokenMulEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenMulEq);
+    type StablePtr = TokenMulEqPtr;
+    type Green = TokenMulEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenColonGreen(db.intern_green(GreenNode {
+        TokenMulEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -14749,7 +18989,7 @@ impl TypedSyntaxNode for TokenColon {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenColon)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenMulEq)
             }
         }
     }
@@ -14760,27 +19000,27 @@ impl TypedSyntaxNode for TokenColon {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenColonPtr(self.node.0.stable_ptr)
+        TokenMulEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalColon {
+pub struct TerminalMulEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalColon {
-    const KIND: SyntaxKind = SyntaxKind::TerminalColon;
-    type TokenType = TokenColon;
+impl Terminal for TerminalMulEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalMulEq;
+    type TokenType = TokenMulEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalColon as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalMulEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalColonGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalColon,
+        TerminalMulEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMulEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -14788,37 +19028,37 @@ impl Terminal for TerminalColon {
         self.token(db).text(db)
     }
 }
-impl TerminalColon {
+impl TerminalMulEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenColon {
-        TokenColon::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenMulEq {
+        TokenMulEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalColonPtr(pub SyntaxStablePtrId);
-impl TerminalColonPtr {
+pub struct TerminalMulEqPtr(pub SyntaxStablePtrId);
+impl TerminalMulEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalColonGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalColon {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalColon);
-    type StablePtr = TerminalColonPtr;
-    type Green = TerminalColonGreen;
+pub struct TerminalMulEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalMulEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalMulEq);
+    type StablePtr = TerminalMulEqPtr;
+    type Green = TerminalMulEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalColonGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalColon,
+        TerminalMulEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalMulEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenColon::missing(db).0,
+                    TokenMulEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -14829,10 +19069,10 @@ impl TypedSyntaxNode for TerminalColon {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalColon,
+            SyntaxKind::TerminalMulEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalColon
+            SyntaxKind::TerminalMulEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -14844,17 +19084,17 @@ impl TypedSyntaxNode for TerminalColon {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalColonPtr(self.node.0.stable_ptr)
+        TerminalMulEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenColonColon {
+pub struct TokenDivEq {
     node: SyntaxNode,
 }
-impl Token for TokenColonColon {
+impl Token for TokenDivEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenColonColonGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenColonColon,
+        TokenDivEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenDivEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -14863,25 +19103,25 @@ impl Token for TokenColonColon {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenColonColonPtr(pub SyntaxStablePtrId);
-impl TokenColonColonPtr {
+pub struct TokenDivEqPtr(pub SyntaxStablePtrId);
+impl TokenDivEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenColonColonGreen(pub GreenId);
-impl TokenColonColonGreen {
+pub struct TokenDivEqGreen(pub GreenId);
+impl TokenDivEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenColonColon {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenColonColon);
-    type StablePtr = TokenColonColonPtr;
-    type Green = TokenColonColonGreen;
+impl TypedSyntaxNode for TokenDivEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenDivEq);
+    type StablePtr = TokenDivEqPtr;
+    type Green = TokenDivEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenColonColonGreen(db.intern_green(GreenNode {
+        TokenDivEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -14890,7 +19130,7 @@ impl TypedSyntaxNode for TokenColonColon {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenColonColon)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenDivEq)
             }
         }
     }
@@ -14901,27 +19141,27 @@ impl TypedSyntaxNode for TokenColonColon {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenColonColonPtr(self.node.0.stable_ptr)
+        TokenDivEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalColonColon {
+pub struct TerminalDivEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalColonColon {
-    const KIND: SyntaxKind = SyntaxKind::TerminalColonColon;
-    type TokenType = TokenColonColon;
+impl Terminal for TerminalDivEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalDivEq;
+    type TokenType = TokenDivEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalColonColon as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalDivEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalColonColonGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalColonColon,
+        TerminalDivEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDivEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -14929,37 +19169,37 @@ impl Terminal for TerminalColonColon {
         self.token(db).text(db)
     }
 }
-impl TerminalColonColon {
+impl TerminalDivEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenColonColon {
-        TokenColonColon::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenDivEq {
+        TokenDivEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalColonColonPtr(pub SyntaxStablePtrId);
-impl TerminalColonColonPtr {
+pub struct TerminalDivEqPtr(pub SyntaxStablePtrId);
+impl TerminalDivEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalColonColonGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalColonColon {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalColonColon);
-    type StablePtr = TerminalColonColonPtr;
-    type Green = TerminalColonColonGreen;
+pub struct TerminalDivEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalDivEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalDivEq);
+    type StablePtr = TerminalDivEqPtr;
+    type Green = TerminalDivEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalColonColonGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalColonColon,
+        TerminalDivEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalDivEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenColonColon::missing(db).0,
+                    TokenDivEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -14970,10 +19210,10 @@ impl TypedSyntaxNode for TerminalColonColon {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalColonColon,
+            SyntaxKind::TerminalDivEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalColonColon
+            SyntaxKind::TerminalDivEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -14985,17 +19225,17 @@ impl TypedSyntaxNode for TerminalColonColon {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalColonColonPtr(self.node.0.stable_ptr)
+        TerminalDivEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenComma {
+pub struct TokenModEq {
     node: SyntaxNode,
 }
-impl Token for TokenComma {
+impl Token for TokenModEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenCommaGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenComma,
+        TokenModEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenModEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -15004,25 +19244,25 @@ impl Token for TokenComma {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenCommaPtr(pub SyntaxStablePtrId);
-impl TokenCommaPtr {
+pub struct TokenModEqPtr(pub SyntaxStablePtrId);
+impl TokenModEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenCommaGreen(pub GreenId);
-impl TokenCommaGreen {
+pub struct TokenModEqGreen(pub GreenId);
+impl TokenModEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenComma {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenComma);
-    type StablePtr = TokenCommaPtr;
-    type Green = TokenCommaGreen;
+impl TypedSyntaxNode for TokenModEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenModEq);
+    type StablePtr = TokenModEqPtr;
+    type Green = TokenModEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenCommaGreen(db.intern_green(GreenNode {
+        TokenModEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -15031,7 +19271,7 @@ impl TypedSyntaxNode for TokenComma {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenComma)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenModEq)
             }
         }
     }
@@ -15042,27 +19282,27 @@ impl TypedSyntaxNode for TokenComma {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenCommaPtr(self.node.0.stable_ptr)
+        TokenModEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalComma {
+pub struct TerminalModEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalComma {
-    const KIND: SyntaxKind = SyntaxKind::TerminalComma;
-    type TokenType = TokenComma;
+impl Terminal for TerminalModEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalModEq;
+    type TokenType = TokenModEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalComma as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalModEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalCommaGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalComma,
+        TerminalModEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalModEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -15070,37 +19310,37 @@ impl Terminal for TerminalComma {
         self.token(db).text(db)
     }
 }
-impl TerminalComma {
+impl TerminalModEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenComma {
-        TokenComma::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenModEq {
+        TokenModEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalCommaPtr(pub SyntaxStablePtrId);
-impl TerminalCommaPtr {
+pub struct TerminalModEqPtr(pub SyntaxStablePtrId);
+impl TerminalModEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalCommaGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalComma {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalComma);
-    type StablePtr = TerminalCommaPtr;
-    type Green = TerminalCommaGreen;
+pub struct TerminalModEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalModEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalModEq);
+    type StablePtr = TerminalModEqPtr;
+    type Green = TerminalModEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalCommaGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalComma,
+        TerminalModEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalModEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenComma::missing(db).0,
+                    TokenModEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -15111,10 +19351,10 @@ impl TypedSyntaxNode for TerminalComma {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalComma,
+            SyntaxKind::TerminalModEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalComma
+            SyntaxKind::TerminalModEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -15126,17 +19366,17 @@ impl TypedSyntaxNode for TerminalComma {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalCommaPtr(self.node.0.stable_ptr)
+        TerminalModEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenDot {
+pub struct TokenAndEq {
     node: SyntaxNode,
 }
-impl Token for TokenDot {
+impl Token for TokenAndEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenDotGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenDot,
+        TokenAndEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenAndEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -15145,25 +19385,25 @@ impl Token for TokenDot {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenDotPtr(pub SyntaxStablePtrId);
-impl TokenDotPtr {
+pub struct TokenAndEqPtr(pub SyntaxStablePtrId);
+impl TokenAndEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenDotGreen(pub GreenId);
-impl TokenDotGreen {
+pub struct TokenAndEqGreen(pub GreenId);
+impl TokenAndEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenDot {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenDot);
-    type StablePtr = TokenDotPtr;
-    type Green = TokenDotGreen;
+impl TypedSyntaxNode for TokenAndEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenAndEq);
+    type StablePtr = TokenAndEqPtr;
+    type Green = TokenAndEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenDotGreen(db.intern_green(GreenNode {
+        TokenAndEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -15172,7 +19412,7 @@ impl TypedSyntaxNode for TokenDot {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenDot)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenAndEq)
             }
         }
     }
@@ -15183,27 +19423,27 @@ impl TypedSyntaxNode for TokenDot {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenDotPtr(self.node.0.stable_ptr)
+        TokenAndEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalDot {
+pub struct TerminalAndEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalDot {
-    const KIND: SyntaxKind = SyntaxKind::TerminalDot;
-    type TokenType = TokenDot;
+impl Terminal for TerminalAndEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalAndEq;
+    type TokenType = TokenAndEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalDot as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalAndEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalDotGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalDot,
+        TerminalAndEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalAndEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -15211,37 +19451,37 @@ impl Terminal for TerminalDot {
         self.token(db).text(db)
     }
 }
-impl TerminalDot {
+impl TerminalAndEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenDot {
-        TokenDot::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenAndEq {
+        TokenAndEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalDotPtr(pub SyntaxStablePtrId);
-impl TerminalDotPtr {
+pub struct TerminalAndEqPtr(pub SyntaxStablePtrId);
+impl TerminalAndEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalDotGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalDot {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalDot);
-    type StablePtr = TerminalDotPtr;
-    type Green = TerminalDotGreen;
+pub struct TerminalAndEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalAndEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalAndEq);
+    type StablePtr = TerminalAndEqPtr;
+    type Green = TerminalAndEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalDotGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalDot,
+        TerminalAndEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalAndEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenDot::missing(db).0,
+                    TokenAndEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -15252,10 +19492,10 @@ impl TypedSyntaxNode for TerminalDot {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalDot,
+            SyntaxKind::TerminalAndEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalDot
+            SyntaxKind::TerminalAndEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -15267,17 +19507,17 @@ impl TypedSyntaxNode for TerminalDot {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalDotPtr(self.node.0.stable_ptr)
+        TerminalAndEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenDotDot {
+pub struct TokenOrEq {
     node: SyntaxNode,
 }
-impl Token for TokenDotDot {
+impl Token for TokenOrEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenDotDotGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenDotDot,
+        TokenOrEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenOrEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -15286,25 +19526,25 @@ impl Token for TokenDotDot {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenDotDotPtr(pub SyntaxStablePtrId);
-impl TokenDotDotPtr {
+pub struct TokenOrEqPtr(pub SyntaxStablePtrId);
+impl TokenOrEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenDotDotGreen(pub GreenId);
-impl TokenDotDotGreen {
+pub struct TokenOrEqGreen(pub GreenId);
+impl TokenOrEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenDotDot {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenDotDot);
-    type StablePtr = TokenDotDotPtr;
-    type Green = TokenDotDotGreen;
+impl TypedSyntaxNode for TokenOrEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenOrEq);
+    type StablePtr = TokenOrEqPtr;
+    type Green = TokenOrEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenDotDotGreen(db.intern_green(GreenNode {
+        TokenOrEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -15313,7 +19553,7 @@ impl TypedSyntaxNode for TokenDotDot {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenDotDot)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenOrEq)
             }
         }
     }
@@ -15324,27 +19564,27 @@ impl TypedSyntaxNode for TokenDotDot {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenDotDotPtr(self.node.0.stable_ptr)
+        TokenOrEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalDotDot {
+pub struct TerminalOrEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalDotDot {
-    const KIND: SyntaxKind = SyntaxKind::TerminalDotDot;
-    type TokenType = TokenDotDot;
+impl Terminal for TerminalOrEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalOrEq;
+    type TokenType = TokenOrEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalDotDot as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalOrEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalDotDotGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalDotDot,
+        TerminalOrEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOrEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -15352,37 +19592,37 @@ impl Terminal for TerminalDotDot {
         self.token(db).text(db)
     }
 }
-impl TerminalDotDot {
+impl TerminalOrEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenDotDot {
-        TokenDotDot::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenOrEq {
+        TokenOrEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalDotDotPtr(pub SyntaxStablePtrId);
-impl TerminalDotDotPtr {
+pub struct TerminalOrEqPtr(pub SyntaxStablePtrId);
+impl TerminalOrEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalDotDotGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalDotDot {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalDotDot);
-    type StablePtr = TerminalDotDotPtr;
-    type Green = TerminalDotDotGreen;
+pub struct TerminalOrEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalOrEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalOrEq);
+    type StablePtr = TerminalOrEqPtr;
+    type Green = TerminalOrEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalDotDotGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalDotDot,
+        TerminalOrEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalOrEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenDotDot::missing(db).0,
+                    TokenOrEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -15393,10 +19633,10 @@ impl TypedSyntaxNode for TerminalDotDot {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalDotDot,
+            SyntaxKind::TerminalOrEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalDotDot
+            SyntaxKind::TerminalOrEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -15408,17 +19648,17 @@ impl TypedSyntaxNode for TerminalDotDot {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalDotDotPtr(self.node.0.stable_ptr)
+        TerminalOrEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenEq {
+pub struct TokenXorEq {
     node: SyntaxNode,
 }
-impl Token for TokenEq {
+impl Token for TokenXorEq {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenEqGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenEq,
+        TokenXorEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenXorEq,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -15427,25 +19667,25 @@ impl Token for TokenEq {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEqPtr(pub SyntaxStablePtrId);
-impl TokenEqPtr {
+pub struct TokenXorEqPtr(pub SyntaxStablePtrId);
+impl TokenXorEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEqGreen(pub GreenId);
-impl TokenEqGreen {
+pub struct TokenXorEqGreen(pub GreenId);
+impl TokenXorEqGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenEq {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEq);
-    type StablePtr = TokenEqPtr;
-    type Green = TokenEqGreen;
+impl TypedSyntaxNode for TokenXorEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenXorEq);
+    type StablePtr = TokenXorEqPtr;
+    type Green = TokenXorEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenEqGreen(db.intern_green(GreenNode {
+        TokenXorEqGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -15454,7 +19694,7 @@ impl TypedSyntaxNode for TokenEq {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEq)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenXorEq)
             }
         }
     }
@@ -15465,27 +19705,27 @@ impl TypedSyntaxNode for TokenEq {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenEqPtr(self.node.0.stable_ptr)
+        TokenXorEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalEq {
+pub struct TerminalXorEq {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalEq {
-    const KIND: SyntaxKind = SyntaxKind::TerminalEq;
-    type TokenType = TokenEq;
+impl Terminal for TerminalXorEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalXorEq;
+    type TokenType = TokenXorEq;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalXorEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalEqGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEq,
+        TerminalXorEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalXorEq,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -15493,37 +19733,37 @@ impl Terminal for TerminalEq {
         self.token(db).text(db)
     }
 }
-impl TerminalEq {
+impl TerminalXorEq {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEq {
-        TokenEq::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenXorEq {
+        TokenXorEq::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEqPtr(pub SyntaxStablePtrId);
-impl TerminalEqPtr {
+pub struct TerminalXorEqPtr(pub SyntaxStablePtrId);
+impl TerminalXorEqPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEqGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalEq {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEq);
-    type StablePtr = TerminalEqPtr;
-    type Green = TerminalEqGreen;
+pub struct TerminalXorEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalXorEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalXorEq);
+    type StablePtr = TerminalXorEqPtr;
+    type Green = TerminalXorEqGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalEqGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEq,
+        TerminalXorEqGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalXorEq,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenEq::missing(db).0,
+                    TokenXorEq::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: 0,
@@ -15534,10 +19774,10 @@ impl TypedSyntaxNode for TerminalEq {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalEq,
+            SyntaxKind::TerminalXorEq,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalEq
+            SyntaxKind::TerminalXorEq
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -15549,7 +19789,7 @@ impl TypedSyntaxNode for TerminalEq {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalEqPtr(self.node.0.stable_ptr)
+        TerminalXorEqPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
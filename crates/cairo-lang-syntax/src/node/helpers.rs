@@ -1,10 +1,11 @@
 use smol_str::SmolStr;
 
 use super::ast::{
-    self, FunctionDeclaration, FunctionDeclarationGreen, Item, ItemEnum, ItemExternFunction,
-    ItemExternFunctionPtr, ItemExternType, ItemFreeFunction, ItemFreeFunctionPtr, ItemImpl,
-    ItemModule, ItemStruct, ItemTrait, ItemTypeAlias, ItemUse, Modifier, TerminalIdentifierGreen,
-    TokenIdentifierGreen, TraitItemFunctionPtr,
+    self, FunctionDeclaration, FunctionDeclarationGreen, Item, ItemConstant, ItemEnum,
+    ItemExternFunction, ItemExternFunctionPtr, ItemExternType, ItemFreeFunction,
+    ItemFreeFunctionPtr, ItemImpl, ItemModule, ItemStruct, ItemTrait, ItemTypeAlias, ItemUse,
+    Modifier, TerminalIdentifierGreen, TokenIdentifierGreen, TraitItemFunction,
+    TraitItemFunctionPtr,
 };
 use super::db::SyntaxGroup;
 use super::kind::SyntaxKind;
@@ -196,6 +197,17 @@ impl QueryAttrs for ItemTrait {
         }
     }
 }
+impl QueryAttrs for TraitItemFunction {
+    fn has_attr(&self, db: &dyn SyntaxGroup, attr: &str) -> bool {
+        self.attributes(db).elements(db).iter().any(|a| a.attr(db).text(db) == attr)
+    }
+    fn last_attr(&self, db: &dyn SyntaxGroup, attr: &str) -> bool {
+        match self.attributes(db).elements(db).last() {
+            None => false,
+            Some(last_attr) => last_attr.attr(db).text(db) == attr,
+        }
+    }
+}
 impl QueryAttrs for ItemImpl {
     fn has_attr(&self, db: &dyn SyntaxGroup, attr: &str) -> bool {
         self.attributes(db).elements(db).iter().any(|a| a.attr(db).text(db) == attr)
@@ -240,6 +252,17 @@ impl QueryAttrs for ItemTypeAlias {
         }
     }
 }
+impl QueryAttrs for ItemConstant {
+    fn has_attr(&self, db: &dyn SyntaxGroup, attr: &str) -> bool {
+        self.attributes(db).elements(db).iter().any(|a| a.attr(db).text(db) == attr)
+    }
+    fn last_attr(&self, db: &dyn SyntaxGroup, attr: &str) -> bool {
+        match self.attributes(db).elements(db).last() {
+            None => false,
+            Some(last_attr) => last_attr.attr(db).text(db) == attr,
+        }
+    }
+}
 
 impl QueryAttrs for Item {
     fn has_attr(&self, db: &dyn SyntaxGroup, attr: &str) -> bool {
@@ -254,6 +277,7 @@ impl QueryAttrs for Item {
             ast::Item::Struct(item) => item.has_attr(db, attr),
             ast::Item::Enum(item) => item.has_attr(db, attr),
             ast::Item::TypeAlias(item) => item.has_attr(db, attr),
+            ast::Item::Constant(item) => item.has_attr(db, attr),
         }
     }
 
@@ -269,6 +293,7 @@ impl QueryAttrs for Item {
             ast::Item::Struct(item) => item.last_attr(db, attr),
             ast::Item::Enum(item) => item.last_attr(db, attr),
             ast::Item::TypeAlias(item) => item.last_attr(db, attr),
+            ast::Item::Constant(item) => item.last_attr(db, attr),
         }
     }
 }
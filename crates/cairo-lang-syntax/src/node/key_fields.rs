@@ -24,9 +24,15 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::MatchArms => vec![],
         SyntaxKind::MatchArm => vec![],
         SyntaxKind::ExprIf => vec![],
+        SyntaxKind::ExprIfLet => vec![],
         SyntaxKind::ElseClause => vec![],
         SyntaxKind::OptionElseClauseEmpty => vec![],
+        SyntaxKind::ExprLoop => vec![],
+        SyntaxKind::ExprWhile => vec![],
         SyntaxKind::ExprErrorPropagate => vec![],
+        SyntaxKind::ExprIndexed => vec![],
+        SyntaxKind::ExprClosure => vec![],
+        SyntaxKind::ClosureParamsWrapped => vec![],
         SyntaxKind::StructArgExpr => vec![],
         SyntaxKind::OptionStructArgExprEmpty => vec![],
         SyntaxKind::StructArgSingle => vec![/* identifier */ children[0]],
@@ -47,9 +53,15 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::StatementList => vec![],
         SyntaxKind::StatementMissing => vec![],
         SyntaxKind::StatementLet => vec![/* pattern */ children[1]],
+        SyntaxKind::LetElseClause => vec![],
+        SyntaxKind::OptionLetElseClauseEmpty => vec![],
         SyntaxKind::OptionTerminalSemicolonEmpty => vec![],
         SyntaxKind::StatementExpr => vec![],
         SyntaxKind::StatementReturn => vec![],
+        SyntaxKind::ExprClause => vec![],
+        SyntaxKind::OptionExprClauseEmpty => vec![],
+        SyntaxKind::StatementBreak => vec![],
+        SyntaxKind::StatementContinue => vec![],
         SyntaxKind::Param => vec![/* name */ children[1]],
         SyntaxKind::ModifierList => vec![],
         SyntaxKind::ParamList => vec![],
@@ -58,30 +70,33 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::OptionImplicitsClauseEmpty => vec![],
         SyntaxKind::OptionTerminalNoPanicEmpty => vec![],
         SyntaxKind::FunctionSignature => vec![],
-        SyntaxKind::Member => vec![/* name */ children[0]],
+        SyntaxKind::Member => vec![/* name */ children[1]],
         SyntaxKind::MemberList => vec![],
         SyntaxKind::ItemList => vec![],
+        SyntaxKind::VisibilityDefault => vec![],
+        SyntaxKind::VisibilityPub => vec![],
         SyntaxKind::Attribute => vec![],
         SyntaxKind::AttributeList => vec![],
-        SyntaxKind::ItemModule => vec![/* name */ children[2]],
+        SyntaxKind::ItemModule => vec![/* name */ children[3]],
         SyntaxKind::ModuleBody => vec![],
         SyntaxKind::OptionAttributeArgsEmpty => vec![],
         SyntaxKind::AttributeArgs => vec![],
         SyntaxKind::AttributeArgList => vec![],
         SyntaxKind::FunctionDeclaration => vec![/* name */ children[1]],
-        SyntaxKind::ItemFreeFunction => vec![/* declaration */ children[1]],
-        SyntaxKind::ItemExternFunction => vec![/* declaration */ children[2]],
-        SyntaxKind::ItemExternType => vec![/* name */ children[3]],
-        SyntaxKind::ItemTrait => vec![/* name */ children[2]],
+        SyntaxKind::ItemFreeFunction => vec![/* declaration */ children[2]],
+        SyntaxKind::ItemExternFunction => vec![/* declaration */ children[3]],
+        SyntaxKind::ItemExternType => vec![/* name */ children[4]],
+        SyntaxKind::ItemTrait => vec![/* name */ children[3]],
         SyntaxKind::TraitBody => vec![],
         SyntaxKind::TraitItemList => vec![],
         SyntaxKind::TraitItemFunction => vec![/* declaration */ children[1]],
-        SyntaxKind::ItemImpl => vec![/* name */ children[2]],
+        SyntaxKind::ItemImpl => vec![/* name */ children[3]],
         SyntaxKind::ImplBody => vec![],
-        SyntaxKind::ItemStruct => vec![/* name */ children[2]],
-        SyntaxKind::ItemEnum => vec![/* name */ children[2]],
-        SyntaxKind::ItemTypeAlias => vec![/* name */ children[2]],
-        SyntaxKind::ItemUse => vec![/* name */ children[2]],
+        SyntaxKind::ItemStruct => vec![/* name */ children[3]],
+        SyntaxKind::ItemEnum => vec![/* name */ children[3]],
+        SyntaxKind::ItemTypeAlias => vec![/* name */ children[3]],
+        SyntaxKind::ItemConstant => vec![/* name */ children[3]],
+        SyntaxKind::ItemUse => vec![/* name */ children[3]],
         SyntaxKind::GenericArgs => vec![],
         SyntaxKind::GenericArgList => vec![],
         SyntaxKind::OptionWrappedGenericParamListEmpty => vec![],
@@ -94,6 +109,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalLiteralNumber => vec![],
         SyntaxKind::TokenShortString => vec![],
         SyntaxKind::TerminalShortString => vec![],
+        SyntaxKind::TokenString => vec![],
+        SyntaxKind::TerminalString => vec![],
         SyntaxKind::TokenFalse => vec![],
         SyntaxKind::TerminalFalse => vec![],
         SyntaxKind::TokenTrue => vec![],
@@ -102,6 +119,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalExtern => vec![],
         SyntaxKind::TokenType => vec![],
         SyntaxKind::TerminalType => vec![],
+        SyntaxKind::TokenConst => vec![],
+        SyntaxKind::TerminalConst => vec![],
         SyntaxKind::TokenFunction => vec![],
         SyntaxKind::TerminalFunction => vec![],
         SyntaxKind::TokenModule => vec![],
@@ -126,8 +145,18 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalIf => vec![],
         SyntaxKind::TokenElse => vec![],
         SyntaxKind::TerminalElse => vec![],
+        SyntaxKind::TokenLoop => vec![],
+        SyntaxKind::TerminalLoop => vec![],
+        SyntaxKind::TokenWhile => vec![],
+        SyntaxKind::TerminalWhile => vec![],
+        SyntaxKind::TokenBreak => vec![],
+        SyntaxKind::TerminalBreak => vec![],
+        SyntaxKind::TokenContinue => vec![],
+        SyntaxKind::TerminalContinue => vec![],
         SyntaxKind::TokenUse => vec![],
         SyntaxKind::TerminalUse => vec![],
+        SyntaxKind::TokenPub => vec![],
+        SyntaxKind::TerminalPub => vec![],
         SyntaxKind::TokenImplicits => vec![],
         SyntaxKind::TerminalImplicits => vec![],
         SyntaxKind::TokenRef => vec![],
@@ -146,6 +175,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalOrOr => vec![],
         SyntaxKind::TokenXor => vec![],
         SyntaxKind::TerminalXor => vec![],
+        SyntaxKind::TokenShl => vec![],
+        SyntaxKind::TerminalShl => vec![],
+        SyntaxKind::TokenShr => vec![],
+        SyntaxKind::TerminalShr => vec![],
         SyntaxKind::TokenEqEq => vec![],
         SyntaxKind::TerminalEqEq => vec![],
         SyntaxKind::TokenNeq => vec![],
@@ -182,6 +215,22 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalDotDot => vec![],
         SyntaxKind::TokenEq => vec![],
         SyntaxKind::TerminalEq => vec![],
+        SyntaxKind::TokenPlusEq => vec![],
+        SyntaxKind::TerminalPlusEq => vec![],
+        SyntaxKind::TokenMinusEq => vec![],
+        SyntaxKind::TerminalMinusEq => vec![],
+        SyntaxKind::TokenMulEq => vec![],
+        SyntaxKind::TerminalMulEq => vec![],
+        SyntaxKind::TokenDivEq => vec![],
+        SyntaxKind::TerminalDivEq => vec![],
+        SyntaxKind::TokenModEq => vec![],
+        SyntaxKind::TerminalModEq => vec![],
+        SyntaxKind::TokenAndEq => vec![],
+        SyntaxKind::TerminalAndEq => vec![],
+        SyntaxKind::TokenOrEq => vec![],
+        SyntaxKind::TerminalOrEq => vec![],
+        SyntaxKind::TokenXorEq => vec![],
+        SyntaxKind::TerminalXorEq => vec![],
         SyntaxKind::TokenSemicolon => vec![],
         SyntaxKind::TerminalSemicolon => vec![],
         SyntaxKind::TokenQuestionMark => vec![],
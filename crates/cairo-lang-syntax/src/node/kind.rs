@@ -20,9 +20,15 @@ pub enum SyntaxKind {
     MatchArms,
     MatchArm,
     ExprIf,
+    ExprIfLet,
     ElseClause,
     OptionElseClauseEmpty,
+    ExprLoop,
+    ExprWhile,
     ExprErrorPropagate,
+    ExprIndexed,
+    ExprClosure,
+    ClosureParamsWrapped,
     StructArgExpr,
     OptionStructArgExprEmpty,
     StructArgSingle,
@@ -43,9 +49,15 @@ pub enum SyntaxKind {
     StatementList,
     StatementMissing,
     StatementLet,
+    LetElseClause,
+    OptionLetElseClauseEmpty,
     OptionTerminalSemicolonEmpty,
     StatementExpr,
     StatementReturn,
+    ExprClause,
+    OptionExprClauseEmpty,
+    StatementBreak,
+    StatementContinue,
     Param,
     ModifierList,
     ParamList,
@@ -57,6 +69,8 @@ pub enum SyntaxKind {
     Member,
     MemberList,
     ItemList,
+    VisibilityDefault,
+    VisibilityPub,
     Attribute,
     AttributeList,
     ItemModule,
@@ -77,6 +91,7 @@ pub enum SyntaxKind {
     ItemStruct,
     ItemEnum,
     ItemTypeAlias,
+    ItemConstant,
     ItemUse,
     GenericArgs,
     GenericArgList,
@@ -90,6 +105,8 @@ pub enum SyntaxKind {
     TerminalLiteralNumber,
     TokenShortString,
     TerminalShortString,
+    TokenString,
+    TerminalString,
     TokenFalse,
     TerminalFalse,
     TokenTrue,
@@ -98,6 +115,8 @@ pub enum SyntaxKind {
     TerminalExtern,
     TokenType,
     TerminalType,
+    TokenConst,
+    TerminalConst,
     TokenFunction,
     TerminalFunction,
     TokenModule,
@@ -122,8 +141,18 @@ pub enum SyntaxKind {
     TerminalIf,
     TokenElse,
     TerminalElse,
+    TokenLoop,
+    TerminalLoop,
+    TokenWhile,
+    TerminalWhile,
+    TokenBreak,
+    TerminalBreak,
+    TokenContinue,
+    TerminalContinue,
     TokenUse,
     TerminalUse,
+    TokenPub,
+    TerminalPub,
     TokenImplicits,
     TerminalImplicits,
     TokenRef,
@@ -142,6 +171,10 @@ pub enum SyntaxKind {
     TerminalOrOr,
     TokenXor,
     TerminalXor,
+    TokenShl,
+    TerminalShl,
+    TokenShr,
+    TerminalShr,
     TokenEqEq,
     TerminalEqEq,
     TokenNeq,
@@ -178,6 +211,22 @@ pub enum SyntaxKind {
     TerminalDotDot,
     TokenEq,
     TerminalEq,
+    TokenPlusEq,
+    TerminalPlusEq,
+    TokenMinusEq,
+    TerminalMinusEq,
+    TokenMulEq,
+    TerminalMulEq,
+    TokenDivEq,
+    TerminalDivEq,
+    TokenModEq,
+    TerminalModEq,
+    TokenAndEq,
+    TerminalAndEq,
+    TokenOrEq,
+    TerminalOrEq,
+    TokenXorEq,
+    TerminalXorEq,
     TokenSemicolon,
     TerminalSemicolon,
     TokenQuestionMark,
@@ -220,10 +269,12 @@ impl SyntaxKind {
             SyntaxKind::TokenIdentifier
                 | SyntaxKind::TokenLiteralNumber
                 | SyntaxKind::TokenShortString
+                | SyntaxKind::TokenString
                 | SyntaxKind::TokenFalse
                 | SyntaxKind::TokenTrue
                 | SyntaxKind::TokenExtern
                 | SyntaxKind::TokenType
+                | SyntaxKind::TokenConst
                 | SyntaxKind::TokenFunction
                 | SyntaxKind::TokenModule
                 | SyntaxKind::TokenEnum
@@ -236,7 +287,12 @@ impl SyntaxKind {
                 | SyntaxKind::TokenMatch
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenElse
+                | SyntaxKind::TokenLoop
+                | SyntaxKind::TokenWhile
+                | SyntaxKind::TokenBreak
+                | SyntaxKind::TokenContinue
                 | SyntaxKind::TokenUse
+                | SyntaxKind::TokenPub
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenRef
                 | SyntaxKind::TokenMut
@@ -246,6 +302,8 @@ impl SyntaxKind {
                 | SyntaxKind::TokenOr
                 | SyntaxKind::TokenOrOr
                 | SyntaxKind::TokenXor
+                | SyntaxKind::TokenShl
+                | SyntaxKind::TokenShr
                 | SyntaxKind::TokenEqEq
                 | SyntaxKind::TokenNeq
                 | SyntaxKind::TokenGE
@@ -264,6 +322,14 @@ impl SyntaxKind {
                 | SyntaxKind::TokenDot
                 | SyntaxKind::TokenDotDot
                 | SyntaxKind::TokenEq
+                | SyntaxKind::TokenPlusEq
+                | SyntaxKind::TokenMinusEq
+                | SyntaxKind::TokenMulEq
+                | SyntaxKind::TokenDivEq
+                | SyntaxKind::TokenModEq
+                | SyntaxKind::TokenAndEq
+                | SyntaxKind::TokenOrEq
+                | SyntaxKind::TokenXorEq
                 | SyntaxKind::TokenSemicolon
                 | SyntaxKind::TokenQuestionMark
                 | SyntaxKind::TokenUnderscore
@@ -291,10 +357,12 @@ impl SyntaxKind {
             SyntaxKind::TerminalIdentifier
                 | SyntaxKind::TerminalLiteralNumber
                 | SyntaxKind::TerminalShortString
+                | SyntaxKind::TerminalString
                 | SyntaxKind::TerminalFalse
                 | SyntaxKind::TerminalTrue
                 | SyntaxKind::TerminalExtern
                 | SyntaxKind::TerminalType
+                | SyntaxKind::TerminalConst
                 | SyntaxKind::TerminalFunction
                 | SyntaxKind::TerminalModule
                 | SyntaxKind::TerminalEnum
@@ -307,7 +375,12 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalMatch
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalElse
+                | SyntaxKind::TerminalLoop
+                | SyntaxKind::TerminalWhile
+                | SyntaxKind::TerminalBreak
+                | SyntaxKind::TerminalContinue
                 | SyntaxKind::TerminalUse
+                | SyntaxKind::TerminalPub
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalRef
                 | SyntaxKind::TerminalMut
@@ -317,6 +390,8 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalOr
                 | SyntaxKind::TerminalOrOr
                 | SyntaxKind::TerminalXor
+                | SyntaxKind::TerminalShl
+                | SyntaxKind::TerminalShr
                 | SyntaxKind::TerminalEqEq
                 | SyntaxKind::TerminalNeq
                 | SyntaxKind::TerminalGE
@@ -335,6 +410,14 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalDot
                 | SyntaxKind::TerminalDotDot
                 | SyntaxKind::TerminalEq
+                | SyntaxKind::TerminalPlusEq
+                | SyntaxKind::TerminalMinusEq
+                | SyntaxKind::TerminalMulEq
+                | SyntaxKind::TerminalDivEq
+                | SyntaxKind::TerminalModEq
+                | SyntaxKind::TerminalAndEq
+                | SyntaxKind::TerminalOrEq
+                | SyntaxKind::TerminalXorEq
                 | SyntaxKind::TerminalSemicolon
                 | SyntaxKind::TerminalQuestionMark
                 | SyntaxKind::TerminalUnderscore
@@ -358,6 +441,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenTrue
                 | SyntaxKind::TokenExtern
                 | SyntaxKind::TokenType
+                | SyntaxKind::TokenConst
                 | SyntaxKind::TokenFunction
                 | SyntaxKind::TokenModule
                 | SyntaxKind::TokenEnum
@@ -370,7 +454,12 @@ impl SyntaxKind {
                 | SyntaxKind::TokenMatch
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenElse
+                | SyntaxKind::TokenLoop
+                | SyntaxKind::TokenWhile
+                | SyntaxKind::TokenBreak
+                | SyntaxKind::TokenContinue
                 | SyntaxKind::TokenUse
+                | SyntaxKind::TokenPub
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenRef
                 | SyntaxKind::TokenMut
@@ -384,6 +473,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalTrue
                 | SyntaxKind::TerminalExtern
                 | SyntaxKind::TerminalType
+                | SyntaxKind::TerminalConst
                 | SyntaxKind::TerminalFunction
                 | SyntaxKind::TerminalModule
                 | SyntaxKind::TerminalEnum
@@ -396,7 +486,12 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalMatch
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalElse
+                | SyntaxKind::TerminalLoop
+                | SyntaxKind::TerminalWhile
+                | SyntaxKind::TerminalBreak
+                | SyntaxKind::TerminalContinue
                 | SyntaxKind::TerminalUse
+                | SyntaxKind::TerminalPub
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalRef
                 | SyntaxKind::TerminalMut
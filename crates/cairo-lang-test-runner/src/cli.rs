@@ -27,9 +27,14 @@ use cairo_lang_syntax::node::Token;
 use clap::Parser;
 use colored::Colorize;
 use itertools::Itertools;
-use num_bigint::BigInt;
+use lazy_static::lazy_static;
+use num_bigint::{BigInt, Sign};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
+#[cfg(test)]
+#[path = "cli_test.rs"]
+mod test;
+
 /// Command line args parser.
 /// Exits with 0/1 if the input is formatted correctly/incorrectly.
 #[derive(Parser, Debug)]
@@ -114,14 +119,7 @@ fn main() -> anyhow::Result<()> {
                     println!("expected panic but finished successfully.");
                 }
                 RunResultValue::Panic(values) => {
-                    print!("panicked with [");
-                    for value in &values {
-                        match as_cairo_short_string(value) {
-                            Some(as_string) => print!("{value} ('{as_string}'), "),
-                            None => print!("{value}, "),
-                        }
-                    }
-                    println!("].")
+                    println!("panicked with [{}].", format_panic_data(&values));
                 }
             }
         }
@@ -136,6 +134,48 @@ fn main() -> anyhow::Result<()> {
     }
 }
 
+/// Formats the panic data of a failed test, rendering the byte arrays as strings and the values
+/// compared by a failed `assert_eq` as `left: X, right: Y`.
+fn format_panic_data(values: &[BigInt]) -> String {
+    let mut formatted = String::default();
+    let mut remaining = values;
+    while let [value, rest @ ..] = remaining {
+        if let Some((as_string, rest)) = as_cairo_byte_array(value, rest) {
+            formatted.push_str(&format!("\"{as_string}\", "));
+            remaining = rest;
+            continue;
+        }
+        if let Some(([left, right], rest)) = as_assert_eq_values(value, rest) {
+            formatted.push_str(&format!(
+                "left: {}, right: {}, ",
+                format_serialized(left),
+                format_serialized(right)
+            ));
+            remaining = rest;
+            continue;
+        }
+        formatted.push_str(&format!("{}, ", format_felt(value)));
+        remaining = rest;
+    }
+    formatted
+}
+
+/// Formats a value serialized to felts: as a single felt if it is one, and as a list otherwise.
+fn format_serialized(felts: &[BigInt]) -> String {
+    match felts {
+        [felt] => format_felt(felt),
+        _ => format!("[{}]", felts.iter().map(format_felt).join(", ")),
+    }
+}
+
+/// Formats a felt, followed by its value as a Cairo short-string if it has one.
+fn format_felt(value: &BigInt) -> String {
+    match as_cairo_short_string(value) {
+        Some(as_string) => format!("{value} ('{as_string}')"),
+        None => format!("{value}"),
+    }
+}
+
 /// Converts a bigint representing a felt to a Cairo short-string.
 fn as_cairo_short_string(value: &BigInt) -> Option<String> {
     let mut as_string = String::default();
@@ -152,6 +192,84 @@ fn as_cairo_short_string(value: &BigInt) -> Option<String> {
     Some(as_string)
 }
 
+lazy_static! {
+    /// Marks the two compared values in the panic data of a failed `assert_eq`. Must match
+    /// `ASSERT_EQ_MAGIC` in the corelib.
+    static ref ASSERT_EQ_MAGIC: BigInt = BigInt::parse_bytes(
+        b"1a05699b6238917737df08e5945ed936dca2c938ca584c0f963c937327a68c2",
+        16
+    )
+    .unwrap();
+}
+
+/// Extracts the serializations of the two compared values of a failed `assert_eq`, given the panic
+/// data starting at `value` and continuing at `rest`. The values are of the same type, so they are
+/// serialized to the same number of felts, and end the panic data. Returns the serializations and
+/// the (empty) panic data following them, or None if `value` does not mark them.
+fn as_assert_eq_values<'a>(
+    value: &BigInt,
+    rest: &'a [BigInt],
+) -> Option<([&'a [BigInt]; 2], &'a [BigInt])> {
+    if *value != *ASSERT_EQ_MAGIC || rest.is_empty() || rest.len() % 2 != 0 {
+        return None;
+    }
+    let (left, right) = rest.split_at(rest.len() / 2);
+    Some(([left, right], &rest[rest.len()..]))
+}
+
+lazy_static! {
+    /// Marks the start of a serialized byte array in panic data. Must match `BYTE_ARRAY_MAGIC` in
+    /// the corelib.
+    static ref BYTE_ARRAY_MAGIC: BigInt = BigInt::parse_bytes(
+        b"46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3",
+        16
+    )
+    .unwrap();
+}
+/// The number of bytes in each of the full words of a serialized byte array.
+const BYTES_IN_WORD: usize = 31;
+
+/// Converts a serialized byte array, starting at `value` and continuing at `rest`, to a string.
+/// Returns the string and the values following the byte array, or None if `value` does not start
+/// a valid byte array.
+fn as_cairo_byte_array<'a>(value: &BigInt, rest: &'a [BigInt]) -> Option<(String, &'a [BigInt])> {
+    if *value != *BYTE_ARRAY_MAGIC {
+        return None;
+    }
+    let (n_words, rest) = rest.split_first()?;
+    let n_words: usize = n_words.try_into().ok()?;
+    if rest.len() < n_words {
+        return None;
+    }
+    let (words, rest) = rest.split_at(n_words);
+    let [pending_word, pending_word_len, rest @ ..] = rest else {
+        return None;
+    };
+    let pending_word_len: usize = pending_word_len.try_into().ok()?;
+    if pending_word_len >= BYTES_IN_WORD {
+        return None;
+    }
+    let mut bytes = vec![];
+    for (word, len) in
+        words.iter().map(|word| (word, BYTES_IN_WORD)).chain([(pending_word, pending_word_len)])
+    {
+        let (sign, mut word_bytes) = word.to_bytes_be();
+        match sign {
+            Sign::Minus => return None,
+            // Zero is represented by a single zero byte.
+            Sign::NoSign => word_bytes.clear(),
+            Sign::Plus => {}
+        }
+        if word_bytes.len() > len {
+            return None;
+        }
+        // Leading zero bytes are dropped by `to_bytes_be`.
+        bytes.resize(bytes.len() + len - word_bytes.len(), 0);
+        bytes.extend(word_bytes);
+    }
+    Some((String::from_utf8(bytes).ok()?, rest))
+}
+
 /// Summary data of the ran tests.
 struct TestsSummary {
     passed: Vec<String>,
@@ -0,0 +1,67 @@
+use num_bigint::{BigInt, Sign};
+use pretty_assertions::assert_eq;
+
+use super::{as_cairo_byte_array, format_panic_data, ASSERT_EQ_MAGIC, BYTE_ARRAY_MAGIC};
+
+/// Returns the felt whose big-endian bytes are `bytes`.
+fn word(bytes: &[u8]) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, bytes)
+}
+
+#[test]
+fn test_as_cairo_byte_array() {
+    let full_word = word(b"abcdefghijklmnopqrstuvwxyz01234");
+    let data = [1.into(), full_word, word(b"xy"), 2.into(), 7.into()];
+    assert_eq!(
+        as_cairo_byte_array(&BYTE_ARRAY_MAGIC, &data),
+        Some(("abcdefghijklmnopqrstuvwxyz01234xy".to_string(), &data[4..]))
+    );
+}
+
+#[test]
+fn test_as_cairo_byte_array_empty_pending_word() {
+    let data = [1.into(), word(b"abcdefghijklmnopqrstuvwxyz01234"), 0.into(), 0.into()];
+    assert_eq!(
+        as_cairo_byte_array(&BYTE_ARRAY_MAGIC, &data),
+        Some(("abcdefghijklmnopqrstuvwxyz01234".to_string(), &data[4..]))
+    );
+}
+
+#[test]
+fn test_as_cairo_byte_array_wrong_magic() {
+    let data = [0.into(), word(b"ab"), 2.into()];
+    assert_eq!(as_cairo_byte_array(&1.into(), &data), None);
+}
+
+#[test]
+fn test_as_cairo_byte_array_too_few_words() {
+    let data = [2.into(), word(b"abcdefghijklmnopqrstuvwxyz01234"), word(b"ab"), 2.into()];
+    assert_eq!(as_cairo_byte_array(&BYTE_ARRAY_MAGIC, &data), None);
+}
+
+#[test]
+fn test_as_cairo_byte_array_word_too_long() {
+    let data = [1.into(), word(b"abcdefghijklmnopqrstuvwxyz012345"), 0.into(), 0.into()];
+    assert_eq!(as_cairo_byte_array(&BYTE_ARRAY_MAGIC, &data), None);
+}
+
+#[test]
+fn test_format_panic_data_assert_eq() {
+    let data = [word(b"err"), ASSERT_EQ_MAGIC.clone(), word(b"a"), word(b"b")];
+    assert_eq!(format_panic_data(&data), "6648434 ('err'), left: 97 ('a'), right: 98 ('b'), ");
+}
+
+#[test]
+fn test_format_panic_data_assert_eq_multiple_felts() {
+    let data = [word(b"err"), ASSERT_EQ_MAGIC.clone(), word(b"a"), 0.into(), word(b"b"), 0.into()];
+    assert_eq!(
+        format_panic_data(&data),
+        "6648434 ('err'), left: [97 ('a'), 0 ('')], right: [98 ('b'), 0 ('')], "
+    );
+}
+
+#[test]
+fn test_format_panic_data_byte_array() {
+    let data = [BYTE_ARRAY_MAGIC.clone(), 0.into(), word(b"ab"), 2.into(), word(b"c")];
+    assert_eq!(format_panic_data(&data), "\"ab\", 99 ('c'), ");
+}
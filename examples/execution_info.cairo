@@ -0,0 +1,34 @@
+use starknet::contract_address_to_felt;
+
+// Returns the execution info values, and the length and first value of the signature.
+fn execution_info() -> (felt, felt, felt, felt, felt, felt, felt, felt, felt, felt, felt) {
+    let caller_address = starknet::get_caller_address();
+    let contract_address = starknet::get_contract_address();
+    let block_number = starknet::get_block_number();
+    let block_timestamp = starknet::get_block_timestamp();
+    let starknet::TxInfo{account_contract_address,
+    max_fee,
+    signature,
+    transaction_hash,
+    chain_id,
+    nonce } = starknet::get_tx_info();
+    let mut signature = signature;
+    let signature_len: felt = array_len::<felt>(signature).into();
+    let signature_first = match array_at::<felt>(signature, 0_u128) {
+        Option::Some(x) => x,
+        Option::None(()) => 0,
+    };
+    (
+        contract_address_to_felt(caller_address),
+        contract_address_to_felt(contract_address),
+        block_number.into(),
+        block_timestamp.into(),
+        contract_address_to_felt(account_contract_address),
+        max_fee.into(),
+        signature_len,
+        signature_first,
+        transaction_hash,
+        chain_id,
+        nonce
+    )
+}
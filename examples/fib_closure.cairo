@@ -0,0 +1,14 @@
+// Calculates the sum of the first n Fibonacci numbers, each multiplied by `factor`, using closures.
+fn fib_sum(n: felt, factor: felt) -> felt {
+    let mut arr = array_new();
+    fib_inner(arr, 1, 1, n);
+    let mut scaled: Array::<felt> = array_map(arr, |x: felt| x * factor);
+    array_fold(scaled, 0, |acc: felt, x: felt| acc + x)
+}
+
+fn fib_inner(ref arr: Array::<felt>, a: felt, b: felt, n: felt) {
+    if n != 0 {
+        array_append(arr, a);
+        fib_inner(arr, b, a + b, n - 1);
+    }
+}
@@ -0,0 +1,14 @@
+// Calculates fib, or returns the last two values once the budget of steps runs out.
+enum Either<L, R> { Left: L, Right: R, }
+
+fn fib(a: felt, b: felt, n: felt, budget: felt) -> Either::<felt, (felt, felt)> {
+    match n {
+        0 => Either::<felt, (felt, felt)>::Left(a),
+        _ => {
+            match budget {
+                0 => Either::<felt, (felt, felt)>::Right((a, b)),
+                _ => fib(b, a + b, n - 1, budget - 1),
+            }
+        },
+    }
+}
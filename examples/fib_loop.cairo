@@ -0,0 +1,24 @@
+// Calculates fib using a loop.
+fn fib(a: felt, b: felt, n: felt) -> felt implicits(RangeCheck, GasBuiltin) {
+    let mut a = a;
+    let mut b = b;
+    let mut n = n;
+    loop {
+        match get_gas() {
+            Option::Some(_) => {
+            },
+            Option::None(_) => {
+                let mut data = array_new::<felt>();
+                array_append::<felt>(data, 'OOG');
+                panic(data);
+            },
+        }
+        if n == 0 {
+            break a;
+        }
+        let c = a + b;
+        a = b;
+        b = c;
+        n = n - 1;
+    }
+}
@@ -0,0 +1,28 @@
+// Returns the index of the first fib number that is at least `n`, or 0 if it is past `limit`.
+fn fib_index(n: felt, limit: felt) -> felt implicits(RangeCheck, GasBuiltin) {
+    let mut a = 1;
+    let mut b = 1;
+    let mut i = 0;
+    loop {
+        match get_gas() {
+            Option::Some(_) => {
+            },
+            Option::None(_) => {
+                let mut data = array_new::<felt>();
+                array_append::<felt>(data, 'OOG');
+                panic(data);
+            },
+        }
+        if a == n {
+            return i;
+        }
+        if i == limit {
+            break;
+        }
+        let c = a + b;
+        a = b;
+        b = c;
+        i = i + 1;
+    };
+    0
+}
@@ -0,0 +1,24 @@
+// Calculates fib using a method call on a struct.
+#[derive(Copy, Drop)]
+struct FibState { a: felt, b: felt }
+
+trait FibStateTrait {
+    fn next(self: FibState) -> FibState;
+}
+
+impl FibStateImpl of FibStateTrait {
+    fn next(self: FibState) -> FibState {
+        FibState { a: self.b, b: self.a + self.b }
+    }
+}
+
+fn fib(a: felt, b: felt, n: felt) -> felt {
+    match n {
+        0 => a,
+        _ => {
+            let state = FibState { a: a, b: b };
+            let state = state.next();
+            fib(state.a, state.b, n - 1)
+        },
+    }
+}
@@ -0,0 +1,13 @@
+// Calculates fib, returning the result and its index in a generic struct.
+#[derive(Copy, Drop)]
+struct Pair<A, B> { first: A, second: B }
+
+fn fib(a: felt, b: felt, n: felt) -> Pair::<felt, felt> {
+    match n {
+        0 => Pair::<felt, felt> { first: a, second: 0 },
+        _ => {
+            let r = fib(b, a + b, n - 1);
+            Pair::<felt, felt> { first: r.first, second: r.second + 1 }
+        },
+    }
+}
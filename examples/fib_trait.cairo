@@ -0,0 +1,23 @@
+// Calculates fib using a trait implemented for a struct.
+#[derive(Copy, Drop)]
+struct FibState { a: felt, b: felt }
+
+trait FibTrait {
+    fn next(self: FibState) -> FibState;
+}
+
+impl FibImpl of FibTrait {
+    fn next(self: FibState) -> FibState {
+        FibState { a: self.b, b: self.a + self.b }
+    }
+}
+
+fn fib(a: felt, b: felt, n: felt) -> felt {
+    match n {
+        0 => a,
+        _ => {
+            let state = FibTrait::next(FibState { a: a, b: b });
+            fib(state.a, state.b, n - 1)
+        },
+    }
+}
@@ -1,8 +1,7 @@
 // Calculates fib...
 fn fib(a: u128, b: u128, n: u128) -> u128 implicits(RangeCheck) {
-    // TODO(orizi): Use match on u128 when supported.
-    match u128_to_felt(n) {
-        0 => a,
+    match n {
+        0_u128 => a,
         _ => {
             fib(b, a + b, n - 1_u128)
         },
@@ -1,7 +1,7 @@
 // Test two level of inline module nesting.
-mod inner {
-    mod inner {
-        fn fib_inner(a: felt, b: felt, n: felt) -> felt {
+pub mod inner {
+    pub mod inner {
+        pub fn fib_inner(a: felt, b: felt, n: felt) -> felt {
             if n != 0 {
                 fib_inner(b, a + b, n - 1)
             } else {
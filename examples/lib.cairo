@@ -1,13 +1,21 @@
 mod corelib_usage;
 mod enum_flow;
+mod execution_info;
 mod fib;
 mod fib_array;
 mod fib_box;
 mod fib_caller;
+mod fib_closure;
 mod fib_counter;
+mod fib_either;
 mod fib_gas;
 mod fib_local;
+mod fib_loop;
+mod fib_loop_return;
+mod fib_method;
+mod fib_pair;
 mod fib_struct;
+mod fib_trait;
 mod fib_u128_checked;
 mod fib_u128;
 mod fib_unary;
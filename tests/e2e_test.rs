@@ -15,16 +15,21 @@ cairo_lang_test_utils::test_file_test!(
         box_: "box",
         dict_felt_to: "dict_felt_to",
         nullable: "nullable",
+        u8: "u8",
+        i8: "i8",
         u128: "u128",
+        u256: "u256",
         bool: "bool",
+        starknet: "starknet",
     },
     run_small_e2e_test
 );
 
 fn run_small_e2e_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashMap<String, String> {
     let db = &mut RootDatabase::default();
-    // Parse code and create semantic model.
-    let test_module = setup_test_module(db, inputs["cairo"].as_str()).unwrap();
+    // Parse code and create semantic model. Warnings are allowed, as some of the tests exercise
+    // libfuncs through their deprecated wrapper functions.
+    let (test_module, _warnings) = setup_test_module(db, inputs["cairo"].as_str()).split();
     assert!(!check_and_eprint_diagnostics(db));
 
     // Compile to Sierra.
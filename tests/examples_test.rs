@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use assert_matches::assert_matches;
+use cairo_lang_casm::run::{StarknetState, TxInfo};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::check_and_eprint_diagnostics;
 use cairo_lang_compiler::project::setup_project;
@@ -50,12 +51,18 @@ fn checked_compile_to_sierra(name: &str) -> cairo_lang_sierra::program::Program
 #[test_case("fib")]
 #[test_case("fib_box")]
 #[test_case("fib_array")]
+#[test_case("fib_closure")]
 #[test_case("fib_counter")]
+#[test_case("fib_either")]
+#[test_case("fib_pair")]
 #[test_case("fib_struct")]
+#[test_case("fib_trait")]
+#[test_case("fib_method")]
 #[test_case("fib_u128")]
 #[test_case("fib_u128_checked")]
 #[test_case("fib_gas")]
 #[test_case("fib_local")]
+#[test_case("fib_loop")]
 #[test_case("fib_unary")]
 #[test_case("enum_flow")]
 #[test_case("corelib_usage")]
@@ -71,12 +78,18 @@ fn cairo_to_sierra(name: &str) {
 #[test_case("fib", false)]
 #[test_case("fib_box", false)]
 #[test_case("fib_array", false)]
+#[test_case("fib_closure", false)]
 #[test_case("fib_counter", false)]
+#[test_case("fib_either", false)]
+#[test_case("fib_pair", false)]
 #[test_case("fib_struct", false)]
+#[test_case("fib_trait", false)]
+#[test_case("fib_method", false)]
 #[test_case("fib_u128", false)]
 #[test_case("fib_u128_checked", false)]
 #[test_case("fib_gas", true)]
 #[test_case("fib_local", false)]
+#[test_case("fib_loop", true)]
 #[test_case("fib_unary", false)]
 #[test_case("enum_flow", false)]
 #[test_case("corelib_usage", false)]
@@ -103,11 +116,16 @@ fn cairo_to_casm(name: &str, enable_gas_checks: bool) {
 #[test_case("fib_box")]
 #[test_case("fib_array")]
 #[test_case("fib_counter")]
+#[test_case("fib_either")]
+#[test_case("fib_pair")]
 #[test_case("fib_struct")]
+#[test_case("fib_trait")]
+#[test_case("fib_method")]
 #[test_case("fib_u128")]
 #[test_case("fib_u128_checked")]
 #[test_case("fib_gas")]
 #[test_case("fib_local")]
+#[test_case("fib_loop")]
 #[test_case("fib_unary")]
 #[test_case("corelib_usage")]
 #[test_case("hash_chain")]
@@ -122,18 +140,54 @@ fn lowering_test(name: &str) {
     RunResultValue::Success(vec![BigInt::from(21)]);
     "fib"
 )]
+#[test_case(
+    "fib_closure",
+    &[6, 2].map(BigInt::from), None =>
+    RunResultValue::Success(vec![BigInt::from(40)]);
+    "fib_closure"
+)]
 #[test_case(
     "fib_counter",
     &[1, 1, 8].map(BigInt::from), None =>
     RunResultValue::Success([34, 8].map(BigInt::from).into_iter().collect());
     "fib_counter"
 )]
+#[test_case(
+    "fib_either",
+    &[1, 1, 9, 20].map(BigInt::from), None =>
+    RunResultValue::Success([/*left*/0, 55, /*padding*/0].map(BigInt::from).into_iter().collect());
+    "fib_either"
+)]
+#[test_case(
+    "fib_either",
+    &[1, 1, 9, 3].map(BigInt::from), None =>
+    RunResultValue::Success([/*right*/1, 3, 5].map(BigInt::from).into_iter().collect());
+    "fib_either_out_of_budget"
+)]
+#[test_case(
+    "fib_pair",
+    &[1, 1, 9].map(BigInt::from), None =>
+    RunResultValue::Success([55, 9].map(BigInt::from).into_iter().collect());
+    "fib_pair"
+)]
 #[test_case(
     "fib_struct",
     &[1, 1, 9].map(BigInt::from), None =>
     RunResultValue::Success([55, 9].map(BigInt::from).into_iter().collect());
     "fib_struct"
 )]
+#[test_case(
+    "fib_trait",
+    &[1, 1, 10].map(BigInt::from), None =>
+    RunResultValue::Success(vec![BigInt::from(89)]);
+    "fib_trait"
+)]
+#[test_case(
+    "fib_method",
+    &[1, 1, 10].map(BigInt::from), None =>
+    RunResultValue::Success(vec![BigInt::from(89)]);
+    "fib_method"
+)]
 #[test_case(
     "fib_u128_checked",
     &[1, 1, 10].map(BigInt::from), None =>
@@ -176,6 +230,30 @@ fn lowering_test(name: &str) {
     RunResultValue::Success(vec![BigInt::from(13)]);
     "fib_local"
 )]
+#[test_case(
+    "fib_loop",
+    &[1, 1, 10].map(BigInt::from), Some(200000) =>
+    RunResultValue::Success([89].map(BigInt::from).into_iter().collect());
+    "fib_loop"
+)]
+#[test_case(
+    "fib_loop",
+    &[1, 1, 10].map(BigInt::from), Some(2000) =>
+    RunResultValue::Panic(vec![BigInt::from_bytes_be(num_bigint::Sign::Plus, b"OOG")]);
+    "fib_loop_out_of_gas"
+)]
+#[test_case(
+    "fib_loop_return",
+    &[21, 20].map(BigInt::from), Some(200000) =>
+    RunResultValue::Success([7].map(BigInt::from).into_iter().collect());
+    "fib_loop_return"
+)]
+#[test_case(
+    "fib_loop_return",
+    &[22, 20].map(BigInt::from), Some(200000) =>
+    RunResultValue::Success([0].map(BigInt::from).into_iter().collect());
+    "fib_loop_return_not_found"
+)]
 #[test_case(
     "fib_unary",
     &[7].map(BigInt::from), None =>
@@ -220,3 +298,30 @@ fn run_fib_array_len(n: usize, last: usize) {
         [_, _, actual_last, actual_len] if actual_last == &BigInt::from(last) && actual_len == &BigInt::from(n)
     );
 }
+
+#[test]
+fn run_execution_info() {
+    let runner = SierraCasmRunner::new(checked_compile_to_sierra("execution_info"), true)
+        .expect("Failed setting up runner.");
+    let starknet_state = StarknetState {
+        caller_address: BigInt::from(1),
+        contract_address: BigInt::from(2),
+        block_number: BigInt::from(3),
+        block_timestamp: BigInt::from(4),
+        tx_info: TxInfo {
+            account_contract_address: BigInt::from(5),
+            max_fee: BigInt::from(6),
+            signature: [7, 8].map(BigInt::from).to_vec(),
+            transaction_hash: BigInt::from(9),
+            chain_id: BigInt::from(10),
+            nonce: BigInt::from(11),
+        },
+    };
+    let result = runner
+        .run_function_with_starknet_state("", &[], Some(usize::MAX), starknet_state)
+        .expect("Failed running the function.");
+    assert_eq!(
+        result.value,
+        RunResultValue::Success([1, 2, 3, 4, 5, 6, 2, 7, 9, 10, 11].map(BigInt::from).to_vec())
+    );
+}